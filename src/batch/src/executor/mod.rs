@@ -18,11 +18,14 @@ use risingwave_pb::batch_plan::plan_node::NodeBody;
 use risingwave_pb::batch_plan::PlanNode;
 
 use crate::executor2::{
-    BoxedExecutor2, BoxedExecutor2Builder, DeleteExecutor2, ExchangeExecutor2, FilterExecutor2,
-    GenerateSeriesExecutor2Builder, HashAggExecutor2Builder, HashJoinExecutor2Builder,
-    HopWindowExecutor2, InsertExecutor2, LimitExecutor2, MergeSortExchangeExecutor2,
-    NestedLoopJoinExecutor2, OrderByExecutor2, ProjectExecutor2, RowSeqScanExecutor2Builder,
-    SortAggExecutor2, SortMergeJoinExecutor2, TopNExecutor2, TraceExecutor2, ValuesExecutor2,
+    BoxedExecutor2, BoxedExecutor2Builder, DeleteExecutor2, ExchangeExecutor2, ExpandExecutor2,
+    FilterExecutor2, GenerateSeriesExecutor2Builder, HashAggExecutor2Builder,
+    HashJoinExecutor2Builder, HopWindowExecutor2, InsertExecutor2, LimitExecutor2,
+    LookupExecutor2Builder, MergeSortExchangeExecutor2, NestedLoopJoinExecutor2, OrderByExecutor2,
+    ProfileExecutor2, ProjectExecutor2, ProjectSetExecutor2, RowSeqScanExecutor2Builder,
+    SortAggExecutor2, SortMergeJoinExecutor2, SysRowSeqScanExecutor2,
+    TableFunctionExecutor2Builder, TaskProfiler, TopNExecutor2, TraceExecutor2, UpdateExecutor2,
+    ValuesExecutor2,
 };
 use crate::task::{BatchEnvironment, TaskId};
 
@@ -49,6 +52,7 @@ pub struct ExecutorBuilder<'a> {
     pub task_id: &'a TaskId,
     env: BatchEnvironment,
     epoch: u64,
+    profiler: TaskProfiler,
 }
 
 macro_rules! build_executor2 {
@@ -69,12 +73,14 @@ impl<'a> ExecutorBuilder<'a> {
         task_id: &'a TaskId,
         env: BatchEnvironment,
         epoch: u64,
+        profiler: TaskProfiler,
     ) -> Self {
         Self {
             plan_node,
             task_id,
             env,
             epoch,
+            profiler,
         }
     }
 
@@ -91,14 +97,23 @@ impl<'a> ExecutorBuilder<'a> {
 
     #[must_use]
     pub fn clone_for_plan(&self, plan_node: &'a PlanNode) -> Self {
-        ExecutorBuilder::new(plan_node, self.task_id, self.env.clone(), self.epoch)
+        ExecutorBuilder::new(
+            plan_node,
+            self.task_id,
+            self.env.clone(),
+            self.epoch,
+            self.profiler.clone(),
+        )
     }
 
     fn try_build2(&self) -> Result<BoxedExecutor2> {
         let real_executor = build_executor2! { self,
             NodeBody::RowSeqScan => RowSeqScanExecutor2Builder,
+            NodeBody::SysRowSeqScan => SysRowSeqScanExecutor2,
+            NodeBody::Lookup => LookupExecutor2Builder,
             NodeBody::Insert => InsertExecutor2,
             NodeBody::Delete => DeleteExecutor2,
+            NodeBody::Update => UpdateExecutor2,
             NodeBody::Exchange => ExchangeExecutor2,
             NodeBody::Filter => FilterExecutor2,
             NodeBody::Project => ProjectExecutor2,
@@ -113,10 +128,17 @@ impl<'a> ExecutorBuilder<'a> {
             NodeBody::HashAgg => HashAggExecutor2Builder,
             NodeBody::MergeSortExchange => MergeSortExchangeExecutor2,
             NodeBody::GenerateSeries => GenerateSeriesExecutor2Builder,
+            NodeBody::TableFunction => TableFunctionExecutor2Builder,
             NodeBody::HopWindow => HopWindowExecutor2,
+            NodeBody::Expand => ExpandExecutor2,
+            NodeBody::ProjectSet => ProjectSetExecutor2,
         }?;
         let input_desc = real_executor.identity().to_string();
-        Ok(Box::new(TraceExecutor2::new(real_executor, input_desc)))
+        let traced_executor = Box::new(TraceExecutor2::new(real_executor, input_desc));
+        let profile = self
+            .profiler
+            .register(self.plan_node.get_identity().clone());
+        Ok(Box::new(ProfileExecutor2::new(traced_executor, profile)))
     }
 
     pub fn plan_node(&self) -> &PlanNode {
@@ -137,6 +159,7 @@ mod tests {
     use risingwave_pb::batch_plan::PlanNode;
 
     use crate::executor::ExecutorBuilder;
+    use crate::executor2::TaskProfiler;
     use crate::task::{BatchEnvironment, TaskId};
 
     #[test]
@@ -149,8 +172,13 @@ mod tests {
             stage_id: 1,
             query_id: "test_query_id".to_string(),
         };
-        let builder =
-            ExecutorBuilder::new(&plan_node, task_id, BatchEnvironment::for_test(), u64::MAX);
+        let builder = ExecutorBuilder::new(
+            &plan_node,
+            task_id,
+            BatchEnvironment::for_test(),
+            u64::MAX,
+            TaskProfiler::default(),
+        );
         let child_plan = &PlanNode {
             ..Default::default()
         };
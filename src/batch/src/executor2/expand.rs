@@ -0,0 +1,195 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use futures_async_stream::try_stream;
+use itertools::Itertools;
+use risingwave_common::array::column::Column;
+use risingwave_common::array::{DataChunk, I64Array};
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::DataType;
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+
+use crate::executor::ExecutorBuilder;
+use crate::executor2::{BoxedDataChunkStream, BoxedExecutor2, BoxedExecutor2Builder, Executor2};
+
+/// `ExpandExecutor2` duplicates each input row once for every column subset. In the `i`-th copy,
+/// the columns not in `column_subsets[i]` are set to NULL, while the original columns and the flag
+/// `i` are appended after them.
+pub struct ExpandExecutor2 {
+    column_subsets: Vec<Vec<usize>>,
+    child: BoxedExecutor2,
+    schema: Schema,
+    identity: String,
+}
+
+impl ExpandExecutor2 {
+    pub fn new(child: BoxedExecutor2, column_subsets: Vec<Vec<usize>>, identity: String) -> Self {
+        let schema = {
+            let mut fields = child.schema().clone().into_fields();
+            fields.extend(fields.clone());
+            fields.push(Field::with_name(DataType::Int64, "flag"));
+            Schema::new(fields)
+        };
+        Self {
+            column_subsets,
+            child,
+            schema,
+            identity,
+        }
+    }
+
+    #[try_stream(boxed, ok = DataChunk, error = RwError)]
+    async fn do_execute(self: Box<Self>) {
+        let Self {
+            column_subsets,
+            child,
+            ..
+        } = *self;
+        let data_types = child.schema().data_types();
+
+        #[for_await]
+        for data_chunk in child.execute() {
+            let data_chunk = data_chunk?.compact()?;
+            let cardinality = data_chunk.cardinality();
+            let (columns, _) = data_chunk.into_parts();
+
+            for (flag, subset) in column_subsets.iter().enumerate() {
+                let mut new_columns = Vec::with_capacity(columns.len() * 2 + 1);
+                for (idx, column) in columns.iter().enumerate() {
+                    if subset.contains(&idx) {
+                        new_columns.push(column.clone());
+                    } else {
+                        new_columns.push(null_column(&data_types[idx], cardinality)?);
+                    }
+                }
+                new_columns.extend(columns.iter().cloned());
+                let flags = I64Array::from_slice(&vec![Some(flag as i64); cardinality])?;
+                new_columns.push(Column::new(Arc::new(flags.into())));
+                yield DataChunk::new(new_columns, None);
+            }
+        }
+    }
+}
+
+fn null_column(data_type: &DataType, cardinality: usize) -> Result<Column> {
+    let mut builder = data_type.create_array_builder(cardinality)?;
+    for _ in 0..cardinality {
+        builder.append_null()?;
+    }
+    Ok(Column::new(Arc::new(builder.finish()?)))
+}
+
+impl Executor2 for ExpandExecutor2 {
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+
+    fn execute(self: Box<Self>) -> BoxedDataChunkStream {
+        self.do_execute()
+    }
+}
+
+impl BoxedExecutor2Builder for ExpandExecutor2 {
+    fn new_boxed_executor2(source: &ExecutorBuilder) -> Result<BoxedExecutor2> {
+        ensure!(source.plan_node().get_children().len() == 1);
+        let expand_node = try_match_expand!(
+            source.plan_node().get_node_body().unwrap(),
+            NodeBody::Expand
+        )?;
+        let column_subsets = expand_node
+            .column_subsets
+            .iter()
+            .map(|subset| {
+                subset
+                    .column_indices
+                    .iter()
+                    .map(|idx| *idx as usize)
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        let child_node = &source.plan_node().get_children()[0];
+        let child = source.clone_for_plan(child_node).build2()?;
+
+        Ok(Box::new(Self::new(
+            child,
+            column_subsets,
+            source.plan_node().get_identity().clone(),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use risingwave_common::array::{DataChunk, DataChunkTestExt};
+    use risingwave_common::catalog::{Field, Schema};
+    use risingwave_common::types::DataType;
+
+    use super::ExpandExecutor2;
+    use crate::executor::test_utils::MockExecutor;
+    use crate::executor2::Executor2;
+
+    #[tokio::test]
+    async fn test_expand_executor() {
+        let mock_schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int32),
+                Field::unnamed(DataType::Int32),
+                Field::unnamed(DataType::Int32),
+            ],
+        };
+        let mut mock_executor = MockExecutor::new(mock_schema);
+        mock_executor.add(DataChunk::from_pretty(
+            "i i i
+             1 2 3
+             2 3 4",
+        ));
+        let column_subsets = vec![vec![0, 1], vec![1, 2]];
+        let expand_executor = Box::new(ExpandExecutor2::new(
+            Box::new(mock_executor),
+            column_subsets,
+            "ExpandExecutor2".to_string(),
+        ));
+        assert_eq!(expand_executor.schema().len(), 7);
+
+        let mut stream = expand_executor.execute();
+        let res = stream.next().await.unwrap().unwrap();
+        assert_eq!(
+            res,
+            DataChunk::from_pretty(
+                "i i i i i i I
+                 1 2 . 1 2 3 0
+                 2 3 . 2 3 4 0"
+            )
+        );
+        let res = stream.next().await.unwrap().unwrap();
+        assert_eq!(
+            res,
+            DataChunk::from_pretty(
+                "i i i i i i I
+                 . 2 3 1 2 3 1
+                 . 3 4 2 3 4 1"
+            )
+        );
+        assert!(stream.next().await.is_none());
+    }
+}
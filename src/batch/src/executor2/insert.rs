@@ -17,6 +17,7 @@ use std::sync::Arc;
 
 use futures::future::try_join_all;
 use futures_async_stream::try_stream;
+use itertools::Itertools;
 use risingwave_common::array::column::Column;
 use risingwave_common::array::{
     ArrayBuilder, DataChunk, I64ArrayBuilder, Op, PrimitiveArrayBuilder, StreamChunk,
@@ -26,7 +27,9 @@ use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_common::types::DataType;
 use risingwave_pb::batch_plan::plan_node::NodeBody;
 use risingwave_source::SourceManagerRef;
+use risingwave_storage::StateStoreImpl;
 
+use super::unique_index::{check_unique_indexes, chunk_rows, revert_rows, UniqueIndex};
 use crate::executor::ExecutorBuilder;
 use crate::executor2::{BoxedDataChunkStream, BoxedExecutor2, BoxedExecutor2Builder, Executor2};
 
@@ -37,22 +40,50 @@ pub struct InsertExecutor2 {
     source_manager: SourceManagerRef,
 
     child: BoxedExecutor2,
+    /// Indices of the user-defined primary key in the chunks from `child`.
+    pk_indices: Vec<usize>,
+    /// The unique indexes on the table, which the inserted rows are checked against.
+    unique_indexes: Vec<UniqueIndex>,
+    state_store: Option<StateStoreImpl>,
     schema: Schema,
     identity: String,
 }
 
 impl InsertExecutor2 {
-    pub fn new(table_id: TableId, source_manager: SourceManagerRef, child: BoxedExecutor2) -> Self {
+    pub fn new(
+        table_id: TableId,
+        source_manager: SourceManagerRef,
+        child: BoxedExecutor2,
+        pk_indices: Vec<usize>,
+    ) -> Self {
         Self {
             table_id,
             source_manager,
             child,
+            pk_indices,
+            unique_indexes: vec![],
+            state_store: None,
             schema: Schema {
                 fields: vec![Field::unnamed(DataType::Int64)],
             },
             identity: "InsertExecutor".to_string(),
         }
     }
+
+    /// Let the executor check the inserted rows against the unique indexes on the table, which
+    /// are read from `state_store`.
+    #[must_use]
+    pub(super) fn with_unique_indexes(
+        self,
+        unique_indexes: Vec<UniqueIndex>,
+        state_store: StateStoreImpl,
+    ) -> Self {
+        Self {
+            unique_indexes,
+            state_store: Some(state_store),
+            ..self
+        }
+    }
 }
 
 impl Executor2 for InsertExecutor2 {
@@ -76,12 +107,18 @@ impl InsertExecutor2 {
         let source = source_desc.source.as_table_v2().expect("not table source");
 
         let mut notifiers = Vec::new();
+        // The inserted rows, only kept to be checked against the unique indexes.
+        let mut inserted_rows = Vec::new();
 
         #[for_await]
         for data_chunk in self.child.execute() {
             let data_chunk = data_chunk?;
             let len = data_chunk.cardinality();
             assert!(data_chunk.visibility().is_none());
+            // The row id column is not in the chunk yet.
+            check_pk_not_null(&data_chunk, &self.pk_indices, |idx| {
+                source_desc.columns[idx + 1].name.clone()
+            })?;
 
             // add row-id column as first column
             let mut builder = I64ArrayBuilder::new(len).unwrap();
@@ -96,6 +133,9 @@ impl InsertExecutor2 {
             // id column to the first.
             let columns = rowid_column.chain(child_columns).collect();
             let chunk = StreamChunk::new(vec![Op::Insert; len], columns, None);
+            if !self.unique_indexes.is_empty() {
+                inserted_rows.extend(chunk_rows(&chunk));
+            }
 
             let notifier = source.write_chunk(chunk)?;
             notifiers.push(notifier);
@@ -112,6 +152,19 @@ impl InsertExecutor2 {
             .into_iter()
             .sum::<usize>();
 
+        if let Some(state_store) = &self.state_store
+            && let Err(err) =
+                check_unique_indexes(state_store, &self.unique_indexes, &inserted_rows).await
+        {
+            let data_types = source_desc
+                .columns
+                .iter()
+                .map(|column| column.data_type.clone())
+                .collect_vec();
+            revert_rows(source, inserted_rows, &data_types).await?;
+            return Err(err);
+        }
+
         // create ret value
         {
             let mut array_builder = PrimitiveArrayBuilder::<i64>::new(1)?;
@@ -143,12 +196,42 @@ impl BoxedExecutor2Builder for InsertExecutor2 {
         })?;
         let child = source.clone_for_plan(proto_child).build2()?;
 
-        Ok(Box::new(Self::new(
-            table_id,
-            source.global_batch_env().source_manager_ref(),
-            child,
-        )))
+        let unique_indexes = insert_node
+            .unique_indexes
+            .iter()
+            .map(UniqueIndex::from_prost)
+            .try_collect()?;
+
+        Ok(Box::new(
+            Self::new(
+                table_id,
+                source.global_batch_env().source_manager_ref(),
+                child,
+                insert_node
+                    .pk_indices
+                    .iter()
+                    .map(|idx| *idx as usize)
+                    .collect(),
+            )
+            .with_unique_indexes(unique_indexes, source.global_batch_env().state_store()),
+        ))
+    }
+}
+
+/// Returns an error if a column of the user-defined primary key contains NULL. `column_name` gives
+/// the name of a column by its index in `chunk`.
+pub(super) fn check_pk_not_null(
+    chunk: &DataChunk,
+    pk_indices: &[usize],
+    column_name: impl Fn(usize) -> String,
+) -> Result<()> {
+    for &idx in pk_indices {
+        let array = chunk.column_at(idx).array_ref();
+        if array.null_bitmap().num_high_bits() != array.len() {
+            return Err(ErrorCode::NotNullViolation(column_name(idx)).into());
+        }
     }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -197,6 +280,7 @@ mod tests {
                 name: f.name.clone(),
                 field_descs: vec![],
                 type_name: "".to_string(),
+                default_value: None,
             })
             .collect();
 
@@ -233,6 +317,7 @@ mod tests {
             table_id,
             source_manager.clone(),
             Box::new(mock_executor),
+            vec![],
         ));
         let handle = tokio::spawn(async move {
             let fields = &insert_executor.schema().fields;
@@ -304,4 +389,37 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_insert_null_pk() -> Result<()> {
+        let source_manager = Arc::new(MemSourceManager::default());
+        let mut mock_executor = MockExecutor::new(schema_test_utils::ii());
+
+        let table_columns = vec![
+            ColumnDesc::new_atomic(DataType::Int64, "_row_id", 0),
+            ColumnDesc::new_atomic(DataType::Int64, "k", 1),
+            ColumnDesc::new_atomic(DataType::Int64, "v", 2),
+        ];
+        let table_id = TableId::new(0);
+        source_manager.create_table_source(&table_id, table_columns)?;
+
+        let col1 = Column::new(Arc::new(array! { I64Array, [Some(1), None] }.into()));
+        let col2 = column_nonnull! { I64Array, [2, 4] };
+        mock_executor.add(DataChunk::builder().columns(vec![col1, col2]).build());
+
+        let insert_executor = Box::new(InsertExecutor2::new(
+            table_id,
+            source_manager.clone(),
+            Box::new(mock_executor),
+            vec![0],
+        ));
+        let mut stream = insert_executor.execute();
+        let err = stream.next().await.unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "null value in column \"k\" violates not-null constraint"
+        );
+
+        Ok(())
+    }
 }
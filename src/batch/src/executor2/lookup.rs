@@ -0,0 +1,174 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures_async_stream::try_stream;
+use itertools::Itertools;
+use risingwave_common::array::{DataChunk, Row};
+use risingwave_common::catalog::{ColumnDesc, OrderedColumnDesc, Schema, TableId};
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::DataType;
+use risingwave_common::util::ordered::OrderedRowSerializer;
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+use risingwave_storage::table::cell_based_table::CellBasedTable;
+use risingwave_storage::{dispatch_state_store, Keyspace, StateStore, StateStoreImpl};
+
+use crate::executor::ExecutorBuilder;
+use crate::executor2::{BoxedDataChunkStream, BoxedExecutor2, BoxedExecutor2Builder, Executor2};
+
+/// `LookupExecutor2` gets the row of a table with the primary key of each input row, e.g. to fetch
+/// the columns not in an index for the rows found by scanning the index.
+pub struct LookupExecutor2<S: StateStore> {
+    child: BoxedExecutor2,
+    table: CellBasedTable<S>,
+    /// The indices of the input columns of the primary key of `table`.
+    lookup_key_indices: Vec<usize>,
+    chunk_size: usize,
+    schema: Schema,
+    identity: String,
+    epoch: u64,
+}
+
+impl<S: StateStore> LookupExecutor2<S> {
+    pub fn new(
+        child: BoxedExecutor2,
+        table: CellBasedTable<S>,
+        lookup_key_indices: Vec<usize>,
+        chunk_size: usize,
+        identity: String,
+        epoch: u64,
+    ) -> Self {
+        let schema = table.schema().clone();
+
+        Self {
+            child,
+            table,
+            lookup_key_indices,
+            chunk_size,
+            schema,
+            identity,
+            epoch,
+        }
+    }
+}
+
+pub struct LookupExecutor2Builder {}
+
+impl LookupExecutor2Builder {
+    pub const DEFAULT_CHUNK_SIZE: usize = 1024;
+}
+
+impl BoxedExecutor2Builder for LookupExecutor2Builder {
+    fn new_boxed_executor2(source: &ExecutorBuilder) -> Result<BoxedExecutor2> {
+        ensure!(source.plan_node().get_children().len() == 1);
+
+        let lookup_node = try_match_expand!(
+            source.plan_node().get_node_body().unwrap(),
+            NodeBody::Lookup
+        )?;
+        let child = source
+            .clone_for_plan(&source.plan_node().get_children()[0])
+            .build2()?;
+
+        let table_desc = lookup_node.get_table_desc()?;
+        let table_id = TableId::new(table_desc.table_id);
+        let column_descs = lookup_node
+            .column_descs
+            .iter()
+            .map(|column_desc| ColumnDesc::from(column_desc.clone()))
+            .collect_vec();
+        let pk_serializer = OrderedRowSerializer::new(
+            table_desc
+                .pk
+                .iter()
+                .map(|pk_desc| OrderedColumnDesc::from(pk_desc.clone()).order)
+                .collect(),
+        );
+        let lookup_key_indices = lookup_node
+            .lookup_key_indices
+            .iter()
+            .map(|idx| *idx as usize)
+            .collect();
+        dispatch_state_store!(source.global_batch_env().state_store(), state_store, {
+            let keyspace = Keyspace::table_root(state_store.clone(), &table_id);
+            let storage_stats = state_store.stats();
+            let table =
+                CellBasedTable::new(keyspace, column_descs, Some(pk_serializer), storage_stats);
+            Ok(Box::new(LookupExecutor2::new(
+                child,
+                table,
+                lookup_key_indices,
+                LookupExecutor2Builder::DEFAULT_CHUNK_SIZE,
+                source.plan_node().get_identity().clone(),
+                source.epoch(),
+            )))
+        })
+    }
+}
+
+impl<S: StateStore> Executor2 for LookupExecutor2<S> {
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+
+    fn execute(self: Box<Self>) -> BoxedDataChunkStream {
+        self.do_execute()
+    }
+}
+
+impl<S: StateStore> LookupExecutor2<S> {
+    #[try_stream(boxed, ok = DataChunk, error = RwError)]
+    async fn do_execute(self: Box<Self>) {
+        self.table
+            .wait_epoch(self.epoch)
+            .await
+            .map_err(RwError::from)?;
+
+        let data_types = self.schema.data_types();
+        let mut rows = vec![];
+        #[for_await]
+        for data_chunk in self.child.execute() {
+            let data_chunk = data_chunk?;
+            for input_row in data_chunk.rows() {
+                let pk = input_row.row_by_indices(&self.lookup_key_indices);
+                let row = self
+                    .table
+                    .get_row(&pk, self.epoch)
+                    .await
+                    .map_err(RwError::from)?;
+                rows.extend(row);
+                if rows.len() >= self.chunk_size {
+                    yield rows_to_chunk(std::mem::take(&mut rows), &data_types)?;
+                }
+            }
+        }
+
+        if !rows.is_empty() {
+            yield rows_to_chunk(rows, &data_types)?;
+        }
+    }
+}
+
+fn rows_to_chunk(rows: Vec<Row>, data_types: &[DataType]) -> Result<DataChunk> {
+    if data_types.is_empty() {
+        // Generate some dummy data to ensure a correct cardinality, which might be used by
+        // count(*).
+        Ok(DataChunk::new_dummy(rows.len()))
+    } else {
+        DataChunk::from_rows(&rows, data_types)
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod delete;
+mod expand;
 mod filter;
 mod generate_series;
 mod generic_exchange;
@@ -21,17 +22,24 @@ mod hop_window;
 mod insert;
 mod join;
 mod limit;
+mod lookup;
 mod merge_sort_exchange;
 pub mod monitor;
 mod order_by;
 mod project;
+mod project_set;
 mod row_seq_scan;
 mod sort_agg;
+mod sys_row_seq_scan;
+mod table_function;
 mod top_n;
 mod trace;
+mod unique_index;
+mod update;
 mod values;
 
 pub use delete::*;
+pub use expand::*;
 pub use filter::*;
 use futures::stream::BoxStream;
 pub use generate_series::*;
@@ -41,17 +49,22 @@ pub use hop_window::*;
 pub use insert::*;
 pub use join::*;
 pub use limit::*;
+pub use lookup::*;
 pub use merge_sort_exchange::*;
 pub use monitor::*;
 pub use order_by::*;
 pub use project::*;
+pub use project_set::*;
 use risingwave_common::array::DataChunk;
 use risingwave_common::catalog::Schema;
 use risingwave_common::error::Result;
 pub use row_seq_scan::*;
 pub use sort_agg::*;
+pub use sys_row_seq_scan::*;
+pub use table_function::*;
 pub use top_n::*;
 pub use trace::*;
+pub use update::*;
 pub use values::*;
 
 use crate::executor::ExecutorBuilder;
@@ -14,3 +14,5 @@
 //
 pub mod stats;
 pub use stats::*;
+pub mod profile;
+pub use profile::*;
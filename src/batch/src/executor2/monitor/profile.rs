@@ -0,0 +1,186 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+use futures::StreamExt;
+use futures_async_stream::try_stream;
+use parking_lot::Mutex;
+use risingwave_common::array::DataChunk;
+use risingwave_common::catalog::Schema;
+use risingwave_common::error::RwError;
+use risingwave_pb::task_service::ExecutorProfile as ProstExecutorProfile;
+
+use crate::executor2::{BoxedDataChunkStream, BoxedExecutor2, Executor2};
+
+/// Runtime statistics of a single executor.
+#[derive(Debug, Default)]
+pub struct ExecutorProfile {
+    /// Identity of the plan node the executor is built from.
+    identity: String,
+    output_rows: AtomicU64,
+    output_chunks: AtomicU64,
+    /// Time spent in the executor including its inputs, in nanoseconds.
+    elapsed_ns: AtomicU64,
+}
+
+pub type ExecutorProfileRef = Arc<ExecutorProfile>;
+
+impl ExecutorProfile {
+    pub fn output_rows(&self) -> u64 {
+        self.output_rows.load(Ordering::Relaxed)
+    }
+
+    pub fn output_chunks(&self) -> u64 {
+        self.output_chunks.load(Ordering::Relaxed)
+    }
+
+    pub fn elapsed_ns(&self) -> u64 {
+        self.elapsed_ns.load(Ordering::Relaxed)
+    }
+
+    pub fn to_prost(&self) -> ProstExecutorProfile {
+        ProstExecutorProfile {
+            identity: self.identity.clone(),
+            output_rows: self.output_rows(),
+            output_chunks: self.output_chunks(),
+            elapsed_ns: self.elapsed_ns(),
+        }
+    }
+}
+
+/// Collects the [`ExecutorProfile`]s of all executors of a task, so that they can be reported
+/// back to the frontend for `EXPLAIN ANALYZE`.
+#[derive(Clone, Debug, Default)]
+pub struct TaskProfiler {
+    profiles: Arc<Mutex<Vec<ExecutorProfileRef>>>,
+}
+
+impl TaskProfiler {
+    /// Registers a new executor with the given plan node identity.
+    pub fn register(&self, identity: String) -> ExecutorProfileRef {
+        let profile = Arc::new(ExecutorProfile {
+            identity,
+            ..Default::default()
+        });
+        self.profiles.lock().push(profile.clone());
+        profile
+    }
+
+    pub fn to_prost(&self) -> Vec<ProstExecutorProfile> {
+        self.profiles
+            .lock()
+            .iter()
+            .map(|profile| profile.to_prost())
+            .collect()
+    }
+}
+
+/// [`ProfileExecutor2`] is built on top of every executor to record the number of rows and chunks
+/// it outputs and the time spent in polling it.
+pub struct ProfileExecutor2 {
+    child: BoxedExecutor2,
+    profile: ExecutorProfileRef,
+}
+
+impl ProfileExecutor2 {
+    pub fn new(child: BoxedExecutor2, profile: ExecutorProfileRef) -> Self {
+        Self { child, profile }
+    }
+}
+
+impl Executor2 for ProfileExecutor2 {
+    fn schema(&self) -> &Schema {
+        self.child.schema()
+    }
+
+    fn identity(&self) -> &str {
+        "ProfileExecutor"
+    }
+
+    fn execute(self: Box<Self>) -> BoxedDataChunkStream {
+        self.do_execute()
+    }
+}
+
+impl ProfileExecutor2 {
+    #[try_stream(boxed, ok = DataChunk, error = RwError)]
+    async fn do_execute(self: Box<Self>) {
+        let profile = self.profile;
+        let mut child_stream = self.child.execute();
+        loop {
+            let start = Instant::now();
+            let chunk = child_stream.next().await;
+            profile
+                .elapsed_ns
+                .fetch_add(start.elapsed().as_nanos() as u64, Ordering::Relaxed);
+            match chunk {
+                Some(chunk) => {
+                    let chunk = chunk?;
+                    profile
+                        .output_rows
+                        .fetch_add(chunk.cardinality() as u64, Ordering::Relaxed);
+                    profile.output_chunks.fetch_add(1, Ordering::Relaxed);
+                    yield chunk;
+                }
+                None => break,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::Field;
+    use risingwave_common::types::DataType;
+
+    use super::*;
+    use crate::executor::test_utils::MockExecutor;
+
+    #[tokio::test]
+    async fn test_profile_executor() {
+        let schema = Schema {
+            fields: vec![Field::unnamed(DataType::Int32)],
+        };
+        let mut mock_executor = MockExecutor::new(schema);
+        mock_executor.add(DataChunk::from_pretty(
+            "i
+             1
+             2
+             3",
+        ));
+        mock_executor.add(DataChunk::from_pretty(
+            "i
+             4",
+        ));
+
+        let profiler = TaskProfiler::default();
+        let profile = profiler.register("BatchValues-1".to_string());
+        let executor = Box::new(ProfileExecutor2::new(Box::new(mock_executor), profile));
+        let mut stream = executor.execute();
+        let mut rows = 0;
+        while let Some(chunk) = stream.next().await {
+            rows += chunk.unwrap().cardinality();
+        }
+        assert_eq!(rows, 4);
+
+        let profiles = profiler.to_prost();
+        assert_eq!(profiles.len(), 1);
+        assert_eq!(profiles[0].identity, "BatchValues-1");
+        assert_eq!(profiles[0].output_rows, 4);
+        assert_eq!(profiles[0].output_chunks, 2);
+    }
+}
@@ -246,7 +246,10 @@ impl OrderByExecutor2 {
                             Interval,
                             NaiveDate,
                             NaiveTime,
-                            NaiveDateTime
+                            NaiveDateTime,
+                            Jsonb,
+                            Bytea,
+                            Uuid
                         ]
                     );
                 }
@@ -0,0 +1,251 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use futures_async_stream::try_stream;
+use itertools::Itertools;
+use risingwave_common::array::column::Column;
+use risingwave_common::array::{ArrayBuilderImpl, ArrayRef, DataChunk};
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::DataType;
+use risingwave_common::util::chunk_coalesce::DEFAULT_CHUNK_BUFFER_SIZE;
+use risingwave_expr::table_function::ProjectSetSelectItem;
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+
+use crate::executor::ExecutorBuilder;
+use crate::executor2::{BoxedDataChunkStream, BoxedExecutor2, BoxedExecutor2Builder, Executor2};
+
+/// `ProjectSetExecutor2` evaluates the select list, which contains table functions, on each input
+/// row. Each row is expanded into as many rows as the longest output of the table functions, where
+/// the shorter outputs are padded with NULLs and the other expressions are repeated. The first
+/// output column is `projected_row_id`, the index of the row in the expansion.
+pub struct ProjectSetExecutor2 {
+    select_list: Vec<ProjectSetSelectItem>,
+    child: BoxedExecutor2,
+    schema: Schema,
+    identity: String,
+}
+
+impl ProjectSetExecutor2 {
+    pub fn new(
+        select_list: Vec<ProjectSetSelectItem>,
+        child: BoxedExecutor2,
+        identity: String,
+    ) -> Self {
+        let fields = std::iter::once(Field::with_name(DataType::Int64, "projected_row_id"))
+            .chain(
+                select_list
+                    .iter()
+                    .map(|item| Field::unnamed(item.return_type())),
+            )
+            .collect();
+        Self {
+            select_list,
+            child,
+            schema: Schema { fields },
+            identity,
+        }
+    }
+}
+
+/// The values of a select item on the input rows, which is an array per row for table functions.
+enum SelectItemOutput {
+    TableFunction(Vec<ArrayRef>),
+    Expr(ArrayRef),
+}
+
+impl Executor2 for ProjectSetExecutor2 {
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+
+    fn execute(self: Box<Self>) -> BoxedDataChunkStream {
+        self.do_execute()
+    }
+}
+
+impl ProjectSetExecutor2 {
+    #[try_stream(boxed, ok = DataChunk, error = RwError)]
+    async fn do_execute(self: Box<Self>) {
+        let Self {
+            select_list,
+            child,
+            schema,
+            ..
+        } = *self;
+        let data_types = schema.data_types();
+        let new_builders = || -> Result<Vec<ArrayBuilderImpl>> {
+            data_types
+                .iter()
+                .map(|ty| ty.create_array_builder(DEFAULT_CHUNK_BUFFER_SIZE))
+                .try_collect()
+        };
+        let finish = |builders: Vec<ArrayBuilderImpl>| -> Result<DataChunk> {
+            let columns = builders
+                .into_iter()
+                .map(|builder| Ok(Column::new(Arc::new(builder.finish()?))))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(DataChunk::new(columns, None))
+        };
+
+        let mut builders = new_builders()?;
+        let mut rows = 0;
+
+        #[for_await]
+        for data_chunk in child.execute() {
+            let data_chunk = data_chunk?.compact()?;
+            let outputs: Vec<SelectItemOutput> = select_list
+                .iter()
+                .map(|item| match item {
+                    ProjectSetSelectItem::TableFunction(tf) => {
+                        tf.eval(&data_chunk).map(SelectItemOutput::TableFunction)
+                    }
+                    ProjectSetSelectItem::Expr(expr) => {
+                        expr.eval(&data_chunk).map(SelectItemOutput::Expr)
+                    }
+                })
+                .try_collect()?;
+
+            for row_idx in 0..data_chunk.cardinality() {
+                let len = outputs
+                    .iter()
+                    .map(|output| match output {
+                        SelectItemOutput::TableFunction(arrays) => arrays[row_idx].len(),
+                        SelectItemOutput::Expr(_) => 0,
+                    })
+                    .max()
+                    .unwrap_or(0);
+                for projected_row_id in 0..len {
+                    builders[0].append_datum(&Some((projected_row_id as i64).into()))?;
+                    for (output, builder) in outputs.iter().zip_eq(builders[1..].iter_mut()) {
+                        match output {
+                            SelectItemOutput::TableFunction(arrays) => {
+                                let array = &arrays[row_idx];
+                                if projected_row_id < array.len() {
+                                    builder.append_datum_ref(array.value_at(projected_row_id))?;
+                                } else {
+                                    builder.append_null()?;
+                                }
+                            }
+                            SelectItemOutput::Expr(array) => {
+                                builder.append_datum_ref(array.value_at(row_idx))?;
+                            }
+                        }
+                    }
+                    rows += 1;
+                    if rows == DEFAULT_CHUNK_BUFFER_SIZE {
+                        yield finish(std::mem::replace(&mut builders, new_builders()?))?;
+                        rows = 0;
+                    }
+                }
+            }
+        }
+        if rows > 0 {
+            yield finish(builders)?;
+        }
+    }
+}
+
+impl BoxedExecutor2Builder for ProjectSetExecutor2 {
+    fn new_boxed_executor2(source: &ExecutorBuilder) -> Result<BoxedExecutor2> {
+        ensure!(source.plan_node().get_children().len() == 1);
+        let project_set_node = try_match_expand!(
+            source.plan_node().get_node_body().unwrap(),
+            NodeBody::ProjectSet
+        )?;
+        let select_list = project_set_node
+            .get_select_list()
+            .iter()
+            .map(ProjectSetSelectItem::from_prost)
+            .try_collect()?;
+
+        let child_node = &source.plan_node().get_children()[0];
+        let child = source.clone_for_plan(child_node).build2()?;
+
+        Ok(Box::new(Self::new(
+            select_list,
+            child,
+            source.plan_node().get_identity().clone(),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use risingwave_common::array::DataChunkTestExt;
+    use risingwave_expr::expr::{BoxedExpression, InputRefExpression};
+    use risingwave_expr::table_function::GenerateSeries;
+
+    use super::*;
+    use crate::executor::test_utils::MockExecutor;
+
+    #[tokio::test]
+    async fn test_project_set_executor() {
+        let mut mock_executor = MockExecutor::new(Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int32),
+                Field::unnamed(DataType::Int32),
+            ],
+        });
+        mock_executor.add(DataChunk::from_pretty(
+            "i i
+             1 2
+             2 0
+             3 1",
+        ));
+        let input_ref = |idx: usize| -> BoxedExpression {
+            Box::new(InputRefExpression::new(DataType::Int32, idx))
+        };
+        let series = |stop: usize| {
+            ProjectSetSelectItem::TableFunction(Box::new(GenerateSeries::new(
+                input_ref(0),
+                input_ref(stop),
+                input_ref(0),
+                DataType::Int32,
+            )))
+        };
+        // SELECT generate_series(v1, v2, v1), v1, generate_series(v1, v1, v1)
+        let executor = Box::new(ProjectSetExecutor2::new(
+            vec![
+                series(1),
+                ProjectSetSelectItem::Expr(input_ref(0)),
+                series(0),
+            ],
+            Box::new(mock_executor),
+            "ProjectSetExecutor2".to_string(),
+        ));
+        assert_eq!(executor.schema().len(), 4);
+
+        let mut stream = executor.execute();
+        let res = stream.next().await.unwrap().unwrap();
+        assert_eq!(
+            res,
+            DataChunk::from_pretty(
+                "I i i i
+                 0 1 1 1
+                 1 2 1 .
+                 0 . 2 2
+                 0 . 3 3"
+            )
+        );
+        assert!(stream.next().await.is_none());
+    }
+}
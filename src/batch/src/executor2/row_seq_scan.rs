@@ -1,3 +1,4 @@
+use std::ops::Bound::{self, *};
 use std::sync::Arc;
 
 use futures_async_stream::try_stream;
@@ -15,9 +16,12 @@ use futures_async_stream::try_stream;
 // See the License for the specific language governing permissions and
 // limitations under the License.
 use itertools::Itertools;
-use risingwave_common::array::DataChunk;
-use risingwave_common::catalog::{ColumnDesc, Schema, TableId};
+use risingwave_common::array::{DataChunk, Row};
+use risingwave_common::catalog::{ColumnDesc, OrderedColumnDesc, Schema, TableId};
 use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::ScalarImpl;
+use risingwave_common::util::ordered::OrderedRowSerializer;
+use risingwave_common::util::scan_range::ScanRange;
 use risingwave_pb::batch_plan::plan_node::NodeBody;
 use risingwave_storage::table::cell_based_table::CellBasedTable;
 use risingwave_storage::{dispatch_state_store, Keyspace, StateStore, StateStoreImpl};
@@ -29,6 +33,8 @@ use crate::executor2::{BoxedDataChunkStream, BoxedExecutor2, BoxedExecutor2Build
 /// Executor that scans data from row table
 pub struct RowSeqScanExecutor2<S: StateStore> {
     table: CellBasedTable<S>,
+    /// The ranges of the pk to scan. The whole table is scanned if empty.
+    scan_ranges: Vec<ScanRange>,
     primary: bool,
     chunk_size: usize,
     schema: Schema,
@@ -40,6 +46,7 @@ pub struct RowSeqScanExecutor2<S: StateStore> {
 impl<S: StateStore> RowSeqScanExecutor2<S> {
     pub fn new(
         table: CellBasedTable<S>,
+        scan_ranges: Vec<ScanRange>,
         chunk_size: usize,
         primary: bool,
         identity: String,
@@ -50,6 +57,7 @@ impl<S: StateStore> RowSeqScanExecutor2<S> {
 
         Self {
             table,
+            scan_ranges,
             primary,
             chunk_size,
             schema,
@@ -89,13 +97,37 @@ impl BoxedExecutor2Builder for RowSeqScanExecutor2Builder {
             .iter()
             .map(|column_desc| ColumnDesc::from(column_desc.clone()))
             .collect_vec();
+        let pk_descs = seq_scan_node
+            .table_desc
+            .as_ref()
+            .unwrap()
+            .pk
+            .iter()
+            .map(|pk_desc| OrderedColumnDesc::from(pk_desc.clone()))
+            .collect_vec();
+        let pk_types = pk_descs
+            .iter()
+            .map(|pk_desc| pk_desc.column_desc.data_type.clone())
+            .collect_vec();
+        let scan_ranges: Vec<_> = seq_scan_node
+            .scan_ranges
+            .iter()
+            .map(|scan_range| ScanRange::from_protobuf(scan_range, &pk_types))
+            .try_collect()?;
+        let pk_serializer =
+            OrderedRowSerializer::new(pk_descs.iter().map(|pk_desc| pk_desc.order).collect());
         dispatch_state_store!(source.global_batch_env().state_store(), state_store, {
             let keyspace = Keyspace::table_root(state_store.clone(), &table_id);
             let storage_stats = state_store.stats();
             let batch_stats = source.global_batch_env().stats();
-            let table = CellBasedTable::new_adhoc(keyspace, column_descs, storage_stats);
+            let table = if pk_descs.is_empty() {
+                CellBasedTable::new_adhoc(keyspace, column_descs, storage_stats)
+            } else {
+                CellBasedTable::new(keyspace, column_descs, Some(pk_serializer), storage_stats)
+            };
             Ok(Box::new(RowSeqScanExecutor2::new(
                 table,
+                scan_ranges,
                 RowSeqScanExecutor2Builder::DEFAULT_CHUNK_SIZE,
                 source.task_id.task_id == 0,
                 source.plan_node().get_identity().clone(),
@@ -123,8 +155,56 @@ impl<S: StateStore> Executor2 for RowSeqScanExecutor2<S> {
 impl<S: StateStore> RowSeqScanExecutor2<S> {
     #[try_stream(boxed, ok = DataChunk, error = RwError)]
     async fn do_execute(self: Box<Self>) {
-        if !self.should_ignore() {
-            let mut iter = self.table.iter(self.epoch).await.map_err(RwError::from)?;
+        if self.should_ignore() {
+            return Ok(());
+        }
+
+        let scan_ranges = if self.scan_ranges.is_empty() {
+            vec![ScanRange::full_table_scan()]
+        } else {
+            self.scan_ranges.clone()
+        };
+        let pk_len = self.table.pk_len();
+        self.table
+            .wait_epoch(self.epoch)
+            .await
+            .map_err(RwError::from)?;
+
+        // Rows got by point gets are buffered to be yielded in chunks.
+        let mut point_get_rows = vec![];
+        for scan_range in scan_ranges {
+            if let Some(pk_len) = pk_len && scan_range.is_point_get(pk_len) {
+                let pk = Row(scan_range.eq_conds);
+                let row = self
+                    .table
+                    .get_row(&pk, self.epoch)
+                    .await
+                    .map_err(RwError::from)?;
+                point_get_rows.extend(row);
+                if point_get_rows.len() >= self.chunk_size {
+                    yield self.rows_to_chunk(std::mem::take(&mut point_get_rows))?;
+                }
+                continue;
+            }
+
+            let mut iter = if scan_range.is_full_table_scan() {
+                self.table.iter(self.epoch).await
+            } else {
+                let to_datum_bound = |bound: Bound<ScalarImpl>| match bound {
+                    Included(value) => Included(Some(value)),
+                    Excluded(value) => Excluded(Some(value)),
+                    Unbounded => Unbounded,
+                };
+                let (start, end) = scan_range.range;
+                self.table
+                    .iter_with_pk_bounds(
+                        self.epoch,
+                        &Row(scan_range.eq_conds),
+                        (to_datum_bound(start), to_datum_bound(end)),
+                    )
+                    .await
+            }
+            .map_err(RwError::from)?;
 
             loop {
                 let timer = self.stats.row_seq_scan_next_duration.start_timer();
@@ -142,5 +222,19 @@ impl<S: StateStore> RowSeqScanExecutor2<S> {
                 }
             }
         }
+
+        if !point_get_rows.is_empty() {
+            yield self.rows_to_chunk(point_get_rows)?;
+        }
+    }
+
+    fn rows_to_chunk(&self, rows: Vec<Row>) -> Result<DataChunk> {
+        if self.schema.is_empty() {
+            // Generate some dummy data to ensure a correct cardinality, which might be used by
+            // count(*).
+            Ok(DataChunk::new_dummy(rows.len()))
+        } else {
+            DataChunk::from_rows(&rows, &self.schema.data_types())
+        }
     }
 }
@@ -0,0 +1,98 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures_async_stream::try_stream;
+use risingwave_common::array::DataChunk;
+use risingwave_common::catalog::{ColumnDesc, Field, Schema};
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+
+use crate::executor::ExecutorBuilder;
+use crate::executor2::{BoxedDataChunkStream, BoxedExecutor2, BoxedExecutor2Builder, Executor2};
+
+/// `SysRowSeqScanExecutor2` scans a system table. Its rows are generated from the catalog by the
+/// frontend and carried in the plan.
+pub struct SysRowSeqScanExecutor2 {
+    chunk: DataChunk,
+    schema: Schema,
+    identity: String,
+}
+
+impl SysRowSeqScanExecutor2 {
+    pub fn new(chunk: DataChunk, schema: Schema, identity: String) -> Self {
+        Self {
+            chunk,
+            schema,
+            identity,
+        }
+    }
+}
+
+impl BoxedExecutor2Builder for SysRowSeqScanExecutor2 {
+    fn new_boxed_executor2(source: &ExecutorBuilder) -> Result<BoxedExecutor2> {
+        let sys_scan_node = try_match_expand!(
+            source.plan_node().get_node_body().unwrap(),
+            NodeBody::SysRowSeqScan
+        )?;
+
+        let columns = &sys_scan_node.get_table()?.columns;
+        let fields = sys_scan_node
+            .column_ids
+            .iter()
+            .map(|column_id| {
+                columns
+                    .iter()
+                    .map(|column| ColumnDesc::from(column.get_column_desc().unwrap()))
+                    .find(|column_desc| column_desc.column_id.get_id() == *column_id)
+                    .map(|column_desc| Field::from(&column_desc))
+                    .ok_or_else(|| {
+                        RwError::from(ErrorCode::InternalError(format!(
+                            "column {} not found in system table",
+                            column_id
+                        )))
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let chunk = DataChunk::from_protobuf(sys_scan_node.get_chunk()?)?;
+
+        Ok(Box::new(Self::new(
+            chunk,
+            Schema { fields },
+            source.plan_node().get_identity().clone(),
+        )))
+    }
+}
+
+impl Executor2 for SysRowSeqScanExecutor2 {
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+
+    fn execute(self: Box<Self>) -> BoxedDataChunkStream {
+        self.do_execute()
+    }
+}
+
+impl SysRowSeqScanExecutor2 {
+    #[try_stream(boxed, ok = DataChunk, error = RwError)]
+    async fn do_execute(self: Box<Self>) {
+        if self.chunk.cardinality() > 0 {
+            yield self.chunk;
+        }
+    }
+}
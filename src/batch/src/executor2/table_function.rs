@@ -0,0 +1,91 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures_async_stream::try_stream;
+use risingwave_common::array::column::Column;
+use risingwave_common::array::DataChunk;
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::{Result, RwError};
+use risingwave_expr::table_function::{build_table_function_from_prost, BoxedTableFunction};
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+
+use super::{BoxedExecutor2, BoxedExecutor2Builder};
+use crate::executor::ExecutorBuilder;
+use crate::executor2::{BoxedDataChunkStream, Executor2};
+
+/// Evaluates a table function whose arguments are all constants, e.g. `SELECT * FROM
+/// jsonb_array_elements('[1, 2]')`.
+pub struct TableFunctionExecutor2 {
+    schema: Schema,
+    identity: String,
+    table_function: BoxedTableFunction,
+}
+
+impl TableFunctionExecutor2 {
+    pub fn new(table_function: BoxedTableFunction, identity: String) -> Self {
+        Self {
+            schema: Schema::new(vec![Field::unnamed(table_function.return_type())]),
+            identity,
+            table_function,
+        }
+    }
+}
+
+impl Executor2 for TableFunctionExecutor2 {
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+
+    fn execute(self: Box<Self>) -> BoxedDataChunkStream {
+        self.do_execute()
+    }
+}
+
+impl TableFunctionExecutor2 {
+    #[try_stream(boxed, ok = DataChunk, error = RwError)]
+    async fn do_execute(self: Box<Self>) {
+        let dummy_chunk = DataChunk::new_dummy(1);
+        for array in self.table_function.eval(&dummy_chunk)? {
+            if array.len() == 0 {
+                continue;
+            }
+            yield DataChunk::builder()
+                .columns(vec![Column::new(array)])
+                .build();
+        }
+    }
+}
+
+pub struct TableFunctionExecutor2Builder {}
+
+impl BoxedExecutor2Builder for TableFunctionExecutor2Builder {
+    fn new_boxed_executor2(source: &ExecutorBuilder) -> Result<BoxedExecutor2> {
+        let node = try_match_expand!(
+            source.plan_node().get_node_body().unwrap(),
+            NodeBody::TableFunction
+        )?;
+
+        let identity = source.plan_node().get_identity().clone();
+        let table_function = build_table_function_from_prost(node.get_table_function()?)?;
+
+        Ok(Box::new(TableFunctionExecutor2::new(
+            table_function,
+            identity,
+        )))
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Enforcement of unique indexes on the write path.
+//!
+//! The materialized view of a unique index keeps the first row written with each non-null key and
+//! drops the later ones. After `INSERT` or `UPDATE` has written its rows, it waits until they are
+//! committed and looks them up in the unique indexes on the table. If any of them has been
+//! dropped, the statement writes the changes reverting its rows and fails.
+
+use risingwave_common::array::{Op, Row, StreamChunk};
+use risingwave_common::catalog::{OrderedColumnDesc, TableId};
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::types::DataType;
+use risingwave_common::util::epoch::Epoch;
+use risingwave_common::util::ordered::OrderedRowSerializer;
+use risingwave_pb::batch_plan::UniqueIndex as ProstUniqueIndex;
+use risingwave_source::TableSourceV2;
+use risingwave_storage::table::cell_based_table::CellBasedTable;
+use risingwave_storage::{dispatch_state_store, Keyspace, StateStore, StateStoreImpl};
+
+/// A unique index on the table written by a DML executor.
+pub(super) struct UniqueIndex {
+    name: String,
+    table_id: TableId,
+    /// The order key of the index, which is the key of the index followed by the pk of the table.
+    pk_descs: Vec<OrderedColumnDesc>,
+    /// Indices of the columns of `pk_descs` in the rows of the table.
+    pk_indices: Vec<usize>,
+    /// Number of the leading columns of `pk_descs` which are the key of the index.
+    key_len: usize,
+}
+
+impl UniqueIndex {
+    pub fn from_prost(index: &ProstUniqueIndex) -> Result<Self> {
+        let table_desc = index.get_table_desc()?;
+        Ok(Self {
+            name: index.name.clone(),
+            table_id: TableId::new(table_desc.table_id),
+            pk_descs: table_desc
+                .pk
+                .iter()
+                .map(|pk_desc| OrderedColumnDesc::from(pk_desc.clone()))
+                .collect(),
+            pk_indices: index.pk_indices.iter().map(|idx| *idx as usize).collect(),
+            key_len: index.key_len as usize,
+        })
+    }
+
+    fn table<S: StateStore>(&self, store: S) -> CellBasedTable<S> {
+        let column_descs = self
+            .pk_descs
+            .iter()
+            .map(|pk_desc| pk_desc.column_desc.clone())
+            .collect();
+        let pk_serializer =
+            OrderedRowSerializer::new(self.pk_descs.iter().map(|pk_desc| pk_desc.order).collect());
+        let stats = store.stats();
+        let keyspace = Keyspace::table_root(store, &self.table_id);
+        CellBasedTable::new(keyspace, column_descs, Some(pk_serializer), stats)
+    }
+
+    /// Returns whether `row` of the table has been dropped by the index as of `epoch`.
+    async fn is_dropped<S: StateStore>(
+        &self,
+        table: &CellBasedTable<S>,
+        row: &Row,
+        epoch: u64,
+    ) -> Result<bool> {
+        let pk = Row(self
+            .pk_indices
+            .iter()
+            .map(|idx| row.0[*idx].clone())
+            .collect());
+        // NULLs are not equal to each other, so a row with NULL in its key never conflicts.
+        if pk.0[..self.key_len].iter().any(Option::is_none) {
+            return Ok(false);
+        }
+        let index_row = table.get_row(&pk, epoch).await.map_err(RwError::from)?;
+        Ok(index_row.is_none())
+    }
+}
+
+/// Checks the `rows` written to a table against the unique `indexes` on it, once they have been
+/// committed. Returns an error for the first index violated.
+pub(super) async fn check_unique_indexes(
+    state_store: &StateStoreImpl,
+    indexes: &[UniqueIndex],
+    rows: &[(Op, Row)],
+) -> Result<()> {
+    if indexes.is_empty() {
+        return Ok(());
+    }
+
+    // The rows have all been taken by the table source, so they are in epochs before now, which
+    // have been applied to the indexes once now is committed.
+    let epoch = Epoch::now().0;
+    dispatch_state_store!(state_store, state_store, {
+        state_store.wait_epoch(epoch).await.map_err(RwError::from)?;
+        for index in indexes {
+            let table = index.table(state_store.clone());
+            for (op, row) in rows {
+                if matches!(op, Op::Insert | Op::UpdateInsert)
+                    && index.is_dropped(&table, row, epoch).await?
+                {
+                    return Err(ErrorCode::UniqueViolation(index.name.clone()).into());
+                }
+            }
+        }
+    });
+    Ok(())
+}
+
+/// Gets the rows of a chunk written to a table source, which is always compact.
+pub(super) fn chunk_rows(chunk: &StreamChunk) -> Vec<(Op, Row)> {
+    chunk
+        .ops()
+        .iter()
+        .enumerate()
+        .map(|(idx, op)| {
+            let row = Row(chunk
+                .columns()
+                .iter()
+                .map(|column| column.array_ref().datum_at(idx))
+                .collect());
+            (*op, row)
+        })
+        .collect()
+}
+
+/// Writes the changes reverting `rows` to `source` and waits until they are taken. In `rows`, each
+/// `UpdateDelete` is immediately followed by its `UpdateInsert`.
+pub(super) async fn revert_rows(
+    source: &TableSourceV2,
+    rows: Vec<(Op, Row)>,
+    data_types: &[DataType],
+) -> Result<()> {
+    let mut reverted_rows = Vec::with_capacity(rows.len());
+    for (op, row) in rows.into_iter().rev() {
+        match op {
+            Op::Insert => reverted_rows.push((Op::Delete, row)),
+            Op::Delete => reverted_rows.push((Op::Insert, row)),
+            // The rows are reversed, so the new row of an update comes first.
+            Op::UpdateInsert => reverted_rows.push((Op::UpdateDelete, row)),
+            Op::UpdateDelete => reverted_rows.push((Op::UpdateInsert, row)),
+        }
+    }
+
+    let chunk = StreamChunk::from_rows(&reverted_rows, data_types)?;
+    source.write_chunk(chunk)?.await.map_err(|_| {
+        RwError::from(ErrorCode::InternalError(
+            "failed to wait chunks to be written".to_owned(),
+        ))
+    })?;
+    Ok(())
+}
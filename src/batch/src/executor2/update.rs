@@ -0,0 +1,364 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use futures::future::try_join_all;
+use futures_async_stream::try_stream;
+use itertools::Itertools;
+use risingwave_common::array::column::Column;
+use risingwave_common::array::{ArrayBuilder, DataChunk, Op, PrimitiveArrayBuilder, StreamChunk};
+use risingwave_common::catalog::{Field, Schema, TableId};
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::types::DataType;
+use risingwave_expr::expr::{build_from_prost, BoxedExpression};
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+use risingwave_source::SourceManagerRef;
+use risingwave_storage::StateStoreImpl;
+
+use super::insert::check_pk_not_null;
+use super::unique_index::{check_unique_indexes, revert_rows, UniqueIndex};
+use crate::executor::ExecutorBuilder;
+use crate::executor2::{BoxedDataChunkStream, BoxedExecutor2, BoxedExecutor2Builder, Executor2};
+
+/// [`UpdateExecutor2`] implements table update with values from its child executor and given
+/// expressions.
+///
+/// For each row from the child, an `UpdateDelete` of the old row and an `UpdateInsert` of the new
+/// row are written to the table source in pairs.
+// TODO: concurrent `UPDATE` may cause problems. A scheduler might be required.
+pub struct UpdateExecutor2 {
+    /// Target table id.
+    table_id: TableId,
+    source_manager: SourceManagerRef,
+    child: BoxedExecutor2,
+    exprs: Vec<BoxedExpression>,
+    /// Indices of the user-defined primary key in `exprs`.
+    pk_indices: Vec<usize>,
+    /// The unique indexes on the table, which the updated rows are checked against.
+    unique_indexes: Vec<UniqueIndex>,
+    state_store: Option<StateStoreImpl>,
+    schema: Schema,
+    identity: String,
+}
+
+impl UpdateExecutor2 {
+    pub fn new(
+        table_id: TableId,
+        source_manager: SourceManagerRef,
+        child: BoxedExecutor2,
+        exprs: Vec<BoxedExpression>,
+        pk_indices: Vec<usize>,
+    ) -> Self {
+        assert_eq!(
+            child.schema().data_types(),
+            exprs.iter().map(|e| e.return_type()).collect_vec(),
+            "bad update schema"
+        );
+
+        Self {
+            table_id,
+            source_manager,
+            child,
+            exprs,
+            pk_indices,
+            unique_indexes: vec![],
+            state_store: None,
+            // TODO: support `RETURNING`
+            schema: Schema {
+                fields: vec![Field::unnamed(DataType::Int64)],
+            },
+            identity: "UpdateExecutor".to_string(),
+        }
+    }
+
+    /// Let the executor check the updated rows against the unique indexes on the table, which
+    /// are read from `state_store`.
+    #[must_use]
+    pub(super) fn with_unique_indexes(
+        self,
+        unique_indexes: Vec<UniqueIndex>,
+        state_store: StateStoreImpl,
+    ) -> Self {
+        Self {
+            unique_indexes,
+            state_store: Some(state_store),
+            ..self
+        }
+    }
+}
+
+impl Executor2 for UpdateExecutor2 {
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+
+    fn execute(self: Box<Self>) -> BoxedDataChunkStream {
+        self.do_execute()
+    }
+}
+
+impl UpdateExecutor2 {
+    #[try_stream(boxed, ok = DataChunk, error = RwError)]
+    async fn do_execute(mut self: Box<Self>) {
+        let source_desc = self.source_manager.get_source(&self.table_id)?;
+        let source = source_desc.source.as_table_v2().expect("not table source");
+
+        let data_types = self.child.schema().data_types();
+        let mut notifiers = Vec::new();
+        // The written rows, only kept to be checked against the unique indexes.
+        let mut updated_rows = Vec::new();
+
+        #[for_await]
+        for data_chunk in self.child.execute() {
+            let data_chunk = data_chunk?.compact()?;
+            let len = data_chunk.cardinality();
+
+            let updated_data_chunk = {
+                let columns: Vec<_> = self
+                    .exprs
+                    .iter_mut()
+                    .map(|expr| expr.eval(&data_chunk).map(Column::new))
+                    .try_collect()?;
+
+                DataChunk::builder().columns(columns).build()
+            };
+            check_pk_not_null(&updated_data_chunk, &self.pk_indices, |idx| {
+                source_desc.columns[idx].name.clone()
+            })?;
+
+            // Interleave the old and new rows so that each `UpdateDelete` is immediately followed
+            // by its `UpdateInsert`.
+            let mut rows = Vec::with_capacity(len * 2);
+            for (old_row, new_row) in data_chunk.rows().zip_eq(updated_data_chunk.rows()) {
+                rows.push((Op::UpdateDelete, old_row.to_owned_row()));
+                rows.push((Op::UpdateInsert, new_row.to_owned_row()));
+            }
+            let chunk = StreamChunk::from_rows(&rows, &data_types)?;
+            if !self.unique_indexes.is_empty() {
+                updated_rows.extend(rows);
+            }
+
+            let notifier = source.write_chunk(chunk)?;
+            notifiers.push(notifier);
+        }
+
+        // Wait for all chunks to be taken / written.
+        let rows_updated = try_join_all(notifiers)
+            .await
+            .map_err(|_| {
+                RwError::from(ErrorCode::InternalError(
+                    "failed to wait chunks to be written".to_owned(),
+                ))
+            })?
+            .into_iter()
+            .sum::<usize>()
+            / 2;
+
+        if let Some(state_store) = &self.state_store
+            && let Err(err) =
+                check_unique_indexes(state_store, &self.unique_indexes, &updated_rows).await
+        {
+            revert_rows(source, updated_rows, &data_types).await?;
+            return Err(err);
+        }
+
+        // create ret value
+        {
+            let mut array_builder = PrimitiveArrayBuilder::<i64>::new(1)?;
+            array_builder.append(Some(rows_updated as i64))?;
+
+            let array = array_builder.finish()?;
+            let ret_chunk = DataChunk::builder()
+                .columns(vec![Column::new(Arc::new(array.into()))])
+                .build();
+
+            yield ret_chunk
+        }
+    }
+}
+
+impl BoxedExecutor2Builder for UpdateExecutor2 {
+    fn new_boxed_executor2(source: &ExecutorBuilder) -> Result<BoxedExecutor2> {
+        let update_node = try_match_expand!(
+            source.plan_node().get_node_body().unwrap(),
+            NodeBody::Update
+        )?;
+
+        let table_id = TableId::from(&update_node.table_source_ref_id);
+
+        let proto_child = source.plan_node.get_children().get(0).ok_or_else(|| {
+            RwError::from(ErrorCode::InternalError(String::from(
+                "Child interpreting error",
+            )))
+        })?;
+        let child = source.clone_for_plan(proto_child).build2()?;
+
+        let exprs = update_node
+            .get_exprs()
+            .iter()
+            .map(build_from_prost)
+            .try_collect()?;
+
+        let unique_indexes = update_node
+            .unique_indexes
+            .iter()
+            .map(UniqueIndex::from_prost)
+            .try_collect()?;
+
+        Ok(Box::new(
+            Self::new(
+                table_id,
+                source.global_batch_env().source_manager_ref(),
+                child,
+                exprs,
+                update_node
+                    .pk_indices
+                    .iter()
+                    .map(|idx| *idx as usize)
+                    .collect(),
+            )
+            .with_unique_indexes(unique_indexes, source.global_batch_env().state_store()),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use futures::StreamExt;
+    use risingwave_common::array::{Array, I64Array};
+    use risingwave_common::catalog::{schema_test_utils, ColumnDesc, ColumnId};
+    use risingwave_common::column_nonnull;
+    use risingwave_expr::expr::InputRefExpression;
+    use risingwave_source::{MemSourceManager, SourceManager, StreamSourceReader};
+
+    use super::*;
+    use crate::executor::test_utils::MockExecutor;
+    use crate::*;
+
+    #[tokio::test]
+    async fn test_update_executor() -> Result<()> {
+        let source_manager = Arc::new(MemSourceManager::default());
+
+        // Schema for mock executor.
+        let schema = schema_test_utils::ii();
+        let mut mock_executor = MockExecutor::new(schema.clone());
+
+        // Schema of the table
+        let schema = schema_test_utils::ii();
+
+        let table_columns: Vec<_> = schema
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, f)| ColumnDesc {
+                data_type: f.data_type.clone(),
+                column_id: ColumnId::from(i as i32), // use column index as column id
+                name: f.name.clone(),
+                field_descs: vec![],
+                type_name: "".to_string(),
+                default_value: None,
+            })
+            .collect();
+
+        let col1 = column_nonnull! { I64Array, [1, 3, 5, 7, 9] };
+        let col2 = column_nonnull! { I64Array, [2, 4, 6, 8, 10] };
+        let data_chunk: DataChunk = DataChunk::builder().columns(vec![col1, col2]).build();
+        mock_executor.add(data_chunk.clone());
+
+        // Update expressions, which swap the two columns.
+        let exprs: Vec<BoxedExpression> = vec![
+            Box::new(InputRefExpression::new(DataType::Int64, 1)),
+            Box::new(InputRefExpression::new(DataType::Int64, 0)),
+        ];
+
+        // Create the table.
+        let table_id = TableId::new(0);
+        source_manager.create_table_source(&table_id, table_columns.to_vec())?;
+
+        // Create reader
+        let source_desc = source_manager.get_source(&table_id)?;
+        let source = source_desc.source.as_table_v2().unwrap();
+        let mut reader = source.stream_reader(vec![0.into(), 1.into()]).await?;
+
+        // Update
+        let update_executor = Box::new(UpdateExecutor2::new(
+            table_id,
+            source_manager.clone(),
+            Box::new(mock_executor),
+            exprs,
+            vec![],
+        ));
+
+        let handle = tokio::spawn(async move {
+            let fields = &update_executor.schema().fields;
+            assert_eq!(fields[0].data_type, DataType::Int64);
+
+            let mut stream = update_executor.execute();
+            let result = stream.next().await.unwrap().unwrap();
+
+            assert_eq!(
+                result
+                    .column_at(0)
+                    .array()
+                    .as_int64()
+                    .iter()
+                    .collect::<Vec<_>>(),
+                vec![Some(5)] // updated rows
+            );
+        });
+
+        // Read
+        let chunk = reader.next().await?;
+
+        assert_eq!(
+            chunk.chunk.ops().chunks(2).collect_vec(),
+            vec![&[Op::UpdateDelete, Op::UpdateInsert]; 5]
+        );
+
+        assert_eq!(
+            chunk.chunk.columns()[0]
+                .array()
+                .as_int64()
+                .iter()
+                .collect::<Vec<_>>(),
+            [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]
+                .into_iter()
+                .map(Some)
+                .collect_vec()
+        );
+
+        assert_eq!(
+            chunk.chunk.columns()[1]
+                .array()
+                .as_int64()
+                .iter()
+                .collect::<Vec<_>>(),
+            [2, 1, 4, 3, 6, 5, 8, 7, 10, 9]
+                .into_iter()
+                .map(Some)
+                .collect_vec()
+        );
+
+        handle.await.unwrap();
+
+        Ok(())
+    }
+}
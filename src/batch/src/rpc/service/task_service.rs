@@ -62,9 +62,22 @@ impl TaskService for BatchServiceImpl {
     #[cfg_attr(coverage, no_coverage)]
     async fn get_task_info(
         &self,
-        _: Request<GetTaskInfoRequest>,
+        request: Request<GetTaskInfoRequest>,
     ) -> Result<Response<GetTaskInfoResponse>, Status> {
-        todo!()
+        let req = request.into_inner();
+        let res = self
+            .mgr
+            .get_task_info(req.get_task_id().expect("no task id found"));
+        match res {
+            Ok(task_info) => Ok(Response::new(GetTaskInfoResponse {
+                status: None,
+                task_info: Some(task_info),
+            })),
+            Err(e) => {
+                error!("failed to get task info {}", e);
+                Err(e.to_grpc_status())
+            }
+        }
     }
 
     #[cfg_attr(coverage, no_coverage)]
@@ -23,11 +23,11 @@ use risingwave_pb::batch_plan::{
     PlanFragment, TaskId as ProstTaskId, TaskOutputId as ProstOutputId,
 };
 use risingwave_pb::task_service::task_info::TaskStatus;
-use risingwave_pb::task_service::GetDataResponse;
+use risingwave_pb::task_service::{GetDataResponse, TaskInfo};
 use tracing_futures::Instrument;
 
 use crate::executor::ExecutorBuilder;
-use crate::executor2::BoxedExecutor2;
+use crate::executor2::{BoxedExecutor2, TaskProfiler};
 use crate::rpc::service::exchange::ExchangeWriter;
 use crate::task::channel::{create_output_channel, ChanReceiverImpl, ChanSenderImpl};
 use crate::task::{BatchEnvironment, BatchManager};
@@ -184,6 +184,9 @@ pub struct BatchTaskExecution {
     failure: Arc<Mutex<Option<RwError>>>,
 
     epoch: u64,
+
+    /// Runtime statistics of the executors of the task.
+    profiler: TaskProfiler,
 }
 
 impl BatchTaskExecution {
@@ -201,6 +204,7 @@ impl BatchTaskExecution {
             env,
             failure: Arc::new(Mutex::new(None)),
             epoch,
+            profiler: TaskProfiler::default(),
         })
     }
 
@@ -226,6 +230,7 @@ impl BatchTaskExecution {
             &self.task_id.clone(),
             self.env.clone(),
             self.epoch,
+            self.profiler.clone(),
         )
         .build2()?;
 
@@ -301,6 +306,15 @@ impl BatchTaskExecution {
         self.failure.lock().clone()
     }
 
+    /// Returns the status of the task and the runtime statistics of its executors.
+    pub fn get_task_info(&self) -> TaskInfo {
+        TaskInfo {
+            task_id: Some(self.task_id.to_prost()),
+            task_status: *self.state.lock() as i32,
+            executor_profiles: self.profiler.to_prost(),
+        }
+    }
+
     pub fn check_if_running(&self) -> Result<()> {
         if *self.state.lock() != TaskStatus::Running {
             return Err(ErrorCode::InternalError(format!(
@@ -21,6 +21,7 @@ use risingwave_common::error::{Result, RwError};
 use risingwave_pb::batch_plan::{
     PlanFragment, TaskId as ProstTaskId, TaskOutputId as ProstOutputId,
 };
+use risingwave_pb::task_service::TaskInfo;
 
 use crate::task::env::BatchEnvironment;
 use crate::task::{BatchTaskExecution, TaskId, TaskOutput};
@@ -98,6 +99,16 @@ impl BatchManager {
             .ok_or(TaskNotFound)?
             .get_error())
     }
+
+    pub fn get_task_info(&self, tid: &ProstTaskId) -> Result<TaskInfo> {
+        let task_id = TaskId::from(tid);
+        Ok(self
+            .tasks
+            .lock()
+            .get(&task_id)
+            .ok_or(TaskNotFound)?
+            .get_task_info())
+    }
 }
 
 impl Default for BatchManager {
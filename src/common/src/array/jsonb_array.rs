@@ -0,0 +1,628 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt;
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use bytes::{Buf, BufMut};
+use itertools::Itertools;
+use risingwave_pb::data::{Array as ProstArray, ArrayType as ProstArrayType};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{
+    Array, ArrayBuilder, ArrayBuilderImpl, ArrayImpl, ArrayIterator, ArrayMeta, Utf8ArrayBuilder,
+    NULL_VAL_FOR_HASH,
+};
+use crate::buffer::{Bitmap, BitmapBuilder};
+use crate::error::ErrorCode::InternalError;
+use crate::error::{Result, RwError};
+use crate::types::Scalar;
+
+/// `JsonbArrayBuilder` keeps parsed json values, so that operators on jsonb don't need to parse
+/// the text again.
+#[derive(Debug)]
+pub struct JsonbArrayBuilder {
+    bitmap: BitmapBuilder,
+    data: Vec<Value>,
+}
+
+impl ArrayBuilder for JsonbArrayBuilder {
+    type ArrayType = JsonbArray;
+
+    fn with_meta(capacity: usize, _meta: ArrayMeta) -> Result<Self> {
+        Ok(Self {
+            bitmap: BitmapBuilder::with_capacity(capacity),
+            data: Vec::with_capacity(capacity),
+        })
+    }
+
+    fn append(&mut self, value: Option<JsonbRef<'_>>) -> Result<()> {
+        match value {
+            Some(v) => {
+                self.bitmap.append(true);
+                self.data.push(v.0.clone());
+            }
+            None => {
+                self.bitmap.append(false);
+                self.data.push(Value::Null);
+            }
+        }
+        Ok(())
+    }
+
+    fn append_array(&mut self, other: &JsonbArray) -> Result<()> {
+        self.bitmap.append_bitmap(&other.bitmap);
+        self.data.extend_from_slice(&other.data);
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<JsonbArray> {
+        Ok(JsonbArray {
+            bitmap: self.bitmap.finish(),
+            data: self.data,
+        })
+    }
+}
+
+/// `JsonbArray` is a collection of parsed json values. Null slots hold a placeholder
+/// `Value::Null`, which is never exposed since the bitmap is checked first.
+#[derive(Debug)]
+pub struct JsonbArray {
+    bitmap: Bitmap,
+    data: Vec<Value>,
+}
+
+impl Array for JsonbArray {
+    type Builder = JsonbArrayBuilder;
+    type Iter<'a> = ArrayIterator<'a, Self>;
+    type OwnedItem = JsonbVal;
+    type RefItem<'a> = JsonbRef<'a>;
+
+    fn value_at(&self, idx: usize) -> Option<JsonbRef<'_>> {
+        if !self.is_null(idx) {
+            Some(JsonbRef(&self.data[idx]))
+        } else {
+            None
+        }
+    }
+
+    unsafe fn value_at_unchecked(&self, idx: usize) -> Option<JsonbRef<'_>> {
+        if !self.is_null_unchecked(idx) {
+            Some(JsonbRef(self.data.get_unchecked(idx)))
+        } else {
+            None
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        ArrayIterator::new(self)
+    }
+
+    /// Jsonb arrays are transferred in the same layout as `Utf8Array`, holding the json text of
+    /// each value.
+    fn to_protobuf(&self) -> ProstArray {
+        let mut builder = Utf8ArrayBuilder::new(self.len()).unwrap();
+        for v in self.iter() {
+            builder
+                .append(v.map(|v| v.to_json_text()).as_deref())
+                .unwrap();
+        }
+        let mut array = builder.finish().unwrap().to_protobuf();
+        array.array_type = ProstArrayType::Jsonb as i32;
+        array
+    }
+
+    fn null_bitmap(&self) -> &Bitmap {
+        &self.bitmap
+    }
+
+    fn set_bitmap(&mut self, bitmap: Bitmap) {
+        self.bitmap = bitmap;
+    }
+
+    fn hash_at<H: Hasher>(&self, idx: usize, state: &mut H) {
+        if !self.is_null(idx) {
+            JsonbRef(&self.data[idx]).hash(state);
+        } else {
+            NULL_VAL_FOR_HASH.hash(state);
+        }
+    }
+
+    fn create_builder(&self, capacity: usize) -> Result<ArrayBuilderImpl> {
+        let array_builder = JsonbArrayBuilder::new(capacity)?;
+        Ok(ArrayBuilderImpl::Jsonb(array_builder))
+    }
+}
+
+impl JsonbArray {
+    pub fn from_protobuf(array: &ProstArray, cardinality: usize) -> Result<ArrayImpl> {
+        use super::column_proto_readers::read_string_array;
+        use super::value_reader::Utf8ValueReader;
+
+        let texts = read_string_array::<Utf8ArrayBuilder, Utf8ValueReader>(array, cardinality)?;
+        let mut builder = JsonbArrayBuilder::new(cardinality)?;
+        for text in texts.as_utf8().iter() {
+            let value = text.map(JsonbVal::from_str).transpose()?;
+            builder.append(value.as_ref().map(|v| v.as_scalar_ref()))?;
+        }
+        Ok(builder.finish()?.into())
+    }
+}
+
+/// The owned jsonb scalar. The value is boxed to keep `ScalarImpl` small.
+#[derive(Clone, Debug)]
+pub struct JsonbVal(pub(crate) Box<Value>);
+
+impl JsonbVal {
+    pub fn from_serde(value: Value) -> Self {
+        Self(Box::new(value))
+    }
+
+    pub fn into_serde(self) -> Value {
+        *self.0
+    }
+
+    /// Deserialize the memcomparable encoding written by [`JsonbRef::memcmp_serialize`].
+    pub fn memcmp_deserialize(
+        de: &mut memcomparable::Deserializer<impl Buf>,
+    ) -> memcomparable::Result<Self> {
+        memcmp_deserialize_value(de).map(Self::from_serde)
+    }
+}
+
+impl FromStr for JsonbVal {
+    type Err = RwError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        serde_json::from_str(s).map(Self::from_serde).map_err(|e| {
+            InternalError(format!("invalid input syntax for type jsonb: {}", e)).into()
+        })
+    }
+}
+
+impl PartialEq for JsonbVal {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_scalar_ref() == other.as_scalar_ref()
+    }
+}
+
+impl Eq for JsonbVal {}
+
+impl PartialOrd for JsonbVal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for JsonbVal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_scalar_ref().cmp(&other.as_scalar_ref())
+    }
+}
+
+impl Hash for JsonbVal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_scalar_ref().hash(state)
+    }
+}
+
+impl Display for JsonbVal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.as_scalar_ref(), f)
+    }
+}
+
+/// A reference to a jsonb value, either in a [`JsonbArray`] or a [`JsonbVal`].
+#[derive(Copy, Clone, Debug)]
+pub struct JsonbRef<'a>(pub(crate) &'a Value);
+
+impl<'a> JsonbRef<'a> {
+    pub fn value(&self) -> &'a Value {
+        self.0
+    }
+
+    /// The compact json text, used for the wire and storage formats.
+    pub fn to_json_text(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the name of the json type, as `jsonb_typeof` does.
+    pub fn type_name(&self) -> &'static str {
+        match self.0 {
+            Value::Null => "null",
+            Value::Bool(_) => "boolean",
+            Value::Number(_) => "number",
+            Value::String(_) => "string",
+            Value::Array(_) => "array",
+            Value::Object(_) => "object",
+        }
+    }
+
+    /// Returns the field with `key` if this is an object.
+    pub fn access_object_field(&self, key: &str) -> Option<JsonbRef<'a>> {
+        self.0.as_object()?.get(key).map(JsonbRef)
+    }
+
+    /// Returns the `idx`-th element if this is an array. Negative indexes count from the end.
+    pub fn access_array_element(&self, idx: i32) -> Option<JsonbRef<'a>> {
+        let array = self.0.as_array()?;
+        let idx = if idx < 0 {
+            array.len().checked_sub(idx.unsigned_abs() as usize)?
+        } else {
+            idx as usize
+        };
+        array.get(idx).map(JsonbRef)
+    }
+
+    /// Follows `path`, where each step is either an object key or an array index.
+    pub fn access_path<'b>(&self, path: impl IntoIterator<Item = &'b str>) -> Option<JsonbRef<'a>> {
+        path.into_iter().try_fold(*self, |v, step| match v.0 {
+            Value::Array(_) => v.access_array_element(step.trim().parse().ok()?),
+            _ => v.access_object_field(step),
+        })
+    }
+
+    /// Returns the value as text, as the `->>` operator does. Strings are unquoted, and a json
+    /// `null` becomes `None`.
+    pub fn as_text(&self) -> Option<String> {
+        match self.0 {
+            Value::Null => None,
+            Value::String(s) => Some(s.clone()),
+            _ => Some(self.to_string()),
+        }
+    }
+
+    /// Returns the elements if this is an array, as `jsonb_array_elements` does.
+    pub fn array_elements(&self) -> Result<impl Iterator<Item = JsonbRef<'a>>> {
+        match self.0 {
+            Value::Array(a) => Ok(a.iter().map(JsonbRef)),
+            Value::Object(_) => {
+                Err(InternalError("cannot extract elements from an object".to_string()).into())
+            }
+            _ => Err(InternalError("cannot extract elements from a scalar".to_string()).into()),
+        }
+    }
+
+    /// Returns whether `other` is contained in this value, as the `@>` operator does.
+    pub fn contains(&self, other: JsonbRef<'_>) -> bool {
+        match (self.0, other.0) {
+            // A top-level array contains a primitive value appearing as one of its elements.
+            (Value::Array(l), r) if !r.is_array() && !r.is_object() => {
+                l.iter().any(|l| JsonbRef(l) == JsonbRef(r))
+            }
+            _ => contains_nested(self.0, other.0),
+        }
+    }
+
+    /// Returns whether `key` exists as a top-level key or array element, as the `?` operator
+    /// does.
+    pub fn exists(&self, key: &str) -> bool {
+        match self.0 {
+            Value::Object(o) => o.contains_key(key),
+            Value::Array(a) => a.iter().any(|v| v.as_str() == Some(key)),
+            Value::String(s) => s == key,
+            _ => false,
+        }
+    }
+
+    /// Serialize into the memcomparable format. The encoding follows the order defined by
+    /// [`Ord`]: a tag for the json type, followed by the length of containers and then their
+    /// elements.
+    pub fn memcmp_serialize(
+        &self,
+        ser: &mut memcomparable::Serializer<impl BufMut>,
+    ) -> memcomparable::Result<()> {
+        memcmp_serialize_value(self.0, ser)
+    }
+}
+
+fn contains_nested(l: &Value, r: &Value) -> bool {
+    match (l, r) {
+        (Value::Object(l), Value::Object(r)) => r
+            .iter()
+            .all(|(k, r)| l.get(k).map_or(false, |l| contains_nested(l, r))),
+        (Value::Array(l), Value::Array(r)) => {
+            r.iter().all(|r| l.iter().any(|l| contains_nested(l, r)))
+        }
+        (l, r) => JsonbRef(l) == JsonbRef(r),
+    }
+}
+
+/// The rank of json types when comparing values of different types, following Postgres.
+fn type_rank(v: &Value) -> u8 {
+    match v {
+        Value::Null => 0,
+        Value::String(_) => 1,
+        Value::Number(_) => 2,
+        Value::Bool(_) => 3,
+        Value::Array(_) => 4,
+        Value::Object(_) => 5,
+    }
+}
+
+fn number_as_f64(n: &serde_json::Number) -> f64 {
+    n.as_f64().unwrap_or_default()
+}
+
+fn cmp_value(l: &Value, r: &Value) -> Ordering {
+    match (l, r) {
+        (Value::Null, Value::Null) => Ordering::Equal,
+        (Value::String(l), Value::String(r)) => l.cmp(r),
+        // Numbers with the same value but different texts, e.g. `1` and `1.0`, are ordered by
+        // their texts so that the order is consistent with the memcomparable encoding.
+        (Value::Number(l), Value::Number(r)) => number_as_f64(l)
+            .partial_cmp(&number_as_f64(r))
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| l.to_string().cmp(&r.to_string())),
+        (Value::Bool(l), Value::Bool(r)) => l.cmp(r),
+        (Value::Array(l), Value::Array(r)) => l.len().cmp(&r.len()).then_with(|| {
+            l.iter()
+                .zip_eq(r.iter())
+                .map(|(l, r)| cmp_value(l, r))
+                .find(|o| o.is_ne())
+                .unwrap_or(Ordering::Equal)
+        }),
+        (Value::Object(l), Value::Object(r)) => l.len().cmp(&r.len()).then_with(|| {
+            l.iter()
+                .zip_eq(r.iter())
+                .map(|((lk, lv), (rk, rv))| lk.cmp(rk).then_with(|| cmp_value(lv, rv)))
+                .find(|o| o.is_ne())
+                .unwrap_or(Ordering::Equal)
+        }),
+        (l, r) => type_rank(l).cmp(&type_rank(r)),
+    }
+}
+
+fn memcmp_serialize_value(
+    v: &Value,
+    ser: &mut memcomparable::Serializer<impl BufMut>,
+) -> memcomparable::Result<()> {
+    type_rank(v).serialize(&mut *ser)?;
+    match v {
+        Value::Null => {}
+        Value::String(s) => s.serialize(&mut *ser)?,
+        Value::Number(n) => {
+            number_as_f64(n).serialize(&mut *ser)?;
+            n.to_string().serialize(&mut *ser)?;
+        }
+        Value::Bool(b) => b.serialize(&mut *ser)?,
+        Value::Array(a) => {
+            (a.len() as u64).serialize(&mut *ser)?;
+            for v in a {
+                memcmp_serialize_value(v, ser)?;
+            }
+        }
+        Value::Object(o) => {
+            (o.len() as u64).serialize(&mut *ser)?;
+            for (k, v) in o {
+                k.serialize(&mut *ser)?;
+                memcmp_serialize_value(v, ser)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn memcmp_deserialize_value(
+    de: &mut memcomparable::Deserializer<impl Buf>,
+) -> memcomparable::Result<Value> {
+    let tag = u8::deserialize(&mut *de)?;
+    Ok(match tag {
+        0 => Value::Null,
+        1 => Value::String(String::deserialize(&mut *de)?),
+        2 => {
+            let _ = f64::deserialize(&mut *de)?;
+            let text = String::deserialize(&mut *de)?;
+            Value::Number(
+                serde_json::Number::from_str(&text)
+                    .map_err(|e| memcomparable::Error::Message(e.to_string()))?,
+            )
+        }
+        3 => Value::Bool(bool::deserialize(&mut *de)?),
+        4 => {
+            let len = u64::deserialize(&mut *de)?;
+            let array = (0..len)
+                .map(|_| memcmp_deserialize_value(de))
+                .collect::<memcomparable::Result<_>>()?;
+            Value::Array(array)
+        }
+        5 => {
+            let len = u64::deserialize(&mut *de)?;
+            let object = (0..len)
+                .map(|_| {
+                    Ok((
+                        String::deserialize(&mut *de)?,
+                        memcmp_deserialize_value(de)?,
+                    ))
+                })
+                .collect::<memcomparable::Result<_>>()?;
+            Value::Object(object)
+        }
+        _ => return Err(memcomparable::Error::InvalidTagEncoding(tag as _)),
+    })
+}
+
+impl PartialEq for JsonbRef<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for JsonbRef<'_> {}
+
+impl PartialOrd for JsonbRef<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for JsonbRef<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_value(self.0, other.0)
+    }
+}
+
+impl Hash for JsonbRef<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Equal values always have the same compact text.
+        self.to_json_text().hash(state)
+    }
+}
+
+/// Formats the value like Postgres does, with a space after `:` and `,`.
+fn fmt_value(v: &Value, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match v {
+        Value::Array(a) => {
+            write!(f, "[")?;
+            for (i, v) in a.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                fmt_value(v, f)?;
+            }
+            write!(f, "]")
+        }
+        Value::Object(o) => {
+            write!(f, "{{")?;
+            for (i, (k, v)) in o.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}: ", Value::String(k.clone()))?;
+                fmt_value(v, f)?;
+            }
+            write!(f, "}}")
+        }
+        v => write!(f, "{}", v),
+    }
+}
+
+impl Display for JsonbRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_value(self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jsonb(s: &str) -> JsonbVal {
+        JsonbVal::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_jsonb_array_protobuf() {
+        let values = [Some(jsonb(r#"{"a": [1, "b"]}"#)), None, Some(jsonb("null"))];
+        let mut builder = JsonbArrayBuilder::new(values.len()).unwrap();
+        for v in &values {
+            builder
+                .append(v.as_ref().map(|v| v.as_scalar_ref()))
+                .unwrap();
+        }
+        let array = builder.finish().unwrap();
+        let decoded = ArrayImpl::from_protobuf(&array.to_protobuf(), values.len()).unwrap();
+        let decoded = decoded
+            .as_jsonb()
+            .iter()
+            .map(|v| v.map(|v| JsonbVal::from_serde(v.value().clone())))
+            .collect_vec();
+        assert_eq!(decoded, values);
+    }
+
+    #[test]
+    fn test_jsonb_display() {
+        assert_eq!(
+            jsonb(r#"{"b":[1,2.5,"x"],"a":{"c":null}}"#).to_string(),
+            r#"{"a": {"c": null}, "b": [1, 2.5, "x"]}"#
+        );
+    }
+
+    #[test]
+    fn test_jsonb_memcomparable() {
+        let sorted = [
+            "null",
+            r#""a""#,
+            r#""b""#,
+            "-1",
+            "1",
+            "1.0",
+            "2",
+            "false",
+            "true",
+            "[2]",
+            "[1, 2]",
+            "[1, 3]",
+            r#"{"a": 2}"#,
+            r#"{"b": 1}"#,
+            r#"{"a": 1, "b": 1}"#,
+        ]
+        .map(jsonb);
+        assert!(sorted.is_sorted());
+
+        let encoded = sorted
+            .iter()
+            .map(|v| {
+                let mut ser = memcomparable::Serializer::new(vec![]);
+                v.as_scalar_ref().memcmp_serialize(&mut ser).unwrap();
+                ser.into_inner()
+            })
+            .collect_vec();
+        assert!(encoded.is_sorted());
+
+        for (v, bytes) in sorted.iter().zip_eq(encoded) {
+            let mut de = memcomparable::Deserializer::new(bytes.as_slice());
+            assert_eq!(&JsonbVal::memcmp_deserialize(&mut de).unwrap(), v);
+        }
+    }
+
+    #[test]
+    fn test_jsonb_access() {
+        let v = jsonb(r#"{"a": [1, {"b": "x"}], "c": null}"#);
+        let v = v.as_scalar_ref();
+        assert_eq!(
+            v.access_object_field("a").unwrap().to_string(),
+            r#"[1, {"b": "x"}]"#
+        );
+        assert!(v.access_object_field("d").is_none());
+        assert_eq!(
+            v.access_path(["a", "-1", "b"]).unwrap().as_text(),
+            Some("x".to_string())
+        );
+        assert!(v.access_path(["a", "x"]).is_none());
+        assert_eq!(v.access_object_field("c").unwrap().as_text(), None);
+        assert_eq!(v.type_name(), "object");
+        assert!(v.exists("c"));
+        assert!(!v.exists("x"));
+    }
+
+    #[test]
+    fn test_jsonb_contains() {
+        let contains =
+            |l: &str, r: &str| jsonb(l).as_scalar_ref().contains(jsonb(r).as_scalar_ref());
+        assert!(contains(r#"{"a": 1, "b": [1, 2, 3]}"#, r#"{"b": [3, 1]}"#));
+        assert!(!contains(r#"{"a": 1}"#, r#"{"a": 2}"#));
+        assert!(contains("[1, [2, 3]]", "[[3]]"));
+        assert!(contains(r#"["a", 1]"#, r#""a""#));
+        assert!(!contains(r#"[["a"]]"#, r#""a""#));
+    }
+}
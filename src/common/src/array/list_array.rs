@@ -267,8 +267,8 @@ pub struct ListValue {
 }
 
 impl fmt::Display for ListValue {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        Ok(())
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_scalar_ref().fmt(f)
     }
 }
 
@@ -368,11 +368,44 @@ impl Debug for ListRef<'_> {
 }
 
 impl Display for ListRef<'_> {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+    /// Formats the list in the text format of Postgres arrays, e.g. `{1,NULL,"a b"}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+        for (i, value) in self.values_ref().into_iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            match value {
+                None => write!(f, "NULL")?,
+                Some(ScalarRefImpl::Bool(b)) => write!(f, "{}", if b { "t" } else { "f" })?,
+                Some(ScalarRefImpl::List(list)) => write!(f, "{}", list)?,
+                Some(value) => write_array_element(f, &value.to_string())?,
+            }
+        }
+        write!(f, "}}")
     }
 }
 
+/// Writes an element of an array, which is quoted if it is empty, `NULL`, or contains whitespace
+/// or any of `{},"\`.
+fn write_array_element(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    let need_quote = s.is_empty()
+        || s.eq_ignore_ascii_case("null")
+        || s.chars()
+            .any(|c| c.is_whitespace() || matches!(c, '{' | '}' | ',' | '"' | '\\'));
+    if !need_quote {
+        return write!(f, "{}", s);
+    }
+    write!(f, "\"")?;
+    for c in s.chars() {
+        if c == '"' || c == '\\' {
+            write!(f, "\\")?;
+        }
+        write!(f, "{}", c)?;
+    }
+    write!(f, "\"")
+}
+
 impl Eq for ListRef<'_> {}
 
 impl Ord for ListRef<'_> {
@@ -632,4 +665,22 @@ mod tests {
             ListValue::new(vec![Some(1.into()), None]),
         );
     }
+
+    #[test]
+    fn test_list_display() {
+        let list = ListValue::new(vec![
+            Some("a".to_string().into()),
+            None,
+            Some("a b".to_string().into()),
+            Some("".to_string().into()),
+            Some("null".to_string().into()),
+            Some("{\"\\}".to_string().into()),
+        ]);
+        assert_eq!(list.to_string(), r#"{a,NULL,"a b","","null","{\"\\}"}"#);
+        let nested = ListValue::new(vec![
+            Some(ListValue::new(vec![Some(1.into()), Some(2.into())]).into()),
+            Some(ListValue::new(vec![]).into()),
+        ]);
+        assert_eq!(nested.to_string(), "{{1,2},{}}");
+    }
 }
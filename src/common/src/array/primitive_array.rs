@@ -27,7 +27,7 @@ use crate::error::Result;
 use crate::for_all_native_types;
 use crate::types::interval::IntervalUnit;
 use crate::types::{
-    NaiveDateTimeWrapper, NaiveDateWrapper, NaiveTimeWrapper, NativeType, Scalar, ScalarRef,
+    NaiveDateTimeWrapper, NaiveDateWrapper, NaiveTimeWrapper, NativeType, Scalar, ScalarRef, Uuid,
 };
 
 /// Physical type of array items which have fixed size.
@@ -130,7 +130,8 @@ impl_primitive_for_others! {
     { IntervalUnit, Interval, Interval },
     { NaiveDateWrapper, Date, NaiveDate },
     { NaiveTimeWrapper, Time, NaiveTime },
-    { NaiveDateTimeWrapper, Timestamp, NaiveDateTime }
+    { NaiveDateTimeWrapper, Timestamp, NaiveDateTime },
+    { Uuid, Uuid, Uuid }
 }
 
 /// `PrimitiveArray` is a collection of primitive types, such as `i32`, `f32`.
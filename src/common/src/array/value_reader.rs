@@ -18,11 +18,12 @@ use std::str::{from_utf8, FromStr};
 use byteorder::{BigEndian, ReadBytesExt};
 
 use crate::array::{
-    Array, ArrayBuilder, DecimalArrayBuilder, PrimitiveArrayItemType, Utf8ArrayBuilder,
+    Array, ArrayBuilder, BytesArrayBuilder, DecimalArrayBuilder, PrimitiveArrayItemType,
+    Utf8ArrayBuilder,
 };
 use crate::error::ErrorCode::InternalError;
 use crate::error::{ErrorCode, Result, RwError};
-use crate::types::{Decimal, OrderedF32, OrderedF64};
+use crate::types::{Decimal, OrderedF32, OrderedF64, Uuid};
 
 /// Reads an encoded buffer into a value.
 pub trait PrimitiveValueReader<T: PrimitiveArrayItemType> {
@@ -56,6 +57,19 @@ impl_numeric_value_reader!(i64, I64ValueReader, read_i64);
 impl_numeric_value_reader!(OrderedF32, F32ValueReader, read_f32);
 impl_numeric_value_reader!(OrderedF64, F64ValueReader, read_f64);
 
+pub struct UuidValueReader {}
+
+impl PrimitiveValueReader<Uuid> for UuidValueReader {
+    fn read(cur: &mut Cursor<&[u8]>) -> Result<Uuid> {
+        cur.read_u128::<BigEndian>().map(Uuid).map_err(|e| {
+            RwError::from(ErrorCode::InternalError(format!(
+                "Failed to read value from buffer: {}",
+                e
+            )))
+        })
+    }
+}
+
 pub trait VarSizedValueReader<AB: ArrayBuilder> {
     fn read(buf: &[u8]) -> Result<<<AB as ArrayBuilder>::ArrayType as Array>::RefItem<'_>>;
 }
@@ -73,6 +87,14 @@ impl VarSizedValueReader<Utf8ArrayBuilder> for Utf8ValueReader {
     }
 }
 
+pub struct BytesValueReader {}
+
+impl VarSizedValueReader<BytesArrayBuilder> for BytesValueReader {
+    fn read(buf: &[u8]) -> Result<&[u8]> {
+        Ok(buf)
+    }
+}
+
 pub struct DecimalValueReader {}
 
 impl VarSizedValueReader<DecimalArrayBuilder> for DecimalValueReader {
@@ -20,8 +20,9 @@ use risingwave_pb::plan_common::{
 
 use crate::catalog::Field;
 use crate::error::ErrorCode;
-use crate::types::DataType;
+use crate::types::{DataType, Datum};
 use crate::util::sort_util::OrderType;
+use crate::util::value_encoding::{deserialize_cell, serialize_cell};
 
 /// Column ID is the unique identifier of a column in a table. Different from table ID,
 /// column ID is not globally unique.
@@ -69,6 +70,9 @@ pub struct ColumnDesc {
     pub name: String, // for debugging
     pub field_descs: Vec<ColumnDesc>,
     pub type_name: String,
+    /// The default value of a column added by `ALTER TABLE ADD COLUMN`. Readers of the cell-based
+    /// table fill it in for the rows written before the column was added.
+    pub default_value: Datum,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -85,6 +89,7 @@ impl ColumnDesc {
             name: String::new(),
             field_descs: vec![],
             type_name: String::new(),
+            default_value: None,
         }
     }
 
@@ -101,6 +106,7 @@ impl ColumnDesc {
                 .map(|f| f.to_protobuf())
                 .collect_vec(),
             type_name: self.type_name.clone(),
+            default_value: serialize_cell(&self.default_value).unwrap(),
         }
     }
 
@@ -144,6 +150,7 @@ impl ColumnDesc {
             name: name.to_string(),
             field_descs: vec![],
             type_name: "".to_string(),
+            default_value: None,
         }
     }
 
@@ -167,6 +174,7 @@ impl ColumnDesc {
             name: name.to_string(),
             field_descs: fields,
             type_name: type_name.to_string(),
+            default_value: None,
         }
     }
 
@@ -190,6 +198,7 @@ impl ColumnDesc {
                 .map(Self::from_field_without_column_id)
                 .collect_vec(),
             type_name: field.type_name.clone(),
+            default_value: None,
         }
     }
 }
@@ -201,12 +210,15 @@ impl From<ProstColumnDesc> for ColumnDesc {
             .into_iter()
             .map(ColumnDesc::from)
             .collect();
+        let data_type = DataType::from(prost.column_type.as_ref().unwrap());
+        let default_value = deserialize_cell(&prost.default_value[..], &data_type).unwrap();
         Self {
-            data_type: DataType::from(prost.column_type.as_ref().unwrap()),
+            data_type,
             column_id: ColumnId::new(prost.column_id),
             name: prost.name,
             type_name: prost.type_name,
             field_descs,
+            default_value,
         }
     }
 }
@@ -225,6 +237,16 @@ impl From<&ColumnDesc> for ProstColumnDesc {
             name: c.name.clone(),
             field_descs: c.field_descs.iter().map(ColumnDesc::to_protobuf).collect(),
             type_name: c.type_name.clone(),
+            default_value: serialize_cell(&c.default_value).unwrap(),
+        }
+    }
+}
+
+impl OrderedColumnDesc {
+    pub fn to_protobuf(&self) -> ProstOrderedColumnDesc {
+        ProstOrderedColumnDesc {
+            column_desc: Some(self.column_desc.to_protobuf()),
+            order: self.order.to_prost() as i32,
         }
     }
 }
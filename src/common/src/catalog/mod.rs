@@ -24,6 +24,27 @@ pub use schema::{test_utils as schema_test_utils, Field, Schema};
 
 pub const DEFAULT_DATABASE_NAME: &str = "dev";
 pub const DEFAULT_SCHEMA_NAME: &str = "dev";
+pub const DEFAULT_SUPER_USER: &str = "root";
+/// The default user of many PostgreSQL clients, which is also created as a superuser.
+pub const DEFAULT_SUPER_USER_FOR_PG: &str = "postgres";
+
+/// Returns whether `user_name` is one of the default superusers, which cannot be dropped or
+/// renamed.
+pub fn is_default_super_user(user_name: &str) -> bool {
+    user_name == DEFAULT_SUPER_USER || user_name == DEFAULT_SUPER_USER_FOR_PG
+}
+
+/// Prefix of the names given to the columns dropped by `ALTER TABLE DROP COLUMN`. A dropped column
+/// is kept in the table as a hidden column, so that the positions of the other columns are stable.
+const DROPPED_COLUMN_PREFIX: &str = "_dropped#";
+
+pub fn gen_dropped_column_name(column_id: ColumnId) -> String {
+    format!("{}{}", DROPPED_COLUMN_PREFIX, column_id)
+}
+
+pub fn is_dropped_column_name(name: &str) -> bool {
+    name.starts_with(DROPPED_COLUMN_PREFIX)
+}
 
 pub type CatalogVersion = u64;
 
@@ -55,6 +55,7 @@ impl ColumnDescTestExt for ColumnDesc {
             name: name.to_string(),
             type_name: type_name.to_string(),
             field_descs: fields,
+            ..Default::default()
         }
     }
 }
@@ -116,6 +116,8 @@ pub enum ErrorCode {
     ItemNotFound(String),
     #[error("Invalid input syntax: {0}")]
     InvalidInputSyntax(String),
+    #[error("Invalid parameter value: {0}")]
+    InvalidParameterValue(String),
     #[error("Can not compare in memory: {0}")]
     MemComparableError(MemComparableError),
     #[error("Error while de/se values: {0}")]
@@ -133,6 +135,21 @@ pub enum ErrorCode {
     #[error("Unknown worker")]
     UnknownWorker,
 
+    #[error("Permission denied: {0}")]
+    PermissionDenied(String),
+
+    #[error("unrecognized configuration parameter \"{0}\"")]
+    UnrecognizedConfigurationParameter(String),
+
+    #[error("canceling statement due to statement timeout")]
+    StatementTimeout,
+
+    #[error("duplicate key value violates unique constraint \"{0}\"")]
+    UniqueViolation(String),
+
+    #[error("null value in column \"{0}\" violates not-null constraint")]
+    NotNullViolation(String),
+
     /// `Eof` represents an upstream node will not generate new data. This error is rare in our
     /// system, currently only used in the `BatchQueryExecutor` as an ephemeral solution.
     #[error("End of the stream")]
@@ -301,6 +318,12 @@ impl ErrorCode {
             ErrorCode::Eof => 22,
             ErrorCode::BindError(_) => 23,
             ErrorCode::UnknownWorker => 24,
+            ErrorCode::PermissionDenied(_) => 25,
+            ErrorCode::UnrecognizedConfigurationParameter(_) => 26,
+            ErrorCode::StatementTimeout => 27,
+            ErrorCode::UniqueViolation(_) => 28,
+            ErrorCode::InvalidParameterValue(_) => 29,
+            ErrorCode::NotNullViolation(_) => 30,
             ErrorCode::UnknownError(_) => 101,
         }
     }
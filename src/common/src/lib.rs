@@ -48,6 +48,7 @@ pub mod collection;
 pub mod config;
 pub mod hash;
 pub mod service;
+pub mod session_config;
 #[cfg(test)]
 pub mod test_utils;
 pub mod types;
@@ -0,0 +1,525 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Session variables, i.e. the run-time parameters of a session which are changed by `SET` and
+//! `RESET` and shown by `SHOW`.
+
+use std::num::NonZeroU64;
+use std::time::Duration;
+
+use chrono_tz::Tz;
+use itertools::Itertools;
+
+use crate::error::{ErrorCode, Result, RwError};
+
+/// The name of the schema standing for the current user in `search_path`.
+pub const USER_NAME_WILD_CARD: &str = "$user";
+
+/// The values of all session variables of a session.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigMap {
+    implicit_flush: bool,
+    force_delta_join: bool,
+    /// In milliseconds, 0 for no timeout.
+    statement_timeout: u64,
+    /// An IANA time zone name like `Asia/Shanghai`, `UTC` or a fixed offset like `+08:00`.
+    timezone: String,
+    search_path: Vec<String>,
+    /// 0 for the default parallelism.
+    batch_parallelism: u64,
+    application_name: String,
+    extra_float_digits: i32,
+    date_style: String,
+    /// Has no effect, see [`SESSION_VARIABLES`].
+    enable_new_subquery_batch_planner: bool,
+}
+
+impl Default for ConfigMap {
+    fn default() -> Self {
+        Self {
+            implicit_flush: false,
+            force_delta_join: false,
+            statement_timeout: 0,
+            timezone: "UTC".to_string(),
+            search_path: vec![USER_NAME_WILD_CARD.to_string(), "public".to_string()],
+            batch_parallelism: 0,
+            application_name: String::new(),
+            extra_float_digits: 1,
+            date_style: "ISO, MDY".to_string(),
+            enable_new_subquery_batch_planner: true,
+        }
+    }
+}
+
+/// A session variable, with its documentation and the conversion of its value from and to a
+/// string.
+pub struct SessionVariable {
+    /// The name of the variable, which is matched case-insensitively.
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Whether the client is notified with a `ParameterStatus` message when the value changes.
+    pub report: bool,
+    /// Validates the value and sets it, returning `None` if the value is invalid.
+    set: fn(&mut ConfigMap, &str) -> Option<()>,
+    get: fn(&ConfigMap) -> String,
+}
+
+impl SessionVariable {
+    pub fn value(&self, config: &ConfigMap) -> String {
+        (self.get)(config)
+    }
+}
+
+/// All the session variables, in the order shown by `SHOW ALL`.
+pub static SESSION_VARIABLES: &[SessionVariable] = &[
+    SessionVariable {
+        name: "application_name",
+        description: "Sets the application name to be reported in statistics and logs.",
+        report: true,
+        set: |c, v| {
+            c.application_name = v.to_string();
+            Some(())
+        },
+        get: |c| c.application_name.clone(),
+    },
+    SessionVariable {
+        name: "batch_parallelism",
+        description: "Sets the parallelism of the stages of batch queries. 0 means the number of \
+                      compute nodes.",
+        report: false,
+        set: |c, v| {
+            c.batch_parallelism = v.parse().ok()?;
+            Some(())
+        },
+        get: |c| c.batch_parallelism.to_string(),
+    },
+    SessionVariable {
+        name: "client_encoding",
+        description: "Sets the client's character set encoding. Only UTF8 is supported.",
+        report: true,
+        set: |_, v| {
+            ["UTF8", "UTF-8", "UNICODE"]
+                .iter()
+                .any(|e| e.eq_ignore_ascii_case(v))
+                .then_some(())
+        },
+        get: |_| "UTF8".to_string(),
+    },
+    SessionVariable {
+        name: "DateStyle",
+        description: "Sets the display format for date and time values. Only ISO is supported.",
+        report: true,
+        set: |c, v| {
+            let mut date_style = ("ISO", "MDY");
+            for part in v.split(',').map(str::trim) {
+                match part.to_ascii_uppercase().as_str() {
+                    "ISO" => {}
+                    "MDY" | "US" | "NONEURO" | "NONEUROPEAN" => date_style.1 = "MDY",
+                    "DMY" | "EURO" | "EUROPEAN" => date_style.1 = "DMY",
+                    "YMD" => date_style.1 = "YMD",
+                    _ => return None,
+                }
+            }
+            c.date_style = format!("{}, {}", date_style.0, date_style.1);
+            Some(())
+        },
+        get: |c| c.date_style.clone(),
+    },
+    SessionVariable {
+        name: "enable_new_subquery_batch_planner",
+        description: "Has no effect. Subqueries are always planned by the new batch planner, and \
+                      the variable is only kept so that existing scripts setting it still work.",
+        report: false,
+        set: |c, v| {
+            c.enable_new_subquery_batch_planner = parse_bool(v)?;
+            Some(())
+        },
+        get: |c| format_bool(c.enable_new_subquery_batch_planner),
+    },
+    SessionVariable {
+        name: "extra_float_digits",
+        description: "Sets the number of digits displayed for floating-point values, from -15 \
+                      to 3.",
+        report: false,
+        set: |c, v| {
+            c.extra_float_digits = v.parse().ok().filter(|d| (-15..=3).contains(d))?;
+            Some(())
+        },
+        get: |c| c.extra_float_digits.to_string(),
+    },
+    SessionVariable {
+        name: "RW_FORCE_DELTA_JOIN",
+        description: "Forces the streaming hash joins to be planned as delta joins.",
+        report: false,
+        set: |c, v| {
+            c.force_delta_join = parse_bool(v)?;
+            Some(())
+        },
+        get: |c| format_bool(c.force_delta_join),
+    },
+    SessionVariable {
+        name: "RW_IMPLICIT_FLUSH",
+        description: "If on, every INSERT, UPDATE and DELETE waits until its changes are \
+                      visible to the following queries.",
+        report: false,
+        set: |c, v| {
+            c.implicit_flush = parse_bool(v)?;
+            Some(())
+        },
+        get: |c| format_bool(c.implicit_flush),
+    },
+    SessionVariable {
+        name: "search_path",
+        description: "Sets the schema search order for names that are not schema-qualified.",
+        report: false,
+        set: |c, v| {
+            c.search_path = parse_search_path(v)?;
+            Some(())
+        },
+        get: |c| format_search_path(&c.search_path),
+    },
+    SessionVariable {
+        name: "server_version",
+        description: "Shows the server version.",
+        report: true,
+        set: |_, v| (v == "9.5.0").then_some(()),
+        get: |_| "9.5.0".to_string(),
+    },
+    SessionVariable {
+        name: "standard_conforming_strings",
+        description: "Causes '...' strings to treat backslashes literally. Only on is supported.",
+        report: true,
+        set: |_, v| parse_bool(v).filter(|on| *on).map(|_| ()),
+        get: |_| "on".to_string(),
+    },
+    SessionVariable {
+        name: "statement_timeout",
+        description: "Sets the maximum allowed duration of any statement, in milliseconds \
+                      unless a unit is given. 0 turns this off.",
+        report: false,
+        set: |c, v| {
+            c.statement_timeout = parse_milliseconds(v)?;
+            Some(())
+        },
+        get: |c| format_milliseconds(c.statement_timeout),
+    },
+    SessionVariable {
+        name: "TimeZone",
+        description: "Sets the time zone for displaying and interpreting time stamps. Either an \
+                      IANA time zone name like Asia/Shanghai, UTC or a fixed offset from UTC like \
+                      +08:00.",
+        report: true,
+        set: |c, v| {
+            c.timezone = normalize_time_zone(v)?;
+            Some(())
+        },
+        get: |c| c.timezone.clone(),
+    },
+    SessionVariable {
+        name: "transaction_isolation",
+        description: "Sets the current transaction's isolation level. Only read committed is \
+                      supported.",
+        report: false,
+        set: |_, v| v.eq_ignore_ascii_case("read committed").then_some(()),
+        get: |_| "read committed".to_string(),
+    },
+];
+
+/// Finds the session variable by name, case-insensitively.
+pub fn get_session_variable(name: &str) -> Result<&'static SessionVariable> {
+    SESSION_VARIABLES
+        .iter()
+        .find(|var| var.name.eq_ignore_ascii_case(name))
+        .ok_or_else(|| ErrorCode::UnrecognizedConfigurationParameter(name.to_string()).into())
+}
+
+impl ConfigMap {
+    /// Sets the variable `name` to `value`, failing if the variable does not exist or the value
+    /// is invalid for it.
+    pub fn set(&mut self, name: &str, value: &str) -> Result<()> {
+        let var = get_session_variable(name)?;
+        (var.set)(self, value).ok_or_else(|| {
+            RwError::from(ErrorCode::InvalidConfigValue {
+                config_entry: var.name.to_string(),
+                config_value: value.to_string(),
+            })
+        })
+    }
+
+    /// Resets the variable `name` to its default value.
+    pub fn reset(&mut self, name: &str) -> Result<()> {
+        let var = get_session_variable(name)?;
+        (var.set)(self, &var.value(&Self::default())).unwrap();
+        Ok(())
+    }
+
+    pub fn reset_all(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Gets the value of the variable `name` as a string.
+    pub fn get(&self, name: &str) -> Result<String> {
+        Ok(get_session_variable(name)?.value(self))
+    }
+
+    /// Returns the names and values of the reported variables whose values differ from `old`.
+    pub fn changed_reported_variables(&self, old: &ConfigMap) -> Vec<(&'static str, String)> {
+        SESSION_VARIABLES
+            .iter()
+            .filter(|var| var.report && var.value(self) != var.value(old))
+            .map(|var| (var.name, var.value(self)))
+            .collect_vec()
+    }
+
+    pub fn get_implicit_flush(&self) -> bool {
+        self.implicit_flush
+    }
+
+    pub fn get_force_delta_join(&self) -> bool {
+        self.force_delta_join
+    }
+
+    pub fn get_statement_timeout(&self) -> Option<Duration> {
+        (self.statement_timeout > 0).then(|| Duration::from_millis(self.statement_timeout))
+    }
+
+    pub fn get_timezone(&self) -> &str {
+        &self.timezone
+    }
+
+    /// Returns the schema names in `search_path`, which may include [`USER_NAME_WILD_CARD`].
+    pub fn get_search_path(&self) -> &[String] {
+        &self.search_path
+    }
+
+    pub fn get_batch_parallelism(&self) -> Option<NonZeroU64> {
+        NonZeroU64::new(self.batch_parallelism)
+    }
+
+    pub fn get_extra_float_digits(&self) -> i32 {
+        self.extra_float_digits
+    }
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.to_ascii_lowercase().as_str() {
+        "true" | "on" | "yes" | "1" => Some(true),
+        "false" | "off" | "no" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+fn format_bool(value: bool) -> String {
+    if value { "on" } else { "off" }.to_string()
+}
+
+const MILLISECOND_UNITS: [(&str, u64); 5] = [
+    ("d", 24 * 60 * 60 * 1000),
+    ("h", 60 * 60 * 1000),
+    ("min", 60 * 1000),
+    ("s", 1000),
+    ("ms", 1),
+];
+
+/// Parses a duration like `100`, `100ms` or `5s` into milliseconds.
+fn parse_milliseconds(value: &str) -> Option<u64> {
+    let value = value.trim();
+    let digits_end = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(digits_end);
+    let number: u64 = number.parse().ok()?;
+    let unit = match unit.trim() {
+        "" => 1,
+        unit => MILLISECOND_UNITS.iter().find(|(u, _)| *u == unit)?.1,
+    };
+    // Keep the same limit as Postgres.
+    number.checked_mul(unit).filter(|ms| *ms <= i32::MAX as u64)
+}
+
+/// Formats milliseconds with the largest unit dividing it, like Postgres.
+fn format_milliseconds(ms: u64) -> String {
+    if ms == 0 {
+        return "0".to_string();
+    }
+    let (unit, size) = MILLISECOND_UNITS
+        .iter()
+        .find(|(_, size)| ms % size == 0)
+        .unwrap();
+    format!("{}{}", ms / size, unit)
+}
+
+/// Parses a time zone, which is either `UTC` or a fixed offset like `+08:00` or `-5`, into the
+/// offset east of UTC in seconds.
+pub fn parse_time_zone_offset(time_zone: &str) -> Option<i32> {
+    let time_zone = time_zone.trim();
+    if ["UTC", "GMT", "Z", "Etc/UTC"]
+        .iter()
+        .any(|tz| tz.eq_ignore_ascii_case(time_zone))
+    {
+        return Some(0);
+    }
+    let (sign, offset) = match time_zone.as_bytes().first()? {
+        b'+' => (1, &time_zone[1..]),
+        b'-' => (-1, &time_zone[1..]),
+        _ => return None,
+    };
+    let (hours, minutes) = offset.split_once(':').unwrap_or((offset, "0"));
+    let hours: i32 = hours.parse().ok().filter(|h| (0..=15).contains(h))?;
+    let minutes: i32 = minutes.parse().ok().filter(|m| (0..60).contains(m))?;
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Parses a time zone accepted by [`parse_time_zone_offset`] or an IANA time zone name, which is
+/// matched case-insensitively, and returns its canonical form.
+pub fn normalize_time_zone(time_zone: &str) -> Option<String> {
+    match parse_time_zone_offset(time_zone) {
+        Some(offset) => Some(format_time_zone_offset(offset)),
+        None => Tz::from_str_insensitive(time_zone.trim())
+            .ok()
+            .map(|tz| tz.name().to_string()),
+    }
+}
+
+fn format_time_zone_offset(offset: i32) -> String {
+    if offset == 0 {
+        return "UTC".to_string();
+    }
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.abs();
+    format!("{}{:02}:{:02}", sign, offset / 3600, offset % 3600 / 60)
+}
+
+/// Parses a comma-separated list of schema names. Unquoted names are folded to lower case.
+fn parse_search_path(value: &str) -> Option<Vec<String>> {
+    if value.trim().is_empty() {
+        return Some(vec![]);
+    }
+    let mut names = vec![];
+    let mut chars = value.chars().peekable();
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        let mut name = String::new();
+        if chars.next_if_eq(&'"').is_some() {
+            loop {
+                match chars.next()? {
+                    '"' if chars.next_if_eq(&'"').is_some() => name.push('"'),
+                    '"' => break,
+                    c => name.push(c),
+                }
+            }
+        } else {
+            while let Some(c) = chars.next_if(|c| *c != ',') {
+                name.push(c.to_ascii_lowercase());
+            }
+            name.truncate(name.trim_end().len());
+            if name.contains(char::is_whitespace) {
+                return None;
+            }
+        }
+        if name.is_empty() {
+            return None;
+        }
+        names.push(name);
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        match chars.next() {
+            Some(',') => continue,
+            Some(_) => return None,
+            None => return Some(names),
+        }
+    }
+}
+
+fn format_search_path(names: &[String]) -> String {
+    names
+        .iter()
+        .map(|name| {
+            let is_simple = name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+                && !name.starts_with(|c: char| c.is_ascii_digit());
+            if is_simple {
+                name.clone()
+            } else {
+                format!("\"{}\"", name.replace('"', "\"\""))
+            }
+        })
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_and_reset() {
+        let mut config = ConfigMap::default();
+        config.set("rw_implicit_flush", "true").unwrap();
+        assert!(config.get_implicit_flush());
+        assert_eq!(config.get("RW_IMPLICIT_FLUSH").unwrap(), "on");
+
+        config.set("statement_timeout", "5s").unwrap();
+        assert_eq!(
+            config.get_statement_timeout(),
+            Some(Duration::from_millis(5000))
+        );
+        assert_eq!(config.get("statement_timeout").unwrap(), "5s");
+        config.set("statement_timeout", "1500").unwrap();
+        assert_eq!(config.get("statement_timeout").unwrap(), "1500ms");
+
+        config.set("timezone", "+8").unwrap();
+        assert_eq!(config.get_timezone(), "+08:00");
+        config.set("TimeZone", "utc").unwrap();
+        assert_eq!(config.get_timezone(), "UTC");
+        config.set("timezone", "asia/shanghai").unwrap();
+        assert_eq!(config.get_timezone(), "Asia/Shanghai");
+
+        config.set("search_path", "\"My Schema\", PUBLIC").unwrap();
+        assert_eq!(config.get_search_path(), ["My Schema", "public"]);
+        assert_eq!(config.get("search_path").unwrap(), "\"My Schema\", public");
+
+        let old = config.clone();
+        config.set("application_name", "psql").unwrap();
+        config.set("batch_parallelism", "4").unwrap();
+        assert_eq!(
+            config.changed_reported_variables(&old),
+            vec![("application_name", "psql".to_string())]
+        );
+
+        config.reset("search_path").unwrap();
+        assert_eq!(config.get("search_path").unwrap(), "\"$user\", public");
+        config.reset_all();
+        assert_eq!(config, ConfigMap::default());
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        let mut config = ConfigMap::default();
+        assert!(config.set("no_such_variable", "1").is_err());
+        assert!(config.reset("no_such_variable").is_err());
+        assert!(config.set("rw_implicit_flush", "maybe").is_err());
+        assert!(config.set("extra_float_digits", "4").is_err());
+        assert!(config.set("statement_timeout", "-1").is_err());
+        assert!(config.set("statement_timeout", "1 week").is_err());
+        assert!(config.set("timezone", "Mars/Olympus_Mons").is_err());
+        assert!(config.set("timezone", "+16:00").is_err());
+        assert!(config.set("search_path", "a, , b").is_err());
+        assert!(config.set("search_path", "\"a").is_err());
+        assert!(config.set("search_path", "a b").is_err());
+        assert!(config.set("client_encoding", "LATIN1").is_err());
+        assert!(config.set("server_version", "14.0").is_err());
+        assert!(config.set("transaction_isolation", "serializable").is_err());
+        assert_eq!(config, ConfigMap::default());
+    }
+}
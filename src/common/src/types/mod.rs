@@ -33,6 +33,7 @@ pub use scalar_impl::*;
 mod chrono_wrapper;
 mod decimal;
 pub mod interval;
+mod uuid;
 
 mod ordered_float;
 
@@ -45,10 +46,12 @@ pub use interval::*;
 use itertools::Itertools;
 pub use ops::CheckedAdd;
 pub use ordered_float::IntoOrdered;
+pub use self::uuid::Uuid;
 use paste::paste;
 
 use crate::array::{
-    ArrayBuilderImpl, ListRef, ListValue, PrimitiveArrayItemType, StructRef, StructValue,
+    ArrayBuilderImpl, JsonbRef, JsonbVal, ListRef, ListValue, PrimitiveArrayItemType, StructRef,
+    StructValue,
 };
 
 pub type OrderedF32 = ordered_float::OrderedFloat<f32>;
@@ -71,6 +74,13 @@ pub enum DataType {
     Interval,
     Struct { fields: Arc<[DataType]> },
     List { datatype: Box<DataType> },
+    Jsonb,
+    Bytea,
+    Uuid,
+    /// Fixed-width, blank-padded string. It shares the physical representation of `Varchar`.
+    Char {
+        len: u32,
+    },
 }
 
 const DECIMAL_DEFAULT_PRECISION: u32 = 20;
@@ -109,6 +119,12 @@ impl From<&ProstDataType> for DataType {
             TypeName::List => DataType::List {
                 datatype: Box::new((&proto.field_type[0]).into()),
             },
+            TypeName::Jsonb => DataType::Jsonb,
+            TypeName::Bytea => DataType::Bytea,
+            TypeName::Uuid => DataType::Uuid,
+            TypeName::Char => DataType::Char {
+                len: proto.precision,
+            },
         }
     }
 }
@@ -144,6 +160,10 @@ impl DataType {
                 },
             )?
             .into(),
+            DataType::Jsonb => JsonbArrayBuilder::new(capacity)?.into(),
+            DataType::Bytea => BytesArrayBuilder::new(capacity)?.into(),
+            DataType::Uuid => UuidArrayBuilder::new(capacity)?.into(),
+            DataType::Char { .. } => Utf8ArrayBuilder::new(capacity)?.into(),
         })
     }
 
@@ -164,6 +184,10 @@ impl DataType {
             DataType::Interval => TypeName::Interval,
             DataType::Struct { .. } => TypeName::Struct,
             DataType::List { .. } => TypeName::List,
+            DataType::Jsonb => TypeName::Jsonb,
+            DataType::Bytea => TypeName::Bytea,
+            DataType::Uuid => TypeName::Uuid,
+            DataType::Char { .. } => TypeName::Char,
         }
     }
 
@@ -179,10 +203,15 @@ impl DataType {
                 }
             }
         };
+        let precision = match self {
+            DataType::Char { len } => *len,
+            _ => 0,
+        };
         ProstDataType {
             type_name: self.prost_type_name() as i32,
             is_nullable: true,
             field_type,
+            precision,
             ..Default::default()
         }
     }
@@ -205,6 +234,10 @@ impl DataType {
             DataType::Interval => DataSize::Variable,
             DataType::Struct { .. } => DataSize::Variable,
             DataType::List { .. } => DataSize::Variable,
+            DataType::Jsonb => DataSize::Variable,
+            DataType::Bytea => DataSize::Variable,
+            DataType::Uuid => DataSize::Fixed(size_of::<Uuid>()),
+            DataType::Char { .. } => DataSize::Variable,
         }
     }
 
@@ -295,7 +328,10 @@ macro_rules! for_all_scalar_variants {
             { NaiveDateTime, naivedatetime, NaiveDateTimeWrapper, NaiveDateTimeWrapper },
             { NaiveTime, naivetime, NaiveTimeWrapper, NaiveTimeWrapper },
             { Struct, struct, StructValue, StructRef<'scalar> },
-            { List, list, ListValue, ListRef<'scalar> }
+            { List, list, ListValue, ListRef<'scalar> },
+            { Jsonb, jsonb, JsonbVal, JsonbRef<'scalar> },
+            { Bytea, bytea, Box<[u8]>, &'scalar [u8] },
+            { Uuid, uuid, Uuid, Uuid }
         }
     };
 }
@@ -568,6 +604,9 @@ impl std::hash::Hash for ScalarImpl {
                     Self::NaiveTime(naivetime) => naivetime.hash(state),
                     Self::Struct(v) => v.hash(state),
                     Self::List(v) => v.hash(state),
+                    Self::Jsonb(v) => v.hash(state),
+                    Self::Bytea(v) => v.hash(state),
+                    Self::Uuid(v) => v.hash(state),
                 }
             };
         }
@@ -575,13 +614,25 @@ impl std::hash::Hash for ScalarImpl {
     }
 }
 
+/// Formats a scalar in its Postgres text representation. Raw bytes don't implement `Display`, so
+/// `bytea` is special-cased to the hex format, e.g. `\x0a1b`.
+macro_rules! display_scalar {
+    (Bytea, $inner:expr, $f:expr) => {{
+        write!($f, "\\x")?;
+        $inner.iter().try_for_each(|b| write!($f, "{:02x}", b))
+    }};
+    ($variant_name:ident, $inner:expr, $f:expr) => {
+        Display::fmt($inner, $f)
+    };
+}
+
 impl Display for ScalarImpl {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         macro_rules! impl_display_fmt {
             ([], $( { $variant_name:ident, $suffix_name:ident, $scalar:ty, $scalar_ref:ty } ),*) => {
                 match self {
                     $( Self::$variant_name(ref inner) => {
-                        Display::fmt(inner, f)
+                        display_scalar!($variant_name, inner, f)
                     }, )*
                 }
             }
@@ -597,7 +648,7 @@ impl Display for ScalarRefImpl<'_> {
             ([], $( { $variant_name:ident, $suffix_name:ident, $scalar:ty, $scalar_ref:ty } ),*) => {
                 match self {
                     $( Self::$variant_name(inner) => {
-                        Display::fmt(inner, f)
+                        display_scalar!($variant_name, inner, f)
                     }, )*
                 }
             }
@@ -632,6 +683,9 @@ impl ScalarRefImpl<'_> {
             &Self::NaiveTime(v) => {
                 ser.serialize_naivetime(v.0.num_seconds_from_midnight(), v.0.nanosecond())?
             }
+            Self::Jsonb(v) => v.memcmp_serialize(ser)?,
+            &Self::Bytea(v) => serde::Serializer::serialize_bytes(ser, v)?,
+            &Self::Uuid(v) => v.memcmp_serialize(ser)?,
             _ => {
                 panic!("Type is unable to be serialized.")
             }
@@ -661,7 +715,7 @@ impl ScalarImpl {
             Ty::Int64 => Self::Int64(i64::deserialize(de)?),
             Ty::Float32 => Self::Float32(f32::deserialize(de)?.into()),
             Ty::Float64 => Self::Float64(f64::deserialize(de)?.into()),
-            Ty::Varchar => Self::Utf8(String::deserialize(de)?),
+            Ty::Varchar | Ty::Char { .. } => Self::Utf8(String::deserialize(de)?),
             Ty::Boolean => Self::Bool(bool::deserialize(de)?),
             Ty::Decimal => Self::Decimal({
                 let (mantissa, scale) = de.deserialize_decimal()?;
@@ -686,6 +740,9 @@ impl ScalarImpl {
                 let days = de.deserialize_naivedate()?;
                 NaiveDateWrapper::with_days(days)?
             }),
+            Ty::Jsonb => Self::Jsonb(JsonbVal::memcmp_deserialize(de)?),
+            Ty::Bytea => Self::Bytea(de.read_bytes()?.into()),
+            Ty::Uuid => Self::Uuid(Uuid::memcmp_deserialize(de)?),
             _ => {
                 panic!("Type is unable to be deserialized.")
             }
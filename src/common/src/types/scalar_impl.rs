@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::*;
+use crate::array::jsonb_array::{JsonbRef, JsonbVal};
 use crate::array::list_array::{ListRef, ListValue};
 use crate::array::struct_array::{StructRef, StructValue};
 use crate::{for_all_native_types, for_all_scalar_variants};
@@ -94,6 +95,33 @@ impl Scalar for ListValue {
     }
 }
 
+/// Implement `Scalar` for `JsonbVal`.
+impl Scalar for JsonbVal {
+    type ScalarRefType<'a> = JsonbRef<'a>;
+
+    fn as_scalar_ref(&self) -> JsonbRef<'_> {
+        JsonbRef(&self.0)
+    }
+
+    fn to_scalar_value(self) -> ScalarImpl {
+        ScalarImpl::Jsonb(self)
+    }
+}
+
+/// Implement `Scalar` for `Box<[u8]>`.
+/// `Box<[u8]>` could be converted to `&[u8]`.
+impl Scalar for Box<[u8]> {
+    type ScalarRefType<'a> = &'a [u8];
+
+    fn as_scalar_ref(&self) -> &[u8] {
+        self
+    }
+
+    fn to_scalar_value(self) -> ScalarImpl {
+        ScalarImpl::Bytea(self)
+    }
+}
+
 /// Implement `ScalarRef` for `String`.
 /// `String` could be converted to `&str`.
 impl<'a> ScalarRef<'a> for &'a str {
@@ -110,6 +138,12 @@ impl ScalarPartialOrd for Decimal {
     }
 }
 
+impl ScalarPartialOrd for Box<[u8]> {
+    fn scalar_cmp(&self, other: &[u8]) -> Option<std::cmp::Ordering> {
+        self.as_ref().partial_cmp(other)
+    }
+}
+
 impl ScalarPartialOrd for String {
     fn scalar_cmp(&self, other: &str) -> Option<std::cmp::Ordering> {
         self.as_str().partial_cmp(other)
@@ -128,6 +162,12 @@ impl ScalarPartialOrd for bool {
     }
 }
 
+impl ScalarPartialOrd for JsonbVal {
+    fn scalar_cmp(&self, other: JsonbRef<'_>) -> Option<std::cmp::Ordering> {
+        self.as_scalar_ref().partial_cmp(&other)
+    }
+}
+
 /// Implement `Scalar` for `bool`.
 impl Scalar for bool {
     type ScalarRefType<'a> = bool;
@@ -289,6 +329,46 @@ impl<'a> ScalarRef<'a> for ListRef<'a> {
     }
 }
 
+/// Implement `ScalarRef` for `&[u8]`.
+impl<'a> ScalarRef<'a> for &'a [u8] {
+    type ScalarType = Box<[u8]>;
+
+    fn to_owned_scalar(&self) -> Box<[u8]> {
+        (*self).into()
+    }
+}
+
+/// Implement `Scalar` for `Uuid`.
+impl Scalar for Uuid {
+    type ScalarRefType<'a> = Uuid;
+
+    fn as_scalar_ref(&self) -> Uuid {
+        *self
+    }
+
+    fn to_scalar_value(self) -> ScalarImpl {
+        ScalarImpl::Uuid(self)
+    }
+}
+
+/// Implement `ScalarRef` for `Uuid`.
+impl<'a> ScalarRef<'a> for Uuid {
+    type ScalarType = Uuid;
+
+    fn to_owned_scalar(&self) -> Uuid {
+        *self
+    }
+}
+
+/// Implement `ScalarRef` for `JsonbRef`.
+impl<'a> ScalarRef<'a> for JsonbRef<'a> {
+    type ScalarType = JsonbVal;
+
+    fn to_owned_scalar(&self) -> JsonbVal {
+        JsonbVal::from_serde(self.0.clone())
+    }
+}
+
 impl ScalarImpl {
     pub fn get_ident(&self) -> &'static str {
         macro_rules! impl_all_get_ident {
@@ -0,0 +1,166 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+use std::str::FromStr;
+
+use bytes::{Buf, BufMut};
+use serde::{Deserialize, Serialize};
+
+use crate::error::ErrorCode::{InvalidInputSyntax, IoError};
+use crate::error::{Result, RwError};
+
+/// A 128-bit universally unique identifier, stored as a big-endian integer so that the derived
+/// ordering matches the byte-wise ordering of Postgres.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Uuid(pub u128);
+
+impl Uuid {
+    pub fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self(u128::from_be_bytes(bytes))
+    }
+
+    pub fn to_bytes(self) -> [u8; 16] {
+        self.0.to_be_bytes()
+    }
+
+    pub fn to_protobuf<T: Write>(self, output: &mut T) -> Result<usize> {
+        output
+            .write(&self.to_bytes())
+            .map_err(|e| RwError::from(IoError(e)))
+    }
+
+    pub fn from_protobuf(bytes: &[u8]) -> Result<Self> {
+        let bytes: [u8; 16] = bytes.try_into().map_err(|_| {
+            RwError::from(InvalidInputSyntax(format!(
+                "invalid length for uuid: {}",
+                bytes.len()
+            )))
+        })?;
+        Ok(Self::from_bytes(bytes))
+    }
+
+    /// Serialize the uuid into a memcomparable encoding.
+    pub fn memcmp_serialize(
+        self,
+        ser: &mut memcomparable::Serializer<impl BufMut>,
+    ) -> memcomparable::Result<()> {
+        ((self.0 >> 64) as u64).serialize(&mut *ser)?;
+        (self.0 as u64).serialize(ser)
+    }
+
+    /// Deserialize the memcomparable encoding written by [`Uuid::memcmp_serialize`].
+    pub fn memcmp_deserialize(
+        de: &mut memcomparable::Deserializer<impl Buf>,
+    ) -> memcomparable::Result<Self> {
+        let high = u64::deserialize(&mut *de)?;
+        let low = u64::deserialize(de)?;
+        Ok(Self(((high as u128) << 64) | low as u128))
+    }
+}
+
+impl FromStr for Uuid {
+    type Err = RwError;
+
+    /// Accepts the standard hyphenated form, optionally wrapped in braces, as well as the plain
+    /// 32-digit hex form. Hyphens may also appear after any group of four digits, as Postgres
+    /// does.
+    fn from_str(s: &str) -> Result<Self> {
+        let err = || RwError::from(InvalidInputSyntax(format!("invalid input for uuid: {}", s)));
+        let inner = match s.strip_prefix('{') {
+            Some(inner) => inner.strip_suffix('}').ok_or_else(err)?,
+            None => s,
+        };
+        let mut value = 0u128;
+        let mut digits = 0;
+        let mut after_hyphen = false;
+        for c in inner.chars() {
+            if c == '-' && digits > 0 && digits % 4 == 0 && !after_hyphen {
+                after_hyphen = true;
+                continue;
+            }
+            after_hyphen = false;
+            let d = c.to_digit(16).ok_or_else(err)?;
+            if digits == 32 {
+                return Err(err());
+            }
+            value = (value << 4) | d as u128;
+            digits += 1;
+        }
+        if digits != 32 || after_hyphen {
+            return Err(err());
+        }
+        Ok(Self(value))
+    }
+}
+
+impl Display for Uuid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let v = self.0;
+        write!(
+            f,
+            "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+            (v >> 96) as u32,
+            (v >> 80) as u16,
+            (v >> 64) as u16,
+            (v >> 48) as u16,
+            v as u64 & 0xffff_ffff_ffff
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uuid_parse_and_display() {
+        let expected = "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11";
+        for input in [
+            "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11",
+            "A0EEBC99-9C0B-4EF8-BB6D-6BB9BD380A11",
+            "{a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11}",
+            "a0eebc999c0b4ef8bb6d6bb9bd380a11",
+            "a0ee-bc99-9c0b-4ef8-bb6d-6bb9-bd38-0a11",
+        ] {
+            assert_eq!(Uuid::from_str(input).unwrap().to_string(), expected);
+        }
+        for input in [
+            "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a1",
+            "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a111",
+            "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a1g",
+            "{a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11",
+            "-a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11",
+            "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11-",
+            "a0eebc99--9c0b-4ef8-bb6d-6bb9bd380a11",
+        ] {
+            assert!(Uuid::from_str(input).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_uuid_memcmp_order() {
+        let a = Uuid::from_str("00000000-0000-0000-ffff-ffffffffffff").unwrap();
+        let b = Uuid::from_str("00000000-0000-0001-0000-000000000000").unwrap();
+        let encode = |v: Uuid| {
+            let mut ser = memcomparable::Serializer::new(vec![]);
+            v.memcmp_serialize(&mut ser).unwrap();
+            ser.into_inner()
+        };
+        assert!(encode(a) < encode(b));
+        let mut de = memcomparable::Deserializer::new(encode(b).as_slice());
+        assert_eq!(Uuid::memcmp_deserialize(&mut de).unwrap(), b);
+    }
+}
@@ -75,6 +75,16 @@ impl OrderedRowSerializer {
         Self { order_types }
     }
 
+    pub fn order_types(&self) -> &[OrderType] {
+        &self.order_types
+    }
+
+    /// Returns a serializer for the first `len` columns of the rows serialized by this one.
+    #[must_use]
+    pub fn prefix(&self, len: usize) -> Self {
+        Self::new(self.order_types[..len].to_vec())
+    }
+
     pub fn serialize(&self, row: &Row, append_to: &mut Vec<u8>) {
         self.serialize_datums(row.values(), append_to)
     }
@@ -149,14 +159,17 @@ type ValueBytes = Vec<u8>;
 /// and value. If all data of this row are null, there will be one cell of column id `-1` to
 /// represent a row of all null values.
 ///
+/// The value of the sentinel cell is the largest column id written with this row. Readers use it
+/// to tell a column added afterwards (which should read as its default value) from a null cell.
+///
 /// The returned value is an iterator of `column_ids.len() + 1` length, where the first n items are
 /// serialization result of the `column_id` items correspondingly, and the last item is the sentinel
 /// cell.
 ///
 /// If a cell is null, the corresponding position of that cell will be `None`. For example,
 ///
-/// * Serialize [0, 1, 2] => [Some((pk, 0)), Some((pk, 1)), Some((pk, 2)), Some((pk, []))]
-/// * Serialize [null, null, null] => [None, None, None, Some((pk, []))]
+/// * Serialize [0, 1, 2] => [Some((pk, 0)), Some((pk, 1)), Some((pk, 2)), Some((pk, max_id))]
+/// * Serialize [null, null, null] => [None, None, None, Some((pk, max_id))]
 pub fn serialize_pk_and_row(
     pk_buf: &[u8],
     row: &Row,
@@ -182,7 +195,11 @@ pub fn serialize_pk_and_row(
     }
 
     let key = serialize_pk_and_column_id(pk_buf, &SENTINEL_CELL_ID)?;
-    result.push(Some((key, vec![])));
+    let value = match column_ids.iter().max_by_key(|id| id.get_id()) {
+        Some(max_column_id) => serialize_column_id(max_column_id)?,
+        None => vec![],
+    };
+    result.push(Some((key, value)));
 
     Ok(result)
 }
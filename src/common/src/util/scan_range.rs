@@ -0,0 +1,148 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Bound::{self, *};
+
+use risingwave_pb::batch_plan::scan_range::Bound as BoundProst;
+use risingwave_pb::batch_plan::ScanRange as ScanRangeProst;
+
+use crate::error::ErrorCode::InternalError;
+use crate::error::Result;
+use crate::types::{DataType, Datum, ScalarImpl};
+use crate::util::value_encoding::{deserialize_cell, serialize_cell};
+
+/// A range of the primary key of a table to scan: the rows whose leading primary key columns equal
+/// to `eq_conds`, and whose next primary key column is within `range`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScanRange {
+    pub eq_conds: Vec<Datum>,
+    pub range: (Bound<ScalarImpl>, Bound<ScalarImpl>),
+}
+
+impl ScanRange {
+    pub fn full_table_scan() -> Self {
+        Self {
+            eq_conds: vec![],
+            range: (Unbounded, Unbounded),
+        }
+    }
+
+    pub fn is_full_table_scan(&self) -> bool {
+        self.eq_conds.is_empty() && self.range == (Unbounded, Unbounded)
+    }
+
+    /// Whether at most one row of a table with `pk_len` primary key columns is in the range.
+    pub fn is_point_get(&self, pk_len: usize) -> bool {
+        self.eq_conds.len() >= pk_len
+    }
+
+    pub fn to_protobuf(&self) -> Result<ScanRangeProst> {
+        let bound_to_protobuf = |bound: &Bound<ScalarImpl>| -> Result<Option<BoundProst>> {
+            let (value, inclusive) = match bound {
+                Included(value) => (value, true),
+                Excluded(value) => (value, false),
+                Unbounded => return Ok(None),
+            };
+            Ok(Some(BoundProst {
+                value: serialize_cell(&Some(value.clone()))?,
+                inclusive,
+            }))
+        };
+        Ok(ScanRangeProst {
+            eq_conds: self
+                .eq_conds
+                .iter()
+                .map(serialize_cell)
+                .collect::<Result<_>>()?,
+            lower_bound: bound_to_protobuf(&self.range.0)?,
+            upper_bound: bound_to_protobuf(&self.range.1)?,
+        })
+    }
+
+    /// Decodes a scan range on a primary key whose columns are of `pk_types`.
+    pub fn from_protobuf(prost: &ScanRangeProst, pk_types: &[DataType]) -> Result<Self> {
+        if prost.eq_conds.len() > pk_types.len()
+            || (prost.eq_conds.len() == pk_types.len()
+                && (prost.lower_bound.is_some() || prost.upper_bound.is_some()))
+        {
+            return Err(InternalError(format!("invalid scan range: {:?}", prost)).into());
+        }
+
+        let eq_conds = prost
+            .eq_conds
+            .iter()
+            .zip(pk_types)
+            .map(|(value, data_type)| deserialize_cell(&value[..], data_type))
+            .collect::<Result<_>>()?;
+        let bound_from_protobuf = |bound: &Option<BoundProst>| -> Result<Bound<ScalarImpl>> {
+            let bound = match bound {
+                Some(bound) => bound,
+                None => return Ok(Unbounded),
+            };
+            let value = deserialize_cell(&bound.value[..], &pk_types[prost.eq_conds.len()])?
+                .ok_or_else(|| InternalError("scan range bound cannot be null".to_string()))?;
+            Ok(if bound.inclusive {
+                Included(value)
+            } else {
+                Excluded(value)
+            })
+        };
+        Ok(Self {
+            eq_conds,
+            range: (
+                bound_from_protobuf(&prost.lower_bound)?,
+                bound_from_protobuf(&prost.upper_bound)?,
+            ),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan_range_protobuf() {
+        let pk_types = vec![DataType::Int32, DataType::Varchar, DataType::Int64];
+        let scan_range = ScanRange {
+            eq_conds: vec![
+                Some(ScalarImpl::Int32(1)),
+                Some(ScalarImpl::Utf8("a".into())),
+            ],
+            range: (Excluded(ScalarImpl::Int64(2)), Unbounded),
+        };
+        let prost = scan_range.to_protobuf().unwrap();
+        assert_eq!(
+            ScanRange::from_protobuf(&prost, &pk_types).unwrap(),
+            scan_range
+        );
+
+        let point_get = ScanRange {
+            eq_conds: vec![
+                Some(ScalarImpl::Int32(1)),
+                Some(ScalarImpl::Utf8("a".into())),
+                Some(ScalarImpl::Int64(2)),
+            ],
+            range: (Unbounded, Unbounded),
+        };
+        assert!(point_get.is_point_get(pk_types.len()));
+        let prost = point_get.to_protobuf().unwrap();
+        assert_eq!(
+            ScanRange::from_protobuf(&prost, &pk_types).unwrap(),
+            point_get
+        );
+
+        assert!(ScanRange::full_table_scan().is_full_table_scan());
+    }
+}
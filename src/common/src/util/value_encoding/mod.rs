@@ -18,10 +18,11 @@
 use bytes::{Buf, BufMut};
 use chrono::{Datelike, Timelike};
 
+use crate::array::{JsonbVal, ListRef, ListValue};
 use crate::error::{Result, RwError};
 use crate::types::{
     DataType, Datum, Decimal, IntervalUnit, NaiveDateTimeWrapper, NaiveDateWrapper,
-    NaiveTimeWrapper, OrderedF32, OrderedF64, ScalarImpl, ScalarRefImpl,
+    NaiveTimeWrapper, OrderedF32, OrderedF64, ScalarImpl, ScalarRefImpl, Uuid,
 };
 
 pub mod error;
@@ -62,6 +63,10 @@ fn serialize_value(value: ScalarRefImpl, mut buf: impl BufMut) {
         ScalarRefImpl::NaiveTime(v) => {
             serialize_naivetime(v.0.num_seconds_from_midnight(), v.0.nanosecond(), buf)
         }
+        ScalarRefImpl::Jsonb(v) => serialize_str(v.to_json_text().as_bytes(), buf),
+        ScalarRefImpl::Bytea(v) => serialize_str(v, buf),
+        ScalarRefImpl::Uuid(v) => buf.put_u128_le(v.0),
+        ScalarRefImpl::List(v) => serialize_list(v, &mut buf),
         _ => {
             panic!("Type is unable to be serialized.")
         }
@@ -73,6 +78,22 @@ fn serialize_str(bytes: &[u8], mut buf: impl BufMut) {
     buf.put_slice(bytes);
 }
 
+/// Lists are encoded as the number of elements followed by the elements, each of which is prefixed
+/// by a null flag.
+fn serialize_list(list: ListRef<'_>, buf: &mut dyn BufMut) {
+    let values = list.values_ref();
+    buf.put_u32_le(values.len() as u32);
+    for value in values {
+        match value {
+            Some(value) => {
+                buf.put_u8(1);
+                serialize_value(value, &mut *buf);
+            }
+            None => buf.put_u8(0),
+        }
+    }
+}
+
 fn serialize_interval(interval: &IntervalUnit, mut buf: impl BufMut) {
     buf.put_i32_le(interval.get_months());
     buf.put_i32_le(interval.get_days());
@@ -104,7 +125,7 @@ fn deserialize_value(ty: &DataType, mut data: impl Buf) -> Result<Datum> {
         DataType::Int64 => ScalarImpl::Int64(data.get_i64_le()),
         DataType::Float32 => ScalarImpl::Float32(OrderedF32::from(data.get_f32_le())),
         DataType::Float64 => ScalarImpl::Float64(OrderedF64::from(data.get_f64_le())),
-        DataType::Varchar => ScalarImpl::Utf8(deserialize_str(data)?),
+        DataType::Varchar | DataType::Char { .. } => ScalarImpl::Utf8(deserialize_str(data)?),
         DataType::Boolean => ScalarImpl::Bool(deserialize_bool(data)?),
         DataType::Decimal => ScalarImpl::Decimal(deserialize_decimal(data)?),
         DataType::Interval => ScalarImpl::Interval(deserialize_interval(data)?),
@@ -112,17 +133,45 @@ fn deserialize_value(ty: &DataType, mut data: impl Buf) -> Result<Datum> {
         DataType::Timestamp => ScalarImpl::NaiveDateTime(deserialize_naivedatetime(data)?),
         DataType::Timestampz => ScalarImpl::Int64(data.get_i64_le()),
         DataType::Date => ScalarImpl::NaiveDate(deserialize_naivedate(data)?),
+        DataType::Jsonb => ScalarImpl::Jsonb(deserialize_jsonb(data)?),
+        DataType::Bytea => ScalarImpl::Bytea(deserialize_bytes(data).into()),
+        DataType::Uuid => ScalarImpl::Uuid(Uuid(data.get_u128_le())),
+        DataType::List { ref datatype } => ScalarImpl::List(deserialize_list(datatype, &mut data)?),
         _ => {
             panic!("Type is unable to be deserialized.")
         }
     }))
 }
 
-fn deserialize_str(mut data: impl Buf) -> Result<String> {
+fn deserialize_str(data: impl Buf) -> Result<String> {
+    let bytes = deserialize_bytes(data);
+    Ok(String::from_utf8(bytes).map_err(ValueEncodingError::InvalidUtf8)?)
+}
+
+fn deserialize_bytes(mut data: impl Buf) -> Vec<u8> {
     let len = data.get_u32_le();
     let mut bytes = vec![0; len as usize];
     data.copy_to_slice(&mut bytes);
-    Ok(String::from_utf8(bytes).map_err(ValueEncodingError::InvalidUtf8)?)
+    bytes
+}
+
+fn deserialize_jsonb(data: impl Buf) -> Result<JsonbVal> {
+    let text = deserialize_str(data)?;
+    let value = serde_json::from_str(&text).map_err(ValueEncodingError::InvalidJsonbEncoding)?;
+    Ok(JsonbVal::from_serde(value))
+}
+
+fn deserialize_list(item_type: &DataType, data: &mut dyn Buf) -> Result<ListValue> {
+    let len = data.get_u32_le();
+    let mut values = Vec::with_capacity(len as usize);
+    for _ in 0..len {
+        let value = match data.get_u8() {
+            0 => None,
+            _ => deserialize_value(item_type, &mut *data)?,
+        };
+        values.push(value);
+    }
+    Ok(ListValue::new(values))
 }
 
 fn deserialize_bool(mut data: impl Buf) -> Result<bool> {
@@ -1,3 +1,4 @@
+use std::ops::Bound::*;
 use std::sync::Arc;
 
 // Copyright 2022 Singularity Data
@@ -19,7 +20,8 @@ use risingwave_batch::executor2::{Executor2, RowSeqScanExecutor2};
 use risingwave_common::array::{Array, Row};
 use risingwave_common::catalog::{ColumnDesc, ColumnId, Field, Schema};
 use risingwave_common::error::Result;
-use risingwave_common::types::DataType;
+use risingwave_common::types::{DataType, ScalarImpl};
+use risingwave_common::util::scan_range::ScanRange;
 use risingwave_common::util::sort_util::OrderType;
 use risingwave_storage::memory::MemoryStateStore;
 use risingwave_storage::monitor::StateStoreMetrics;
@@ -60,6 +62,7 @@ async fn test_row_seq_scan() -> Result<()> {
 
     let executor = Box::new(RowSeqScanExecutor2::new(
         table,
+        vec![],
         1,
         true,
         "RowSeqScanExecutor2".to_string(),
@@ -137,3 +140,76 @@ async fn test_row_seq_scan() -> Result<()> {
     );
     Ok(())
 }
+
+#[tokio::test]
+async fn test_row_seq_scan_with_scan_ranges() -> Result<()> {
+    let memory_state_store = MemoryStateStore::new();
+    let keyspace = Keyspace::executor_root(memory_state_store.clone(), 0x42);
+
+    let column_descs = vec![
+        ColumnDesc::unnamed(ColumnId::from(0), DataType::Int32),
+        ColumnDesc::unnamed(ColumnId::from(1), DataType::Int32),
+    ];
+    let mut state = StateTable::new(
+        keyspace.clone(),
+        column_descs.clone(),
+        vec![OrderType::Descending],
+    );
+    let epoch: u64 = 0;
+    for i in 1..=5_i32 {
+        state
+            .insert(
+                Row(vec![Some(i.into())]),
+                Row(vec![Some(i.into()), Some((i * 10).into())]),
+            )
+            .unwrap();
+    }
+    state.commit(epoch).await.unwrap();
+
+    let table = CellBasedTable::new_for_test(keyspace, column_descs, vec![OrderType::Descending]);
+    let scan_ranges = vec![
+        // 1 < pk <= 3
+        ScanRange {
+            eq_conds: vec![],
+            range: (
+                Excluded(ScalarImpl::Int32(1)),
+                Included(ScalarImpl::Int32(3)),
+            ),
+        },
+        // pk = 5
+        ScanRange {
+            eq_conds: vec![Some(ScalarImpl::Int32(5))],
+            range: (Unbounded, Unbounded),
+        },
+        // pk = 6
+        ScanRange {
+            eq_conds: vec![Some(ScalarImpl::Int32(6))],
+            range: (Unbounded, Unbounded),
+        },
+    ];
+    let executor = Box::new(RowSeqScanExecutor2::new(
+        table,
+        scan_ranges,
+        1024,
+        true,
+        "RowSeqScanExecutor2".to_string(),
+        u64::MAX,
+        Arc::new(BatchMetrics::unused()),
+    ));
+
+    let mut stream = executor.execute();
+    let mut rows = vec![];
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        rows.extend(
+            chunk
+                .column_at(1)
+                .array()
+                .as_int32()
+                .iter()
+                .collect::<Vec<_>>(),
+        );
+    }
+    assert_eq!(rows, vec![Some(30), Some(20), Some(50)]);
+    Ok(())
+}
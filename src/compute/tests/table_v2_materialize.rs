@@ -152,10 +152,12 @@ async fn test_table_v2_materialize() -> Result<()> {
     let keyspace = Keyspace::table_root(memory_state_store.clone(), &source_table_id);
     let mut materialize = MaterializeExecutor::new(
         Box::new(stream_source),
+        source_table_id,
         keyspace.clone(),
         vec![OrderPair::new(0, OrderType::Ascending)],
         all_column_ids.clone(),
         2,
+        false,
     )
     .boxed()
     .execute();
@@ -173,6 +175,7 @@ async fn test_table_v2_materialize() -> Result<()> {
         source_table_id,
         source_manager.clone(),
         insert_inner,
+        vec![],
     ));
 
     tokio::spawn(async move {
@@ -190,6 +193,7 @@ async fn test_table_v2_materialize() -> Result<()> {
             name: field.name,
             field_descs: vec![],
             type_name: "".to_string(),
+            default_value: None,
         })
         .collect_vec();
 
@@ -203,6 +207,7 @@ async fn test_table_v2_materialize() -> Result<()> {
 
     let scan = Box::new(RowSeqScanExecutor2::new(
         table.clone(),
+        vec![],
         1024,
         true,
         "RowSeqExecutor2".to_string(),
@@ -262,6 +267,7 @@ async fn test_table_v2_materialize() -> Result<()> {
     // Scan the table again, we are able to get the data now!
     let scan = Box::new(RowSeqScanExecutor2::new(
         table.clone(),
+        vec![],
         1024,
         true,
         "RowSeqScanExecutor2".to_string(),
@@ -331,6 +337,7 @@ async fn test_table_v2_materialize() -> Result<()> {
     // Scan the table again, we are able to see the deletion now!
     let scan = Box::new(RowSeqScanExecutor2::new(
         table.clone(),
+        vec![],
         1024,
         true,
         "RowSeqScanExecutor2".to_string(),
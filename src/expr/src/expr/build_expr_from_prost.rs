@@ -12,23 +12,51 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use risingwave_common::array::DataChunk;
+use chrono::Utc;
+use risingwave_common::array::{
+    DataChunk, DecimalArray, I32Array, I64Array, IntervalArray, ListRef, ListValue, NaiveDateArray,
+    Utf8Array,
+};
 use risingwave_common::ensure;
 use risingwave_common::error::{ErrorCode, Result, RwError};
-use risingwave_common::types::{DataType, ToOwnedDatum};
-use risingwave_pb::expr::expr_node::RexNode;
+use risingwave_common::types::{
+    DataType, Datum, DatumRef, ScalarImpl, ScalarRefImpl, ToOwnedDatum,
+};
+use risingwave_pb::expr::expr_node::{RexNode, Type};
 use risingwave_pb::expr::ExprNode;
 
-use crate::expr::expr_binary_bytes::new_substr_start;
-use crate::expr::expr_binary_nonnull::{new_binary_expr, new_like_default};
+use crate::expr::expr_array::ArrayFunctionExpression;
+use crate::expr::expr_binary_bytes::{
+    new_concat_op_expr, new_left_expr, new_repeat_expr, new_right_expr, new_substr_start,
+};
+use crate::expr::expr_binary_nonnull::{new_binary_expr, new_ilike_default, new_like_default};
 use crate::expr::expr_binary_nullable::new_nullable_binary_expr;
 use crate::expr::expr_case::{CaseExpression, WhenClause};
 use crate::expr::expr_in::InExpression;
-use crate::expr::expr_ternary_bytes::{new_replace_expr, new_substr_start_end, new_translate_expr};
+use crate::expr::expr_regexp::{
+    new_regexp_like_expr, new_regexp_replace_expr, RegexpCompiler, RegexpMatchExpression,
+    RegexpPattern,
+};
+use crate::expr::expr_string_to_array::StringToArrayExpression;
+use crate::expr::expr_ternary_bytes::{
+    new_lpad_expr, new_replace_expr, new_rpad_expr, new_split_part_expr, new_substr_start_end,
+    new_translate_expr,
+};
 use crate::expr::expr_unary::{
     new_length_default, new_ltrim_expr, new_rtrim_expr, new_trim_expr, new_unary_expr,
 };
-use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression};
+use crate::expr::template::TernaryExpression;
+use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression, LiteralExpression};
+use crate::vector_op::array::{
+    array_access, array_all, array_any, array_append, array_cat, array_contains, array_length,
+    array_overlap, array_position, array_to_string, cardinality, comparison_op,
+};
+use crate::vector_op::make_date::make_date;
+use crate::vector_op::regexp::RegexpContext;
+use crate::vector_op::timestampz::{
+    date_trunc_timestampz, extract_from_timestampz, timestampz_interval_add,
+    timestampz_interval_sub,
+};
 
 fn get_return_type_and_children(prost: &ExprNode) -> Result<(Vec<ExprNode>, DataType)> {
     let ret_type = DataType::from(prost.get_return_type()?);
@@ -74,6 +102,115 @@ pub fn build_nullable_binary_expr_prost(prost: &ExprNode) -> Result<BoxedExpress
     ))
 }
 
+/// `extract(field from source)`, with an extra time zone argument when the source is
+/// `timestamptz`.
+pub fn build_extract_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() == 2 || children.len() == 3);
+    let field = expr_build_from_prost(&children[0])?;
+    let source = expr_build_from_prost(&children[1])?;
+    if children.len() == 2 {
+        Ok(new_binary_expr(Type::Extract, ret_type, field, source))
+    } else {
+        let time_zone = expr_build_from_prost(&children[2])?;
+        Ok(Box::new(TernaryExpression::<
+            Utf8Array,
+            I64Array,
+            Utf8Array,
+            DecimalArray,
+            _,
+        >::new(
+            field,
+            source,
+            time_zone,
+            ret_type,
+            extract_from_timestampz,
+        )))
+    }
+}
+
+/// `timestamptz + interval` and `timestamptz - interval` take the time zone as the third argument.
+/// Other additions and subtractions are binary.
+pub fn build_add_sub_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() == 2 || children.len() == 3);
+    if children.len() == 2 {
+        return build_binary_expr_prost(prost);
+    }
+    let source = expr_build_from_prost(&children[0])?;
+    let interval = expr_build_from_prost(&children[1])?;
+    let time_zone = expr_build_from_prost(&children[2])?;
+    let func = match prost.get_expr_type()? {
+        Type::Add => timestampz_interval_add,
+        Type::Subtract => timestampz_interval_sub,
+        _ => unreachable!(),
+    };
+    Ok(Box::new(TernaryExpression::<
+        I64Array,
+        IntervalArray,
+        Utf8Array,
+        I64Array,
+        _,
+    >::new(
+        source, interval, time_zone, ret_type, func
+    )))
+}
+
+/// `date_trunc(field, source)`, with an extra time zone argument when the source is
+/// `timestamptz`.
+pub fn build_date_trunc_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() == 2 || children.len() == 3);
+    let field = expr_build_from_prost(&children[0])?;
+    let source = expr_build_from_prost(&children[1])?;
+    if children.len() == 2 {
+        Ok(new_binary_expr(Type::DateTrunc, ret_type, field, source))
+    } else {
+        let time_zone = expr_build_from_prost(&children[2])?;
+        Ok(Box::new(TernaryExpression::<
+            Utf8Array,
+            I64Array,
+            Utf8Array,
+            I64Array,
+            _,
+        >::new(
+            field,
+            source,
+            time_zone,
+            ret_type,
+            date_trunc_timestampz,
+        )))
+    }
+}
+
+pub fn build_make_date_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() == 3);
+    let year = expr_build_from_prost(&children[0])?;
+    let month = expr_build_from_prost(&children[1])?;
+    let day = expr_build_from_prost(&children[2])?;
+    Ok(Box::new(TernaryExpression::<
+        I32Array,
+        I32Array,
+        I32Array,
+        NaiveDateArray,
+        _,
+    >::new(year, month, day, ret_type, make_date)))
+}
+
+/// `now()` is evaluated when the expression is built, so that it returns the same value for all
+/// the rows.
+pub fn build_now_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.is_empty());
+    let now = Utc::now();
+    let micros = now.timestamp() * 1_000_000 + now.timestamp_subsec_micros() as i64;
+    Ok(Box::new(LiteralExpression::new(
+        ret_type,
+        Some(ScalarImpl::Int64(micros)),
+    )))
+}
+
 pub fn build_substr_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     let (children, ret_type) = get_return_type_and_children(prost)?;
     let child = expr_build_from_prost(&children[0])?;
@@ -136,7 +273,125 @@ pub fn build_like_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     ensure!(children.len() == 2);
     let expr_ia1 = expr_build_from_prost(&children[0])?;
     let expr_ia2 = expr_build_from_prost(&children[1])?;
-    Ok(new_like_default(expr_ia1, expr_ia2, ret_type))
+    match prost.get_expr_type()? {
+        Type::Like => Ok(new_like_default(expr_ia1, expr_ia2, ret_type)),
+        Type::Ilike => Ok(new_ilike_default(expr_ia1, expr_ia2, ret_type)),
+        _ => unreachable!(),
+    }
+}
+
+/// Returns the value of `prost` if it is a constant.
+pub fn get_constant(prost: &ExprNode) -> Result<Option<Datum>> {
+    if prost.get_expr_type()? == Type::ConstantValue {
+        Ok(Some(LiteralExpression::try_from(prost)?.literal()))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Returns the flags of a regular expression function, which must be a constant, or `None` if
+/// they are NULL.
+pub fn get_regexp_flags(flags: Option<&ExprNode>) -> Result<Option<String>> {
+    match flags {
+        None => Ok(Some(String::new())),
+        Some(flags) => match get_constant(flags)? {
+            Some(Some(flags)) => Ok(Some(String::try_from(flags)?)),
+            Some(None) => Ok(None),
+            None => Err(ErrorCode::InvalidInputSyntax(
+                "the flags of regular expression functions must be a constant".to_string(),
+            )
+            .into()),
+        },
+    }
+}
+
+/// Builds the pattern of a regular expression function, which is compiled with `compile` right
+/// away if it is a constant. Returns `None` if the pattern is NULL.
+pub fn build_regexp_pattern(
+    pattern: &ExprNode,
+    compile: RegexpCompiler,
+) -> Result<Option<RegexpPattern>> {
+    match get_constant(pattern)? {
+        Some(Some(pattern)) => Ok(Some(RegexpPattern::Constant(compile(&String::try_from(
+            pattern,
+        )?)?))),
+        Some(None) => Ok(None),
+        None => Ok(Some(RegexpPattern::Dynamic {
+            pattern: expr_build_from_prost(pattern)?,
+            compile,
+        })),
+    }
+}
+
+/// `string ~ pattern`, `regexp_like(string, pattern, flags)` and `string SIMILAR TO pattern`.
+pub fn build_regexp_like_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    let compile: RegexpCompiler = match prost.get_expr_type()? {
+        Type::SimilarTo => {
+            ensure!(children.len() == 2);
+            Box::new(RegexpContext::from_similar_to)
+        }
+        _ => {
+            ensure!(children.len() == 2 || children.len() == 3);
+            let flags = match get_regexp_flags(children.get(2))? {
+                Some(flags) => flags,
+                None => return Ok(Box::new(LiteralExpression::new(ret_type, None))),
+            };
+            Box::new(move |pattern: &str| RegexpContext::new(pattern, &flags))
+        }
+    };
+    let input = expr_build_from_prost(&children[0])?;
+    let pattern = match build_regexp_pattern(&children[1], compile)? {
+        Some(pattern) => pattern,
+        None => return Ok(Box::new(LiteralExpression::new(ret_type, None))),
+    };
+    Ok(new_regexp_like_expr(input, pattern, ret_type))
+}
+
+pub fn build_regexp_match_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() == 2 || children.len() == 3);
+    let flags = match get_regexp_flags(children.get(2))? {
+        Some(flags) => flags,
+        None => return Ok(Box::new(LiteralExpression::new(ret_type, None))),
+    };
+    if flags.contains('g') {
+        return Err(ErrorCode::InvalidInputSyntax(
+            "regexp_match() does not support the \"global\" option".to_string(),
+        )
+        .into());
+    }
+    let input = expr_build_from_prost(&children[0])?;
+    let compile = Box::new(move |pattern: &str| RegexpContext::new(pattern, &flags));
+    let pattern = match build_regexp_pattern(&children[1], compile)? {
+        Some(pattern) => pattern,
+        None => return Ok(Box::new(LiteralExpression::new(ret_type, None))),
+    };
+    Ok(Box::new(RegexpMatchExpression::new(
+        input, pattern, ret_type,
+    )))
+}
+
+pub fn build_regexp_replace_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() == 3 || children.len() == 4);
+    let flags = match get_regexp_flags(children.get(3))? {
+        Some(flags) => flags,
+        None => return Ok(Box::new(LiteralExpression::new(ret_type, None))),
+    };
+    let input = expr_build_from_prost(&children[0])?;
+    let replacement = expr_build_from_prost(&children[2])?;
+    let compile = Box::new(move |pattern: &str| RegexpContext::new(pattern, &flags));
+    let pattern = match build_regexp_pattern(&children[1], compile)? {
+        Some(pattern) => pattern,
+        None => return Ok(Box::new(LiteralExpression::new(ret_type, None))),
+    };
+    Ok(new_regexp_replace_expr(
+        input,
+        pattern,
+        replacement,
+        ret_type,
+    ))
 }
 
 pub fn build_in_expr(prost: &ExprNode) -> Result<BoxedExpression> {
@@ -210,6 +465,242 @@ pub fn build_translate_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     Ok(new_translate_expr(s, match_str, replace_str, ret_type))
 }
 
+pub fn build_split_part_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() == 3);
+    let s = expr_build_from_prost(&children[0])?;
+    let delimiter = expr_build_from_prost(&children[1])?;
+    let n = expr_build_from_prost(&children[2])?;
+    Ok(new_split_part_expr(s, delimiter, n, ret_type))
+}
+
+/// `lpad` and `rpad`, whose fill has been filled in by the frontend if omitted.
+pub fn build_pad_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() == 3);
+    let s = expr_build_from_prost(&children[0])?;
+    let len = expr_build_from_prost(&children[1])?;
+    let fill = expr_build_from_prost(&children[2])?;
+    match prost.get_expr_type()? {
+        Type::Lpad => Ok(new_lpad_expr(s, len, fill, ret_type)),
+        Type::Rpad => Ok(new_rpad_expr(s, len, fill, ret_type)),
+        _ => unreachable!(),
+    }
+}
+
+/// String functions of two arguments that write a string.
+pub fn build_binary_bytes_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() == 2);
+    let left_expr = expr_build_from_prost(&children[0])?;
+    let right_expr = expr_build_from_prost(&children[1])?;
+    let new_expr = match prost.get_expr_type()? {
+        Type::Repeat => new_repeat_expr,
+        Type::Left => new_left_expr,
+        Type::Right => new_right_expr,
+        Type::ConcatOp => new_concat_op_expr,
+        _ => unreachable!(),
+    };
+    Ok(new_expr(left_expr, right_expr, ret_type))
+}
+
+pub fn build_string_to_array_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() == 2 || children.len() == 3);
+    let input = expr_build_from_prost(&children[0])?;
+    let delimiter = expr_build_from_prost(&children[1])?;
+    let null_str = children.get(2).map(expr_build_from_prost).transpose()?;
+    Ok(Box::new(StringToArrayExpression::new(
+        input, delimiter, null_str, ret_type,
+    )))
+}
+
+fn list(datum: DatumRef<'_>) -> Option<ListRef<'_>> {
+    datum.map(ScalarRefImpl::into_list)
+}
+
+fn int32(datum: DatumRef<'_>) -> Option<i32> {
+    datum.map(ScalarRefImpl::into_int32)
+}
+
+fn utf8(datum: DatumRef<'_>) -> Option<&str> {
+    datum.map(ScalarRefImpl::into_utf8)
+}
+
+pub fn build_array_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let func_type = prost.get_expr_type()?;
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    let children: Vec<BoxedExpression> = children
+        .iter()
+        .map(expr_build_from_prost)
+        .collect::<Result<_>>()?;
+    let expr = match func_type {
+        Type::Array => ArrayFunctionExpression::new(
+            func_type,
+            children,
+            ret_type,
+            |args: &[DatumRef<'_>]| {
+                let values = args.iter().map(|v| v.to_owned_datum()).collect();
+                Ok(Some(ScalarImpl::List(ListValue::new(values))))
+            },
+        ),
+        Type::ArrayAccess => {
+            ensure!(children.len() == 2);
+            ArrayFunctionExpression::new(
+                func_type,
+                children,
+                ret_type,
+                |args: &[DatumRef<'_>]| {
+                    Ok(match (list(args[0]), int32(args[1])) {
+                        (Some(l), Some(i)) => array_access(l, i),
+                        _ => None,
+                    })
+                },
+            )
+        }
+        Type::ArrayLength => {
+            ensure!(children.len() == 2);
+            ArrayFunctionExpression::new(
+                func_type,
+                children,
+                ret_type,
+                |args: &[DatumRef<'_>]| {
+                    Ok(match (list(args[0]), int32(args[1])) {
+                        (Some(l), Some(d)) => array_length(l, d).map(ScalarImpl::Int32),
+                        _ => None,
+                    })
+                },
+            )
+        }
+        Type::Cardinality => {
+            ensure!(children.len() == 1);
+            ArrayFunctionExpression::new(
+                func_type,
+                children,
+                ret_type,
+                |args: &[DatumRef<'_>]| Ok(list(args[0]).map(|l| ScalarImpl::Int32(cardinality(l)))),
+            )
+        }
+        Type::ArrayAppend => {
+            ensure!(children.len() == 2);
+            ArrayFunctionExpression::new(
+                func_type,
+                children,
+                ret_type,
+                |args: &[DatumRef<'_>]| {
+                    Ok(Some(ScalarImpl::List(array_append(list(args[0]), args[1]))))
+                },
+            )
+        }
+        Type::ArrayCat => {
+            ensure!(children.len() == 2);
+            ArrayFunctionExpression::new(
+                func_type,
+                children,
+                ret_type,
+                |args: &[DatumRef<'_>]| {
+                    Ok(array_cat(list(args[0]), list(args[1])).map(ScalarImpl::List))
+                },
+            )
+        }
+        Type::ArrayPosition => {
+            ensure!(children.len() == 2 || children.len() == 3);
+            ArrayFunctionExpression::new(
+                func_type,
+                children,
+                ret_type,
+                |args: &[DatumRef<'_>]| {
+                    let start = match args.get(2) {
+                        None => 1,
+                        Some(start) => int32(*start).ok_or_else(|| {
+                            ErrorCode::InvalidParameterValue(
+                                "initial position must not be null".to_string(),
+                            )
+                        })?,
+                    };
+                    Ok(list(args[0])
+                        .and_then(|l| array_position(l, args[1], start))
+                        .map(ScalarImpl::Int32))
+                },
+            )
+        }
+        Type::ArrayContains | Type::ArrayOverlap => {
+            ensure!(children.len() == 2);
+            let func = match func_type {
+                Type::ArrayContains => array_contains,
+                Type::ArrayOverlap => array_overlap,
+                _ => unreachable!(),
+            };
+            ArrayFunctionExpression::new(
+                func_type,
+                children,
+                ret_type,
+                move |args: &[DatumRef<'_>]| {
+                    Ok(match (list(args[0]), list(args[1])) {
+                        (Some(l), Some(r)) => Some(ScalarImpl::Bool(func(l, r))),
+                        _ => None,
+                    })
+                },
+            )
+        }
+        Type::ArrayToString => {
+            ensure!(children.len() == 2 || children.len() == 3);
+            ArrayFunctionExpression::new(
+                func_type,
+                children,
+                ret_type,
+                |args: &[DatumRef<'_>]| {
+                    let null_str = args.get(2).and_then(|v| utf8(*v));
+                    Ok(match (list(args[0]), utf8(args[1])) {
+                        (Some(l), Some(delimiter)) => {
+                            Some(ScalarImpl::Utf8(array_to_string(l, delimiter, null_str)?))
+                        }
+                        _ => None,
+                    })
+                },
+            )
+        }
+        _ => unreachable!(),
+    };
+    Ok(Box::new(expr))
+}
+
+/// Builds `element op ANY(list)` or `element op ALL(list)`, where `op` must be a constant.
+pub fn build_array_any_all_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let func_type = prost.get_expr_type()?;
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() == 3);
+    let op = match get_constant(&children[1])? {
+        Some(Some(op)) => comparison_op(&String::try_from(op)?)?,
+        _ => {
+            return Err(ErrorCode::InternalError(
+                "the operator of ANY or ALL must be a constant varchar".to_string(),
+            )
+            .into())
+        }
+    };
+    let element = expr_build_from_prost(&children[0])?;
+    let list = expr_build_from_prost(&children[2])?;
+    let any = func_type == Type::ArrayAny;
+    Ok(Box::new(ArrayFunctionExpression::new(
+        func_type,
+        vec![element, list],
+        ret_type,
+        |args: &[DatumRef<'_>]| {
+            let list = match args[1] {
+                Some(list) => list.into_list(),
+                None => return Ok(None),
+            };
+            let result = if any {
+                array_any(args[0], list, op)
+            } else {
+                array_all(args[0], list, op)
+            };
+            Ok(result.map(ScalarImpl::Bool))
+        },
+    )))
+}
+
 #[cfg(test)]
 mod tests {
     use std::vec;
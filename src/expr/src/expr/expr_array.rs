@@ -0,0 +1,136 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::sync::Arc;
+
+use itertools::Itertools;
+use risingwave_common::array::{ArrayRef, DataChunk};
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, Datum, DatumRef};
+use risingwave_pb::expr::expr_node::Type;
+
+use crate::expr::{BoxedExpression, Expression};
+
+type ArrayFunction = Box<dyn Fn(&[DatumRef<'_>]) -> Result<Datum> + Send + Sync>;
+
+/// An expression on lists, e.g. `ARRAY[...]` and `array_append`, which is evaluated row by row
+/// with `func` on the values of the children. Unlike most functions, NULL arguments are passed to
+/// `func`, because many array functions, e.g. `array_cat`, do not return NULL on NULL inputs.
+pub struct ArrayFunctionExpression {
+    func_type: Type,
+    children: Vec<BoxedExpression>,
+    return_type: DataType,
+    func: ArrayFunction,
+}
+
+impl fmt::Debug for ArrayFunctionExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArrayFunctionExpression")
+            .field("func_type", &self.func_type)
+            .field("children", &self.children)
+            .field("return_type", &self.return_type)
+            .finish()
+    }
+}
+
+impl ArrayFunctionExpression {
+    pub fn new(
+        func_type: Type,
+        children: Vec<BoxedExpression>,
+        return_type: DataType,
+        func: impl Fn(&[DatumRef<'_>]) -> Result<Datum> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            func_type,
+            children,
+            return_type,
+            func: Box::new(func),
+        }
+    }
+}
+
+impl Expression for ArrayFunctionExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let children: Vec<ArrayRef> = self
+            .children
+            .iter()
+            .map(|child| child.eval(input))
+            .try_collect()?;
+        let visibility = input.get_visibility_ref();
+        let mut builder = self.return_type.create_array_builder(input.capacity())?;
+        let mut args = Vec::with_capacity(children.len());
+        for row_idx in 0..input.capacity() {
+            let visible = match visibility {
+                Some(visibility) => visibility.is_set(row_idx)?,
+                None => true,
+            };
+            if !visible {
+                builder.append_datum(&None)?;
+                continue;
+            }
+            args.clear();
+            args.extend(children.iter().map(|child| child.value_at(row_idx)));
+            builder.append_datum(&(self.func)(&args)?)?;
+        }
+        Ok(Arc::new(builder.finish()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{DataChunkTestExt, ListValue};
+    use risingwave_common::types::{ScalarImpl, ToOwnedDatum};
+
+    use super::*;
+    use crate::expr::InputRefExpression;
+
+    #[test]
+    fn test_array_function() {
+        let chunk = DataChunk::from_pretty(
+            "i i
+             1 2
+             . 3",
+        );
+        let input_ref = |idx: usize| -> BoxedExpression {
+            Box::new(InputRefExpression::new(DataType::Int32, idx))
+        };
+        let expr = ArrayFunctionExpression::new(
+            Type::Array,
+            vec![input_ref(0), input_ref(1)],
+            DataType::List {
+                datatype: Box::new(DataType::Int32),
+            },
+            |args: &[DatumRef<'_>]| {
+                let values = args.iter().map(|v| v.to_owned_datum()).collect();
+                Ok(Some(ScalarImpl::List(ListValue::new(values))))
+            },
+        );
+        let output = expr.eval(&chunk).unwrap();
+        let output = (0..output.len()).map(|i| output.datum_at(i)).collect_vec();
+        let list = |values: &[Option<i32>]| -> Datum {
+            Some(ScalarImpl::List(ListValue::new(
+                values.iter().map(|v| v.map(ScalarImpl::Int32)).collect(),
+            )))
+        };
+        assert_eq!(
+            output,
+            vec![list(&[Some(1), Some(2)]), list(&[None, Some(3)])]
+        );
+    }
+}
@@ -19,6 +19,9 @@ use risingwave_common::types::DataType;
 
 use crate::expr::template::BinaryBytesExpression;
 use crate::expr::BoxedExpression;
+use crate::vector_op::concat_op::concat_op;
+use crate::vector_op::left_right::{left, right};
+use crate::vector_op::repeat::repeat;
 use crate::vector_op::substr::*;
 
 pub fn new_substr_start(
@@ -48,6 +51,58 @@ pub fn new_substr_for(
     ))
 }
 
+pub fn new_repeat_expr(
+    s: BoxedExpression,
+    n: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    Box::new(BinaryBytesExpression::<Utf8Array, I32Array, _>::new(
+        s,
+        n,
+        return_type,
+        repeat,
+    ))
+}
+
+pub fn new_left_expr(
+    s: BoxedExpression,
+    n: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    Box::new(BinaryBytesExpression::<Utf8Array, I32Array, _>::new(
+        s,
+        n,
+        return_type,
+        left,
+    ))
+}
+
+pub fn new_right_expr(
+    s: BoxedExpression,
+    n: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    Box::new(BinaryBytesExpression::<Utf8Array, I32Array, _>::new(
+        s,
+        n,
+        return_type,
+        right,
+    ))
+}
+
+pub fn new_concat_op_expr(
+    l: BoxedExpression,
+    r: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    Box::new(BinaryBytesExpression::<Utf8Array, Utf8Array, _>::new(
+        l,
+        r,
+        return_type,
+        concat_op,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use risingwave_common::array::DataChunk;
@@ -113,4 +168,39 @@ mod tests {
         let res = substr_for_str_none.eval(&DataChunk::new_dummy(1)).unwrap();
         assert_eq!(res.to_datum(), None);
     }
+    #[test]
+    fn test_repeat_left_right() {
+        type ExprBuilder = fn(BoxedExpression, BoxedExpression, DataType) -> BoxedExpression;
+        let cases: [(ExprBuilder, &str); 3] = [
+            (new_repeat_expr, "abab"),
+            (new_left_expr, "ab"),
+            (new_right_expr, "ab"),
+        ];
+        for (f, expected) in cases {
+            let expr = create_str_i32_binary_expr(
+                f,
+                Some(ScalarImpl::from(String::from("ab"))),
+                Some(ScalarImpl::Int32(2)),
+            );
+            let res = expr.eval(&DataChunk::new_dummy(1)).unwrap();
+            assert_eq!(
+                res.to_datum(),
+                Some(ScalarImpl::from(String::from(expected)))
+            );
+        }
+    }
+
+    #[test]
+    fn test_concat_op() {
+        let expr = new_concat_op_expr(
+            Box::new(LiteralExpression::new(
+                DataType::Varchar,
+                Some(ScalarImpl::from(String::from("ab"))),
+            )),
+            Box::new(LiteralExpression::new(DataType::Varchar, None)),
+            DataType::Varchar,
+        );
+        let res = expr.eval(&DataChunk::new_dummy(1)).unwrap();
+        assert_eq!(res.to_datum(), None);
+    }
 }
@@ -13,8 +13,8 @@
 // limitations under the License.
 
 use risingwave_common::array::{
-    Array, BoolArray, DecimalArray, I32Array, IntervalArray, NaiveDateArray, NaiveDateTimeArray,
-    Utf8Array,
+    Array, BoolArray, BytesArray, DecimalArray, F64Array, I16Array, I32Array, I64Array,
+    IntervalArray, JsonbArray, NaiveDateArray, NaiveDateTimeArray, Utf8Array,
 };
 use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::Result;
@@ -24,11 +24,20 @@ use risingwave_pb::expr::expr_node::Type;
 use crate::expr::template::BinaryExpression;
 use crate::expr::BoxedExpression;
 use crate::vector_op::arithmetic_op::*;
+use crate::vector_op::bitwise_op::*;
+use crate::vector_op::bytea::{decode, encode};
 use crate::vector_op::cmp::*;
+use crate::vector_op::date_trunc::date_trunc_timestamp;
 use crate::vector_op::extract::{extract_from_date, extract_from_timestamp};
-use crate::vector_op::like::like_default;
+use crate::vector_op::jsonb::{jsonb_contains, jsonb_exists};
+use crate::vector_op::like::{ilike_default, like_default};
+use crate::vector_op::math::{atan2_f64, pow_decimal, pow_f64};
 use crate::vector_op::position::position;
 use crate::vector_op::round::round_digits;
+use crate::vector_op::timestampz::{
+    str_to_timestampz_with_zone, timestamp_at_time_zone, timestampz_at_time_zone, timestampz_to_str,
+};
+use crate::vector_op::to_char::to_char_timestamp;
 use crate::vector_op::tumble::{tumble_start_date, tumble_start_date_time};
 
 /// A placeholder function that returns bool in [`gen_binary_expr_atm`]
@@ -167,7 +176,8 @@ macro_rules! gen_binary_expr_cmp {
                     { date, date, date, $general_f },
                     { boolean, boolean, boolean, $general_f },
                     { timestamp, date, timestamp, $general_f },
-                    { date, timestamp, timestamp, $general_f }
+                    { date, timestamp, timestamp, $general_f },
+                    { uuid, uuid, uuid, $general_f }
                 }
             }
         }
@@ -236,6 +246,25 @@ macro_rules! gen_binary_expr_atm {
     };
 }
 
+/// Bitwise operators are defined on integers, where the narrower one is casted to the wider one as
+/// in arithmetic.
+macro_rules! gen_binary_expr_bitwise {
+    ($macro:ident, $l:expr, $r:expr, $ret:expr, $general_f:ident) => {
+        $macro! {
+            [$l, $r, $ret],
+            { int16, int16, int16, $general_f },
+            { int16, int32, int32, $general_f },
+            { int16, int64, int64, $general_f },
+            { int32, int16, int32, $general_f },
+            { int32, int32, int32, $general_f },
+            { int32, int64, int64, $general_f },
+            { int64, int16, int64, $general_f },
+            { int64, int32, int64, $general_f },
+            { int64, int64, int64, $general_f },
+        }
+    };
+}
+
 fn build_extract_expr(ret: DataType, l: BoxedExpression, r: BoxedExpression) -> BoxedExpression {
     match r.return_type() {
         DataType::Date => Box::new(
@@ -258,6 +287,105 @@ fn build_extract_expr(ret: DataType, l: BoxedExpression, r: BoxedExpression) ->
     }
 }
 
+fn build_at_time_zone_expr(
+    ret: DataType,
+    l: BoxedExpression,
+    r: BoxedExpression,
+) -> BoxedExpression {
+    match l.return_type() {
+        DataType::Timestamp => Box::new(BinaryExpression::<
+            NaiveDateTimeArray,
+            Utf8Array,
+            I64Array,
+            _,
+        >::new(l, r, ret, timestamp_at_time_zone)),
+        DataType::Timestampz => Box::new(BinaryExpression::<
+            I64Array,
+            Utf8Array,
+            NaiveDateTimeArray,
+            _,
+        >::new(l, r, ret, timestampz_at_time_zone)),
+        _ => {
+            unimplemented!("AtTimeZone ( {:?} ) is not supported yet!", l.return_type())
+        }
+    }
+}
+
+fn build_cast_with_time_zone_expr(
+    ret: DataType,
+    l: BoxedExpression,
+    r: BoxedExpression,
+) -> BoxedExpression {
+    match (l.return_type(), &ret) {
+        (DataType::Varchar, DataType::Timestampz) => {
+            Box::new(BinaryExpression::<Utf8Array, Utf8Array, I64Array, _>::new(
+                l,
+                r,
+                ret,
+                str_to_timestampz_with_zone,
+            ))
+        }
+        (DataType::Timestampz, DataType::Varchar) => {
+            Box::new(BinaryExpression::<I64Array, Utf8Array, Utf8Array, _>::new(
+                l,
+                r,
+                ret,
+                timestampz_to_str,
+            ))
+        }
+        (input, _) => unimplemented!(
+            "CastWithTimeZone ( {:?} AS {:?} ) is not supported yet!",
+            input,
+            ret
+        ),
+    }
+}
+
+/// `<<` and `>>`, where the shift amount is always `int`.
+fn build_shift_expr(
+    ret: DataType,
+    l: BoxedExpression,
+    r: BoxedExpression,
+    shl: bool,
+) -> BoxedExpression {
+    match (l.return_type(), shl) {
+        (DataType::Int16, true) => Box::new(
+            BinaryExpression::<I16Array, I32Array, I16Array, _>::new(l, r, ret, general_shl),
+        ),
+        (DataType::Int16, false) => Box::new(
+            BinaryExpression::<I16Array, I32Array, I16Array, _>::new(l, r, ret, general_shr),
+        ),
+        (DataType::Int32, true) => Box::new(
+            BinaryExpression::<I32Array, I32Array, I32Array, _>::new(l, r, ret, general_shl),
+        ),
+        (DataType::Int32, false) => Box::new(
+            BinaryExpression::<I32Array, I32Array, I32Array, _>::new(l, r, ret, general_shr),
+        ),
+        (DataType::Int64, true) => Box::new(
+            BinaryExpression::<I64Array, I32Array, I64Array, _>::new(l, r, ret, general_shl),
+        ),
+        (DataType::Int64, false) => Box::new(
+            BinaryExpression::<I64Array, I32Array, I64Array, _>::new(l, r, ret, general_shr),
+        ),
+        _ => unimplemented!("Shift ( {:?} ) is not supported yet!", l.return_type()),
+    }
+}
+
+fn build_pow_expr(ret: DataType, l: BoxedExpression, r: BoxedExpression) -> BoxedExpression {
+    match l.return_type() {
+        DataType::Float64 => Box::new(BinaryExpression::<F64Array, F64Array, F64Array, _>::new(
+            l, r, ret, pow_f64,
+        )),
+        DataType::Decimal => Box::new(BinaryExpression::<
+            DecimalArray,
+            DecimalArray,
+            DecimalArray,
+            _,
+        >::new(l, r, ret, pow_decimal)),
+        _ => unimplemented!("Pow ( {:?} ) is not supported yet!", l.return_type()),
+    }
+}
+
 pub fn new_binary_expr(
     expr_type: Type,
     ret: DataType,
@@ -296,6 +424,12 @@ pub fn new_binary_expr(
                     { interval, date, timestamp, interval_date_add },
                     { date, interval, timestamp, date_interval_add },
                     { interval, interval, interval, general_add },
+                    { date, int32, date, date_int_add },
+                    { int32, date, date, int_date_add },
+                    { date, time, timestamp, date_time_add },
+                    { time, date, timestamp, time_date_add },
+                    { time, interval, time, time_interval_add },
+                    { interval, time, time, interval_time_add },
                 },
             }
         }
@@ -310,6 +444,10 @@ pub fn new_binary_expr(
                     { date, date, int32, date_date_sub },
                     { date, interval, timestamp, date_interval_sub },
                     { interval, interval, interval, general_sub },
+                    { date, int32, date, date_int_sub },
+                    { timestampz, timestampz, interval, timestampz_timestampz_sub },
+                    { time, time, interval, time_time_sub },
+                    { time, interval, time, time_interval_sub },
                 },
             }
         }
@@ -346,7 +484,24 @@ pub fn new_binary_expr(
                 },
             }
         }
+        Type::BitwiseAnd => {
+            gen_binary_expr_bitwise! {gen_atm_impl, l, r, ret, general_bitand}
+        }
+        Type::BitwiseOr => {
+            gen_binary_expr_bitwise! {gen_atm_impl, l, r, ret, general_bitor}
+        }
+        Type::BitwiseXor => {
+            gen_binary_expr_bitwise! {gen_atm_impl, l, r, ret, general_bitxor}
+        }
+        Type::BitwiseShiftLeft => build_shift_expr(ret, l, r, true),
+        Type::BitwiseShiftRight => build_shift_expr(ret, l, r, false),
+        Type::Pow => build_pow_expr(ret, l, r),
+        Type::Atan2 => Box::new(BinaryExpression::<F64Array, F64Array, F64Array, _>::new(
+            l, r, ret, atan2_f64,
+        )),
         Type::Extract => build_extract_expr(ret, l, r),
+        Type::AtTimeZone => build_at_time_zone_expr(ret, l, r),
+        Type::CastWithTimeZone => build_cast_with_time_zone_expr(ret, l, r),
         Type::RoundDigit => Box::new(
             BinaryExpression::<DecimalArray, I32Array, DecimalArray, _>::new(
                 l,
@@ -359,6 +514,41 @@ pub fn new_binary_expr(
             l, r, ret, position,
         )),
         Type::TumbleStart => new_tumble_start(l, r, ret),
+        Type::DateTrunc => Box::new(BinaryExpression::<
+            Utf8Array,
+            NaiveDateTimeArray,
+            NaiveDateTimeArray,
+            _,
+        >::new(l, r, ret, date_trunc_timestamp)),
+        Type::ToChar => Box::new(BinaryExpression::<
+            NaiveDateTimeArray,
+            Utf8Array,
+            Utf8Array,
+            _,
+        >::new(l, r, ret, to_char_timestamp)),
+        Type::Age => Box::new(BinaryExpression::<
+            NaiveDateTimeArray,
+            NaiveDateTimeArray,
+            IntervalArray,
+            _,
+        >::new(l, r, ret, timestamp_age)),
+        Type::JsonbContains => Box::new(
+            BinaryExpression::<JsonbArray, JsonbArray, BoolArray, _>::new(
+                l,
+                r,
+                ret,
+                jsonb_contains,
+            ),
+        ),
+        Type::JsonbExists => Box::new(
+            BinaryExpression::<JsonbArray, Utf8Array, BoolArray, _>::new(l, r, ret, jsonb_exists),
+        ),
+        Type::Encode => Box::new(
+            BinaryExpression::<BytesArray, Utf8Array, Utf8Array, _>::new(l, r, ret, encode),
+        ),
+        Type::Decode => Box::new(
+            BinaryExpression::<Utf8Array, Utf8Array, BytesArray, _>::new(l, r, ret, decode),
+        ),
         tp => {
             unimplemented!(
                 "The expression {:?} using vectorized expression framework is not supported yet!",
@@ -410,6 +600,19 @@ pub fn new_like_default(
     ))
 }
 
+pub fn new_ilike_default(
+    expr_ia1: BoxedExpression,
+    expr_ia2: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    Box::new(BinaryExpression::<Utf8Array, Utf8Array, BoolArray, _>::new(
+        expr_ia1,
+        expr_ia2,
+        return_type,
+        ilike_default,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::NaiveDate;
@@ -14,13 +14,14 @@
 
 //! For expression that only accept two nullable arguments as input.
 
-use risingwave_common::array::BoolArray;
+use risingwave_common::array::{BoolArray, I32Array, JsonbArray, Utf8Array};
 use risingwave_common::types::DataType;
 use risingwave_pb::expr::expr_node::Type;
 
 use super::BoxedExpression;
 use crate::expr::template::BinaryNullableExpression;
 use crate::vector_op::conjunction::{and, or};
+use crate::vector_op::jsonb::*;
 
 pub fn new_nullable_binary_expr(
     expr_type: Type,
@@ -35,6 +36,40 @@ pub fn new_nullable_binary_expr(
         Type::Or => Box::new(
             BinaryNullableExpression::<BoolArray, BoolArray, BoolArray, _>::new(l, r, ret, or),
         ),
+        Type::JsonbAccessInner => match r.return_type() {
+            DataType::Int32 => Box::new(BinaryNullableExpression::<
+                JsonbArray,
+                I32Array,
+                JsonbArray,
+                _,
+            >::new(l, r, ret, jsonb_access_element)),
+            _ => Box::new(BinaryNullableExpression::<
+                JsonbArray,
+                Utf8Array,
+                JsonbArray,
+                _,
+            >::new(l, r, ret, jsonb_access_field)),
+        },
+        Type::JsonbAccessStr => match r.return_type() {
+            DataType::Int32 => Box::new(BinaryNullableExpression::<
+                JsonbArray,
+                I32Array,
+                Utf8Array,
+                _,
+            >::new(l, r, ret, jsonb_access_element_str)),
+            _ => Box::new(BinaryNullableExpression::<
+                JsonbArray,
+                Utf8Array,
+                Utf8Array,
+                _,
+            >::new(l, r, ret, jsonb_access_field_str)),
+        },
+        Type::JsonbAccessPath => Box::new(BinaryNullableExpression::<
+            JsonbArray,
+            Utf8Array,
+            JsonbArray,
+            _,
+        >::new(l, r, ret, jsonb_access_path)),
         tp => {
             unimplemented!(
                 "The expression {:?} using vectorized expression framework is not supported yet!",
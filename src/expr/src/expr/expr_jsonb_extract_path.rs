@@ -0,0 +1,174 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use risingwave_common::array::{
+    Array, ArrayBuilder, ArrayImpl, ArrayRef, DataChunk, JsonbArrayBuilder,
+};
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::DataType;
+use risingwave_common::{ensure, try_match_expand};
+use risingwave_pb::expr::expr_node::{RexNode, Type};
+use risingwave_pb::expr::ExprNode;
+
+use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression, Expression};
+
+/// `jsonb_extract_path(from_json, VARIADIC path_elems)`, which is equivalent to the `#>`
+/// operator with the path given as separate arguments.
+#[derive(Debug)]
+pub struct JsonbExtractPathExpression {
+    return_type: DataType,
+    json_expr: BoxedExpression,
+    path_exprs: Vec<BoxedExpression>,
+}
+
+impl Expression for JsonbExtractPathExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let json_column = self.json_expr.eval(input)?;
+        let json_column = json_column.as_jsonb();
+
+        let path_columns = self
+            .path_exprs
+            .iter()
+            .map(|c| c.eval(input))
+            .collect::<Result<Vec<_>>>()?;
+        let path_columns_ref = path_columns.iter().map(|c| c.as_utf8()).collect::<Vec<_>>();
+
+        let row_len = input.cardinality();
+        let mut builder = JsonbArrayBuilder::new(row_len)?;
+
+        for row_idx in 0..row_len {
+            let path = path_columns_ref
+                .iter()
+                .map(|c| c.value_at(row_idx))
+                .collect::<Option<Vec<_>>>();
+            let value = json_column
+                .value_at(row_idx)
+                .zip(path)
+                .and_then(|(json, path)| json.access_path(path));
+            builder.append(value)?;
+        }
+        Ok(Arc::new(ArrayImpl::from(builder.finish()?)))
+    }
+}
+
+impl JsonbExtractPathExpression {
+    pub fn new(
+        return_type: DataType,
+        json_expr: BoxedExpression,
+        path_exprs: Vec<BoxedExpression>,
+    ) -> Self {
+        JsonbExtractPathExpression {
+            return_type,
+            json_expr,
+            path_exprs,
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a ExprNode> for JsonbExtractPathExpression {
+    type Error = RwError;
+
+    fn try_from(prost: &'a ExprNode) -> Result<Self> {
+        ensure!(prost.get_expr_type()? == Type::JsonbExtractPath);
+
+        let ret_type = DataType::from(prost.get_return_type()?);
+        let func_call_node = try_match_expand!(prost.get_rex_node().unwrap(), RexNode::FuncCall)?;
+
+        let children = &func_call_node.children;
+        ensure!(!children.is_empty());
+        let json_expr = expr_build_from_prost(&children[0])?;
+
+        let path_exprs = children[1..]
+            .iter()
+            .map(expr_build_from_prost)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(JsonbExtractPathExpression::new(
+            ret_type, json_expr, path_exprs,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use risingwave_common::array::column::Column;
+    use risingwave_common::array::{JsonbVal, Utf8Array};
+    use risingwave_common::types::Scalar;
+    use risingwave_pb::data::data_type::TypeName;
+    use risingwave_pb::data::DataType as ProstDataType;
+    use risingwave_pb::expr::expr_node::RexNode;
+    use risingwave_pb::expr::expr_node::Type::JsonbExtractPath;
+    use risingwave_pb::expr::{ExprNode, FunctionCall};
+
+    use super::*;
+    use crate::expr::test_utils::make_input_ref;
+
+    #[test]
+    fn test_eval_jsonb_extract_path() {
+        let prost = ExprNode {
+            expr_type: JsonbExtractPath as i32,
+            return_type: Some(ProstDataType {
+                type_name: TypeName::Jsonb as i32,
+                ..Default::default()
+            }),
+            rex_node: Some(RexNode::FuncCall(FunctionCall {
+                children: vec![
+                    make_input_ref(0, TypeName::Jsonb),
+                    make_input_ref(1, TypeName::Varchar),
+                    make_input_ref(2, TypeName::Varchar),
+                ],
+            })),
+        };
+        let expr = JsonbExtractPathExpression::try_from(&prost).unwrap();
+
+        let mut json = JsonbArrayBuilder::new(4).unwrap();
+        for v in [
+            Some(r#"{"a": [1, {"b": 2}]}"#),
+            Some(r#"{"a": [1, {"b": 2}]}"#),
+            Some(r#"{"a": 1}"#),
+            None,
+        ] {
+            let v = v.map(|v| JsonbVal::from_str(v).unwrap());
+            json.append(v.as_ref().map(|v| v.as_scalar_ref())).unwrap();
+        }
+        let json = json.finish().unwrap();
+        let key1 = Utf8Array::from_slice(&[Some("a"), Some("a"), Some("a"), Some("a")]).unwrap();
+        let key2 = Utf8Array::from_slice(&[Some("1"), None, Some("0"), Some("1")]).unwrap();
+        let chunk = DataChunk::builder()
+            .columns(vec![
+                Column::new(Arc::new(json.into())),
+                Column::new(Arc::new(key1.into())),
+                Column::new(Arc::new(key2.into())),
+            ])
+            .build();
+
+        let actual = expr.eval(&chunk).unwrap();
+        let actual = actual
+            .iter()
+            .map(|v| v.map(|v| v.into_jsonb().to_json_text()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            vec![Some(r#"{"b":2}"#.to_string()), None, None, None]
+        );
+    }
+}
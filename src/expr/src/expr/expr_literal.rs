@@ -19,11 +19,11 @@ use std::sync::Arc;
 
 use prost::DecodeError;
 use risingwave_common::array::{
-    read_interval_unit, Array, ArrayBuilder, ArrayBuilderImpl, ArrayRef, DataChunk,
+    read_interval_unit, Array, ArrayBuilder, ArrayBuilderImpl, ArrayRef, DataChunk, JsonbVal,
 };
 use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::{ErrorCode, Result, RwError};
-use risingwave_common::types::{DataType, Datum, Decimal, IntervalUnit, Scalar, ScalarImpl};
+use risingwave_common::types::{DataType, Datum, Decimal, IntervalUnit, Scalar, ScalarImpl, Uuid};
 use risingwave_common::{ensure, for_all_variants};
 use risingwave_pb::data::data_type::IntervalType::*;
 use risingwave_pb::data::data_type::{IntervalType, TypeName};
@@ -98,12 +98,16 @@ fn literal_type_match(return_type: &DataType, literal: Option<&ScalarImpl>) -> b
                     | (DataType::Float64, ScalarImpl::Float64(_))
                     | (DataType::Date, ScalarImpl::Int32(_))
                     | (DataType::Varchar, ScalarImpl::Utf8(_))
+                    | (DataType::Char { .. }, ScalarImpl::Utf8(_))
                     | (DataType::Date, ScalarImpl::NaiveDate(_))
                     | (DataType::Time, ScalarImpl::NaiveTime(_))
                     | (DataType::Timestamp, ScalarImpl::NaiveDateTime(_))
                     | (DataType::Decimal, ScalarImpl::Decimal(_))
                     | (DataType::Interval, ScalarImpl::Interval(_))
                     | (DataType::Struct { .. }, ScalarImpl::Struct(_))
+                    | (DataType::Jsonb, ScalarImpl::Jsonb(_))
+                    | (DataType::Bytea, ScalarImpl::Bytea(_))
+                    | (DataType::Uuid, ScalarImpl::Uuid(_))
             )
         }
         None => true,
@@ -201,7 +205,7 @@ impl<'a> TryFrom<&'a ExprNode> for LiteralExpression {
                     )?)
                     .into(),
                 ),
-                TypeName::Varchar => ScalarImpl::Utf8(
+                TypeName::Varchar | TypeName::Char => ScalarImpl::Utf8(
                     std::str::from_utf8(prost_value.get_body())
                         .map_err(|e| {
                             InternalError(format!("Failed to deserialize varchar, reason: {:?}", e))
@@ -221,6 +225,13 @@ impl<'a> TryFrom<&'a ExprNode> for LiteralExpression {
                         prost.get_return_type()?.get_interval_type()?,
                     )?)
                 }
+                TypeName::Jsonb => ScalarImpl::Jsonb(JsonbVal::from_str(
+                    std::str::from_utf8(prost_value.get_body()).map_err(|e| {
+                        InternalError(format!("Failed to deserialize jsonb, reason: {:?}", e))
+                    })?,
+                )?),
+                TypeName::Bytea => ScalarImpl::Bytea(prost_value.get_body().as_slice().into()),
+                TypeName::Uuid => ScalarImpl::Uuid(Uuid::from_protobuf(prost_value.get_body())?),
                 _ => {
                     return Err(InternalError(format!(
                         "Unrecognized type name: {:?}",
@@ -0,0 +1,277 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! For regular expression functions, whose pattern is compiled once when the expression is built
+//! if it is a constant, and for each row otherwise.
+
+use std::fmt;
+use std::sync::Arc;
+
+use itertools::Itertools;
+use risingwave_common::array::{
+    Array, ArrayRef, BoolArray, BytesGuard, BytesWriter, DataChunk, Utf8Array,
+};
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use crate::expr::template::{
+    BinaryBytesExpression, BinaryExpression, TernaryBytesExpression, UnaryExpression,
+};
+use crate::expr::{BoxedExpression, Expression};
+use crate::vector_op::regexp::{regexp_like, regexp_match, regexp_replace, RegexpContext};
+
+/// Compiles a pattern computed for each row.
+pub type RegexpCompiler = Box<dyn Fn(&str) -> Result<RegexpContext> + Send + Sync>;
+
+/// The pattern argument of a regular expression function.
+pub enum RegexpPattern {
+    Constant(RegexpContext),
+    Dynamic {
+        pattern: BoxedExpression,
+        compile: RegexpCompiler,
+    },
+}
+
+impl fmt::Debug for RegexpPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Constant(ctx) => f.debug_tuple("Constant").field(&ctx.regex).finish(),
+            Self::Dynamic { pattern, .. } => f.debug_tuple("Dynamic").field(pattern).finish(),
+        }
+    }
+}
+
+/// `~`, `SIMILAR TO` and `regexp_like`.
+pub fn new_regexp_like_expr(
+    input: BoxedExpression,
+    pattern: RegexpPattern,
+    return_type: DataType,
+) -> BoxedExpression {
+    match pattern {
+        RegexpPattern::Constant(ctx) => Box::new(UnaryExpression::<Utf8Array, BoolArray, _>::new(
+            input,
+            return_type,
+            move |s: &str| -> Result<bool> { Ok(regexp_like(s, &ctx)) },
+        )),
+        RegexpPattern::Dynamic { pattern, compile } => {
+            Box::new(BinaryExpression::<Utf8Array, Utf8Array, BoolArray, _>::new(
+                input,
+                pattern,
+                return_type,
+                move |s: &str, p: &str| -> Result<bool> { Ok(regexp_like(s, &compile(p)?)) },
+            ))
+        }
+    }
+}
+
+pub fn new_regexp_replace_expr(
+    input: BoxedExpression,
+    pattern: RegexpPattern,
+    replacement: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    match pattern {
+        RegexpPattern::Constant(ctx) => {
+            Box::new(BinaryBytesExpression::<Utf8Array, Utf8Array, _>::new(
+                input,
+                replacement,
+                return_type,
+                move |s: &str, r: &str, writer: BytesWriter| -> Result<BytesGuard> {
+                    regexp_replace(s, &ctx, r, writer)
+                },
+            ))
+        }
+        RegexpPattern::Dynamic { pattern, compile } => {
+            Box::new(
+                TernaryBytesExpression::<Utf8Array, Utf8Array, Utf8Array, _>::new(
+                    input,
+                    pattern,
+                    replacement,
+                    return_type,
+                    move |s: &str, p: &str, r: &str, writer: BytesWriter| -> Result<BytesGuard> {
+                        regexp_replace(s, &compile(p)?, r, writer)
+                    },
+                ),
+            )
+        }
+    }
+}
+
+/// `regexp_match(string, pattern [, flags])`, which returns a `varchar[]`.
+#[derive(Debug)]
+pub struct RegexpMatchExpression {
+    input: BoxedExpression,
+    pattern: RegexpPattern,
+    return_type: DataType,
+}
+
+impl RegexpMatchExpression {
+    pub fn new(input: BoxedExpression, pattern: RegexpPattern, return_type: DataType) -> Self {
+        Self {
+            input,
+            pattern,
+            return_type,
+        }
+    }
+}
+
+impl Expression for RegexpMatchExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let strings = self.input.eval(input)?;
+        let strings: &Utf8Array = strings.as_ref().into();
+        let mut builder = self.return_type.create_array_builder(strings.len())?;
+        match &self.pattern {
+            RegexpPattern::Constant(ctx) => {
+                for s in strings.iter() {
+                    let matched = s.and_then(|s| regexp_match(s, ctx));
+                    builder.append_datum(&matched.map(ScalarImpl::List))?;
+                }
+            }
+            RegexpPattern::Dynamic { pattern, compile } => {
+                let patterns = pattern.eval(input)?;
+                let patterns: &Utf8Array = patterns.as_ref().into();
+                for (s, p) in strings.iter().zip_eq(patterns.iter()) {
+                    let matched = match (s, p) {
+                        (Some(s), Some(p)) => regexp_match(s, &compile(p)?),
+                        _ => None,
+                    };
+                    builder.append_datum(&matched.map(ScalarImpl::List))?;
+                }
+            }
+        }
+        Ok(Arc::new(builder.finish()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::column::Column;
+    use risingwave_common::array::ListValue;
+    use risingwave_common::types::Datum;
+
+    use super::*;
+    use crate::expr::InputRefExpression;
+
+    fn input_chunk(columns: &[&[Option<&str>]]) -> DataChunk {
+        let columns = columns
+            .iter()
+            .map(|values| {
+                let array = Utf8Array::from_slice(values).unwrap();
+                Column::new(Arc::new(array.into()))
+            })
+            .collect();
+        DataChunk::new(columns, None)
+    }
+
+    fn input_ref(idx: usize) -> BoxedExpression {
+        Box::new(InputRefExpression::new(DataType::Varchar, idx))
+    }
+
+    fn dynamic(idx: usize, flags: &'static str) -> RegexpPattern {
+        RegexpPattern::Dynamic {
+            pattern: input_ref(idx),
+            compile: Box::new(move |p: &str| RegexpContext::new(p, flags)),
+        }
+    }
+
+    #[test]
+    fn test_regexp_like() {
+        let chunk = input_chunk(&[
+            &[Some("abc"), Some("ABC"), None],
+            &[Some("^a"), Some("^a"), Some("^a")],
+        ]);
+        let expected = vec![Some(true), Some(false), None];
+        let exprs = [
+            new_regexp_like_expr(
+                input_ref(0),
+                RegexpPattern::Constant(RegexpContext::new("^a", "").unwrap()),
+                DataType::Boolean,
+            ),
+            new_regexp_like_expr(input_ref(0), dynamic(1, ""), DataType::Boolean),
+        ];
+        for expr in exprs {
+            let output = expr.eval(&chunk).unwrap();
+            let output: &BoolArray = output.as_ref().into();
+            assert_eq!(output.iter().collect_vec(), expected);
+        }
+    }
+
+    #[test]
+    fn test_regexp_replace() {
+        let chunk = input_chunk(&[
+            &[Some("a1b2"), Some("c3"), None],
+            &[Some("\\d"), Some("\\d"), Some("\\d")],
+            &[Some("<\\&>"), None, Some("x")],
+        ]);
+        let expected = vec![Some("a<1>b<2>"), None, None];
+        let exprs = [
+            new_regexp_replace_expr(
+                input_ref(0),
+                RegexpPattern::Constant(RegexpContext::new("\\d", "g").unwrap()),
+                input_ref(2),
+                DataType::Varchar,
+            ),
+            new_regexp_replace_expr(
+                input_ref(0),
+                dynamic(1, "g"),
+                input_ref(2),
+                DataType::Varchar,
+            ),
+        ];
+        for expr in exprs {
+            let output = expr.eval(&chunk).unwrap();
+            let output: &Utf8Array = output.as_ref().into();
+            assert_eq!(output.iter().collect_vec(), expected);
+        }
+    }
+
+    #[test]
+    fn test_regexp_match() {
+        let chunk = input_chunk(&[
+            &[Some("k=v"), Some("none"), None],
+            &[Some("(\\w)=(\\w)"), Some("(\\w)=(\\w)"), None],
+        ]);
+        let return_type = DataType::List {
+            datatype: Box::new(DataType::Varchar),
+        };
+        let expected: Vec<Datum> = vec![
+            Some(
+                ListValue::new(vec![
+                    Some(ScalarImpl::Utf8("k".to_string())),
+                    Some(ScalarImpl::Utf8("v".to_string())),
+                ])
+                .into(),
+            ),
+            None,
+            None,
+        ];
+        let exprs = [
+            RegexpMatchExpression::new(
+                input_ref(0),
+                RegexpPattern::Constant(RegexpContext::new("(\\w)=(\\w)", "").unwrap()),
+                return_type.clone(),
+            ),
+            RegexpMatchExpression::new(input_ref(0), dynamic(1, ""), return_type),
+        ];
+        for expr in exprs {
+            let output = expr.eval(&chunk).unwrap();
+            let output = (0..output.len()).map(|i| output.datum_at(i)).collect_vec();
+            assert_eq!(output, expected);
+        }
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use itertools::Itertools;
+use risingwave_common::array::{Array, ArrayRef, DataChunk, Utf8Array};
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use crate::expr::{BoxedExpression, Expression};
+use crate::vector_op::string_to_array::string_to_array;
+
+/// `string_to_array(string, delimiter [, null_string])`, which returns a `varchar[]`. Unlike most
+/// functions, a NULL delimiter or null string does not make the result NULL.
+#[derive(Debug)]
+pub struct StringToArrayExpression {
+    input: BoxedExpression,
+    delimiter: BoxedExpression,
+    null_str: Option<BoxedExpression>,
+    return_type: DataType,
+}
+
+impl StringToArrayExpression {
+    pub fn new(
+        input: BoxedExpression,
+        delimiter: BoxedExpression,
+        null_str: Option<BoxedExpression>,
+        return_type: DataType,
+    ) -> Self {
+        Self {
+            input,
+            delimiter,
+            null_str,
+            return_type,
+        }
+    }
+}
+
+impl Expression for StringToArrayExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let strings = self.input.eval(input)?;
+        let strings: &Utf8Array = strings.as_ref().into();
+        let delimiters = self.delimiter.eval(input)?;
+        let delimiters: &Utf8Array = delimiters.as_ref().into();
+        let null_strs = match &self.null_str {
+            Some(null_str) => Some(null_str.eval(input)?),
+            None => None,
+        };
+        let null_strs = match &null_strs {
+            Some(null_strs) => {
+                let null_strs: &Utf8Array = null_strs.as_ref().into();
+                null_strs.iter().collect_vec()
+            }
+            None => vec![None; strings.len()],
+        };
+
+        let mut builder = self.return_type.create_array_builder(strings.len())?;
+        for ((s, delimiter), null_str) in strings.iter().zip_eq(delimiters.iter()).zip_eq(null_strs)
+        {
+            let list = s.map(|s| ScalarImpl::List(string_to_array(s, delimiter, null_str)));
+            builder.append_datum(&list)?;
+        }
+        Ok(Arc::new(builder.finish()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{DataChunkTestExt, ListValue};
+    use risingwave_common::types::Datum;
+
+    use super::*;
+    use crate::expr::InputRefExpression;
+
+    #[test]
+    fn test_string_to_array() {
+        let chunk = DataChunk::from_pretty(
+            "T     T T
+             a,b,* , *
+             ab    . .
+             .     , .",
+        );
+        let input_ref = |idx: usize| -> BoxedExpression {
+            Box::new(InputRefExpression::new(DataType::Varchar, idx))
+        };
+        let expr = StringToArrayExpression::new(
+            input_ref(0),
+            input_ref(1),
+            Some(input_ref(2)),
+            DataType::List {
+                datatype: Box::new(DataType::Varchar),
+            },
+        );
+        let output = expr.eval(&chunk).unwrap();
+        let output = (0..output.len()).map(|i| output.datum_at(i)).collect_vec();
+        let list = |values: &[Option<&str>]| -> Datum {
+            Some(
+                ListValue::new(
+                    values
+                        .iter()
+                        .map(|v| v.map(|v| ScalarImpl::Utf8(v.to_string())))
+                        .collect(),
+                )
+                .into(),
+            )
+        };
+        assert_eq!(
+            output,
+            vec![
+                list(&[Some("a"), Some("b"), None]),
+                list(&[Some("a"), Some("b")]),
+                None
+            ]
+        );
+    }
+}
@@ -25,13 +25,20 @@ use crate::expr::template::UnaryNullableExpression;
 use crate::expr::BoxedExpression;
 use crate::vector_op::arithmetic_op::{decimal_abs, general_abs, general_neg};
 use crate::vector_op::ascii::ascii;
+use crate::vector_op::bitwise_op::general_bitnot;
+use crate::vector_op::bytea::{bytea_to_str, md5, md5_str, sha256, str_to_bytea};
 use crate::vector_op::cast::*;
 use crate::vector_op::cmp::{is_false, is_not_false, is_not_true, is_true};
 use crate::vector_op::conjunction;
+use crate::vector_op::initcap::initcap;
+use crate::vector_op::jsonb::{jsonb_to_str, jsonb_typeof, str_to_jsonb};
 use crate::vector_op::length::length_default;
 use crate::vector_op::lower::lower;
 use crate::vector_op::ltrim::ltrim;
+use crate::vector_op::math::*;
+use crate::vector_op::reverse::reverse;
 use crate::vector_op::rtrim::rtrim;
+use crate::vector_op::timestampz::to_timestamp;
 use crate::vector_op::trim::trim;
 use crate::vector_op::upper::upper;
 
@@ -87,6 +94,9 @@ macro_rules! gen_cast {
             { time, time, |x| Ok(x) },
             { boolean, boolean, |x| Ok(x) },
             { varchar, varchar, |x| Ok(x.into()) },
+            { jsonb, jsonb, |x| Ok(x.to_owned_scalar()) },
+            { bytea, bytea, |x| Ok(x.into()) },
+            { uuid, uuid, |x| Ok(x) },
 
             { varchar, date, str_to_date },
             { varchar, time, str_to_time },
@@ -99,6 +109,9 @@ macro_rules! gen_cast {
             { varchar, float64, str_parse },
             { varchar, decimal, str_parse },
             { varchar, boolean, str_to_bool },
+            { varchar, jsonb, str_to_jsonb },
+            { varchar, bytea, str_to_bytea },
+            { varchar, uuid, str_parse },
 
             { boolean, varchar, general_to_string },
             { int16, varchar, general_to_string },
@@ -107,6 +120,9 @@ macro_rules! gen_cast {
             { float32, varchar, general_to_string },
             { float64, varchar, general_to_string },
             { decimal, varchar, general_to_string },
+            { jsonb, varchar, jsonb_to_str },
+            { bytea, varchar, bytea_to_str },
+            { uuid, varchar, general_to_string },
 
             { boolean, int32, general_cast },
             { int32, boolean, int32_to_bool },
@@ -144,7 +160,9 @@ macro_rules! gen_cast {
             { decimal, float32, to_f32 },
             { decimal, float64, to_f64 },
 
-            { date, timestamp, date_to_timestamp }
+            { date, timestamp, date_to_timestamp },
+            { timestamp, date, timestamp_to_date },
+            { timestamp, time, timestamp_to_time }
         }
     };
 }
@@ -204,6 +222,18 @@ macro_rules! gen_unary_atm_expr  {
     };
 }
 
+/// Creates a math function on `double precision`, and optionally on `numeric`. The frontend casts
+/// the other numeric types to one of them.
+macro_rules! gen_unary_math_expr {
+    ($expr_name: literal, $child:expr, $ret:expr, $float_func:ident $(, $decimal_func:ident)? $(,)?) => {
+        gen_unary_impl! {
+            [$expr_name, $child, $ret],
+            { float64, float64, $float_func },
+            $( { decimal, decimal, $decimal_func }, )?
+        }
+    };
+}
+
 pub fn new_unary_expr(
     expr_type: ProstType,
     return_type: DataType,
@@ -212,6 +242,21 @@ pub fn new_unary_expr(
     use crate::expr::data_types::*;
 
     let expr: BoxedExpression = match (expr_type, return_type.clone(), child_expr.return_type()) {
+        // Casts to and from `CHAR(n)` are not covered by `gen_cast`, as they depend on the length.
+        (ProstType::Cast, DataType::Char { len }, DataType::Varchar | DataType::Char { .. }) => {
+            Box::new(UnaryExpression::<Utf8Array, Utf8Array, _>::new(
+                child_expr,
+                return_type,
+                move |s: &str| str_to_char(s, len),
+            ))
+        }
+        (ProstType::Cast, DataType::Varchar, DataType::Char { .. }) => {
+            Box::new(UnaryExpression::<Utf8Array, Utf8Array, _>::new(
+                child_expr,
+                return_type,
+                char_to_str,
+            ))
+        }
         (ProstType::Cast, _, _) => gen_cast! { child_expr, return_type, },
         (ProstType::Not, _, _) => {
             Box::new(UnaryNullableExpression::<BoolArray, BoolArray, _>::new(
@@ -260,11 +305,42 @@ pub fn new_unary_expr(
             return_type,
             lower,
         )),
+        (ProstType::Reverse, _, _) => Box::new(UnaryBytesExpression::<Utf8Array, _>::new(
+            child_expr,
+            return_type,
+            reverse,
+        )),
+        (ProstType::Initcap, _, _) => Box::new(UnaryBytesExpression::<Utf8Array, _>::new(
+            child_expr,
+            return_type,
+            initcap,
+        )),
         (ProstType::Ascii, _, _) => Box::new(UnaryExpression::<Utf8Array, I32Array, _>::new(
             child_expr,
             return_type,
             ascii,
         )),
+        (ProstType::JsonbTypeof, _, _) => Box::new(
+            UnaryExpression::<JsonbArray, Utf8Array, _>::new(child_expr, return_type, jsonb_typeof),
+        ),
+        (ProstType::Md5, _, DataType::Bytea) => Box::new(
+            UnaryExpression::<BytesArray, Utf8Array, _>::new(child_expr, return_type, md5),
+        ),
+        (ProstType::Md5, _, _) => Box::new(UnaryExpression::<Utf8Array, Utf8Array, _>::new(
+            child_expr,
+            return_type,
+            md5_str,
+        )),
+        (ProstType::Sha256, _, _) => Box::new(UnaryExpression::<BytesArray, BytesArray, _>::new(
+            child_expr,
+            return_type,
+            sha256,
+        )),
+        (ProstType::ToTimestamp, _, _) => Box::new(UnaryExpression::<F64Array, I64Array, _>::new(
+            child_expr,
+            return_type,
+            to_timestamp,
+        )),
         (ProstType::Neg, _, _) => {
             gen_unary_atm_expr! { "Neg", child_expr, return_type, general_neg,
                 {
@@ -279,6 +355,57 @@ pub fn new_unary_expr(
                 }
             }
         }
+        (ProstType::BitwiseNot, _, _) => {
+            gen_unary_impl! {
+                ["BitwiseNot", child_expr, return_type],
+                { int16, int16, general_bitnot },
+                { int32, int32, general_bitnot },
+                { int64, int64, general_bitnot },
+            }
+        }
+        (ProstType::Sqrt, _, _) => {
+            gen_unary_math_expr! { "Sqrt", child_expr, return_type, sqrt_f64, sqrt_decimal }
+        }
+        (ProstType::Exp, _, _) => {
+            gen_unary_math_expr! { "Exp", child_expr, return_type, exp_f64, exp_decimal }
+        }
+        (ProstType::Ln, _, _) => {
+            gen_unary_math_expr! { "Ln", child_expr, return_type, ln_f64, ln_decimal }
+        }
+        (ProstType::Log10, _, _) => {
+            gen_unary_math_expr! { "Log10", child_expr, return_type, log10_f64, log10_decimal }
+        }
+        (ProstType::Floor, _, _) => {
+            gen_unary_math_expr! { "Floor", child_expr, return_type, floor_f64, floor_decimal }
+        }
+        (ProstType::Ceil, _, _) => {
+            gen_unary_math_expr! { "Ceil", child_expr, return_type, ceil_f64, ceil_decimal }
+        }
+        (ProstType::Trunc, _, _) => {
+            gen_unary_math_expr! { "Trunc", child_expr, return_type, trunc_f64, trunc_decimal }
+        }
+        (ProstType::Sign, _, _) => {
+            gen_unary_math_expr! { "Sign", child_expr, return_type, sign_f64, sign_decimal }
+        }
+        (ProstType::Cbrt, _, _) => {
+            gen_unary_math_expr! { "Cbrt", child_expr, return_type, cbrt_f64 }
+        }
+        (ProstType::Round, _, _) => {
+            gen_unary_math_expr! { "Round", child_expr, return_type, round_f64 }
+        }
+        (ProstType::Sin, _, _) => gen_unary_math_expr! { "Sin", child_expr, return_type, sin_f64 },
+        (ProstType::Cos, _, _) => gen_unary_math_expr! { "Cos", child_expr, return_type, cos_f64 },
+        (ProstType::Tan, _, _) => gen_unary_math_expr! { "Tan", child_expr, return_type, tan_f64 },
+        (ProstType::Cot, _, _) => gen_unary_math_expr! { "Cot", child_expr, return_type, cot_f64 },
+        (ProstType::Asin, _, _) => {
+            gen_unary_math_expr! { "Asin", child_expr, return_type, asin_f64 }
+        }
+        (ProstType::Acos, _, _) => {
+            gen_unary_math_expr! { "Acos", child_expr, return_type, acos_f64 }
+        }
+        (ProstType::Atan, _, _) => {
+            gen_unary_math_expr! { "Atan", child_expr, return_type, atan_f64 }
+        }
         (expr, ret, child) => {
             return Err(ErrorCode::NotImplemented(format!(
                 "The expression {:?}({:?}) ->{:?} using vectorized expression framework is not supported yet.",
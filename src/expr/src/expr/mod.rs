@@ -15,6 +15,7 @@
 mod agg;
 pub mod build_expr_from_prost;
 pub mod data_types;
+mod expr_array;
 mod expr_binary_bytes;
 pub mod expr_binary_nonnull;
 pub mod expr_binary_nullable;
@@ -25,7 +26,10 @@ mod expr_field;
 mod expr_in;
 mod expr_input_ref;
 mod expr_is_null;
+mod expr_jsonb_extract_path;
 mod expr_literal;
+pub mod expr_regexp;
+mod expr_string_to_array;
 mod expr_ternary_bytes;
 pub mod expr_unary;
 mod template;
@@ -47,6 +51,7 @@ use crate::expr::build_expr_from_prost::*;
 use crate::expr::expr_coalesce::CoalesceExpression;
 use crate::expr::expr_concat_ws::ConcatWsExpression;
 use crate::expr::expr_field::FieldExpression;
+use crate::expr::expr_jsonb_extract_path::JsonbExtractPathExpression;
 
 pub type ExpressionRef = Arc<dyn Expression>;
 
@@ -76,16 +81,30 @@ pub fn build_from_prost(prost: &ExprNode) -> Result<BoxedExpression> {
 
     match prost.get_expr_type()? {
         Cast | Upper | Lower | Not | IsTrue | IsNotTrue | IsFalse | IsNotFalse | IsNull
-        | IsNotNull | Neg | Ascii | Abs => build_unary_expr_prost(prost),
-        Equal | NotEqual | LessThan | LessThanOrEqual | GreaterThan | GreaterThanOrEqual | Add
-        | Subtract | Multiply | Divide | Modulus | Extract | RoundDigit | TumbleStart
-        | Position => build_binary_expr_prost(prost),
-        And | Or => build_nullable_binary_expr_prost(prost),
+        | IsNotNull | Neg | Ascii | Abs | JsonbTypeof | Md5 | Sha256 | ToTimestamp | BitwiseNot
+        | Sqrt | Cbrt | Exp | Ln | Log10 | Floor | Ceil | Trunc | Sign | Round | Sin | Cos
+        | Tan | Cot | Asin | Acos | Atan | Reverse | Initcap => build_unary_expr_prost(prost),
+        Equal | NotEqual | LessThan | LessThanOrEqual | GreaterThan | GreaterThanOrEqual
+        | Multiply | Divide | Modulus | RoundDigit | TumbleStart | Position | JsonbContains
+        | JsonbExists | Encode | Decode | AtTimeZone | CastWithTimeZone | ToChar | Age
+        | BitwiseAnd | BitwiseOr | BitwiseXor | BitwiseShiftLeft | BitwiseShiftRight | Pow
+        | Atan2 => build_binary_expr_prost(prost),
+        Add | Subtract => build_add_sub_expr(prost),
+        And | Or | JsonbAccessInner | JsonbAccessStr | JsonbAccessPath => {
+            build_nullable_binary_expr_prost(prost)
+        }
         Coalesce => CoalesceExpression::try_from(prost).map(|d| Box::new(d) as BoxedExpression),
+        Extract => build_extract_expr(prost),
+        DateTrunc => build_date_trunc_expr(prost),
+        MakeDate => build_make_date_expr(prost),
+        Now => build_now_expr(prost),
         Substr => build_substr_expr(prost),
         Length => build_length_expr(prost),
         Replace => build_replace_expr(prost),
-        Like => build_like_expr(prost),
+        Like | Ilike => build_like_expr(prost),
+        RegexpLike | SimilarTo => build_regexp_like_expr(prost),
+        RegexpMatch => build_regexp_match_expr(prost),
+        RegexpReplace => build_regexp_replace_expr(prost),
         Trim => build_trim_expr(prost),
         Ltrim => build_ltrim_expr(prost),
         Rtrim => build_rtrim_expr(prost),
@@ -94,8 +113,18 @@ pub fn build_from_prost(prost: &ExprNode) -> Result<BoxedExpression> {
         InputRef => InputRefExpression::try_from(prost).map(|d| Box::new(d) as BoxedExpression),
         Case => build_case_expr(prost),
         Translate => build_translate_expr(prost),
+        SplitPart => build_split_part_expr(prost),
+        Lpad | Rpad => build_pad_expr(prost),
+        Repeat | Left | Right | ConcatOp => build_binary_bytes_expr(prost),
+        StringToArray => build_string_to_array_expr(prost),
         In => build_in_expr(prost),
+        Array | ArrayAccess | ArrayLength | Cardinality | ArrayAppend | ArrayCat
+        | ArrayPosition | ArrayContains | ArrayOverlap | ArrayToString => build_array_expr(prost),
+        ArrayAny | ArrayAll => build_array_any_all_expr(prost),
         Field => FieldExpression::try_from(prost).map(|d| Box::new(d) as BoxedExpression),
+        JsonbExtractPath => {
+            JsonbExtractPathExpression::try_from(prost).map(|d| Box::new(d) as BoxedExpression)
+        }
         _ => Err(InternalError(format!(
             "Unsupported expression type: {:?}",
             prost.get_expr_type()
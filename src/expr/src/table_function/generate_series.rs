@@ -0,0 +1,184 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use risingwave_common::array::{ArrayRef, DataChunk};
+use risingwave_common::error::ErrorCode::{InternalError, InvalidParameterValue};
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::{CheckedAdd, DataType, ScalarImpl, ScalarRefImpl};
+
+use super::TableFunction;
+use crate::expr::BoxedExpression;
+
+/// `generate_series(start, stop, step)` returns the values from `start` to `stop` with a step of
+/// `step`, which can be negative. It is defined on integers, and on timestamps with an interval
+/// step. The series stops before overflowing, as in Postgres.
+#[derive(Debug)]
+pub struct GenerateSeries {
+    start: BoxedExpression,
+    stop: BoxedExpression,
+    step: BoxedExpression,
+    return_type: DataType,
+}
+
+impl GenerateSeries {
+    pub fn new(
+        start: BoxedExpression,
+        stop: BoxedExpression,
+        step: BoxedExpression,
+        return_type: DataType,
+    ) -> Self {
+        Self {
+            start,
+            stop,
+            step,
+            return_type,
+        }
+    }
+}
+
+fn zero_step() -> RwError {
+    InvalidParameterValue("step size cannot equal zero".to_string()).into()
+}
+
+fn series<T, S>(start: T, stop: T, step: S, increasing: bool, output: &mut Vec<ScalarImpl>)
+where
+    T: CheckedAdd<S> + PartialOrd + Into<ScalarImpl> + Copy,
+    S: Copy,
+{
+    let mut cur = start;
+    while (increasing && cur <= stop) || (!increasing && cur >= stop) {
+        output.push(cur.into());
+        cur = match cur.checked_add(step) {
+            Ok(next) => next,
+            Err(_) => break,
+        };
+    }
+}
+
+impl TableFunction for GenerateSeries {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<Vec<ArrayRef>> {
+        let starts = self.start.eval(input)?;
+        let stops = self.stop.eval(input)?;
+        let steps = self.step.eval(input)?;
+
+        let mut output = Vec::with_capacity(starts.len());
+        let mut values = vec![];
+        for i in 0..starts.len() {
+            values.clear();
+            match (starts.value_at(i), stops.value_at(i), steps.value_at(i)) {
+                (
+                    Some(ScalarRefImpl::Int32(start)),
+                    Some(ScalarRefImpl::Int32(stop)),
+                    Some(ScalarRefImpl::Int32(step)),
+                ) => {
+                    if step == 0 {
+                        return Err(zero_step());
+                    }
+                    series(start, stop, step, step > 0, &mut values);
+                }
+                (
+                    Some(ScalarRefImpl::Int64(start)),
+                    Some(ScalarRefImpl::Int64(stop)),
+                    Some(ScalarRefImpl::Int64(step)),
+                ) => {
+                    if step == 0 {
+                        return Err(zero_step());
+                    }
+                    series(start, stop, step, step > 0, &mut values);
+                }
+                (
+                    Some(ScalarRefImpl::NaiveDateTime(start)),
+                    Some(ScalarRefImpl::NaiveDateTime(stop)),
+                    Some(ScalarRefImpl::Interval(step)),
+                ) => {
+                    // The direction of an interval depends on the timestamp it is added to.
+                    let next = start.checked_add(step)?;
+                    if next == start {
+                        return Err(zero_step());
+                    }
+                    series(start, stop, step, next > start, &mut values);
+                }
+                (Some(_), Some(_), Some(_)) => {
+                    return Err(InternalError(format!(
+                        "generate_series is not defined on {:?}",
+                        self.return_type
+                    ))
+                    .into())
+                }
+                _ => {}
+            }
+            let mut builder = self.return_type.create_array_builder(values.len())?;
+            for value in &values {
+                builder.append_datum_ref(Some(value.as_scalar_ref_impl()))?;
+            }
+            output.push(Arc::new(builder.finish()?));
+        }
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use risingwave_common::array::DataChunkTestExt;
+
+    use super::*;
+    use crate::expr::InputRefExpression;
+
+    fn eval(chunk: &str) -> Result<Vec<Vec<i32>>> {
+        let chunk = DataChunk::from_pretty(chunk);
+        let input_ref = |idx: usize| -> BoxedExpression {
+            Box::new(InputRefExpression::new(DataType::Int32, idx))
+        };
+        let func = GenerateSeries::new(input_ref(0), input_ref(1), input_ref(2), DataType::Int32);
+        let output = func.eval(&chunk)?;
+        Ok(output
+            .iter()
+            .map(|array| {
+                (0..array.len())
+                    .map(|i| array.datum_at(i).unwrap().into_int32())
+                    .collect_vec()
+            })
+            .collect_vec())
+    }
+
+    #[test]
+    fn test_generate_series() {
+        assert_eq!(
+            eval(
+                "i  i i
+                 1  3 1
+                 5  1 -2
+                 1  0 1
+                 .  3 1
+                 2147483646 2147483647 1"
+            )
+            .unwrap(),
+            vec![
+                vec![1, 2, 3],
+                vec![5, 3, 1],
+                vec![],
+                vec![],
+                vec![2147483646, 2147483647]
+            ]
+        );
+        assert!(eval("i i i\n 1 3 0").is_err());
+    }
+}
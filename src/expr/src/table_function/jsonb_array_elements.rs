@@ -0,0 +1,122 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use risingwave_common::array::{
+    Array, ArrayBuilder, ArrayImpl, ArrayRef, DataChunk, JsonbArrayBuilder,
+};
+use risingwave_common::error::Result;
+use risingwave_common::types::DataType;
+
+use super::TableFunction;
+use crate::expr::BoxedExpression;
+
+/// `jsonb_array_elements(jsonb)` expands a json array into a set of json values.
+#[derive(Debug)]
+pub struct JsonbArrayElements {
+    arg: BoxedExpression,
+    return_type: DataType,
+}
+
+impl JsonbArrayElements {
+    pub fn new(arg: BoxedExpression, return_type: DataType) -> Self {
+        Self { arg, return_type }
+    }
+}
+
+impl TableFunction for JsonbArrayElements {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<Vec<ArrayRef>> {
+        let arg = self.arg.eval(input)?;
+        let arg = arg.as_jsonb();
+
+        let mut output = Vec::with_capacity(input.cardinality());
+        for value in arg.iter() {
+            let mut builder = JsonbArrayBuilder::new(0)?;
+            if let Some(value) = value {
+                for element in value.array_elements()? {
+                    builder.append(Some(element))?;
+                }
+            }
+            output.push(Arc::new(ArrayImpl::from(builder.finish()?)));
+        }
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use risingwave_common::array::column::Column;
+    use risingwave_common::array::JsonbVal;
+    use risingwave_common::types::Scalar;
+
+    use super::*;
+    use crate::expr::InputRefExpression;
+
+    #[test]
+    fn test_jsonb_array_elements() {
+        let mut builder = JsonbArrayBuilder::new(3).unwrap();
+        for v in [Some("[1, \"a\", [2]]"), None, Some("[]")] {
+            let v = v.map(|v| JsonbVal::from_str(v).unwrap());
+            builder
+                .append(v.as_ref().map(|v| v.as_scalar_ref()))
+                .unwrap();
+        }
+        let chunk = DataChunk::builder()
+            .columns(vec![Column::new(Arc::new(
+                builder.finish().unwrap().into(),
+            ))])
+            .build();
+
+        let func = JsonbArrayElements::new(
+            Box::new(InputRefExpression::new(DataType::Jsonb, 0)),
+            DataType::Jsonb,
+        );
+        let output = func.eval(&chunk).unwrap();
+        let output = output
+            .iter()
+            .map(|array| {
+                array
+                    .iter()
+                    .map(|v| v.unwrap().into_jsonb().to_json_text())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            output,
+            vec![
+                vec!["1".to_string(), "\"a\"".to_string(), "[2]".to_string()],
+                vec![],
+                vec![],
+            ]
+        );
+
+        let mut builder = JsonbArrayBuilder::new(1).unwrap();
+        builder
+            .append(Some(JsonbVal::from_str("{}").unwrap().as_scalar_ref()))
+            .unwrap();
+        let chunk = DataChunk::builder()
+            .columns(vec![Column::new(Arc::new(
+                builder.finish().unwrap().into(),
+            ))])
+            .build();
+        assert!(func.eval(&chunk).is_err());
+    }
+}
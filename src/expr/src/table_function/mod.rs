@@ -0,0 +1,164 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Table functions (a.k.a. set-returning functions), which produce zero or more rows for each
+//! input row.
+
+mod generate_series;
+mod jsonb_array_elements;
+mod regexp_matches;
+mod regexp_split_to_table;
+mod unnest;
+
+pub use generate_series::GenerateSeries;
+pub use jsonb_array_elements::JsonbArrayElements;
+pub use regexp_matches::RegexpMatches;
+pub use regexp_split_to_table::RegexpSplitToTable;
+use risingwave_common::array::{ArrayRef, DataChunk};
+use risingwave_common::ensure;
+use risingwave_common::error::ErrorCode::{InternalError, InvalidInputSyntax};
+use risingwave_common::error::Result;
+use risingwave_common::types::DataType;
+use risingwave_pb::expr::project_set_select_item::SelectItem;
+use risingwave_pb::expr::table_function::Type;
+use risingwave_pb::expr::{
+    ExprNode, ProjectSetSelectItem as ProjectSetSelectItemProst,
+    TableFunction as TableFunctionProst,
+};
+pub use unnest::Unnest;
+
+use crate::expr::build_expr_from_prost::{build_regexp_pattern, get_regexp_flags};
+use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression};
+use crate::vector_op::regexp::RegexpContext;
+
+/// Instance of a table function.
+pub trait TableFunction: std::fmt::Debug + Sync + Send {
+    fn return_type(&self) -> DataType;
+
+    /// Evaluate the table function on each row of `input`.
+    ///
+    /// Returns one array for each input row, holding the output rows produced by that row.
+    fn eval(&self, input: &DataChunk) -> Result<Vec<ArrayRef>>;
+
+    fn boxed(self) -> BoxedTableFunction
+    where
+        Self: Sized + Send + 'static,
+    {
+        Box::new(self)
+    }
+}
+
+pub type BoxedTableFunction = Box<dyn TableFunction>;
+
+pub fn build_table_function_from_prost(prost: &TableFunctionProst) -> Result<BoxedTableFunction> {
+    let return_type = DataType::from(prost.get_return_type()?);
+    match prost.get_function_type()? {
+        Type::RegexpMatches => return build_regexp_matches(prost.get_args(), return_type),
+        Type::RegexpSplitToTable => return build_regexp_split_to_table(prost.get_args()),
+        _ => {}
+    }
+    let args = prost
+        .get_args()
+        .iter()
+        .map(expr_build_from_prost)
+        .collect::<Result<Vec<_>>>()?;
+
+    match prost.get_function_type()? {
+        Type::JsonbArrayElements => {
+            let [arg]: [_; 1] = args.try_into().map_err(|_| {
+                InternalError("jsonb_array_elements expects exactly one argument".to_string())
+            })?;
+            Ok(JsonbArrayElements::new(arg, return_type).boxed())
+        }
+        Type::Unnest => {
+            let [arg]: [_; 1] = args
+                .try_into()
+                .map_err(|_| InternalError("unnest expects exactly one argument".to_string()))?;
+            Ok(Unnest::new(arg, return_type).boxed())
+        }
+        Type::GenerateSeries => {
+            let [start, stop, step]: [_; 3] = args.try_into().map_err(|_| {
+                InternalError("generate_series expects exactly three arguments".to_string())
+            })?;
+            Ok(GenerateSeries::new(start, stop, step, return_type).boxed())
+        }
+        Type::RegexpMatches | Type::RegexpSplitToTable | Type::Unspecified => {
+            Err(InternalError(format!(
+                "Unsupported table function type: {:?}",
+                prost.get_function_type()
+            ))
+            .into())
+        }
+    }
+}
+
+/// The pattern of `regexp_matches` is compiled once if it is a constant, so it is built from the
+/// protobuf rather than as an expression.
+fn build_regexp_matches(args: &[ExprNode], return_type: DataType) -> Result<BoxedTableFunction> {
+    ensure!(args.len() == 2 || args.len() == 3);
+    let input = expr_build_from_prost(&args[0])?;
+    let pattern = match get_regexp_flags(args.get(2))? {
+        Some(flags) => {
+            let compile = Box::new(move |pattern: &str| RegexpContext::new(pattern, &flags));
+            build_regexp_pattern(&args[1], compile)?
+        }
+        None => None,
+    };
+    Ok(RegexpMatches::new(input, pattern, return_type).boxed())
+}
+
+/// Same as `regexp_matches`, but the `g` flag is not allowed.
+fn build_regexp_split_to_table(args: &[ExprNode]) -> Result<BoxedTableFunction> {
+    ensure!(args.len() == 2 || args.len() == 3);
+    let input = expr_build_from_prost(&args[0])?;
+    let pattern = match get_regexp_flags(args.get(2))? {
+        Some(flags) => {
+            if flags.contains('g') {
+                return Err(InvalidInputSyntax(
+                    "regexp_split_to_table() does not support the \"global\" option".to_string(),
+                )
+                .into());
+            }
+            let compile = Box::new(move |pattern: &str| RegexpContext::new(pattern, &flags));
+            build_regexp_pattern(&args[1], compile)?
+        }
+        None => None,
+    };
+    Ok(RegexpSplitToTable::new(input, pattern).boxed())
+}
+
+/// An item in the select list of `ProjectSet`, which is either a table function or an expression.
+#[derive(Debug)]
+pub enum ProjectSetSelectItem {
+    TableFunction(BoxedTableFunction),
+    Expr(BoxedExpression),
+}
+
+impl ProjectSetSelectItem {
+    pub fn from_prost(prost: &ProjectSetSelectItemProst) -> Result<Self> {
+        match prost.select_item.as_ref().unwrap() {
+            SelectItem::Expr(expr) => expr_build_from_prost(expr).map(Self::Expr),
+            SelectItem::TableFunction(tf) => {
+                build_table_function_from_prost(tf).map(Self::TableFunction)
+            }
+        }
+    }
+
+    pub fn return_type(&self) -> DataType {
+        match self {
+            Self::TableFunction(tf) => tf.return_type(),
+            Self::Expr(expr) => expr.return_type(),
+        }
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use itertools::Itertools;
+use risingwave_common::array::{Array, ArrayRef, DataChunk, Utf8Array};
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use super::TableFunction;
+use crate::expr::expr_regexp::RegexpPattern;
+use crate::expr::BoxedExpression;
+use crate::vector_op::regexp::regexp_matches;
+
+/// `regexp_matches(string, pattern [, flags])` returns the captures of the first match of the
+/// pattern, or of all the matches with the `g` flag, as a set of `varchar[]`.
+#[derive(Debug)]
+pub struct RegexpMatches {
+    input: BoxedExpression,
+    /// `None` if the pattern or the flags are NULL, in which case there is no output row.
+    pattern: Option<RegexpPattern>,
+    return_type: DataType,
+}
+
+impl RegexpMatches {
+    pub fn new(
+        input: BoxedExpression,
+        pattern: Option<RegexpPattern>,
+        return_type: DataType,
+    ) -> Self {
+        Self {
+            input,
+            pattern,
+            return_type,
+        }
+    }
+
+    fn matches(&self, s: Option<&str>, pattern: Option<&str>) -> Result<ArrayRef> {
+        let matches = match (s, &self.pattern, pattern) {
+            (Some(s), Some(RegexpPattern::Constant(ctx)), _) => regexp_matches(s, ctx),
+            (Some(s), Some(RegexpPattern::Dynamic { compile, .. }), Some(pattern)) => {
+                regexp_matches(s, &compile(pattern)?)
+            }
+            _ => vec![],
+        };
+        let mut builder = self.return_type.create_array_builder(matches.len())?;
+        for m in matches {
+            builder.append_datum(&Some(ScalarImpl::List(m)))?;
+        }
+        Ok(Arc::new(builder.finish()?))
+    }
+}
+
+impl TableFunction for RegexpMatches {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<Vec<ArrayRef>> {
+        let strings = self.input.eval(input)?;
+        let strings: &Utf8Array = strings.as_ref().into();
+        let patterns = match &self.pattern {
+            Some(RegexpPattern::Dynamic { pattern, .. }) => Some(pattern.eval(input)?),
+            _ => None,
+        };
+        let patterns = match &patterns {
+            Some(patterns) => {
+                let patterns: &Utf8Array = patterns.as_ref().into();
+                patterns.iter().collect_vec()
+            }
+            None => vec![None; strings.len()],
+        };
+
+        let mut output = Vec::with_capacity(strings.len());
+        for (s, pattern) in strings.iter().zip_eq(patterns) {
+            output.push(self.matches(s, pattern)?);
+        }
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::column::Column;
+    use risingwave_common::array::ListValue;
+
+    use super::*;
+    use crate::expr::InputRefExpression;
+    use crate::vector_op::regexp::RegexpContext;
+
+    #[test]
+    fn test_regexp_matches() {
+        let strings = Utf8Array::from_slice(&[Some("a1b22"), None, Some("c")]).unwrap();
+        let chunk = DataChunk::new(vec![Column::new(Arc::new(strings.into()))], None);
+        let func = RegexpMatches::new(
+            Box::new(InputRefExpression::new(DataType::Varchar, 0)),
+            Some(RegexpPattern::Constant(
+                RegexpContext::new("[a-z](\\d+)", "g").unwrap(),
+            )),
+            DataType::List {
+                datatype: Box::new(DataType::Varchar),
+            },
+        );
+        let output = func.eval(&chunk).unwrap();
+        let output = output
+            .iter()
+            .map(|array| {
+                (0..array.len())
+                    .map(|i| array.datum_at(i).unwrap().into_list())
+                    .collect_vec()
+            })
+            .collect_vec();
+        let list = |v: &str| ListValue::new(vec![Some(ScalarImpl::Utf8(v.to_string()))]);
+        assert_eq!(output, vec![vec![list("1"), list("22")], vec![], vec![]]);
+    }
+}
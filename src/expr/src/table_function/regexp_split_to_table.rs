@@ -0,0 +1,115 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use itertools::Itertools;
+use risingwave_common::array::{
+    Array, ArrayBuilder, ArrayRef, DataChunk, Utf8Array, Utf8ArrayBuilder,
+};
+use risingwave_common::error::Result;
+use risingwave_common::types::DataType;
+
+use super::TableFunction;
+use crate::expr::expr_regexp::RegexpPattern;
+use crate::expr::BoxedExpression;
+use crate::vector_op::regexp::regexp_split;
+
+/// `regexp_split_to_table(string, pattern [, flags])` splits the string at the matches of the
+/// pattern into a set of `varchar`.
+#[derive(Debug)]
+pub struct RegexpSplitToTable {
+    input: BoxedExpression,
+    /// `None` if the pattern or the flags are NULL, in which case there is no output row.
+    pattern: Option<RegexpPattern>,
+}
+
+impl RegexpSplitToTable {
+    pub fn new(input: BoxedExpression, pattern: Option<RegexpPattern>) -> Self {
+        Self { input, pattern }
+    }
+
+    fn split(&self, s: Option<&str>, pattern: Option<&str>) -> Result<ArrayRef> {
+        let parts = match (s, &self.pattern, pattern) {
+            (Some(s), Some(RegexpPattern::Constant(ctx)), _) => regexp_split(s, ctx),
+            (Some(s), Some(RegexpPattern::Dynamic { compile, .. }), Some(pattern)) => {
+                regexp_split(s, &compile(pattern)?)
+            }
+            _ => vec![],
+        };
+        let mut builder = Utf8ArrayBuilder::new(parts.len())?;
+        for part in parts {
+            builder.append(Some(part))?;
+        }
+        Ok(Arc::new(builder.finish()?.into()))
+    }
+}
+
+impl TableFunction for RegexpSplitToTable {
+    fn return_type(&self) -> DataType {
+        DataType::Varchar
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<Vec<ArrayRef>> {
+        let strings = self.input.eval(input)?;
+        let strings: &Utf8Array = strings.as_ref().into();
+        let patterns = match &self.pattern {
+            Some(RegexpPattern::Dynamic { pattern, .. }) => Some(pattern.eval(input)?),
+            _ => None,
+        };
+        let patterns = match &patterns {
+            Some(patterns) => {
+                let patterns: &Utf8Array = patterns.as_ref().into();
+                patterns.iter().collect_vec()
+            }
+            None => vec![None; strings.len()],
+        };
+
+        let mut output = Vec::with_capacity(strings.len());
+        for (s, pattern) in strings.iter().zip_eq(patterns) {
+            output.push(self.split(s, pattern)?);
+        }
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::column::Column;
+
+    use super::*;
+    use crate::expr::InputRefExpression;
+    use crate::vector_op::regexp::RegexpContext;
+
+    #[test]
+    fn test_regexp_split_to_table() {
+        let strings = Utf8Array::from_slice(&[Some("a, b,c"), None]).unwrap();
+        let chunk = DataChunk::new(vec![Column::new(Arc::new(strings.into()))], None);
+        let func = RegexpSplitToTable::new(
+            Box::new(InputRefExpression::new(DataType::Varchar, 0)),
+            Some(RegexpPattern::Constant(
+                RegexpContext::new(",\\s*", "").unwrap(),
+            )),
+        );
+        let output = func.eval(&chunk).unwrap();
+        let output = output
+            .iter()
+            .map(|array| {
+                let array: &Utf8Array = array.as_ref().into();
+                array.iter().map(|v| v.unwrap().to_string()).collect_vec()
+            })
+            .collect_vec();
+        assert_eq!(output, vec![vec!["a", "b", "c"], vec![]]);
+    }
+}
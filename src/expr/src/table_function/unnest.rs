@@ -0,0 +1,128 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use risingwave_common::array::{ArrayRef, DataChunk, ListRef};
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, DatumRef, ScalarRefImpl};
+
+use super::TableFunction;
+use crate::expr::BoxedExpression;
+
+/// `unnest(list)` expands a list into a set of its elements. Multi-dimensional lists are flattened
+/// as in Postgres, so the return type is the innermost element type.
+#[derive(Debug)]
+pub struct Unnest {
+    list: BoxedExpression,
+    return_type: DataType,
+}
+
+impl Unnest {
+    pub fn new(list: BoxedExpression, return_type: DataType) -> Self {
+        Self { list, return_type }
+    }
+}
+
+fn flatten<'a>(list: ListRef<'a>, output: &mut Vec<DatumRef<'a>>) {
+    for value in list.values_ref() {
+        match value {
+            Some(ScalarRefImpl::List(inner)) => flatten(inner, output),
+            value => output.push(value),
+        }
+    }
+}
+
+impl TableFunction for Unnest {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<Vec<ArrayRef>> {
+        let lists = self.list.eval(input)?;
+
+        let mut output = Vec::with_capacity(lists.len());
+        let mut values = vec![];
+        for i in 0..lists.len() {
+            values.clear();
+            if let Some(list) = lists.value_at(i) {
+                flatten(list.into_list(), &mut values);
+            }
+            let mut builder = self.return_type.create_array_builder(values.len())?;
+            for value in &values {
+                builder.append_datum_ref(*value)?;
+            }
+            output.push(Arc::new(builder.finish()?));
+        }
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use risingwave_common::array::{DataChunkTestExt, ListValue};
+    use risingwave_common::types::{Datum, ScalarImpl};
+
+    use super::*;
+    use crate::expr::LiteralExpression;
+
+    #[test]
+    fn test_unnest() {
+        let list = |values: Vec<Datum>| Some(ScalarImpl::List(ListValue::new(values)));
+        let nested = list(vec![
+            list(vec![Some(ScalarImpl::Int32(1)), None]),
+            list(vec![Some(ScalarImpl::Int32(3))]),
+        ]);
+        let func = Unnest::new(
+            Box::new(LiteralExpression::new(
+                DataType::List {
+                    datatype: Box::new(DataType::List {
+                        datatype: Box::new(DataType::Int32),
+                    }),
+                },
+                nested,
+            )),
+            DataType::Int32,
+        );
+        let output = func.eval(&DataChunk::new_dummy(1)).unwrap();
+        let output = output
+            .iter()
+            .map(|array| (0..array.len()).map(|i| array.datum_at(i)).collect_vec())
+            .collect_vec();
+        assert_eq!(
+            output,
+            vec![vec![
+                Some(ScalarImpl::Int32(1)),
+                None,
+                Some(ScalarImpl::Int32(3))
+            ]]
+        );
+
+        let func = Unnest::new(
+            Box::new(LiteralExpression::new(
+                DataType::List {
+                    datatype: Box::new(DataType::Int32),
+                },
+                None,
+            )),
+            DataType::Int32,
+        );
+        let output = func.eval(&DataChunk::from_pretty("i\n1\n2")).unwrap();
+        assert_eq!(
+            output.iter().map(|array| array.len()).collect_vec(),
+            vec![0, 0]
+        );
+    }
+}
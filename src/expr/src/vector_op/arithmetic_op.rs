@@ -16,12 +16,13 @@ use std::any::type_name;
 use std::convert::TryInto;
 use std::fmt::Debug;
 
+use chrono::{Datelike, Duration, NaiveDate};
 use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, Signed};
 use risingwave_common::error::ErrorCode::{InternalError, NumericValueOutOfRange};
 use risingwave_common::error::{Result, RwError};
 use risingwave_common::types::{
     CheckedAdd as NaiveDateTimeCheckedAdd, Decimal, IntervalUnit, NaiveDateTimeWrapper,
-    NaiveDateWrapper,
+    NaiveDateWrapper, NaiveTimeWrapper,
 };
 
 use super::cast::date_to_timestamp;
@@ -137,13 +138,122 @@ where
     atm(l, r)
 }
 
+/// Converts a duration to an interval of days and the milliseconds less than a day.
+fn duration_to_interval(d: Duration) -> IntervalUnit {
+    let days = d.num_days();
+    let ms = (d - Duration::days(days)).num_milliseconds();
+    IntervalUnit::new(0, days as i32, ms)
+}
+
 #[inline(always)]
 pub fn timestamp_timestamp_sub<T1, T2, T3>(
     l: NaiveDateTimeWrapper,
     r: NaiveDateTimeWrapper,
 ) -> Result<IntervalUnit> {
-    let tmp = l.0 - r.0;
-    Ok(IntervalUnit::new(0, tmp.num_days() as i32, 0))
+    Ok(duration_to_interval(l.0 - r.0))
+}
+
+#[inline(always)]
+pub fn timestampz_timestampz_sub<T1, T2, T3>(l: i64, r: i64) -> Result<IntervalUnit> {
+    let d = l
+        .checked_sub(r)
+        .ok_or_else(|| RwError::from(NumericValueOutOfRange))?;
+    Ok(duration_to_interval(Duration::microseconds(d)))
+}
+
+#[inline(always)]
+pub fn time_time_sub<T1, T2, T3>(l: NaiveTimeWrapper, r: NaiveTimeWrapper) -> Result<IntervalUnit> {
+    Ok(IntervalUnit::from_millis((l.0 - r.0).num_milliseconds()))
+}
+
+#[inline(always)]
+pub fn date_int_add<T1, T2, T3>(l: NaiveDateWrapper, r: i32) -> Result<NaiveDateWrapper> {
+    l.0.checked_add_signed(Duration::days(r as i64))
+        .map(NaiveDateWrapper::new)
+        .ok_or_else(|| InternalError("Date out of range".to_string()).into())
+}
+
+#[inline(always)]
+pub fn int_date_add<T1, T2, T3>(l: i32, r: NaiveDateWrapper) -> Result<NaiveDateWrapper> {
+    date_int_add::<T2, T1, T3>(r, l)
+}
+
+#[inline(always)]
+pub fn date_int_sub<T1, T2, T3>(l: NaiveDateWrapper, r: i32) -> Result<NaiveDateWrapper> {
+    date_int_add::<T1, T2, T3>(l, r.checked_neg().ok_or(NumericValueOutOfRange)?)
+}
+
+#[inline(always)]
+pub fn date_time_add<T1, T2, T3>(
+    l: NaiveDateWrapper,
+    r: NaiveTimeWrapper,
+) -> Result<NaiveDateTimeWrapper> {
+    Ok(NaiveDateTimeWrapper::new(l.0.and_time(r.0)))
+}
+
+#[inline(always)]
+pub fn time_date_add<T1, T2, T3>(
+    l: NaiveTimeWrapper,
+    r: NaiveDateWrapper,
+) -> Result<NaiveDateTimeWrapper> {
+    date_time_add::<T2, T1, T3>(r, l)
+}
+
+/// Adds an interval to a time of day, wrapping around midnight. The months and days of the
+/// interval are ignored, as in Postgres.
+#[inline(always)]
+pub fn time_interval_add<T1, T2, T3>(
+    l: NaiveTimeWrapper,
+    r: IntervalUnit,
+) -> Result<NaiveTimeWrapper> {
+    Ok(NaiveTimeWrapper::new(
+        l.0.overflowing_add_signed(Duration::milliseconds(r.get_ms()))
+            .0,
+    ))
+}
+
+#[inline(always)]
+pub fn interval_time_add<T1, T2, T3>(
+    l: IntervalUnit,
+    r: NaiveTimeWrapper,
+) -> Result<NaiveTimeWrapper> {
+    time_interval_add::<T2, T1, T3>(r, l)
+}
+
+#[inline(always)]
+pub fn time_interval_sub<T1, T2, T3>(
+    l: NaiveTimeWrapper,
+    r: IntervalUnit,
+) -> Result<NaiveTimeWrapper> {
+    time_interval_add::<T1, T2, T3>(l, r.negative())
+}
+
+/// `age(timestamp, timestamp) -> interval`, which subtracts the timestamps symbolically into
+/// years, months and days rather than just days.
+pub fn timestamp_age(l: NaiveDateTimeWrapper, r: NaiveDateTimeWrapper) -> Result<IntervalUnit> {
+    if l.0 < r.0 {
+        return Ok(timestamp_age(r, l)?.negative());
+    }
+    let (l, r) = (l.0, r.0);
+    let mut months = (l.year() - r.year()) * 12 + l.month() as i32 - r.month() as i32;
+    let mut days = l.day() as i32 - r.day() as i32;
+    let mut time = l.time() - r.time();
+    if time < Duration::zero() {
+        time = Duration::days(1) + time;
+        days -= 1;
+    }
+    if days < 0 {
+        // Borrow the days of the earlier month.
+        let (year, month) = (r.year(), r.month());
+        let next_month = if month == 12 {
+            NaiveDate::from_ymd(year + 1, 1, 1)
+        } else {
+            NaiveDate::from_ymd(year, month + 1, 1)
+        };
+        days += (next_month - NaiveDate::from_ymd(year, month, 1)).num_days() as i32;
+        months -= 1;
+    }
+    Ok(IntervalUnit::new(months, days, time.num_milliseconds()))
 }
 
 #[inline(always)]
@@ -222,7 +332,8 @@ mod tests {
 
     use risingwave_common::types::Decimal;
 
-    use crate::vector_op::arithmetic_op::general_add;
+    use super::*;
+    use crate::vector_op::cast::str_to_timestamp;
 
     #[test]
     fn test() {
@@ -231,4 +342,29 @@ mod tests {
             Decimal::from_str("2").unwrap()
         );
     }
+
+    #[test]
+    fn test_timestamp_sub_and_age() {
+        let ts = |s| str_to_timestamp(s).unwrap();
+        assert_eq!(
+            timestamp_timestamp_sub::<(), (), ()>(
+                ts("2022-03-02 01:00:00"),
+                ts("2022-01-31 12:30:00")
+            )
+            .unwrap(),
+            IntervalUnit::new(0, 29, 45_000_000)
+        );
+        assert_eq!(
+            timestamp_age(ts("2022-03-02 01:00:00"), ts("2022-01-31 12:30:00")).unwrap(),
+            IntervalUnit::new(1, 1, 48_600_000)
+        );
+        assert_eq!(
+            timestamp_age(ts("2001-04-10"), ts("1957-06-13")).unwrap(),
+            IntervalUnit::new(43 * 12 + 9, 27, 0)
+        );
+        assert_eq!(
+            timestamp_age(ts("1957-06-13"), ts("2001-04-10")).unwrap(),
+            IntervalUnit::new(43 * 12 + 9, 27, 0).negative()
+        );
+    }
 }
@@ -0,0 +1,340 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Functions on lists, which are the arrays in PG. Arrays are 1-based, and multi-dimensional arrays
+//! are nested lists, which are assumed to be rectangular as in PG.
+
+use std::cmp::Ordering;
+use std::fmt::Write;
+
+use risingwave_common::array::{ListRef, ListValue};
+use risingwave_common::error::ErrorCode::{InternalError, InvalidParameterValue};
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::{Datum, DatumRef, ScalarRefImpl, ToOwnedDatum};
+
+/// `list[index]`, which is NULL if the index is out of range.
+pub fn array_access(list: ListRef<'_>, index: i32) -> Datum {
+    if index < 1 {
+        return None;
+    }
+    list.values_ref()
+        .get(index as usize - 1)
+        .and_then(|value| value.to_owned_datum())
+}
+
+/// `array_length(list, dimension)`, which is NULL if the dimension does not exist or the list is
+/// empty.
+pub fn array_length(list: ListRef<'_>, dimension: i32) -> Option<i32> {
+    if dimension < 1 {
+        return None;
+    }
+    let mut values = list.values_ref();
+    for _ in 1..dimension {
+        values = match values.first() {
+            Some(Some(ScalarRefImpl::List(inner))) => inner.values_ref(),
+            _ => return None,
+        };
+    }
+    if values.is_empty() {
+        None
+    } else {
+        Some(values.len() as i32)
+    }
+}
+
+/// `cardinality(list)`, the total number of elements in all dimensions.
+pub fn cardinality(list: ListRef<'_>) -> i32 {
+    list.values_ref()
+        .into_iter()
+        .map(|value| match value {
+            Some(ScalarRefImpl::List(inner)) => cardinality(inner),
+            _ => 1,
+        })
+        .sum()
+}
+
+/// `array_append(list, element)`, where a NULL list is taken as empty.
+pub fn array_append(list: Option<ListRef<'_>>, element: DatumRef<'_>) -> ListValue {
+    let mut values = list.map(owned_values).unwrap_or_default();
+    values.push(element.to_owned_datum());
+    ListValue::new(values)
+}
+
+/// `array_cat(left, right)`, where a NULL list is taken as empty unless both are NULL.
+pub fn array_cat(left: Option<ListRef<'_>>, right: Option<ListRef<'_>>) -> Option<ListValue> {
+    if left.is_none() && right.is_none() {
+        return None;
+    }
+    let mut values = left.map(owned_values).unwrap_or_default();
+    values.extend(right.map(owned_values).unwrap_or_default());
+    Some(ListValue::new(values))
+}
+
+/// `array_position(list, element [, start])` returns the position of the first occurrence of the
+/// element from `start`, where NULL is also searchable as in `IS NOT DISTINCT FROM`.
+pub fn array_position(list: ListRef<'_>, element: DatumRef<'_>, start: i32) -> Option<i32> {
+    let skip = start.max(1) as usize - 1;
+    list.values_ref()
+        .into_iter()
+        .enumerate()
+        .skip(skip)
+        .find(|(_, value)| *value == element)
+        .map(|(i, _)| i as i32 + 1)
+}
+
+/// `left @> right`, whether all the elements of `right` are in `left`. NULL elements are never
+/// contained.
+pub fn array_contains(left: ListRef<'_>, right: ListRef<'_>) -> bool {
+    let left = left.values_ref();
+    right
+        .values_ref()
+        .into_iter()
+        .all(|value| value.is_some() && left.contains(&value))
+}
+
+/// `left && right`, whether the lists have any non-null element in common.
+pub fn array_overlap(left: ListRef<'_>, right: ListRef<'_>) -> bool {
+    let left = left.values_ref();
+    right
+        .values_ref()
+        .into_iter()
+        .any(|value| value.is_some() && left.contains(&value))
+}
+
+/// `array_to_string(list, delimiter [, null_string])` joins the elements of all dimensions. NULL
+/// elements are skipped unless `null_string` is given.
+pub fn array_to_string(
+    list: ListRef<'_>,
+    delimiter: &str,
+    null_str: Option<&str>,
+) -> Result<String> {
+    let mut output = String::new();
+    let mut first = true;
+    write_elements(&mut output, list, delimiter, null_str, &mut first)
+        .map_err(|e| RwError::from(InternalError(e.to_string())))?;
+    Ok(output)
+}
+
+fn write_elements(
+    output: &mut String,
+    list: ListRef<'_>,
+    delimiter: &str,
+    null_str: Option<&str>,
+    first: &mut bool,
+) -> std::fmt::Result {
+    for value in list.values_ref() {
+        let text = match value {
+            Some(ScalarRefImpl::List(inner)) => {
+                write_elements(output, inner, delimiter, null_str, first)?;
+                continue;
+            }
+            None => match null_str {
+                Some(null_str) => null_str.to_string(),
+                None => continue,
+            },
+            Some(ScalarRefImpl::Bool(b)) => if b { "t" } else { "f" }.to_string(),
+            Some(value) => value.to_string(),
+        };
+        if !*first {
+            output.push_str(delimiter);
+        }
+        *first = false;
+        write!(output, "{}", text)?;
+    }
+    Ok(())
+}
+
+/// Returns the comparison of `element op ANY/ALL(list)`, where `op` is one of `=`, `<>`, `<`,
+/// `<=`, `>` and `>=`.
+pub fn comparison_op(op: &str) -> Result<fn(Ordering) -> bool> {
+    Ok(match op {
+        "=" => Ordering::is_eq,
+        "<>" => Ordering::is_ne,
+        "<" => Ordering::is_lt,
+        "<=" => Ordering::is_le,
+        ">" => Ordering::is_gt,
+        ">=" => Ordering::is_ge,
+        _ => {
+            return Err(RwError::from(InvalidParameterValue(format!(
+                "unsupported comparison operator with ANY or ALL: {}",
+                op
+            ))))
+        }
+    })
+}
+
+fn compare(left: DatumRef<'_>, right: DatumRef<'_>, op: fn(Ordering) -> bool) -> Option<bool> {
+    Some(op(left?.cmp(&right?)))
+}
+
+/// `element op ANY(list)`, which is true if any comparison is true, NULL if none is true but
+/// some is NULL, and false otherwise, e.g. for an empty list.
+pub fn array_any(
+    element: DatumRef<'_>,
+    list: ListRef<'_>,
+    op: fn(Ordering) -> bool,
+) -> Option<bool> {
+    let mut result = Some(false);
+    for value in list.values_ref() {
+        match compare(element, value, op) {
+            Some(true) => return Some(true),
+            Some(false) => {}
+            None => result = None,
+        }
+    }
+    result
+}
+
+/// `element op ALL(list)`, which is false if any comparison is false, NULL if none is false but
+/// some is NULL, and true otherwise, e.g. for an empty list.
+pub fn array_all(
+    element: DatumRef<'_>,
+    list: ListRef<'_>,
+    op: fn(Ordering) -> bool,
+) -> Option<bool> {
+    let mut result = Some(true);
+    for value in list.values_ref() {
+        match compare(element, value, op) {
+            Some(false) => return Some(false),
+            Some(true) => {}
+            None => result = None,
+        }
+    }
+    result
+}
+
+fn owned_values(list: ListRef<'_>) -> Vec<Datum> {
+    list.values_ref()
+        .into_iter()
+        .map(ToOwnedDatum::to_owned_datum)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::types::{Scalar, ScalarImpl};
+
+    use super::*;
+
+    fn list(values: &[Option<i32>]) -> ListValue {
+        ListValue::new(values.iter().map(|v| v.map(ScalarImpl::Int32)).collect())
+    }
+
+    fn int(v: i32) -> DatumRef<'static> {
+        Some(ScalarRefImpl::Int32(v))
+    }
+
+    #[test]
+    fn test_array_access_and_length() {
+        let l = list(&[Some(1), None, Some(3)]);
+        let l = l.as_scalar_ref();
+        assert_eq!(array_access(l, 1), Some(ScalarImpl::Int32(1)));
+        assert_eq!(array_access(l, 2), None);
+        assert_eq!(array_access(l, 0), None);
+        assert_eq!(array_access(l, 4), None);
+        assert_eq!(array_length(l, 1), Some(3));
+        assert_eq!(array_length(l, 2), None);
+        assert_eq!(cardinality(l), 3);
+        assert_eq!(array_length(list(&[]).as_scalar_ref(), 1), None);
+
+        let nested = ListValue::new(vec![
+            Some(ScalarImpl::List(list(&[Some(1), Some(2)]))),
+            Some(ScalarImpl::List(list(&[Some(3), Some(4)]))),
+            Some(ScalarImpl::List(list(&[Some(5), Some(6)]))),
+        ]);
+        let nested = nested.as_scalar_ref();
+        assert_eq!(array_length(nested, 1), Some(3));
+        assert_eq!(array_length(nested, 2), Some(2));
+        assert_eq!(cardinality(nested), 6);
+    }
+
+    #[test]
+    fn test_array_append_and_cat() {
+        let l = list(&[Some(1)]);
+        let r = list(&[Some(2), None]);
+        assert_eq!(
+            array_append(Some(l.as_scalar_ref()), None),
+            list(&[Some(1), None])
+        );
+        assert_eq!(array_append(None, int(1)), list(&[Some(1)]));
+        assert_eq!(
+            array_cat(Some(l.as_scalar_ref()), Some(r.as_scalar_ref())),
+            Some(list(&[Some(1), Some(2), None]))
+        );
+        assert_eq!(array_cat(None, Some(r.as_scalar_ref())), Some(r.clone()));
+        assert_eq!(array_cat(None, None), None);
+    }
+
+    #[test]
+    fn test_array_position() {
+        let l = list(&[Some(1), None, Some(1)]);
+        let l = l.as_scalar_ref();
+        assert_eq!(array_position(l, int(1), 1), Some(1));
+        assert_eq!(array_position(l, int(1), 2), Some(3));
+        assert_eq!(array_position(l, None, 1), Some(2));
+        assert_eq!(array_position(l, int(2), 1), None);
+    }
+
+    #[test]
+    fn test_array_contains_and_overlap() {
+        let l = list(&[Some(1), Some(2), None]);
+        let l = l.as_scalar_ref();
+        assert!(array_contains(l, list(&[Some(2), Some(1)]).as_scalar_ref()));
+        assert!(array_contains(l, list(&[]).as_scalar_ref()));
+        assert!(!array_contains(l, list(&[None]).as_scalar_ref()));
+        assert!(!array_contains(l, list(&[Some(3)]).as_scalar_ref()));
+        assert!(array_overlap(l, list(&[Some(3), Some(2)]).as_scalar_ref()));
+        assert!(!array_overlap(l, list(&[None, Some(3)]).as_scalar_ref()));
+    }
+
+    #[test]
+    fn test_array_to_string() {
+        let l = list(&[Some(1), None, Some(3)]);
+        let l = l.as_scalar_ref();
+        assert_eq!(array_to_string(l, ",", None).unwrap(), "1,3");
+        assert_eq!(array_to_string(l, ",", Some("*")).unwrap(), "1,*,3");
+        let nested = ListValue::new(vec![
+            Some(ScalarImpl::List(list(&[Some(1), Some(2)]))),
+            Some(ScalarImpl::List(list(&[Some(3)]))),
+        ]);
+        assert_eq!(
+            array_to_string(nested.as_scalar_ref(), "-", None).unwrap(),
+            "1-2-3"
+        );
+    }
+
+    #[test]
+    fn test_array_any_all() {
+        let eq = comparison_op("=").unwrap();
+        let lt = comparison_op("<").unwrap();
+        let l = list(&[Some(1), Some(2)]);
+        let l = l.as_scalar_ref();
+        assert_eq!(array_any(int(2), l, eq), Some(true));
+        assert_eq!(array_any(int(3), l, eq), Some(false));
+        assert_eq!(array_all(int(0), l, lt), Some(true));
+        assert_eq!(array_all(int(1), l, lt), Some(false));
+        assert_eq!(array_any(None, l, eq), None);
+
+        let with_null = list(&[Some(1), None]);
+        let with_null = with_null.as_scalar_ref();
+        assert_eq!(array_any(int(1), with_null, eq), Some(true));
+        assert_eq!(array_any(int(2), with_null, eq), None);
+        assert_eq!(array_all(int(0), with_null, lt), None);
+
+        let empty = list(&[]);
+        assert_eq!(array_any(None, empty.as_scalar_ref(), eq), Some(false));
+        assert_eq!(array_all(None, empty.as_scalar_ref(), eq), Some(true));
+        assert!(comparison_op("~").is_err());
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryInto;
+use std::fmt::Debug;
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+
+use num_traits::PrimInt;
+use risingwave_common::error::Result;
+
+use super::arithmetic_op::general_atm;
+
+#[inline(always)]
+pub fn general_bitand<T1, T2, T3>(l: T1, r: T2) -> Result<T3>
+where
+    T1: TryInto<T3> + Debug,
+    T2: TryInto<T3> + Debug,
+    T3: BitAnd<Output = T3>,
+{
+    general_atm(l, r, |a, b| Ok(a & b))
+}
+
+#[inline(always)]
+pub fn general_bitor<T1, T2, T3>(l: T1, r: T2) -> Result<T3>
+where
+    T1: TryInto<T3> + Debug,
+    T2: TryInto<T3> + Debug,
+    T3: BitOr<Output = T3>,
+{
+    general_atm(l, r, |a, b| Ok(a | b))
+}
+
+#[inline(always)]
+pub fn general_bitxor<T1, T2, T3>(l: T1, r: T2) -> Result<T3>
+where
+    T1: TryInto<T3> + Debug,
+    T2: TryInto<T3> + Debug,
+    T3: BitXor<Output = T3>,
+{
+    general_atm(l, r, |a, b| Ok(a ^ b))
+}
+
+#[inline(always)]
+pub fn general_bitnot<T1: Not<Output = T1>>(expr: T1) -> Result<T1> {
+    Ok(!expr)
+}
+
+/// PG shifts the integers as `int` in C, where the amount is taken modulo the bit width on common
+/// platforms, and `smallint` is shifted in 32 bits before being truncated.
+fn shift_amount<T: PrimInt>(r: i32) -> u32 {
+    let width = T::zero().count_zeros().max(32);
+    r.rem_euclid(width as i32) as u32
+}
+
+/// `<<` on integers, which never overflows.
+pub fn general_shl<T1: PrimInt>(l: T1, r: i32) -> Result<T1> {
+    let shift = shift_amount::<T1>(r);
+    if shift >= T1::zero().count_zeros() {
+        Ok(T1::zero())
+    } else {
+        Ok(l << shift as usize)
+    }
+}
+
+/// `>>` on integers, which is an arithmetic shift.
+pub fn general_shr<T1: PrimInt>(l: T1, r: i32) -> Result<T1> {
+    let shift = shift_amount::<T1>(r);
+    let width = T1::zero().count_zeros();
+    Ok(l >> shift.min(width - 1) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bitwise() {
+        assert_eq!(general_bitand::<i32, i32, i32>(12, 10).unwrap(), 8);
+        assert_eq!(general_bitor::<i16, i32, i32>(12, 10).unwrap(), 14);
+        assert_eq!(general_bitxor::<i64, i64, i64>(12, 10).unwrap(), 6);
+        assert_eq!(general_bitnot(1i16).unwrap(), -2);
+    }
+
+    #[test]
+    fn test_shift() {
+        assert_eq!(general_shl(1i32, 4).unwrap(), 16);
+        assert_eq!(general_shl(1i32, 33).unwrap(), 2);
+        assert_eq!(general_shl(1i64, 40).unwrap(), 1 << 40);
+        assert_eq!(general_shl(1i16, 17).unwrap(), 0);
+        assert_eq!(general_shr(-16i32, 2).unwrap(), -4);
+        assert_eq!(general_shr(-16i16, 20).unwrap(), -1);
+        assert_eq!(general_shr(16i16, 20).unwrap(), 0);
+    }
+}
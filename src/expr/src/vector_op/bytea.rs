@@ -0,0 +1,185 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+
+use risingwave_common::error::ErrorCode::InvalidInputSyntax;
+use risingwave_common::error::{Result, RwError};
+
+/// Postgres wraps base64 output every 76 characters.
+const BASE64_LINE_WIDTH: usize = 76;
+
+fn invalid_input(msg: String) -> RwError {
+    RwError::from(InvalidInputSyntax(msg))
+}
+
+/// Parses the text input of `bytea`, which is either in the hex format (`\x0a1b`) or the escape
+/// format, where backslashes start an octal escape (`\012`) or escape themselves (`\\`).
+pub fn str_to_bytea(s: &str) -> Result<Box<[u8]>> {
+    match s.strip_prefix("\\x") {
+        Some(hex) => decode_hex(hex),
+        None => decode_escape(s),
+    }
+}
+
+/// Formats `bytea` in the hex output format, e.g. `\x0a1b`.
+pub fn bytea_to_str(v: &[u8]) -> Result<String> {
+    Ok(format!("\\x{}", hex::encode(v)))
+}
+
+/// `encode(bytes bytea, format text) -> text`, where the format is one of `hex`, `base64` and
+/// `escape`.
+pub fn encode(v: &[u8], format: &str) -> Result<String> {
+    match format.to_ascii_lowercase().as_str() {
+        "hex" => Ok(hex::encode(v)),
+        "base64" => {
+            let encoded = base64::encode(v);
+            Ok(encoded
+                .as_bytes()
+                .chunks(BASE64_LINE_WIDTH)
+                // Base64 output is always ascii.
+                .map(|line| std::str::from_utf8(line).unwrap())
+                .collect::<Vec<_>>()
+                .join("\n"))
+        }
+        "escape" => {
+            let mut s = String::with_capacity(v.len());
+            for &b in v {
+                match b {
+                    b'\\' => s.push_str("\\\\"),
+                    0 | 0x80..=0xff => write!(s, "\\{:03o}", b).unwrap(),
+                    _ => s.push(b as char),
+                }
+            }
+            Ok(s)
+        }
+        _ => Err(invalid_input(format!(
+            "unrecognized encoding: \"{}\"",
+            format
+        ))),
+    }
+}
+
+/// `decode(string text, format text) -> bytea`, the inverse of [`encode`].
+pub fn decode(s: &str, format: &str) -> Result<Box<[u8]>> {
+    match format.to_ascii_lowercase().as_str() {
+        "hex" => decode_hex(s),
+        "base64" => {
+            let s = s
+                .chars()
+                .filter(|c| !c.is_ascii_whitespace())
+                .collect::<String>();
+            base64::decode(s)
+                .map(Vec::into_boxed_slice)
+                .map_err(|e| invalid_input(format!("invalid base64 input: {}", e)))
+        }
+        "escape" => decode_escape(s),
+        _ => Err(invalid_input(format!(
+            "unrecognized encoding: \"{}\"",
+            format
+        ))),
+    }
+}
+
+/// `md5(text) -> text` and `md5(bytea) -> text`, returning the digest in hex.
+pub fn md5(v: &[u8]) -> Result<String> {
+    Ok(format!("{:x}", md5::compute(v)))
+}
+
+pub fn md5_str(s: &str) -> Result<String> {
+    md5(s.as_bytes())
+}
+
+/// `sha256(bytea) -> bytea`
+pub fn sha256(v: &[u8]) -> Result<Box<[u8]>> {
+    Ok(ring::digest::digest(&ring::digest::SHA256, v)
+        .as_ref()
+        .into())
+}
+
+fn decode_hex(s: &str) -> Result<Box<[u8]>> {
+    // Postgres allows whitespace between pairs of digits.
+    let s = s
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace())
+        .collect::<String>();
+    hex::decode(s)
+        .map(Vec::into_boxed_slice)
+        .map_err(|e| invalid_input(format!("invalid hexadecimal data: {}", e)))
+}
+
+fn decode_escape(s: &str) -> Result<Box<[u8]>> {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'\\' {
+            out.push(bytes[i]);
+            i += 1;
+        } else if bytes.get(i + 1) == Some(&b'\\') {
+            out.push(b'\\');
+            i += 2;
+        } else {
+            let octal = bytes
+                .get(i + 1..i + 4)
+                .filter(|d| d[0] <= b'3' && d.iter().all(|d| (b'0'..=b'7').contains(d)))
+                .ok_or_else(|| invalid_input("invalid input syntax for type bytea".to_string()))?;
+            out.push(octal.iter().fold(0, |acc, d| acc * 8 + (d - b'0')));
+            i += 4;
+        }
+    }
+    Ok(out.into_boxed_slice())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytea_text() {
+        assert_eq!(&*str_to_bytea("\\x0a1B").unwrap(), b"\x0a\x1b");
+        assert_eq!(&*str_to_bytea("a\\\\b\\001").unwrap(), b"a\\b\x01");
+        assert!(str_to_bytea("\\x0").is_err());
+        assert!(str_to_bytea("\\9").is_err());
+        assert_eq!(bytea_to_str(b"\x0a\x1b").unwrap(), "\\x0a1b");
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let data = b"\x00abc\\\xff";
+        for (format, text) in [
+            ("hex", "00616263 5cff"),
+            ("base64", "AGFiY1z/"),
+            ("escape", "\\000abc\\\\\\377"),
+        ] {
+            assert_eq!(&*decode(text, format).unwrap(), data);
+            assert_eq!(
+                decode(&encode(data, format).unwrap(), format).unwrap(),
+                decode(text, format).unwrap()
+            );
+        }
+        assert_eq!(encode(data, "escape").unwrap(), "\\000abc\\\\\\377");
+        assert_eq!(encode(&[0; 60], "base64").unwrap().lines().count(), 2);
+        assert!(encode(data, "utf8").is_err());
+    }
+
+    #[test]
+    fn test_digest() {
+        assert_eq!(md5_str("abc").unwrap(), "900150983cd24fb0d6963f7d28e17f72");
+        assert_eq!(
+            hex::encode(sha256(b"abc").unwrap()),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
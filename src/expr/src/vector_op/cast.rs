@@ -122,6 +122,16 @@ pub fn date_to_timestamp(elem: NaiveDateWrapper) -> Result<NaiveDateTimeWrapper>
     Ok(NaiveDateTimeWrapper::new(elem.0.and_hms(0, 0, 0)))
 }
 
+#[inline(always)]
+pub fn timestamp_to_date(elem: NaiveDateTimeWrapper) -> Result<NaiveDateWrapper> {
+    Ok(NaiveDateWrapper::new(elem.0.date()))
+}
+
+#[inline(always)]
+pub fn timestamp_to_time(elem: NaiveDateTimeWrapper) -> Result<NaiveTimeWrapper> {
+    Ok(NaiveTimeWrapper::new(elem.0.time()))
+}
+
 /// Define the cast function to primitive types.
 ///
 /// Due to the orphan rule, some data can't implement `TryFrom` trait for basic type.
@@ -221,6 +231,34 @@ pub fn general_to_string<T: std::fmt::Display>(elem: T) -> Result<String> {
     Ok(elem.to_string())
 }
 
+/// Casts a string to `CHAR(len)`, padding it with spaces to `len` characters. Like storing into a
+/// `CHAR` column in Postgres, it's an error to cut off anything other than trailing spaces.
+pub fn str_to_char(input: &str, len: u32) -> Result<String> {
+    let len = len as usize;
+    match input.char_indices().nth(len) {
+        Some((end, _)) => {
+            if input[end..].trim_end_matches(' ').is_empty() {
+                Ok(input[..end].to_string())
+            } else {
+                Err(
+                    InvalidInputSyntax(format!("value too long for type character({})", len))
+                        .into(),
+                )
+            }
+        }
+        None => {
+            let mut padded = input.to_string();
+            padded.extend(std::iter::repeat(' ').take(len - input.chars().count()));
+            Ok(padded)
+        }
+    }
+}
+
+/// Casts `CHAR(n)` to a string. Trailing spaces of `CHAR` are insignificant and thus removed.
+pub fn char_to_str(input: &str) -> Result<String> {
+    Ok(input.trim_end_matches(' ').to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use num_traits::FromPrimitive;
@@ -289,4 +327,14 @@ mod tests {
 
         assert_eq!(general_to_string(Decimal::NaN).unwrap(), "NaN");
     }
+
+    #[test]
+    fn test_char() {
+        use super::*;
+        assert_eq!(str_to_char("ab", 4).unwrap(), "ab  ");
+        assert_eq!(str_to_char("äb", 3).unwrap(), "äb ");
+        assert_eq!(str_to_char("abc  ", 3).unwrap(), "abc");
+        assert!(str_to_char("abcd", 3).is_err());
+        assert_eq!(char_to_str("ab  ").unwrap(), "ab");
+    }
 }
@@ -0,0 +1,100 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{Datelike, NaiveDate, NaiveTime, Timelike, Weekday};
+use risingwave_common::error::ErrorCode::InvalidInputSyntax;
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::NaiveDateTimeWrapper;
+
+/// Returns the first year of the period of `years` years containing `year`. Like Postgres, decades
+/// count from year 0, while centuries and millennia count from year 1.
+fn first_year_of(year: i32, years: i32, from_one: bool) -> i32 {
+    if from_one {
+        (year - 1).div_euclid(years) * years + 1
+    } else {
+        year.div_euclid(years) * years
+    }
+}
+
+/// `date_trunc(field, timestamp) -> timestamp`, which sets the parts of the timestamp less
+/// significant than `field` to their minimum values.
+pub fn date_trunc_timestamp(field: &str, v: NaiveDateTimeWrapper) -> Result<NaiveDateTimeWrapper> {
+    let v = v.0;
+    let date = v.date();
+    let time = v.time();
+    let ymd = |year, month, day| NaiveDate::from_ymd(year, month, day).and_hms(0, 0, 0);
+    let truncated = match field.to_ascii_lowercase().as_str() {
+        "microseconds" => v,
+        "milliseconds" => date.and_hms_micro(
+            time.hour(),
+            time.minute(),
+            time.second(),
+            time.nanosecond() / 1_000_000 * 1000,
+        ),
+        "second" => date.and_hms(time.hour(), time.minute(), time.second()),
+        "minute" => date.and_hms(time.hour(), time.minute(), 0),
+        "hour" => date.and_hms(time.hour(), 0, 0),
+        "day" => date.and_time(NaiveTime::from_hms(0, 0, 0)),
+        // Weeks start on Monday.
+        "week" => {
+            NaiveDate::from_isoywd(date.iso_week().year(), date.iso_week().week(), Weekday::Mon)
+                .and_hms(0, 0, 0)
+        }
+        "month" => ymd(date.year(), date.month(), 1),
+        "quarter" => ymd(date.year(), (date.month() - 1) / 3 * 3 + 1, 1),
+        "year" => ymd(date.year(), 1, 1),
+        "decade" => ymd(first_year_of(date.year(), 10, false), 1, 1),
+        "century" => ymd(first_year_of(date.year(), 100, true), 1, 1),
+        "millennium" => ymd(first_year_of(date.year(), 1000, true), 1, 1),
+        _ => {
+            return Err(RwError::from(InvalidInputSyntax(format!(
+                "timestamp units \"{}\" not recognized",
+                field
+            ))))
+        }
+    };
+    Ok(NaiveDateTimeWrapper::new(truncated))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector_op::cast::str_to_timestamp;
+
+    #[test]
+    fn test_date_trunc() {
+        let v = str_to_timestamp("2022-08-17 13:45:21.123456").unwrap();
+        for (field, expected) in [
+            ("microseconds", "2022-08-17 13:45:21.123456"),
+            ("milliseconds", "2022-08-17 13:45:21.123"),
+            ("second", "2022-08-17 13:45:21"),
+            ("MINUTE", "2022-08-17 13:45:00"),
+            ("hour", "2022-08-17 13:00:00"),
+            ("day", "2022-08-17 00:00:00"),
+            ("week", "2022-08-15 00:00:00"),
+            ("month", "2022-08-01 00:00:00"),
+            ("quarter", "2022-07-01 00:00:00"),
+            ("year", "2022-01-01 00:00:00"),
+            ("decade", "2020-01-01 00:00:00"),
+            ("century", "2001-01-01 00:00:00"),
+            ("millennium", "2001-01-01 00:00:00"),
+        ] {
+            assert_eq!(
+                date_trunc_timestamp(field, v).unwrap(),
+                str_to_timestamp(expected).unwrap()
+            );
+        }
+        assert!(date_trunc_timestamp("fortnight", v).is_err());
+    }
+}
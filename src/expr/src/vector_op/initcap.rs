@@ -0,0 +1,60 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Either;
+use risingwave_common::array::{BytesGuard, BytesWriter};
+use risingwave_common::error::Result;
+
+/// `initcap(string)` converts the first letter of each word to upper case and the rest to lower
+/// case, where words are sequences of alphanumeric characters.
+#[inline(always)]
+pub fn initcap(s: &str, writer: BytesWriter) -> Result<BytesGuard> {
+    let mut in_word = false;
+    let chars = s.chars().flat_map(move |c| {
+        let first = !in_word;
+        in_word = c.is_alphanumeric();
+        if first {
+            Either::Left(c.to_uppercase())
+        } else {
+            Either::Right(c.to_lowercase())
+        }
+    });
+    writer.write_from_char_iter(chars)
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_initcap() {
+        let cases = [
+            ("hi THOMAS", "Hi Thomas"),
+            ("foo_bar-baz2x", "Foo_Bar-Baz2x"),
+            ("", ""),
+            ("élan vital", "Élan Vital"),
+        ];
+        let mut builder = Utf8ArrayBuilder::new(cases.len()).unwrap();
+        for (s, _) in cases {
+            let writer = builder.writer();
+            builder = initcap(s, writer).unwrap().into_inner();
+        }
+        let array = builder.finish().unwrap();
+        for (i, (_, expected)) in cases.iter().enumerate() {
+            assert_eq!(array.value_at(i), Some(*expected));
+        }
+    }
+}
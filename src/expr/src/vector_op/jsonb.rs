@@ -0,0 +1,166 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+
+use risingwave_common::array::{JsonbRef, JsonbVal};
+use risingwave_common::error::ErrorCode::InvalidInputSyntax;
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::ScalarRef;
+
+/// `jsonb -> text`
+pub fn jsonb_access_field(v: Option<JsonbRef>, key: Option<&str>) -> Result<Option<JsonbVal>> {
+    Ok(v.zip(key)
+        .and_then(|(v, key)| v.access_object_field(key))
+        .map(|v| v.to_owned_scalar()))
+}
+
+/// `jsonb -> int`
+pub fn jsonb_access_element(v: Option<JsonbRef>, idx: Option<i32>) -> Result<Option<JsonbVal>> {
+    Ok(v.zip(idx)
+        .and_then(|(v, idx)| v.access_array_element(idx))
+        .map(|v| v.to_owned_scalar()))
+}
+
+/// `jsonb ->> text`
+pub fn jsonb_access_field_str(v: Option<JsonbRef>, key: Option<&str>) -> Result<Option<String>> {
+    Ok(v.zip(key)
+        .and_then(|(v, key)| v.access_object_field(key))
+        .and_then(|v| v.as_text()))
+}
+
+/// `jsonb ->> int`
+pub fn jsonb_access_element_str(v: Option<JsonbRef>, idx: Option<i32>) -> Result<Option<String>> {
+    Ok(v.zip(idx)
+        .and_then(|(v, idx)| v.access_array_element(idx))
+        .and_then(|v| v.as_text()))
+}
+
+/// `jsonb #> text[]`, where the path is given as a text array literal such as `'{a,0}'`.
+pub fn jsonb_access_path(v: Option<JsonbRef>, path: Option<&str>) -> Result<Option<JsonbVal>> {
+    let (v, path) = match v.zip(path) {
+        Some(v) => v,
+        None => return Ok(None),
+    };
+    let path = parse_text_array(path)?;
+    Ok(v.access_path(path).map(|v| v.to_owned_scalar()))
+}
+
+/// `jsonb @> jsonb`
+pub fn jsonb_contains(l: JsonbRef, r: JsonbRef) -> Result<bool> {
+    Ok(l.contains(r))
+}
+
+/// `jsonb ? text`
+pub fn jsonb_exists(v: JsonbRef, key: &str) -> Result<bool> {
+    Ok(v.exists(key))
+}
+
+pub fn jsonb_typeof(v: JsonbRef) -> Result<String> {
+    Ok(v.type_name().to_string())
+}
+
+pub fn str_to_jsonb(s: &str) -> Result<JsonbVal> {
+    JsonbVal::from_str(s)
+}
+
+pub fn jsonb_to_str(v: JsonbRef) -> Result<String> {
+    Ok(v.to_string())
+}
+
+/// Parses a one-dimensional text array literal, e.g. `{a,"b c",0}`.
+fn parse_text_array(s: &str) -> Result<Vec<&str>> {
+    let inner = s
+        .trim()
+        .strip_prefix('{')
+        .and_then(|s| s.strip_suffix('}'))
+        .ok_or_else(|| {
+            RwError::from(InvalidInputSyntax(format!(
+                "malformed array literal: \"{}\"",
+                s
+            )))
+        })?;
+    if inner.trim().is_empty() {
+        return Ok(vec![]);
+    }
+    Ok(inner
+        .split(',')
+        .map(|e| {
+            let e = e.trim();
+            e.strip_prefix('"')
+                .and_then(|e| e.strip_suffix('"'))
+                .unwrap_or(e)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::types::Scalar;
+
+    use super::*;
+
+    fn jsonb(s: &str) -> JsonbVal {
+        str_to_jsonb(s).unwrap()
+    }
+
+    #[test]
+    fn test_jsonb_access() {
+        let v = jsonb(r#"{"a": {"b": [1, "x", null]}, "c": "d"}"#);
+        let v = v.as_scalar_ref();
+
+        assert_eq!(
+            jsonb_access_field(Some(v), Some("c")).unwrap(),
+            Some(jsonb(r#""d""#))
+        );
+        assert_eq!(jsonb_access_field(Some(v), Some("z")).unwrap(), None);
+        assert_eq!(jsonb_access_field(Some(v), None).unwrap(), None);
+        assert_eq!(
+            jsonb_access_field_str(Some(v), Some("c")).unwrap(),
+            Some("d".to_string())
+        );
+        assert_eq!(
+            jsonb_access_path(Some(v), Some("{a,b,1}")).unwrap(),
+            Some(jsonb(r#""x""#))
+        );
+        assert_eq!(
+            jsonb_access_path(Some(v), Some("{a,b,2}")).unwrap(),
+            Some(jsonb("null"))
+        );
+        assert!(jsonb_access_path(Some(v), Some("a,b")).is_err());
+
+        let arr = jsonb("[1, [2, 3]]");
+        let arr = arr.as_scalar_ref();
+        assert_eq!(
+            jsonb_access_element(Some(arr), Some(-1)).unwrap(),
+            Some(jsonb("[2, 3]"))
+        );
+        assert_eq!(
+            jsonb_access_element_str(Some(arr), Some(0)).unwrap(),
+            Some("1".to_string())
+        );
+        assert_eq!(jsonb_access_element(Some(arr), Some(2)).unwrap(), None);
+    }
+
+    #[test]
+    fn test_jsonb_predicates() {
+        let v = jsonb(r#"{"a": 1, "b": [1, 2, 3]}"#);
+        let v = v.as_scalar_ref();
+        assert!(jsonb_contains(v, jsonb(r#"{"b": [3]}"#).as_scalar_ref()).unwrap());
+        assert!(!jsonb_contains(v, jsonb(r#"{"a": 2}"#).as_scalar_ref()).unwrap());
+        assert!(jsonb_exists(v, "a").unwrap());
+        assert!(!jsonb_exists(v, "c").unwrap());
+        assert_eq!(jsonb_typeof(v).unwrap(), "object");
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::{BytesGuard, BytesWriter};
+use risingwave_common::error::Result;
+
+/// The number of characters `left` and `right` keep, where a negative `n` means all but the
+/// last or first `|n|` characters.
+fn kept_chars(s: &str, n: i32) -> usize {
+    if n >= 0 {
+        n as usize
+    } else {
+        s.chars().count().saturating_sub(n.unsigned_abs() as usize)
+    }
+}
+
+/// `left(string, n)` returns the first `n` characters of the string.
+#[inline(always)]
+pub fn left(s: &str, n: i32, writer: BytesWriter) -> Result<BytesGuard> {
+    writer.write_from_char_iter(s.chars().take(kept_chars(s, n)))
+}
+
+/// `right(string, n)` returns the last `n` characters of the string.
+#[inline(always)]
+pub fn right(s: &str, n: i32, writer: BytesWriter) -> Result<BytesGuard> {
+    let skipped = s.chars().count().saturating_sub(kept_chars(s, n));
+    writer.write_from_char_iter(s.chars().skip(skipped))
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_left_right() {
+        let cases = [
+            ("abcde", 2, "ab", "de"),
+            ("abcde", -2, "abc", "cde"),
+            ("abcde", 10, "abcde", "abcde"),
+            ("abcde", -10, "", ""),
+            ("abcde", 0, "", ""),
+            ("你好世界", 1, "你", "界"),
+        ];
+        let mut builder = Utf8ArrayBuilder::new(cases.len() * 2).unwrap();
+        for (s, n, ..) in cases {
+            let writer = builder.writer();
+            builder = left(s, n, writer).unwrap().into_inner();
+            let writer = builder.writer();
+            builder = right(s, n, writer).unwrap().into_inner();
+        }
+        let array = builder.finish().unwrap();
+        for (i, (.., l, r)) in cases.iter().enumerate() {
+            assert_eq!(array.value_at(2 * i), Some(*l));
+            assert_eq!(array.value_at(2 * i + 1), Some(*r));
+        }
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::NaiveDate;
+use risingwave_common::error::ErrorCode::InvalidInputSyntax;
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::NaiveDateWrapper;
+
+/// `make_date(year, month, day) -> date`, where negative years are BC as in Postgres.
+pub fn make_date(year: i32, month: i32, day: i32) -> Result<NaiveDateWrapper> {
+    let out_of_range = || {
+        RwError::from(InvalidInputSyntax(format!(
+            "date field value out of range: {}-{:02}-{:02}",
+            year, month, day
+        )))
+    };
+    // There is no year 0 in Postgres, while in chrono year 0 is 1 BC.
+    let chrono_year = match year {
+        0 => return Err(out_of_range()),
+        year if year < 0 => year + 1,
+        year => year,
+    };
+    NaiveDate::from_ymd_opt(
+        chrono_year,
+        month.try_into().map_err(|_| out_of_range())?,
+        day.try_into().map_err(|_| out_of_range())?,
+    )
+    .map(NaiveDateWrapper::new)
+    .ok_or_else(out_of_range)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector_op::cast::str_to_date;
+
+    #[test]
+    fn test_make_date() {
+        assert_eq!(
+            make_date(2024, 2, 29).unwrap(),
+            str_to_date("2024-02-29").unwrap()
+        );
+        assert_eq!(make_date(-1, 1, 1).unwrap().0, NaiveDate::from_ymd(0, 1, 1));
+        assert!(make_date(2023, 2, 29).is_err());
+        assert!(make_date(0, 1, 1).is_err());
+        assert!(make_date(2023, -1, 1).is_err());
+    }
+}
@@ -0,0 +1,387 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Math functions on `double precision` and `numeric`, with the domain errors and the overflow
+//! checks of PG. The infinities and NaN of `numeric` are evaluated as `double precision`.
+
+use num_traits::{FromPrimitive, ToPrimitive};
+use risingwave_common::error::ErrorCode::{InvalidParameterValue, NumericValueOutOfRange};
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::{Decimal, OrderedF64};
+use rust_decimal::{Decimal as RustDecimal, MathematicalOps};
+
+fn invalid_parameter(msg: &str) -> RwError {
+    RwError::from(InvalidParameterValue(msg.to_string()))
+}
+
+/// Checks that a finite input does not give an infinite or zero result, which means the result is
+/// out of the range of `double precision`.
+fn check_float_range(input_finite: bool, result: f64, may_be_zero: bool) -> Result<OrderedF64> {
+    if input_finite && (result.is_infinite() || (result == 0.0 && !may_be_zero)) {
+        return Err(RwError::from(NumericValueOutOfRange));
+    }
+    Ok(result.into())
+}
+
+/// Evaluates a function of `numeric` on the infinities and NaN with its `double precision`
+/// version, and on the finite values with `f`.
+fn decimal_or_float(
+    input: Decimal,
+    f: impl FnOnce(RustDecimal) -> Result<Decimal>,
+    float_f: impl FnOnce(OrderedF64) -> Result<OrderedF64>,
+) -> Result<Decimal> {
+    match input {
+        Decimal::Normalized(d) => f(d),
+        _ => {
+            let result = float_f(input.to_f64().unwrap().into())?;
+            Ok(Decimal::from_f64(result.0).unwrap())
+        }
+    }
+}
+
+pub fn sqrt_f64(input: OrderedF64) -> Result<OrderedF64> {
+    if input.0 < 0.0 {
+        return Err(invalid_parameter(
+            "cannot take square root of a negative number",
+        ));
+    }
+    Ok(input.0.sqrt().into())
+}
+
+pub fn sqrt_decimal(input: Decimal) -> Result<Decimal> {
+    decimal_or_float(
+        input,
+        |d| {
+            d.sqrt()
+                .map(Decimal::Normalized)
+                .ok_or_else(|| invalid_parameter("cannot take square root of a negative number"))
+        },
+        sqrt_f64,
+    )
+}
+
+pub fn cbrt_f64(input: OrderedF64) -> Result<OrderedF64> {
+    Ok(input.0.cbrt().into())
+}
+
+pub fn exp_f64(input: OrderedF64) -> Result<OrderedF64> {
+    check_float_range(input.0.is_finite(), input.0.exp(), false)
+}
+
+pub fn exp_decimal(input: Decimal) -> Result<Decimal> {
+    decimal_or_float(
+        input,
+        |d| {
+            d.checked_exp()
+                .map(Decimal::Normalized)
+                .ok_or_else(|| RwError::from(NumericValueOutOfRange))
+        },
+        exp_f64,
+    )
+}
+
+fn check_logarithm_domain(is_zero: bool, is_negative: bool) -> Result<()> {
+    if is_zero {
+        return Err(invalid_parameter("cannot take logarithm of zero"));
+    }
+    if is_negative {
+        return Err(invalid_parameter(
+            "cannot take logarithm of a negative number",
+        ));
+    }
+    Ok(())
+}
+
+pub fn ln_f64(input: OrderedF64) -> Result<OrderedF64> {
+    check_logarithm_domain(input.0 == 0.0, input.0 < 0.0)?;
+    Ok(input.0.ln().into())
+}
+
+pub fn ln_decimal(input: Decimal) -> Result<Decimal> {
+    decimal_or_float(
+        input,
+        |d| {
+            check_logarithm_domain(d.is_zero(), d.is_sign_negative())?;
+            Ok(Decimal::Normalized(d.ln()))
+        },
+        ln_f64,
+    )
+}
+
+pub fn log10_f64(input: OrderedF64) -> Result<OrderedF64> {
+    check_logarithm_domain(input.0 == 0.0, input.0 < 0.0)?;
+    Ok(input.0.log10().into())
+}
+
+pub fn log10_decimal(input: Decimal) -> Result<Decimal> {
+    decimal_or_float(
+        input,
+        |d| {
+            check_logarithm_domain(d.is_zero(), d.is_sign_negative())?;
+            Ok(Decimal::Normalized(d.log10()))
+        },
+        log10_f64,
+    )
+}
+
+fn check_power_domain(
+    base_is_zero: bool,
+    base_is_negative: bool,
+    exponent_is_negative: bool,
+    exponent_is_integer: bool,
+) -> Result<()> {
+    if base_is_zero && exponent_is_negative {
+        return Err(invalid_parameter(
+            "zero raised to a negative power is undefined",
+        ));
+    }
+    if base_is_negative && !exponent_is_integer {
+        return Err(invalid_parameter(
+            "a negative number raised to a non-integer power yields a complex result",
+        ));
+    }
+    Ok(())
+}
+
+pub fn pow_f64(base: OrderedF64, exponent: OrderedF64) -> Result<OrderedF64> {
+    let (b, e) = (base.0, exponent.0);
+    // `pow(1, NaN)` and `pow(NaN, 0)` are 1, and the other powers with NaN are NaN.
+    if b.is_nan() || e.is_nan() {
+        return Ok(b.powf(e).into());
+    }
+    check_power_domain(b == 0.0, b < 0.0, e < 0.0, e.floor() == e)?;
+    check_float_range(b.is_finite() && e.is_finite(), b.powf(e), b == 0.0)
+}
+
+pub fn pow_decimal(base: Decimal, exponent: Decimal) -> Result<Decimal> {
+    match (base, exponent) {
+        (Decimal::Normalized(b), Decimal::Normalized(e)) => {
+            check_power_domain(
+                b.is_zero(),
+                b.is_sign_negative() && !b.is_zero(),
+                e.is_sign_negative() && !e.is_zero(),
+                e.fract().is_zero(),
+            )?;
+            b.checked_powd(e)
+                .map(Decimal::Normalized)
+                .ok_or_else(|| RwError::from(NumericValueOutOfRange))
+        }
+        _ => {
+            let result = pow_f64(
+                base.to_f64().unwrap().into(),
+                exponent.to_f64().unwrap().into(),
+            )?;
+            Ok(Decimal::from_f64(result.0).unwrap())
+        }
+    }
+}
+
+pub fn floor_f64(input: OrderedF64) -> Result<OrderedF64> {
+    Ok(input.0.floor().into())
+}
+
+pub fn floor_decimal(input: Decimal) -> Result<Decimal> {
+    decimal_or_float(input, |d| Ok(Decimal::Normalized(d.floor())), floor_f64)
+}
+
+pub fn ceil_f64(input: OrderedF64) -> Result<OrderedF64> {
+    Ok(input.0.ceil().into())
+}
+
+pub fn ceil_decimal(input: Decimal) -> Result<Decimal> {
+    decimal_or_float(input, |d| Ok(Decimal::Normalized(d.ceil())), ceil_f64)
+}
+
+pub fn trunc_f64(input: OrderedF64) -> Result<OrderedF64> {
+    Ok(input.0.trunc().into())
+}
+
+pub fn trunc_decimal(input: Decimal) -> Result<Decimal> {
+    decimal_or_float(input, |d| Ok(Decimal::Normalized(d.trunc())), trunc_f64)
+}
+
+/// `round(double precision)`, which rounds half to even as `rint` in C.
+pub fn round_f64(input: OrderedF64) -> Result<OrderedF64> {
+    if (input.0 - input.0.trunc()).abs() == 0.5 {
+        Ok((2.0 * (input.0 / 2.0).round()).into())
+    } else {
+        Ok(input.0.round().into())
+    }
+}
+
+/// `sign(double precision)`, which is 0 for zero and NaN as in PG.
+pub fn sign_f64(input: OrderedF64) -> Result<OrderedF64> {
+    let sign = if input.0 > 0.0 {
+        1.0
+    } else if input.0 < 0.0 {
+        -1.0
+    } else {
+        0.0
+    };
+    Ok(sign.into())
+}
+
+/// `sign(numeric)`, which is NaN for NaN as in PG.
+pub fn sign_decimal(input: Decimal) -> Result<Decimal> {
+    match input {
+        Decimal::Normalized(d) => Ok(Decimal::Normalized(d.signum())),
+        Decimal::NaN => Ok(Decimal::NaN),
+        Decimal::PositiveINF => Ok(Decimal::from(1)),
+        Decimal::NegativeINF => Ok(Decimal::from(-1)),
+    }
+}
+
+/// The trigonometric functions are not defined on the infinities.
+fn check_trigonometric_domain(input: OrderedF64) -> Result<()> {
+    if input.0.is_infinite() {
+        return Err(invalid_parameter("input is out of range"));
+    }
+    Ok(())
+}
+
+pub fn sin_f64(input: OrderedF64) -> Result<OrderedF64> {
+    check_trigonometric_domain(input)?;
+    Ok(input.0.sin().into())
+}
+
+pub fn cos_f64(input: OrderedF64) -> Result<OrderedF64> {
+    check_trigonometric_domain(input)?;
+    Ok(input.0.cos().into())
+}
+
+pub fn tan_f64(input: OrderedF64) -> Result<OrderedF64> {
+    check_trigonometric_domain(input)?;
+    Ok(input.0.tan().into())
+}
+
+pub fn cot_f64(input: OrderedF64) -> Result<OrderedF64> {
+    check_trigonometric_domain(input)?;
+    Ok((1.0 / input.0.tan()).into())
+}
+
+/// The inverse sine and cosine are only defined on `[-1, 1]`.
+fn check_inverse_domain(input: OrderedF64) -> Result<()> {
+    if input.0.abs() > 1.0 {
+        return Err(invalid_parameter("input is out of range"));
+    }
+    Ok(())
+}
+
+pub fn asin_f64(input: OrderedF64) -> Result<OrderedF64> {
+    check_inverse_domain(input)?;
+    Ok(input.0.asin().into())
+}
+
+pub fn acos_f64(input: OrderedF64) -> Result<OrderedF64> {
+    check_inverse_domain(input)?;
+    Ok(input.0.acos().into())
+}
+
+pub fn atan_f64(input: OrderedF64) -> Result<OrderedF64> {
+    Ok(input.0.atan().into())
+}
+
+pub fn atan2_f64(y: OrderedF64, x: OrderedF64) -> Result<OrderedF64> {
+    Ok(y.0.atan2(x.0).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn f(v: f64) -> OrderedF64 {
+        v.into()
+    }
+
+    fn d(v: &str) -> Decimal {
+        Decimal::from_str(v).unwrap()
+    }
+
+    #[test]
+    fn test_float_functions() {
+        assert_eq!(sqrt_f64(f(16.0)).unwrap(), f(4.0));
+        assert!(sqrt_f64(f(-1.0)).is_err());
+        assert_eq!(cbrt_f64(f(-27.0)).unwrap(), f(-3.0));
+        assert_eq!(exp_f64(f(0.0)).unwrap(), f(1.0));
+        assert_eq!(exp_f64(f(f64::NEG_INFINITY)).unwrap(), f(0.0));
+        assert!(exp_f64(f(1000.0)).is_err());
+        assert!(exp_f64(f(-1000.0)).is_err());
+        assert_eq!(ln_f64(f(1.0)).unwrap(), f(0.0));
+        assert_eq!(log10_f64(f(1000.0)).unwrap(), f(3.0));
+        assert!(ln_f64(f(0.0)).is_err());
+        assert!(log10_f64(f(-1.0)).is_err());
+        assert_eq!(floor_f64(f(-1.5)).unwrap(), f(-2.0));
+        assert_eq!(ceil_f64(f(-1.5)).unwrap(), f(-1.0));
+        assert_eq!(trunc_f64(f(-1.5)).unwrap(), f(-1.0));
+        assert_eq!(round_f64(f(2.5)).unwrap(), f(2.0));
+        assert_eq!(round_f64(f(-3.5)).unwrap(), f(-4.0));
+        assert_eq!(round_f64(f(1.4)).unwrap(), f(1.0));
+        assert_eq!(sign_f64(f(-0.1)).unwrap(), f(-1.0));
+        assert_eq!(sign_f64(f(0.0)).unwrap(), f(0.0));
+        assert_eq!(sign_f64(f(f64::NAN)).unwrap(), f(0.0));
+    }
+
+    #[test]
+    fn test_pow() {
+        assert_eq!(pow_f64(f(2.0), f(10.0)).unwrap(), f(1024.0));
+        assert_eq!(pow_f64(f(-2.0), f(3.0)).unwrap(), f(-8.0));
+        assert_eq!(pow_f64(f(0.0), f(0.0)).unwrap(), f(1.0));
+        assert!(pow_f64(f(0.0), f(-1.0)).is_err());
+        assert!(pow_f64(f(-2.0), f(0.5)).is_err());
+        assert!(pow_f64(f(10.0), f(400.0)).is_err());
+        assert!(pow_f64(f(10.0), f(-400.0)).is_err());
+        assert_eq!(pow_decimal(d("1.5"), d("2")).unwrap(), d("2.25"));
+        assert!(pow_decimal(d("0"), d("-1")).is_err());
+        assert!(pow_decimal(d("-2"), d("0.5")).is_err());
+    }
+
+    #[test]
+    fn test_decimal_functions() {
+        assert_eq!(sqrt_decimal(d("2.25")).unwrap(), d("1.5"));
+        assert!(sqrt_decimal(d("-1")).is_err());
+        assert_eq!(
+            sqrt_decimal(Decimal::PositiveINF).unwrap(),
+            Decimal::PositiveINF
+        );
+        assert!(sqrt_decimal(Decimal::NegativeINF).is_err());
+        assert_eq!(exp_decimal(d("0")).unwrap(), d("1"));
+        assert_eq!(exp_decimal(Decimal::NegativeINF).unwrap(), d("0"));
+        assert!(ln_decimal(d("0")).is_err());
+        assert!(log10_decimal(d("-10")).is_err());
+        assert_eq!(floor_decimal(d("-1.5")).unwrap(), d("-2"));
+        assert_eq!(ceil_decimal(d("1.2")).unwrap(), d("2"));
+        assert_eq!(trunc_decimal(d("-1.7")).unwrap(), d("-1"));
+        assert_eq!(floor_decimal(Decimal::NaN).unwrap(), Decimal::NaN);
+        assert_eq!(sign_decimal(d("-3.2")).unwrap(), d("-1"));
+        assert_eq!(sign_decimal(d("0")).unwrap(), d("0"));
+        assert_eq!(sign_decimal(Decimal::PositiveINF).unwrap(), d("1"));
+    }
+
+    #[test]
+    fn test_trigonometric_functions() {
+        assert_eq!(sin_f64(f(0.0)).unwrap(), f(0.0));
+        assert_eq!(cos_f64(f(0.0)).unwrap(), f(1.0));
+        assert!(tan_f64(f(f64::INFINITY)).is_err());
+        assert_eq!(cot_f64(f(0.0)).unwrap(), f(f64::INFINITY));
+        assert_eq!(asin_f64(f(1.0)).unwrap(), f(std::f64::consts::FRAC_PI_2));
+        assert!(acos_f64(f(1.1)).is_err());
+        assert_eq!(atan_f64(f(0.0)).unwrap(), f(0.0));
+        assert_eq!(
+            atan2_f64(f(1.0), f(0.0)).unwrap(),
+            f(std::f64::consts::FRAC_PI_2)
+        );
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::{BytesGuard, BytesWriter};
+use risingwave_common::error::Result;
+
+/// Returns the padding and the prefix of the string to keep, so that they have `len` characters
+/// in total. The string is truncated if it is longer, and not padded if `fill` is empty.
+fn pad<'a>(s: &'a str, len: i32, fill: &'a str) -> (impl Iterator<Item = char> + 'a, &'a str) {
+    let len = len.max(0) as usize;
+    let (kept, padding) = match s.char_indices().nth(len) {
+        Some((end, _)) => (&s[..end], 0),
+        None => (s, len - s.chars().count()),
+    };
+    let padding = if fill.is_empty() { 0 } else { padding };
+    (fill.chars().cycle().take(padding), kept)
+}
+
+/// `lpad(string, length [, fill])`, which pads the string on the left with `fill`, a space by
+/// default.
+#[inline(always)]
+pub fn lpad(s: &str, len: i32, fill: &str, writer: BytesWriter) -> Result<BytesGuard> {
+    let (padding, kept) = pad(s, len, fill);
+    writer.write_from_char_iter(padding.chain(kept.chars()))
+}
+
+/// `rpad(string, length [, fill])`, which pads the string on the right with `fill`, a space by
+/// default.
+#[inline(always)]
+pub fn rpad(s: &str, len: i32, fill: &str, writer: BytesWriter) -> Result<BytesGuard> {
+    let (padding, kept) = pad(s, len, fill);
+    writer.write_from_char_iter(kept.chars().chain(padding))
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_pad() {
+        let cases = [
+            ("hi", 5, "xy", "xyxhi", "hixyx"),
+            ("hello", 3, "x", "hel", "hel"),
+            ("hi", 4, "", "hi", "hi"),
+            ("hi", -1, "x", "", ""),
+            ("你好", 3, "！", "！你好", "你好！"),
+        ];
+        let mut builder = Utf8ArrayBuilder::new(cases.len() * 2).unwrap();
+        for (s, len, fill, ..) in cases {
+            let writer = builder.writer();
+            builder = lpad(s, len, fill, writer).unwrap().into_inner();
+            let writer = builder.writer();
+            builder = rpad(s, len, fill, writer).unwrap().into_inner();
+        }
+        let array = builder.finish().unwrap();
+        for (i, (.., left, right)) in cases.iter().enumerate() {
+            assert_eq!(array.value_at(2 * i), Some(*left));
+            assert_eq!(array.value_at(2 * i + 1), Some(*right));
+        }
+    }
+}
@@ -0,0 +1,341 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use regex::{Captures, Regex, RegexBuilder};
+use risingwave_common::array::{BytesGuard, BytesWriter, ListValue};
+use risingwave_common::error::ErrorCode::InvalidInputSyntax;
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::ScalarImpl;
+
+/// A regular expression compiled with the flags of the Postgres regexp functions.
+#[derive(Debug, Clone)]
+pub struct RegexpContext {
+    pub regex: Regex,
+    /// Whether the `g` flag is given, which makes `regexp_replace` and `regexp_matches` apply to
+    /// all the matches instead of the first one.
+    pub global: bool,
+}
+
+impl RegexpContext {
+    /// Compiles `pattern` with `flags`, e.g. `i` for case-insensitive matching. As in Postgres,
+    /// `.` matches newlines unless the `n` flag is given.
+    pub fn new(pattern: &str, flags: &str) -> Result<Self> {
+        let mut builder = RegexBuilder::new(pattern);
+        builder.dot_matches_new_line(true);
+        let mut global = false;
+        for flag in flags.chars() {
+            match flag {
+                'i' => {
+                    builder.case_insensitive(true);
+                }
+                'c' => {
+                    builder.case_insensitive(false);
+                }
+                'n' | 'm' => {
+                    builder.dot_matches_new_line(false).multi_line(true);
+                }
+                's' => {
+                    builder.dot_matches_new_line(true).multi_line(false);
+                }
+                'x' => {
+                    builder.ignore_whitespace(true);
+                }
+                'g' => global = true,
+                _ => {
+                    return Err(RwError::from(InvalidInputSyntax(format!(
+                        "invalid regular expression option: \"{}\"",
+                        flag
+                    ))))
+                }
+            }
+        }
+        let regex = builder.build().map_err(|e| {
+            RwError::from(InvalidInputSyntax(format!(
+                "invalid regular expression: {}",
+                e
+            )))
+        })?;
+        Ok(Self { regex, global })
+    }
+
+    /// Compiles the pattern of `SIMILAR TO`.
+    pub fn from_similar_to(pattern: &str) -> Result<Self> {
+        Self::new(&similar_to_regex(pattern)?, "")
+    }
+}
+
+/// Translates the pattern of `SIMILAR TO` to a regular expression, which must match the whole
+/// string. `%` and `_` are the wildcards of `LIKE`, while `|`, `*`, `+`, `?`, `{m,n}`, `()` and
+/// `[]` have their meanings in regular expressions. A backslash escapes the next character.
+fn similar_to_regex(pattern: &str) -> Result<String> {
+    let mut regex = String::with_capacity(pattern.len() + 6);
+    regex.push_str("^(?:");
+    let mut in_bracket = false;
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                let escaped = chars.next().ok_or_else(|| {
+                    RwError::from(InvalidInputSyntax(
+                        "SIMILAR TO pattern must not end with escape character".to_string(),
+                    ))
+                })?;
+                regex.push_str(&regex::escape(escaped.encode_utf8(&mut [0; 4])));
+            }
+            '[' if !in_bracket => {
+                in_bracket = true;
+                regex.push(c);
+            }
+            ']' if in_bracket => {
+                in_bracket = false;
+                regex.push(c);
+            }
+            _ if in_bracket => regex.push(c),
+            '%' => regex.push_str(".*"),
+            '_' => regex.push('.'),
+            '.' | '^' | '$' => {
+                regex.push('\\');
+                regex.push(c);
+            }
+            _ => regex.push(c),
+        }
+    }
+    regex.push_str(")$");
+    Ok(regex)
+}
+
+/// Returns the texts matched by the capture groups, or the whole match if there is no capture
+/// group. The groups that do not participate in the match are NULL.
+fn captures_to_list(captures: &Captures<'_>) -> ListValue {
+    let groups = if captures.len() == 1 {
+        vec![captures.get(0)]
+    } else {
+        captures.iter().skip(1).collect()
+    };
+    ListValue::new(
+        groups
+            .into_iter()
+            .map(|group| group.map(|m| ScalarImpl::Utf8(m.as_str().to_string())))
+            .collect(),
+    )
+}
+
+/// `string ~ pattern`, which is true if the pattern matches any part of the string.
+#[inline(always)]
+pub fn regexp_like(s: &str, ctx: &RegexpContext) -> bool {
+    ctx.regex.is_match(s)
+}
+
+/// `regexp_match(string, pattern [, flags]) -> text[]`, which returns the captures of the first
+/// match, or NULL if there is no match.
+pub fn regexp_match(s: &str, ctx: &RegexpContext) -> Option<ListValue> {
+    ctx.regex
+        .captures(s)
+        .map(|captures| captures_to_list(&captures))
+}
+
+/// `regexp_matches(string, pattern [, flags]) -> setof text[]`, which returns the captures of the
+/// first match, or of all the matches with the `g` flag.
+pub fn regexp_matches(s: &str, ctx: &RegexpContext) -> Vec<ListValue> {
+    let matches = ctx
+        .regex
+        .captures_iter(s)
+        .map(|captures| captures_to_list(&captures));
+    if ctx.global {
+        matches.collect()
+    } else {
+        matches.take(1).collect()
+    }
+}
+
+/// Converts the replacement of `regexp_replace` to the syntax of the `regex` crate. In Postgres,
+/// `\n` refers to the n-th capture group and `\&` to the whole match, while `$` has no special
+/// meaning.
+fn to_regex_replacement(replacement: &str) -> String {
+    let mut out = String::with_capacity(replacement.len());
+    let mut chars = replacement.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.peek().copied() {
+                Some(d @ '1'..='9') => {
+                    out.push_str("${");
+                    out.push(d);
+                    out.push('}');
+                    chars.next();
+                }
+                Some('&') => {
+                    out.push_str("${0}");
+                    chars.next();
+                }
+                Some('\\') => {
+                    out.push('\\');
+                    chars.next();
+                }
+                _ => out.push('\\'),
+            },
+            '$' => out.push_str("$$"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// `regexp_replace(string, pattern, replacement [, flags])`, which replaces the first match, or
+/// all the matches with the `g` flag.
+pub fn regexp_replace(
+    s: &str,
+    ctx: &RegexpContext,
+    replacement: &str,
+    writer: BytesWriter,
+) -> Result<BytesGuard> {
+    let replacement = to_regex_replacement(replacement);
+    let replaced = if ctx.global {
+        ctx.regex.replace_all(s, replacement.as_str())
+    } else {
+        ctx.regex.replace(s, replacement.as_str())
+    };
+    writer.write_ref(&replaced)
+}
+
+/// `regexp_split_to_table(string, pattern [, flags])`, which splits the string at the matches of
+/// the pattern. As in Postgres, zero-length matches at the start or the end of the string, or right
+/// after the previous match, are ignored, so that e.g. an empty pattern splits into characters.
+pub fn regexp_split<'a>(s: &'a str, ctx: &RegexpContext) -> Vec<&'a str> {
+    let mut parts = vec![];
+    let mut prev_match_end = 0;
+    for m in ctx.regex.find_iter(s) {
+        if m.start() >= s.len() || m.end() <= prev_match_end {
+            continue;
+        }
+        parts.push(&s[prev_match_end..m.start()]);
+        prev_match_end = m.end();
+    }
+    parts.push(&s[prev_match_end..]);
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+
+    use super::*;
+
+    fn list(values: &[Option<&str>]) -> ListValue {
+        ListValue::new(
+            values
+                .iter()
+                .map(|v| v.map(|v| ScalarImpl::Utf8(v.to_string())))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_regexp_like() {
+        let ctx = RegexpContext::new("^a.c$", "").unwrap();
+        assert!(regexp_like("abc", &ctx));
+        assert!(regexp_like("a\nc", &ctx));
+        assert!(!regexp_like("ABC", &ctx));
+        let ctx = RegexpContext::new("^a.c$", "in").unwrap();
+        assert!(regexp_like("ABC", &ctx));
+        assert!(!regexp_like("a\nc", &ctx));
+        assert!(regexp_like("x\nabc", &ctx));
+        assert!(RegexpContext::new("(", "").is_err());
+        assert!(RegexpContext::new("a", "z").is_err());
+    }
+
+    #[test]
+    fn test_similar_to() {
+        for (s, pattern, expected) in [
+            ("abc", "abc", true),
+            ("abc", "a", false),
+            ("abc", "%(b|d)%", true),
+            ("abc", "(b|c)%", false),
+            ("abc", "a_c", true),
+            ("a.c", "a.c", true),
+            ("abc", "a.c", false),
+            ("a%c", "a\\%c", true),
+            ("abc", "a\\%c", false),
+            ("a_c", "a[_%]c", true),
+            ("abbbc", "ab+c", true),
+            ("abc", "[a-c]{3}", true),
+        ] {
+            let ctx = RegexpContext::from_similar_to(pattern).unwrap();
+            assert_eq!(regexp_like(s, &ctx), expected, "{} {}", s, pattern);
+        }
+        assert!(RegexpContext::from_similar_to("a\\").is_err());
+    }
+
+    #[test]
+    fn test_regexp_match() {
+        let ctx = RegexpContext::new("(\\d+)-(\\d+)?", "").unwrap();
+        assert_eq!(
+            regexp_match("tel: 12-34, 56-", &ctx),
+            Some(list(&[Some("12"), Some("34")]))
+        );
+        assert_eq!(regexp_match("none", &ctx), None);
+        let ctx = RegexpContext::new("\\d+", "g").unwrap();
+        assert_eq!(regexp_match("a1b22", &ctx), Some(list(&[Some("1")])));
+        assert_eq!(
+            regexp_matches("a1b22", &ctx),
+            vec![list(&[Some("1")]), list(&[Some("22")])]
+        );
+        let ctx = RegexpContext::new("(\\d+)-(\\d+)?", "").unwrap();
+        assert_eq!(
+            regexp_matches("12-34, 56-", &ctx),
+            vec![list(&[Some("12"), Some("34")])]
+        );
+        let ctx = RegexpContext::new("(\\d+)-(\\d+)?", "g").unwrap();
+        assert_eq!(
+            regexp_matches("12-34, 56-", &ctx),
+            vec![list(&[Some("12"), Some("34")]), list(&[Some("56"), None])]
+        );
+    }
+
+    #[test]
+    fn test_regexp_split() {
+        for (s, pattern, expected) in [
+            ("a1b22c", "\\d+", vec!["a", "b", "c"]),
+            ("abc", "", vec!["a", "b", "c"]),
+            ("abc", "a", vec!["", "bc"]),
+            ("abc", "x*", vec!["a", "b", "c"]),
+            ("", "x", vec![""]),
+        ] {
+            let ctx = RegexpContext::new(pattern, "").unwrap();
+            assert_eq!(regexp_split(s, &ctx), expected, "{} {}", s, pattern);
+        }
+    }
+
+    #[test]
+    fn test_regexp_replace() {
+        let cases = [
+            ("foobarbaz", "b..", "X", "", "fooXbaz"),
+            ("foobarbaz", "b..", "X", "g", "fooXX"),
+            ("foobarbaz", "b(..)", "X\\1Y", "g", "fooXarYXazY"),
+            ("foobarbaz", "BAR", "<\\&>", "i", "foo<bar>baz"),
+            ("price", "e$", "$1\\\\", "", "pric$1\\"),
+        ];
+        let mut builder = Utf8ArrayBuilder::new(cases.len()).unwrap();
+        for (s, pattern, replacement, flags, _) in cases {
+            let ctx = RegexpContext::new(pattern, flags).unwrap();
+            let writer = builder.writer();
+            builder = regexp_replace(s, &ctx, replacement, writer)
+                .unwrap()
+                .into_inner();
+        }
+        let array = builder.finish().unwrap();
+        for (i, (.., expected)) in cases.iter().enumerate() {
+            assert_eq!(array.value_at(i), Some(*expected));
+        }
+    }
+}
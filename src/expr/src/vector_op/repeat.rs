@@ -0,0 +1,64 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::{BytesGuard, BytesWriter};
+use risingwave_common::error::ErrorCode::InvalidParameterValue;
+use risingwave_common::error::{Result, RwError};
+
+/// The maximum length of the result in bytes, which is the limit of a field in PG.
+const MAX_LEN: usize = 1 << 30;
+
+/// `repeat(string, n)`, which is empty if `n` is not positive.
+#[inline(always)]
+pub fn repeat(s: &str, n: i32, writer: BytesWriter) -> Result<BytesGuard> {
+    if s.len().saturating_mul(n.max(0) as usize) > MAX_LEN {
+        return Err(RwError::from(InvalidParameterValue(
+            "requested length too large".to_string(),
+        )));
+    }
+    let mut writer = writer.begin();
+    for _ in 0..n {
+        writer.write_ref(s)?;
+    }
+    writer.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_repeat() {
+        let cases = [
+            ("ab", 3, "ababab"),
+            ("ab", 0, ""),
+            ("ab", -1, ""),
+            ("", 5, ""),
+        ];
+        let mut builder = Utf8ArrayBuilder::new(cases.len()).unwrap();
+        for (s, n, _) in cases {
+            let writer = builder.writer();
+            builder = repeat(s, n, writer).unwrap().into_inner();
+        }
+        let array = builder.finish().unwrap();
+        for (i, (.., expected)) in cases.iter().enumerate() {
+            assert_eq!(array.value_at(i), Some(*expected));
+        }
+
+        let writer = Utf8ArrayBuilder::new(1).unwrap().writer();
+        assert!(repeat("ab", i32::MAX, writer).is_err());
+    }
+}
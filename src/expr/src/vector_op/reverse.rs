@@ -0,0 +1,42 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::{BytesGuard, BytesWriter};
+use risingwave_common::error::Result;
+
+#[inline(always)]
+pub fn reverse(s: &str, writer: BytesWriter) -> Result<BytesGuard> {
+    writer.write_from_char_iter(s.chars().rev())
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_reverse() {
+        let cases = [("abc", "cba"), ("", ""), ("你好", "好你")];
+        let mut builder = Utf8ArrayBuilder::new(cases.len()).unwrap();
+        for (s, _) in cases {
+            let writer = builder.writer();
+            builder = reverse(s, writer).unwrap().into_inner();
+        }
+        let array = builder.finish().unwrap();
+        for (i, (_, expected)) in cases.iter().enumerate() {
+            assert_eq!(array.value_at(i), Some(*expected));
+        }
+    }
+}
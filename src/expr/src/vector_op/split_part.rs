@@ -0,0 +1,75 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::{BytesGuard, BytesWriter};
+use risingwave_common::error::ErrorCode::InvalidParameterValue;
+use risingwave_common::error::{Result, RwError};
+
+/// `split_part(string, delimiter, n)` returns the `n`-th field of the string split by the
+/// delimiter, counting from 1. A negative `n` counts from the end. The result is empty if there
+/// are fewer fields.
+#[inline(always)]
+pub fn split_part(s: &str, delimiter: &str, n: i32, writer: BytesWriter) -> Result<BytesGuard> {
+    if n == 0 {
+        return Err(RwError::from(InvalidParameterValue(
+            "field position must not be zero".to_string(),
+        )));
+    }
+    // An empty delimiter gives the whole string as the only field.
+    let fields: Vec<&str> = if delimiter.is_empty() {
+        vec![s]
+    } else {
+        s.split(delimiter).collect()
+    };
+    let idx = if n > 0 {
+        Some(n as usize - 1)
+    } else {
+        fields.len().checked_sub(n.unsigned_abs() as usize)
+    };
+    writer.write_ref(idx.and_then(|idx| fields.get(idx)).unwrap_or(&""))
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_split_part() {
+        let cases = [
+            ("abc~@~def~@~ghi", "~@~", 2, "def"),
+            ("abc,def,ghi", ",", -1, "ghi"),
+            ("abc,def,ghi", ",", -3, "abc"),
+            ("abc,def,ghi", ",", 4, ""),
+            ("abc,def,ghi", ",", -4, ""),
+            ("abc", "", 1, "abc"),
+            ("abc", "", 2, ""),
+            ("", ",", 1, ""),
+            ("路径/到/文件", "/", 3, "文件"),
+        ];
+        let mut builder = Utf8ArrayBuilder::new(cases.len()).unwrap();
+        for (s, delimiter, n, _) in cases {
+            let writer = builder.writer();
+            builder = split_part(s, delimiter, n, writer).unwrap().into_inner();
+        }
+        let array = builder.finish().unwrap();
+        for (i, (.., expected)) in cases.iter().enumerate() {
+            assert_eq!(array.value_at(i), Some(*expected));
+        }
+
+        let writer = Utf8ArrayBuilder::new(1).unwrap().writer();
+        assert!(split_part("abc", ",", 0, writer).is_err());
+    }
+}
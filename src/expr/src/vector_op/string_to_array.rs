@@ -0,0 +1,72 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::ListValue;
+use risingwave_common::types::ScalarImpl;
+
+/// `string_to_array(string, delimiter [, null_string])` splits the string into a `varchar[]`.
+/// A NULL delimiter splits the string into characters, and an empty one keeps the string as a
+/// single element. Elements equal to `null_string` become NULL.
+pub fn string_to_array(s: &str, delimiter: Option<&str>, null_str: Option<&str>) -> ListValue {
+    let elements: Vec<&str> = if s.is_empty() {
+        vec![]
+    } else {
+        match delimiter {
+            None => s
+                .char_indices()
+                .map(|(i, c)| &s[i..i + c.len_utf8()])
+                .collect(),
+            Some("") => vec![s],
+            Some(delimiter) => s.split(delimiter).collect(),
+        }
+    };
+    ListValue::new(
+        elements
+            .into_iter()
+            .map(|e| (Some(e) != null_str).then(|| ScalarImpl::Utf8(e.to_string())))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(values: &[Option<&str>]) -> ListValue {
+        ListValue::new(
+            values
+                .iter()
+                .map(|v| v.map(|v| ScalarImpl::Utf8(v.to_string())))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_string_to_array() {
+        assert_eq!(
+            string_to_array("a,b,,c", Some(","), None),
+            list(&[Some("a"), Some("b"), Some(""), Some("c")])
+        );
+        assert_eq!(
+            string_to_array("a,*,c", Some(","), Some("*")),
+            list(&[Some("a"), None, Some("c")])
+        );
+        assert_eq!(
+            string_to_array("好a", None, None),
+            list(&[Some("好"), Some("a")])
+        );
+        assert_eq!(string_to_array("ab", Some(""), None), list(&[Some("ab")]));
+        assert_eq!(string_to_array("", Some(","), None), list(&[]));
+    }
+}
@@ -0,0 +1,277 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Functions on `timestamptz`, which is stored as microseconds since the Unix epoch in UTC. The
+//! time zone used to interpret or display a value is always passed explicitly. The frontend fills
+//! in the session time zone, so that the results do not depend on where the expression runs.
+
+use std::fmt::Write;
+
+use chrono::{DateTime, Duration, FixedOffset, LocalResult, NaiveDateTime, Offset, TimeZone};
+use chrono_tz::Tz;
+use risingwave_common::error::ErrorCode::{InvalidInputSyntax, NumericValueOutOfRange};
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::session_config::parse_time_zone_offset;
+use risingwave_common::types::{
+    CheckedAdd, Decimal, IntervalUnit, NaiveDateTimeWrapper, OrderedF64,
+};
+
+use super::cast::str_to_timestamp;
+use super::date_trunc::date_trunc_timestamp;
+use super::extract::extract_from_timestamp;
+
+/// A time zone accepted by the `TimeZone` session variable, i.e. a fixed offset or an IANA time
+/// zone name.
+enum Zone {
+    Fixed(FixedOffset),
+    Named(Tz),
+}
+
+impl Zone {
+    fn parse(time_zone: &str) -> Result<Self> {
+        if let Some(offset) = parse_time_zone_offset(time_zone) {
+            return Ok(Self::Fixed(FixedOffset::east(offset)));
+        }
+        Tz::from_str_insensitive(time_zone.trim())
+            .map(Self::Named)
+            .map_err(|_| {
+                RwError::from(InvalidInputSyntax(format!(
+                    "time zone \"{}\" not recognized",
+                    time_zone
+                )))
+            })
+    }
+
+    /// Returns the offset from UTC at the instant `utc`.
+    fn offset_at_utc(&self, utc: &NaiveDateTime) -> FixedOffset {
+        match self {
+            Self::Fixed(offset) => *offset,
+            Self::Named(tz) => tz.offset_from_utc_datetime(utc).fix(),
+        }
+    }
+
+    /// Returns the offset from UTC at the wall-clock time `local`. A time repeated when clocks are
+    /// turned back takes the earlier offset, and a time skipped when clocks are turned forward
+    /// takes the offset before the transition, as Postgres does.
+    fn offset_at_local(&self, local: &NaiveDateTime) -> FixedOffset {
+        match self {
+            Self::Fixed(offset) => *offset,
+            Self::Named(tz) => match tz.offset_from_local_datetime(local) {
+                LocalResult::Single(offset) | LocalResult::Ambiguous(offset, _) => offset.fix(),
+                LocalResult::None => tz.offset_from_utc_datetime(local).fix(),
+            },
+        }
+    }
+}
+
+fn utc_from_micros(v: i64) -> NaiveDateTime {
+    NaiveDateTime::from_timestamp(
+        v.div_euclid(1_000_000),
+        (v.rem_euclid(1_000_000) * 1000) as u32,
+    )
+}
+
+fn micros_from_utc(utc: NaiveDateTime) -> i64 {
+    utc.timestamp() * 1_000_000 + utc.timestamp_subsec_micros() as i64
+}
+
+fn to_local(v: i64, zone: &Zone) -> NaiveDateTime {
+    let utc = utc_from_micros(v);
+    utc + Duration::seconds(zone.offset_at_utc(&utc).local_minus_utc() as i64)
+}
+
+fn from_local(local: NaiveDateTime, zone: &Zone) -> i64 {
+    let offset = zone.offset_at_local(&local).local_minus_utc();
+    micros_from_utc(local - Duration::seconds(offset as i64))
+}
+
+/// `timestamp AT TIME ZONE zone -> timestamptz`, which takes the timestamp as the wall-clock time
+/// in the zone.
+pub fn timestamp_at_time_zone(v: NaiveDateTimeWrapper, time_zone: &str) -> Result<i64> {
+    Ok(from_local(v.0, &Zone::parse(time_zone)?))
+}
+
+/// `timestamptz AT TIME ZONE zone -> timestamp`, which returns the wall-clock time in the zone.
+pub fn timestampz_at_time_zone(v: i64, time_zone: &str) -> Result<NaiveDateTimeWrapper> {
+    Ok(NaiveDateTimeWrapper::new(to_local(
+        v,
+        &Zone::parse(time_zone)?,
+    )))
+}
+
+/// Casts a string to `timestamptz`. The string is taken as the wall-clock time in `time_zone`,
+/// unless it has an explicit offset, e.g. `2022-01-01 08:00:00+08`.
+pub fn str_to_timestampz_with_zone(s: &str, time_zone: &str) -> Result<i64> {
+    let s = s.trim();
+    for format in ["%Y-%m-%d %H:%M:%S%.f%#z", "%Y-%m-%d %H:%M:%S%.f %#z"] {
+        if let Ok(v) = DateTime::parse_from_str(s, format) {
+            return Ok(micros_from_utc(v.naive_utc()));
+        }
+    }
+    Ok(from_local(str_to_timestamp(s)?.0, &Zone::parse(time_zone)?))
+}
+
+/// Casts `timestamptz` to a string in `time_zone`, e.g. `2022-01-01 08:00:00+08`.
+pub fn timestampz_to_str(v: i64, time_zone: &str) -> Result<String> {
+    let zone = Zone::parse(time_zone)?;
+    let utc = utc_from_micros(v);
+    let offset = zone.offset_at_utc(&utc).local_minus_utc();
+    let mut s = (utc + Duration::seconds(offset as i64)).to_string();
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.abs();
+    write!(s, "{}{:02}", sign, offset / 3600).unwrap();
+    if offset % 3600 != 0 {
+        write!(s, ":{:02}", offset % 3600 / 60).unwrap();
+    }
+    Ok(s)
+}
+
+/// `extract(field from timestamptz)`, where the fields are those of the wall-clock time in
+/// `time_zone`.
+pub fn extract_from_timestampz(time_unit: &str, v: i64, time_zone: &str) -> Result<Decimal> {
+    extract_from_timestamp(time_unit, timestampz_at_time_zone(v, time_zone)?)
+}
+
+/// `date_trunc(field, timestamptz)`, which truncates the wall-clock time in `time_zone`.
+pub fn date_trunc_timestampz(field: &str, v: i64, time_zone: &str) -> Result<i64> {
+    let zone = Zone::parse(time_zone)?;
+    let local = date_trunc_timestamp(field, NaiveDateTimeWrapper::new(to_local(v, &zone)))?;
+    Ok(from_local(local.0, &zone))
+}
+
+/// `timestamptz + interval`. Like Postgres, the months and days of the interval are added to the
+/// wall-clock time in `time_zone`, so that adding `1 day` keeps the time of day across a daylight
+/// saving time transition, while the rest of the interval is added as elapsed time.
+pub fn timestampz_interval_add(v: i64, interval: IntervalUnit, time_zone: &str) -> Result<i64> {
+    let zone = Zone::parse(time_zone)?;
+    let mut v = v;
+    if interval.get_months() != 0 || interval.get_days() != 0 {
+        let local = NaiveDateTimeWrapper::new(to_local(v, &zone)).checked_add(
+            IntervalUnit::new(interval.get_months(), interval.get_days(), 0),
+        )?;
+        v = from_local(local.0, &zone);
+    }
+    interval
+        .get_ms()
+        .checked_mul(1000)
+        .and_then(|us| v.checked_add(us))
+        .ok_or_else(|| NumericValueOutOfRange.into())
+}
+
+/// `timestamptz - interval`
+pub fn timestampz_interval_sub(v: i64, interval: IntervalUnit, time_zone: &str) -> Result<i64> {
+    timestampz_interval_add(v, interval.negative(), time_zone)
+}
+
+/// `to_timestamp(double precision) -> timestamptz`, from seconds since the Unix epoch.
+pub fn to_timestamp(seconds: OrderedF64) -> Result<i64> {
+    let micros = (seconds.0 * 1e6).round();
+    // The bounds are exclusive as `i64::MAX as f64` is rounded up.
+    if !(i64::MIN as f64..i64::MAX as f64).contains(&micros) {
+        return Err(RwError::from(InvalidInputSyntax(format!(
+            "timestamp out of range: \"{}\"",
+            seconds
+        ))));
+    }
+    Ok(micros as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timestampz_str() {
+        let v = str_to_timestampz_with_zone("2022-01-01 08:00:00", "+08:00").unwrap();
+        assert_eq!(
+            v,
+            str_to_timestampz_with_zone("2022-01-01 00:00:00", "UTC").unwrap()
+        );
+        assert_eq!(
+            v,
+            str_to_timestampz_with_zone("2022-01-01 09:30:00+09:30", "UTC").unwrap()
+        );
+        assert_eq!(
+            timestampz_to_str(v, "UTC").unwrap(),
+            "2022-01-01 00:00:00+00"
+        );
+        assert_eq!(
+            timestampz_to_str(v + 1500, "Asia/Kolkata").unwrap(),
+            "2022-01-01 05:30:00.001500+05:30"
+        );
+        assert_eq!(
+            timestampz_to_str(v, "america/new_york").unwrap(),
+            "2021-12-31 19:00:00-05"
+        );
+        assert!(timestampz_to_str(v, "Mars/Olympus_Mons").is_err());
+    }
+
+    #[test]
+    fn test_at_time_zone_dst() {
+        let local = |s| str_to_timestamp(s).unwrap();
+        let zone = "America/New_York";
+        // Summer time.
+        let v = timestamp_at_time_zone(local("2022-07-01 12:00:00"), zone).unwrap();
+        assert_eq!(
+            timestampz_to_str(v, "UTC").unwrap(),
+            "2022-07-01 16:00:00+00"
+        );
+        assert_eq!(
+            timestampz_at_time_zone(v, zone).unwrap(),
+            local("2022-07-01 12:00:00")
+        );
+        // 02:30 is skipped when clocks are turned forward.
+        let v = timestamp_at_time_zone(local("2022-03-13 02:30:00"), zone).unwrap();
+        assert_eq!(
+            timestampz_to_str(v, zone).unwrap(),
+            "2022-03-13 03:30:00-04"
+        );
+        // 01:30 occurs twice when clocks are turned back.
+        let v = timestamp_at_time_zone(local("2022-11-06 01:30:00"), zone).unwrap();
+        assert_eq!(
+            timestampz_to_str(v, zone).unwrap(),
+            "2022-11-06 01:30:00-04"
+        );
+
+        assert_eq!(extract_from_timestampz("HOUR", v, "UTC").unwrap(), 5.into());
+    }
+
+    #[test]
+    fn test_timestampz_arithmetic() {
+        let zone = "America/New_York";
+        let v = str_to_timestampz_with_zone("2022-03-12 12:00:00", zone).unwrap();
+        let add = |interval| timestampz_interval_add(v, interval, zone).unwrap();
+        assert_eq!(
+            timestampz_to_str(add(IntervalUnit::from_days(1)), zone).unwrap(),
+            "2022-03-13 12:00:00-04"
+        );
+        assert_eq!(
+            timestampz_to_str(add(IntervalUnit::from_minutes(24 * 60)), zone).unwrap(),
+            "2022-03-13 13:00:00-04"
+        );
+        assert_eq!(
+            timestampz_to_str(
+                date_trunc_timestampz("day", v, "Asia/Shanghai").unwrap(),
+                zone
+            )
+            .unwrap(),
+            "2022-03-12 11:00:00-05"
+        );
+        assert_eq!(
+            timestampz_to_str(to_timestamp(1.5.into()).unwrap(), "UTC").unwrap(),
+            "1970-01-01 00:00:01.500+00"
+        );
+        assert!(to_timestamp(f64::NAN.into()).is_err());
+    }
+}
@@ -0,0 +1,175 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+
+use chrono::{Datelike, NaiveDateTime, Timelike};
+use risingwave_common::error::Result;
+use risingwave_common::types::NaiveDateTimeWrapper;
+
+/// The template patterns of `to_char`, longest first so that e.g. `HH24` is not taken as `HH`.
+/// They are matched case-insensitively. For the patterns of names, the case of the pattern decides
+/// the case of the output, e.g. `MONTH`, `Month` or `month`.
+const PATTERNS: &[&str] = &[
+    "HH24", "HH12", "HH", "MI", "SS", "MS", "US", "AM", "PM", "YYYY", "YYY", "YY", "Y", "MONTH",
+    "MON", "MM", "DAY", "DY", "DDD", "DD", "D", "Q",
+];
+
+/// Postgres pads the full names of months and days to the longest one.
+const NAME_WIDTH: usize = 9;
+
+enum Case {
+    Upper,
+    Capitalized,
+    Lower,
+}
+
+impl Case {
+    fn of(pattern: &str) -> Self {
+        let mut chars = pattern.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), _) if c.is_ascii_lowercase() => Self::Lower,
+            (_, Some(c)) if c.is_ascii_lowercase() => Self::Capitalized,
+            _ => Self::Upper,
+        }
+    }
+
+    /// Converts a capitalized name like `January` to the case.
+    fn apply(&self, name: &str) -> String {
+        match self {
+            Self::Upper => name.to_uppercase(),
+            Self::Capitalized => name.to_string(),
+            Self::Lower => name.to_lowercase(),
+        }
+    }
+}
+
+fn write_pattern(
+    out: &mut String,
+    v: &NaiveDateTime,
+    pattern: &str,
+    original: &str,
+    fill_mode: bool,
+) {
+    let number = |out: &mut String, n: i64, width: usize| {
+        if fill_mode {
+            write!(out, "{}", n).unwrap();
+        } else {
+            write!(out, "{:0width$}", n, width = width).unwrap();
+        }
+    };
+    let name = |out: &mut String, name: &str, pad: bool| {
+        let name = Case::of(original).apply(name);
+        if pad && !fill_mode {
+            write!(out, "{:width$}", name, width = NAME_WIDTH).unwrap();
+        } else {
+            out.push_str(&name);
+        }
+    };
+    let hour12 = (v.hour() + 11) % 12 + 1;
+    match pattern {
+        "HH24" => number(out, v.hour() as i64, 2),
+        "HH12" | "HH" => number(out, hour12 as i64, 2),
+        "MI" => number(out, v.minute() as i64, 2),
+        "SS" => number(out, v.second() as i64, 2),
+        "MS" => number(out, (v.nanosecond() / 1_000_000) as i64, 3),
+        "US" => number(out, (v.nanosecond() / 1000) as i64, 6),
+        "AM" | "PM" => name(out, if v.hour() < 12 { "Am" } else { "Pm" }, false),
+        "YYYY" => number(out, v.year() as i64, 4),
+        "YYY" => number(out, v.year() as i64 % 1000, 3),
+        "YY" => number(out, v.year() as i64 % 100, 2),
+        "Y" => number(out, v.year() as i64 % 10, 1),
+        "MONTH" => name(out, &v.format("%B").to_string(), true),
+        "MON" => name(out, &v.format("%b").to_string(), false),
+        "MM" => number(out, v.month() as i64, 2),
+        "DAY" => name(out, &v.format("%A").to_string(), true),
+        "DY" => name(out, &v.format("%a").to_string(), false),
+        "DDD" => number(out, v.ordinal() as i64, 3),
+        "DD" => number(out, v.day() as i64, 2),
+        "D" => number(out, v.weekday().number_from_sunday() as i64, 1),
+        "Q" => number(out, ((v.month() - 1) / 3 + 1) as i64, 1),
+        _ => unreachable!(),
+    }
+}
+
+/// `to_char(timestamp, format) -> text`, which formats the timestamp with the template patterns
+/// of Postgres, e.g. `YYYY-MM-DD HH24:MI:SS`. A pattern prefixed by `FM` is not padded. Text in
+/// double quotes or after a backslash is copied as is, as are the characters not in any pattern.
+pub fn to_char_timestamp(v: NaiveDateTimeWrapper, format: &str) -> Result<String> {
+    let v = v.0;
+    let mut out = String::with_capacity(format.len());
+    let mut rest = format;
+    let mut fill_mode = false;
+    while let Some(c) = rest.chars().next() {
+        let starts_with = |pattern: &str| {
+            rest.get(..pattern.len())
+                .map_or(false, |s| s.eq_ignore_ascii_case(pattern))
+        };
+        if starts_with("FM") {
+            fill_mode = true;
+            rest = &rest[2..];
+            continue;
+        }
+        if c == '"' {
+            let text = &rest[1..];
+            let end = text.find('"').unwrap_or(text.len());
+            out.push_str(&text[..end]);
+            rest = text.get(end + 1..).unwrap_or("");
+            continue;
+        }
+        if c == '\\' {
+            let mut chars = rest[1..].chars();
+            if let Some(escaped) = chars.next() {
+                out.push(escaped);
+            }
+            rest = chars.as_str();
+            continue;
+        }
+        match PATTERNS.iter().find(|p| starts_with(p)) {
+            Some(pattern) => {
+                write_pattern(&mut out, &v, pattern, &rest[..pattern.len()], fill_mode);
+                rest = &rest[pattern.len()..];
+            }
+            None => {
+                out.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+        fill_mode = false;
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector_op::cast::str_to_timestamp;
+
+    #[test]
+    fn test_to_char() {
+        let v = str_to_timestamp("2022-08-07 13:05:09.123456").unwrap();
+        for (format, expected) in [
+            ("YYYY-MM-DD HH24:MI:SS", "2022-08-07 13:05:09"),
+            ("HH12:MI:SS.MS PM", "01:05:09.123 PM"),
+            ("yy/mm/dd am US", "22/08/07 pm 123456"),
+            ("Month Day", "August    Sunday   "),
+            ("FMMonth FMDD, YYYY", "August 7, 2022"),
+            ("MON mon Dy DDD D Q", "AUG aug Sun 219 1 3"),
+            ("\"Day\" DD \\MM", "Day 07 MM"),
+            ("日期: YYYY", "日期: 2022"),
+        ] {
+            assert_eq!(to_char_timestamp(v, format).unwrap(), expected);
+        }
+    }
+}
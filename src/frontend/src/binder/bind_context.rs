@@ -37,10 +37,24 @@ impl ColumnBinding {
     }
 }
 
+/// A column merged from both sides of a join by `USING` or `NATURAL`.
+#[derive(Debug, Clone)]
+pub struct UsingColumn {
+    pub name: String,
+    /// Indexes in `columns` whose first non-null value is the value of the merged column, e.g.
+    /// only the left column for a left join, or both columns for a full join.
+    pub sources: Vec<usize>,
+    /// Indexes in `columns` of all columns merged into this one.
+    pub merged: Vec<usize>,
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Clause {
     Where,
     Values,
+    GroupBy,
+    Having,
+    JoinOn,
 }
 
 impl Display for Clause {
@@ -48,6 +62,9 @@ impl Display for Clause {
         match self {
             Clause::Where => write!(f, "WHERE"),
             Clause::Values => write!(f, "VALUES"),
+            Clause::GroupBy => write!(f, "GROUP BY"),
+            Clause::Having => write!(f, "HAVING"),
+            Clause::JoinOn => write!(f, "JOIN conditions"),
         }
     }
 }
@@ -60,6 +77,8 @@ pub struct BindContext {
     pub indexs_of: HashMap<String, Vec<usize>>,
     // Mapping table name to [begin, end) of its columns.
     pub range_of: HashMap<String, (usize, usize)>,
+    // Columns merged by `USING` or `NATURAL` joins, in the order they appear in `SELECT *`.
+    pub using_columns: Vec<UsingColumn>,
     // `clause` identifies in what clause we are binding.
     pub clause: Option<Clause>,
 }
@@ -76,6 +95,27 @@ impl BindContext {
         }
     }
 
+    /// Returns the merged column of `USING` or `NATURAL` joins referred to by an unqualified
+    /// `column_name`, or `None` if there isn't one.
+    pub fn get_using_column(&self, column_name: &String) -> Result<Option<&UsingColumn>> {
+        let using_column = match self.using_columns.iter().find(|c| c.name == *column_name) {
+            Some(using_column) => using_column,
+            None => return Ok(None),
+        };
+        // Other columns with the same name make the reference ambiguous.
+        if let Some(columns) = self.indexs_of.get(column_name) {
+            if columns.iter().any(|i| !using_column.merged.contains(i)) {
+                return Err(ErrorCode::InternalError("Ambiguous column name".into()).into());
+            }
+        }
+        Ok(Some(using_column))
+    }
+
+    /// Whether the column at `index` is merged into a column of `USING` or `NATURAL` joins.
+    pub fn is_merged(&self, index: usize) -> bool {
+        self.using_columns.iter().any(|c| c.merged.contains(&index))
+    }
+
     fn get_index(&self, column_name: &String) -> Result<usize> {
         let columns = self
             .indexs_of
@@ -118,6 +158,7 @@ impl BindContext {
             columns: Vec::new(),
             indexs_of: HashMap::new(),
             range_of: HashMap::new(),
+            using_columns: Vec::new(),
             clause: None,
         }
     }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use risingwave_common::error::Result;
+use risingwave_pb::user::grant_privilege::Action;
 use risingwave_sqlparser::ast::{Expr, ObjectName};
 
 use super::{Binder, BoundBaseTable, BoundTableSource};
@@ -35,8 +36,8 @@ impl Binder {
         source_name: ObjectName,
         selection: Option<Expr>,
     ) -> Result<BoundDelete> {
-        let (schema_name, table_name) = Self::resolve_table_name(source_name.clone())?;
-        let table_source = self.bind_table_source(source_name)?;
+        let (schema_name, table_name) = self.resolve_relation_name(source_name.clone())?;
+        let table_source = self.bind_table_source(source_name, Action::Delete)?;
         let table = self.bind_table(&schema_name, &table_name, None)?;
         let delete = BoundDelete {
             table_source,
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::DataType;
 use risingwave_sqlparser::ast::{BinaryOperator, Expr};
 
 use crate::binder::Binder;
@@ -26,7 +27,6 @@ impl Binder {
         right: Expr,
     ) -> Result<ExprImpl> {
         let bound_left = self.bind_expr(left)?;
-        let bound_right = self.bind_expr(right)?;
         let func_type = match op {
             BinaryOperator::Plus => ExprType::Add,
             BinaryOperator::Minus => ExprType::Subtract,
@@ -41,19 +41,76 @@ impl Binder {
             BinaryOperator::GtEq => ExprType::GreaterThanOrEqual,
             BinaryOperator::And => ExprType::And,
             BinaryOperator::Or => ExprType::Or,
+            BinaryOperator::BitwiseAnd => ExprType::BitwiseAnd,
+            BinaryOperator::BitwiseOr => ExprType::BitwiseOr,
+            BinaryOperator::PGBitwiseXor => ExprType::BitwiseXor,
+            BinaryOperator::PGBitwiseShiftLeft => ExprType::BitwiseShiftLeft,
+            BinaryOperator::PGBitwiseShiftRight => ExprType::BitwiseShiftRight,
+            // `^` is exponentiation in PG, while `#` is the bitwise XOR.
+            BinaryOperator::BitwiseXor => ExprType::Pow,
+            BinaryOperator::StringConcat => ExprType::ConcatOp,
             BinaryOperator::Like => ExprType::Like,
-            BinaryOperator::NotLike => return self.bind_not_like(bound_left, bound_right),
+            BinaryOperator::ILike => ExprType::Ilike,
+            BinaryOperator::SimilarTo => ExprType::SimilarTo,
+            BinaryOperator::PGRegexMatch => ExprType::RegexpLike,
+            BinaryOperator::PGJsonGet => ExprType::JsonbAccessInner,
+            BinaryOperator::PGJsonGetText => ExprType::JsonbAccessStr,
+            BinaryOperator::PGJsonGetPath => ExprType::JsonbAccessPath,
+            // `@>` is also the containment of arrays.
+            BinaryOperator::PGJsonContains => match bound_left.return_type() {
+                DataType::List { .. } => ExprType::ArrayContains,
+                _ => ExprType::JsonbContains,
+            },
+            BinaryOperator::PGOverlap => ExprType::ArrayOverlap,
+            BinaryOperator::PGJsonExists => ExprType::JsonbExists,
+            BinaryOperator::NotLike
+            | BinaryOperator::NotILike
+            | BinaryOperator::NotSimilarTo
+            | BinaryOperator::PGRegexNotMatch => {
+                let func_type = match op {
+                    BinaryOperator::NotLike => ExprType::Like,
+                    BinaryOperator::NotILike => ExprType::Ilike,
+                    BinaryOperator::NotSimilarTo => ExprType::SimilarTo,
+                    _ => ExprType::RegexpLike,
+                };
+                let bound_right = self.bind_expr(right)?;
+                return Self::bind_not(FunctionCall::new(
+                    func_type,
+                    vec![bound_left, bound_right],
+                )?);
+            }
+            BinaryOperator::PGRegexIMatch | BinaryOperator::PGRegexNotIMatch => {
+                // `~*` is `~` with the case-insensitive flag.
+                let bound_right = self.bind_expr(right)?;
+                let func_call = FunctionCall::new(
+                    ExprType::RegexpLike,
+                    vec![
+                        bound_left,
+                        bound_right,
+                        ExprImpl::literal_varchar("i".to_string()),
+                    ],
+                )?;
+                return match op {
+                    BinaryOperator::PGRegexIMatch => Ok(func_call.into()),
+                    _ => Self::bind_not(func_call),
+                };
+            }
             _ => return Err(ErrorCode::NotImplemented(format!("{:?}", op), 112.into()).into()),
         };
+        let bound_right = match right {
+            Expr::SomeOp(right) => {
+                return self.bind_quantified_comparison(bound_left, func_type, *right, false)
+            }
+            Expr::AllOp(right) => {
+                return self.bind_quantified_comparison(bound_left, func_type, *right, true)
+            }
+            right => self.bind_expr(right)?,
+        };
         Ok(FunctionCall::new(func_type, vec![bound_left, bound_right])?.into())
     }
 
-    /// Apply a NOT on top of LIKE.
-    fn bind_not_like(&mut self, left: ExprImpl, right: ExprImpl) -> Result<ExprImpl> {
-        Ok(FunctionCall::new(
-            ExprType::Not,
-            vec![FunctionCall::new(ExprType::Like, vec![left, right])?.into()],
-        )?
-        .into())
+    /// Apply a NOT on top of a negated operator, e.g. `NOT LIKE` or `!~`.
+    fn bind_not(func_call: FunctionCall) -> Result<ExprImpl> {
+        Ok(FunctionCall::new(ExprType::Not, vec![func_call.into()])?.into())
     }
 }
@@ -15,8 +15,9 @@
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_sqlparser::ast::Ident;
 
+use crate::binder::bind_context::{BindContext, UsingColumn};
 use crate::binder::Binder;
-use crate::expr::{CorrelatedInputRef, ExprImpl, InputRef};
+use crate::expr::{CorrelatedInputRef, ExprImpl, ExprType, FunctionCall, InputRef};
 
 impl Binder {
     pub fn bind_column(&mut self, idents: &[Ident]) -> Result<ExprImpl> {
@@ -32,6 +33,11 @@ impl Binder {
             }
         };
 
+        if table_name.is_none() {
+            if let Ok(Some(using_column)) = self.context.get_using_column(column_name) {
+                return Self::bind_using_column(&self.context, using_column, 0);
+            }
+        }
         if let Ok(index) = self
             .context
             .get_column_binding_index(table_name, column_name)
@@ -45,6 +51,11 @@ impl Binder {
         for (i, context) in self.upper_contexts.iter().rev().enumerate() {
             // `depth` starts from 1.
             let depth = i + 1;
+            if table_name.is_none() {
+                if let Ok(Some(using_column)) = context.get_using_column(column_name) {
+                    return Self::bind_using_column(context, using_column, depth);
+                }
+            }
             match context.get_column_binding_index(table_name, column_name) {
                 Ok(index) => {
                     let column = &context.columns[index];
@@ -62,4 +73,31 @@ impl Binder {
         }
         Err(err)
     }
+
+    /// Binds a column merged by `USING` or `NATURAL` joins in `context`, which is the current
+    /// context if `depth` is 0, or the context of the query `depth` levels above.
+    pub(in crate::binder) fn bind_using_column(
+        context: &BindContext,
+        using_column: &UsingColumn,
+        depth: usize,
+    ) -> Result<ExprImpl> {
+        let mut inputs = using_column
+            .sources
+            .iter()
+            .map(|index| {
+                let column = &context.columns[*index];
+                let data_type = column.field.data_type.clone();
+                if depth == 0 {
+                    InputRef::new(column.index, data_type).into()
+                } else {
+                    CorrelatedInputRef::new(column.index, data_type, depth).into()
+                }
+            })
+            .collect::<Vec<ExprImpl>>();
+        if inputs.len() == 1 {
+            Ok(inputs.pop().unwrap())
+        } else {
+            Ok(FunctionCall::new(ExprType::Coalesce, inputs)?.into())
+        }
+    }
 }
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::str::FromStr;
+
 use itertools::Itertools;
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
@@ -20,7 +22,12 @@ use risingwave_sqlparser::ast::{Function, FunctionArg, FunctionArgExpr};
 
 use crate::binder::bind_context::Clause;
 use crate::binder::Binder;
-use crate::expr::{AggCall, Expr, ExprImpl, ExprType, FunctionCall, Literal};
+use crate::catalog::system_catalog::{
+    format_type_names, visible_relation_ids, PG_CATALOG_SCHEMA_NAME,
+};
+use crate::expr::{
+    AggCall, Expr, ExprImpl, ExprType, FunctionCall, Literal, TableFunction, TableFunctionType,
+};
 
 impl Binder {
     pub(super) fn bind_function(&mut self, f: Function) -> Result<ExprImpl> {
@@ -31,60 +38,162 @@ impl Binder {
             .flatten_ok()
             .try_collect()?;
 
-        if f.name.0.len() == 1 {
-            let function_name = f.name.0.get(0).unwrap().value.as_str();
-            let function_name = function_name.to_lowercase();
-            let agg_kind = match function_name.as_str() {
-                "count" => Some(AggKind::Count),
-                "sum" => Some(AggKind::Sum),
-                "min" => Some(AggKind::Min),
-                "max" => Some(AggKind::Max),
-                "avg" => Some(AggKind::Avg),
-                _ => None,
-            };
-            if let Some(kind) = agg_kind {
-                self.ensure_aggregate_allowed()?;
-                return Ok(ExprImpl::AggCall(Box::new(AggCall::new(
-                    kind, inputs, f.distinct,
-                )?)));
+        let (schema_name, function_name) = Self::resolve_schema_qualified_name(f.name)?;
+        // All the functions are built into `pg_catalog`, which is always searched for function
+        // names. Other schemas have no functions.
+        if let Some(schema_name) = schema_name {
+            if schema_name != PG_CATALOG_SCHEMA_NAME {
+                return Err(ErrorCode::BindError(format!(
+                    "function {}.{} does not exist",
+                    schema_name, function_name
+                ))
+                .into());
+            }
+        }
+        let function_name = function_name.to_lowercase();
+        let agg_kind = match function_name.as_str() {
+            "count" => Some(AggKind::Count),
+            "sum" => Some(AggKind::Sum),
+            "min" => Some(AggKind::Min),
+            "max" => Some(AggKind::Max),
+            "avg" => Some(AggKind::Avg),
+            _ => None,
+        };
+        if let Some(kind) = agg_kind {
+            self.ensure_aggregate_allowed()?;
+            if inputs.iter().any(|input| input.has_table_function()) {
+                return Err(ErrorCode::InvalidInputSyntax(
+                    "aggregate function calls cannot contain set-returning function calls"
+                        .to_string(),
+                )
+                .into());
+            }
+            return Ok(ExprImpl::AggCall(Box::new(AggCall::new(
+                kind, inputs, f.distinct,
+            )?)));
+        }
+        if function_name == "grouping" {
+            return self.bind_grouping(inputs);
+        }
+        if let Ok(function_type) = TableFunctionType::from_str(&function_name) {
+            self.ensure_table_function_allowed()?;
+            return Ok(TableFunction::new(function_type, inputs)?.into());
+        }
+        let function_type = match function_name.as_str() {
+            "substr" => ExprType::Substr,
+            "length" => ExprType::Length,
+            "upper" => ExprType::Upper,
+            "lower" => ExprType::Lower,
+            "trim" => ExprType::Trim,
+            "replace" => ExprType::Replace,
+            "position" => ExprType::Position,
+            "ltrim" => ExprType::Ltrim,
+            "rtrim" => ExprType::Rtrim,
+            "nullif" => {
+                inputs = Self::rewrite_nullif_to_case_when(inputs)?;
+                ExprType::Case
             }
-            let function_type = match function_name.as_str() {
-                "substr" => ExprType::Substr,
-                "length" => ExprType::Length,
-                "upper" => ExprType::Upper,
-                "lower" => ExprType::Lower,
-                "trim" => ExprType::Trim,
-                "replace" => ExprType::Replace,
-                "position" => ExprType::Position,
-                "ltrim" => ExprType::Ltrim,
-                "rtrim" => ExprType::Rtrim,
-                "nullif" => {
-                    inputs = Self::rewrite_nullif_to_case_when(inputs)?;
-                    ExprType::Case
+            "concat_ws" => ExprType::ConcatWs,
+            "concat" => {
+                inputs = Self::rewrite_concat_to_concat_ws(inputs)?;
+                ExprType::ConcatWs
+            }
+            "strpos" => ExprType::Position,
+            "split_part" => ExprType::SplitPart,
+            "lpad" | "rpad" => {
+                if inputs.len() == 2 {
+                    // The fill defaults to a space.
+                    inputs.push(ExprImpl::literal_varchar(" ".to_string()));
+                }
+                if function_name == "lpad" {
+                    ExprType::Lpad
+                } else {
+                    ExprType::Rpad
                 }
-                "concat_ws" => ExprType::ConcatWs,
-                "coalesce" => ExprType::Coalesce,
-                "round" => {
+            }
+            "repeat" => ExprType::Repeat,
+            "reverse" => ExprType::Reverse,
+            "initcap" => ExprType::Initcap,
+            "left" => ExprType::Left,
+            "right" => ExprType::Right,
+            "string_to_array" => ExprType::StringToArray,
+            "array_length" => ExprType::ArrayLength,
+            "cardinality" => ExprType::Cardinality,
+            "array_append" => ExprType::ArrayAppend,
+            "array_cat" => ExprType::ArrayCat,
+            "array_position" => ExprType::ArrayPosition,
+            "array_to_string" => ExprType::ArrayToString,
+            "coalesce" => ExprType::Coalesce,
+            "round" => {
+                if let [input] = inputs.as_slice()
+                    && matches!(input.return_type(), DataType::Float32 | DataType::Float64)
+                {
+                    ExprType::Round
+                } else {
                     inputs = Self::rewrite_round_args(inputs);
                     ExprType::RoundDigit
                 }
-                "abs" => ExprType::Abs,
-                _ => {
-                    return Err(ErrorCode::NotImplemented(
-                        format!("unsupported function: {:?}", function_name),
-                        112.into(),
-                    )
-                    .into())
+            }
+            "abs" => ExprType::Abs,
+            "pow" | "power" => ExprType::Pow,
+            "sqrt" => ExprType::Sqrt,
+            "cbrt" => ExprType::Cbrt,
+            "exp" => ExprType::Exp,
+            "ln" => ExprType::Ln,
+            "log10" => ExprType::Log10,
+            "log" => {
+                if inputs.len() == 2 {
+                    return Self::bind_log_with_base(inputs);
                 }
-            };
-            Ok(FunctionCall::new(function_type, inputs)?.into())
-        } else {
-            Err(ErrorCode::NotImplemented(
-                format!("unsupported function: {:?}", f.name),
-                112.into(),
-            )
-            .into())
-        }
+                ExprType::Log10
+            }
+            "floor" => ExprType::Floor,
+            "ceil" | "ceiling" => ExprType::Ceil,
+            "trunc" => ExprType::Trunc,
+            "sign" => ExprType::Sign,
+            "sin" => ExprType::Sin,
+            "cos" => ExprType::Cos,
+            "tan" => ExprType::Tan,
+            "cot" => ExprType::Cot,
+            "asin" => ExprType::Asin,
+            "acos" => ExprType::Acos,
+            "atan" => ExprType::Atan,
+            "atan2" => ExprType::Atan2,
+            "jsonb_typeof" => ExprType::JsonbTypeof,
+            "jsonb_extract_path" => ExprType::JsonbExtractPath,
+            "encode" => ExprType::Encode,
+            "decode" => ExprType::Decode,
+            "md5" => ExprType::Md5,
+            "sha256" => ExprType::Sha256,
+            "regexp_match" => ExprType::RegexpMatch,
+            "regexp_replace" => ExprType::RegexpReplace,
+            "date_trunc" => ExprType::DateTrunc,
+            "to_char" => ExprType::ToChar,
+            "to_timestamp" => ExprType::ToTimestamp,
+            "make_date" => ExprType::MakeDate,
+            "age" => {
+                if inputs.len() == 1 {
+                    // `age(t)` is `age(current_date, t)`, where the date is taken as midnight.
+                    inputs.insert(0, self.bind_current_date()?);
+                }
+                ExprType::Age
+            }
+            "now" => {
+                self.ensure_now_allowed()?;
+                ExprType::Now
+            }
+            "pg_table_is_visible" => return self.bind_pg_table_is_visible(inputs),
+            "format_type" => return self.bind_format_type(inputs),
+            "pg_get_userbyid" => return self.bind_pg_get_userbyid(inputs),
+            _ => {
+                return Err(ErrorCode::NotImplemented(
+                    format!("unsupported function: {:?}", function_name),
+                    112.into(),
+                )
+                .into())
+            }
+        };
+        Ok(FunctionCall::new(function_type, inputs)?.into())
     }
 
     /// Make sure inputs only have 2 value and rewrite the arguments.
@@ -102,6 +211,21 @@ impl Binder {
         }
     }
 
+    /// `concat(args)` is `concat_ws('', args)`, which ignores NULL arguments.
+    fn rewrite_concat_to_concat_ws(inputs: Vec<ExprImpl>) -> Result<Vec<ExprImpl>> {
+        if inputs.is_empty() {
+            Err(ErrorCode::BindError(
+                "Concat function must contain at least 1 argument".to_string(),
+            )
+            .into())
+        } else {
+            let inputs = std::iter::once(ExprImpl::literal_varchar("".to_string()))
+                .chain(inputs)
+                .collect();
+            Ok(inputs)
+        }
+    }
+
     /// Rewrite the arguments to be consistent with the `round` signature:
     /// - round(Decimal, Int32) -> Decimal
     /// - round(Decimal) -> Decimal
@@ -134,6 +258,180 @@ impl Binder {
         }
     }
 
+    /// `log(b, x)` is the logarithm of `x` to base `b`, which is only defined on `numeric` and
+    /// computed as `ln(x) / ln(b)`.
+    fn bind_log_with_base(inputs: Vec<ExprImpl>) -> Result<ExprImpl> {
+        let [base, input]: [ExprImpl; 2] = inputs.try_into().unwrap();
+        let ln = |input: ExprImpl| -> Result<ExprImpl> {
+            Ok(
+                FunctionCall::new(ExprType::Ln, vec![input.cast_implicit(DataType::Decimal)?])?
+                    .into(),
+            )
+        };
+        Ok(FunctionCall::new(ExprType::Divide, vec![ln(input)?, ln(base)?])?.into())
+    }
+
+    /// `GROUPING(args)` can only be evaluated on top of the aggregation, so it is bound as a
+    /// placeholder function call and will be rewritten by the planner.
+    fn bind_grouping(&self, inputs: Vec<ExprImpl>) -> Result<ExprImpl> {
+        if let Some(clause) = self.context.clause {
+            if clause == Clause::Values || clause == Clause::Where {
+                return Err(ErrorCode::InvalidInputSyntax(format!(
+                    "grouping operations are not allowed in {}",
+                    clause
+                ))
+                .into());
+            }
+        }
+        if inputs.is_empty() || inputs.len() > 31 {
+            return Err(ErrorCode::InvalidInputSyntax(
+                "GROUPING must have between 1 and 31 arguments".into(),
+            )
+            .into());
+        }
+        Ok(FunctionCall::new_unchecked(ExprType::Grouping, inputs, DataType::Int32).into())
+    }
+
+    /// `now()` changes over time, so it can only be evaluated in batch queries, where it is the
+    /// time when the query starts.
+    fn ensure_now_allowed(&self) -> Result<()> {
+        if self.in_streaming {
+            return Err(ErrorCode::NotImplemented(
+                "now() in streaming queries".to_string(),
+                None.into(),
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Returns the midnight of the current date in the session time zone, as `timestamptz`.
+    fn bind_current_date(&self) -> Result<ExprImpl> {
+        self.ensure_now_allowed()?;
+        Ok(FunctionCall::new(
+            ExprType::DateTrunc,
+            vec![
+                ExprImpl::literal_varchar("day".to_string()),
+                FunctionCall::new(ExprType::Now, vec![])?.into(),
+            ],
+        )?
+        .into())
+    }
+
+    /// `pg_table_is_visible(oid)` is whether the relation can be referred to by its unqualified
+    /// name. The visible relations are looked up in the catalog while binding, so it is bound as
+    /// `oid IN (visible relation ids)`.
+    fn bind_pg_table_is_visible(&self, inputs: Vec<ExprImpl>) -> Result<ExprImpl> {
+        let [oid] = self.bind_catalog_function_args("pg_table_is_visible", inputs)?;
+        let ids = visible_relation_ids(&self.catalog, &self.db_name, &self.search_path);
+        if ids.is_empty() {
+            return Self::bind_null_or(oid, ExprImpl::literal_bool(false));
+        }
+        let inputs = std::iter::once(oid)
+            .chain(ids.into_iter().map(|id| ExprImpl::literal_int(id as i32)))
+            .collect();
+        Ok(FunctionCall::new(ExprType::In, inputs)?.into())
+    }
+
+    /// `format_type(type_oid, typemod)` is the SQL name of a data type, or `???` for an unknown
+    /// oid. The modifier is ignored as no type has modifiers.
+    fn bind_format_type(&self, inputs: Vec<ExprImpl>) -> Result<ExprImpl> {
+        let [oid, _typemod] = self.bind_catalog_function_args("format_type", inputs)?;
+        let names = format_type_names().map(|(type_oid, name)| (type_oid, name.to_string()));
+        let unknown = ExprImpl::literal_varchar("???".to_string());
+        Self::bind_oid_lookup(oid, names, unknown)
+    }
+
+    /// `pg_get_userbyid(role_oid)` is the name of a user, or `unknown (OID=n)` for an unknown
+    /// oid.
+    fn bind_pg_get_userbyid(&self, inputs: Vec<ExprImpl>) -> Result<ExprImpl> {
+        let [oid] = self.bind_catalog_function_args("pg_get_userbyid", inputs)?;
+        let names = self
+            .catalog
+            .iter_users()
+            .map(|user| (user.id as i32, user.name.clone()))
+            .sorted()
+            .collect_vec();
+        let unknown = FunctionCall::new(
+            ExprType::ConcatWs,
+            vec![
+                ExprImpl::literal_varchar("".to_string()),
+                ExprImpl::literal_varchar("unknown (OID=".to_string()),
+                oid.clone(),
+                ExprImpl::literal_varchar(")".to_string()),
+            ],
+        )?
+        .into();
+        Self::bind_oid_lookup(oid, names, unknown)
+    }
+
+    /// Checks the number of the arguments of a function on the catalog, and casts them to `int4`
+    /// as oids are shown in `int4`. The catalog is read while binding, so such functions can't be
+    /// used in streaming queries, which would keep outdated results.
+    fn bind_catalog_function_args<const N: usize>(
+        &self,
+        function_name: &str,
+        inputs: Vec<ExprImpl>,
+    ) -> Result<[ExprImpl; N]> {
+        if self.in_streaming {
+            return Err(ErrorCode::NotImplemented(
+                format!("{}() in streaming queries", function_name),
+                None.into(),
+            )
+            .into());
+        }
+        let inputs: [ExprImpl; N] = inputs.try_into().map_err(|_| {
+            ErrorCode::BindError(format!(
+                "function {} must contain {} argument(s)",
+                function_name, N
+            ))
+        })?;
+        let inputs = inputs
+            .into_iter()
+            .map(|input| input.cast_explicit(DataType::Int32))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(inputs.try_into().unwrap())
+    }
+
+    /// `CASE WHEN oid IS NULL THEN NULL WHEN oid = k THEN v ... ELSE unknown END` for the pairs
+    /// `(k, v)` of `names`.
+    fn bind_oid_lookup(
+        oid: ExprImpl,
+        names: impl IntoIterator<Item = (i32, String)>,
+        unknown: ExprImpl,
+    ) -> Result<ExprImpl> {
+        let mut inputs = vec![
+            FunctionCall::new(ExprType::IsNull, vec![oid.clone()])?.into(),
+            Literal::new(None, DataType::Varchar).into(),
+        ];
+        for (key, name) in names {
+            inputs.push(
+                FunctionCall::new(
+                    ExprType::Equal,
+                    vec![oid.clone(), ExprImpl::literal_int(key)],
+                )?
+                .into(),
+            );
+            inputs.push(ExprImpl::literal_varchar(name));
+        }
+        inputs.push(unknown);
+        Ok(FunctionCall::new(ExprType::Case, inputs)?.into())
+    }
+
+    /// `CASE WHEN input IS NULL THEN NULL ELSE value END`.
+    fn bind_null_or(input: ExprImpl, value: ExprImpl) -> Result<ExprImpl> {
+        let return_type = value.return_type();
+        Ok(FunctionCall::new(
+            ExprType::Case,
+            vec![
+                FunctionCall::new(ExprType::IsNull, vec![input])?.into(),
+                Literal::new(None, return_type).into(),
+                value,
+            ],
+        )?
+        .into())
+    }
+
     fn ensure_aggregate_allowed(&self) -> Result<()> {
         if let Some(clause) = self.context.clause {
             if clause == Clause::Values || clause == Clause::Where {
@@ -147,6 +445,19 @@ impl Binder {
         Ok(())
     }
 
+    /// Set-returning functions are evaluated by `ProjectSet` on top of the aggregation, so they are
+    /// only allowed in the SELECT list.
+    fn ensure_table_function_allowed(&self) -> Result<()> {
+        if let Some(clause) = self.context.clause {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "set-returning functions are not allowed in {}",
+                clause
+            ))
+            .into());
+        }
+        Ok(())
+    }
+
     pub(in crate::binder) fn bind_function_expr_arg(
         &mut self,
         arg_expr: FunctionArgExpr,
@@ -22,7 +22,7 @@ use risingwave_sqlparser::ast::{
 };
 
 use crate::binder::Binder;
-use crate::expr::{Expr as _, ExprImpl, ExprType, FunctionCall, SubqueryKind};
+use crate::expr::{Expr as _, ExprImpl, ExprRewriter, ExprType, FunctionCall, SubqueryKind};
 
 mod binary_op;
 mod column;
@@ -32,6 +32,12 @@ mod value;
 
 impl Binder {
     pub(super) fn bind_expr(&mut self, expr: Expr) -> Result<ExprImpl> {
+        let expr = self.bind_expr_inner(expr)?;
+        // The rewriting is idempotent, so it does no harm to apply it again on the outer levels.
+        Ok(self.session_timezone.rewrite_expr(expr))
+    }
+
+    fn bind_expr_inner(&mut self, expr: Expr) -> Result<ExprImpl> {
         match expr {
             // literal
             Expr::Value(v) => Ok(ExprImpl::Literal(Box::new(self.bind_value(v)?))),
@@ -40,6 +46,8 @@ impl Binder {
                 s.cast_explicit(bind_data_type(&data_type)?)
             }
             Expr::Row(exprs) => Ok(ExprImpl::Literal(Box::new(self.bind_row(&exprs)?))),
+            Expr::Array(exprs) => self.bind_array(exprs),
+            Expr::ArrayIndex { obj, index } => self.bind_array_index(*obj, *index),
             // input ref
             Expr::Identifier(ident) => self.bind_column(&[ident]),
             Expr::CompoundIdentifier(idents) => self.bind_column(&idents),
@@ -88,6 +96,10 @@ impl Binder {
             } => self.bind_in_list(*expr, list, negated),
             // special syntax for date/time
             Expr::Extract { field, expr } => self.bind_extract(field, *expr),
+            Expr::AtTimeZone {
+                timestamp,
+                time_zone,
+            } => self.bind_at_time_zone(*timestamp, *time_zone),
             // special syntaxt for string
             Expr::Trim { expr, trim_where } => self.bind_trim(*expr, trim_where),
             Expr::Substring {
@@ -103,6 +115,39 @@ impl Binder {
         }
     }
 
+    /// Binds the `DEFAULT` expression of a column, which must be a constant of `data_type`.
+    pub fn bind_column_default(&mut self, expr: Expr, data_type: DataType) -> Result<ExprImpl> {
+        let expr = self.bind_expr(expr)?.cast_assign(data_type)?;
+        let expr = self.session_timezone.rewrite_expr(expr);
+        if !expr.is_const() {
+            return Err(ErrorCode::BindError(
+                "only constants are supported in DEFAULT expression".to_string(),
+            )
+            .into());
+        }
+        Ok(expr)
+    }
+
+    /// Binds `ARRAY[expr, ...]`, whose elements are casted to a common type.
+    pub(super) fn bind_array(&mut self, exprs: Vec<Expr>) -> Result<ExprImpl> {
+        if exprs.is_empty() {
+            return Err(
+                ErrorCode::BindError("cannot determine type of empty array".to_string()).into(),
+            );
+        }
+        let exprs = exprs
+            .into_iter()
+            .map(|e| self.bind_expr(e))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(FunctionCall::new(ExprType::Array, exprs)?.into())
+    }
+
+    pub(super) fn bind_array_index(&mut self, obj: Expr, index: Expr) -> Result<ExprImpl> {
+        let obj = self.bind_expr(obj)?;
+        let index = self.bind_expr(index)?;
+        Ok(FunctionCall::new(ExprType::ArrayAccess, vec![obj, index])?.into())
+    }
+
     pub(super) fn bind_extract(&mut self, field: DateTimeField, expr: Expr) -> Result<ExprImpl> {
         let arg = self.bind_expr(expr)?;
         let arg_type = arg.return_type();
@@ -122,6 +167,12 @@ impl Binder {
         .into())
     }
 
+    pub(super) fn bind_at_time_zone(&mut self, input: Expr, time_zone: Expr) -> Result<ExprImpl> {
+        let input = self.bind_expr(input)?;
+        let time_zone = self.bind_expr(time_zone)?;
+        FunctionCall::new(ExprType::AtTimeZone, vec![input, time_zone]).map(Into::into)
+    }
+
     pub(super) fn bind_in_list(
         &mut self,
         expr: Expr,
@@ -179,6 +230,10 @@ impl Binder {
         let func_type = match op {
             UnaryOperator::Not => ExprType::Not,
             UnaryOperator::Minus => ExprType::Neg,
+            UnaryOperator::PGBitwiseNot => ExprType::BitwiseNot,
+            UnaryOperator::PGSquareRoot => ExprType::Sqrt,
+            UnaryOperator::PGCubeRoot => ExprType::Cbrt,
+            UnaryOperator::PGAbs => ExprType::Abs,
             UnaryOperator::Plus => {
                 return self.rewrite_positive(expr);
             }
@@ -382,6 +437,7 @@ pub fn bind_struct_field(column_def: &StructField) -> Result<ColumnDesc> {
                     name: f.name.value.clone(),
                     field_descs: vec![],
                     type_name: "".to_string(),
+                    default_value: None,
                 })
             })
             .collect::<Result<Vec<_>>>()?
@@ -394,6 +450,7 @@ pub fn bind_struct_field(column_def: &StructField) -> Result<ColumnDesc> {
         name: column_def.name.value.clone(),
         field_descs,
         type_name: "".to_string(),
+        default_value: None,
     })
 }
 
@@ -412,16 +469,15 @@ pub fn bind_data_type(data_type: &AstDataType) -> Result<DataType> {
         AstDataType::Timestamp(false) => DataType::Timestamp,
         AstDataType::Timestamp(true) => DataType::Timestampz,
         AstDataType::Interval => DataType::Interval,
+        AstDataType::Jsonb => DataType::Jsonb,
+        AstDataType::Bytea => DataType::Bytea,
+        AstDataType::Uuid => DataType::Uuid,
+        AstDataType::Char(len) => DataType::Char {
+            len: len.unwrap_or(1) as u32,
+        },
         AstDataType::Array(datatype) => DataType::List {
             datatype: Box::new(bind_data_type(datatype)?),
         },
-        AstDataType::Char(..) => {
-            return Err(ErrorCode::NotImplemented(
-                "CHAR is not supported, please use VARCHAR instead\n".to_string(),
-                None.into(),
-            )
-            .into())
-        }
         AstDataType::Struct(types) => DataType::Struct {
             fields: types
                 .iter()
@@ -13,10 +13,11 @@
 // limitations under the License.
 
 use risingwave_common::error::{ErrorCode, Result};
-use risingwave_sqlparser::ast::Query;
+use risingwave_common::types::DataType;
+use risingwave_sqlparser::ast::{Expr, Query};
 
 use crate::binder::Binder;
-use crate::expr::{ExprImpl, Subquery, SubqueryKind};
+use crate::expr::{ExprImpl, ExprType, FunctionCall, InputRef, Subquery, SubqueryKind};
 
 impl Binder {
     pub(super) fn bind_subquery_expr(
@@ -32,4 +33,89 @@ impl Binder {
         }
         Ok(Subquery::new(query, kind).into())
     }
+
+    /// Binds `left <op> SOME (subquery)` (or `ANY`) when `is_all` is false, and
+    /// `left <op> ALL (subquery)` otherwise. Only comparison operators are allowed.
+    pub(super) fn bind_quantified_comparison(
+        &mut self,
+        left: ExprImpl,
+        func_type: ExprType,
+        right: Expr,
+        is_all: bool,
+    ) -> Result<ExprImpl> {
+        if !matches!(
+            func_type,
+            ExprType::Equal
+                | ExprType::NotEqual
+                | ExprType::LessThan
+                | ExprType::LessThanOrEqual
+                | ExprType::GreaterThan
+                | ExprType::GreaterThanOrEqual
+        ) {
+            return Err(ErrorCode::BindError(format!(
+                "operator {:?} is not supported with ANY, SOME or ALL",
+                func_type
+            ))
+            .into());
+        }
+        let query = match right {
+            Expr::Subquery(query) => *query,
+            right => return self.bind_array_comparison(left, func_type, right, is_all),
+        };
+        let query = self.bind_query(query)?;
+        let right_types = query.data_types();
+        if right_types.len() != 1 {
+            return Err(
+                ErrorCode::BindError("Subquery must return only one column".to_string()).into(),
+            );
+        }
+        // Check that the operands are comparable. The comparison itself is built by the planner.
+        FunctionCall::new(
+            func_type,
+            vec![left.clone(), InputRef::new(0, right_types[0].clone()).into()],
+        )?;
+        let kind = if is_all {
+            SubqueryKind::All(left, func_type)
+        } else {
+            SubqueryKind::Some(left, func_type)
+        };
+        Ok(Subquery::new(query, kind).into())
+    }
+
+    /// Binds `left <op> ANY (array)` or `left <op> ALL (array)`, which is evaluated by comparing
+    /// `left` with each element of the array.
+    fn bind_array_comparison(
+        &mut self,
+        left: ExprImpl,
+        func_type: ExprType,
+        right: Expr,
+        is_all: bool,
+    ) -> Result<ExprImpl> {
+        let right = self.bind_expr(right)?;
+        if !matches!(right.return_type(), DataType::List { .. }) {
+            return Err(ErrorCode::BindError(format!(
+                "op ANY/ALL (array) requires array on right side, but got {:?}",
+                right.return_type()
+            ))
+            .into());
+        }
+        let op = match func_type {
+            ExprType::Equal => "=",
+            ExprType::NotEqual => "<>",
+            ExprType::LessThan => "<",
+            ExprType::LessThanOrEqual => "<=",
+            ExprType::GreaterThan => ">",
+            _ => ">=",
+        };
+        let func_type = if is_all {
+            ExprType::ArrayAll
+        } else {
+            ExprType::ArrayAny
+        };
+        Ok(FunctionCall::new(
+            func_type,
+            vec![left, ExprImpl::literal_varchar(op.to_string()), right],
+        )?
+        .into())
+    }
 }
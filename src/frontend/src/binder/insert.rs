@@ -15,11 +15,12 @@
 use itertools::Itertools;
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
+use risingwave_pb::user::grant_privilege::Action;
 use risingwave_sqlparser::ast::{Ident, ObjectName, Query, SetExpr};
 
 use super::{BoundQuery, BoundSetExpr};
 use crate::binder::{Binder, BoundTableSource};
-use crate::expr::{ExprImpl, InputRef};
+use crate::expr::{ExprImpl, ExprRewriter, InputRef, Literal};
 
 #[derive(Debug)]
 pub struct BoundInsert {
@@ -30,6 +31,7 @@ pub struct BoundInsert {
 
     /// Used as part of an extra `Project` when the column types of `source` query does not match
     /// `table_source`. This does not include a simple `VALUE`. See comments in code for details.
+    /// It also fills NULL for the dropped columns of `table_source`, if any.
     pub cast_exprs: Vec<ExprImpl>,
 }
 
@@ -40,7 +42,7 @@ impl Binder {
         _columns: Vec<Ident>,
         source: Query,
     ) -> Result<BoundInsert> {
-        let table_source = self.bind_table_source(source_name)?;
+        let table_source = self.bind_table_source(source_name, Action::Insert)?;
 
         let expected_types = table_source
             .columns
@@ -70,7 +72,7 @@ impl Binder {
         // internal implicit cast.
         // In other cases, the `source` query is handled on its own and assignment cast is done
         // afterwards.
-        let (source, cast_exprs) = match source {
+        let (source, mut cast_exprs) = match source {
             Query {
                 with: None,
                 body: SetExpr::Values(values),
@@ -97,7 +99,7 @@ impl Binder {
                 let actual_types = bound.data_types();
                 let cast_exprs = match expected_types == actual_types {
                     true => vec![],
-                    false => Self::cast_on_insert(
+                    false => self.cast_on_insert(
                         expected_types,
                         actual_types
                             .into_iter()
@@ -110,6 +112,21 @@ impl Binder {
             }
         };
 
+        // The dropped columns are still written, as NULL.
+        if !table_source.dropped_columns.is_empty() {
+            if cast_exprs.is_empty() {
+                cast_exprs = table_source
+                    .columns
+                    .iter()
+                    .enumerate()
+                    .map(|(i, c)| InputRef::new(i, c.data_type.clone()).into())
+                    .collect();
+            }
+            for (i, data_type) in &table_source.dropped_columns {
+                cast_exprs.insert(*i, Literal::new(None, data_type.clone()).into());
+            }
+        }
+
         let insert = BoundInsert {
             table_source,
             source,
@@ -122,6 +139,7 @@ impl Binder {
     /// Cast a list of `exprs` to corresponding `expected_types` IN ASSIGNMENT CONTEXT. Make sure
     /// you understand the difference of implicit, assignment and explicit cast before reusing it.
     pub(super) fn cast_on_insert(
+        &mut self,
         expected_types: Vec<DataType>,
         exprs: Vec<ExprImpl>,
     ) -> Result<Vec<ExprImpl>> {
@@ -130,7 +148,7 @@ impl Binder {
                 return exprs
                     .into_iter()
                     .zip_eq(expected_types)
-                    .map(|(e, t)| e.cast_assign(t))
+                    .map(|(e, t)| Ok(self.session_timezone.rewrite_expr(e.cast_assign(t)?)))
                     .try_collect()
             }
             std::cmp::Ordering::Less => "INSERT has more expressions than target columns",
@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+
 use risingwave_common::error::Result;
+use risingwave_pb::user::grant_privilege::{Action, Target};
 use risingwave_sqlparser::ast::Statement;
 
 pub mod bind_context;
@@ -33,8 +36,8 @@ pub use delete::BoundDelete;
 pub use insert::BoundInsert;
 pub use query::BoundQuery;
 pub use relation::{
-    BoundBaseTable, BoundGenerateSeriesFunction, BoundJoin, BoundSource, BoundTableSource,
-    BoundWindowTableFunction, Relation, WindowTableFunctionKind,
+    BoundBaseTable, BoundGenerateSeriesFunction, BoundJoin, BoundSource, BoundSystemTable,
+    BoundTableSource, BoundWindowTableFunction, Relation, WindowTableFunctionKind,
 };
 pub use select::BoundSelect;
 pub use set_expr::BoundSetExpr;
@@ -43,12 +46,22 @@ pub use update::BoundUpdate;
 pub use values::BoundValues;
 
 use crate::catalog::catalog_service::CatalogReadGuard;
+use crate::catalog::search_path::SearchPath;
+use crate::catalog::{TableId, ViewId};
+use crate::expr::SessionTimezone;
 
 /// `Binder` binds the identifiers in AST to columns in relations
 pub struct Binder {
     // TODO: maybe we can only lock the database, but not the whole catalog.
     catalog: CatalogReadGuard,
     db_name: String,
+    /// The user whose privileges are checked on the bound relations.
+    user_name: String,
+    /// The schemas to look up unqualified relation names in.
+    search_path: SearchPath,
+    /// Applied to every bound expression to pass the session time zone to the functions depending
+    /// on it.
+    session_timezone: SessionTimezone,
     context: BindContext,
     /// A stack holding contexts of outer queries when binding a subquery.
     ///
@@ -56,16 +69,50 @@ pub struct Binder {
     upper_contexts: Vec<BindContext>,
 
     next_subquery_id: usize,
+
+    /// The tables, sources and views referred to by the statement, not counting those only
+    /// referred to inside the views. A view defined by the statement depends on them.
+    included_relations: HashSet<TableId>,
+    /// The views being expanded, innermost last.
+    expanding_views: Vec<ViewId>,
+    /// Whether the statement is a streaming query, where functions like `now()` are not allowed.
+    in_streaming: bool,
 }
 
 impl Binder {
-    pub fn new(catalog: CatalogReadGuard, db_name: String) -> Binder {
+    pub fn new(
+        catalog: CatalogReadGuard,
+        db_name: String,
+        user_name: String,
+        search_path: SearchPath,
+        session_timezone: SessionTimezone,
+    ) -> Binder {
         Binder {
             catalog,
             db_name,
+            user_name,
+            search_path,
+            session_timezone,
             context: BindContext::new(),
             upper_contexts: vec![],
             next_subquery_id: 0,
+            included_relations: HashSet::new(),
+            expanding_views: vec![],
+            in_streaming: false,
+        }
+    }
+
+    /// Creates a binder for a streaming query, e.g. the query of a materialized view.
+    pub fn new_for_stream(
+        catalog: CatalogReadGuard,
+        db_name: String,
+        user_name: String,
+        search_path: SearchPath,
+        session_timezone: SessionTimezone,
+    ) -> Binder {
+        Binder {
+            in_streaming: true,
+            ..Self::new(catalog, db_name, user_name, search_path, session_timezone)
         }
     }
 
@@ -74,6 +121,18 @@ impl Binder {
         self.bind_statement(stmt)
     }
 
+    /// Returns the relations referred to by the bound statements.
+    pub fn included_relations(&self) -> &HashSet<TableId> {
+        &self.included_relations
+    }
+
+    /// Checks that the current user is allowed to perform `action` on `target`.
+    fn check_privilege(&self, target: Target, action: Action, object_name: &str) -> Result<()> {
+        self.catalog
+            .get_user_by_name(&self.user_name)?
+            .check_privilege(&target, action, object_name)
+    }
+
     fn push_context(&mut self) {
         let new_context = std::mem::take(&mut self.context);
         self.upper_contexts.push(new_context);
@@ -96,16 +155,25 @@ pub mod test_utils {
     use std::sync::Arc;
 
     use parking_lot::RwLock;
+    use risingwave_common::catalog::DEFAULT_SUPER_USER;
 
     use super::Binder;
     use crate::catalog::catalog_service::CatalogReader;
     use crate::catalog::root_catalog::Catalog;
+    use crate::catalog::search_path::SearchPath;
+    use crate::expr::SessionTimezone;
 
     #[cfg(test)]
     pub fn mock_binder_with_catalog(catalog: Catalog, db_name: String) -> Binder {
         let catalog = Arc::new(RwLock::new(catalog));
         let catalog_reader = CatalogReader::new(catalog);
-        Binder::new(catalog_reader.read_guard(), db_name)
+        Binder::new(
+            catalog_reader.read_guard(),
+            db_name,
+            DEFAULT_SUPER_USER.to_string(),
+            SearchPath::default(),
+            SessionTimezone::default(),
+        )
     }
     #[cfg(test)]
     pub fn mock_binder() -> Binder {
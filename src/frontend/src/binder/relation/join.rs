@@ -12,13 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use itertools::Itertools;
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
 use risingwave_pb::plan_common::JoinType;
-use risingwave_sqlparser::ast::{JoinConstraint, JoinOperator, TableWithJoins};
+use risingwave_sqlparser::ast::{Ident, JoinConstraint, JoinOperator, TableFactor, TableWithJoins};
 
+use crate::binder::bind_context::{Clause, UsingColumn};
 use crate::binder::{Binder, Relation};
-use crate::expr::{Expr as _, ExprImpl};
+use crate::expr::{merge_expr_by_binary, Expr as _, ExprImpl, ExprType, FunctionCall};
 
 #[derive(Debug)]
 pub struct BoundJoin {
@@ -33,27 +35,52 @@ impl Binder {
         &mut self,
         from: Vec<TableWithJoins>,
     ) -> Result<Option<Relation>> {
-        let mut from_iter = from.into_iter();
-        let first = match from_iter.next() {
-            Some(t) => t,
-            None => return Ok(None),
-        };
-        let mut root = self.bind_table_with_joins(first)?;
-        for t in from_iter {
-            let right = self.bind_table_with_joins(t)?;
-            root = Relation::Join(Box::new(BoundJoin {
-                join_type: JoinType::Inner,
-                left: root,
-                right,
-                cond: ExprImpl::literal_bool(true),
-            }));
+        let mut root = None;
+        for t in from {
+            root = Some(self.bind_table_with_joins(root, t)?);
         }
-        Ok(Some(root))
+        Ok(root)
     }
 
-    fn bind_table_with_joins(&mut self, table: TableWithJoins) -> Result<Relation> {
-        let mut root = self.bind_table_factor(table.relation)?;
+    /// Binds `table` and cross joins it with `left`, which is the previous FROM items if any.
+    fn bind_table_with_joins(
+        &mut self,
+        left: Option<Relation>,
+        table: TableWithJoins,
+    ) -> Result<Relation> {
+        // `left_begin` is where the columns of `root` begin in the context.
+        let (left_begin, mut root) = match left {
+            // A `LATERAL` subquery can refer to the previous FROM items, so it is joined with them
+            // before the following joins.
+            Some(left) if matches!(table.relation, TableFactor::Derived { lateral: true, .. }) => {
+                let right_begin = self.context.columns.len();
+                let right = self.bind_table_factor(table.relation)?;
+                let join = self.bind_join(
+                    left,
+                    right,
+                    JoinType::Inner,
+                    JoinConstraint::None,
+                    0,
+                    right_begin,
+                )?;
+                (0, join)
+            }
+            Some(left) => {
+                let right = self.bind_table_with_joins(None, table)?;
+                return Ok(Relation::Join(Box::new(BoundJoin {
+                    join_type: JoinType::Inner,
+                    left,
+                    right,
+                    cond: ExprImpl::literal_bool(true),
+                })));
+            }
+            None => (
+                self.context.columns.len(),
+                self.bind_table_factor(table.relation)?,
+            ),
+        };
         for join in table.joins {
+            let right_begin = self.context.columns.len();
             let right = self.bind_table_factor(join.relation)?;
             let (constraint, join_type) = match join.join_operator {
                 JoinOperator::Inner(constraint) => (constraint, JoinType::Inner),
@@ -63,27 +90,82 @@ impl Binder {
                 // Cross join equals to inner join with with no constraint.
                 JoinOperator::CrossJoin => (JoinConstraint::None, JoinType::Inner),
             };
-            let cond = self.bind_join_constraint(constraint)?;
-            let join = BoundJoin {
-                join_type,
-                left: root,
-                right,
-                cond,
-            };
-            root = Relation::Join(Box::new(join));
+            root = self.bind_join(root, right, join_type, constraint, left_begin, right_begin)?;
         }
 
         Ok(root)
     }
 
-    fn bind_join_constraint(&mut self, constraint: JoinConstraint) -> Result<ExprImpl> {
+    /// Binds a join of `left` and `right`, whose columns are in `[left_begin, right_begin)` and
+    /// `[right_begin, ..)` of the context respectively.
+    fn bind_join(
+        &mut self,
+        left: Relation,
+        right: Relation,
+        join_type: JoinType,
+        constraint: JoinConstraint,
+        left_begin: usize,
+        right_begin: usize,
+    ) -> Result<Relation> {
+        if let Relation::Subquery(subquery) = &right {
+            if subquery.lateral && subquery.query.is_correlated() {
+                if !matches!(join_type, JoinType::Inner | JoinType::LeftOuter) {
+                    return Err(ErrorCode::InvalidInputSyntax(
+                        "the combining JOIN type must be INNER or LEFT for a LATERAL reference"
+                            .into(),
+                    )
+                    .into());
+                }
+                // The correlated columns refer to the whole context, so `left` must cover it.
+                if left_begin != 0 {
+                    return Err(ErrorCode::NotImplemented(
+                        "LATERAL subquery not joined with the leading FROM item".into(),
+                        None.into(),
+                    )
+                    .into());
+                }
+            }
+        }
+        let cond = self.bind_join_constraint(constraint, join_type, left_begin, right_begin)?;
+        Ok(Relation::Join(Box::new(BoundJoin {
+            join_type,
+            left,
+            right,
+            cond,
+        })))
+    }
+
+    fn bind_join_constraint(
+        &mut self,
+        constraint: JoinConstraint,
+        join_type: JoinType,
+        left_begin: usize,
+        right_begin: usize,
+    ) -> Result<ExprImpl> {
         Ok(match constraint {
             JoinConstraint::None => ExprImpl::literal_bool(true),
             JoinConstraint::Natural => {
-                return Err(ErrorCode::NotImplemented("Natural join".into(), 1633.into()).into())
+                // The common column names, in the order of the left side.
+                let right_names = self.context.columns[right_begin..]
+                    .iter()
+                    .filter(|c| !c.is_hidden)
+                    .map(|c| c.field.name.clone())
+                    .collect_vec();
+                let mut names = vec![];
+                for column in &self.context.columns[left_begin..right_begin] {
+                    if !column.is_hidden
+                        && right_names.contains(&column.field.name)
+                        && !names.contains(&column.field.name)
+                    {
+                        names.push(column.field.name.clone());
+                    }
+                }
+                self.bind_using(names, join_type, left_begin, right_begin)?
             }
             JoinConstraint::On(expr) => {
+                self.context.clause = Some(Clause::JoinOn);
                 let bound_expr = self.bind_expr(expr)?;
+                self.context.clause = None;
                 if bound_expr.return_type() != DataType::Boolean {
                     return Err(ErrorCode::InternalError(format!(
                         "argument of ON must be boolean, not type {:?}",
@@ -93,9 +175,112 @@ impl Binder {
                 }
                 bound_expr
             }
-            JoinConstraint::Using(_columns) => {
-                return Err(ErrorCode::NotImplemented("USING".into(), 1636.into()).into())
+            JoinConstraint::Using(columns) => {
+                let names = columns
+                    .into_iter()
+                    .map(|Ident { value, .. }| value)
+                    .collect();
+                self.bind_using(names, join_type, left_begin, right_begin)?
             }
         })
     }
+
+    /// Merges the columns named `names` of both sides into [`UsingColumn`]s, and returns the join
+    /// condition equating them.
+    fn bind_using(
+        &mut self,
+        names: Vec<String>,
+        join_type: JoinType,
+        left_begin: usize,
+        right_begin: usize,
+    ) -> Result<ExprImpl> {
+        let right_end = self.context.columns.len();
+        let mut new_using_columns = Vec::with_capacity(names.len());
+        let mut conjunctions = Vec::with_capacity(names.len());
+        for name in names {
+            if new_using_columns
+                .iter()
+                .any(|c: &UsingColumn| c.name == name)
+            {
+                return Err(ErrorCode::InvalidInputSyntax(format!(
+                    "column name \"{}\" appears more than once in USING clause",
+                    name
+                ))
+                .into());
+            }
+            let left = self.take_using_side(&name, left_begin, right_begin, "left")?;
+            let right = self.take_using_side(&name, right_begin, right_end, "right")?;
+            conjunctions.push(
+                FunctionCall::new(
+                    ExprType::Equal,
+                    vec![
+                        Self::bind_using_column(&self.context, &left, 0)?,
+                        Self::bind_using_column(&self.context, &right, 0)?,
+                    ],
+                )?
+                .into(),
+            );
+            let sources = match join_type {
+                JoinType::RightOuter => right.sources,
+                JoinType::FullOuter => left.sources.into_iter().chain(right.sources).collect(),
+                _ => left.sources,
+            };
+            new_using_columns.push(UsingColumn {
+                name,
+                sources,
+                merged: left.merged.into_iter().chain(right.merged).collect(),
+            });
+        }
+        // The columns merged by this join come before the ones merged by the inner joins.
+        new_using_columns.append(&mut self.context.using_columns);
+        self.context.using_columns = new_using_columns;
+
+        Ok(merge_expr_by_binary(
+            conjunctions.into_iter(),
+            ExprType::And,
+            ExprImpl::literal_bool(true),
+        ))
+    }
+
+    /// Finds the column `name` of one side of the join, whose columns are in `[begin, end)` of the
+    /// context. If it is already merged by an inner join, the merged column is taken out of the
+    /// context so that it can be merged again.
+    fn take_using_side(
+        &mut self,
+        name: &str,
+        begin: usize,
+        end: usize,
+        side: &str,
+    ) -> Result<UsingColumn> {
+        let in_range = |index: &usize| (begin..end).contains(index);
+        if let Some(pos) = self
+            .context
+            .using_columns
+            .iter()
+            .position(|c| c.name == name && c.merged.iter().all(in_range))
+        {
+            return Ok(self.context.using_columns.remove(pos));
+        }
+        let mut indexes = (begin..end).filter(|index| {
+            let column = &self.context.columns[*index];
+            !column.is_hidden && column.field.name == name && !self.context.is_merged(*index)
+        });
+        match (indexes.next(), indexes.next()) {
+            (Some(index), None) => Ok(UsingColumn {
+                name: name.to_string(),
+                sources: vec![index],
+                merged: vec![index],
+            }),
+            (None, _) => Err(ErrorCode::ItemNotFound(format!(
+                "column \"{}\" specified in USING clause does not exist in {} table",
+                name, side
+            ))
+            .into()),
+            (Some(_), Some(_)) => Err(ErrorCode::InvalidInputSyntax(format!(
+                "common column name \"{}\" appears more than once in {} table",
+                name, side
+            ))
+            .into()),
+        }
+    }
 }
@@ -15,22 +15,26 @@
 use std::collections::hash_map::Entry;
 use std::str::FromStr;
 
-use risingwave_common::catalog::{Field, DEFAULT_SCHEMA_NAME};
+use itertools::Itertools;
+use risingwave_common::catalog::Field;
 use risingwave_common::error::{internal_error, ErrorCode, Result};
 use risingwave_sqlparser::ast::{Ident, ObjectName, TableAlias, TableFactor};
 
 use super::bind_context::ColumnBinding;
 use crate::binder::Binder;
+use crate::expr::{TableFunction, TableFunctionType};
 
 mod generate_series;
 mod join;
+mod qualify;
 mod subquery;
+mod table_function;
 mod table_or_source;
 mod window_table_function;
 pub use generate_series::BoundGenerateSeriesFunction;
 pub use join::BoundJoin;
 pub use subquery::BoundSubquery;
-pub use table_or_source::{BoundBaseTable, BoundSource, BoundTableSource};
+pub use table_or_source::{BoundBaseTable, BoundSource, BoundSystemTable, BoundTableSource};
 pub use window_table_function::{BoundWindowTableFunction, WindowTableFunctionKind};
 
 /// A validated item that refers to a table-like entity, including base table, subquery, join, etc.
@@ -39,10 +43,12 @@ pub use window_table_function::{BoundWindowTableFunction, WindowTableFunctionKin
 pub enum Relation {
     Source(Box<BoundSource>),
     BaseTable(Box<BoundBaseTable>),
+    SystemTable(Box<BoundSystemTable>),
     Subquery(Box<BoundSubquery>),
     Join(Box<BoundJoin>),
     WindowTableFunction(Box<BoundWindowTableFunction>),
     GenerateSeriesFunction(Box<BoundGenerateSeriesFunction>),
+    TableFunction(Box<TableFunction>),
 }
 
 impl Binder {
@@ -66,9 +72,37 @@ impl Binder {
         Ok((first_name, second_name))
     }
 
-    /// return the (`schema_name`, `table_name`)
-    pub fn resolve_table_name(name: ObjectName) -> Result<(String, String)> {
-        Self::resolve_double_name(name.0, "empty table name", DEFAULT_SCHEMA_NAME)
+    /// return the (`schema_name`, `name`), where `schema_name` is `None` if the name is not
+    /// qualified.
+    pub fn resolve_schema_qualified_name(name: ObjectName) -> Result<(Option<String>, String)> {
+        let mut identifiers = name.0;
+        if identifiers.len() > 2 {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "improper qualified name (too many dotted names): {}",
+                identifiers.iter().join(".")
+            ))
+            .into());
+        }
+        let name = identifiers
+            .pop()
+            .ok_or_else(|| internal_error("empty name"))?
+            .value;
+        let schema_name = identifiers.pop().map(|ident| ident.value);
+
+        Ok((schema_name, name))
+    }
+
+    /// return the (`schema_name`, `table_name`) of an existing relation, looking it up in the
+    /// search path if the name is not qualified.
+    pub(crate) fn resolve_relation_name(&self, name: ObjectName) -> Result<(String, String)> {
+        let (schema_name, table_name) = Self::resolve_schema_qualified_name(name)?;
+        let schema_name = self.catalog.resolve_relation_schema(
+            &self.db_name,
+            schema_name,
+            &self.search_path,
+            &table_name,
+        )?;
+        Ok((schema_name, table_name))
     }
 
     /// return the ( `database_name`, `schema_name`)
@@ -93,6 +127,20 @@ impl Binder {
         Ok(database_name)
     }
 
+    /// return the `user_name`
+    pub fn resolve_user_name(name: ObjectName) -> Result<String> {
+        let mut identifiers = name.0;
+        if identifiers.len() > 1 {
+            return Err(internal_error("user name must contain 1 argument"));
+        }
+        let user_name = identifiers
+            .pop()
+            .ok_or_else(|| internal_error("empty user name"))?
+            .value;
+
+        Ok(user_name)
+    }
+
     /// Fill the [`BindContext`](super::BindContext) for table.
     pub(super) fn bind_context(
         &mut self,
@@ -157,7 +205,7 @@ impl Binder {
         match table_factor {
             TableFactor::Table { name, alias, args } => {
                 if args.is_empty() {
-                    let (schema_name, table_name) = Self::resolve_table_name(name)?;
+                    let (schema_name, table_name) = self.resolve_relation_name(name)?;
                     self.bind_table_or_source(&schema_name, &table_name, alias)
                 } else {
                     let func_name = &name.0[0].value;
@@ -166,6 +214,11 @@ impl Binder {
                             self.bind_generate_series_function(args)?,
                         )));
                     }
+                    if let Ok(function_type) = TableFunctionType::from_str(func_name) {
+                        return Ok(Relation::TableFunction(Box::new(
+                            self.bind_table_function(alias, function_type, args)?,
+                        )));
+                    }
                     let kind = WindowTableFunctionKind::from_str(func_name).map_err(|_| {
                         ErrorCode::NotImplemented(
                             format!("unknown window function kind: {}", name.0[0].value),
@@ -181,15 +234,9 @@ impl Binder {
                 lateral,
                 subquery,
                 alias,
-            } => {
-                if lateral {
-                    Err(ErrorCode::NotImplemented("unsupported lateral".into(), None.into()).into())
-                } else {
-                    Ok(Relation::Subquery(Box::new(
-                        self.bind_subquery_relation(*subquery, alias)?,
-                    )))
-                }
-            }
+            } => Ok(Relation::Subquery(Box::new(
+                self.bind_subquery_relation(*subquery, alias, lateral)?,
+            ))),
             _ => Err(ErrorCode::NotImplemented(
                 format!("unsupported table factor {:?}", table_factor),
                 None.into(),
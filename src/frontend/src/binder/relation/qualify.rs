@@ -0,0 +1,251 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+
+use risingwave_common::error::Result;
+use risingwave_sqlparser::ast::{
+    Expr, Function, FunctionArg, FunctionArgExpr, Ident, JoinConstraint, JoinOperator, ObjectName,
+    Query, Select, SelectItem, SetExpr, TableFactor, TableWithJoins,
+};
+
+use super::WindowTableFunctionKind;
+use crate::binder::Binder;
+
+impl Binder {
+    /// Qualifies the unqualified relation names in `query` with the schemas they are resolved to
+    /// in the search path, so that the query keeps referring to the same relations when bound
+    /// with another search path.
+    pub(crate) fn qualify_relation_names(&self, query: &mut Query) -> Result<()> {
+        self.qualify_query(query)
+    }
+
+    fn qualify_name(&self, name: &mut ObjectName) -> Result<()> {
+        if let [ident] = name.0.as_slice() {
+            let (schema_name, _) = self.resolve_relation_name(ObjectName(vec![ident.clone()]))?;
+            name.0.insert(0, Ident::with_quote('"', schema_name));
+        }
+        Ok(())
+    }
+
+    fn qualify_query(&self, query: &mut Query) -> Result<()> {
+        self.qualify_set_expr(&mut query.body)?;
+        for order_by in &mut query.order_by {
+            self.qualify_expr(&mut order_by.expr)?;
+        }
+        Ok(())
+    }
+
+    fn qualify_set_expr(&self, set_expr: &mut SetExpr) -> Result<()> {
+        match set_expr {
+            SetExpr::Select(select) => self.qualify_select(select),
+            SetExpr::Query(query) => self.qualify_query(query),
+            SetExpr::SetOperation { left, right, .. } => {
+                self.qualify_set_expr(left)?;
+                self.qualify_set_expr(right)
+            }
+            SetExpr::Values(values) => values
+                .0
+                .iter_mut()
+                .flatten()
+                .try_for_each(|expr| self.qualify_expr(expr)),
+            SetExpr::Insert(_) => Ok(()),
+        }
+    }
+
+    fn qualify_select(&self, select: &mut Select) -> Result<()> {
+        for item in &mut select.projection {
+            match item {
+                SelectItem::UnnamedExpr(expr)
+                | SelectItem::ExprQualifiedWildcard(expr, _)
+                | SelectItem::ExprWithAlias { expr, .. } => self.qualify_expr(expr)?,
+                SelectItem::QualifiedWildcard(_) | SelectItem::Wildcard => {}
+            }
+        }
+        for table in &mut select.from {
+            self.qualify_table_with_joins(table)?;
+        }
+        if let Some(selection) = &mut select.selection {
+            self.qualify_expr(selection)?;
+        }
+        for expr in &mut select.group_by {
+            self.qualify_expr(expr)?;
+        }
+        if let Some(having) = &mut select.having {
+            self.qualify_expr(having)?;
+        }
+        Ok(())
+    }
+
+    fn qualify_table_with_joins(&self, table: &mut TableWithJoins) -> Result<()> {
+        self.qualify_table_factor(&mut table.relation)?;
+        for join in &mut table.joins {
+            self.qualify_table_factor(&mut join.relation)?;
+            match &mut join.join_operator {
+                JoinOperator::Inner(JoinConstraint::On(expr))
+                | JoinOperator::LeftOuter(JoinConstraint::On(expr))
+                | JoinOperator::RightOuter(JoinConstraint::On(expr))
+                | JoinOperator::FullOuter(JoinConstraint::On(expr)) => self.qualify_expr(expr)?,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn qualify_table_factor(&self, table_factor: &mut TableFactor) -> Result<()> {
+        match table_factor {
+            TableFactor::Table { name, args, .. } if args.is_empty() => self.qualify_name(name),
+            TableFactor::Table { name, args, .. } => {
+                // The first argument of a window table function is the name of a relation.
+                if WindowTableFunctionKind::from_str(&name.0[0].value).is_ok()
+                    && let Some(FunctionArg::Unnamed(FunctionArgExpr::Expr(expr))) =
+                        args.first_mut()
+                    && let Expr::Identifier(ident) = &*expr
+                {
+                    let mut name = ObjectName(vec![ident.clone()]);
+                    self.qualify_name(&mut name)?;
+                    *expr = Expr::CompoundIdentifier(name.0);
+                    return Ok(());
+                }
+                args.iter_mut()
+                    .try_for_each(|arg| self.qualify_function_arg(arg))
+            }
+            TableFactor::Derived { subquery, .. } => self.qualify_query(subquery),
+            TableFactor::TableFunction { expr, .. } => self.qualify_expr(expr),
+            TableFactor::NestedJoin(table) => self.qualify_table_with_joins(table),
+        }
+    }
+
+    fn qualify_function_arg(&self, arg: &mut FunctionArg) -> Result<()> {
+        match arg {
+            FunctionArg::Named { arg, .. } | FunctionArg::Unnamed(arg) => match arg {
+                FunctionArgExpr::Expr(expr) | FunctionArgExpr::ExprQualifiedWildcard(expr, _) => {
+                    self.qualify_expr(expr)
+                }
+                FunctionArgExpr::QualifiedWildcard(_) | FunctionArgExpr::Wildcard => Ok(()),
+            },
+        }
+    }
+
+    fn qualify_function(&self, function: &mut Function) -> Result<()> {
+        for arg in &mut function.args {
+            self.qualify_function_arg(arg)?;
+        }
+        if let Some(over) = &mut function.over {
+            for expr in &mut over.partition_by {
+                self.qualify_expr(expr)?;
+            }
+            for order_by in &mut over.order_by {
+                self.qualify_expr(&mut order_by.expr)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Only subqueries inside expressions refer to relations.
+    fn qualify_expr(&self, expr: &mut Expr) -> Result<()> {
+        match expr {
+            Expr::InSubquery { expr, subquery, .. } => {
+                self.qualify_expr(expr)?;
+                self.qualify_query(subquery)
+            }
+            Expr::Exists(query) | Expr::Subquery(query) => self.qualify_query(query),
+            Expr::Function(function) => self.qualify_function(function),
+            Expr::FieldIdentifier(expr, _)
+            | Expr::IsNull(expr)
+            | Expr::IsNotNull(expr)
+            | Expr::IsTrue(expr)
+            | Expr::IsNotTrue(expr)
+            | Expr::IsFalse(expr)
+            | Expr::IsNotFalse(expr)
+            | Expr::UnaryOp { expr, .. }
+            | Expr::Cast { expr, .. }
+            | Expr::TryCast { expr, .. }
+            | Expr::Extract { expr, .. }
+            | Expr::Collate { expr, .. }
+            | Expr::Nested(expr)
+            | Expr::SomeOp(expr)
+            | Expr::AllOp(expr) => self.qualify_expr(expr),
+            Expr::IsDistinctFrom(left, right)
+            | Expr::IsNotDistinctFrom(left, right)
+            | Expr::BinaryOp { left, right, .. }
+            | Expr::AtTimeZone {
+                timestamp: left,
+                time_zone: right,
+            }
+            | Expr::ArrayIndex {
+                obj: left,
+                index: right,
+            } => {
+                self.qualify_expr(left)?;
+                self.qualify_expr(right)
+            }
+            Expr::InList { expr, list, .. } => {
+                self.qualify_expr(expr)?;
+                list.iter_mut().try_for_each(|e| self.qualify_expr(e))
+            }
+            Expr::Between {
+                expr, low, high, ..
+            } => {
+                self.qualify_expr(expr)?;
+                self.qualify_expr(low)?;
+                self.qualify_expr(high)
+            }
+            Expr::Substring {
+                expr,
+                substring_from,
+                substring_for,
+            } => {
+                self.qualify_expr(expr)?;
+                substring_from
+                    .iter_mut()
+                    .chain(substring_for)
+                    .try_for_each(|e| self.qualify_expr(e))
+            }
+            Expr::Trim { expr, trim_where } => {
+                self.qualify_expr(expr)?;
+                match trim_where {
+                    Some((_, e)) => self.qualify_expr(e),
+                    None => Ok(()),
+                }
+            }
+            Expr::Case {
+                operand,
+                conditions,
+                results,
+                else_result,
+            } => {
+                operand
+                    .iter_mut()
+                    .chain(else_result)
+                    .try_for_each(|e| self.qualify_expr(e))?;
+                conditions
+                    .iter_mut()
+                    .chain(results)
+                    .try_for_each(|e| self.qualify_expr(e))
+            }
+            Expr::Row(exprs) | Expr::Array(exprs) => {
+                exprs.iter_mut().try_for_each(|e| self.qualify_expr(e))
+            }
+            Expr::GroupingSets(sets) | Expr::Cube(sets) | Expr::Rollup(sets) => sets
+                .iter_mut()
+                .flatten()
+                .try_for_each(|e| self.qualify_expr(e)),
+            Expr::Identifier(_)
+            | Expr::CompoundIdentifier(_)
+            | Expr::Value(_)
+            | Expr::TypedString { .. } => Ok(()),
+        }
+    }
+}
@@ -20,6 +20,8 @@ use crate::binder::{Binder, BoundQuery, UNNAMED_SUBQUERY};
 #[derive(Debug)]
 pub struct BoundSubquery {
     pub query: BoundQuery,
+    /// Whether the subquery is `LATERAL`, i.e. it can refer to the FROM items before it.
+    pub lateral: bool,
 }
 
 impl Binder {
@@ -31,6 +33,7 @@ impl Binder {
         &mut self,
         query: Query,
         alias: Option<TableAlias>,
+        lateral: bool,
     ) -> Result<BoundSubquery> {
         let query = self.bind_query(query)?;
         let sub_query_id = self.next_subquery_id();
@@ -45,6 +48,6 @@ impl Binder {
             format!("{}_{}", UNNAMED_SUBQUERY, sub_query_id),
             alias,
         )?;
-        Ok(BoundSubquery { query })
+        Ok(BoundSubquery { query, lateral })
     }
 }
@@ -0,0 +1,52 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use risingwave_common::catalog::Field;
+use risingwave_sqlparser::ast::{FunctionArg, TableAlias};
+
+use super::{Binder, Result};
+use crate::expr::{TableFunction, TableFunctionType};
+
+impl Binder {
+    /// Binds a table function in the `FROM` clause, e.g. `jsonb_array_elements('[1, 2]')`. Its
+    /// output is a single column named after the function.
+    pub(super) fn bind_table_function(
+        &mut self,
+        alias: Option<TableAlias>,
+        function_type: TableFunctionType,
+        args: Vec<FunctionArg>,
+    ) -> Result<TableFunction> {
+        let args = args
+            .into_iter()
+            .map(|arg| self.bind_function_arg(arg))
+            .flatten_ok()
+            .try_collect()?;
+        let table_function = TableFunction::new(function_type, args)?;
+
+        let columns = [(
+            false,
+            Field {
+                data_type: table_function.return_type.clone(),
+                name: table_function.name().to_string(),
+                sub_fields: vec![],
+                type_name: "".to_string(),
+            },
+        )]
+        .into_iter();
+        self.bind_context(columns, table_function.name().to_string(), alias)?;
+
+        Ok(table_function)
+    }
+}
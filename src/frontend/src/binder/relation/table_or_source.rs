@@ -12,15 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use risingwave_common::catalog::ColumnDesc;
+use itertools::Itertools;
+use risingwave_common::array::Row;
+use risingwave_common::catalog::{is_dropped_column_name, ColumnDesc};
 use risingwave_common::error::{ErrorCode, Result, RwError};
-use risingwave_sqlparser::ast::{ObjectName, TableAlias};
+use risingwave_common::types::DataType;
+use risingwave_pb::batch_plan::UniqueIndex as ProstUniqueIndex;
+use risingwave_pb::catalog::VirtualTable as ProstVirtualTable;
+use risingwave_pb::plan_common::CellBasedTableDesc;
+use risingwave_pb::user::grant_privilege::{Action, Target};
+use risingwave_sqlparser::ast::{ObjectName, Statement, TableAlias};
+use risingwave_sqlparser::parser::Parser;
 
-use crate::binder::{Binder, Relation};
+use crate::binder::{Binder, BoundSubquery, Relation};
 use crate::catalog::source_catalog::SourceCatalog;
+use crate::catalog::system_catalog::{get_sys_catalog, is_system_schema, virtual_table_fields};
 use crate::catalog::table_catalog::TableCatalog;
+use crate::catalog::view_catalog::ViewCatalog;
 use crate::catalog::{CatalogError, TableId};
 
 #[derive(Debug)]
@@ -37,6 +48,32 @@ pub struct BoundTableSource {
     pub name: String,       // explain-only
     pub source_id: TableId, // TODO: refactor to source id
     pub columns: Vec<ColumnDesc>,
+    /// Positions and types of the dropped columns among all the columns written by DML, i.e.
+    /// all the columns except the row id. They are still physically stored and must be filled
+    /// with NULL on insertion.
+    pub dropped_columns: Vec<(usize, DataType)>,
+    /// Positions of the user-defined primary key among all the columns written by DML, which
+    /// can't be NULL. Empty if the table is keyed by the row id.
+    pub pk_indices: Vec<usize>,
+    /// The unique indexes on the table, which the written rows are checked against.
+    pub unique_indexes: Vec<ProstUniqueIndex>,
+}
+
+/// `BoundSystemTable` is a table in `pg_catalog` or `information_schema`, whose rows are
+/// generated from the catalog while binding.
+pub struct BoundSystemTable {
+    pub name: String, // explain-only
+    pub table: ProstVirtualTable,
+    pub rows: Vec<Row>,
+}
+
+impl std::fmt::Debug for BoundSystemTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BoundSystemTable")
+            .field("name", &self.name)
+            .field("rows", &self.rows)
+            .finish()
+    }
 }
 
 #[derive(Debug)]
@@ -57,20 +94,8 @@ impl Binder {
         table_name: &str,
         alias: Option<TableAlias>,
     ) -> Result<Relation> {
-        if schema_name == "pg_catalog" {
-            // TODO: support pg_catalog.
-            return Err(ErrorCode::NotImplemented(
-                // TODO: We can ref the document of `SHOW` commands here if ready.
-                r###"pg_catalog is not supported, please use `SHOW` commands for now.
-`SHOW TABLES`,
-`SHOW MATERIALIZED VIEWS`,
-`DESCRIBE <table>`,
-`SHOW COLUMNS FROM [table]`
-"###
-                .into(),
-                1695.into(),
-            )
-            .into());
+        if is_system_schema(schema_name) {
+            return self.bind_sys_table(schema_name, table_name, alias);
         }
 
         let (ret, columns) = {
@@ -79,9 +104,15 @@ impl Binder {
                 catalog.get_table_by_name(&self.db_name, schema_name, table_name)
             {
                 let table_id = table_catalog.id();
+                self.check_privilege(
+                    Target::TableId(table_id.table_id),
+                    Action::Select,
+                    table_name,
+                )?;
                 let table_catalog = table_catalog.clone();
                 let columns = table_catalog.columns.clone();
                 let table_indexes = self.resolve_table_indexes(schema_name, table_id)?;
+                self.included_relations.insert(table_id);
 
                 let table = BoundBaseTable {
                     name: table_name.to_string(),
@@ -93,7 +124,15 @@ impl Binder {
                 (Relation::BaseTable(Box::new(table)), columns)
             } else if let Ok(s) = catalog.get_source_by_name(&self.db_name, schema_name, table_name)
             {
+                self.check_privilege(Target::SourceId(s.id), Action::Select, table_name)?;
+                self.included_relations.insert(TableId::new(s.id));
                 (Relation::Source(Box::new(s.into())), s.columns.clone())
+            } else if let Ok(view) =
+                catalog.get_view_by_name(&self.db_name, schema_name, table_name)
+            {
+                let view = view.clone();
+                self.included_relations.insert(TableId::new(view.id));
+                return self.bind_view(&view, alias);
             } else {
                 return Err(RwError::from(CatalogError::NotFound(
                     "table or source",
@@ -112,6 +151,102 @@ impl Binder {
         Ok(ret)
     }
 
+    /// Binds a view by binding its query as a subquery. The query is bound in a fresh context, so
+    /// that it cannot refer to the outer query, and the relations inside it are not included in
+    /// the relations of the statement.
+    ///
+    /// The relation names in the query are qualified when the view is created, so the search path
+    /// of the session doesn't change what the view refers to.
+    ///
+    /// Note that there are no privileges on views: the relations inside are checked against the
+    /// privileges of the current user.
+    fn bind_view(&mut self, view: &ViewCatalog, alias: Option<TableAlias>) -> Result<Relation> {
+        if self.expanding_views.contains(&view.id) {
+            return Err(ErrorCode::BindError(format!(
+                "infinite recursion detected in rules for relation \"{}\"",
+                view.name
+            ))
+            .into());
+        }
+        let query = match Parser::parse_sql(&view.sql)
+            .map_err(|e| ErrorCode::InternalError(e.to_string()))?
+            .as_slice()
+        {
+            [Statement::Query(query)] => *query.clone(),
+            _ => {
+                return Err(ErrorCode::InternalError(format!(
+                    "invalid definition of view \"{}\": {}",
+                    view.name, view.sql
+                ))
+                .into())
+            }
+        };
+
+        let upper_contexts = std::mem::take(&mut self.upper_contexts);
+        let included_relations = std::mem::take(&mut self.included_relations);
+        self.expanding_views.push(view.id);
+        let result = self.bind_query(query);
+        self.expanding_views.pop();
+        self.included_relations = included_relations;
+        self.upper_contexts = upper_contexts;
+        let query = result?;
+
+        // The tables inside may have been altered since the view was created.
+        if query.data_types()
+            != view
+                .columns
+                .iter()
+                .map(|c| c.data_type.clone())
+                .collect_vec()
+        {
+            return Err(ErrorCode::BindError(format!(
+                "the definition of view \"{}\" no longer matches its columns",
+                view.name
+            ))
+            .into());
+        }
+
+        self.bind_context(
+            view.columns.iter().map(|c| (false, c.clone())),
+            view.name.clone(),
+            alias,
+        )?;
+        Ok(Relation::Subquery(Box::new(BoundSubquery {
+            query,
+            lateral: false,
+        })))
+    }
+
+    fn bind_sys_table(
+        &mut self,
+        schema_name: &str,
+        table_name: &str,
+        alias: Option<TableAlias>,
+    ) -> Result<Relation> {
+        let sys_table_catalog = get_sys_catalog(schema_name, table_name).ok_or_else(|| {
+            RwError::from(CatalogError::NotFound(
+                "table or source",
+                table_name.to_string(),
+            ))
+        })?;
+        let table = sys_table_catalog.to_prost();
+        let rows = (sys_table_catalog.generate_rows)(&self.catalog, &self.db_name);
+
+        self.bind_context(
+            virtual_table_fields(&table)
+                .into_iter()
+                .map(|field| (false, field)),
+            table_name.to_string(),
+            alias,
+        )?;
+
+        Ok(Relation::SystemTable(Box::new(BoundSystemTable {
+            name: table_name.to_string(),
+            table,
+            rows,
+        })))
+    }
+
     fn resolve_table_indexes(
         &mut self,
         schema_name: &str,
@@ -158,14 +293,32 @@ impl Binder {
         })
     }
 
-    pub(crate) fn bind_table_source(&mut self, name: ObjectName) -> Result<BoundTableSource> {
-        let (schema_name, source_name) = Self::resolve_table_name(name)?;
+    /// Binds the table source written by a DML statement, checking that the current user is
+    /// allowed to perform `action` on the table.
+    pub(crate) fn bind_table_source(
+        &mut self,
+        name: ObjectName,
+        action: Action,
+    ) -> Result<BoundTableSource> {
+        let (schema_name, source_name) = self.resolve_relation_name(name)?;
         let source = self
             .catalog
             .get_source_by_name(&self.db_name, &schema_name, &source_name)?;
 
         let source_id = TableId::new(source.id);
 
+        // Privileges on a table are granted on its table catalog rather than its source.
+        let table_id = self
+            .catalog
+            .get_table_by_name(&self.db_name, &schema_name, &source_name)
+            .ok()
+            .map(|table| table.id());
+        let target = match table_id {
+            Some(table_id) => Target::TableId(table_id.table_id),
+            None => Target::SourceId(source.id),
+        };
+        self.check_privilege(target, action, &source_name)?;
+
         let columns = source
             .columns
             .iter()
@@ -173,12 +326,65 @@ impl Binder {
             .map(|c| c.column_desc.clone())
             .collect();
 
+        let dml_columns = source
+            .columns
+            .iter()
+            .filter(|c| !c.is_hidden || is_dropped_column_name(c.name()))
+            .collect_vec();
+        let dropped_columns = dml_columns
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| is_dropped_column_name(c.name()))
+            .map(|(i, c)| (i, c.data_type().clone()))
+            .collect();
+        let pk_indices = dml_columns
+            .iter()
+            .positions(|c| source.pk_col_ids.contains(&c.column_id()))
+            .collect();
+
+        // The columns of an index share the column ids with its table.
+        let column_positions = source
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (c.column_id(), i as u32))
+            .collect::<HashMap<_, _>>();
+        let unique_indexes = match table_id {
+            Some(table_id) => self
+                .catalog
+                .get_schema_by_name(&self.db_name, &schema_name)?
+                .iter_mv()
+                .filter(|index| index.is_index_on == Some(table_id) && index.is_unique_index)
+                .map(|index| ProstUniqueIndex {
+                    name: index.name().to_string(),
+                    table_desc: Some(CellBasedTableDesc {
+                        table_id: index.id().table_id,
+                        pk: index
+                            .order_desc()
+                            .iter()
+                            .map(|order| order.to_protobuf())
+                            .collect(),
+                    }),
+                    pk_indices: index
+                        .order_desc()
+                        .iter()
+                        .map(|order| column_positions[&order.column_desc.column_id])
+                        .collect(),
+                    key_len: index.index_key_count as u32,
+                })
+                .collect(),
+            None => vec![],
+        };
+
         // Note(bugen): do not bind context here.
 
         Ok(BoundTableSource {
             name: source_name,
             source_id,
             columns,
+            dropped_columns,
+            pk_indices,
+            unique_indexes,
         })
     }
 }
@@ -18,6 +18,7 @@ use itertools::Itertools;
 use risingwave_common::catalog::Field;
 use risingwave_common::error::{ErrorCode, RwError};
 use risingwave_common::types::DataType;
+use risingwave_pb::user::grant_privilege::{Action, Target};
 use risingwave_sqlparser::ast::{Expr, FunctionArg, FunctionArgExpr, ObjectName, TableAlias};
 
 use super::{Binder, Relation, Result};
@@ -76,7 +77,7 @@ impl Binder {
             )
             .into()),
         }?;
-        let (schema_name, table_name) = Self::resolve_table_name(table_name)?;
+        let (schema_name, table_name) = self.resolve_relation_name(table_name)?;
 
         let base = self.bind_table_or_source(&schema_name, &table_name, None)?;
 
@@ -98,6 +99,11 @@ impl Binder {
         let table_catalog =
             self.catalog
                 .get_table_by_name(&self.db_name, &schema_name, &table_name)?;
+        self.check_privilege(
+            Target::TableId(table_catalog.id().table_id),
+            Action::Select,
+            &table_name,
+        )?;
 
         let columns = table_catalog.columns().to_vec();
         if columns.iter().any(|col| {
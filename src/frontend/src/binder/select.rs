@@ -26,6 +26,11 @@ use crate::binder::{Binder, Relation};
 use crate::catalog::check_valid_column_name;
 use crate::expr::{Expr as _, ExprImpl, InputRef};
 
+/// Same limit as Postgres.
+const MAX_GROUPING_SETS: usize = 4096;
+/// Same limit as Postgres.
+const MAX_CUBE_ELEMENTS: usize = 12;
+
 #[derive(Debug)]
 pub struct BoundSelect {
     pub distinct: bool,
@@ -34,6 +39,9 @@ pub struct BoundSelect {
     pub from: Option<Relation>,
     pub where_clause: Option<ExprImpl>,
     pub group_by: Vec<ExprImpl>,
+    /// Each grouping set is a list of indices into `group_by`. It is empty when the GROUP BY
+    /// clause contains no `GROUPING SETS`, `CUBE` or `ROLLUP`.
+    pub grouping_sets: Vec<Vec<usize>>,
     pub having: Option<ExprImpl>,
     schema: Schema,
 }
@@ -49,6 +57,7 @@ impl BoundSelect {
             .iter()
             .chain(self.group_by.iter())
             .chain(self.where_clause.iter())
+            .chain(self.having.iter())
             .any(|expr| expr.has_correlated_input_ref())
     }
 }
@@ -69,14 +78,14 @@ impl Binder {
         Self::require_bool_clause(&selection, "WHERE")?;
 
         // Bind GROUP BY clause.
-        let group_by = select
-            .group_by
-            .into_iter()
-            .map(|expr| self.bind_expr(expr))
-            .try_collect()?;
+        self.context.clause = Some(Clause::GroupBy);
+        let (group_by, grouping_sets) = self.bind_group_by(select.group_by)?;
+        self.context.clause = None;
 
         // Bind HAVING clause.
+        self.context.clause = Some(Clause::Having);
         let having = select.having.map(|expr| self.bind_expr(expr)).transpose()?;
+        self.context.clause = None;
         Self::require_bool_clause(&having, "HAVING")?;
 
         // Bind SELECT clause.
@@ -99,11 +108,121 @@ impl Binder {
             from,
             where_clause: selection,
             group_by,
+            grouping_sets,
             having,
             schema: Schema { fields },
         })
     }
 
+    /// Binds the GROUP BY clause. Returns the bound group exprs and the grouping sets over them.
+    ///
+    /// Without `GROUPING SETS`, `CUBE` or `ROLLUP`, the grouping sets are empty and the group
+    /// exprs are returned as they are. Otherwise the group exprs are deduplicated, and the grouping
+    /// sets are the cross product of the sets generated by each item in the clause, e.g.
+    /// `GROUP BY a, ROLLUP(b, c)` is `GROUPING SETS ((a, b, c), (a, b), (a))`.
+    fn bind_group_by(&mut self, group_by: Vec<Expr>) -> Result<(Vec<ExprImpl>, Vec<Vec<usize>>)> {
+        let has_grouping_sets = group_by.iter().any(|expr| {
+            matches!(
+                expr,
+                Expr::GroupingSets(_) | Expr::Cube(_) | Expr::Rollup(_)
+            )
+        });
+        if !has_grouping_sets {
+            let group_by = group_by
+                .into_iter()
+                .map(|expr| self.bind_expr(expr))
+                .try_collect()?;
+            return Ok((group_by, vec![]));
+        }
+
+        let mut group_exprs = vec![];
+        let mut grouping_sets: Vec<Vec<usize>> = vec![vec![]];
+        for expr in group_by {
+            let item_sets: Vec<Vec<usize>> = match expr {
+                Expr::GroupingSets(sets) => sets
+                    .into_iter()
+                    .map(|set| self.bind_grouping_set(set, &mut group_exprs))
+                    .try_collect()?,
+                Expr::Cube(elems) => {
+                    if elems.len() > MAX_CUBE_ELEMENTS {
+                        return Err(ErrorCode::InvalidInputSyntax(format!(
+                            "CUBE is limited to {} elements",
+                            MAX_CUBE_ELEMENTS
+                        ))
+                        .into());
+                    }
+                    let elems: Vec<Vec<usize>> = elems
+                        .into_iter()
+                        .map(|elem| self.bind_grouping_set(elem, &mut group_exprs))
+                        .try_collect()?;
+                    // All subsets of the elements, from the largest to the smallest.
+                    (0..=elems.len())
+                        .rev()
+                        .flat_map(|k| {
+                            elems.iter().combinations(k).map(|subset| {
+                                subset.into_iter().flatten().copied().unique().collect_vec()
+                            })
+                        })
+                        .collect_vec()
+                }
+                Expr::Rollup(elems) => {
+                    let elems: Vec<Vec<usize>> = elems
+                        .into_iter()
+                        .map(|elem| self.bind_grouping_set(elem, &mut group_exprs))
+                        .try_collect()?;
+                    // All prefixes of the elements, from the longest to the empty one.
+                    (0..=elems.len())
+                        .rev()
+                        .map(|k| elems[..k].iter().flatten().copied().unique().collect_vec())
+                        .collect_vec()
+                }
+                expr => vec![self.bind_grouping_set(vec![expr], &mut group_exprs)?],
+            };
+            grouping_sets = grouping_sets
+                .into_iter()
+                .cartesian_product(item_sets.iter())
+                .map(|(mut set, item_set)| {
+                    for idx in item_set {
+                        if !set.contains(idx) {
+                            set.push(*idx);
+                        }
+                    }
+                    set
+                })
+                .collect_vec();
+            if grouping_sets.len() > MAX_GROUPING_SETS {
+                return Err(ErrorCode::InvalidInputSyntax(format!(
+                    "too many grouping sets present (maximum {})",
+                    MAX_GROUPING_SETS
+                ))
+                .into());
+            }
+        }
+        Ok((group_exprs, grouping_sets))
+    }
+
+    /// Binds the exprs of a grouping set, and returns their indices in `group_exprs`. Exprs that
+    /// are not yet in `group_exprs` are appended to it.
+    fn bind_grouping_set(
+        &mut self,
+        set: Vec<Expr>,
+        group_exprs: &mut Vec<ExprImpl>,
+    ) -> Result<Vec<usize>> {
+        set.into_iter()
+            .map(|expr| {
+                let expr = self.bind_expr(expr)?;
+                let idx = match group_exprs.iter().position(|e| *e == expr) {
+                    Some(idx) => idx,
+                    None => {
+                        group_exprs.push(expr);
+                        group_exprs.len() - 1
+                    }
+                };
+                Ok(idx)
+            })
+            .try_collect()
+    }
+
     pub fn bind_project(
         &mut self,
         select_items: Vec<SelectItem>,
@@ -153,8 +272,15 @@ impl Binder {
                     aliases.extend(names);
                 }
                 SelectItem::Wildcard => {
+                    // Columns merged by `USING` or `NATURAL` joins come first and only once.
+                    for using_column in &self.context.using_columns {
+                        select_list.push(Self::bind_using_column(&self.context, using_column, 0)?);
+                        aliases.push(Some(using_column.name.clone()));
+                    }
                     let (exprs, names) = Self::iter_bound_columns(
-                        self.context.columns[..].iter().filter(|c| !c.is_hidden),
+                        self.context.columns[..]
+                            .iter()
+                            .filter(|c| !c.is_hidden && !self.context.is_merged(c.index)),
                     );
                     select_list.extend(exprs);
                     aliases.extend(names);
@@ -19,10 +19,11 @@ use std::collections::HashMap;
 use itertools::Itertools;
 use risingwave_common::ensure;
 use risingwave_common::error::{ErrorCode, Result};
+use risingwave_pb::user::grant_privilege::Action;
 use risingwave_sqlparser::ast::{Assignment, Expr, TableFactor, TableWithJoins};
 
 use super::{Binder, BoundTableSource, Relation};
-use crate::expr::{Expr as _, ExprImpl};
+use crate::expr::{Expr as _, ExprImpl, ExprRewriter};
 
 #[derive(Debug)]
 pub struct BoundUpdate {
@@ -52,7 +53,7 @@ impl Binder {
                 TableFactor::Table { name, .. } => name.clone(),
                 _ => unreachable!(),
             };
-            self.bind_table_source(name)?
+            self.bind_table_source(name, Action::Update)?
         };
 
         let table = self.bind_vec_table_with_joins(vec![table])?.unwrap();
@@ -93,6 +94,7 @@ impl Binder {
             for (id, value) in assignments {
                 let id_expr = self.bind_expr(Expr::Identifier(id.clone()))?;
                 let value_expr = self.bind_expr(value)?.cast_assign(id_expr.return_type())?;
+                let value_expr = self.session_timezone.rewrite_expr(value_expr);
 
                 match assignment_exprs.entry(id_expr) {
                     Entry::Occupied(_) => {
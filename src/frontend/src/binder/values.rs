@@ -21,7 +21,7 @@ use risingwave_sqlparser::ast::{Expr, Values};
 
 use super::bind_context::Clause;
 use crate::binder::Binder;
-use crate::expr::{align_types, Expr as _, ExprImpl, Literal};
+use crate::expr::{align_types, Expr as _, ExprImpl, ExprRewriter, Literal};
 
 #[derive(Debug)]
 pub struct BoundValues {
@@ -65,14 +65,25 @@ impl Binder {
             Some(types) => {
                 bound = bound
                     .into_iter()
-                    .map(|vec| Self::cast_on_insert(types.clone(), vec))
+                    .map(|vec| self.cast_on_insert(types.clone(), vec))
                     .try_collect()?;
 
                 types
             }
-            None => (0..num_columns)
-                .map(|col_index| align_types(bound.iter_mut().map(|row| &mut row[col_index])))
-                .try_collect()?,
+            None => {
+                let types: Vec<_> = (0..num_columns)
+                    .map(|col_index| align_types(bound.iter_mut().map(|row| &mut row[col_index])))
+                    .try_collect()?;
+                bound = bound
+                    .into_iter()
+                    .map(|row| {
+                        row.into_iter()
+                            .map(|expr| self.session_timezone.rewrite_expr(expr))
+                            .collect()
+                    })
+                    .collect();
+                types
+            }
         };
 
         let schema = Schema::new(types.into_iter().map(Field::unnamed).collect());
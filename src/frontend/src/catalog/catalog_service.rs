@@ -21,13 +21,16 @@ use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::{Result, RwError};
 use risingwave_pb::catalog::{
     Database as ProstDatabase, Schema as ProstSchema, Source as ProstSource, Table as ProstTable,
+    View as ProstView,
 };
+use risingwave_pb::ddl_service::alter_relation_request::Operation as AlterRelationOperation;
 use risingwave_pb::stream_plan::StreamFragmentGraph;
+use risingwave_pb::user::{GrantPrivilege as ProstGrantPrivilege, UserInfo as ProstUserInfo};
 use risingwave_rpc_client::MetaClient;
 use tokio::sync::watch::Receiver;
 
 use super::root_catalog::Catalog;
-use super::DatabaseId;
+use super::{DatabaseId, UserId};
 
 pub type CatalogReadGuard = ArcRwLockReadGuard<RawRwLock, Catalog>;
 
@@ -68,15 +71,53 @@ pub trait CatalogWriter: Send + Sync {
 
     async fn create_source(&self, source: ProstSource) -> Result<()>;
 
-    async fn drop_materialized_source(&self, source_id: u32, table_id: TableId) -> Result<()>;
+    /// Creates a view, or replaces the existing one with the same name if `or_replace` is true.
+    async fn create_view(&self, view: ProstView, or_replace: bool) -> Result<()>;
 
-    async fn drop_materialized_view(&self, table_id: TableId) -> Result<()>;
+    /// Drops a table. If `cascade` is true, the materialized views depending on it are dropped as
+    /// well, otherwise the drop fails if there are any.
+    async fn drop_materialized_source(
+        &self,
+        source_id: u32,
+        table_id: TableId,
+        cascade: bool,
+    ) -> Result<()>;
+
+    async fn drop_materialized_view(&self, table_id: TableId, cascade: bool) -> Result<()>;
+
+    async fn drop_source(&self, source_id: u32, cascade: bool) -> Result<()>;
 
-    async fn drop_source(&self, source_id: u32) -> Result<()>;
+    async fn drop_view(&self, view_id: u32, cascade: bool) -> Result<()>;
 
     async fn drop_database(&self, database_id: u32) -> Result<()>;
 
     async fn drop_schema(&self, schema_id: u32) -> Result<()>;
+
+    /// Alters the columns or the name of a table or materialized view.
+    async fn alter_relation(
+        &self,
+        table_id: TableId,
+        operation: AlterRelationOperation,
+    ) -> Result<()>;
+
+    async fn create_user(&self, user: ProstUserInfo) -> Result<()>;
+
+    async fn drop_user(&self, user_id: UserId) -> Result<()>;
+
+    /// Updates the name and attributes of a user. Privileges are granted or revoked separately.
+    async fn update_user(&self, user: ProstUserInfo) -> Result<()>;
+
+    async fn grant_privilege(
+        &self,
+        user_ids: Vec<UserId>,
+        privileges: Vec<ProstGrantPrivilege>,
+    ) -> Result<()>;
+
+    async fn revoke_privilege(
+        &self,
+        user_ids: Vec<UserId>,
+        privileges: Vec<ProstGrantPrivilege>,
+    ) -> Result<()>;
 }
 
 #[derive(Clone)]
@@ -141,21 +182,39 @@ impl CatalogWriter for CatalogWriterImpl {
         self.wait_version(version).await
     }
 
-    async fn drop_materialized_source(&self, source_id: u32, table_id: TableId) -> Result<()> {
+    async fn create_view(&self, view: ProstView, or_replace: bool) -> Result<()> {
+        let (_id, version) = self.meta_client.create_view(view, or_replace).await?;
+        self.wait_version(version).await
+    }
+
+    async fn drop_materialized_source(
+        &self,
+        source_id: u32,
+        table_id: TableId,
+        cascade: bool,
+    ) -> Result<()> {
+        let version = self
+            .meta_client
+            .drop_materialized_source(source_id, table_id, cascade)
+            .await?;
+        self.wait_version(version).await
+    }
+
+    async fn drop_materialized_view(&self, table_id: TableId, cascade: bool) -> Result<()> {
         let version = self
             .meta_client
-            .drop_materialized_source(source_id, table_id)
+            .drop_materialized_view(table_id, cascade)
             .await?;
         self.wait_version(version).await
     }
 
-    async fn drop_materialized_view(&self, table_id: TableId) -> Result<()> {
-        let version = self.meta_client.drop_materialized_view(table_id).await?;
+    async fn drop_source(&self, source_id: u32, cascade: bool) -> Result<()> {
+        let version = self.meta_client.drop_source(source_id, cascade).await?;
         self.wait_version(version).await
     }
 
-    async fn drop_source(&self, source_id: u32) -> Result<()> {
-        let version = self.meta_client.drop_source(source_id).await?;
+    async fn drop_view(&self, view_id: u32, cascade: bool) -> Result<()> {
+        let version = self.meta_client.drop_view(view_id, cascade).await?;
         self.wait_version(version).await
     }
 
@@ -168,6 +227,54 @@ impl CatalogWriter for CatalogWriterImpl {
         let version = self.meta_client.drop_database(database_id).await?;
         self.wait_version(version).await
     }
+
+    async fn alter_relation(
+        &self,
+        table_id: TableId,
+        operation: AlterRelationOperation,
+    ) -> Result<()> {
+        let version = self.meta_client.alter_relation(table_id, operation).await?;
+        self.wait_version(version).await
+    }
+
+    async fn create_user(&self, user: ProstUserInfo) -> Result<()> {
+        let (_, version) = self.meta_client.create_user(user).await?;
+        self.wait_version(version).await
+    }
+
+    async fn drop_user(&self, user_id: UserId) -> Result<()> {
+        let version = self.meta_client.drop_user(user_id).await?;
+        self.wait_version(version).await
+    }
+
+    async fn update_user(&self, user: ProstUserInfo) -> Result<()> {
+        let version = self.meta_client.update_user(user).await?;
+        self.wait_version(version).await
+    }
+
+    async fn grant_privilege(
+        &self,
+        user_ids: Vec<UserId>,
+        privileges: Vec<ProstGrantPrivilege>,
+    ) -> Result<()> {
+        let version = self
+            .meta_client
+            .grant_privilege(user_ids, privileges)
+            .await?;
+        self.wait_version(version).await
+    }
+
+    async fn revoke_privilege(
+        &self,
+        user_ids: Vec<UserId>,
+        privileges: Vec<ProstGrantPrivilege>,
+    ) -> Result<()> {
+        let version = self
+            .meta_client
+            .revoke_privilege(user_ids, privileges)
+            .await?;
+        self.wait_version(version).await
+    }
 }
 
 impl CatalogWriterImpl {
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use risingwave_common::catalog::ColumnDesc;
+use risingwave_common::catalog::{is_dropped_column_name, ColumnDesc};
 use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_common::types::DataType;
 use thiserror::Error;
@@ -22,13 +22,19 @@ pub(crate) mod column_catalog;
 pub(crate) mod database_catalog;
 pub(crate) mod root_catalog;
 pub(crate) mod schema_catalog;
+pub(crate) mod search_path;
 pub(crate) mod source_catalog;
+pub(crate) mod system_catalog;
 pub(crate) mod table_catalog;
+pub(crate) mod user_catalog;
+pub(crate) mod view_catalog;
 
 pub(crate) type SourceId = u32;
+pub(crate) type ViewId = u32;
 
 pub(crate) type DatabaseId = u32;
 pub(crate) type SchemaId = u32;
+pub(crate) type UserId = u32;
 pub(crate) type TableId = risingwave_common::catalog::TableId;
 pub(crate) type ColumnId = risingwave_common::catalog::ColumnId;
 
@@ -40,6 +46,12 @@ pub fn check_valid_column_name(column_name: &str) -> Result<()> {
             ROWID_PREFIX
         ))
         .into())
+    } else if is_dropped_column_name(column_name) {
+        Err(ErrorCode::InternalError(format!(
+            "column name {:?} is reserved for dropped columns.",
+            column_name
+        ))
+        .into())
     } else {
         Ok(())
     }
@@ -65,6 +77,7 @@ pub fn row_id_column_desc() -> ColumnDesc {
         name: gen_row_id_column_name(0),
         field_descs: vec![],
         type_name: "".to_string(),
+        default_value: None,
     }
 }
 
@@ -76,6 +89,8 @@ pub enum CatalogError {
     Duplicated(&'static str, String),
     #[error("cannot drop {0} {1} because {2} {3} depend on it")]
     NotEmpty(&'static str, String, &'static str, String),
+    #[error("no schema has been selected to create in")]
+    NoSchemaSelected,
 }
 
 impl From<CatalogError> for RwError {
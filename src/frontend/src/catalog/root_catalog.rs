@@ -19,14 +19,20 @@ use risingwave_common::catalog::{CatalogVersion, TableId};
 use risingwave_common::error::Result;
 use risingwave_pb::catalog::{
     Database as ProstDatabase, Schema as ProstSchema, Source as ProstSource, Table as ProstTable,
+    View as ProstView,
 };
+use risingwave_pb::user::UserInfo as ProstUserInfo;
 
+use super::search_path::SearchPath;
 use super::source_catalog::SourceCatalog;
-use super::{CatalogError, SourceId};
+use super::system_catalog::{get_sys_catalog, is_system_schema};
+use super::view_catalog::ViewCatalog;
+use super::{CatalogError, SourceId, ViewId};
 use crate::catalog::database_catalog::DatabaseCatalog;
 use crate::catalog::schema_catalog::SchemaCatalog;
 use crate::catalog::table_catalog::TableCatalog;
-use crate::catalog::{DatabaseId, SchemaId};
+use crate::catalog::user_catalog::UserCatalog;
+use crate::catalog::{DatabaseId, SchemaId, UserId};
 
 /// Root catalog of database catalog. Manage all database/schema/table in memory on frontend. it
 /// is protected by a `RwLock`. only [`crate::observer::observer_manager::ObserverManager`] will get
@@ -38,10 +44,13 @@ use crate::catalog::{DatabaseId, SchemaId};
 ///     - schema catalog
 ///       - table catalog
 ///        - column catalog
+/// - user catalog
 pub struct Catalog {
     version: CatalogVersion,
     database_by_name: HashMap<String, DatabaseCatalog>,
     db_name_by_id: HashMap<DatabaseId, String>,
+    user_by_name: HashMap<String, UserCatalog>,
+    user_name_by_id: HashMap<UserId, String>,
 }
 
 #[allow(clippy::derivable_impls)]
@@ -51,6 +60,8 @@ impl Default for Catalog {
             version: 0,
             database_by_name: HashMap::new(),
             db_name_by_id: HashMap::new(),
+            user_by_name: HashMap::new(),
+            user_name_by_id: HashMap::new(),
         }
     }
 }
@@ -64,6 +75,8 @@ impl Catalog {
     pub fn clear(&mut self) {
         self.database_by_name.clear();
         self.db_name_by_id.clear();
+        self.user_by_name.clear();
+        self.user_name_by_id.clear();
     }
 
     pub fn create_database(&mut self, db: ProstDatabase) {
@@ -98,6 +111,48 @@ impl Catalog {
             .create_source(proto);
     }
 
+    pub fn create_view(&mut self, proto: &ProstView) {
+        self.get_database_mut(proto.database_id)
+            .unwrap()
+            .get_schema_mut(proto.schema_id)
+            .unwrap()
+            .create_view(proto);
+    }
+
+    /// Replaces the view with the same id, whose query has been replaced.
+    pub fn update_view(&mut self, proto: &ProstView) {
+        self.drop_view(proto.database_id, proto.schema_id, proto.id);
+        self.create_view(proto);
+    }
+
+    /// Replaces the table with the same id, whose name or columns may have been changed.
+    pub fn update_table(&mut self, proto: &ProstTable) {
+        self.drop_table(proto.database_id, proto.schema_id, proto.id.into());
+        self.create_table(proto);
+    }
+
+    /// Replaces the source with the same id, whose name or columns may have been changed.
+    pub fn update_source(&mut self, proto: ProstSource) {
+        self.drop_source(proto.database_id, proto.schema_id, proto.id);
+        self.create_source(proto);
+    }
+
+    pub fn create_user(&mut self, proto: ProstUserInfo) {
+        let name = proto.name.clone();
+        let id = proto.id;
+
+        self.user_by_name
+            .try_insert(name.clone(), (&proto).into())
+            .unwrap();
+        self.user_name_by_id.try_insert(id, name).unwrap();
+    }
+
+    /// Replaces the user with the same id, whose name may have been changed.
+    pub fn update_user(&mut self, proto: ProstUserInfo) {
+        self.drop_user(proto.id);
+        self.create_user(proto);
+    }
+
     pub fn drop_database(&mut self, db_id: DatabaseId) {
         let name = self.db_name_by_id.remove(&db_id).unwrap();
         let _database = self.database_by_name.remove(&name).unwrap();
@@ -123,6 +178,34 @@ impl Catalog {
             .drop_source(source_id);
     }
 
+    pub fn drop_view(&mut self, db_id: DatabaseId, schema_id: SchemaId, view_id: ViewId) {
+        self.get_database_mut(db_id)
+            .unwrap()
+            .get_schema_mut(schema_id)
+            .unwrap()
+            .drop_view(view_id);
+    }
+
+    pub fn drop_user(&mut self, user_id: UserId) {
+        let name = self.user_name_by_id.remove(&user_id).unwrap();
+        self.user_by_name.remove(&name).unwrap();
+    }
+
+    pub fn get_user_by_name(&self, user_name: &str) -> Result<&UserCatalog> {
+        self.user_by_name
+            .get(user_name)
+            .ok_or_else(|| CatalogError::NotFound("user", user_name.to_string()).into())
+    }
+
+    pub fn get_user_by_id(&self, user_id: UserId) -> Option<&UserCatalog> {
+        let name = self.user_name_by_id.get(&user_id)?;
+        self.user_by_name.get(name)
+    }
+
+    pub fn iter_users(&self) -> impl Iterator<Item = &UserCatalog> {
+        self.user_by_name.values()
+    }
+
     pub fn get_database_by_name(&self, db_name: &str) -> Result<&DatabaseCatalog> {
         self.database_by_name
             .get(db_name)
@@ -165,7 +248,93 @@ impl Catalog {
             .ok_or_else(|| CatalogError::NotFound("source", source_name.to_string()).into())
     }
 
-    /// Check the name if duplicated with existing table, materialized view or source.
+    pub fn get_view_by_name(
+        &self,
+        db_name: &str,
+        schema_name: &str,
+        view_name: &str,
+    ) -> Result<&ViewCatalog> {
+        self.get_schema_by_name(db_name, schema_name)?
+            .get_view_by_name(view_name)
+            .ok_or_else(|| CatalogError::NotFound("view", view_name.to_string()).into())
+    }
+
+    /// Returns the schema where new objects with unqualified names are created, i.e. the first
+    /// schema in `search_path` that exists.
+    pub fn first_valid_schema(
+        &self,
+        db_name: &str,
+        search_path: &SearchPath,
+    ) -> Result<&SchemaCatalog> {
+        let db = self.get_database_by_name(db_name)?;
+        search_path
+            .path()
+            .iter()
+            .find_map(|schema_name| db.get_schema_by_name(schema_name))
+            .ok_or_else(|| CatalogError::NoSchemaSelected.into())
+    }
+
+    /// Returns the name of the first schema in `search_path` which has a table, materialized
+    /// view, source, view or system table named `relation_name`.
+    pub fn find_relation_schema<'a>(
+        &self,
+        db_name: &str,
+        search_path: &'a SearchPath,
+        relation_name: &str,
+    ) -> Option<&'a str> {
+        let db = self.get_database_by_name(db_name).ok()?;
+        search_path.lookup_path().find(|schema_name| {
+            if is_system_schema(schema_name) {
+                return get_sys_catalog(schema_name, relation_name).is_some();
+            }
+            db.get_schema_by_name(schema_name).map_or(false, |schema| {
+                schema.get_table_by_name(relation_name).is_some()
+                    || schema.get_source_by_name(relation_name).is_some()
+                    || schema.get_view_by_name(relation_name).is_some()
+            })
+        })
+    }
+
+    /// Returns the schema of the existing relation `relation_name`. If the name is not qualified
+    /// with `schema_name`, the schemas in `search_path` are searched in order. If the relation is
+    /// not found, the first valid schema is returned so that the caller reports it as not found.
+    pub fn resolve_relation_schema(
+        &self,
+        db_name: &str,
+        schema_name: Option<String>,
+        search_path: &SearchPath,
+        relation_name: &str,
+    ) -> Result<String> {
+        if let Some(schema_name) = schema_name {
+            return Ok(schema_name);
+        }
+        if let Some(schema_name) = self.find_relation_schema(db_name, search_path, relation_name) {
+            return Ok(schema_name.to_string());
+        }
+        match self.first_valid_schema(db_name, search_path) {
+            Ok(schema) => Ok(schema.name().to_string()),
+            Err(_) => Err(CatalogError::NotFound("relation", relation_name.to_string()).into()),
+        }
+    }
+
+    /// Returns the schema to create a new object in, which is `schema_name` if the name of the
+    /// object is qualified, or the first valid schema in `search_path` otherwise.
+    pub fn resolve_schema_for_create(
+        &self,
+        db_name: &str,
+        schema_name: Option<String>,
+        search_path: &SearchPath,
+    ) -> Result<String> {
+        match schema_name {
+            Some(schema_name) => Ok(schema_name),
+            None => Ok(self
+                .first_valid_schema(db_name, search_path)?
+                .name()
+                .to_string()),
+        }
+    }
+
+    /// Check the name if duplicated with existing table, materialized view, source or view.
     pub fn check_relation_name_duplicated(
         &self,
         db_name: &str,
@@ -188,6 +357,8 @@ impl Catalog {
             }
         } else if let Some(_table) = schema.get_table_by_name(relation_name) {
             Err(CatalogError::Duplicated("materialized view", relation_name.to_string()).into())
+        } else if let Some(_view) = schema.get_view_by_name(relation_name) {
+            Err(CatalogError::Duplicated("view", relation_name.to_string()).into())
         } else {
             Ok((db.id(), schema.id()))
         }
@@ -15,10 +15,14 @@
 use std::collections::HashMap;
 
 use risingwave_common::catalog::TableId;
-use risingwave_pb::catalog::{Schema as ProstSchema, Source as ProstSource, Table as ProstTable};
+use risingwave_pb::catalog::{
+    Schema as ProstSchema, Source as ProstSource, Table as ProstTable, View as ProstView,
+};
 use risingwave_pb::stream_plan::source_node::SourceType;
 
 use super::source_catalog::SourceCatalog;
+use super::view_catalog::ViewCatalog;
+use super::ViewId;
 use crate::catalog::table_catalog::TableCatalog;
 use crate::catalog::SchemaId;
 
@@ -27,12 +31,13 @@ pub type SourceId = u32;
 #[derive(Clone, Debug)]
 pub struct SchemaCatalog {
     id: SchemaId,
-    #[allow(dead_code)]
     name: String,
     table_by_name: HashMap<String, TableCatalog>,
     table_name_by_id: HashMap<TableId, String>,
     source_by_name: HashMap<String, SourceCatalog>,
     source_name_by_id: HashMap<SourceId, String>,
+    view_by_name: HashMap<String, ViewCatalog>,
+    view_name_by_id: HashMap<ViewId, String>,
 }
 
 impl SchemaCatalog {
@@ -65,14 +70,33 @@ impl SchemaCatalog {
         self.source_by_name.remove(&name).unwrap();
     }
 
+    pub fn create_view(&mut self, prost: &ProstView) {
+        let name = prost.name.clone();
+        let id = prost.id;
+
+        self.view_by_name
+            .try_insert(name.clone(), ViewCatalog::from(prost))
+            .unwrap();
+        self.view_name_by_id.try_insert(id, name).unwrap();
+    }
+
+    pub fn drop_view(&mut self, id: ViewId) {
+        let name = self.view_name_by_id.remove(&id).unwrap();
+        self.view_by_name.remove(&name).unwrap();
+    }
+
     pub fn iter_table(&self) -> impl Iterator<Item = &TableCatalog> {
         self.table_by_name
             .iter()
             .filter(|(_, v)| {
                 // Internally, a table with an associated source can be
                 // MATERIALIZED SOURCE or TABLE.
-                v.associated_source_id.is_some()
-                    && self.get_source_by_name(v.name()).unwrap().source_type == SourceType::Table
+                // The source is looked up by id, since a renamed table and its source are updated
+                // by separate notifications.
+                v.associated_source_id.map_or(false, |source_id| {
+                    self.get_source_by_id(source_id.table_id)
+                        .map_or(false, |source| source.source_type == SourceType::Table)
+                })
             })
             .map(|(_, v)| v)
     }
@@ -84,6 +108,10 @@ impl SchemaCatalog {
             .map(|(_, v)| v)
     }
 
+    pub fn iter_view(&self) -> impl Iterator<Item = &ViewCatalog> {
+        self.view_by_name.values()
+    }
+
     /// Iterate all sources, including the materialized sources.
     pub fn iter_source(&self) -> impl Iterator<Item = &SourceCatalog> {
         self.source_by_name
@@ -111,9 +139,29 @@ impl SchemaCatalog {
         self.source_by_name.get(source_name)
     }
 
+    pub fn get_view_by_name(&self, view_name: &str) -> Option<&ViewCatalog> {
+        self.view_by_name.get(view_name)
+    }
+
+    pub fn get_table_by_id(&self, table_id: TableId) -> Option<&TableCatalog> {
+        self.table_name_by_id
+            .get(&table_id)
+            .and_then(|name| self.table_by_name.get(name))
+    }
+
+    pub fn get_source_by_id(&self, source_id: SourceId) -> Option<&SourceCatalog> {
+        self.source_name_by_id
+            .get(&source_id)
+            .and_then(|name| self.source_by_name.get(name))
+    }
+
     pub fn id(&self) -> SchemaId {
         self.id
     }
+
+    pub fn name(&self) -> &str {
+        self.name.as_ref()
+    }
 }
 
 impl From<&ProstSchema> for SchemaCatalog {
@@ -125,6 +173,8 @@ impl From<&ProstSchema> for SchemaCatalog {
             table_name_by_id: HashMap::new(),
             source_by_name: HashMap::new(),
             source_name_by_id: HashMap::new(),
+            view_by_name: HashMap::new(),
+            view_name_by_id: HashMap::new(),
         }
     }
 }
@@ -0,0 +1,91 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::catalog::DEFAULT_SUPER_USER;
+use risingwave_common::session_config::{ConfigMap, USER_NAME_WILD_CARD};
+
+use super::system_catalog::PG_CATALOG_SCHEMA_NAME;
+
+/// The schemas to resolve unqualified names in, i.e. the session variable `search_path` with
+/// `$user` replaced by the name of the current user.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchPath {
+    path: Vec<String>,
+}
+
+impl SearchPath {
+    pub fn new(search_path: &[String], user_name: &str) -> Self {
+        let path = search_path
+            .iter()
+            .map(|schema| {
+                if schema == USER_NAME_WILD_CARD {
+                    user_name.to_string()
+                } else {
+                    schema.clone()
+                }
+            })
+            .collect();
+        Self { path }
+    }
+
+    /// The schemas listed in `search_path`. Unqualified names of new objects are created in the
+    /// first one of them that exists.
+    pub fn path(&self) -> &[String] {
+        &self.path
+    }
+
+    /// The schemas to look up unqualified names of existing objects in. As in Postgres,
+    /// `pg_catalog` is searched first unless it is listed in `search_path` explicitly.
+    pub fn lookup_path(&self) -> impl Iterator<Item = &str> {
+        let implicit_pg_catalog = (!self.path.iter().any(|s| s == PG_CATALOG_SCHEMA_NAME))
+            .then_some(PG_CATALOG_SCHEMA_NAME);
+        implicit_pg_catalog
+            .into_iter()
+            .chain(self.path.iter().map(String::as_str))
+    }
+}
+
+impl Default for SearchPath {
+    fn default() -> Self {
+        Self::new(ConfigMap::default().get_search_path(), DEFAULT_SUPER_USER)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_path() {
+        let search_path = SearchPath::new(
+            &[
+                USER_NAME_WILD_CARD.to_string(),
+                "public".to_string(),
+                "s1".to_string(),
+            ],
+            "alice",
+        );
+        assert_eq!(search_path.path(), ["alice", "public", "s1"]);
+        assert_eq!(
+            search_path.lookup_path().collect::<Vec<_>>(),
+            ["pg_catalog", "alice", "public", "s1"]
+        );
+
+        let search_path = SearchPath::new(&["s1".to_string(), "pg_catalog".to_string()], "alice");
+        assert_eq!(
+            search_path.lookup_path().collect::<Vec<_>>(),
+            ["s1", "pg_catalog"]
+        );
+    }
+}
@@ -69,7 +69,15 @@ impl From<&ProstSource> for SourceCatalog {
             Some(Info::TableSource(source)) => (
                 SourceType::Table,
                 source.columns.clone(),
-                vec![TABLE_SOURCE_PK_COLID],
+                if source.pk_column_ids.is_empty() {
+                    vec![TABLE_SOURCE_PK_COLID]
+                } else {
+                    source
+                        .pk_column_ids
+                        .iter()
+                        .map(|id| ColumnId::new(*id))
+                        .collect()
+                },
             ),
             None => unreachable!(),
         };
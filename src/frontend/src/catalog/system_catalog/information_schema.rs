@@ -0,0 +1,130 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::Row;
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use super::{iter_relations, RelationKind, SystemCatalog, SYSTEM_TABLE_ID_START};
+use crate::catalog::root_catalog::Catalog;
+
+pub(super) static INFORMATION_SCHEMA_TABLES: &[&SystemCatalog] = &[&TABLES, &COLUMNS];
+
+/// The view `tables` contains all tables and views defined in the current database.
+/// Ref: [`https://www.postgresql.org/docs/current/infoschema-tables.html`]
+static TABLES: SystemCatalog = SystemCatalog {
+    id: SYSTEM_TABLE_ID_START + 32,
+    name: "tables",
+    columns: &[
+        ("table_catalog", DataType::Varchar),
+        ("table_schema", DataType::Varchar),
+        ("table_name", DataType::Varchar),
+        ("table_type", DataType::Varchar),
+    ],
+    generate_rows: tables_rows,
+};
+
+/// The view `columns` contains information about all table columns in the database.
+/// Ref: [`https://www.postgresql.org/docs/current/infoschema-columns.html`]
+static COLUMNS: SystemCatalog = SystemCatalog {
+    id: SYSTEM_TABLE_ID_START + 33,
+    name: "columns",
+    columns: &[
+        ("table_catalog", DataType::Varchar),
+        ("table_schema", DataType::Varchar),
+        ("table_name", DataType::Varchar),
+        ("column_name", DataType::Varchar),
+        ("ordinal_position", DataType::Int32),
+        ("is_nullable", DataType::Varchar),
+        ("data_type", DataType::Varchar),
+    ],
+    generate_rows: columns_rows,
+};
+
+/// Get the type name used by `information_schema.columns.data_type`.
+fn data_type_name(data_type: &DataType) -> &'static str {
+    match data_type {
+        DataType::Int16 => "smallint",
+        DataType::Int32 => "integer",
+        DataType::Int64 => "bigint",
+        DataType::Float32 => "real",
+        DataType::Float64 => "double precision",
+        DataType::Boolean => "boolean",
+        DataType::Varchar => "character varying",
+        DataType::Date => "date",
+        DataType::Time => "time without time zone",
+        DataType::Timestamp => "timestamp without time zone",
+        DataType::Timestampz => "timestamp with time zone",
+        DataType::Decimal => "numeric",
+        DataType::Interval => "interval",
+        DataType::Struct { .. } => "USER-DEFINED",
+        DataType::List { .. } => "ARRAY",
+        DataType::Jsonb => "jsonb",
+        DataType::Bytea => "bytea",
+        DataType::Uuid => "uuid",
+        DataType::Char { .. } => "character",
+    }
+}
+
+fn table_type(kind: RelationKind) -> Option<&'static str> {
+    match kind {
+        RelationKind::Table => Some("BASE TABLE"),
+        RelationKind::MaterializedView => Some("MATERIALIZED VIEW"),
+        RelationKind::Source => Some("FOREIGN"),
+        RelationKind::View => Some("VIEW"),
+        // Indexes are not listed in `information_schema`.
+        RelationKind::Index => None,
+    }
+}
+
+fn tables_rows(catalog: &Catalog, db_name: &str) -> Vec<Row> {
+    iter_relations(catalog, db_name)
+        .into_iter()
+        .filter_map(|relation| {
+            let table_type = table_type(relation.kind)?;
+            Some(Row(vec![
+                Some(ScalarImpl::Utf8(db_name.to_owned())),
+                Some(ScalarImpl::Utf8(relation.schema.name().to_owned())),
+                Some(ScalarImpl::Utf8(relation.name.to_owned())),
+                Some(ScalarImpl::Utf8(table_type.to_owned())),
+            ]))
+        })
+        .collect()
+}
+
+fn columns_rows(catalog: &Catalog, db_name: &str) -> Vec<Row> {
+    iter_relations(catalog, db_name)
+        .into_iter()
+        .filter(|relation| table_type(relation.kind).is_some())
+        .flat_map(|relation| {
+            let columns = relation.columns.into_owned();
+            columns
+                .into_iter()
+                .filter(|c| !c.is_hidden)
+                .enumerate()
+                .map(move |(idx, column)| {
+                    Row(vec![
+                        Some(ScalarImpl::Utf8(db_name.to_owned())),
+                        Some(ScalarImpl::Utf8(relation.schema.name().to_owned())),
+                        Some(ScalarImpl::Utf8(relation.name.to_owned())),
+                        Some(ScalarImpl::Utf8(column.name().to_owned())),
+                        Some(ScalarImpl::Int32(idx as i32 + 1)),
+                        Some(ScalarImpl::Utf8("YES".to_owned())),
+                        Some(ScalarImpl::Utf8(
+                            data_type_name(column.data_type()).to_owned(),
+                        )),
+                    ])
+                })
+        })
+        .collect()
+}
@@ -0,0 +1,200 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! System tables in `pg_catalog` and `information_schema`. Each of them is defined as a
+//! [`ProstVirtualTable`] with an id reserved for it. Their rows are generated on the fly from the
+//! frontend [`Catalog`], so they are always consistent with the catalog read guard held by the
+//! binder. Only the frontend has the catalog to generate them, so the rows are carried to the
+//! compute nodes by the scan in the plan.
+
+mod information_schema;
+mod pg_catalog;
+
+use std::borrow::Cow;
+
+pub use pg_catalog::{format_type_names, visible_relation_ids};
+use risingwave_common::array::Row;
+use risingwave_common::catalog::{ColumnDesc, ColumnId, Field};
+use risingwave_common::types::DataType;
+use risingwave_pb::catalog::VirtualTable as ProstVirtualTable;
+
+use super::column_catalog::ColumnCatalog;
+use super::root_catalog::Catalog;
+use super::schema_catalog::SchemaCatalog;
+
+pub const PG_CATALOG_SCHEMA_NAME: &str = "pg_catalog";
+pub const INFORMATION_SCHEMA_SCHEMA_NAME: &str = "information_schema";
+
+/// The ids of the system schemas and tables are reserved at the top of the `int4` range, which
+/// oids are shown in, far beyond the ids allocated by meta.
+const SYSTEM_ID_START: u32 = i32::MAX as u32 - 1023;
+pub const PG_CATALOG_SCHEMA_ID: u32 = SYSTEM_ID_START;
+pub const INFORMATION_SCHEMA_SCHEMA_ID: u32 = SYSTEM_ID_START + 1;
+/// The ids of the system tables start from here.
+const SYSTEM_TABLE_ID_START: u32 = SYSTEM_ID_START + 64;
+
+/// Column name and data type of a system table.
+pub type SystemCatalogColumn = (&'static str, DataType);
+
+/// A read-only table whose rows are generated from the catalog of the current database.
+pub struct SystemCatalog {
+    pub id: u32,
+    pub name: &'static str,
+    pub columns: &'static [SystemCatalogColumn],
+    /// Generates the rows with the given catalog and database name.
+    pub generate_rows: fn(&Catalog, &str) -> Vec<Row>,
+}
+
+impl SystemCatalog {
+    /// The definition of the table, where the id of a column is its position.
+    pub fn to_prost(&self) -> ProstVirtualTable {
+        let columns = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(idx, (name, data_type))| {
+                let column = ColumnCatalog {
+                    column_desc: ColumnDesc {
+                        name: name.to_string(),
+                        ..ColumnDesc::unnamed(ColumnId::new(idx as i32), data_type.clone())
+                    },
+                    is_hidden: false,
+                };
+                column.to_protobuf()
+            })
+            .collect();
+        ProstVirtualTable {
+            id: self.id,
+            name: self.name.to_string(),
+            columns,
+        }
+    }
+}
+
+/// The fields of the columns of the system table `table`.
+pub fn virtual_table_fields(table: &ProstVirtualTable) -> Vec<Field> {
+    table
+        .columns
+        .iter()
+        .map(|column| {
+            let column = ColumnCatalog::from(column.clone());
+            Field::with_name(column.data_type().clone(), column.name())
+        })
+        .collect()
+}
+
+/// Get the system table by schema name and table name. Returns `None` if the schema is not a
+/// system schema or the table does not exist in it.
+pub fn get_sys_catalog(schema_name: &str, table_name: &str) -> Option<&'static SystemCatalog> {
+    let tables = match schema_name {
+        PG_CATALOG_SCHEMA_NAME => pg_catalog::PG_CATALOG_TABLES,
+        INFORMATION_SCHEMA_SCHEMA_NAME => information_schema::INFORMATION_SCHEMA_TABLES,
+        _ => return None,
+    };
+    tables
+        .iter()
+        .copied()
+        .find(|table| table.name == table_name)
+}
+
+pub fn is_system_schema(schema_name: &str) -> bool {
+    schema_name == PG_CATALOG_SCHEMA_NAME || schema_name == INFORMATION_SCHEMA_SCHEMA_NAME
+}
+
+/// The system schemas, by id and name.
+fn system_schemas() -> [(u32, &'static str); 2] {
+    [
+        (PG_CATALOG_SCHEMA_ID, PG_CATALOG_SCHEMA_NAME),
+        (INFORMATION_SCHEMA_SCHEMA_ID, INFORMATION_SCHEMA_SCHEMA_NAME),
+    ]
+}
+
+/// Kind of a relation listed in the system tables.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RelationKind {
+    Table,
+    MaterializedView,
+    Index,
+    Source,
+    View,
+}
+
+/// A relation listed in the system tables.
+struct SysRelation<'a> {
+    schema: &'a SchemaCatalog,
+    id: u32,
+    name: &'a str,
+    kind: RelationKind,
+    /// The id of the indexed relation, if it's an index.
+    index_on: Option<u32>,
+    columns: Cow<'a, [ColumnCatalog]>,
+}
+
+/// Iterate all relations in the given database, ordered by schema name and relation name.
+fn iter_relations<'a>(catalog: &'a Catalog, db_name: &str) -> Vec<SysRelation<'a>> {
+    let database = match catalog.get_database_by_name(db_name) {
+        Ok(database) => database,
+        Err(_) => return vec![],
+    };
+
+    let mut relations = vec![];
+    for schema in database.iter_schemas() {
+        relations.extend(schema.iter_table().map(|t| SysRelation {
+            schema,
+            id: t.id().table_id,
+            name: t.name(),
+            kind: RelationKind::Table,
+            index_on: None,
+            columns: t.columns().into(),
+        }));
+        relations.extend(schema.iter_mv().map(|t| SysRelation {
+            schema,
+            id: t.id().table_id,
+            name: t.name(),
+            kind: match t.is_index_on {
+                Some(_) => RelationKind::Index,
+                None => RelationKind::MaterializedView,
+            },
+            index_on: t.is_index_on.map(|id| id.table_id),
+            columns: t.columns().into(),
+        }));
+        relations.extend(schema.iter_source().map(|s| SysRelation {
+            schema,
+            id: s.id,
+            name: &s.name,
+            kind: RelationKind::Source,
+            index_on: None,
+            columns: s.columns.as_slice().into(),
+        }));
+        relations.extend(schema.iter_view().map(|v| {
+            SysRelation {
+                schema,
+                id: v.id,
+                name: &v.name,
+                kind: RelationKind::View,
+                index_on: None,
+                columns: v
+                    .columns
+                    .iter()
+                    .map(|field| ColumnCatalog {
+                        column_desc: ColumnDesc::from_field_without_column_id(field),
+                        is_hidden: false,
+                    })
+                    .collect(),
+            }
+        }));
+    }
+    relations.sort_by(|a, b| (a.schema.name(), a.name).cmp(&(b.schema.name(), b.name)));
+    relations
+}
@@ -0,0 +1,242 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use itertools::Itertools;
+use pgwire::pg_field_descriptor::TypeOid;
+use risingwave_common::array::Row;
+use risingwave_common::catalog::DEFAULT_SUPER_USER;
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use super::{iter_relations, system_schemas, RelationKind, SystemCatalog, SYSTEM_TABLE_ID_START};
+use crate::catalog::root_catalog::Catalog;
+use crate::catalog::search_path::SearchPath;
+use crate::handler::util::data_type_to_type_oid;
+
+pub(super) static PG_CATALOG_TABLES: &[&SystemCatalog] =
+    &[&PG_NAMESPACE, &PG_CLASS, &PG_ATTRIBUTE, &PG_TYPE];
+
+/// The catalog `pg_namespace` stores namespaces, i.e., schemas.
+/// Ref: [`https://www.postgresql.org/docs/current/catalog-pg-namespace.html`]
+static PG_NAMESPACE: SystemCatalog = SystemCatalog {
+    id: SYSTEM_TABLE_ID_START,
+    name: "pg_namespace",
+    columns: &[("oid", DataType::Int32), ("nspname", DataType::Varchar)],
+    generate_rows: pg_namespace_rows,
+};
+
+/// The catalog `pg_class` catalogs tables and most everything else that has columns.
+/// Ref: [`https://www.postgresql.org/docs/current/catalog-pg-class.html`]
+static PG_CLASS: SystemCatalog = SystemCatalog {
+    id: SYSTEM_TABLE_ID_START + 1,
+    name: "pg_class",
+    columns: &[
+        ("oid", DataType::Int32),
+        ("relname", DataType::Varchar),
+        ("relnamespace", DataType::Int32),
+        ("relowner", DataType::Int32),
+        ("reltuples", DataType::Float32),
+        ("relhasindex", DataType::Boolean),
+        ("relpersistence", DataType::Varchar),
+        ("relkind", DataType::Varchar),
+        ("relnatts", DataType::Int16),
+    ],
+    generate_rows: pg_class_rows,
+};
+
+/// The catalog `pg_attribute` stores information about table columns.
+/// Ref: [`https://www.postgresql.org/docs/current/catalog-pg-attribute.html`]
+static PG_ATTRIBUTE: SystemCatalog = SystemCatalog {
+    id: SYSTEM_TABLE_ID_START + 2,
+    name: "pg_attribute",
+    columns: &[
+        ("attrelid", DataType::Int32),
+        ("attname", DataType::Varchar),
+        ("atttypid", DataType::Int32),
+        ("attnum", DataType::Int16),
+        ("attnotnull", DataType::Boolean),
+        ("attisdropped", DataType::Boolean),
+    ],
+    generate_rows: pg_attribute_rows,
+};
+
+/// The catalog `pg_type` stores information about data types.
+/// Ref: [`https://www.postgresql.org/docs/current/catalog-pg-type.html`]
+static PG_TYPE: SystemCatalog = SystemCatalog {
+    id: SYSTEM_TABLE_ID_START + 3,
+    name: "pg_type",
+    columns: &[
+        ("oid", DataType::Int32),
+        ("typname", DataType::Varchar),
+        ("typlen", DataType::Int16),
+    ],
+    generate_rows: pg_type_rows,
+};
+
+/// Types exposed in `pg_type`, with the names shown by `format_type`. The oids are consistent
+/// with [`data_type_to_type_oid`], which is also used by `pg_attribute.atttypid`.
+const PG_TYPE_DATA: &[(TypeOid, &str, i16, &str)] = &[
+    (TypeOid::Boolean, "bool", 1, "boolean"),
+    (TypeOid::BigInt, "int8", 8, "bigint"),
+    (TypeOid::SmallInt, "int2", 2, "smallint"),
+    (TypeOid::Int, "int4", 4, "integer"),
+    (TypeOid::Float4, "float4", 4, "real"),
+    (TypeOid::Float8, "float8", 8, "double precision"),
+    (TypeOid::CharArray, "_char", -1, "\"char\"[]"),
+    (TypeOid::Varchar, "varchar", -1, "character varying"),
+    (TypeOid::Date, "date", 4, "date"),
+    (TypeOid::Time, "time", 8, "time without time zone"),
+    (
+        TypeOid::Timestamp,
+        "timestamp",
+        8,
+        "timestamp without time zone",
+    ),
+    (
+        TypeOid::Timestampz,
+        "timestamptz",
+        8,
+        "timestamp with time zone",
+    ),
+    (TypeOid::Decimal, "numeric", -1, "numeric"),
+    (TypeOid::Interval, "interval", 16, "interval"),
+    (TypeOid::Jsonb, "jsonb", -1, "jsonb"),
+    (TypeOid::Bytea, "bytea", -1, "bytea"),
+    (TypeOid::Uuid, "uuid", 16, "uuid"),
+    (TypeOid::Bpchar, "bpchar", -1, "character"),
+];
+
+/// The type oids and their names shown by `format_type`.
+pub fn format_type_names() -> impl Iterator<Item = (i32, &'static str)> {
+    PG_TYPE_DATA
+        .iter()
+        .map(|(oid, _, _, format_name)| (oid.as_number(), *format_name))
+}
+
+/// The ids of the relations for `pg_table_is_visible`, i.e. those that can be referred to by
+/// unqualified names with `search_path`, not hidden by relations of the same names in the schemas
+/// searched earlier.
+pub fn visible_relation_ids(
+    catalog: &Catalog,
+    db_name: &str,
+    search_path: &SearchPath,
+) -> Vec<u32> {
+    iter_relations(catalog, db_name)
+        .into_iter()
+        .filter(|relation| {
+            catalog.find_relation_schema(db_name, search_path, relation.name)
+                == Some(relation.schema.name())
+        })
+        .map(|relation| relation.id)
+        .collect()
+}
+
+fn pg_namespace_rows(catalog: &Catalog, db_name: &str) -> Vec<Row> {
+    let database = match catalog.get_database_by_name(db_name) {
+        Ok(database) => database,
+        Err(_) => return vec![],
+    };
+    database
+        .iter_schemas()
+        .map(|schema| (schema.id(), schema.name()))
+        .chain(system_schemas())
+        .sorted_by(|a, b| a.1.cmp(b.1))
+        .map(|(id, name)| {
+            Row(vec![
+                Some(ScalarImpl::Int32(id as i32)),
+                Some(ScalarImpl::Utf8(name.to_owned())),
+            ])
+        })
+        .collect()
+}
+
+fn pg_class_rows(catalog: &Catalog, db_name: &str) -> Vec<Row> {
+    let relations = iter_relations(catalog, db_name);
+    let indexed_ids: HashSet<_> = relations
+        .iter()
+        .filter(|relation| relation.kind == RelationKind::Index)
+        .filter_map(|relation| relation.index_on)
+        .collect();
+    // Relations have no owners yet, so they are all shown as owned by the default superuser.
+    let owner = catalog
+        .get_user_by_name(DEFAULT_SUPER_USER)
+        .ok()
+        .map(|user| ScalarImpl::Int32(user.id as i32));
+    relations
+        .iter()
+        .map(|relation| {
+            let relkind = match relation.kind {
+                RelationKind::Table => "r",
+                RelationKind::MaterializedView => "m",
+                RelationKind::Index => "i",
+                // Sources are external data like foreign tables in PostgreSQL.
+                RelationKind::Source => "f",
+                RelationKind::View => "v",
+            };
+            let relnatts = relation.columns.iter().filter(|c| !c.is_hidden).count();
+            Row(vec![
+                Some(ScalarImpl::Int32(relation.id as i32)),
+                Some(ScalarImpl::Utf8(relation.name.to_owned())),
+                Some(ScalarImpl::Int32(relation.schema.id() as i32)),
+                owner.clone(),
+                // The number of rows is not tracked, which is -1 in PostgreSQL.
+                Some(ScalarImpl::Float32((-1.0).into())),
+                Some(ScalarImpl::Bool(indexed_ids.contains(&relation.id))),
+                // All relations are permanent.
+                Some(ScalarImpl::Utf8("p".to_owned())),
+                Some(ScalarImpl::Utf8(relkind.to_owned())),
+                Some(ScalarImpl::Int16(relnatts as i16)),
+            ])
+        })
+        .collect()
+}
+
+fn pg_attribute_rows(catalog: &Catalog, db_name: &str) -> Vec<Row> {
+    iter_relations(catalog, db_name)
+        .into_iter()
+        .flat_map(|relation| {
+            let columns = relation.columns.into_owned();
+            columns
+                .into_iter()
+                .filter(|c| !c.is_hidden)
+                .enumerate()
+                .map(move |(idx, column)| {
+                    let type_oid = data_type_to_type_oid(column.data_type().clone());
+                    Row(vec![
+                        Some(ScalarImpl::Int32(relation.id as i32)),
+                        Some(ScalarImpl::Utf8(column.name().to_owned())),
+                        Some(ScalarImpl::Int32(type_oid.as_number())),
+                        // Attribute numbers of user columns start from 1.
+                        Some(ScalarImpl::Int16(idx as i16 + 1)),
+                        Some(ScalarImpl::Bool(false)),
+                        Some(ScalarImpl::Bool(false)),
+                    ])
+                })
+        })
+        .collect()
+}
+
+fn pg_type_rows(_catalog: &Catalog, _db_name: &str) -> Vec<Row> {
+    PG_TYPE_DATA
+        .iter()
+        .map(|(oid, name, len, _)| {
+            Row(vec![
+                Some(ScalarImpl::Int32(oid.as_number())),
+                Some(ScalarImpl::Utf8(name.to_string())),
+                Some(ScalarImpl::Int16(*len)),
+            ])
+        })
+        .collect()
+}
@@ -47,6 +47,17 @@ pub struct TableCatalog {
 
     /// If set to Some(TableId), then this table is an index on another table.
     pub is_index_on: Option<TableId>,
+
+    /// Whether this index rejects duplicated values on its key columns.
+    pub is_unique_index: bool,
+
+    /// Number of leading columns in `order_desc` which are the key columns of this index. Zero if
+    /// this table is not an index.
+    pub index_key_count: usize,
+
+    /// The table that expression evaluation errors of this materialized view are written to, if
+    /// it's created with a non-strict `expr_error_mode`.
+    pub error_table_id: Option<TableId>,
 }
 
 impl TableCatalog {
@@ -91,6 +102,16 @@ impl TableCatalog {
         self.distribution_keys.as_ref()
     }
 
+    /// Get the key columns of this index.
+    pub fn index_keys(&self) -> &[OrderedColumnDesc] {
+        &self.order_desc[..self.index_key_count]
+    }
+
+    /// Get the table catalog's error table id.
+    pub fn error_table_id(&self) -> Option<TableId> {
+        self.error_table_id
+    }
+
     pub fn to_prost(&self, schema_id: SchemaId, database_id: DatabaseId) -> ProstTable {
         let (order_column_ids, orders) = self
             .order_desc()
@@ -123,6 +144,9 @@ impl TableCatalog {
                 .iter()
                 .map(|k| *k as i32)
                 .collect_vec(),
+            is_unique_index: self.is_unique_index,
+            index_key_count: self.index_key_count as u32,
+            error_table_id: self.error_table_id.unwrap_or_default().table_id(),
         }
     }
 }
@@ -180,6 +204,13 @@ impl From<ProstTable> for TableCatalog {
                 .map(|k| *k as usize)
                 .collect_vec(),
             pks: tb.pk.iter().map(|x| *x as _).collect(),
+            is_unique_index: tb.is_unique_index,
+            index_key_count: tb.index_key_count as usize,
+            error_table_id: if tb.error_table_id != 0 {
+                Some(tb.error_table_id.into())
+            } else {
+                None
+            },
         }
     }
 }
@@ -246,6 +277,9 @@ mod tests {
             orders: vec![OrderType::Ascending.to_prost() as i32],
             dependent_relations: vec![],
             distribution_keys: vec![],
+            is_unique_index: false,
+            index_key_count: 0,
+            error_table_id: 0,
             optional_associated_source_id: OptionalAssociatedSourceId::AssociatedSourceId(233)
                 .into(),
         }
@@ -255,6 +289,9 @@ mod tests {
             table,
             TableCatalog {
                 is_index_on: None,
+                is_unique_index: false,
+                index_key_count: 0,
+                error_table_id: None,
                 id: TableId::new(0),
                 associated_source_id: Some(TableId::new(233)),
                 name: "test".to_string(),
@@ -274,6 +311,7 @@ mod tests {
                                     name: "country.address".to_string(),
                                     field_descs: vec![],
                                     type_name: String::new(),
+                                    default_value: None,
                                 },
                                 ColumnDesc {
                                     data_type: DataType::Varchar,
@@ -281,9 +319,11 @@ mod tests {
                                     name: "country.zipcode".to_string(),
                                     field_descs: vec![],
                                     type_name: String::new(),
+                                    default_value: None,
                                 }
                             ],
-                            type_name: ".test.Country".to_string()
+                            type_name: ".test.Country".to_string(),
+                            default_value: None,
                         },
                         is_hidden: false
                     }
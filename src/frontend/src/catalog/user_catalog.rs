@@ -0,0 +1,151 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_pb::user::grant_privilege::{Action, Target};
+use risingwave_pb::user::{GrantPrivilege as ProstGrantPrivilege, UserInfo as ProstUserInfo};
+
+use crate::catalog::UserId;
+
+/// A user and the privileges granted to it. Superusers bypass all privilege checks.
+#[derive(Clone, Debug)]
+pub struct UserCatalog {
+    pub id: UserId,
+    pub name: String,
+    pub is_superuser: bool,
+    pub can_create_db: bool,
+    pub can_login: bool,
+    pub grant_privileges: Vec<ProstGrantPrivilege>,
+}
+
+impl UserCatalog {
+    pub fn to_prost(&self) -> ProstUserInfo {
+        ProstUserInfo {
+            id: self.id,
+            name: self.name.clone(),
+            is_superuser: self.is_superuser,
+            can_create_db: self.can_create_db,
+            can_login: self.can_login,
+            grant_privileges: self.grant_privileges.clone(),
+        }
+    }
+
+    /// Returns whether the user is allowed to perform `action` on `target`.
+    pub fn has_privilege(&self, target: &Target, action: Action) -> bool {
+        self.is_superuser || self.find_action(target, action).is_some()
+    }
+
+    /// Returns whether the user is allowed to grant `action` on `target` to others.
+    pub fn has_grant_option(&self, target: &Target, action: Action) -> bool {
+        self.is_superuser
+            || self
+                .find_action(target, action)
+                .map_or(false, |with_grant_option| with_grant_option)
+    }
+
+    /// Same as [`UserCatalog::has_privilege`], but returns a `PermissionDenied` error naming
+    /// `object_name` if the privilege is missing.
+    pub fn check_privilege(
+        &self,
+        target: &Target,
+        action: Action,
+        object_name: &str,
+    ) -> Result<()> {
+        if self.has_privilege(target, action) {
+            return Ok(());
+        }
+        let object_type = match target {
+            Target::DatabaseId(_) => "database",
+            Target::SchemaId(_) => "schema",
+            Target::TableId(_) => "table",
+            Target::SourceId(_) => "source",
+        };
+        Err(ErrorCode::PermissionDenied(format!(
+            "permission denied for {} {}",
+            object_type, object_name
+        ))
+        .into())
+    }
+
+    /// Finds the granted `action` on `target` and returns whether it carries the grant option.
+    fn find_action(&self, target: &Target, action: Action) -> Option<bool> {
+        self.grant_privileges
+            .iter()
+            .filter(|p| p.target.as_ref() == Some(target))
+            .flat_map(|p| p.action_with_opts.iter())
+            .find(|a| a.action == action as i32)
+            .map(|a| a.with_grant_option)
+    }
+}
+
+impl From<&ProstUserInfo> for UserCatalog {
+    fn from(user: &ProstUserInfo) -> Self {
+        Self {
+            id: user.id,
+            name: user.name.clone(),
+            is_superuser: user.is_superuser,
+            can_create_db: user.can_create_db,
+            can_login: user.can_login,
+            grant_privileges: user.grant_privileges.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_pb::user::grant_privilege::ActionWithGrantOption;
+
+    use super::*;
+
+    #[test]
+    fn test_check_privilege() {
+        let mut user = UserCatalog {
+            id: 1,
+            name: "user".to_string(),
+            is_superuser: false,
+            can_create_db: false,
+            can_login: true,
+            grant_privileges: vec![ProstGrantPrivilege {
+                target: Some(Target::TableId(1)),
+                action_with_opts: vec![
+                    ActionWithGrantOption {
+                        action: Action::Select as i32,
+                        with_grant_option: true,
+                    },
+                    ActionWithGrantOption {
+                        action: Action::Insert as i32,
+                        with_grant_option: false,
+                    },
+                ],
+            }],
+        };
+        assert!(user.has_privilege(&Target::TableId(1), Action::Select));
+        assert!(user.has_privilege(&Target::TableId(1), Action::Insert));
+        assert!(!user.has_privilege(&Target::TableId(1), Action::Delete));
+        assert!(!user.has_privilege(&Target::TableId(2), Action::Select));
+        assert!(!user.has_privilege(&Target::SourceId(1), Action::Select));
+        assert!(user.has_grant_option(&Target::TableId(1), Action::Select));
+        assert!(!user.has_grant_option(&Target::TableId(1), Action::Insert));
+        assert_eq!(
+            user.check_privilege(&Target::SourceId(1), Action::Select, "s")
+                .unwrap_err()
+                .to_string(),
+            "Permission denied: permission denied for source s"
+        );
+
+        user.is_superuser = true;
+        assert!(user.has_privilege(&Target::SchemaId(1), Action::Create));
+        assert!(user.has_grant_option(&Target::TableId(2), Action::Insert));
+    }
+}
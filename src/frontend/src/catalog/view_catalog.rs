@@ -0,0 +1,39 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::catalog::Field;
+use risingwave_pb::catalog::View as ProstView;
+
+use super::ViewId;
+
+/// A view, whose SQL is parsed and bound in place of the view whenever it is referenced.
+#[derive(Clone, Debug)]
+pub struct ViewCatalog {
+    pub id: ViewId,
+    pub name: String,
+    pub sql: String,
+    /// The output columns of the view, which the bound query must still produce.
+    pub columns: Vec<Field>,
+}
+
+impl From<&ProstView> for ViewCatalog {
+    fn from(prost: &ProstView) -> Self {
+        Self {
+            id: prost.id,
+            name: prost.name.clone(),
+            sql: prost.sql.clone(),
+            columns: prost.columns.iter().map(Field::from).collect(),
+        }
+    }
+}
@@ -59,8 +59,12 @@ impl std::fmt::Debug for FunctionCall {
                     self.inputs[0].fmt(f)?;
                     return write!(f, "::{:?}", self.return_type);
                 }
-                ExprType::Add => debug_binary_op(f, "+", &self.inputs),
-                ExprType::Subtract => debug_binary_op(f, "-", &self.inputs),
+                // Adding or subtracting an interval to or from `timestamptz` takes a third
+                // argument, the time zone.
+                ExprType::Add if self.inputs.len() == 2 => debug_binary_op(f, "+", &self.inputs),
+                ExprType::Subtract if self.inputs.len() == 2 => {
+                    debug_binary_op(f, "-", &self.inputs)
+                }
                 ExprType::Multiply => debug_binary_op(f, "*", &self.inputs),
                 ExprType::Divide => debug_binary_op(f, "/", &self.inputs),
                 ExprType::Modulus => debug_binary_op(f, "%", &self.inputs),
@@ -72,6 +76,14 @@ impl std::fmt::Debug for FunctionCall {
                 ExprType::GreaterThanOrEqual => debug_binary_op(f, ">=", &self.inputs),
                 ExprType::And => debug_binary_op(f, "AND", &self.inputs),
                 ExprType::Or => debug_binary_op(f, "OR", &self.inputs),
+                ExprType::BitwiseAnd => debug_binary_op(f, "&", &self.inputs),
+                ExprType::BitwiseOr => debug_binary_op(f, "|", &self.inputs),
+                ExprType::BitwiseXor => debug_binary_op(f, "#", &self.inputs),
+                ExprType::BitwiseShiftLeft => debug_binary_op(f, "<<", &self.inputs),
+                ExprType::BitwiseShiftRight => debug_binary_op(f, ">>", &self.inputs),
+                ExprType::ConcatOp => debug_binary_op(f, "||", &self.inputs),
+                ExprType::ArrayContains => debug_binary_op(f, "@>", &self.inputs),
+                ExprType::ArrayOverlap => debug_binary_op(f, "&&", &self.inputs),
                 _ => {
                     let func_name = format!("{:?}", self.func_type);
                     let mut builder = f.debug_tuple(&func_name);
@@ -85,6 +97,92 @@ impl std::fmt::Debug for FunctionCall {
     }
 }
 
+/// Returns the element type of the list `input` of an array function.
+fn element_type(func_type: ExprType, input: &ExprImpl) -> Result<DataType> {
+    match input.return_type() {
+        DataType::List { datatype } => Ok(*datatype),
+        t => Err(ErrorCode::BindError(format!(
+            "{:?} function expects an array, but got {:?}",
+            func_type, t
+        ))
+        .into()),
+    }
+}
+
+/// Infers the return type of an array function, and casts the arguments to the types it takes.
+fn infer_array_func_type(func_type: ExprType, inputs: &mut [ExprImpl]) -> Result<DataType> {
+    let cast = |input: &mut ExprImpl, target: DataType| -> Result<()> {
+        let dummy = std::mem::replace(input, ExprImpl::literal_bool(false));
+        *input = dummy.cast_implicit(target)?;
+        Ok(())
+    };
+    let (min_args, max_args) = match func_type {
+        ExprType::Array => (1, usize::MAX),
+        ExprType::Cardinality => (1, 1),
+        ExprType::ArrayPosition | ExprType::ArrayToString => (2, 3),
+        ExprType::ArrayAny | ExprType::ArrayAll => (3, 3),
+        _ => (2, 2),
+    };
+    if inputs.len() < min_args || inputs.len() > max_args {
+        return Err(ErrorCode::BindError(format!(
+            "{:?} function cannot take {} arguments",
+            func_type,
+            inputs.len()
+        ))
+        .into());
+    }
+    match func_type {
+        ExprType::Array => {
+            let datatype = align_types(inputs.iter_mut())?;
+            Ok(DataType::List {
+                datatype: Box::new(datatype),
+            })
+        }
+        ExprType::ArrayAccess => {
+            cast(&mut inputs[1], DataType::Int32)?;
+            element_type(func_type, &inputs[0])
+        }
+        ExprType::ArrayLength | ExprType::Cardinality => {
+            element_type(func_type, &inputs[0])?;
+            if let Some(dimension) = inputs.get_mut(1) {
+                cast(dimension, DataType::Int32)?;
+            }
+            Ok(DataType::Int32)
+        }
+        ExprType::ArrayAppend | ExprType::ArrayPosition => {
+            cast(&mut inputs[1], element_type(func_type, &inputs[0])?)?;
+            if let Some(start) = inputs.get_mut(2) {
+                cast(start, DataType::Int32)?;
+            }
+            match func_type {
+                ExprType::ArrayAppend => Ok(inputs[0].return_type()),
+                _ => Ok(DataType::Int32),
+            }
+        }
+        ExprType::ArrayCat | ExprType::ArrayContains | ExprType::ArrayOverlap => {
+            let list_type = align_types(inputs.iter_mut())?;
+            element_type(func_type, &inputs[0])?;
+            match func_type {
+                ExprType::ArrayCat => Ok(list_type),
+                _ => Ok(DataType::Boolean),
+            }
+        }
+        ExprType::ArrayToString => {
+            element_type(func_type, &inputs[0])?;
+            for input in &mut inputs[1..] {
+                cast(input, DataType::Varchar)?;
+            }
+            Ok(DataType::Varchar)
+        }
+        ExprType::ArrayAny | ExprType::ArrayAll => {
+            // The arguments are the element, the comparison operator and the list.
+            cast(&mut inputs[0], element_type(func_type, &inputs[2])?)?;
+            Ok(DataType::Boolean)
+        }
+        _ => unreachable!(),
+    }
+}
+
 impl FunctionCall {
     /// Create a `FunctionCall` expr with the return type inferred from `func_type` and types of
     /// `inputs`.
@@ -135,10 +233,233 @@ impl FunctionCall {
                     .collect::<Result<Vec<_>>>()?;
                 Ok(DataType::Varchar)
             }
-            _ => infer_type(
-                func_type,
-                inputs.iter().map(|expr| expr.return_type()).collect(),
-            ),
+            ExprType::JsonbContains => {
+                // String literals are bound as varchar rather than unknown, so we allow them to be
+                // casted in assign context, e.g. `v @> '{"a": 1}'`.
+                inputs = inputs
+                    .into_iter()
+                    .map(|input| input.cast_assign(DataType::Jsonb))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(DataType::Boolean)
+            }
+            ExprType::Encode | ExprType::Sha256 => {
+                // Same as above, allows string literals as the `bytea` argument, e.g.
+                // `sha256('abc')`.
+                inputs = inputs
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, input)| match i {
+                        0 => input.cast_assign(DataType::Bytea),
+                        _ => Ok(input),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                infer_type(
+                    func_type,
+                    inputs.iter().map(|expr| expr.return_type()).collect(),
+                )
+            }
+            ExprType::DateTrunc | ExprType::ToChar => {
+                // Dates are taken as `timestamptz` at midnight, as in PG.
+                let source = match func_type {
+                    ExprType::DateTrunc => 1,
+                    _ => 0,
+                };
+                inputs = inputs
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, input)| match input.return_type() {
+                        DataType::Date if i == source => input.cast_implicit(DataType::Timestampz),
+                        _ => Ok(input),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                infer_type(
+                    func_type,
+                    inputs.iter().map(|expr| expr.return_type()).collect(),
+                )
+            }
+            ExprType::ToTimestamp => {
+                inputs = inputs
+                    .into_iter()
+                    .map(|input| input.cast_implicit(DataType::Float64))
+                    .collect::<Result<Vec<_>>>()?;
+                infer_type(
+                    func_type,
+                    inputs.iter().map(|expr| expr.return_type()).collect(),
+                )
+            }
+            ExprType::Age => {
+                align_types(inputs.iter_mut())?;
+                infer_type(
+                    func_type,
+                    inputs.iter().map(|expr| expr.return_type()).collect(),
+                )
+            }
+            ExprType::RegexpMatch => {
+                // Returns `varchar[]`, which is parameterized and thus not in the signature map.
+                if inputs.len() != 2 && inputs.len() != 3 {
+                    return Err(ErrorCode::BindError(
+                        "RegexpMatch function must contain 2 or 3 arguments".into(),
+                    )
+                    .into());
+                }
+                inputs = inputs
+                    .into_iter()
+                    .map(|input| input.cast_implicit(DataType::Varchar))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(DataType::List {
+                    datatype: Box::new(DataType::Varchar),
+                })
+            }
+            ExprType::StringToArray => {
+                // Returns `varchar[]`, which is parameterized and thus not in the signature map.
+                if inputs.len() != 2 && inputs.len() != 3 {
+                    return Err(ErrorCode::BindError(
+                        "StringToArray function must contain 2 or 3 arguments".into(),
+                    )
+                    .into());
+                }
+                inputs = inputs
+                    .into_iter()
+                    .map(|input| input.cast_implicit(DataType::Varchar))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(DataType::List {
+                    datatype: Box::new(DataType::Varchar),
+                })
+            }
+            ExprType::ConcatOp => {
+                // As in PG, `||` accepts any type on one side if the other side is a string, which
+                // is casted to a string first.
+                let is_string = |input: &ExprImpl| {
+                    input.is_null()
+                        || matches!(
+                            input.return_type(),
+                            DataType::Varchar | DataType::Char { .. }
+                        )
+                };
+                if !inputs.iter().any(is_string) {
+                    return Err(ErrorCode::BindError(format!(
+                        "operator does not exist: {:?} || {:?}",
+                        inputs[0].return_type(),
+                        inputs[1].return_type()
+                    ))
+                    .into());
+                }
+                inputs = inputs
+                    .into_iter()
+                    .map(|input| input.cast_explicit(DataType::Varchar))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(DataType::Varchar)
+            }
+            ExprType::Array
+            | ExprType::ArrayAccess
+            | ExprType::ArrayLength
+            | ExprType::Cardinality
+            | ExprType::ArrayAppend
+            | ExprType::ArrayCat
+            | ExprType::ArrayPosition
+            | ExprType::ArrayContains
+            | ExprType::ArrayOverlap
+            | ExprType::ArrayToString
+            | ExprType::ArrayAny
+            | ExprType::ArrayAll => {
+                // Lists are parameterized by the element type and thus not in the signature map.
+                infer_array_func_type(func_type, &mut inputs)
+            }
+            ExprType::Pow
+            | ExprType::Sqrt
+            | ExprType::Cbrt
+            | ExprType::Exp
+            | ExprType::Ln
+            | ExprType::Log10
+            | ExprType::Floor
+            | ExprType::Ceil
+            | ExprType::Trunc
+            | ExprType::Sign
+            | ExprType::Round
+            | ExprType::Sin
+            | ExprType::Cos
+            | ExprType::Tan
+            | ExprType::Cot
+            | ExprType::Asin
+            | ExprType::Acos
+            | ExprType::Atan
+            | ExprType::Atan2 => {
+                // Math functions are defined on `double precision`, and some of them also on
+                // `numeric`. As in PG, the arguments are casted to `numeric` if any of them is
+                // `numeric` and the others are integers, and to `double precision` otherwise.
+                let has_decimal_variant = matches!(
+                    func_type,
+                    ExprType::Pow
+                        | ExprType::Sqrt
+                        | ExprType::Exp
+                        | ExprType::Ln
+                        | ExprType::Log10
+                        | ExprType::Floor
+                        | ExprType::Ceil
+                        | ExprType::Trunc
+                        | ExprType::Sign
+                );
+                let is_exact = |input: &ExprImpl| {
+                    input.is_null()
+                        || matches!(
+                            input.return_type(),
+                            DataType::Int16 | DataType::Int32 | DataType::Int64 | DataType::Decimal
+                        )
+                };
+                let target = if has_decimal_variant
+                    && inputs
+                        .iter()
+                        .any(|input| input.return_type() == DataType::Decimal)
+                    && inputs.iter().all(is_exact)
+                {
+                    DataType::Decimal
+                } else {
+                    DataType::Float64
+                };
+                inputs = inputs
+                    .into_iter()
+                    .map(|input| input.cast_implicit(target.clone()))
+                    .collect::<Result<Vec<_>>>()?;
+                infer_type(
+                    func_type,
+                    inputs.iter().map(|expr| expr.return_type()).collect(),
+                )
+            }
+            ExprType::JsonbExtractPath => {
+                if inputs.is_empty() {
+                    return Err(ErrorCode::BindError(
+                        "JsonbExtractPath function must contain at least 1 argument".into(),
+                    )
+                    .into());
+                }
+
+                inputs = inputs
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, input)| match i {
+                        0 => input.cast_assign(DataType::Jsonb),
+                        // subsequent are path elements
+                        _ => input.cast_implicit(DataType::Varchar),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(DataType::Jsonb)
+            }
+            _ => {
+                // `char(n)` shares the physical representation of `varchar`, so functions are only
+                // defined on the latter. The implicit cast strips the padding spaces, which also
+                // gives trailing spaces no significance in comparisons, as in PG.
+                inputs = inputs
+                    .into_iter()
+                    .map(|input| match input.return_type() {
+                        DataType::Char { .. } => input.cast_implicit(DataType::Varchar),
+                        _ => Ok(input),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                infer_type(
+                    func_type,
+                    inputs.iter().map(|expr| expr.return_type()).collect(),
+                )
+            }
         }?;
         Ok(Self {
             func_type,
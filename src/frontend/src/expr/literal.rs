@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use risingwave_common::types::{DataType, Datum, ScalarImpl};
+use risingwave_common::types::{DataType, Datum, Scalar, ScalarImpl};
 use risingwave_pb::expr::expr_node::RexNode;
 
 use super::Expr;
@@ -36,6 +36,8 @@ impl std::fmt::Debug for Literal {
                 // Add single quotation marks for string and interval literals
                 Some(ScalarImpl::Utf8(v)) => write!(f, "'{}'", v),
                 Some(ScalarImpl::Interval(v)) => write!(f, "'{}'", v),
+                Some(ScalarImpl::Jsonb(v)) => write!(f, "'{}'", v),
+                Some(v @ (ScalarImpl::Bytea(_) | ScalarImpl::Uuid(_))) => write!(f, "'{}'", v),
                 Some(v) => write!(f, "{}", v),
             }?;
             write!(f, ":{:?}", self.data_type)
@@ -95,6 +97,9 @@ fn literal_to_protobuf(d: &Datum) -> Option<RexNode> {
         ScalarImpl::NaiveTime(_) => todo!(),
         ScalarImpl::Struct(_) => todo!(),
         ScalarImpl::List(_) => todo!(),
+        ScalarImpl::Jsonb(v) => v.as_scalar_ref().to_json_text().into_bytes(),
+        ScalarImpl::Bytea(v) => v.to_vec(),
+        ScalarImpl::Uuid(v) => v.to_bytes().to_vec(),
     };
     Some(RexNode::Constant(ConstantValue { body }))
 }
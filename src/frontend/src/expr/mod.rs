@@ -15,9 +15,10 @@
 use enum_as_inner::EnumAsInner;
 use fixedbitset::FixedBitSet;
 use paste::paste;
+use risingwave_common::array::DataChunk;
 use risingwave_common::error::Result;
-use risingwave_common::types::{DataType, Scalar};
-use risingwave_expr::expr::AggKind;
+use risingwave_common::types::{DataType, Datum, Scalar};
+use risingwave_expr::expr::{build_from_prost, AggKind};
 use risingwave_pb::expr::ExprNode;
 
 mod agg_call;
@@ -25,7 +26,9 @@ mod correlated_input_ref;
 mod function_call;
 mod input_ref;
 mod literal;
+mod session_timezone;
 mod subquery;
+mod table_function;
 
 mod expr_rewriter;
 mod expr_visitor;
@@ -37,7 +40,9 @@ pub use correlated_input_ref::CorrelatedInputRef;
 pub use function_call::FunctionCall;
 pub use input_ref::{as_alias_display, input_ref_to_column_indices, InputRef, InputRefDisplay};
 pub use literal::Literal;
+pub use session_timezone::SessionTimezone;
 pub use subquery::{Subquery, SubqueryKind};
+pub use table_function::{TableFunction, TableFunctionType};
 
 pub type ExprType = risingwave_pb::expr::expr_node::Type;
 
@@ -64,6 +69,7 @@ pub enum ExprImpl {
     FunctionCall(Box<FunctionCall>),
     AggCall(Box<AggCall>),
     Subquery(Box<Subquery>),
+    TableFunction(Box<TableFunction>),
 }
 
 impl ExprImpl {
@@ -79,6 +85,12 @@ impl ExprImpl {
         Literal::new(Some(v.to_scalar_value()), DataType::Boolean).into()
     }
 
+    /// A literal varchar value.
+    #[inline(always)]
+    pub fn literal_varchar(v: String) -> Self {
+        Literal::new(Some(v.to_scalar_value()), DataType::Varchar).into()
+    }
+
     /// A `count(*)` aggregate function.
     #[inline(always)]
     pub fn count_star() -> Self {
@@ -148,19 +160,37 @@ macro_rules! impl_has_variant {
     };
 }
 
-impl_has_variant! {InputRef, Literal, FunctionCall, AggCall, Subquery}
+impl_has_variant! {InputRef, Literal, FunctionCall, AggCall, Subquery, TableFunction}
 
 impl ExprImpl {
     // We need to traverse inside subqueries.
     pub fn has_correlated_input_ref(&self) -> bool {
+        self.has_correlated_input_ref_inner(1, false)
+    }
+
+    /// Checks whether the expression refers to the columns of the query `depth` levels above.
+    pub fn has_correlated_input_ref_by_depth(&self, depth: usize) -> bool {
+        self.has_correlated_input_ref_inner(depth, true)
+    }
+
+    /// Checks whether the expression refers to the columns of any query at least `depth` levels
+    /// above.
+    pub fn has_correlated_input_ref_from_depth(&self, depth: usize) -> bool {
+        self.has_correlated_input_ref_inner(depth, false)
+    }
+
+    fn has_correlated_input_ref_inner(&self, depth: usize, exact: bool) -> bool {
         struct Has {
             has: bool,
             depth: usize,
+            exact: bool,
         }
 
         impl ExprVisitor for Has {
             fn visit_correlated_input_ref(&mut self, correlated_input_ref: &CorrelatedInputRef) {
-                if correlated_input_ref.depth() >= self.depth {
+                if correlated_input_ref.depth() == self.depth
+                    || !self.exact && correlated_input_ref.depth() > self.depth
+                {
                     self.has = true;
                 }
             }
@@ -168,6 +198,12 @@ impl ExprImpl {
             fn visit_subquery(&mut self, subquery: &Subquery) {
                 use crate::binder::BoundSetExpr;
 
+                match &subquery.kind {
+                    SubqueryKind::In(expr)
+                    | SubqueryKind::Some(expr, _)
+                    | SubqueryKind::All(expr, _) => self.visit_expr(expr),
+                    SubqueryKind::Scalar | SubqueryKind::Existential => {}
+                }
                 self.depth += 1;
                 match &subquery.query.body {
                     BoundSetExpr::Select(select) => select
@@ -175,6 +211,7 @@ impl ExprImpl {
                         .iter()
                         .chain(select.group_by.iter())
                         .chain(select.where_clause.iter())
+                        .chain(select.having.iter())
                         .for_each(|expr| self.visit_expr(expr)),
                     BoundSetExpr::Values(_) => {}
                 }
@@ -184,12 +221,38 @@ impl ExprImpl {
 
         let mut visitor = Has {
             has: false,
-            depth: 1,
+            depth,
+            exact,
         };
         visitor.visit_expr(self);
         visitor.has
     }
 
+    /// Checks whether the expression contains a `GROUPING` function call, which has to be
+    /// rewritten by the aggregation.
+    pub fn has_grouping_call(&self) -> bool {
+        struct Has {
+            has: bool,
+        }
+
+        impl ExprVisitor for Has {
+            fn visit_function_call(&mut self, func_call: &FunctionCall) {
+                if func_call.get_expr_type() == ExprType::Grouping {
+                    self.has = true;
+                } else {
+                    func_call
+                        .inputs()
+                        .iter()
+                        .for_each(|expr| self.visit_expr(expr));
+                }
+            }
+        }
+
+        let mut visitor = Has { has: false };
+        visitor.visit_expr(self);
+        visitor.has
+    }
+
     /// Checks whether this is a constant expr that can be evaluated over a dummy chunk.
     /// Equivalent to `!has_input_ref && !has_agg_call && !has_subquery &&
     /// !has_correlated_input_ref` but checks them in one pass.
@@ -218,6 +281,30 @@ impl ExprImpl {
         visitor.visit_expr(self);
         !visitor.has
     }
+
+    /// Evaluates a constant expr, which must satisfy [`ExprImpl::is_const`], in frontend.
+    pub fn eval_const(&self) -> Result<Datum> {
+        debug_assert!(self.is_const());
+        Ok(build_from_prost(&self.to_expr_proto())?
+            .eval(&DataChunk::new_dummy(1))?
+            .datum_at(0))
+    }
+
+    /// Returns the column and the constant if this is an equality condition between them, e.g.
+    /// `$1 = 42` or `42 = $1`.
+    pub fn as_eq_const(&self) -> Option<(InputRef, ExprImpl)> {
+        if let ExprImpl::FunctionCall(function_call) = self
+            && function_call.get_expr_type() == ExprType::Equal
+        {
+            match function_call.clone().decompose_as_binary() {
+                (_, ExprImpl::InputRef(x), y) if y.is_const() => Some((*x, y)),
+                (_, x, ExprImpl::InputRef(y)) if x.is_const() => Some((*y, x)),
+                _ => None,
+            }
+        } else {
+            None
+        }
+    }
 }
 
 impl Expr for ExprImpl {
@@ -229,6 +316,7 @@ impl Expr for ExprImpl {
             ExprImpl::AggCall(expr) => expr.return_type(),
             ExprImpl::Subquery(expr) => expr.return_type(),
             ExprImpl::CorrelatedInputRef(expr) => expr.return_type(),
+            ExprImpl::TableFunction(expr) => expr.return_type.clone(),
         }
     }
 
@@ -240,6 +328,9 @@ impl Expr for ExprImpl {
             ExprImpl::AggCall(e) => e.to_expr_proto(),
             ExprImpl::Subquery(e) => e.to_expr_proto(),
             ExprImpl::CorrelatedInputRef(e) => e.to_expr_proto(),
+            ExprImpl::TableFunction(_) => {
+                unreachable!("Table function should not be converted to ExprNode")
+            }
         }
     }
 }
@@ -274,6 +365,12 @@ impl From<Subquery> for ExprImpl {
     }
 }
 
+impl From<TableFunction> for ExprImpl {
+    fn from(table_function: TableFunction) -> Self {
+        ExprImpl::TableFunction(Box::new(table_function))
+    }
+}
+
 impl From<CorrelatedInputRef> for ExprImpl {
     fn from(correlated_input_ref: CorrelatedInputRef) -> Self {
         ExprImpl::CorrelatedInputRef(Box::new(correlated_input_ref))
@@ -305,6 +402,7 @@ impl std::fmt::Debug for ExprImpl {
                 Self::CorrelatedInputRef(arg0) => {
                     f.debug_tuple("CorrelatedInputRef").field(arg0).finish()
                 }
+                Self::TableFunction(arg0) => f.debug_tuple("TableFunction").field(arg0).finish(),
             };
         }
         match self {
@@ -314,6 +412,7 @@ impl std::fmt::Debug for ExprImpl {
             Self::AggCall(x) => write!(f, "{:?}", x),
             Self::Subquery(x) => write!(f, "{:?}", x),
             Self::CorrelatedInputRef(x) => write!(f, "{:?}", x),
+            Self::TableFunction(x) => write!(f, "{:?}", x),
         }
     }
 }
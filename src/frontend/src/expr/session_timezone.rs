@@ -0,0 +1,189 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::session_config::ConfigMap;
+use risingwave_common::types::DataType;
+
+use super::{Expr, ExprImpl, ExprRewriter, ExprType, FunctionCall};
+
+/// Rewrites the expressions whose results depend on the session variable `TimeZone`, so that the
+/// time zone is passed to them explicitly and they are evaluated the same way on compute nodes,
+/// in both batch and streaming queries:
+/// - casts between `timestamptz` and `varchar` become `CastWithTimeZone`,
+/// - casts between `timestamptz` and `timestamp`, `date` or `time` go through `AtTimeZone`,
+/// - `Extract` and `DateTrunc` on `timestamptz`, as well as adding or subtracting an interval to or
+///   from `timestamptz`, take the time zone as the third argument,
+/// - `ToChar` and `Age` on `timestamptz` work on the wall-clock times given by `AtTimeZone`.
+#[derive(Clone, Debug)]
+pub struct SessionTimezone {
+    timezone: String,
+}
+
+impl SessionTimezone {
+    pub fn new(timezone: String) -> Self {
+        Self { timezone }
+    }
+
+    fn timezone(&self) -> ExprImpl {
+        ExprImpl::literal_varchar(self.timezone.clone())
+    }
+
+    fn at_time_zone(&self, input: ExprImpl, return_type: DataType) -> ExprImpl {
+        FunctionCall::new_unchecked(
+            ExprType::AtTimeZone,
+            vec![input, self.timezone()],
+            return_type,
+        )
+        .into()
+    }
+
+    fn rewrite_cast(&self, input: ExprImpl, return_type: DataType) -> Option<ExprImpl> {
+        let cast = |input, return_type| -> ExprImpl {
+            FunctionCall::new_unchecked(ExprType::Cast, vec![input], return_type).into()
+        };
+        let rewritten = match (input.return_type(), &return_type) {
+            (DataType::Varchar, DataType::Timestampz)
+            | (DataType::Timestampz, DataType::Varchar) => FunctionCall::new_unchecked(
+                ExprType::CastWithTimeZone,
+                vec![input, self.timezone()],
+                return_type,
+            )
+            .into(),
+            (DataType::Timestamp, DataType::Timestampz)
+            | (DataType::Timestampz, DataType::Timestamp) => self.at_time_zone(input, return_type),
+            (DataType::Date, DataType::Timestampz) => {
+                self.at_time_zone(cast(input, DataType::Timestamp), return_type)
+            }
+            (DataType::Timestampz, DataType::Date | DataType::Time) => {
+                cast(self.at_time_zone(input, DataType::Timestamp), return_type)
+            }
+            _ => return None,
+        };
+        Some(rewritten)
+    }
+}
+
+impl Default for SessionTimezone {
+    fn default() -> Self {
+        Self::new(ConfigMap::default().get_timezone().to_string())
+    }
+}
+
+impl ExprRewriter for SessionTimezone {
+    fn rewrite_function_call(&mut self, func_call: FunctionCall) -> ExprImpl {
+        let (func_type, inputs, ret) = func_call.decompose();
+        let mut inputs: Vec<_> = inputs
+            .into_iter()
+            .map(|expr| self.rewrite_expr(expr))
+            .collect();
+        match func_type {
+            ExprType::Cast => {
+                assert_eq!(inputs.len(), 1);
+                let input = inputs.pop().unwrap();
+                if let Some(rewritten) = self.rewrite_cast(input.clone(), ret.clone()) {
+                    return rewritten;
+                }
+                inputs.push(input);
+            }
+            ExprType::Extract | ExprType::DateTrunc
+                if inputs.len() == 2 && inputs[1].return_type() == DataType::Timestampz =>
+            {
+                inputs.push(self.timezone());
+            }
+            ExprType::Add | ExprType::Subtract if inputs.len() == 2 => {
+                match (inputs[0].return_type(), inputs[1].return_type()) {
+                    (DataType::Timestampz, DataType::Interval) => inputs.push(self.timezone()),
+                    (DataType::Interval, DataType::Timestampz) if func_type == ExprType::Add => {
+                        inputs.swap(0, 1);
+                        inputs.push(self.timezone());
+                    }
+                    _ => {}
+                }
+            }
+            ExprType::ToChar if inputs[0].return_type() == DataType::Timestampz => {
+                let input = inputs.remove(0);
+                inputs.insert(0, self.at_time_zone(input, DataType::Timestamp));
+            }
+            ExprType::Age
+                if inputs
+                    .iter()
+                    .all(|e| e.return_type() == DataType::Timestampz) =>
+            {
+                inputs = inputs
+                    .into_iter()
+                    .map(|input| self.at_time_zone(input, DataType::Timestamp))
+                    .collect();
+            }
+            _ => {}
+        }
+        FunctionCall::new_unchecked(func_type, inputs, ret).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::InputRef;
+
+    #[test]
+    fn test_rewrite_session_timezone() {
+        let mut rewriter = SessionTimezone::new("Asia/Shanghai".to_string());
+        let tz = ExprImpl::from(InputRef::new(0, DataType::Timestampz));
+
+        let expr = tz.clone().cast_assign(DataType::Varchar).unwrap();
+        let expr = rewriter.rewrite_expr(expr);
+        assert_eq!(
+            format!("{:?}", expr),
+            "CastWithTimeZone($0, 'Asia/Shanghai':Varchar)"
+        );
+
+        let expr = tz.clone().cast_assign(DataType::Date).unwrap();
+        let expr = rewriter.rewrite_expr(expr);
+        assert_eq!(
+            format!("{:?}", expr),
+            "AtTimeZone($0, 'Asia/Shanghai':Varchar)::Date"
+        );
+
+        let expr = FunctionCall::new(
+            ExprType::Extract,
+            vec![ExprImpl::literal_varchar("HOUR".to_string()), tz.clone()],
+        )
+        .unwrap()
+        .into();
+        let expr = rewriter.rewrite_expr(expr);
+        assert_eq!(
+            format!("{:?}", expr),
+            "Extract('HOUR':Varchar, $0, 'Asia/Shanghai':Varchar)"
+        );
+
+        let interval = ExprImpl::from(InputRef::new(1, DataType::Interval));
+        let expr = FunctionCall::new(ExprType::Add, vec![interval, tz.clone()])
+            .unwrap()
+            .into();
+        let expr = rewriter.rewrite_expr(expr);
+        assert_eq!(
+            format!("{:?}", expr),
+            "Add($0, $1, 'Asia/Shanghai':Varchar)"
+        );
+
+        let expr = FunctionCall::new(ExprType::Age, vec![tz.clone(), tz])
+            .unwrap()
+            .into();
+        let expr = rewriter.rewrite_expr(expr);
+        assert_eq!(
+            format!("{:?}", expr),
+            "Age(AtTimeZone($0, 'Asia/Shanghai':Varchar), AtTimeZone($0, 'Asia/Shanghai':Varchar))"
+        );
+    }
+}
@@ -0,0 +1,242 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+
+use itertools::Itertools;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::{DataType, ScalarImpl};
+use risingwave_pb::expr::table_function::Type;
+use risingwave_pb::expr::TableFunction as TableFunctionProst;
+
+use super::{Expr, ExprImpl, Literal};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum TableFunctionType {
+    JsonbArrayElements,
+    RegexpMatches,
+    Unnest,
+    GenerateSeries,
+    RegexpSplitToTable,
+}
+
+impl TableFunctionType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TableFunctionType::JsonbArrayElements => "jsonb_array_elements",
+            TableFunctionType::RegexpMatches => "regexp_matches",
+            TableFunctionType::Unnest => "unnest",
+            TableFunctionType::GenerateSeries => "generate_series",
+            TableFunctionType::RegexpSplitToTable => "regexp_split_to_table",
+        }
+    }
+
+    fn to_protobuf(self) -> Type {
+        match self {
+            TableFunctionType::JsonbArrayElements => Type::JsonbArrayElements,
+            TableFunctionType::RegexpMatches => Type::RegexpMatches,
+            TableFunctionType::Unnest => Type::Unnest,
+            TableFunctionType::GenerateSeries => Type::GenerateSeries,
+            TableFunctionType::RegexpSplitToTable => Type::RegexpSplitToTable,
+        }
+    }
+}
+
+impl FromStr for TableFunctionType {
+    type Err = ();
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("jsonb_array_elements") {
+            Ok(TableFunctionType::JsonbArrayElements)
+        } else if s.eq_ignore_ascii_case("regexp_matches") {
+            Ok(TableFunctionType::RegexpMatches)
+        } else if s.eq_ignore_ascii_case("unnest") {
+            Ok(TableFunctionType::Unnest)
+        } else if s.eq_ignore_ascii_case("generate_series") {
+            Ok(TableFunctionType::GenerateSeries)
+        } else if s.eq_ignore_ascii_case("regexp_split_to_table") {
+            Ok(TableFunctionType::RegexpSplitToTable)
+        } else {
+            Err(())
+        }
+    }
+}
+
+/// A table function (a.k.a. set-returning function), which returns zero or more rows for each
+/// input row.
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct TableFunction {
+    pub args: Vec<ExprImpl>,
+    pub return_type: DataType,
+    pub function_type: TableFunctionType,
+}
+
+impl TableFunction {
+    /// Create a table function and infer its return type. Returns error if the arguments are
+    /// invalid.
+    pub fn new(function_type: TableFunctionType, args: Vec<ExprImpl>) -> Result<Self> {
+        let invalid = |args: &[ExprImpl]| {
+            let args = args
+                .iter()
+                .map(|e| format!("{:?}", e.return_type()))
+                .join(", ");
+            ErrorCode::BindError(format!(
+                "Invalid arguments for table function: {}({})",
+                function_type.name(),
+                args
+            ))
+        };
+        if args.iter().any(|arg| arg.has_table_function()) {
+            return Err(ErrorCode::NotImplemented(
+                "nested set-returning functions".to_string(),
+                None.into(),
+            )
+            .into());
+        }
+        match function_type {
+            TableFunctionType::JsonbArrayElements => {
+                if args.len() != 1 {
+                    return Err(invalid(&args).into());
+                }
+                let args = args
+                    .into_iter()
+                    .map(|arg| arg.cast_assign(DataType::Jsonb))
+                    .try_collect()?;
+                Ok(Self {
+                    args,
+                    return_type: DataType::Jsonb,
+                    function_type,
+                })
+            }
+            TableFunctionType::RegexpMatches => {
+                if args.len() != 2 && args.len() != 3 {
+                    return Err(invalid(&args).into());
+                }
+                let args = args
+                    .into_iter()
+                    .map(|arg| arg.cast_implicit(DataType::Varchar))
+                    .try_collect()?;
+                Ok(Self {
+                    args,
+                    return_type: DataType::List {
+                        datatype: Box::new(DataType::Varchar),
+                    },
+                    function_type,
+                })
+            }
+            TableFunctionType::RegexpSplitToTable => {
+                if args.len() != 2 && args.len() != 3 {
+                    return Err(invalid(&args).into());
+                }
+                let args = args
+                    .into_iter()
+                    .map(|arg| arg.cast_implicit(DataType::Varchar))
+                    .try_collect()?;
+                Ok(Self {
+                    args,
+                    return_type: DataType::Varchar,
+                    function_type,
+                })
+            }
+            TableFunctionType::Unnest => {
+                if args.len() != 1 {
+                    return Err(invalid(&args).into());
+                }
+                // A multi-dimensional array is flattened into its innermost elements.
+                let mut return_type = args[0].return_type();
+                if !matches!(return_type, DataType::List { .. }) {
+                    return Err(invalid(&args).into());
+                }
+                while let DataType::List { datatype } = return_type {
+                    return_type = *datatype;
+                }
+                Ok(Self {
+                    args,
+                    return_type,
+                    function_type,
+                })
+            }
+            TableFunctionType::GenerateSeries => {
+                let mut args = args;
+                let types = args.iter().map(|arg| arg.return_type()).collect_vec();
+                // generate_series ( start int, stop int [, step int ] ) or
+                // generate_series ( start timestamp, stop timestamp, step interval )
+                let is_int = |ty: &DataType| {
+                    matches!(ty, DataType::Int16 | DataType::Int32 | DataType::Int64)
+                };
+                let return_type = match types.as_slice() {
+                    [start, stop, DataType::Interval]
+                        if *start == DataType::Timestamp || *stop == DataType::Timestamp =>
+                    {
+                        DataType::Timestamp
+                    }
+                    [_, _] | [_, _, _] if types.iter().all(is_int) => {
+                        if types.contains(&DataType::Int64) {
+                            DataType::Int64
+                        } else {
+                            DataType::Int32
+                        }
+                    }
+                    _ => return Err(invalid(&args).into()),
+                };
+                if args.len() == 2 {
+                    args.push(Literal::new(Some(ScalarImpl::Int32(1)), DataType::Int32).into());
+                }
+                let args = args
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, arg)| match idx {
+                        2 if return_type == DataType::Timestamp => Ok(arg),
+                        _ => arg.cast_implicit(return_type.clone()),
+                    })
+                    .try_collect()?;
+                Ok(Self {
+                    args,
+                    return_type,
+                    function_type,
+                })
+            }
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.function_type.name()
+    }
+
+    pub fn to_protobuf(&self) -> TableFunctionProst {
+        TableFunctionProst {
+            function_type: self.function_type.to_protobuf() as i32,
+            args: self.args.iter().map(|arg| arg.to_expr_proto()).collect(),
+            return_type: Some(self.return_type.to_protobuf()),
+        }
+    }
+}
+
+impl std::fmt::Debug for TableFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("TableFunction")
+                .field("function_type", &self.function_type)
+                .field("return_type", &self.return_type)
+                .field("args", &self.args)
+                .finish()
+        } else {
+            let mut builder = f.debug_tuple(self.name());
+            self.args.iter().for_each(|arg| {
+                builder.field(arg);
+            });
+            builder.finish()
+        }
+    }
+}
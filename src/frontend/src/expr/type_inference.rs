@@ -43,6 +43,10 @@ enum DataTypeName {
     Interval,
     Struct,
     List,
+    Jsonb,
+    Bytea,
+    Uuid,
+    Char,
 }
 
 fn name_of(ty: &DataType) -> DataTypeName {
@@ -62,6 +66,10 @@ fn name_of(ty: &DataType) -> DataTypeName {
         DataType::Interval => DataTypeName::Interval,
         DataType::Struct { .. } => DataTypeName::Struct,
         DataType::List { .. } => DataTypeName::List,
+        DataType::Jsonb => DataTypeName::Jsonb,
+        DataType::Bytea => DataTypeName::Bytea,
+        DataType::Uuid => DataTypeName::Uuid,
+        DataType::Char { .. } => DataTypeName::Char,
     }
 }
 
@@ -91,6 +99,11 @@ pub fn infer_type(func_type: ExprType, inputs_type: Vec<DataType>) -> Result<Dat
         DataTypeName::List => DataType::List {
             datatype: Box::new(DataType::Int32),
         },
+        DataTypeName::Jsonb => DataType::Jsonb,
+        DataTypeName::Bytea => DataType::Bytea,
+        DataTypeName::Uuid => DataType::Uuid,
+        // No function returns `char(n)`, as its inputs are casted to `varchar` beforehand.
+        DataTypeName::Char => unreachable!(),
     })
 }
 
@@ -183,6 +196,9 @@ fn build_type_derive_map() -> HashMap<FuncSign, DataTypeName> {
         T::Timestampz,
         T::Time,
         T::Interval,
+        T::Jsonb,
+        T::Bytea,
+        T::Uuid,
     ];
     let num_types = [
         T::Int16,
@@ -220,7 +236,7 @@ fn build_type_derive_map() -> HashMap<FuncSign, DataTypeName> {
     build_binary_cmp_funcs(&mut map, cmp_exprs, &[T::Date, T::Timestamp, T::Timestampz]);
     build_binary_cmp_funcs(&mut map, cmp_exprs, &[T::Time, T::Interval]);
     for e in cmp_exprs {
-        for t in [T::Boolean, T::Varchar] {
+        for t in [T::Boolean, T::Varchar, T::Uuid] {
             map.insert(FuncSign::new(*e, vec![t, t]), T::Boolean);
         }
     }
@@ -242,6 +258,56 @@ fn build_type_derive_map() -> HashMap<FuncSign, DataTypeName> {
         FuncSign::new(E::RoundDigit, vec![T::Decimal, T::Int32]),
         T::Decimal,
     );
+    // Math functions are only defined on `double precision` and `numeric`, to which the other
+    // numeric types are casted. See `FunctionCall::new`.
+    build_unary_atm_funcs(
+        &mut map,
+        &[
+            E::Sqrt,
+            E::Exp,
+            E::Ln,
+            E::Log10,
+            E::Floor,
+            E::Ceil,
+            E::Trunc,
+            E::Sign,
+        ],
+        &[T::Float64, T::Decimal],
+    );
+    for e in [
+        E::Cbrt,
+        E::Round,
+        E::Sin,
+        E::Cos,
+        E::Tan,
+        E::Cot,
+        E::Asin,
+        E::Acos,
+        E::Atan,
+    ] {
+        map.insert(FuncSign::new(e, vec![T::Float64]), T::Float64);
+    }
+    for t in [T::Float64, T::Decimal] {
+        map.insert(FuncSign::new(E::Pow, vec![t, t]), t);
+    }
+    map.insert(
+        FuncSign::new(E::Atan2, vec![T::Float64, T::Float64]),
+        T::Float64,
+    );
+
+    // bitwise expressions
+    let int_types = [T::Int16, T::Int32, T::Int64];
+    build_unary_atm_funcs(&mut map, &[E::BitwiseNot], &int_types);
+    build_binary_atm_funcs(
+        &mut map,
+        &[E::BitwiseAnd, E::BitwiseOr, E::BitwiseXor],
+        &int_types,
+    );
+    for e in [E::BitwiseShiftLeft, E::BitwiseShiftRight] {
+        for t in int_types {
+            map.insert(FuncSign::new(e, vec![t, T::Int32]), t);
+        }
+    }
 
     // temporal expressions
     for (base, delta) in [
@@ -270,9 +336,31 @@ fn build_type_derive_map() -> HashMap<FuncSign, DataTypeName> {
         map.insert(FuncSign::new(E::Divide, vec![T::Interval, t]), T::Interval);
     }
 
-    for t in [T::Timestamp, T::Time, T::Date] {
+    for t in [T::Timestamp, T::Timestampz, T::Time, T::Date] {
         map.insert(FuncSign::new(E::Extract, vec![T::Varchar, t]), T::Decimal);
     }
+    for t in [T::Timestamp, T::Timestampz] {
+        map.insert(FuncSign::new(E::DateTrunc, vec![T::Varchar, t]), t);
+        map.insert(FuncSign::new(E::ToChar, vec![t, T::Varchar]), T::Varchar);
+        map.insert(FuncSign::new(E::Age, vec![t, t]), T::Interval);
+    }
+    map.insert(
+        FuncSign::new(E::ToTimestamp, vec![T::Float64]),
+        T::Timestampz,
+    );
+    map.insert(
+        FuncSign::new(E::MakeDate, vec![T::Int32, T::Int32, T::Int32]),
+        T::Date,
+    );
+    map.insert(FuncSign::new(E::Now, vec![]), T::Timestampz);
+    map.insert(
+        FuncSign::new(E::AtTimeZone, vec![T::Timestamp, T::Varchar]),
+        T::Timestampz,
+    );
+    map.insert(
+        FuncSign::new(E::AtTimeZone, vec![T::Timestampz, T::Varchar]),
+        T::Timestamp,
+    );
     for t in [T::Timestamp, T::Date] {
         map.insert(
             FuncSign::new(E::TumbleStart, vec![t, T::Interval]),
@@ -281,7 +369,15 @@ fn build_type_derive_map() -> HashMap<FuncSign, DataTypeName> {
     }
 
     // string expressions
-    for e in [E::Trim, E::Ltrim, E::Rtrim, E::Lower, E::Upper] {
+    for e in [
+        E::Trim,
+        E::Ltrim,
+        E::Rtrim,
+        E::Lower,
+        E::Upper,
+        E::Reverse,
+        E::Initcap,
+    ] {
         map.insert(FuncSign::new(e, vec![T::Varchar]), T::Varchar);
     }
     for e in [E::Trim, E::Ltrim, E::Rtrim] {
@@ -301,6 +397,23 @@ fn build_type_derive_map() -> HashMap<FuncSign, DataTypeName> {
             T::Varchar,
         );
     }
+    map.insert(
+        FuncSign::new(E::SplitPart, vec![T::Varchar, T::Varchar, T::Int32]),
+        T::Varchar,
+    );
+    for e in [E::Lpad, E::Rpad] {
+        map.insert(
+            FuncSign::new(e, vec![T::Varchar, T::Int32, T::Varchar]),
+            T::Varchar,
+        );
+    }
+    for e in [E::Repeat, E::Left, E::Right] {
+        map.insert(FuncSign::new(e, vec![T::Varchar, T::Int32]), T::Varchar);
+    }
+    map.insert(
+        FuncSign::new(E::ConcatOp, vec![T::Varchar, T::Varchar]),
+        T::Varchar,
+    );
     for e in [E::Length, E::Ascii] {
         map.insert(FuncSign::new(e, vec![T::Varchar]), T::Int32);
     }
@@ -308,10 +421,53 @@ fn build_type_derive_map() -> HashMap<FuncSign, DataTypeName> {
         FuncSign::new(E::Position, vec![T::Varchar, T::Varchar]),
         T::Int32,
     );
+    for e in [E::Like, E::Ilike, E::SimilarTo, E::RegexpLike] {
+        map.insert(FuncSign::new(e, vec![T::Varchar, T::Varchar]), T::Boolean);
+    }
+    map.insert(
+        FuncSign::new(E::RegexpLike, vec![T::Varchar, T::Varchar, T::Varchar]),
+        T::Boolean,
+    );
+    map.insert(
+        FuncSign::new(E::RegexpReplace, vec![T::Varchar; 3]),
+        T::Varchar,
+    );
+    map.insert(
+        FuncSign::new(E::RegexpReplace, vec![T::Varchar; 4]),
+        T::Varchar,
+    );
+
+    // bytea expressions
+    map.insert(
+        FuncSign::new(E::Encode, vec![T::Bytea, T::Varchar]),
+        T::Varchar,
+    );
+    map.insert(
+        FuncSign::new(E::Decode, vec![T::Varchar, T::Varchar]),
+        T::Bytea,
+    );
+    for t in [T::Varchar, T::Bytea] {
+        map.insert(FuncSign::new(E::Md5, vec![t]), T::Varchar);
+    }
+    map.insert(FuncSign::new(E::Sha256, vec![T::Bytea]), T::Bytea);
+
+    // jsonb expressions
+    for (e, ret) in [
+        (E::JsonbAccessInner, T::Jsonb),
+        (E::JsonbAccessStr, T::Varchar),
+    ] {
+        map.insert(FuncSign::new(e, vec![T::Jsonb, T::Varchar]), ret);
+        map.insert(FuncSign::new(e, vec![T::Jsonb, T::Int32]), ret);
+    }
+    map.insert(
+        FuncSign::new(E::JsonbAccessPath, vec![T::Jsonb, T::Varchar]),
+        T::Jsonb,
+    );
     map.insert(
-        FuncSign::new(E::Like, vec![T::Varchar, T::Varchar]),
+        FuncSign::new(E::JsonbExists, vec![T::Jsonb, T::Varchar]),
         T::Boolean,
     );
+    map.insert(FuncSign::new(E::JsonbTypeof, vec![T::Jsonb]), T::Varchar);
 
     map
 }
@@ -414,6 +570,11 @@ fn build_cast_map() -> HashMap<(DataTypeName, DataTypeName), CastContext> {
     // As we do not support size parameters in types, there are no sizing casts.
     m.insert((T::Boolean, T::Boolean), CastContext::Explicit);
     m.insert((T::Varchar, T::Varchar), CastContext::Explicit);
+    m.insert((T::Jsonb, T::Jsonb), CastContext::Explicit);
+    m.insert((T::Bytea, T::Bytea), CastContext::Explicit);
+    m.insert((T::Uuid, T::Uuid), CastContext::Explicit);
+    // Unlike the above, casting between `char(n)` of different lengths is a sizing cast.
+    m.insert((T::Char, T::Char), CastContext::Assign);
 
     // Casting to and from string type.
     for t in [
@@ -429,6 +590,9 @@ fn build_cast_map() -> HashMap<(DataTypeName, DataTypeName), CastContext> {
         T::Timestampz,
         T::Time,
         T::Interval,
+        T::Jsonb,
+        T::Bytea,
+        T::Uuid,
     ] {
         m.insert((t, T::Varchar), CastContext::Assign);
         // Casting from string is explicit-only in PG.
@@ -446,6 +610,10 @@ fn build_cast_map() -> HashMap<(DataTypeName, DataTypeName), CastContext> {
     m.insert((T::Timestampz, T::Time), CastContext::Assign);
     m.insert((T::Boolean, T::Int32), CastContext::Explicit);
     m.insert((T::Int32, T::Boolean), CastContext::Explicit);
+    // `char(n)` and `varchar` are binary coercible in PG. Casting to `char(n)` pads or truncates
+    // the string, so it is not implicit here to avoid surprising `least_restrictive` results.
+    m.insert((T::Char, T::Varchar), CastContext::Implicit);
+    m.insert((T::Varchar, T::Char), CastContext::Assign);
     m
 }
 
@@ -16,7 +16,9 @@ use fixedbitset::FixedBitSet;
 use risingwave_common::types::ScalarImpl;
 use risingwave_pb::expr::expr_node::Type;
 
-use super::{ExprImpl, ExprRewriter, ExprVisitor, FunctionCall, InputRef};
+use super::{
+    CorrelatedInputRef, Expr, ExprImpl, ExprRewriter, ExprVisitor, FunctionCall, InputRef,
+};
 use crate::expr::ExprType;
 
 fn split_expr_by(expr: ExprImpl, op: ExprType, rets: &mut Vec<ExprImpl>) {
@@ -206,6 +208,30 @@ fn boolean_constant_fold_or(constant_lhs: ExprImpl, rhs: ExprImpl) -> ExprImpl {
     }
 }
 
+/// Lift the expression out of the right child of an Apply: [`CorrelatedInputRef`]s referencing the
+/// Apply's left child become [`InputRef`]s, and the others are one level closer.
+pub fn lift_correlated_input_ref(expr: ExprImpl) -> ExprImpl {
+    let mut lift = LiftCorrelatedInputRef {};
+    lift.rewrite_expr(expr)
+}
+
+struct LiftCorrelatedInputRef {}
+
+impl ExprRewriter for LiftCorrelatedInputRef {
+    fn rewrite_correlated_input_ref(
+        &mut self,
+        correlated_input_ref: CorrelatedInputRef,
+    ) -> ExprImpl {
+        let data_type = correlated_input_ref.return_type();
+        match correlated_input_ref.depth() {
+            1 => InputRef::new(correlated_input_ref.index(), data_type).into(),
+            depth => {
+                CorrelatedInputRef::new(correlated_input_ref.index(), data_type, depth - 1).into()
+            }
+        }
+    }
+}
+
 /// Expand [`Type::Not`] expressions.
 /// e.g. Not(A And B) will become (Not A) Or (Not B)
 pub fn push_down_not(expr: ExprImpl) -> ExprImpl {
@@ -0,0 +1,357 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::catalog::{ColumnDesc, ColumnId};
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::types::{DataType, Datum};
+use risingwave_pb::ddl_service::alter_relation_request::{
+    AddColumn, DropColumn, Operation as AlterRelationOperation, RenameColumn, RenameRelation,
+};
+use risingwave_pb::plan_common::ColumnCatalog as ProstColumnCatalog;
+use risingwave_pb::stream_plan::source_node::SourceType;
+use risingwave_sqlparser::ast::{
+    AlterTableOperation, ColumnDef, ColumnOption, DataType as AstDataType, Expr, Ident, ObjectName,
+};
+
+use crate::binder::expr::bind_data_type;
+use crate::binder::Binder;
+use crate::catalog::column_catalog::ColumnCatalog;
+use crate::catalog::table_catalog::TableCatalog;
+use crate::catalog::{check_valid_column_name, CatalogError};
+use crate::session::{OptimizerContext, SessionImpl};
+
+pub async fn handle_alter_table(
+    context: OptimizerContext,
+    table_name: ObjectName,
+    operation: AlterTableOperation,
+) -> Result<PgResponse> {
+    let session = context.session_ctx;
+    let (schema_name, table_name) = session.resolve_relation_name(table_name)?;
+
+    let table = {
+        let reader = session.env().catalog_reader().read_guard();
+        if let Ok(s) = reader.get_source_by_name(session.database(), &schema_name, &table_name) {
+            if s.source_type == SourceType::Source {
+                return Err(RwError::from(ErrorCode::InvalidInputSyntax(
+                    "ALTER TABLE on a source is unsupported.".to_owned(),
+                )));
+            }
+        }
+
+        let table = reader.get_table_by_name(session.database(), &schema_name, &table_name)?;
+        if table.associated_source_id().is_none() {
+            return Err(RwError::from(ErrorCode::InvalidInputSyntax(
+                "Use `ALTER MATERIALIZED VIEW` to alter a materialized view.".to_owned(),
+            )));
+        }
+        table.clone()
+    };
+
+    let operation = match operation {
+        AlterTableOperation::AddColumn { column_def } => {
+            bind_add_column(&session, &table, column_def)?
+        }
+        AlterTableOperation::DropColumn {
+            column_name,
+            if_exists,
+            cascade,
+        } => {
+            if cascade {
+                return Err(ErrorCode::NotImplemented(
+                    "DROP COLUMN with CASCADE".to_owned(),
+                    None.into(),
+                )
+                .into());
+            }
+            let column = match get_column_by_name(&table, &column_name) {
+                Ok(column) => column,
+                Err(_) if if_exists => {
+                    return Ok(PgResponse::empty_result_with_notice(
+                        StatementType::ALTER_TABLE,
+                        format!(
+                            "column \"{}\" of relation \"{}\" does not exist, skipping",
+                            column_name.value,
+                            table.name()
+                        ),
+                    ))
+                }
+                Err(e) => return Err(e),
+            };
+            AlterRelationOperation::DropColumn(DropColumn {
+                column_id: column.column_id().get_id(),
+            })
+        }
+        operation => bind_rename(&session, &schema_name, &table, operation)?,
+    };
+
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer.alter_relation(table.id(), operation).await?;
+
+    Ok(PgResponse::empty_result(StatementType::ALTER_TABLE))
+}
+
+pub async fn handle_alter_mv(
+    context: OptimizerContext,
+    mv_name: ObjectName,
+    operation: AlterTableOperation,
+) -> Result<PgResponse> {
+    let session = context.session_ctx;
+    let (schema_name, mv_name) = session.resolve_relation_name(mv_name)?;
+
+    let table = {
+        let reader = session.env().catalog_reader().read_guard();
+        let table = reader.get_table_by_name(session.database(), &schema_name, &mv_name)?;
+        if table.associated_source_id().is_some() {
+            return Err(RwError::from(ErrorCode::InvalidInputSyntax(
+                "Use `ALTER TABLE` to alter a table.".to_owned(),
+            )));
+        }
+        table.clone()
+    };
+
+    let operation = bind_rename(&session, &schema_name, &table, operation)?;
+
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer.alter_relation(table.id(), operation).await?;
+
+    Ok(PgResponse::empty_result(
+        StatementType::ALTER_MATERIALIZED_VIEW,
+    ))
+}
+
+/// Binds the column to add. The column id is left to be assigned by meta.
+fn bind_add_column(
+    session: &SessionImpl,
+    table: &TableCatalog,
+    column_def: ColumnDef,
+) -> Result<AlterRelationOperation> {
+    let ColumnDef {
+        name,
+        data_type,
+        options,
+        ..
+    } = column_def;
+
+    check_valid_column_name(&name.value)?;
+    if get_column_by_name(table, &name).is_ok() {
+        return Err(CatalogError::Duplicated("column", name.value).into());
+    }
+    if matches!(data_type, AstDataType::Struct(_)) {
+        return Err(
+            ErrorCode::NotImplemented("ADD COLUMN of struct type".to_owned(), None.into()).into(),
+        );
+    }
+    let data_type = bind_data_type(&data_type)?;
+
+    let mut default_value = None;
+    for option in options {
+        match option.option {
+            ColumnOption::Null => {}
+            ColumnOption::Default(_) if matches!(data_type, DataType::List { .. }) => {
+                return Err(ErrorCode::NotImplemented(
+                    "DEFAULT of list type".to_owned(),
+                    None.into(),
+                )
+                .into())
+            }
+            ColumnOption::Default(expr) => {
+                default_value = eval_column_default(session, expr, data_type.clone())?;
+            }
+            option => {
+                return Err(ErrorCode::NotImplemented(
+                    format!("ADD COLUMN with option {}", option),
+                    None.into(),
+                )
+                .into())
+            }
+        }
+    }
+
+    let column_desc = ColumnDesc {
+        data_type,
+        column_id: ColumnId::new(0),
+        name: name.value,
+        field_descs: vec![],
+        type_name: "".to_string(),
+        default_value,
+    };
+    Ok(AlterRelationOperation::AddColumn(AddColumn {
+        column: Some(ProstColumnCatalog {
+            column_desc: Some(column_desc.to_protobuf()),
+            is_hidden: false,
+        }),
+    }))
+}
+
+/// Evaluates the constant `DEFAULT` expression of a column in frontend, so that its value is
+/// fixed once the column is added.
+fn eval_column_default(session: &SessionImpl, expr: Expr, data_type: DataType) -> Result<Datum> {
+    let expr = {
+        let mut binder = Binder::new(
+            session.env().catalog_reader().read_guard(),
+            session.database().to_string(),
+            session.user_name().to_string(),
+            session.search_path(),
+            session.session_timezone(),
+        );
+        binder.bind_column_default(expr, data_type)?
+    };
+    expr.eval_const()
+}
+
+/// Binds the renaming of a table or materialized view, or of one of its columns.
+fn bind_rename(
+    session: &SessionImpl,
+    schema_name: &str,
+    table: &TableCatalog,
+    operation: AlterTableOperation,
+) -> Result<AlterRelationOperation> {
+    match operation {
+        AlterTableOperation::RenameColumn {
+            old_column_name,
+            new_column_name,
+        } => {
+            let column = get_column_by_name(table, &old_column_name)?;
+            check_valid_column_name(&new_column_name.value)?;
+            if get_column_by_name(table, &new_column_name).is_ok() {
+                return Err(CatalogError::Duplicated("column", new_column_name.value).into());
+            }
+            Ok(AlterRelationOperation::RenameColumn(RenameColumn {
+                column_id: column.column_id().get_id(),
+                new_name: new_column_name.value,
+            }))
+        }
+        AlterTableOperation::RenameTable { table_name } => {
+            let (new_schema_name, new_name) = Binder::resolve_schema_qualified_name(table_name)?;
+            if new_schema_name.map_or(false, |new_schema_name| new_schema_name != schema_name) {
+                return Err(ErrorCode::NotImplemented(
+                    "renaming a relation into another schema".to_owned(),
+                    None.into(),
+                )
+                .into());
+            }
+            session
+                .env()
+                .catalog_reader()
+                .read_guard()
+                .check_relation_name_duplicated(session.database(), schema_name, &new_name)?;
+            Ok(AlterRelationOperation::RenameRelation(RenameRelation {
+                new_name,
+            }))
+        }
+        operation => Err(ErrorCode::NotImplemented(
+            format!("ALTER {} {}", table.name(), operation),
+            None.into(),
+        )
+        .into()),
+    }
+}
+
+fn get_column_by_name<'a>(table: &'a TableCatalog, name: &Ident) -> Result<&'a ColumnCatalog> {
+    table
+        .columns()
+        .iter()
+        .find(|c| !c.is_hidden() && c.name() == name.value)
+        .ok_or_else(|| CatalogError::NotFound("column", name.value.clone()).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::{DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME};
+    use risingwave_common::types::ScalarImpl;
+
+    use super::*;
+    use crate::test_utils::LocalFrontend;
+
+    #[tokio::test]
+    async fn test_alter_table_handler() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend
+            .run_sql("create table t (v1 int, v2 int);")
+            .await
+            .unwrap();
+        frontend
+            .run_sql("alter table t add column v3 bigint default 1 + 1;")
+            .await
+            .unwrap();
+        frontend
+            .run_sql("alter table t drop column v2;")
+            .await
+            .unwrap();
+        frontend
+            .run_sql("alter table t drop column if exists v2;")
+            .await
+            .unwrap();
+        frontend
+            .run_sql("alter table t rename column v1 to w1;")
+            .await
+            .unwrap();
+        frontend
+            .run_sql("alter table t rename to t2;")
+            .await
+            .unwrap();
+
+        assert!(frontend
+            .run_sql("alter table t2 drop column v2;")
+            .await
+            .is_err());
+        assert!(frontend
+            .run_sql("alter table t2 add column w1 int;")
+            .await
+            .is_err());
+        assert!(frontend
+            .run_sql("alter table t2 add column v4 int default v3;")
+            .await
+            .is_err());
+
+        let session = frontend.session_ref();
+        let catalog_reader = session.env().catalog_reader();
+        let reader = catalog_reader.read_guard();
+        assert!(reader
+            .get_table_by_name(DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME, "t")
+            .is_err());
+        let table = reader
+            .get_table_by_name(DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME, "t2")
+            .unwrap();
+        let columns = table
+            .columns()
+            .iter()
+            .filter(|c| !c.is_hidden())
+            .map(|c| {
+                (
+                    c.name().to_string(),
+                    c.data_type().clone(),
+                    c.column_desc.default_value.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            columns,
+            vec![
+                ("w1".to_string(), DataType::Int32, None),
+                (
+                    "v3".to_string(),
+                    DataType::Int64,
+                    Some(ScalarImpl::Int64(2))
+                ),
+            ]
+        );
+
+        let source = reader
+            .get_source_by_name(DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME, "t2")
+            .unwrap();
+        assert_eq!(source.columns.len(), 4);
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::catalog::is_default_super_user;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_sqlparser::ast::{AlterUserMode, AlterUserStatement};
+
+use super::create_user::apply_user_options;
+use crate::binder::Binder;
+use crate::catalog::CatalogError;
+use crate::session::OptimizerContext;
+
+pub async fn handle_alter_user(
+    context: OptimizerContext,
+    stmt: AlterUserStatement,
+) -> Result<PgResponse> {
+    let session = context.session_ctx;
+    session.check_super_user("alter users")?;
+    let user_name = Binder::resolve_user_name(stmt.user_name)?;
+
+    let mut user = {
+        let catalog_reader = session.env().catalog_reader();
+        let reader = catalog_reader.read_guard();
+        let user = reader.get_user_by_name(&user_name)?.to_prost();
+        if let AlterUserMode::Rename(new_name) = &stmt.mode {
+            let new_name = Binder::resolve_user_name(new_name.clone())?;
+            if reader.get_user_by_name(&new_name).is_ok() {
+                return Err(CatalogError::Duplicated("user", new_name).into());
+            }
+        }
+        user
+    };
+
+    match stmt.mode {
+        AlterUserMode::Options(options) => apply_user_options(&mut user, &options),
+        AlterUserMode::Rename(new_name) => {
+            if is_default_super_user(&user_name) {
+                return Err(ErrorCode::PermissionDenied(format!(
+                    "cannot rename the default super user {}",
+                    user_name
+                ))
+                .into());
+            }
+            user.name = Binder::resolve_user_name(new_name)?;
+        }
+    }
+
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer.update_user(user).await?;
+    Ok(PgResponse::empty_result(StatementType::ALTER_USER))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::LocalFrontend;
+
+    #[tokio::test]
+    async fn test_alter_user() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        let session = frontend.session_ref();
+        let catalog_reader = session.env().catalog_reader();
+
+        frontend.run_sql("CREATE USER user1").await.unwrap();
+        frontend
+            .run_sql("ALTER USER user1 WITH CREATEDB NOLOGIN")
+            .await
+            .unwrap();
+        frontend
+            .run_sql("ALTER USER user1 RENAME TO user2")
+            .await
+            .unwrap();
+
+        let reader = catalog_reader.read_guard();
+        assert!(reader.get_user_by_name("user1").is_err());
+        let user = reader.get_user_by_name("user2").unwrap();
+        assert!(user.can_create_db);
+        assert!(!user.can_login);
+    }
+}
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use pgwire::pg_response::{PgResponse, StatementType};
-use risingwave_common::error::Result;
+use risingwave_common::error::{ErrorCode, Result};
 use risingwave_sqlparser::ast::ObjectName;
 
 use crate::binder::Binder;
@@ -42,6 +42,13 @@ pub async fn handle_create_database(
                 Err(CatalogError::Duplicated("database", database_name).into())
             };
         }
+        let user = reader.get_user_by_name(session.user_name())?;
+        if !user.is_superuser && !user.can_create_db {
+            return Err(ErrorCode::PermissionDenied(
+                "permission denied to create database".to_string(),
+            )
+            .into());
+        }
     }
 
     let catalog_writer = session.env().catalog_writer();
@@ -14,15 +14,21 @@
 
 use std::collections::HashMap;
 use std::rc::Rc;
+use std::sync::Arc;
 
 use fixedbitset::FixedBitSet;
+use futures_async_stream::for_await;
 use itertools::Itertools;
 use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::types::ScalarImpl;
 use risingwave_pb::catalog::Table as ProstTable;
-use risingwave_sqlparser::ast::{ObjectName, OrderByExpr};
+use risingwave_pb::user::grant_privilege::{Action, Target};
+use risingwave_sqlparser::ast::{Ident, ObjectName, OrderByExpr, Statement};
+use risingwave_sqlparser::parser::Parser;
 
 use crate::binder::Binder;
+use crate::handler::query::distribute_execute;
 use crate::optimizer::plan_node::{LogicalScan, StreamTableScan};
 use crate::optimizer::property::{Distribution, FieldOrder, Order};
 use crate::optimizer::{PlanRef, PlanRoot};
@@ -35,6 +41,8 @@ pub(crate) fn gen_create_index_plan(
     index_name: ObjectName,
     table_name: ObjectName,
     columns: Vec<OrderByExpr>,
+    include: Vec<Ident>,
+    unique: bool,
 ) -> Result<(PlanRef, ProstTable)> {
     let columns = columns
         .iter()
@@ -67,7 +75,18 @@ pub(crate) fn gen_create_index_plan(
         })
         .try_collect::<_, Vec<_>, _>()?;
 
-    let (schema_name, table_name) = Binder::resolve_table_name(table_name)?;
+    let (schema_name, table_name) = session.resolve_relation_name(table_name)?;
+    // As in Postgres, an index is created in the schema of its table.
+    let index_table_name = match Binder::resolve_schema_qualified_name(index_name)? {
+        (Some(index_schema_name), index_table_name) if index_schema_name != schema_name => {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "index \"{}\" must be in the same schema as table \"{}\"",
+                index_table_name, table_name
+            ))
+            .into())
+        }
+        (_, index_table_name) => index_table_name,
+    };
     let catalog_reader = session.env().catalog_reader();
     let table = catalog_reader
         .read_guard()
@@ -91,48 +110,97 @@ pub(crate) fn gen_create_index_plan(
                 .ok_or_else(|| ErrorCode::ItemNotFound(x).into())
         })
         .try_collect::<_, Vec<_>, RwError>()?;
+    let include_cols = include
+        .iter()
+        .map(|x| {
+            table_desc_map
+                .get(&x.value)
+                .cloned()
+                .ok_or_else(|| ErrorCode::ItemNotFound(x.value.clone()).into())
+        })
+        .try_collect::<_, Vec<_>, RwError>()?;
+
+    // Without `INCLUDE`, the index covers all columns of the table. Otherwise, it only stores the
+    // key columns, the included columns and the primary key of the table.
+    let (index_cols, visible_cols) = if include.is_empty() {
+        let index_cols = (0..table_desc.columns.len()).collect_vec();
+        let visible_cols = FixedBitSet::from_iter(
+            (0..table_desc.columns.len()).filter(|idx| !table.columns[*idx].is_hidden),
+        );
+        (index_cols, visible_cols)
+    } else {
+        let mut visible_cols = FixedBitSet::with_capacity(table_desc.columns.len());
+        visible_cols.extend(arrange_keys.iter().chain(include_cols.iter()).copied());
+        let mut index_cols = visible_cols.clone();
+        index_cols.extend(table_desc.pks.iter().copied());
+        (index_cols.ones().collect_vec(), visible_cols)
+    };
+    let index_column_descs = index_cols
+        .iter()
+        .map(|idx| table_desc.columns[*idx].clone())
+        .collect_vec();
 
     // Manually assemble the materialization plan for the index MV.
     let materialize = {
         let scan_node = StreamTableScan::new(LogicalScan::new(
             table_name,
-            (0..table_desc.columns.len()).into_iter().collect(),
+            index_cols.clone(),
             table_desc,
             // indexes are only used by DeltaJoin rule, and we don't need to provide them here.
             vec![],
             context,
         ));
-        let mut required_cols = FixedBitSet::with_capacity(scan_node.schema().len());
-        required_cols.toggle_range(..);
-        required_cols.toggle(0);
-        let mut out_names = scan_node.schema().names();
-        out_names.remove(0);
+        let mut required_cols = FixedBitSet::with_capacity(index_cols.len());
+        required_cols.extend(
+            index_cols
+                .iter()
+                .positions(|idx| visible_cols.contains(*idx)),
+        );
+        let out_names = scan_node
+            .schema()
+            .names()
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| required_cols.contains(*i))
+            .map(|(_, name)| name)
+            .collect();
+        let index_col_positions = index_cols
+            .iter()
+            .enumerate()
+            .map(|(pos, idx)| (*idx, pos))
+            .collect::<HashMap<_, _>>();
 
-        PlanRoot::new(
+        let materialize = PlanRoot::new(
             scan_node.into(),
             Distribution::AnyShard,
             Order::new(
                 arrange_keys
                     .iter()
-                    .map(|id| FieldOrder::ascending(*id))
+                    .map(|idx| FieldOrder::ascending(index_col_positions[idx]))
                     .collect(),
             ),
             required_cols,
             out_names,
         )
-        .gen_create_index_plan(index_name.to_string(), table.id())?
+        .gen_create_index_plan(index_table_name.clone(), table.id())?
+        .with_index_column_descs(index_column_descs);
+        if unique {
+            materialize.with_unique_index()
+        } else {
+            materialize
+        }
     };
 
-    let (index_schema_name, index_table_name) = Binder::resolve_table_name(index_name)?;
     let (index_database_id, index_schema_id) = session
         .env()
         .catalog_reader()
         .read_guard()
-        .check_relation_name_duplicated(
-            session.database(),
-            &index_schema_name,
-            &index_table_name,
-        )?;
+        .check_relation_name_duplicated(session.database(), &schema_name, &index_table_name)?;
+    session.check_privilege(
+        &Target::SchemaId(index_schema_id),
+        Action::Create,
+        &schema_name,
+    )?;
 
     let index_table = materialize
         .table()
@@ -143,12 +211,32 @@ pub(crate) fn gen_create_index_plan(
 
 pub async fn handle_create_index(
     context: OptimizerContext,
+    unique: bool,
+    if_not_exists: bool,
     name: ObjectName,
     table_name: ObjectName,
     columns: Vec<OrderByExpr>,
+    include: Vec<Ident>,
 ) -> Result<PgResponse> {
     let session = context.session_ctx.clone();
 
+    let (schema_name, table_name_str) = session.resolve_relation_name(table_name.clone())?;
+    if if_not_exists {
+        let (_, index_table_name) = Binder::resolve_schema_qualified_name(name.clone())?;
+        let catalog_reader = session.env().catalog_reader();
+        let reader = catalog_reader.read_guard();
+        if reader
+            .get_schema_by_name(session.database(), &schema_name)?
+            .get_table_by_name(&index_table_name)
+            .is_some()
+        {
+            return Ok(PgResponse::empty_result_with_notice(
+                StatementType::CREATE_INDEX,
+                format!("relation \"{}\" already exists, skipping", index_table_name),
+            ));
+        }
+    }
+
     let (graph, table) = {
         let (plan, table) = gen_create_index_plan(
             &session,
@@ -156,6 +244,8 @@ pub async fn handle_create_index(
             name.clone(),
             table_name.clone(),
             columns,
+            include,
+            unique,
         )?;
         let plan = plan.to_stream_prost();
         let graph = StreamFragmenter::build_graph(plan);
@@ -169,10 +259,81 @@ pub async fn handle_create_index(
         serde_json::to_string_pretty(&graph).unwrap()
     );
 
+    let index_name = table.name.clone();
     let catalog_writer = session.env().catalog_writer();
     catalog_writer
         .create_materialized_view(table, graph)
         .await?;
 
-    Ok(PgResponse::empty_result(StatementType::CREATE_TABLE))
+    if unique
+        && !check_unique_index_created(&session, &schema_name, &table_name_str, &index_name).await?
+    {
+        // The existing rows have duplicated keys, so the index is dropped.
+        let index_id = {
+            let reader = session.env().catalog_reader().read_guard();
+            reader
+                .get_table_by_name(session.database(), &schema_name, &index_name)?
+                .id()
+        };
+        catalog_writer
+            .drop_materialized_view(index_id, false)
+            .await?;
+        return Err(ErrorCode::UniqueViolation(index_name).into());
+    }
+
+    Ok(PgResponse::empty_result(StatementType::CREATE_INDEX))
+}
+
+/// Checks that the unique index `index_name` just created on table `table_name` in schema
+/// `schema_name` keeps all the rows of the table. The index drops a row whose key is taken by
+/// another row, so it has fewer rows than the table if the existing rows have duplicated keys.
+async fn check_unique_index_created(
+    session: &Arc<SessionImpl>,
+    schema_name: &str,
+    table_name: &str,
+    index_name: &str,
+) -> Result<bool> {
+    let relation = |name: &str| {
+        format!(
+            "{}.{}",
+            Ident::with_quote('"', schema_name),
+            Ident::with_quote('"', name)
+        )
+    };
+    // Both counts are read from the same snapshot in one query.
+    let sql = format!(
+        "SELECT (SELECT count(*) FROM {}) = (SELECT count(*) FROM {})",
+        relation(table_name),
+        relation(index_name)
+    );
+    let stmt = match Parser::parse_sql(&sql)
+        .map_err(|e| ErrorCode::InternalError(e.to_string()))?
+        .as_slice()
+    {
+        [stmt @ Statement::Query(_)] => stmt.clone(),
+        _ => return Err(ErrorCode::InternalError(format!("invalid query: {}", sql)).into()),
+    };
+    let bound = {
+        let mut binder = Binder::new(
+            session.env().catalog_reader().read_guard(),
+            session.database().to_string(),
+            session.user_name().to_string(),
+            session.search_path(),
+            session.session_timezone(),
+        );
+        binder.bind(stmt)?
+    };
+
+    let (data_stream, _) =
+        distribute_execute(OptimizerContext::new(session.clone()), bound).await?;
+    let mut all_rows_kept = false;
+    #[for_await]
+    for chunk in data_stream {
+        let chunk = chunk?;
+        if chunk.cardinality() > 0 {
+            all_rows_kept =
+                chunk.column_at(0).array_ref().datum_at(0) == Some(ScalarImpl::Bool(true));
+        }
+    }
+    Ok(all_rows_kept)
 }
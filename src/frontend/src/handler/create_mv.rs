@@ -12,11 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::catalog::TableId;
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_pb::catalog::Table as ProstTable;
-use risingwave_sqlparser::ast::{ObjectName, Query};
+use risingwave_pb::stream_plan::expr_error_handling::Mode as ExprErrorMode;
+use risingwave_pb::stream_plan::stream_node::NodeBody;
+use risingwave_pb::stream_plan::{ExprErrorHandling, StreamNode};
+use risingwave_pb::user::grant_privilege::{Action, Target};
+use risingwave_sqlparser::ast::{ColumnDef, DataType, Ident, ObjectName, Query, SqlOption, Value};
 
+use super::create_table::gen_create_table_plan;
 use crate::binder::{Binder, BoundSetExpr};
 use crate::optimizer::property::Distribution;
 use crate::optimizer::PlanRef;
@@ -31,17 +39,21 @@ pub fn gen_create_mv_plan(
     query: Box<Query>,
     name: ObjectName,
 ) -> Result<(PlanRef, ProstTable)> {
-    let (schema_name, table_name) = Binder::resolve_table_name(name)?;
+    let (schema_name, table_name) = session.resolve_name_for_create(name)?;
     let (database_id, schema_id) = session
         .env()
         .catalog_reader()
         .read_guard()
         .check_relation_name_duplicated(session.database(), &schema_name, &table_name)?;
+    session.check_privilege(&Target::SchemaId(schema_id), Action::Create, &schema_name)?;
 
     let bound = {
-        let mut binder = Binder::new(
+        let mut binder = Binder::new_for_stream(
             session.env().catalog_reader().read_guard(),
             session.database().to_string(),
+            session.user_name().to_string(),
+            session.search_path(),
+            session.session_timezone(),
         );
         binder.bind_query(*query)?
     };
@@ -66,25 +78,137 @@ pub fn gen_create_mv_plan(
     Ok((plan, table))
 }
 
+/// The option that decides how a materialized view handles expression evaluation errors, e.g.
+/// `CREATE MATERIALIZED VIEW mv WITH (expr_error_mode = 'null') AS ...`.
+const EXPR_ERROR_MODE_OPTION: &str = "expr_error_mode";
+
+/// Resolves the `expr_error_mode` option, which is one of `'strict'` (default), `'null'` and
+/// `'drop'`. Other options are ignored.
+fn resolve_expr_error_mode(with_options: &[SqlOption]) -> Result<ExprErrorMode> {
+    let mut mode = ExprErrorMode::Strict;
+    let options = with_options.iter().filter(|option| {
+        option
+            .name
+            .value
+            .eq_ignore_ascii_case(EXPR_ERROR_MODE_OPTION)
+    });
+    for option in options {
+        mode = match &option.value {
+            Value::SingleQuotedString(value) => match value.to_lowercase().as_str() {
+                "strict" => ExprErrorMode::Strict,
+                "null" => ExprErrorMode::SetNull,
+                "drop" => ExprErrorMode::DropRow,
+                _ => {
+                    return Err(ErrorCode::InvalidInputSyntax(format!(
+                        "invalid value for {}: \"{}\", expected 'strict', 'null' or 'drop'",
+                        EXPR_ERROR_MODE_OPTION, value
+                    ))
+                    .into())
+                }
+            },
+            _ => {
+                return Err(ErrorCode::InvalidInputSyntax(format!(
+                    "{} only supports single quoted string value",
+                    EXPR_ERROR_MODE_OPTION
+                ))
+                .into())
+            }
+        };
+    }
+    Ok(mode)
+}
+
+/// The error table of materialized view `mv` is the table `mv_errors` in the same schema, where
+/// each row is a row that failed to be evaluated by the executor.
+fn error_table_name(mv_name: &ObjectName) -> ObjectName {
+    let mut name = mv_name.clone();
+    let table_name = name.0.last_mut().unwrap();
+    table_name.value = format!("{}_errors", table_name.value);
+    name
+}
+
+/// Creates the error table of a materialized view. Returns its associated source id and table id.
+async fn create_error_table(
+    session: &Arc<SessionImpl>,
+    name: ObjectName,
+) -> Result<(u32, TableId)> {
+    let (source, table, graph) = {
+        let columns = ["executor", "input", "error"]
+            .into_iter()
+            .map(|column| ColumnDef::new(Ident::new(column), DataType::Varchar(None), None, vec![]))
+            .collect();
+        let context = OptimizerContext::new(session.clone()).into();
+        let (plan, source, table) =
+            gen_create_table_plan(session, context, name.clone(), columns, vec![])?;
+        let graph = StreamFragmenter::build_graph(plan.to_stream_prost());
+
+        (source, table, graph)
+    };
+
+    session
+        .env()
+        .catalog_writer()
+        .create_materialized_source(source, table, graph)
+        .await?;
+
+    let (schema_name, table_name) = session.resolve_name_for_create(name)?;
+    let reader = session.env().catalog_reader().read_guard();
+    let table = reader.get_table_by_name(session.database(), &schema_name, &table_name)?;
+    Ok((table.associated_source_id().unwrap().table_id(), table.id()))
+}
+
+/// Lets all the `Project` and `Filter` nodes in the plan handle evaluation errors with
+/// `error_handling`.
+fn set_expr_error_handling(stream_node: &mut StreamNode, error_handling: &ExprErrorHandling) {
+    match stream_node.node_body.as_mut().unwrap() {
+        NodeBody::Project(node) => node.error_handling = Some(error_handling.clone()),
+        NodeBody::Filter(node) => node.error_handling = Some(error_handling.clone()),
+        _ => {}
+    }
+    for input in &mut stream_node.input {
+        set_expr_error_handling(input, error_handling);
+    }
+}
+
 pub async fn handle_create_mv(
     context: OptimizerContext,
     name: ObjectName,
     query: Box<Query>,
+    with_options: Vec<SqlOption>,
 ) -> Result<PgResponse> {
     let session = context.session_ctx.clone();
+    let expr_error_mode = resolve_expr_error_mode(&with_options)?;
 
-    let (table, graph) = {
-        let (plan, table) = gen_create_mv_plan(&session, context.into(), query, name)?;
-        let stream_plan = plan.to_stream_prost();
-        let graph = StreamFragmenter::build_graph(stream_plan);
-
-        (table, graph)
+    let (mut table, mut stream_plan) = {
+        let (plan, table) = gen_create_mv_plan(&session, context.into(), query, name.clone())?;
+        (table, plan.to_stream_prost())
     };
 
     let catalog_writer = session.env().catalog_writer();
-    catalog_writer
-        .create_materialized_view(table, graph)
-        .await?;
+
+    let error_table = if expr_error_mode == ExprErrorMode::Strict {
+        None
+    } else {
+        let (source_id, table_id) = create_error_table(&session, error_table_name(&name)).await?;
+        let error_handling = ExprErrorHandling {
+            mode: expr_error_mode as i32,
+            error_table_source_id: source_id,
+        };
+        set_expr_error_handling(&mut stream_plan, &error_handling);
+        table.error_table_id = table_id.table_id();
+        Some((source_id, table_id))
+    };
+
+    let graph = StreamFragmenter::build_graph(stream_plan);
+    if let Err(err) = catalog_writer.create_materialized_view(table, graph).await {
+        if let Some((source_id, table_id)) = error_table {
+            // Best-effort cleanup. The error of the creation is more interesting to the user.
+            let _ = catalog_writer
+                .drop_materialized_source(source_id, table_id, false)
+                .await;
+        }
+        return Err(err);
+    }
 
     Ok(PgResponse::empty_result(
         StatementType::CREATE_MATERIALIZED_VIEW,
@@ -196,4 +320,66 @@ pub mod tests {
             "Bind error: An alias must be specified for an expression"
         );
     }
+
+    #[tokio::test]
+    async fn test_create_mv_with_error_table() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+
+        let sql = "create table t(x int, y int)";
+        frontend.run_sql(sql).await.unwrap();
+
+        let sql = "create materialized view mv1 with (expr_error_mode = 'null') as select x / y as z from t";
+        frontend.run_sql(sql).await.unwrap();
+
+        let session = frontend.session_ref();
+        let catalog_reader = session.env().catalog_reader();
+
+        let (mv, error_table) = {
+            let reader = catalog_reader.read_guard();
+            let mv = reader
+                .get_table_by_name(DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME, "mv1")
+                .unwrap()
+                .clone();
+            let error_table = reader
+                .get_table_by_name(DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME, "mv1_errors")
+                .unwrap()
+                .clone();
+            (mv, error_table)
+        };
+        assert_eq!(mv.error_table_id(), Some(error_table.id()));
+        assert!(error_table.associated_source_id().is_some());
+
+        let columns = error_table
+            .columns
+            .iter()
+            .filter(|c| !c.is_hidden)
+            .map(|c| (c.name(), c.data_type().clone()))
+            .collect_vec();
+        assert_eq!(
+            columns,
+            vec![
+                ("executor", DataType::Varchar),
+                ("input", DataType::Varchar),
+                ("error", DataType::Varchar),
+            ]
+        );
+
+        // The error table is dropped along with the materialized view.
+        frontend
+            .run_sql("drop materialized view mv1")
+            .await
+            .unwrap();
+        assert!(catalog_reader
+            .read_guard()
+            .get_table_by_name(DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME, "mv1_errors")
+            .is_err());
+
+        let sql =
+            "create materialized view mv2 with (expr_error_mode = 'ignore') as select x from t";
+        let err = frontend.run_sql(sql).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid input syntax: invalid value for expr_error_mode: \"ignore\", expected 'strict', 'null' or 'drop'"
+        );
+    }
 }
@@ -14,6 +14,7 @@
 
 use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::error::Result;
+use risingwave_pb::user::grant_privilege::{Action, Target};
 use risingwave_sqlparser::ast::ObjectName;
 
 use crate::binder::Binder;
@@ -48,6 +49,7 @@ pub async fn handle_create_schema(
         }
         reader.get_database_by_name(&database_name)?.id()
     };
+    session.check_privilege(&Target::DatabaseId(db_id), Action::Create, &database_name)?;
 
     let catalog_writer = session.env().catalog_writer();
     catalog_writer.create_schema(db_id, &schema_name).await?;
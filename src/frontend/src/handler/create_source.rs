@@ -21,13 +21,13 @@ use risingwave_common::error::{Result, RwError};
 use risingwave_pb::catalog::source::Info;
 use risingwave_pb::catalog::{Source as ProstSource, StreamSourceInfo};
 use risingwave_pb::plan_common::{ColumnCatalog as ProstColumnCatalog, RowFormatType};
+use risingwave_pb::user::grant_privilege::{Action, Target};
 use risingwave_source::ProtobufParser;
 use risingwave_sqlparser::ast::{
     CreateSourceStatement, ObjectName, ProtobufSchema, SourceSchema, SqlOption, Value,
 };
 
 use super::create_table::{bind_sql_columns, gen_materialized_source_plan};
-use crate::binder::Binder;
 use crate::catalog::column_catalog::ColumnCatalog;
 use crate::session::{OptimizerContext, SessionImpl};
 use crate::stream_fragmenter::StreamFragmenter;
@@ -37,13 +37,14 @@ pub(crate) fn make_prost_source(
     name: ObjectName,
     source_info: Info,
 ) -> Result<ProstSource> {
-    let (schema_name, name) = Binder::resolve_table_name(name)?;
+    let (schema_name, name) = session.resolve_name_for_create(name)?;
 
     let (database_id, schema_id) = session
         .env()
         .catalog_reader()
         .read_guard()
         .check_relation_name_duplicated(session.database(), &schema_name, &name)?;
+    session.check_privilege(&Target::SchemaId(schema_id), Action::Create, &schema_name)?;
 
     Ok(ProstSource {
         id: 0,
@@ -18,15 +18,17 @@ use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::catalog::{ColumnDesc, ColumnId};
-use risingwave_common::error::Result;
+use risingwave_common::error::{ErrorCode, Result};
 use risingwave_pb::catalog::source::Info;
 use risingwave_pb::catalog::{Source as ProstSource, Table as ProstTable, TableSourceInfo};
 use risingwave_pb::plan_common::ColumnCatalog;
-use risingwave_sqlparser::ast::{ColumnDef, DataType as AstDataType, ObjectName};
+use risingwave_sqlparser::ast::{
+    ColumnDef, ColumnOption, DataType as AstDataType, ObjectName, TableConstraint,
+};
 
 use super::create_source::make_prost_source;
 use crate::binder::expr::{bind_data_type, bind_struct_field};
-use crate::catalog::{check_valid_column_name, row_id_column_desc};
+use crate::catalog::{check_valid_column_name, row_id_column_desc, TABLE_SOURCE_PK_COLID};
 use crate::optimizer::plan_node::{LogicalSource, StreamSource};
 use crate::optimizer::property::{Distribution, Order};
 use crate::optimizer::{PlanRef, PlanRoot};
@@ -58,6 +60,7 @@ pub fn bind_sql_columns(columns: Vec<ColumnDef>) -> Result<Vec<ColumnCatalog>> {
                 name: column.name.value,
                 field_descs,
                 type_name: "".to_string(),
+                default_value: None,
             });
         }
         column_descs
@@ -74,17 +77,79 @@ pub fn bind_sql_columns(columns: Vec<ColumnDef>) -> Result<Vec<ColumnCatalog>> {
     Ok(columns_catalog)
 }
 
+/// Binds the primary key declared in CREATE TABLE, either by column constraints or by a table
+/// constraint, into column ids. The hidden row id column is used if no primary key is declared.
+///
+/// The column ids are assigned in the same way as [`bind_sql_columns`].
+pub fn bind_sql_pk(columns: &[ColumnDef], constraints: &[TableConstraint]) -> Result<Vec<i32>> {
+    let column_pks = columns.iter().filter_map(|column| {
+        column
+            .options
+            .iter()
+            .any(|option| matches!(option.option, ColumnOption::Unique { is_primary: true }))
+            .then(|| vec![column.name.clone()])
+    });
+    let constraint_pks = constraints
+        .iter()
+        .filter_map(|constraint| match constraint {
+            TableConstraint::Unique {
+                columns,
+                is_primary: true,
+                ..
+            } => Some(columns.clone()),
+            _ => None,
+        });
+
+    let pk_names = match column_pks.chain(constraint_pks).at_most_one() {
+        Ok(None) => return Ok(vec![TABLE_SOURCE_PK_COLID.get_id()]),
+        Ok(Some(pk_names)) => pk_names,
+        Err(_) => {
+            return Err(ErrorCode::InvalidInputSyntax(
+                "multiple primary keys for table are not allowed".to_owned(),
+            )
+            .into())
+        }
+    };
+
+    let mut pk_column_ids = Vec::with_capacity(pk_names.len());
+    for name in pk_names {
+        let idx = columns
+            .iter()
+            .position(|column| column.name.value == name.value)
+            .ok_or_else(|| {
+                ErrorCode::InvalidInputSyntax(format!(
+                    "column \"{}\" named in key does not exist",
+                    name.value
+                ))
+            })?;
+        // User columns start from 1, see `bind_sql_columns`.
+        let column_id = (idx + 1) as i32;
+        if pk_column_ids.contains(&column_id) {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "column \"{}\" appears twice in primary key constraint",
+                name.value
+            ))
+            .into());
+        }
+        pk_column_ids.push(column_id);
+    }
+    Ok(pk_column_ids)
+}
+
 pub(crate) fn gen_create_table_plan(
     session: &SessionImpl,
     context: OptimizerContextRef,
     table_name: ObjectName,
     columns: Vec<ColumnDef>,
+    constraints: Vec<TableConstraint>,
 ) -> Result<(PlanRef, ProstSource, ProstTable)> {
+    let pk_column_ids = bind_sql_pk(&columns, &constraints)?;
     let source = make_prost_source(
         session,
         table_name,
         Info::TableSource(TableSourceInfo {
             columns: bind_sql_columns(columns)?,
+            pk_column_ids,
         }),
     )?;
     let (plan, table) = gen_materialized_source_plan(context, source.clone())?;
@@ -101,20 +166,31 @@ pub(crate) fn gen_materialized_source_plan(
         // Manually assemble the materialization plan for the table.
         let source_node: PlanRef =
             StreamSource::new(LogicalSource::new(Rc::new((&source).into()), context)).into();
+        let pk_indices = source_node.pk_indices().to_vec();
         let mut required_cols = FixedBitSet::with_capacity(source_node.schema().len());
         required_cols.toggle_range(..);
         required_cols.toggle(0);
         let mut out_names = source_node.schema().names();
         out_names.remove(0);
 
-        PlanRoot::new(
+        // A user-defined primary key of a table is not guaranteed to be unique in the input
+        // stream, so the materialize executor needs to turn an insertion on an existing key into
+        // an update.
+        let handle_pk_conflict = pk_indices != [0];
+
+        let materialize = PlanRoot::new(
             source_node,
-            Distribution::HashShard(vec![0]),
+            Distribution::HashShard(pk_indices),
             Order::any().clone(),
             required_cols,
             out_names,
         )
-        .gen_create_mv_plan(source.name.clone())?
+        .gen_create_mv_plan(source.name.clone())?;
+        if handle_pk_conflict {
+            materialize.with_pk_conflict_handled()
+        } else {
+            materialize
+        }
     };
     let table = materialize
         .table()
@@ -127,12 +203,18 @@ pub async fn handle_create_table(
     context: OptimizerContext,
     table_name: ObjectName,
     columns: Vec<ColumnDef>,
+    constraints: Vec<TableConstraint>,
 ) -> Result<PgResponse> {
     let session = context.session_ctx.clone();
 
     let (graph, source, table) = {
-        let (plan, source, table) =
-            gen_create_table_plan(&session, context.into(), table_name.clone(), columns)?;
+        let (plan, source, table) = gen_create_table_plan(
+            &session,
+            context.into(),
+            table_name.clone(),
+            columns,
+            constraints,
+        )?;
         let plan = plan.to_stream_prost();
         let graph = StreamFragmenter::build_graph(plan);
 
@@ -212,4 +294,56 @@ mod tests {
 
         assert_eq!(columns, expected_columns);
     }
+
+    #[tokio::test]
+    async fn test_create_table_with_pk() {
+        let sql = "create table t (v1 int, v2 int, v3 int, primary key (v3, v1));";
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend.run_sql(sql).await.unwrap();
+
+        let session = frontend.session_ref();
+        let catalog_reader = session.env().catalog_reader();
+
+        let source = catalog_reader
+            .read_guard()
+            .get_source_by_name(DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME, "t")
+            .unwrap()
+            .clone();
+        assert_eq!(source.pk_col_ids, vec![3.into(), 1.into()]);
+
+        let table = catalog_reader
+            .read_guard()
+            .get_table_by_name(DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME, "t")
+            .unwrap()
+            .clone();
+        let pk_names = table
+            .pks
+            .iter()
+            .map(|&idx| table.columns[idx].column_desc.name.as_str())
+            .collect_vec();
+        assert_eq!(pk_names, vec!["v3", "v1"]);
+    }
+
+    #[tokio::test]
+    async fn test_create_table_with_bad_pk() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+
+        let err = frontend
+            .run_sql("create table t (v1 int primary key, v2 int, primary key (v2));")
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid input syntax: multiple primary keys for table are not allowed"
+        );
+
+        let err = frontend
+            .run_sql("create table t (v1 int, primary key (v2));")
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid input syntax: column \"v2\" named in key does not exist"
+        );
+    }
 }
@@ -0,0 +1,93 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::error::Result;
+use risingwave_pb::user::UserInfo;
+use risingwave_sqlparser::ast::{CreateUserStatement, UserOption, UserOptions};
+
+use crate::binder::Binder;
+use crate::catalog::CatalogError;
+use crate::session::OptimizerContext;
+
+/// Applies the options of `CREATE USER` or `ALTER USER` to `user`.
+pub(super) fn apply_user_options(user: &mut UserInfo, options: &UserOptions) {
+    for option in &options.0 {
+        match option {
+            UserOption::SuperUser => user.is_superuser = true,
+            UserOption::NoSuperUser => user.is_superuser = false,
+            UserOption::CreateDB => user.can_create_db = true,
+            UserOption::NoCreateDB => user.can_create_db = false,
+            UserOption::Login => user.can_login = true,
+            UserOption::NoLogin => user.can_login = false,
+        }
+    }
+}
+
+pub async fn handle_create_user(
+    context: OptimizerContext,
+    stmt: CreateUserStatement,
+) -> Result<PgResponse> {
+    let session = context.session_ctx;
+    session.check_super_user("create users")?;
+    let user_name = Binder::resolve_user_name(stmt.user_name)?;
+
+    {
+        let catalog_reader = session.env().catalog_reader();
+        let reader = catalog_reader.read_guard();
+        if reader.get_user_by_name(&user_name).is_ok() {
+            return Err(CatalogError::Duplicated("user", user_name).into());
+        }
+    }
+
+    // Same as PostgreSQL, a user created by `CREATE USER` is allowed to log in by default.
+    let mut user = UserInfo {
+        name: user_name,
+        can_login: true,
+        ..Default::default()
+    };
+    apply_user_options(&mut user, &stmt.with_options);
+
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer.create_user(user).await?;
+    Ok(PgResponse::empty_result(StatementType::CREATE_USER))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::LocalFrontend;
+
+    #[tokio::test]
+    async fn test_create_user() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        let session = frontend.session_ref();
+        let catalog_reader = session.env().catalog_reader();
+
+        frontend
+            .run_sql("CREATE USER user1 WITH SUPERUSER NOCREATEDB")
+            .await
+            .unwrap();
+
+        let user = catalog_reader
+            .read_guard()
+            .get_user_by_name("user1")
+            .cloned()
+            .unwrap();
+        assert!(user.is_superuser);
+        assert!(!user.can_create_db);
+        assert!(user.can_login);
+
+        assert!(frontend.run_sql("CREATE USER user1").await.is_err());
+    }
+}
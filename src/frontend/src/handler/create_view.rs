@@ -0,0 +1,224 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use itertools::Itertools;
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::catalog::Field;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_pb::catalog::View as ProstView;
+use risingwave_pb::user::grant_privilege::{Action, Target};
+use risingwave_sqlparser::ast::{Ident, ObjectName, Query};
+
+use crate::binder::Binder;
+use crate::catalog::CatalogError;
+use crate::session::OptimizerContext;
+
+pub async fn handle_create_view(
+    context: OptimizerContext,
+    or_replace: bool,
+    name: ObjectName,
+    columns: Vec<Ident>,
+    query: Box<Query>,
+) -> Result<PgResponse> {
+    let session = context.session_ctx;
+    let (schema_name, view_name) = session.resolve_name_for_create(name)?;
+
+    let (database_id, schema_id, old_view) = {
+        let reader = session.env().catalog_reader().read_guard();
+        match reader.get_view_by_name(session.database(), &schema_name, &view_name) {
+            Ok(view) if or_replace => {
+                let schema = reader.get_schema_by_name(session.database(), &schema_name)?;
+                let database_id = reader.get_database_by_name(session.database())?.id();
+                (database_id, schema.id(), Some(view.clone()))
+            }
+            Ok(_) => return Err(CatalogError::Duplicated("view", view_name).into()),
+            Err(_) => {
+                let (database_id, schema_id) = reader.check_relation_name_duplicated(
+                    session.database(),
+                    &schema_name,
+                    &view_name,
+                )?;
+                (database_id, schema_id, None)
+            }
+        }
+    };
+    session.check_privilege(&Target::SchemaId(schema_id), Action::Create, &schema_name)?;
+
+    // Unqualified names in the query are resolved in the search path of the session, and stored
+    // qualified, so that the view keeps referring to the same relations in other sessions.
+    let (query, fields, dependent_relations) = {
+        let mut binder = Binder::new(
+            session.env().catalog_reader().read_guard(),
+            session.database().to_string(),
+            session.user_name().to_string(),
+            session.search_path(),
+            session.session_timezone(),
+        );
+        let mut query = *query;
+        binder.qualify_relation_names(&mut query)?;
+        let bound = binder.bind_query(query.clone())?;
+        let dependent_relations = binder
+            .included_relations()
+            .iter()
+            .map(|id| id.table_id)
+            .collect_vec();
+        (query, bound.schema().fields().to_vec(), dependent_relations)
+    };
+
+    if columns.len() > fields.len() {
+        return Err(ErrorCode::InvalidInputSyntax(
+            "CREATE VIEW specifies more column names than columns".to_owned(),
+        )
+        .into());
+    }
+    let columns = fields
+        .into_iter()
+        .enumerate()
+        .map(|(i, field)| match columns.get(i) {
+            Some(name) => Field {
+                name: name.value.clone(),
+                ..field
+            },
+            None => field,
+        })
+        .collect_vec();
+    let mut names = HashSet::new();
+    if let Some(column) = columns.iter().find(|c| !names.insert(&c.name)) {
+        return Err(CatalogError::Duplicated("column", column.name.clone()).into());
+    }
+
+    if let Some(old_view) = &old_view {
+        if dependent_relations.contains(&old_view.id) {
+            return Err(ErrorCode::BindError(format!(
+                "infinite recursion detected in rules for relation \"{}\"",
+                view_name
+            ))
+            .into());
+        }
+        // Following Postgres, the replacing query may only add columns at the end.
+        if columns.len() < old_view.columns.len() {
+            return Err(
+                ErrorCode::InvalidInputSyntax("cannot drop columns from view".to_owned()).into(),
+            );
+        }
+        for (old, new) in old_view
+            .columns
+            .iter()
+            .zip_eq(&columns[..old_view.columns.len()])
+        {
+            if old.name != new.name || old.data_type != new.data_type {
+                return Err(ErrorCode::InvalidInputSyntax(format!(
+                    "cannot change name or data type of view column \"{}\"",
+                    old.name
+                ))
+                .into());
+            }
+        }
+    }
+
+    let view = ProstView {
+        id: 0,
+        schema_id,
+        database_id,
+        name: view_name,
+        sql: query.to_string(),
+        columns: columns.iter().map(Field::to_prost).collect(),
+        dependent_relations,
+    };
+
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer.create_view(view, or_replace).await?;
+
+    Ok(PgResponse::empty_result(StatementType::CREATE_VIEW))
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::{DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME};
+    use risingwave_common::types::DataType;
+
+    use crate::test_utils::LocalFrontend;
+
+    #[tokio::test]
+    async fn test_create_view_handler() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend
+            .run_sql("create table t (v1 int, v2 int);")
+            .await
+            .unwrap();
+        frontend
+            .run_sql("create view v (a) as select v1, v2 * 2 as b from t;")
+            .await
+            .unwrap();
+        frontend
+            .run_sql("create view v2 as select a from v where b > 1;")
+            .await
+            .unwrap();
+        frontend
+            .run_sql("select v.a, b from v join v2 on v.a = v2.a;")
+            .await
+            .unwrap();
+
+        assert!(frontend
+            .run_sql("create view v as select 1;")
+            .await
+            .is_err());
+        assert!(frontend
+            .run_sql("create view t as select 1;")
+            .await
+            .is_err());
+        // The existing columns must be kept.
+        assert!(frontend
+            .run_sql("create or replace view v as select v1 as a, v2 as c from t;")
+            .await
+            .is_err());
+        assert!(frontend
+            .run_sql("create or replace view v as select a, b from v;")
+            .await
+            .is_err());
+        frontend
+            .run_sql("create or replace view v as select v1 as a, v2 as b, 1 as c from t;")
+            .await
+            .unwrap();
+
+        {
+            let session = frontend.session_ref();
+            let catalog_reader = session.env().catalog_reader();
+            let reader = catalog_reader.read_guard();
+            let view = reader
+                .get_view_by_name(DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME, "v")
+                .unwrap();
+            let columns = view
+                .columns
+                .iter()
+                .map(|c| (c.name.as_str(), c.data_type.clone()))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                columns,
+                vec![
+                    ("a", DataType::Int32),
+                    ("b", DataType::Int32),
+                    ("c", DataType::Int32)
+                ]
+            );
+            assert_eq!(view.sql, "SELECT v1 AS a, v2 AS b, 1 AS c FROM \"dev\".t");
+        }
+
+        frontend.run_sql("drop view v2;").await.unwrap();
+        frontend.run_sql("drop view v;").await.unwrap();
+        assert!(frontend.run_sql("select * from v;").await.is_err());
+    }
+}
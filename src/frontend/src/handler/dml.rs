@@ -23,11 +23,6 @@ use crate::planner::Planner;
 use crate::scheduler::{ExecutionContext, ExecutionContextRef};
 use crate::session::{OptimizerContext, SessionImpl};
 
-/// If `RW_IMPLICIT_FLUSH` is on, then every INSERT/UPDATE/DELETE statement will block
-/// until the entire dataflow is refreshed. In other words, every related table & MV will
-/// be able to see the write.
-pub static IMPLICIT_FLUSH: &str = "RW_IMPLICIT_FLUSH";
-
 pub async fn handle_dml(context: OptimizerContext, stmt: Statement) -> Result<PgResponse> {
     let stmt_type = to_statement_type(&stmt);
     let session = context.session_ctx.clone();
@@ -36,6 +31,9 @@ pub async fn handle_dml(context: OptimizerContext, stmt: Statement) -> Result<Pg
         let mut binder = Binder::new(
             session.env().catalog_reader().read_guard(),
             session.database().to_string(),
+            session.user_name().to_string(),
+            session.search_path(),
+            session.session_timezone(),
         );
         binder.bind(stmt)?
     };
@@ -52,13 +50,25 @@ pub async fn handle_dml(context: OptimizerContext, stmt: Statement) -> Result<Pg
     let execution_context: ExecutionContextRef = ExecutionContext::new(session.clone()).into();
     let query_manager = execution_context.session().env().query_manager().clone();
 
+    let (time_zone, extra_float_digits) = {
+        let config = session.config();
+        (
+            config.get_timezone().to_string(),
+            config.get_extra_float_digits(),
+        )
+    };
     let mut rows = vec![];
     #[for_await]
     for chunk in query_manager
         .schedule_single(execution_context, plan)
         .await?
     {
-        rows.extend(to_pg_rows(chunk?));
+        rows.extend(to_pg_rows(
+            chunk?,
+            &pg_descs,
+            &time_zone,
+            extra_float_digits,
+        ));
     }
 
     let rows_count = match stmt_type {
@@ -74,11 +84,11 @@ pub async fn handle_dml(context: OptimizerContext, stmt: Statement) -> Result<Pg
         _ => unreachable!(),
     };
 
-    // Implicitly flush the writes.
-    if let Some(flag) = session.get_config(IMPLICIT_FLUSH) {
-        if flag.is_set(false) {
-            flush_for_write(&session, stmt_type).await?;
-        }
+    // If `RW_IMPLICIT_FLUSH` is on, then every INSERT/UPDATE/DELETE statement will block
+    // until the entire dataflow is refreshed. In other words, every related table & MV will
+    // be able to see the write.
+    if session.config().get_implicit_flush() {
+        flush_for_write(&session, stmt_type).await?;
     }
 
     Ok(PgResponse::new(stmt_type, rows_count, rows, pg_descs))
@@ -15,17 +15,17 @@
 use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_pb::stream_plan::source_node::SourceType;
-use risingwave_sqlparser::ast::ObjectName;
+use risingwave_sqlparser::ast::{DropMode, ObjectName};
 
-use crate::binder::Binder;
 use crate::session::OptimizerContext;
 
 pub async fn handle_drop_mv(
     context: OptimizerContext,
     table_name: ObjectName,
+    mode: Option<DropMode>,
 ) -> Result<PgResponse> {
     let session = context.session_ctx;
-    let (schema_name, table_name) = Binder::resolve_table_name(table_name)?;
+    let (schema_name, table_name) = session.resolve_relation_name(table_name)?;
 
     let catalog_reader = session.env().catalog_reader();
 
@@ -53,8 +53,11 @@ pub async fn handle_drop_mv(
         table.id()
     };
 
+    // The error table, if any, is dropped along with the materialized view in meta.
     let catalog_writer = session.env().catalog_writer();
-    catalog_writer.drop_materialized_view(table_id).await?;
+    catalog_writer
+        .drop_materialized_view(table_id, mode == Some(DropMode::Cascade))
+        .await?;
 
     Ok(PgResponse::empty_result(
         StatementType::DROP_MATERIALIZED_VIEW,
@@ -67,6 +67,14 @@ pub async fn handle_drop_schema(
                     source.name.clone(),
                 )
                 .into());
+            } else if let Some(view) = schema.iter_view().next() {
+                return Err(CatalogError::NotEmpty(
+                    "schema",
+                    schema_name,
+                    "view",
+                    view.name.clone(),
+                )
+                .into());
             }
             schema.id()
         } else {
@@ -15,14 +15,18 @@
 use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_pb::stream_plan::source_node::SourceType;
-use risingwave_sqlparser::ast::ObjectName;
+use risingwave_sqlparser::ast::{DropMode, ObjectName};
 
-use crate::binder::Binder;
 use crate::session::OptimizerContext;
 
-pub async fn handle_drop_source(context: OptimizerContext, name: ObjectName) -> Result<PgResponse> {
+pub async fn handle_drop_source(
+    context: OptimizerContext,
+    name: ObjectName,
+    mode: Option<DropMode>,
+) -> Result<PgResponse> {
     let session = context.session_ctx;
-    let (schema_name, source_name) = Binder::resolve_table_name(name)?;
+    let cascade = mode == Some(DropMode::Cascade);
+    let (schema_name, source_name) = session.resolve_relation_name(name)?;
 
     let catalog_reader = session.env().catalog_reader();
     let source = catalog_reader
@@ -46,10 +50,10 @@ pub async fn handle_drop_source(context: OptimizerContext, name: ObjectName) ->
             if let Some(table) = table {
                 // Dropping a materialized source.
                 catalog_writer
-                    .drop_materialized_source(source.id, table.id)
+                    .drop_materialized_source(source.id, table.id, cascade)
                     .await?;
             } else {
-                catalog_writer.drop_source(source.id).await?;
+                catalog_writer.drop_source(source.id, cascade).await?;
             }
         }
     }
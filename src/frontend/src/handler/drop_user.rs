@@ -0,0 +1,86 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::catalog::is_default_super_user;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_sqlparser::ast::ObjectName;
+
+use crate::binder::Binder;
+use crate::session::OptimizerContext;
+
+pub async fn handle_drop_user(
+    context: OptimizerContext,
+    user_name: ObjectName,
+    if_exists: bool,
+) -> Result<PgResponse> {
+    let session = context.session_ctx;
+    session.check_super_user("drop users")?;
+    let user_name = Binder::resolve_user_name(user_name)?;
+    if is_default_super_user(&user_name) || user_name == session.user_name() {
+        return Err(
+            ErrorCode::PermissionDenied(format!("user {} cannot be dropped", user_name)).into(),
+        );
+    }
+
+    let user_id = {
+        let catalog_reader = session.env().catalog_reader();
+        let reader = catalog_reader.read_guard();
+        match reader.get_user_by_name(&user_name) {
+            Ok(user) => user.id,
+            Err(err) => {
+                // If `if_exists` is true, not return error.
+                return if if_exists {
+                    Ok(PgResponse::empty_result_with_notice(
+                        StatementType::DROP_USER,
+                        format!("NOTICE: user {} does not exist, skipping", user_name),
+                    ))
+                } else {
+                    Err(err)
+                };
+            }
+        }
+    };
+
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer.drop_user(user_id).await?;
+    Ok(PgResponse::empty_result(StatementType::DROP_USER))
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::DEFAULT_SUPER_USER;
+
+    use crate::test_utils::LocalFrontend;
+
+    #[tokio::test]
+    async fn test_drop_user() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        let session = frontend.session_ref();
+        let catalog_reader = session.env().catalog_reader();
+
+        frontend.run_sql("CREATE USER user1").await.unwrap();
+        frontend.run_sql("DROP USER user1").await.unwrap();
+        assert!(catalog_reader
+            .read_guard()
+            .get_user_by_name("user1")
+            .is_err());
+
+        frontend.run_sql("DROP USER IF EXISTS user").await.unwrap();
+        assert!(frontend
+            .run_sql(format!("DROP USER {}", DEFAULT_SUPER_USER))
+            .await
+            .is_err());
+    }
+}
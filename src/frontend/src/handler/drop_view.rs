@@ -14,25 +14,29 @@
 
 use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::error::Result;
-use risingwave_sqlparser::ast::{Ident, SetVariableValue};
+use risingwave_sqlparser::ast::{DropMode, ObjectName};
 
 use crate::session::OptimizerContext;
 
-pub(super) fn handle_set(
+pub async fn handle_drop_view(
     context: OptimizerContext,
-    name: Ident,
-    value: Vec<SetVariableValue>,
+    view_name: ObjectName,
+    mode: Option<DropMode>,
 ) -> Result<PgResponse> {
-    let string_val = to_string(&value[0]);
-    // Currently store the config variable simply as String -> ConfigEntry(String).
-    // In future we can add converter/parser to make the API more robust.
-    context.session_ctx.set_config(&name.value, &string_val);
+    let session = context.session_ctx;
+    let (schema_name, view_name) = session.resolve_relation_name(view_name)?;
 
-    Ok(PgResponse::empty_result(StatementType::SET_OPTION))
-}
+    let view_id = session
+        .env()
+        .catalog_reader()
+        .read_guard()
+        .get_view_by_name(session.database(), &schema_name, &view_name)?
+        .id;
+
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer
+        .drop_view(view_id, mode == Some(DropMode::Cascade))
+        .await?;
 
-/// Convert any set variable to String.
-/// For example, TRUE -> "TRUE", 1 -> "1".
-fn to_string(value: &SetVariableValue) -> String {
-    format!("{}", value)
+    Ok(PgResponse::empty_result(StatementType::DROP_VIEW))
 }
@@ -12,23 +12,68 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
+use itertools::Itertools;
 use pgwire::pg_field_descriptor::{PgFieldDescriptor, TypeOid};
 use pgwire::pg_response::{PgResponse, StatementType};
 use pgwire::types::Row;
-use risingwave_common::error::Result;
-use risingwave_sqlparser::ast::Statement;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_pb::stream_plan::stream_fragment_graph::StreamFragmentEdge;
+use risingwave_pb::stream_plan::stream_node::NodeBody;
+use risingwave_pb::stream_plan::{StreamFragmentGraph as StreamFragmentGraphProto, StreamNode};
+use risingwave_pb::task_service::ExecutorProfile;
+use risingwave_sqlparser::ast::{ExplainFormat, ExplainOptions, Statement};
+use serde_json::{json, Value};
 
 use super::create_mv::gen_create_mv_plan;
 use super::create_table::gen_create_table_plan;
 use crate::binder::Binder;
+use crate::optimizer::plan_node::Convention;
+use crate::optimizer::PlanRef;
 use crate::planner::Planner;
-use crate::session::OptimizerContext;
+use crate::scheduler::plan_fragmenter::{
+    BatchPlanFragmenter, ExecutionPlanNode, Query, QueryStageRef,
+};
+use crate::scheduler::{ExecutionContext, ExecutionContextRef, QueryProfile};
+use crate::session::{OptimizerContext, SessionImpl};
+use crate::stream_fragmenter::StreamFragmenter;
 
-pub(super) fn handle_explain(
+pub(super) async fn handle_explain(
     context: OptimizerContext,
     stmt: Statement,
-    _verbose: bool,
+    analyze: bool,
+    options: ExplainOptions,
 ) -> Result<PgResponse> {
+    let output = if analyze {
+        explain_analyze(context, stmt, &options).await?
+    } else {
+        explain(context, stmt, &options)?
+    };
+
+    let rows = match options.format {
+        ExplainFormat::Text => output
+            .lines()
+            .map(|s| Row::new(vec![Some(s.into())]))
+            .collect_vec(),
+        // The whole JSON document is returned as a single value, as Postgres does.
+        ExplainFormat::Json => vec![Row::new(vec![Some(output)])],
+    };
+
+    Ok(PgResponse::new(
+        StatementType::EXPLAIN,
+        rows.len() as i32,
+        rows,
+        vec![PgFieldDescriptor::new(
+            "QUERY PLAN".to_owned(),
+            TypeOid::Varchar,
+        )],
+    ))
+}
+
+/// Generates the optimized plan of the statement, which is a stream plan for DDLs and a batch plan
+/// otherwise.
+fn gen_explain_plan(context: OptimizerContext, stmt: Statement) -> Result<PlanRef> {
     let session = context.session_ctx.clone();
     // bind, plan, optimize, and serialize here
     let mut planner = Planner::new(context.into());
@@ -42,15 +87,21 @@ pub(super) fn handle_explain(
             ..
         } => gen_create_mv_plan(&*session, planner.ctx(), query, name)?.0,
 
-        Statement::CreateTable { name, columns, .. } => {
-            gen_create_table_plan(&*session, planner.ctx(), name, columns)?.0
-        }
+        Statement::CreateTable {
+            name,
+            columns,
+            constraints,
+            ..
+        } => gen_create_table_plan(&*session, planner.ctx(), name, columns, constraints)?.0,
 
         stmt => {
             let bound = {
                 let mut binder = Binder::new(
                     session.env().catalog_reader().read_guard(),
                     session.database().to_string(),
+                    session.user_name().to_string(),
+                    session.search_path(),
+                    session.session_timezone(),
                 );
                 binder.bind(stmt)?
             };
@@ -58,21 +109,381 @@ pub(super) fn handle_explain(
             logical.gen_batch_query_plan()?
         }
     };
+    Ok(plan)
+}
+
+fn explain(context: OptimizerContext, stmt: Statement, options: &ExplainOptions) -> Result<String> {
+    let session = context.session_ctx.clone();
+    let plan = gen_explain_plan(context, stmt)?;
+
+    let output = match (options.distsql, options.format) {
+        (false, ExplainFormat::Text) => plan.explain_to_string(options.verbose)?,
+        (false, ExplainFormat::Json) => to_json_string(&plan_node_to_json(&plan, options.verbose)),
+        (true, format) if plan.convention() == Convention::Stream => {
+            let graph = StreamFragmenter::build_graph(plan.to_stream_prost());
+            match format {
+                ExplainFormat::Text => explain_stream_graph(&graph)?,
+                ExplainFormat::Json => to_json_string(&serde_json::to_value(&graph).unwrap()),
+            }
+        }
+        (true, format) => {
+            let stages = BatchStages::new(&session, &plan, options.verbose)?;
+            match format {
+                ExplainFormat::Text => stages.to_text(None),
+                ExplainFormat::Json => to_json_string(&stages.to_json(None)),
+            }
+        }
+    };
+    Ok(output)
+}
 
-    let output = plan.explain_to_string()?;
+async fn explain_analyze(
+    context: OptimizerContext,
+    stmt: Statement,
+    options: &ExplainOptions,
+) -> Result<String> {
+    if !matches!(stmt, Statement::Query(_)) {
+        return Err(ErrorCode::NotImplemented(
+            "EXPLAIN ANALYZE is only supported for batch queries".to_string(),
+            None.into(),
+        )
+        .into());
+    }
 
-    let rows = output
-        .lines()
-        .map(|s| Row::new(vec![Some(s.into())]))
-        .collect::<Vec<_>>();
+    let session = context.session_ctx.clone();
+    // Subblock to make sure PlanRef (an Rc) is dropped before `await` below.
+    let stages = {
+        let plan = gen_explain_plan(context, stmt)?;
+        BatchStages::new(&session, &plan, options.verbose)?
+    };
 
-    Ok(PgResponse::new(
-        StatementType::EXPLAIN,
-        rows.len() as i32,
-        rows,
-        vec![PgFieldDescriptor::new(
-            "QUERY PLAN".to_owned(),
-            TypeOid::Varchar,
-        )],
-    ))
+    let execution_context: ExecutionContextRef = ExecutionContext::new(session.clone()).into();
+    let query_manager = session.env().query_manager().clone();
+    let profile = query_manager
+        .profile(execution_context, stages.query.clone())
+        .await?;
+
+    let output = match options.format {
+        ExplainFormat::Text => stages.to_text(Some(&profile)),
+        ExplainFormat::Json => to_json_string(&stages.to_json(Some(&profile))),
+    };
+    Ok(output)
+}
+
+fn to_json_string(value: &Value) -> String {
+    serde_json::to_string_pretty(value).unwrap()
+}
+
+fn field_names<'a>(names: impl Iterator<Item = &'a str>) -> String {
+    format!("[{}]", names.format(", "))
+}
+
+fn plan_node_to_json(plan: &PlanRef, verbose: bool) -> Value {
+    let mut node = json!({
+        "id": plan.id().0,
+        "name": plan.to_string(),
+    });
+    if verbose {
+        let fields = plan.schema().fields().iter().map(|f| f.name.clone());
+        node["output"] = fields.collect();
+    }
+    node["children"] = plan
+        .inputs()
+        .iter()
+        .map(|input| plan_node_to_json(input, verbose))
+        .collect();
+    node
+}
+
+/// Writes the fragments of a streaming job, and the dispatchers connecting them.
+fn explain_stream_graph(graph: &StreamFragmentGraphProto) -> Result<String> {
+    let mut output = String::new();
+    for (fragment_id, fragment) in graph.fragments.iter().sorted_by_key(|(id, _)| **id) {
+        output.push_str(&format!(
+            "Fragment {} ({:?}, {})\n",
+            fragment_id,
+            fragment.get_fragment_type()?,
+            if fragment.is_singleton {
+                "singleton"
+            } else {
+                "parallel"
+            }
+        ));
+
+        let mut upstreams = graph
+            .edges
+            .iter()
+            .filter(|edge| edge.downstream_id == *fragment_id)
+            .collect_vec();
+        explain_stream_node(fragment.get_node()?, 1, &mut upstreams, &mut output)?;
+        // Edges not attached to an exchange, e.g. the ones generated for delta joins.
+        for edge in upstreams {
+            output.push_str(&format!("  <- {}\n", explain_stream_edge(edge)?));
+        }
+    }
+    Ok(output)
+}
+
+fn explain_stream_node(
+    node: &StreamNode,
+    level: usize,
+    upstreams: &mut Vec<&StreamFragmentEdge>,
+    output: &mut String,
+) -> Result<()> {
+    let indent = "  ".repeat(level);
+    output.push_str(&format!("{}{}", indent, node.identity));
+    if let NodeBody::Exchange(_) = node.get_node_body()? {
+        if let Some(pos) = upstreams
+            .iter()
+            .position(|edge| edge.link_id == node.operator_id)
+        {
+            let edge = upstreams.remove(pos);
+            output.push_str(&format!(" <- {}", explain_stream_edge(edge)?));
+        }
+    }
+    output.push('\n');
+    for input in &node.input {
+        explain_stream_node(input, level + 1, upstreams, output)?;
+    }
+    Ok(())
+}
+
+fn explain_stream_edge(edge: &StreamFragmentEdge) -> Result<String> {
+    let strategy = edge.get_dispatch_strategy()?;
+    let mut desc = format!(
+        "Fragment {} ({:?} dispatcher",
+        edge.upstream_id,
+        strategy.get_type()?
+    );
+    if !strategy.column_indices.is_empty() {
+        desc.push_str(&format!(" on {:?}", strategy.column_indices));
+    }
+    desc.push(')');
+    Ok(desc)
+}
+
+/// Runtime statistics of a plan node, aggregated over all tasks of its stage.
+#[derive(Default)]
+struct PlanNodeStats {
+    output_rows: u64,
+    output_chunks: u64,
+    /// The maximum time spent in the node among the tasks, in nanoseconds.
+    elapsed_ns: u64,
+}
+
+impl PlanNodeStats {
+    fn aggregate(profiles: &[ExecutorProfile]) -> HashMap<&str, PlanNodeStats> {
+        let mut stats: HashMap<&str, PlanNodeStats> = HashMap::new();
+        for profile in profiles {
+            let stat = stats.entry(profile.identity.as_str()).or_default();
+            stat.output_rows += profile.output_rows;
+            stat.output_chunks += profile.output_chunks;
+            stat.elapsed_ns = stat.elapsed_ns.max(profile.elapsed_ns);
+        }
+        stats
+    }
+}
+
+fn format_ns(ns: u64) -> String {
+    format!("{:.3} ms", ns as f64 / 1_000_000.0)
+}
+
+/// The stages of a distributed batch query, along with the description of each plan node.
+struct BatchStages {
+    query: Query,
+    /// The description of each plan node, i.e. its `Display`, by the plan node id.
+    node_names: HashMap<i32, String>,
+    verbose: bool,
+}
+
+impl BatchStages {
+    fn new(session: &SessionImpl, plan: &PlanRef, verbose: bool) -> Result<Self> {
+        fn collect_node_names(plan: &PlanRef, node_names: &mut HashMap<i32, String>) {
+            node_names.insert(plan.id().0, plan.to_string());
+            for input in plan.inputs() {
+                collect_node_names(&input, node_names);
+            }
+        }
+
+        let mut node_names = HashMap::new();
+        collect_node_names(plan, &mut node_names);
+
+        let plan_fragmenter = BatchPlanFragmenter::new(
+            session.env().worker_node_manager_ref(),
+            session.config().get_batch_parallelism(),
+        );
+        let query = plan_fragmenter.split(plan.clone())?;
+        Ok(Self {
+            query,
+            node_names,
+            verbose,
+        })
+    }
+
+    /// Stages ordered by their ids, so the root stage comes first.
+    fn stages(&self) -> impl Iterator<Item = &QueryStageRef> {
+        self.query
+            .stage_graph
+            .stages
+            .values()
+            .sorted_by_key(|stage| stage.id)
+    }
+
+    fn to_text(&self, profile: Option<&QueryProfile>) -> String {
+        let stats = profile.map(|p| PlanNodeStats::aggregate(&p.executor_profiles));
+        let mut output = String::new();
+        for stage in self.stages() {
+            output.push_str(&format!(
+                "Stage {} (parallelism: {}, exchange: {:?})\n",
+                stage.id,
+                stage.parallelism,
+                stage.exchange_info.mode(),
+            ));
+            self.explain_node(&stage.root, 1, stats.as_ref(), &mut output);
+        }
+        if let Some(profile) = profile {
+            output.push_str(&format!("Output rows: {}\n", profile.output_rows));
+            output.push_str(&format!(
+                "Execution time: {}\n",
+                format_ns(profile.elapsed.as_nanos() as u64)
+            ));
+        }
+        output
+    }
+
+    fn explain_node(
+        &self,
+        node: &ExecutionPlanNode,
+        level: usize,
+        stats: Option<&HashMap<&str, PlanNodeStats>>,
+        output: &mut String,
+    ) {
+        let indent = "  ".repeat(level);
+        output.push_str(&format!(
+            "{}{}",
+            indent, self.node_names[&node.plan_node_id.0]
+        ));
+        if let Some(stage_id) = node.stage_id {
+            output.push_str(&format!(" <- Stage {}", stage_id));
+        }
+        if let Some(stats) = stats {
+            let stat = stats.get(node.identity().as_str());
+            output.push_str(&format!(
+                " (actual rows: {}, chunks: {}, time: {})",
+                stat.map_or(0, |s| s.output_rows),
+                stat.map_or(0, |s| s.output_chunks),
+                format_ns(stat.map_or(0, |s| s.elapsed_ns)),
+            ));
+        }
+        output.push('\n');
+        if self.verbose {
+            let fields = node.schema.iter().map(|f| f.name.as_str());
+            output.push_str(&format!("{}  Output: {}\n", indent, field_names(fields)));
+        }
+        for child in &node.children {
+            self.explain_node(child, level + 1, stats, output);
+        }
+    }
+
+    fn to_json(&self, profile: Option<&QueryProfile>) -> Value {
+        let stats = profile.map(|p| PlanNodeStats::aggregate(&p.executor_profiles));
+        let stages: Value = self
+            .stages()
+            .map(|stage| {
+                json!({
+                    "id": stage.id,
+                    "parallelism": stage.parallelism,
+                    "exchange": format!("{:?}", stage.exchange_info.mode()),
+                    "plan": self.node_to_json(&stage.root, stats.as_ref()),
+                })
+            })
+            .collect();
+
+        let mut output = json!({ "stages": stages });
+        if let Some(profile) = profile {
+            output["output_rows"] = json!(profile.output_rows);
+            output["execution_time_ns"] = json!(profile.elapsed.as_nanos() as u64);
+        }
+        output
+    }
+
+    fn node_to_json(
+        &self,
+        node: &ExecutionPlanNode,
+        stats: Option<&HashMap<&str, PlanNodeStats>>,
+    ) -> Value {
+        let mut value = json!({
+            "id": node.plan_node_id.0,
+            "name": self.node_names[&node.plan_node_id.0],
+        });
+        if let Some(stage_id) = node.stage_id {
+            value["input_stage"] = json!(stage_id);
+        }
+        if self.verbose {
+            value["output"] = node.schema.iter().map(|f| f.name.clone()).collect();
+        }
+        if let Some(stats) = stats {
+            let stat = stats.get(node.identity().as_str());
+            value["actual"] = json!({
+                "rows": stat.map_or(0, |s| s.output_rows),
+                "chunks": stat.map_or(0, |s| s.output_chunks),
+                "time_ns": stat.map_or(0, |s| s.elapsed_ns),
+            });
+        }
+        value["children"] = node
+            .children
+            .iter()
+            .map(|child| self.node_to_json(child, stats))
+            .collect();
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::LocalFrontend;
+
+    async fn run_explain(frontend: &LocalFrontend, sql: &str) -> Vec<String> {
+        frontend
+            .run_sql(sql)
+            .await
+            .unwrap()
+            .iter()
+            .map(|row| row[0].clone().unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_explain_options() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend
+            .run_sql("create table t (v1 int, v2 int)")
+            .await
+            .unwrap();
+
+        let plan = run_explain(&frontend, "explain (verbose) select v1 from t").await;
+        assert!(plan.iter().any(|line| line.trim() == "Output: [v1]"));
+
+        let graph = run_explain(
+            &frontend,
+            "explain (distsql) create materialized view mv as select v1, count(*) as cnt from t group by v1",
+        )
+        .await;
+        assert!(graph[0].starts_with("Fragment 0 (Sink"));
+        assert!(graph.iter().any(|line| line.contains("Hash dispatcher")));
+
+        let plan = run_explain(&frontend, "explain (format json) select v1 from t").await;
+        assert_eq!(plan.len(), 1);
+        let plan: serde_json::Value = serde_json::from_str(&plan[0]).unwrap();
+        assert!(plan["name"].is_string());
+        assert!(plan["children"].is_array());
+
+        let err = frontend
+            .run_sql("explain analyze create materialized view mv as select v1 from t")
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("EXPLAIN ANALYZE is only supported for batch queries"));
+    }
 }
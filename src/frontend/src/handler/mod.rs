@@ -21,12 +21,16 @@ use risingwave_sqlparser::ast::{DropStatement, ObjectType, Statement};
 
 use crate::session::{OptimizerContext, SessionImpl};
 
+pub mod alter_table;
+mod alter_user;
 mod create_database;
 pub mod create_index;
 pub mod create_mv;
 mod create_schema;
 pub mod create_source;
 pub mod create_table;
+mod create_user;
+pub mod create_view;
 mod describe;
 pub mod dml;
 mod drop_database;
@@ -34,27 +38,36 @@ pub mod drop_mv;
 mod drop_schema;
 pub mod drop_source;
 pub mod drop_table;
+mod drop_user;
+pub mod drop_view;
 mod explain;
 mod flush;
+mod privilege;
 #[allow(dead_code)]
 pub mod query;
-mod set;
 mod show;
 pub mod util;
+mod variable;
 
 pub(super) async fn handle(session: Arc<SessionImpl>, stmt: Statement) -> Result<PgResponse> {
     let context = OptimizerContext::new(session.clone());
     match stmt {
         Statement::Explain {
-            statement, verbose, ..
-        } => explain::handle_explain(context, *statement, verbose),
+            statement,
+            analyze,
+            options,
+            ..
+        } => explain::handle_explain(context, *statement, analyze, options).await,
         Statement::CreateSource {
             is_materialized,
             stmt,
         } => create_source::handle_create_source(context, is_materialized, stmt).await,
-        Statement::CreateTable { name, columns, .. } => {
-            create_table::handle_create_table(context, name, columns).await
-        }
+        Statement::CreateTable {
+            name,
+            columns,
+            constraints,
+            ..
+        } => create_table::handle_create_table(context, name, columns, constraints).await,
         Statement::CreateDatabase {
             db_name,
             if_not_exists,
@@ -65,6 +78,43 @@ pub(super) async fn handle(session: Arc<SessionImpl>, stmt: Statement) -> Result
             if_not_exists,
             ..
         } => create_schema::handle_create_schema(context, schema_name, if_not_exists).await,
+        Statement::CreateUser(stmt) => create_user::handle_create_user(context, stmt).await,
+        Statement::AlterUser(stmt) => alter_user::handle_alter_user(context, stmt).await,
+        Statement::AlterTable { name, operation } => {
+            alter_table::handle_alter_table(context, name, operation).await
+        }
+        Statement::AlterView {
+            materialized: true,
+            name,
+            operation,
+        } => alter_table::handle_alter_mv(context, name, operation).await,
+        Statement::Grant {
+            privileges,
+            objects,
+            grantees,
+            with_grant_option,
+            granted_by,
+        } => {
+            privilege::handle_grant_privilege(
+                context,
+                privileges,
+                objects,
+                grantees,
+                with_grant_option,
+                granted_by,
+            )
+            .await
+        }
+        Statement::Revoke {
+            privileges,
+            objects,
+            grantees,
+            granted_by,
+            ..
+        } => {
+            privilege::handle_revoke_privilege(context, privileges, objects, grantees, granted_by)
+                .await
+        }
         Statement::Describe { name } => describe::handle_describe(context, name).await,
         // TODO: support complex sql for `show columns from <table>`
         Statement::ShowColumn { name } => describe::handle_describe(context, name).await,
@@ -75,9 +125,18 @@ pub(super) async fn handle(session: Arc<SessionImpl>, stmt: Statement) -> Result
             if_exists,
             drop_mode,
         }) => match object_type {
-            ObjectType::Table => drop_table::handle_drop_table(context, object_name).await,
-            ObjectType::MaterializedView => drop_mv::handle_drop_mv(context, object_name).await,
-            ObjectType::Source => drop_source::handle_drop_source(context, object_name).await,
+            ObjectType::Table => {
+                drop_table::handle_drop_table(context, object_name, drop_mode.into()).await
+            }
+            ObjectType::MaterializedView => {
+                drop_mv::handle_drop_mv(context, object_name, drop_mode.into()).await
+            }
+            ObjectType::Source => {
+                drop_source::handle_drop_source(context, object_name, drop_mode.into()).await
+            }
+            ObjectType::View => {
+                drop_view::handle_drop_view(context, object_name, drop_mode.into()).await
+            }
             ObjectType::Database => {
                 drop_database::handle_drop_database(
                     context,
@@ -91,6 +150,7 @@ pub(super) async fn handle(session: Arc<SessionImpl>, stmt: Statement) -> Result
                 drop_schema::handle_drop_schema(context, object_name, if_exists, drop_mode.into())
                     .await
             }
+            ObjectType::User => drop_user::handle_drop_user(context, object_name, if_exists).await,
             _ => Err(
                 ErrorCode::InvalidInputSyntax(format!("DROP {} is unsupported", object_type))
                     .into(),
@@ -105,34 +165,43 @@ pub(super) async fn handle(session: Arc<SessionImpl>, stmt: Statement) -> Result
             or_replace: false,
             name,
             query,
+            with_options,
             ..
-        } => create_mv::handle_create_mv(context, name, query).await,
+        } => create_mv::handle_create_mv(context, name, query, with_options).await,
+        Statement::CreateView {
+            materialized: false,
+            or_replace,
+            name,
+            columns,
+            query,
+            ..
+        } => create_view::handle_create_view(context, or_replace, name, columns, query).await,
         Statement::Flush => flush::handle_flush(context).await,
         Statement::SetVariable {
             local: _,
             variable,
             value,
-        } => set::handle_set(context, variable, value),
+        } => variable::handle_set(context, variable, value),
+        Statement::ShowVariable { variable } => variable::handle_show(context, variable),
+        Statement::ResetVariable { variable } => variable::handle_reset(context, variable),
         Statement::CreateIndex {
             name,
             table_name,
             columns,
+            include,
             unique,
             if_not_exists,
         } => {
-            if unique {
-                return Err(
-                    ErrorCode::NotImplemented("create unique index".into(), None.into()).into(),
-                );
-            }
-            if if_not_exists {
-                return Err(ErrorCode::NotImplemented(
-                    "create if_not_exists index".into(),
-                    None.into(),
-                )
-                .into());
-            }
-            create_index::handle_create_index(context, name, table_name, columns).await
+            create_index::handle_create_index(
+                context,
+                unique,
+                if_not_exists,
+                name,
+                table_name,
+                columns,
+                include,
+            )
+            .await
         }
         // Ignore `StartTransaction` and `Abort` temporarily.Its not final implementation.
         // 1. Fully support transaction is too hard and gives few benefits to us.
@@ -0,0 +1,385 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_pb::user::grant_privilege::{Action as ProstAction, ActionWithGrantOption, Target};
+use risingwave_pb::user::GrantPrivilege as ProstGrantPrivilege;
+use risingwave_sqlparser::ast::{Action, GrantObjects, Ident, Privileges};
+
+use crate::binder::Binder;
+use crate::catalog::UserId;
+use crate::session::{OptimizerContext, SessionImpl};
+
+/// The kind of objects a privilege can be granted on, which decides the applicable actions.
+#[derive(Clone, Copy)]
+enum ObjectKind {
+    Database,
+    Schema,
+    Table,
+    Source,
+}
+
+impl ObjectKind {
+    fn name(self) -> &'static str {
+        match self {
+            ObjectKind::Database => "database",
+            ObjectKind::Schema => "schema",
+            ObjectKind::Table => "table",
+            ObjectKind::Source => "source",
+        }
+    }
+
+    /// Actions granted by `ALL PRIVILEGES`.
+    fn all_actions(self) -> &'static [ProstAction] {
+        match self {
+            ObjectKind::Database => &[ProstAction::Connect, ProstAction::Create],
+            ObjectKind::Schema => &[ProstAction::Create],
+            ObjectKind::Table => &[
+                ProstAction::Select,
+                ProstAction::Insert,
+                ProstAction::Update,
+                ProstAction::Delete,
+            ],
+            ObjectKind::Source => &[ProstAction::Select],
+        }
+    }
+}
+
+fn resolve_action(action: &Action) -> Result<ProstAction> {
+    match action {
+        Action::Select { columns: None } => Ok(ProstAction::Select),
+        Action::Insert { columns: None } => Ok(ProstAction::Insert),
+        Action::Update { columns: None } => Ok(ProstAction::Update),
+        Action::Delete => Ok(ProstAction::Delete),
+        Action::Create => Ok(ProstAction::Create),
+        Action::Connect => Ok(ProstAction::Connect),
+        _ => Err(ErrorCode::NotImplemented(format!("privilege {}", action), None.into()).into()),
+    }
+}
+
+/// Returns the SQL name of `action`, e.g. `SELECT`.
+fn action_name(action: ProstAction) -> String {
+    format!("{:?}", action).to_uppercase()
+}
+
+/// Resolves the objects of a `GRANT` or `REVOKE` statement to their targets.
+fn resolve_targets(
+    session: &SessionImpl,
+    objects: GrantObjects,
+) -> Result<(ObjectKind, Vec<(Target, String)>)> {
+    let catalog_reader = session.env().catalog_reader();
+    let reader = catalog_reader.read_guard();
+    let db_name = session.database();
+    let search_path = session.search_path();
+
+    let mut targets = vec![];
+    let kind = match objects {
+        GrantObjects::Databases(names) => {
+            for name in names {
+                let database_name = Binder::resolve_database_name(name)?;
+                let db_id = reader.get_database_by_name(&database_name)?.id();
+                targets.push((Target::DatabaseId(db_id), database_name));
+            }
+            ObjectKind::Database
+        }
+        GrantObjects::Schemas(names) => {
+            for name in names {
+                let (database_name, schema_name) = Binder::resolve_schema_name(db_name, name)?;
+                let schema_id = reader
+                    .get_schema_by_name(&database_name, &schema_name)?
+                    .id();
+                targets.push((Target::SchemaId(schema_id), schema_name));
+            }
+            ObjectKind::Schema
+        }
+        GrantObjects::Tables(names) | GrantObjects::Mviews(names) => {
+            for name in names {
+                let (schema_name, table_name) = Binder::resolve_schema_qualified_name(name)?;
+                let schema_name = reader.resolve_relation_schema(
+                    db_name,
+                    schema_name,
+                    &search_path,
+                    &table_name,
+                )?;
+                let table_id = reader
+                    .get_table_by_name(db_name, &schema_name, &table_name)?
+                    .id();
+                targets.push((Target::TableId(table_id.table_id), table_name));
+            }
+            ObjectKind::Table
+        }
+        GrantObjects::AllTablesInSchema { schemas } => {
+            for name in schemas {
+                let (database_name, schema_name) = Binder::resolve_schema_name(db_name, name)?;
+                let schema = reader.get_schema_by_name(&database_name, &schema_name)?;
+                targets.extend(
+                    schema
+                        .iter_table()
+                        .chain(schema.iter_mv())
+                        .map(|t| (Target::TableId(t.id().table_id), t.name().to_string())),
+                );
+            }
+            ObjectKind::Table
+        }
+        GrantObjects::Sources(names) => {
+            for name in names {
+                let (schema_name, source_name) = Binder::resolve_schema_qualified_name(name)?;
+                let schema_name = reader.resolve_relation_schema(
+                    db_name,
+                    schema_name,
+                    &search_path,
+                    &source_name,
+                )?;
+                let source = reader.get_source_by_name(db_name, &schema_name, &source_name)?;
+                // A materialized source is read through its table, so privileges are granted on
+                // the table instead.
+                let target = match reader.get_table_by_name(db_name, &schema_name, &source_name) {
+                    Ok(table) => Target::TableId(table.id().table_id),
+                    Err(_) => Target::SourceId(source.id),
+                };
+                targets.push((target, source_name));
+            }
+            ObjectKind::Source
+        }
+        GrantObjects::AllSequencesInSchema { .. } | GrantObjects::Sequences(_) => {
+            return Err(
+                ErrorCode::NotImplemented("privileges on sequences".into(), None.into()).into(),
+            )
+        }
+    };
+    Ok((kind, targets))
+}
+
+/// Builds the privileges of a `GRANT` or `REVOKE` statement, checking that the session user is
+/// allowed to grant each of them.
+fn make_prost_privileges(
+    session: &SessionImpl,
+    privileges: Privileges,
+    objects: GrantObjects,
+    with_grant_option: bool,
+) -> Result<Vec<ProstGrantPrivilege>> {
+    let (kind, targets) = resolve_targets(session, objects)?;
+    let actions = match privileges {
+        Privileges::All { .. } => kind.all_actions().to_vec(),
+        Privileges::Actions(actions) => {
+            let actions = actions
+                .iter()
+                .map(resolve_action)
+                .collect::<Result<Vec<_>>>()?;
+            if let Some(action) = actions.iter().find(|a| !kind.all_actions().contains(a)) {
+                return Err(ErrorCode::InvalidInputSyntax(format!(
+                    "invalid privilege type {} for {}",
+                    action_name(*action),
+                    kind.name()
+                ))
+                .into());
+            }
+            actions
+        }
+    };
+
+    let catalog_reader = session.env().catalog_reader();
+    let reader = catalog_reader.read_guard();
+    let user = reader.get_user_by_name(session.user_name())?;
+    targets
+        .into_iter()
+        .map(|(target, name)| {
+            for action in &actions {
+                if !user.has_grant_option(&target, *action) {
+                    return Err(ErrorCode::PermissionDenied(format!(
+                        "permission denied to grant {} on {} {}",
+                        action_name(*action),
+                        kind.name(),
+                        name
+                    ))
+                    .into());
+                }
+            }
+            Ok(ProstGrantPrivilege {
+                target: Some(target),
+                action_with_opts: actions
+                    .iter()
+                    .map(|action| ActionWithGrantOption {
+                        action: *action as i32,
+                        with_grant_option,
+                    })
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+fn resolve_grantees(session: &SessionImpl, grantees: Vec<Ident>) -> Result<Vec<UserId>> {
+    let catalog_reader = session.env().catalog_reader();
+    let reader = catalog_reader.read_guard();
+    grantees
+        .into_iter()
+        .map(|grantee| Ok(reader.get_user_by_name(&grantee.value)?.id))
+        .collect()
+}
+
+pub async fn handle_grant_privilege(
+    context: OptimizerContext,
+    privileges: Privileges,
+    objects: GrantObjects,
+    grantees: Vec<Ident>,
+    with_grant_option: bool,
+    granted_by: Option<Ident>,
+) -> Result<PgResponse> {
+    let session = context.session_ctx;
+    if granted_by.is_some() {
+        return Err(ErrorCode::NotImplemented("GRANTED BY".into(), None.into()).into());
+    }
+    let privileges = make_prost_privileges(&session, privileges, objects, with_grant_option)?;
+    let user_ids = resolve_grantees(&session, grantees)?;
+
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer.grant_privilege(user_ids, privileges).await?;
+    Ok(PgResponse::empty_result(StatementType::GRANT_PRIVILEGE))
+}
+
+pub async fn handle_revoke_privilege(
+    context: OptimizerContext,
+    privileges: Privileges,
+    objects: GrantObjects,
+    grantees: Vec<Ident>,
+    granted_by: Option<Ident>,
+) -> Result<PgResponse> {
+    let session = context.session_ctx;
+    if granted_by.is_some() {
+        return Err(ErrorCode::NotImplemented("GRANTED BY".into(), None.into()).into());
+    }
+    let privileges = make_prost_privileges(&session, privileges, objects, false)?;
+    let user_ids = resolve_grantees(&session, grantees)?;
+
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer
+        .revoke_privilege(user_ids, privileges)
+        .await?;
+    Ok(PgResponse::empty_result(StatementType::REVOKE_PRIVILEGE))
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::{DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME};
+    use risingwave_pb::user::grant_privilege::{Action, Target};
+
+    use crate::test_utils::LocalFrontend;
+
+    #[tokio::test]
+    async fn test_grant_privilege() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        let session = frontend.session_ref();
+        let catalog_reader = session.env().catalog_reader();
+
+        frontend.run_sql("CREATE USER user1").await.unwrap();
+        frontend
+            .run_sql("GRANT ALL ON DATABASE dev TO user1 WITH GRANT OPTION")
+            .await
+            .unwrap();
+        frontend.run_sql("CREATE TABLE t (v1 int)").await.unwrap();
+        frontend
+            .run_sql("GRANT SELECT, INSERT ON TABLE t TO user1")
+            .await
+            .unwrap();
+
+        let (db_id, table_id) = {
+            let reader = catalog_reader.read_guard();
+            let db_id = reader
+                .get_database_by_name(DEFAULT_DATABASE_NAME)
+                .unwrap()
+                .id();
+            let table_id = reader
+                .get_table_by_name(DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME, "t")
+                .unwrap()
+                .id()
+                .table_id;
+            (db_id, table_id)
+        };
+        {
+            let reader = catalog_reader.read_guard();
+            let user = reader.get_user_by_name("user1").unwrap();
+            assert!(user.has_grant_option(&Target::DatabaseId(db_id), Action::Connect));
+            assert!(user.has_grant_option(&Target::DatabaseId(db_id), Action::Create));
+            assert!(user.has_privilege(&Target::TableId(table_id), Action::Select));
+            assert!(!user.has_grant_option(&Target::TableId(table_id), Action::Select));
+            assert!(!user.has_privilege(&Target::TableId(table_id), Action::Delete));
+        }
+
+        frontend
+            .run_sql("REVOKE SELECT ON TABLE t FROM user1")
+            .await
+            .unwrap();
+        {
+            let reader = catalog_reader.read_guard();
+            let user = reader.get_user_by_name("user1").unwrap();
+            assert!(!user.has_privilege(&Target::TableId(table_id), Action::Select));
+            assert!(user.has_privilege(&Target::TableId(table_id), Action::Insert));
+        }
+
+        assert!(frontend
+            .run_sql("GRANT INSERT ON DATABASE dev TO user1")
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_check_privilege() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend.run_sql("CREATE USER user1").await.unwrap();
+        frontend.run_sql("CREATE TABLE t (v1 int)").await.unwrap();
+
+        let err = frontend
+            .run_user_sql("EXPLAIN SELECT * FROM t", "user1")
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Permission denied: permission denied for table t"
+        );
+        let err = frontend
+            .run_user_sql("CREATE TABLE t2 (v1 int)", "user1")
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Permission denied: permission denied for schema dev"
+        );
+
+        frontend
+            .run_sql("GRANT SELECT ON TABLE t TO user1")
+            .await
+            .unwrap();
+        frontend
+            .run_sql("GRANT CREATE ON SCHEMA dev TO user1")
+            .await
+            .unwrap();
+        frontend
+            .run_user_sql("EXPLAIN SELECT * FROM t", "user1")
+            .await
+            .unwrap();
+        frontend
+            .run_user_sql("CREATE TABLE t2 (v1 int)", "user1")
+            .await
+            .unwrap();
+
+        // Without the grant option, `user1` cannot grant its privileges to others.
+        frontend.run_sql("CREATE USER user2").await.unwrap();
+        assert!(frontend
+            .run_user_sql("GRANT SELECT ON TABLE t TO user2", "user1")
+            .await
+            .is_err());
+    }
+}
@@ -36,16 +36,31 @@ pub async fn handle_query(context: OptimizerContext, stmt: Statement) -> Result<
         let mut binder = Binder::new(
             session.env().catalog_reader().read_guard(),
             session.database().to_string(),
+            session.user_name().to_string(),
+            session.search_path(),
+            session.session_timezone(),
         );
         binder.bind(stmt)?
     };
 
     let (data_stream, pg_descs) = distribute_execute(context, bound).await?;
 
+    let (time_zone, extra_float_digits) = {
+        let config = session.config();
+        (
+            config.get_timezone().to_string(),
+            config.get_extra_float_digits(),
+        )
+    };
     let mut rows = vec![];
     #[for_await]
     for chunk in data_stream {
-        rows.extend(to_pg_rows(chunk?));
+        rows.extend(to_pg_rows(
+            chunk?,
+            &pg_descs,
+            &time_zone,
+            extra_float_digits,
+        ));
     }
 
     let rows_count = match stmt_type {
@@ -65,7 +80,7 @@ fn to_statement_type(stmt: &Statement) -> StatementType {
     }
 }
 
-async fn distribute_execute(
+pub(crate) async fn distribute_execute(
     context: OptimizerContext,
     stmt: BoundStatement,
 ) -> Result<(impl DataChunkStream, Vec<PgFieldDescriptor>)> {
@@ -85,10 +100,13 @@ async fn distribute_execute(
 
         info!(
             "Generated distributed plan: {:?}",
-            plan.explain_to_string()?
+            plan.explain_to_string(false)?
         );
 
-        let plan_fragmenter = BatchPlanFragmenter::new(session.env().worker_node_manager_ref());
+        let plan_fragmenter = BatchPlanFragmenter::new(
+            session.env().worker_node_manager_ref(),
+            session.config().get_batch_parallelism(),
+        );
         let query = plan_fragmenter.split(plan)?;
         info!("Generated query after plan fragmenter: {:?}", &query);
         (query, pg_descs)
@@ -16,47 +16,41 @@ use itertools::Itertools;
 use pgwire::pg_field_descriptor::{PgFieldDescriptor, TypeOid};
 use pgwire::pg_response::{PgResponse, StatementType};
 use pgwire::types::Row;
-use risingwave_common::catalog::DEFAULT_SCHEMA_NAME;
 use risingwave_common::error::Result;
 use risingwave_sqlparser::ast::{Ident, ShowObject};
 
 use crate::session::OptimizerContext;
 
-fn schema_or_default(schema: &Option<Ident>) -> &str {
-    schema
-        .as_ref()
-        .map_or_else(|| DEFAULT_SCHEMA_NAME, |s| &s.value)
-}
-
 pub async fn handle_show_object(
     context: OptimizerContext,
     command: ShowObject,
 ) -> Result<PgResponse> {
     let session = context.session_ctx;
+    let search_path = session.search_path();
     let catalog_reader = session.env().catalog_reader().read_guard();
+    // If the schema is not specified, use the current schema, i.e. the first valid schema in the
+    // search path.
+    let get_schema = |schema: Option<Ident>| match schema {
+        Some(schema) => catalog_reader.get_schema_by_name(session.database(), &schema.value),
+        None => catalog_reader.first_valid_schema(session.database(), &search_path),
+    };
 
     let names = match command {
-        // If not include schema name, use default schema name
-        ShowObject::Table { schema } => catalog_reader
-            .get_schema_by_name(session.database(), schema_or_default(&schema))?
+        ShowObject::Table { schema } => get_schema(schema)?
             .iter_table()
             .map(|t| t.name.clone())
             .collect(),
         ShowObject::Database => catalog_reader.get_all_database_names(),
         ShowObject::Schema => catalog_reader.get_all_schema_names(session.database())?,
-        // If not include schema name, use default schema name
-        ShowObject::MaterializedView { schema } => catalog_reader
-            .get_schema_by_name(session.database(), schema_or_default(&schema))?
+        ShowObject::MaterializedView { schema } => get_schema(schema)?
             .iter_mv()
             .map(|t| t.name.clone())
             .collect(),
-        ShowObject::Source { schema } => catalog_reader
-            .get_schema_by_name(session.database(), schema_or_default(&schema))?
+        ShowObject::Source { schema } => get_schema(schema)?
             .iter_source()
             .map(|t| t.name.clone())
             .collect(),
-        ShowObject::MaterializedSource { schema } => catalog_reader
-            .get_schema_by_name(session.database(), schema_or_default(&schema))?
+        ShowObject::MaterializedSource { schema } => get_schema(schema)?
             .iter_materialized_source()
             .map(|t| t.name.clone())
             .collect(),
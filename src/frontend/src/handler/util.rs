@@ -19,18 +19,24 @@ use pgwire::types::Row;
 use risingwave_common::array::DataChunk;
 use risingwave_common::catalog::Field;
 use risingwave_common::types::{DataType, ScalarRefImpl};
+use risingwave_expr::vector_op::timestampz::timestampz_to_str;
 
-/// Format scalars according to postgres convention.
-fn pg_value_format(d: ScalarRefImpl) -> String {
+/// Format scalars according to postgres convention, where floats are formatted as described in
+/// [`pg_float_format`].
+fn pg_value_format(d: ScalarRefImpl, extra_float_digits: i32) -> String {
     match d {
         ScalarRefImpl::Bool(b) => if b { "t" } else { "f" }.to_string(),
-        ScalarRefImpl::Float32(v) => pg_float_format(v),
-        ScalarRefImpl::Float64(v) => pg_float_format(v),
+        ScalarRefImpl::Float32(v) => pg_float_format(v, f32::DIGITS as i32, extra_float_digits),
+        ScalarRefImpl::Float64(v) => pg_float_format(v, f64::DIGITS as i32, extra_float_digits),
         _ => d.to_string(),
     }
 }
 
-fn pg_float_format<T: Float + ToString>(v: T) -> String {
+/// Formats a float like postgres does with the session variable `extra_float_digits`. If it's
+/// positive, the shortest text reading back to the same value is used. Otherwise, the value is
+/// rounded to `digits + extra_float_digits` significant digits, where `digits` is 6 for `real`
+/// and 15 for `double precision`.
+fn pg_float_format<T: Float + ToString>(v: T, digits: i32, extra_float_digits: i32) -> String {
     if v.is_infinite() {
         if v.is_sign_positive() {
             "Infinity"
@@ -40,18 +46,57 @@ fn pg_float_format<T: Float + ToString>(v: T) -> String {
         .to_string()
     } else if v.is_nan() {
         "NaN".to_string()
-    } else {
+    } else if extra_float_digits > 0 {
         v.to_string()
+    } else {
+        let precision = (digits + extra_float_digits).max(1) as usize;
+        format_significant(v.to_f64().unwrap(), precision)
     }
 }
 
-pub fn to_pg_rows(chunk: DataChunk) -> Vec<Row> {
+/// Formats `v` with `precision` significant digits like `%g` in C, i.e. in scientific notation
+/// for very large or small exponents and without trailing zeros.
+fn format_significant(v: f64, precision: usize) -> String {
+    let trim_zeros = |s: &str| -> String {
+        if s.contains('.') {
+            s.trim_end_matches('0').trim_end_matches('.').to_string()
+        } else {
+            s.to_string()
+        }
+    };
+    let scientific = format!("{:.*e}", precision - 1, v);
+    let (mantissa, exponent) = scientific.split_once('e').unwrap();
+    let exponent: i32 = exponent.parse().unwrap();
+    if exponent < -4 || exponent >= precision as i32 {
+        let sign = if exponent < 0 { '-' } else { '+' };
+        format!("{}e{}{:02}", trim_zeros(mantissa), sign, exponent.abs())
+    } else {
+        let decimals = (precision as i32 - 1 - exponent) as usize;
+        trim_zeros(&format!("{:.*}", decimals, v))
+    }
+}
+
+/// Converts the rows of `chunk` to text, where `timestamptz` values are shown in `time_zone` and
+/// floats are formatted with `extra_float_digits`.
+pub fn to_pg_rows(
+    chunk: DataChunk,
+    pg_descs: &[PgFieldDescriptor],
+    time_zone: &str,
+    extra_float_digits: i32,
+) -> Vec<Row> {
     chunk
         .rows()
         .map(|r| {
             Row::new(
                 r.values()
-                    .map(|data| data.map(pg_value_format))
+                    .zip_eq(pg_descs)
+                    .map(|(data, desc)| match (data, desc.get_type_oid()) {
+                        (Some(ScalarRefImpl::Int64(v)), TypeOid::Timestampz) => {
+                            // The time zone is validated when set.
+                            Some(timestampz_to_str(v, time_zone).unwrap())
+                        }
+                        (data, _) => data.map(|d| pg_value_format(d, extra_float_digits)),
+                    })
                     .collect_vec(),
             )
         })
@@ -77,9 +122,13 @@ pub fn data_type_to_type_oid(data_type: DataType) -> TypeOid {
         DataType::Timestamp => TypeOid::Timestamp,
         DataType::Timestampz => TypeOid::Timestampz,
         DataType::Decimal => TypeOid::Decimal,
-        DataType::Interval => TypeOid::Varchar,
+        DataType::Interval => TypeOid::Interval,
         DataType::Struct { .. } => TypeOid::Varchar,
         DataType::List { .. } => TypeOid::Varchar,
+        DataType::Jsonb => TypeOid::Jsonb,
+        DataType::Bytea => TypeOid::Bytea,
+        DataType::Uuid => TypeOid::Uuid,
+        DataType::Char { .. } => TypeOid::Bpchar,
     }
 }
 
@@ -109,25 +158,38 @@ mod tests {
                 column!(I64Array, [Some(6), None, Some(7), None]),
                 column!(F32Array, [Some(6.01), None, Some(7.01), None]),
                 column!(Utf8Array, [Some("aaa"), None, Some("vvv"), None]),
+                column!(I64Array, [Some(0), None, Some(3_600_000_000), None]),
             ],
             None,
         );
-        let rows = to_pg_rows(chunk);
+        let pg_descs = [
+            DataType::Int32,
+            DataType::Int64,
+            DataType::Float32,
+            DataType::Varchar,
+            DataType::Timestampz,
+        ]
+        .into_iter()
+        .map(|t| to_pg_field(&Field::unnamed(t)))
+        .collect_vec();
+        let rows = to_pg_rows(chunk, &pg_descs, "+08:00", 1);
         let expected = vec![
             vec![
                 Some("1".to_string()),
                 Some("6".to_string()),
                 Some("6.01".to_string()),
                 Some("aaa".to_string()),
+                Some("1970-01-01 08:00:00+08".to_string()),
             ],
-            vec![Some("2".to_string()), None, None, None],
+            vec![Some("2".to_string()), None, None, None, None],
             vec![
                 Some("3".to_string()),
                 Some("7".to_string()),
                 Some("7.01".to_string()),
                 Some("vvv".to_string()),
+                Some("1970-01-01 09:00:00+08".to_string()),
             ],
-            vec![Some("4".to_string()), None, None, None],
+            vec![Some("4".to_string()), None, None, None, None],
         ];
         let vec = rows
             .into_iter()
@@ -141,7 +203,7 @@ mod tests {
     fn test_value_format() {
         use ScalarRefImpl as S;
 
-        let f = pg_value_format;
+        let f = |d| pg_value_format(d, 1);
         assert_eq!(&f(S::Float32(1_f32.into())), "1");
         assert_eq!(&f(S::Float32(f32::NAN.into())), "NaN");
         assert_eq!(&f(S::Float64(f64::NAN.into())), "NaN");
@@ -151,5 +213,18 @@ mod tests {
         assert_eq!(&f(S::Float64(f64::NEG_INFINITY.into())), "-Infinity");
         assert_eq!(&f(S::Bool(true)), "t");
         assert_eq!(&f(S::Bool(false)), "f");
+
+        let f = pg_value_format;
+        assert_eq!(
+            &f(S::Float64((0.1_f64 + 0.2).into()), 1),
+            "0.30000000000000004"
+        );
+        assert_eq!(&f(S::Float64((0.1_f64 + 0.2).into()), 0), "0.3");
+        assert_eq!(&f(S::Float32((1_f32 / 3.0).into()), 0), "0.333333");
+        assert_eq!(&f(S::Float32((1_f32 / 3.0).into()), -3), "0.333");
+        assert_eq!(&f(S::Float64(123456_f64.into()), -12), "1.23e+05");
+        assert_eq!(&f(S::Float64(0.00001234_f64.into()), 0), "1.234e-05");
+        assert_eq!(&f(S::Float64(9.9999996_f64.into()), -10), "10");
+        assert_eq!(&f(S::Float64(f64::NAN.into()), 0), "NaN");
     }
 }
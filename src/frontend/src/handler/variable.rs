@@ -0,0 +1,249 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use pgwire::pg_field_descriptor::{PgFieldDescriptor, TypeOid};
+use pgwire::pg_response::{PgResponse, StatementType};
+use pgwire::types::Row;
+use risingwave_common::error::Result;
+use risingwave_common::session_config::{get_session_variable, ConfigMap, SESSION_VARIABLES};
+use risingwave_sqlparser::ast::{Ident, SetVariableValue, Value};
+
+use crate::session::{OptimizerContext, SessionImpl};
+
+pub(super) fn handle_set(
+    context: OptimizerContext,
+    name: Ident,
+    value: Vec<SetVariableValue>,
+) -> Result<PgResponse> {
+    let session = context.session_ctx;
+    let old_config = session.config().clone();
+
+    match value.as_slice() {
+        [SetVariableValue::Ident(ident)]
+            if ident.quote_style.is_none()
+                && (ident.value.eq_ignore_ascii_case("DEFAULT")
+                    // `SET TIME ZONE LOCAL` resets the time zone.
+                    || ident.value.eq_ignore_ascii_case("LOCAL")
+                        && get_session_variable(&name.value)?.name == "TimeZone") =>
+        {
+            session.reset_config(&name.value)?
+        }
+        value => {
+            // A list of values, e.g. of `search_path`, is stored as a comma-separated string.
+            let value = value.iter().map(to_string).join(", ");
+            session.set_config(&name.value, &value)?
+        }
+    }
+
+    Ok(PgResponse::empty_result(StatementType::SET_OPTION)
+        .with_parameter_status(changed_parameters(&session, &old_config)))
+}
+
+pub(super) fn handle_reset(context: OptimizerContext, name: Ident) -> Result<PgResponse> {
+    let session = context.session_ctx;
+    let old_config = session.config().clone();
+
+    if name.value.eq_ignore_ascii_case("ALL") {
+        session.reset_all_config();
+    } else {
+        session.reset_config(&name.value)?;
+    }
+
+    Ok(PgResponse::empty_result(StatementType::RESET)
+        .with_parameter_status(changed_parameters(&session, &old_config)))
+}
+
+pub(super) fn handle_show(context: OptimizerContext, variable: Vec<Ident>) -> Result<PgResponse> {
+    let session = context.session_ctx;
+    let config = session.config();
+
+    let name = variable
+        .iter()
+        .map(|ident| ident.value.to_lowercase())
+        .join(" ");
+    // Aliases of the variables as in Postgres.
+    let name = match name.as_str() {
+        "time zone" => "timezone",
+        "transaction isolation level" => "transaction_isolation",
+        name => name,
+    };
+
+    if name == "all" {
+        let rows = SESSION_VARIABLES
+            .iter()
+            .map(|var| {
+                Row::new(vec![
+                    Some(var.name.to_string()),
+                    Some(var.value(&config)),
+                    Some(var.description.to_string()),
+                ])
+            })
+            .collect_vec();
+        return Ok(PgResponse::new(
+            StatementType::SHOW_COMMAND,
+            rows.len() as i32,
+            rows,
+            vec![
+                PgFieldDescriptor::new("name".to_owned(), TypeOid::Varchar),
+                PgFieldDescriptor::new("setting".to_owned(), TypeOid::Varchar),
+                PgFieldDescriptor::new("description".to_owned(), TypeOid::Varchar),
+            ],
+        ));
+    }
+
+    let var = get_session_variable(name)?;
+    Ok(PgResponse::new(
+        StatementType::SHOW_COMMAND,
+        1,
+        vec![Row::new(vec![Some(var.value(&config))])],
+        vec![PgFieldDescriptor::new(
+            var.name.to_owned(),
+            TypeOid::Varchar,
+        )],
+    ))
+}
+
+/// Returns the names and values of the variables to report to the client, which are changed
+/// since `old_config`.
+fn changed_parameters(session: &SessionImpl, old_config: &ConfigMap) -> Vec<(String, String)> {
+    session
+        .config()
+        .changed_reported_variables(old_config)
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), value))
+        .collect()
+}
+
+/// Converts the value to the string to be parsed by the session variable. For example, TRUE ->
+/// "true", 'abc' -> "abc".
+fn to_string(value: &SetVariableValue) -> String {
+    match value {
+        SetVariableValue::Literal(Value::SingleQuotedString(s)) => s.clone(),
+        SetVariableValue::Literal(Value::Boolean(b)) => b.to_string(),
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::{DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME};
+
+    use crate::test_utils::LocalFrontend;
+
+    #[tokio::test]
+    async fn test_session_variables() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        // Session variables are kept in the session, so all statements must run in the same one.
+        let session = frontend.session_ref();
+        let query = |sql: &'static str| {
+            let session = session.clone();
+            async move {
+                session
+                    .run_statement(sql)
+                    .await
+                    .unwrap()
+                    .iter()
+                    .map(|row| format!("{:?}", row))
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        let rows = query("SHOW transaction_isolation").await;
+        assert_eq!(rows, vec!["Row([Some(\"read committed\")])".to_string()]);
+
+        query("SET extra_float_digits = 3").await;
+        query("SET TIME ZONE '+08:00'").await;
+        query("SET search_path TO \"$user\", public, s1").await;
+        let rows = query("SHOW TimeZone").await;
+        assert_eq!(rows, vec!["Row([Some(\"+08:00\")])".to_string()]);
+        let rows = query("SHOW search_path").await;
+        assert_eq!(
+            rows,
+            vec!["Row([Some(\"\\\"$user\\\", public, s1\")])".to_string()]
+        );
+
+        assert!(session
+            .run_statement("SET extra_float_digits = 4")
+            .await
+            .is_err());
+        assert!(session
+            .run_statement("SET no_such_variable = 1")
+            .await
+            .is_err());
+        assert!(session
+            .run_statement("SHOW no_such_variable")
+            .await
+            .is_err());
+
+        query("RESET extra_float_digits").await;
+        query("SET TIME ZONE LOCAL").await;
+        let rows = query("SHOW extra_float_digits").await;
+        assert_eq!(rows, vec!["Row([Some(\"1\")])".to_string()]);
+        let rows = query("SHOW TIME ZONE").await;
+        assert_eq!(rows, vec!["Row([Some(\"UTC\")])".to_string()]);
+
+        query("RESET ALL").await;
+        let rows = query("SHOW search_path").await;
+        assert_eq!(
+            rows,
+            vec!["Row([Some(\"\\\"$user\\\", public\")])".to_string()]
+        );
+        let rows = query("SHOW ALL").await;
+        assert!(rows.len() > 1);
+    }
+
+    #[tokio::test]
+    async fn test_search_path() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        let session = frontend.session_ref();
+        let run = |sql: &'static str| {
+            let session = session.clone();
+            async move { session.run_statement(sql).await.map(|_| ()) }
+        };
+
+        run("CREATE SCHEMA s1").await.unwrap();
+        run("SET search_path TO s1, public").await.unwrap();
+        run("CREATE TABLE t1 (v1 int)").await.unwrap();
+        run("CREATE TABLE public.t2 (v2 int)").await.unwrap();
+        run("CREATE MATERIALIZED VIEW mv AS SELECT v1, v2 FROM t1 JOIN t2 ON v1 = v2")
+            .await
+            .unwrap();
+        {
+            let catalog_reader = session.env().catalog_reader();
+            let reader = catalog_reader.read_guard();
+            reader
+                .get_table_by_name(DEFAULT_DATABASE_NAME, "s1", "t1")
+                .unwrap();
+            reader
+                .get_table_by_name(DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME, "t2")
+                .unwrap();
+            reader
+                .get_table_by_name(DEFAULT_DATABASE_NAME, "s1", "mv")
+                .unwrap();
+        }
+        // `pg_catalog` is searched implicitly, for both relations and functions.
+        run("SELECT * FROM pg_namespace").await.unwrap();
+        run("SELECT pg_catalog.abs(v1) FROM t1").await.unwrap();
+        assert!(run("SELECT s1.abs(v1) FROM t1").await.is_err());
+
+        run("SET search_path TO public").await.unwrap();
+        assert!(run("SELECT * FROM t1").await.is_err());
+        run("SELECT * FROM s1.t1 JOIN t2 ON v1 = v2").await.unwrap();
+        run("DROP MATERIALIZED VIEW s1.mv").await.unwrap();
+
+        run("SET search_path TO no_such_schema").await.unwrap();
+        assert!(run("CREATE TABLE t3 (v3 int)").await.is_err());
+    }
+}
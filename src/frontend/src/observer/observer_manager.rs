@@ -86,6 +86,12 @@ impl ObserverManager {
                 for source in snapshot.source {
                     catalog_guard.create_source(source)
                 }
+                for view in snapshot.view {
+                    catalog_guard.create_view(&view)
+                }
+                for user in snapshot.users {
+                    catalog_guard.create_user(user)
+                }
                 self.worker_node_manager.refresh_worker_node(snapshot.nodes);
             }
             _ => {
@@ -123,6 +129,7 @@ impl ObserverManager {
                 Operation::Delete => {
                     catalog_guard.drop_table(table.database_id, table.schema_id, table.id.into())
                 }
+                Operation::Update => catalog_guard.update_table(table),
                 _ => panic!("receive an unsupported notify {:?}", resp),
             },
             Info::Source(source) => match resp.operation() {
@@ -130,6 +137,21 @@ impl ObserverManager {
                 Operation::Delete => {
                     catalog_guard.drop_source(source.database_id, source.schema_id, source.id)
                 }
+                Operation::Update => catalog_guard.update_source(source.clone()),
+                _ => panic!("receive an unsupported notify {:?}", resp),
+            },
+            Info::View(view) => match resp.operation() {
+                Operation::Add => catalog_guard.create_view(view),
+                Operation::Delete => {
+                    catalog_guard.drop_view(view.database_id, view.schema_id, view.id)
+                }
+                Operation::Update => catalog_guard.update_view(view),
+                _ => panic!("receive an unsupported notify {:?}", resp),
+            },
+            Info::User(user) => match resp.operation() {
+                Operation::Add => catalog_guard.create_user(user.clone()),
+                Operation::Delete => catalog_guard.drop_user(user.id),
+                Operation::Update => catalog_guard.update_user(user.clone()),
                 _ => panic!("receive an unsupported notify {:?}", resp),
             },
             _ => unreachable!(),
@@ -150,7 +172,12 @@ impl ObserverManager {
         };
 
         match info {
-            Info::Database(_) | Info::Schema(_) | Info::Table(_) | Info::Source(_) => {
+            Info::Database(_)
+            | Info::Schema(_)
+            | Info::Table(_)
+            | Info::Source(_)
+            | Info::View(_)
+            | Info::User(_) => {
                 self.handle_catalog_notification(resp);
             }
             Info::Node(node) => {
@@ -125,7 +125,10 @@ impl PlanRoot {
             let rules = vec![
                 // This rule should be applied first to pull up LogicalAgg.
                 UnnestAggForLOJ::create(),
+                PullUpCorrelatedProject::create(),
                 PullUpCorrelatedPredicate::create(),
+                // This rule should be applied last, once the correlated columns are pulled up.
+                ApplyToJoin::create(),
             ];
             let heuristic_optimizer = HeuristicOptimizer::new(ApplyOrder::TopDown, rules);
             heuristic_optimizer.optimize(plan)
@@ -172,6 +175,13 @@ impl PlanRoot {
         // Logical optimization
         let mut plan = self.gen_optimized_logical_plan();
 
+        // Use indexes for point queries
+        plan = {
+            let rules = vec![IndexSelectionRule::create()];
+            let heuristic_optimizer = HeuristicOptimizer::new(ApplyOrder::BottomUp, rules);
+            heuristic_optimizer.optimize(plan)
+        };
+
         // Convert to physical plan node
         plan = plan.to_batch_with_order_required(&self.required_order)?;
 
@@ -78,6 +78,13 @@ impl ToBatchProst for BatchInsert {
             }
             .into(),
             column_ids: vec![], // unused
+            pk_indices: self
+                .logical
+                .pk_indices()
+                .iter()
+                .map(|idx| *idx as u32)
+                .collect(),
+            unique_indexes: self.logical.unique_indexes().to_vec(),
         })
     }
 }
@@ -0,0 +1,113 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use risingwave_common::error::Result;
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+use risingwave_pb::batch_plan::LookupNode;
+use risingwave_pb::plan_common::{CellBasedTableDesc, ColumnDesc as ProstColumnDesc};
+
+use super::{
+    LogicalLookup, PlanBase, PlanRef, PlanTreeNodeUnary, ToBatchProst, ToDistributedBatch,
+};
+use crate::optimizer::plan_node::ToLocalBatch;
+use crate::optimizer::property::{Distribution, Order};
+
+/// `BatchLookup` implements [`super::LogicalLookup`] by getting the row of the table with the
+/// primary key of each input row.
+#[derive(Debug, Clone)]
+pub struct BatchLookup {
+    pub base: PlanBase,
+    logical: LogicalLookup,
+}
+
+impl BatchLookup {
+    pub fn new(logical: LogicalLookup) -> Self {
+        let ctx = logical.base.ctx.clone();
+        // The output rows stay where the input rows are, but the columns the input is distributed
+        // by are not in the output.
+        let dist = match logical.input().distribution() {
+            Distribution::Any => Distribution::Any,
+            Distribution::Single => Distribution::Single,
+            _ => Distribution::AnyShard,
+        };
+        let base = PlanBase::new_batch(ctx, logical.schema().clone(), dist, Order::any().clone());
+        BatchLookup { base, logical }
+    }
+}
+
+impl fmt::Display for BatchLookup {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "BatchLookup {{ table: {}, columns: [{}] }}",
+            self.logical.table_name(),
+            self.logical.column_names().join(", ")
+        )
+    }
+}
+
+impl PlanTreeNodeUnary for BatchLookup {
+    fn input(&self) -> PlanRef {
+        self.logical.input()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(self.logical.clone_with_input(input))
+    }
+}
+impl_plan_tree_node_for_unary! {BatchLookup}
+
+impl ToDistributedBatch for BatchLookup {
+    fn to_distributed(&self) -> Result<PlanRef> {
+        let new_input = self.input().to_distributed()?;
+        Ok(self.clone_with_input(new_input).into())
+    }
+}
+
+impl ToBatchProst for BatchLookup {
+    fn to_batch_prost_body(&self) -> NodeBody {
+        let table_desc = self.logical.table_desc();
+        NodeBody::Lookup(LookupNode {
+            table_desc: Some(CellBasedTableDesc {
+                table_id: table_desc.table_id.into(),
+                pk: table_desc
+                    .order_desc
+                    .iter()
+                    .map(|order| order.to_protobuf())
+                    .collect(),
+            }),
+            column_descs: self
+                .logical
+                .column_descs()
+                .iter()
+                .map(ProstColumnDesc::from)
+                .collect(),
+            lookup_key_indices: self
+                .logical
+                .lookup_key()
+                .iter()
+                .map(|idx| *idx as u32)
+                .collect(),
+        })
+    }
+}
+
+impl ToLocalBatch for BatchLookup {
+    fn to_local(&self) -> Result<PlanRef> {
+        let new_input = self.input().to_local()?;
+        Ok(self.clone_with_input(new_input).into())
+    }
+}
@@ -46,6 +46,9 @@ impl BatchNestedLoopJoin {
         match (left, right) {
             (Distribution::Any, Distribution::Any) => Distribution::Any,
             (Distribution::Single, Distribution::Single) => Distribution::Single,
+            // The left columns come first in the output of the joins that broadcast the right
+            // side, see `LogicalJoin::can_broadcast_right`.
+            (_, Distribution::Broadcast) => left.clone(),
             (_, _) => panic!(),
         }
     }
@@ -80,12 +83,19 @@ impl_plan_tree_node_for_binary! { BatchNestedLoopJoin }
 
 impl ToDistributedBatch for BatchNestedLoopJoin {
     fn to_distributed(&self) -> Result<PlanRef> {
+        // Join each shard of the left side with a copy of the right side if possible, rather than
+        // gathering both sides on a single node.
+        let (left_dist, right_dist) = if self.logical.can_broadcast_right() {
+            (Distribution::Any, Distribution::Broadcast)
+        } else {
+            (Distribution::Single, Distribution::Single)
+        };
         let left = self
             .left()
-            .to_distributed_with_required(Order::any(), &Distribution::Single)?;
+            .to_distributed_with_required(Order::any(), &left_dist)?;
         let right = self
             .right()
-            .to_distributed_with_required(Order::any(), &Distribution::Single)?;
+            .to_distributed_with_required(Order::any(), &right_dist)?;
 
         Ok(self.clone_with_left_right(left, right).into())
     }
@@ -13,8 +13,11 @@
 // limitations under the License.
 
 use std::fmt;
+use std::ops::Bound::*;
 
+use itertools::Itertools;
 use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::util::scan_range::ScanRange;
 use risingwave_pb::batch_plan::plan_node::NodeBody;
 use risingwave_pb::batch_plan::RowSeqScanNode;
 use risingwave_pb::plan_common::{CellBasedTableDesc, ColumnDesc as ProstColumnDesc};
@@ -28,23 +31,33 @@ use crate::optimizer::property::{Distribution, Order};
 pub struct BatchSeqScan {
     pub base: PlanBase,
     logical: LogicalScan,
+    /// The ranges of the order key to scan. The whole table is scanned if empty.
+    scan_ranges: Vec<ScanRange>,
 }
 
 impl BatchSeqScan {
-    pub fn new_inner(logical: LogicalScan, dist: Distribution) -> Self {
+    pub fn new_inner(
+        logical: LogicalScan,
+        dist: Distribution,
+        scan_ranges: Vec<ScanRange>,
+    ) -> Self {
         let ctx = logical.base.ctx.clone();
         // TODO: derive from input
         let base = PlanBase::new_batch(ctx, logical.schema().clone(), dist, Order::any().clone());
 
-        Self { base, logical }
+        Self {
+            base,
+            logical,
+            scan_ranges,
+        }
     }
 
     pub fn new(logical: LogicalScan) -> Self {
-        Self::new_inner(logical, Distribution::Any)
+        Self::new_inner(logical, Distribution::Any, vec![])
     }
 
-    pub fn with_dist(logical: LogicalScan) -> Self {
-        Self::new_inner(logical, Distribution::AnyShard)
+    pub fn new_with_scan_ranges(logical: LogicalScan, scan_ranges: Vec<ScanRange>) -> Self {
+        Self::new_inner(logical, Distribution::Any, scan_ranges)
     }
 
     /// Get a reference to the batch seq scan's logical.
@@ -52,24 +65,83 @@ impl BatchSeqScan {
     pub fn logical(&self) -> &LogicalScan {
         &self.logical
     }
+
+    pub fn scan_ranges(&self) -> &[ScanRange] {
+        &self.scan_ranges
+    }
+
+    fn scan_range_to_string(&self, scan_range: &ScanRange) -> String {
+        let order_desc = &self.logical.table_desc().order_desc;
+        let mut conds = scan_range
+            .eq_conds
+            .iter()
+            .zip_eq(&order_desc[..scan_range.eq_conds.len()])
+            .map(|(value, order)| {
+                format!("{} = {:?}", order.column_desc.name, value.as_ref().unwrap())
+            })
+            .collect_vec();
+        if let Some(order) = order_desc.get(scan_range.eq_conds.len()) {
+            let name = &order.column_desc.name;
+            match &scan_range.range.0 {
+                Included(value) => conds.push(format!("{} >= {:?}", name, value)),
+                Excluded(value) => conds.push(format!("{} > {:?}", name, value)),
+                Unbounded => {}
+            }
+            match &scan_range.range.1 {
+                Included(value) => conds.push(format!("{} <= {:?}", name, value)),
+                Excluded(value) => conds.push(format!("{} < {:?}", name, value)),
+                Unbounded => {}
+            }
+        }
+        conds.join(" AND ")
+    }
+
+    /// Whether the scan only looks up rows by full order keys, so at most one row is got from
+    /// each scan range.
+    pub fn is_point_get(&self) -> bool {
+        let order_key_len = self.logical.table_desc().order_desc.len();
+        !self.scan_ranges.is_empty()
+            && self
+                .scan_ranges
+                .iter()
+                .all(|scan_range| scan_range.is_point_get(order_key_len))
+    }
 }
 
 impl_plan_tree_node_for_leaf! { BatchSeqScan }
 
 impl fmt::Display for BatchSeqScan {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "BatchScan {{ table: {}, columns: [{}] }}",
-            self.logical.table_name(),
-            self.logical.column_names().join(", ")
-        )
+        if self.scan_ranges.is_empty() {
+            write!(
+                f,
+                "BatchScan {{ table: {}, columns: [{}] }}",
+                self.logical.table_name(),
+                self.logical.column_names().join(", ")
+            )
+        } else {
+            write!(
+                f,
+                "BatchScan {{ table: {}, columns: [{}], scan_ranges: [{}] }}",
+                self.logical.table_name(),
+                self.logical.column_names().join(", "),
+                self.scan_ranges
+                    .iter()
+                    .map(|scan_range| self.scan_range_to_string(scan_range))
+                    .join(", ")
+            )
+        }
     }
 }
 
 impl ToDistributedBatch for BatchSeqScan {
     fn to_distributed(&self) -> Result<PlanRef> {
-        Ok(Self::with_dist(self.logical.clone()).into())
+        Ok(Self::new_inner(
+            self.logical.clone(),
+            Distribution::AnyShard,
+            self.scan_ranges.clone(),
+        )
+        .into())
     }
 }
 
@@ -85,9 +157,20 @@ impl ToBatchProst for BatchSeqScan {
         NodeBody::RowSeqScan(RowSeqScanNode {
             table_desc: Some(CellBasedTableDesc {
                 table_id: self.logical.table_desc().table_id.into(),
-                pk: vec![], // TODO:
+                pk: self
+                    .logical
+                    .table_desc()
+                    .order_desc
+                    .iter()
+                    .map(|order| order.to_protobuf())
+                    .collect(),
             }),
             column_descs,
+            scan_ranges: self
+                .scan_ranges
+                .iter()
+                .map(|scan_range| scan_range.to_protobuf().unwrap())
+                .collect(),
         })
     }
 }
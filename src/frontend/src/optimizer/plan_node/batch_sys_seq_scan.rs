@@ -0,0 +1,103 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use risingwave_common::array::DataChunk;
+use risingwave_common::error::Result;
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+use risingwave_pb::batch_plan::SysRowSeqScanNode;
+
+use super::{
+    LogicalSysScan, PlanBase, PlanRef, PlanTreeNodeLeaf, ToBatchProst, ToDistributedBatch,
+};
+use crate::optimizer::plan_node::ToLocalBatch;
+use crate::optimizer::property::{Distribution, Order};
+
+/// `BatchSysSeqScan` implements [`super::LogicalSysScan`] to scan the rows of a system table,
+/// which are carried in the plan.
+#[derive(Debug, Clone)]
+pub struct BatchSysSeqScan {
+    pub base: PlanBase,
+    logical: LogicalSysScan,
+}
+
+impl PlanTreeNodeLeaf for BatchSysSeqScan {}
+impl_plan_tree_node_for_leaf! { BatchSysSeqScan }
+
+impl BatchSysSeqScan {
+    pub fn new(logical: LogicalSysScan) -> Self {
+        Self::with_dist(logical, Distribution::Any)
+    }
+
+    pub fn with_dist(logical: LogicalSysScan, dist: Distribution) -> Self {
+        let ctx = logical.base.ctx.clone();
+        let base = PlanBase::new_batch(ctx, logical.schema().clone(), dist, Order::any().clone());
+        Self { base, logical }
+    }
+
+    /// Get a reference to the batch sys seq scan's logical.
+    #[must_use]
+    pub fn logical(&self) -> &LogicalSysScan {
+        &self.logical
+    }
+}
+
+impl fmt::Display for BatchSysSeqScan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "BatchSysScan {{ table: {}, columns: [{}] }}",
+            self.logical.table().name,
+            self.logical.column_names().join(", ")
+        )
+    }
+}
+
+impl ToDistributedBatch for BatchSysSeqScan {
+    fn to_distributed(&self) -> Result<PlanRef> {
+        Ok(Self::with_dist(self.logical.clone(), Distribution::Single).into())
+    }
+}
+
+impl ToBatchProst for BatchSysSeqScan {
+    fn to_batch_prost_body(&self) -> NodeBody {
+        let table = self.logical.table();
+        let column_ids = self
+            .logical
+            .required_col_idx()
+            .iter()
+            .map(|idx| table.columns[*idx].get_column_desc().unwrap().column_id)
+            .collect();
+        let rows = self.logical.projected_rows();
+        let data_types = self.schema().data_types();
+        // A chunk without columns is a dummy one, which only keeps the cardinality.
+        let chunk = if data_types.is_empty() {
+            DataChunk::new_dummy(rows.len())
+        } else {
+            DataChunk::from_rows(&rows, &data_types).unwrap()
+        };
+        NodeBody::SysRowSeqScan(SysRowSeqScanNode {
+            table: Some(table.clone()),
+            column_ids,
+            chunk: Some(chunk.to_protobuf()),
+        })
+    }
+}
+
+impl ToLocalBatch for BatchSysSeqScan {
+    fn to_local(&self) -> Result<PlanRef> {
+        Ok(Self::with_dist(self.logical.clone(), Distribution::Single).into())
+    }
+}
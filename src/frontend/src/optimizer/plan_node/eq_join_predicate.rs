@@ -41,7 +41,10 @@ impl fmt::Display for EqJoinPredicate {
             write!(f, " AND {} = {}", k1, k2)?;
         }
         if !self.other_cond.always_true() {
-            write!(f, " AND {}", self.other_cond)?;
+            if self.has_eq() {
+                write!(f, " AND ")?;
+            }
+            write!(f, "{}", self.other_cond)?;
         }
 
         Ok(())
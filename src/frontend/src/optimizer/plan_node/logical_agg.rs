@@ -19,7 +19,7 @@ use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 use risingwave_common::catalog::{Field, Schema};
 use risingwave_common::error::{ErrorCode, Result};
-use risingwave_common::types::DataType;
+use risingwave_common::types::{DataType, Scalar};
 use risingwave_expr::expr::AggKind;
 use risingwave_pb::expr::AggCall as ProstAggCall;
 
@@ -27,8 +27,12 @@ use super::{
     BatchHashAgg, BatchSimpleAgg, ColPrunable, PlanBase, PlanRef, PlanTreeNodeUnary, StreamHashAgg,
     StreamSimpleAgg, ToBatch, ToStream,
 };
-use crate::expr::{AggCall, Expr, ExprImpl, ExprRewriter, ExprType, FunctionCall, InputRef};
-use crate::optimizer::plan_node::LogicalProject;
+use crate::binder::{BoundQuery, BoundSetExpr};
+use crate::expr::{
+    AggCall, CorrelatedInputRef, Expr, ExprImpl, ExprRewriter, ExprType, FunctionCall, InputRef,
+    Literal, Subquery, SubqueryKind,
+};
+use crate::optimizer::plan_node::{LogicalExpand, LogicalProject};
 use crate::optimizer::property::Distribution;
 use crate::utils::ColIndexMapping;
 
@@ -104,13 +108,24 @@ struct ExprHandler {
     /// This 2 indices happen to be the same because we always put group exprs at the beginning of
     /// schema, and they are at the beginning of projects.
     expr_index: HashMap<ExprImpl, usize>,
+    /// Grouping sets over the group exprs. When not empty, the aggregation is planned on top of a
+    /// [`LogicalExpand`], and the expand flag is an additional group key right after the group
+    /// exprs.
+    pub grouping_sets: Vec<Vec<usize>>,
+    /// Index of the first agg call in `LogicalAgg::schema`.
+    agg_call_offset: usize,
     pub agg_calls: Vec<PlanAggCall>,
     pub error: Option<ErrorCode>,
 }
 
 impl ExprHandler {
-    fn new(group_exprs: Vec<ExprImpl>) -> Result<Self> {
+    fn new(group_exprs: Vec<ExprImpl>, grouping_sets: Vec<Vec<usize>>) -> Result<Self> {
         let group_key_len = group_exprs.len();
+        let agg_call_offset = match grouping_sets.is_empty() {
+            true => group_key_len,
+            // The expand flag.
+            false => group_key_len + 1,
+        };
 
         // Please note that we currently don't dedup columns in GROUP BY clause.
         let mut expr_index = HashMap::new();
@@ -132,6 +147,8 @@ impl ExprHandler {
             project: group_exprs,
             group_key_len,
             expr_index,
+            grouping_sets,
+            agg_call_offset,
             agg_calls: vec![],
             error: None,
         })
@@ -144,6 +161,54 @@ impl ExprHandler {
         }
         Ok(rewritten_expr)
     }
+
+    /// `GROUPING(args)` returns a bit mask, where the bit of an arg is set if the arg is not
+    /// included in the grouping set of the current row. The first arg is the most significant
+    /// bit. It is rewritten to a `CASE` expression over the expand flag.
+    fn rewrite_grouping(&mut self, inputs: Vec<ExprImpl>) -> ExprImpl {
+        let mut group_indices = Vec::with_capacity(inputs.len());
+        for input in &inputs {
+            match self.expr_index.get(input) {
+                Some(index) if *index < self.group_key_len => group_indices.push(*index),
+                _ => {
+                    self.error = Some(ErrorCode::InvalidInputSyntax(
+                        "arguments to GROUPING must be grouping expressions of the associated query level"
+                            .into(),
+                    ));
+                    return FunctionCall::new_unchecked(
+                        ExprType::Grouping,
+                        inputs,
+                        DataType::Int32,
+                    )
+                    .into();
+                }
+            }
+        }
+
+        // Without grouping sets, all group exprs are in the only grouping set.
+        if self.grouping_sets.is_empty() {
+            return ExprImpl::literal_int(0);
+        }
+
+        let flag: ExprImpl = InputRef::new(self.group_key_len, DataType::Int64).into();
+        let mut case_inputs = Vec::with_capacity(self.grouping_sets.len() * 2);
+        for (flag_value, grouping_set) in self.grouping_sets.iter().enumerate() {
+            let grouping = group_indices.iter().fold(0, |acc, index| {
+                (acc << 1) | (!grouping_set.contains(index) as i32)
+            });
+            let flag_value =
+                Literal::new(Some((flag_value as i64).to_scalar_value()), DataType::Int64);
+            case_inputs.push(
+                FunctionCall::new(ExprType::Equal, vec![flag.clone(), flag_value.into()])
+                    .unwrap()
+                    .into(),
+            );
+            case_inputs.push(ExprImpl::literal_int(grouping));
+        }
+        FunctionCall::new(ExprType::Case, case_inputs)
+            .unwrap()
+            .into()
+    }
 }
 
 impl ExprRewriter for ExprHandler {
@@ -194,7 +259,7 @@ impl ExprRewriter for ExprHandler {
                 distinct,
             });
             let left = ExprImpl::from(InputRef::new(
-                self.group_key_len + self.agg_calls.len() - 1,
+                self.agg_call_offset + self.agg_calls.len() - 1,
                 left_return_type,
             ))
             .cast_implicit(return_type)
@@ -212,7 +277,7 @@ impl ExprRewriter for ExprHandler {
             });
 
             let right = InputRef::new(
-                self.group_key_len + self.agg_calls.len() - 1,
+                self.agg_call_offset + self.agg_calls.len() - 1,
                 right_return_type,
             );
 
@@ -225,7 +290,7 @@ impl ExprRewriter for ExprHandler {
                 distinct,
             });
             ExprImpl::from(InputRef::new(
-                self.group_key_len + self.agg_calls.len() - 1,
+                self.agg_call_offset + self.agg_calls.len() - 1,
                 return_type,
             ))
         }
@@ -234,6 +299,10 @@ impl ExprRewriter for ExprHandler {
     /// When there is an `FunctionCall` (outside of agg call), it must refers to a group column.
     /// Or all `InputRef`s appears in it must refer to a group column.
     fn rewrite_function_call(&mut self, func_call: FunctionCall) -> ExprImpl {
+        if func_call.get_expr_type() == ExprType::Grouping {
+            let (_, inputs, _) = func_call.decompose();
+            return self.rewrite_grouping(inputs);
+        }
         let expr: ExprImpl = func_call.into();
         if !expr.has_subquery() && let Some(index) = self.expr_index.get(&expr) && *index < self.group_key_len {
             InputRef::new(*index, expr.return_type()).into()
@@ -261,13 +330,116 @@ impl ExprRewriter for ExprHandler {
         }
     }
 
-    fn rewrite_subquery(&mut self, subquery: crate::expr::Subquery) -> ExprImpl {
-        if subquery.is_correlated() {
+    /// The left expr of `IN`, `SOME` and `ALL` belongs to this query level, while the correlated
+    /// columns in the subquery must be rewritten to refer to the group columns.
+    fn rewrite_subquery(&mut self, mut subquery: Subquery) -> ExprImpl {
+        subquery.kind = match subquery.kind {
+            SubqueryKind::In(expr) => SubqueryKind::In(self.rewrite_expr(expr)),
+            SubqueryKind::Some(expr, func_type) => {
+                SubqueryKind::Some(self.rewrite_expr(expr), func_type)
+            }
+            SubqueryKind::All(expr, func_type) => {
+                SubqueryKind::All(self.rewrite_expr(expr), func_type)
+            }
+            kind => kind,
+        };
+        let mut rewriter = CorrelatedGroupKeyRewriter {
+            depth: 1,
+            expr_index: &self.expr_index,
+            group_key_len: self.group_key_len,
+            error: None,
+        };
+        rewriter.rewrite_query(&mut subquery.query);
+        if let Some(error) = rewriter.error {
+            self.error = Some(error);
+        }
+        subquery.into()
+    }
+}
+
+/// `CorrelatedGroupKeyRewriter` rewrites the [`CorrelatedInputRef`]s of a subquery in SELECT or
+/// HAVING which refer to the input of the aggregation, so that they refer to the group columns in
+/// the output of the aggregation instead.
+struct CorrelatedGroupKeyRewriter<'a> {
+    /// The nesting depth of the subquery being rewritten, relative to the aggregation.
+    depth: usize,
+    expr_index: &'a HashMap<ExprImpl, usize>,
+    group_key_len: usize,
+    error: Option<ErrorCode>,
+}
+
+impl CorrelatedGroupKeyRewriter<'_> {
+    fn rewrite_query(&mut self, query: &mut BoundQuery) {
+        if let BoundSetExpr::Select(select) = &mut query.body {
+            for exprs in [&mut select.select_items, &mut select.group_by] {
+                *exprs = std::mem::take(exprs)
+                    .into_iter()
+                    .map(|expr| self.rewrite_expr(expr))
+                    .collect();
+            }
+            select.where_clause = select.where_clause.take().map(|e| self.rewrite_expr(e));
+            select.having = select.having.take().map(|e| self.rewrite_expr(e));
+        }
+        query.extra_order_exprs = std::mem::take(&mut query.extra_order_exprs)
+            .into_iter()
+            .map(|expr| self.rewrite_expr(expr))
+            .collect();
+    }
+}
+
+impl ExprRewriter for CorrelatedGroupKeyRewriter<'_> {
+    fn rewrite_correlated_input_ref(&mut self, input_ref: CorrelatedInputRef) -> ExprImpl {
+        if input_ref.depth() != self.depth {
+            return input_ref.into();
+        }
+        let expr = InputRef::new(input_ref.index(), input_ref.return_type()).into();
+        match self.expr_index.get(&expr) {
+            Some(index) if *index < self.group_key_len => {
+                CorrelatedInputRef::new(*index, input_ref.return_type(), self.depth).into()
+            }
+            _ => {
+                self.error = Some(ErrorCode::InvalidInputSyntax(
+                    "subquery uses ungrouped column from outer query".into(),
+                ));
+                input_ref.into()
+            }
+        }
+    }
+
+    fn rewrite_agg_call(&mut self, agg_call: AggCall) -> ExprImpl {
+        if agg_call
+            .inputs()
+            .iter()
+            .any(|expr| expr.has_correlated_input_ref_by_depth(self.depth))
+        {
             self.error = Some(ErrorCode::NotImplemented(
-                "correlated subquery in HAVING or SELECT with agg".into(),
+                "aggregate function referring to outer query in subquery".into(),
                 2275.into(),
             ));
+            return agg_call.into();
         }
+        let (func_type, inputs, distinct) = agg_call.decompose();
+        let inputs = inputs
+            .into_iter()
+            .map(|expr| self.rewrite_expr(expr))
+            .collect();
+        AggCall::new(func_type, inputs, distinct).unwrap().into()
+    }
+
+    fn rewrite_subquery(&mut self, mut subquery: Subquery) -> ExprImpl {
+        subquery.kind = match subquery.kind {
+            SubqueryKind::In(expr) => SubqueryKind::In(self.rewrite_expr(expr)),
+            SubqueryKind::Some(expr, func_type) => {
+                SubqueryKind::Some(self.rewrite_expr(expr), func_type)
+            }
+            SubqueryKind::All(expr, func_type) => {
+                SubqueryKind::All(self.rewrite_expr(expr), func_type)
+            }
+            kind => kind,
+        };
+        self.depth += 1;
+        self.rewrite_query(&mut subquery.query);
+        self.depth -= 1;
         subquery.into()
     }
 }
@@ -349,11 +521,12 @@ impl LogicalAgg {
     pub fn create(
         select_exprs: Vec<ExprImpl>,
         group_exprs: Vec<ExprImpl>,
+        grouping_sets: Vec<Vec<usize>>,
         having: Option<ExprImpl>,
         input: PlanRef,
     ) -> Result<(PlanRef, Vec<ExprImpl>, Option<ExprImpl>)> {
         let group_keys = (0..group_exprs.len()).collect();
-        let mut expr_handler = ExprHandler::new(group_exprs)?;
+        let mut expr_handler = ExprHandler::new(group_exprs, grouping_sets)?;
 
         let rewritten_select_exprs = select_exprs
             .into_iter()
@@ -367,11 +540,50 @@ impl LogicalAgg {
         let logical_project = LogicalProject::create(input, expr_handler.project);
 
         // This LogicalAgg focuses on calculating the aggregates and grouping.
-        let logical_agg = LogicalAgg::new(expr_handler.agg_calls, group_keys, logical_project);
+        let logical_agg = if expr_handler.grouping_sets.is_empty() {
+            LogicalAgg::new(expr_handler.agg_calls, group_keys, logical_project)
+        } else {
+            Self::create_with_grouping_sets(
+                expr_handler.agg_calls,
+                group_keys,
+                expr_handler.grouping_sets,
+                logical_project,
+            )
+        };
 
         Ok((logical_agg.into(), rewritten_select_exprs, rewritten_having))
     }
 
+    /// Plans grouping sets as
+    ///
+    /// ```text
+    /// LogicalAgg -> LogicalExpand -> input
+    /// ```
+    ///
+    /// The group keys refer to the columns nulled by [`LogicalExpand`], followed by its flag,
+    /// while the agg calls refer to the original columns.
+    fn create_with_grouping_sets(
+        agg_calls: Vec<PlanAggCall>,
+        mut group_keys: Vec<usize>,
+        grouping_sets: Vec<Vec<usize>>,
+        input: PlanRef,
+    ) -> Self {
+        let input_len = input.schema().len();
+        let expand = LogicalExpand::new(input, grouping_sets);
+        group_keys.push(expand.flag_col_idx());
+        let agg_calls = agg_calls
+            .into_iter()
+            .map(|mut agg_call| {
+                agg_call
+                    .inputs
+                    .iter_mut()
+                    .for_each(|i| *i = InputRef::new(i.index() + input_len, i.return_type()));
+                agg_call
+            })
+            .collect();
+        LogicalAgg::new(agg_calls, group_keys, expand.into())
+    }
+
     /// Get a reference to the logical agg's agg calls.
     pub fn agg_calls(&self) -> &[PlanAggCall] {
         self.agg_calls.as_ref()
@@ -628,7 +840,7 @@ mod tests {
                                   group_exprs|
          -> (Vec<ExprImpl>, Vec<PlanAggCall>, Vec<usize>) {
             let (plan, exprs, _) =
-                LogicalAgg::create(select_exprs, group_exprs, None, input.clone()).unwrap();
+                LogicalAgg::create(select_exprs, group_exprs, vec![], None, input.clone()).unwrap();
 
             let logical_agg = plan.as_logical_agg().unwrap();
             let agg_calls = logical_agg.agg_calls().to_vec();
@@ -723,6 +935,58 @@ mod tests {
             assert_eq!(group_keys, vec![0]);
         }
     }
+
+    #[tokio::test]
+    async fn test_create_with_grouping_sets() {
+        let ty = DataType::Int32;
+        let ctx = OptimizerContext::mock().await;
+        let fields: Vec<Field> = vec![
+            Field::with_name(ty.clone(), "v1"),
+            Field::with_name(ty.clone(), "v2"),
+        ];
+        let values = LogicalValues::new(vec![], Schema { fields }, ctx);
+        let input_ref_1 = InputRef::new(0, ty.clone());
+
+        // Test case: select v1, grouping(v1), min(v1) from t group by grouping sets ((v1), ());
+        let grouping =
+            FunctionCall::new_unchecked(ExprType::Grouping, vec![input_ref_1.clone().into()], ty);
+        let min_v1 = AggCall::new(AggKind::Min, vec![input_ref_1.clone().into()], false).unwrap();
+        let select_exprs = vec![input_ref_1.clone().into(), grouping.into(), min_v1.into()];
+        let group_exprs = vec![input_ref_1.into()];
+        let grouping_sets = vec![vec![0], vec![]];
+
+        let (plan, exprs, _) = LogicalAgg::create(
+            select_exprs,
+            group_exprs,
+            grouping_sets,
+            None,
+            values.into(),
+        )
+        .unwrap();
+
+        // The flag of expand is the second group key, so the agg call comes after it.
+        assert_eq!(exprs.len(), 3);
+        assert_eq_input_ref!(&exprs[0], 0);
+        let case = exprs[1].as_function_call().unwrap();
+        assert_eq!(case.get_expr_type(), ExprType::Case);
+        assert_eq!(case.inputs().len(), 4);
+        assert_eq!(case.inputs()[1], ExprImpl::literal_int(0));
+        assert_eq!(case.inputs()[3], ExprImpl::literal_int(1));
+        assert_eq_input_ref!(&exprs[2], 2);
+
+        let logical_agg = plan.as_logical_agg().unwrap();
+        assert_eq!(logical_agg.group_keys(), &[0, 2]);
+        assert_eq!(logical_agg.agg_calls().len(), 1);
+        // The agg call refers to the original column instead of the nulled one.
+        assert_eq!(
+            input_ref_to_column_indices(&logical_agg.agg_calls()[0].inputs),
+            vec![1]
+        );
+
+        let expand = logical_agg.input();
+        let expand = expand.as_logical_expand().unwrap();
+        assert_eq!(expand.column_subsets(), &[vec![0], vec![]]);
+    }
     /// Generate a agg call node with given [`DataType`] and fields.
     /// For example, `generate_agg_call(Int32, [v1, v2, v3])` will result in:
     /// ```text
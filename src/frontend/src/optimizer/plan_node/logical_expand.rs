@@ -0,0 +1,285 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use fixedbitset::FixedBitSet;
+use itertools::Itertools;
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::Result;
+use risingwave_common::types::DataType;
+
+use super::{
+    BatchExpand, ColPrunable, LogicalProject, PlanBase, PlanRef, PlanTreeNodeUnary, StreamExpand,
+    ToBatch, ToStream,
+};
+use crate::utils::ColIndexMapping;
+
+/// [`LogicalExpand`] expands each input row into one row per column subset. It is used to
+/// implement `GROUPING SETS`, `CUBE` and `ROLLUP` under a hash aggregation.
+///
+/// The output schema is `[nulled input columns, original input columns, flag]`:
+/// - in the `i`-th copy of a row, the columns not contained in `column_subsets[i]` are set to NULL
+///   in the first part, whose columns are named `{name}_expanded`;
+/// - the second part always carries the original values under the original names, so that aggregate
+///   calls can still refer to columns nulled by the grouping set;
+/// - `flag` is `i`, which tells rows of different grouping sets apart.
+#[derive(Debug, Clone)]
+pub struct LogicalExpand {
+    pub base: PlanBase,
+    column_subsets: Vec<Vec<usize>>,
+    input: PlanRef,
+}
+
+impl LogicalExpand {
+    pub fn new(input: PlanRef, column_subsets: Vec<Vec<usize>>) -> Self {
+        let input_len = input.schema().len();
+        for subset in &column_subsets {
+            for idx in subset {
+                assert!(*idx < input_len);
+            }
+        }
+
+        let ctx = input.ctx();
+        let schema = Self::derive_schema(input.schema());
+        let mut pk_indices = input
+            .pk_indices()
+            .iter()
+            .map(|idx| idx + input_len)
+            .collect_vec();
+        pk_indices.push(input_len * 2);
+        let base = PlanBase::new_logical(ctx, schema, pk_indices);
+        LogicalExpand {
+            base,
+            column_subsets,
+            input,
+        }
+    }
+
+    pub fn create(input: PlanRef, column_subsets: Vec<Vec<usize>>) -> PlanRef {
+        Self::new(input, column_subsets).into()
+    }
+
+    fn derive_schema(input_schema: &Schema) -> Schema {
+        let fields = input_schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let mut field = field.clone();
+                field.name = format!("{}_expanded", field.name);
+                field
+            })
+            .chain(input_schema.fields().iter().cloned())
+            .chain(std::iter::once(Field::with_name(DataType::Int64, "flag")))
+            .collect();
+        Schema { fields }
+    }
+
+    /// Get a reference to the logical expand's column subsets.
+    pub fn column_subsets(&self) -> &[Vec<usize>] {
+        self.column_subsets.as_ref()
+    }
+
+    pub fn flag_col_idx(&self) -> usize {
+        self.input.schema().len() * 2
+    }
+
+    /// Maps the original input columns to the second part of the output, which is never nulled.
+    pub fn i2o_col_mapping(&self) -> ColIndexMapping {
+        let input_len = self.input.schema().len();
+        ColIndexMapping::with_target_size(
+            (0..input_len).map(|i| Some(i + input_len)).collect(),
+            self.schema().len(),
+        )
+    }
+
+    pub fn o2i_col_mapping(&self) -> ColIndexMapping {
+        let input_len = self.input.schema().len();
+        let map = (0..self.schema().len())
+            .map(|i| {
+                if i >= input_len && i < input_len * 2 {
+                    Some(i - input_len)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        ColIndexMapping::with_target_size(map, input_len)
+    }
+
+    pub fn fmt_with_name(&self, f: &mut fmt::Formatter, name: &str) -> fmt::Result {
+        write!(
+            f,
+            "{} {{ column_subsets: {:?} }}",
+            name, self.column_subsets
+        )
+    }
+}
+
+impl PlanTreeNodeUnary for LogicalExpand {
+    fn input(&self) -> PlanRef {
+        self.input.clone()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(input, self.column_subsets.clone())
+    }
+
+    #[must_use]
+    fn rewrite_with_input(
+        &self,
+        input: PlanRef,
+        input_col_change: ColIndexMapping,
+    ) -> (Self, ColIndexMapping) {
+        let column_subsets = self
+            .column_subsets
+            .iter()
+            .map(|subset| {
+                subset
+                    .iter()
+                    .filter_map(|&idx| input_col_change.try_map(idx))
+                    .collect_vec()
+            })
+            .collect_vec();
+        let old_input_len = self.input.schema().len();
+        let new_input_len = input.schema().len();
+        let expand = Self::new(input, column_subsets);
+
+        let mut mapping = Vec::with_capacity(self.schema().len());
+        mapping.extend((0..old_input_len).map(|idx| input_col_change.try_map(idx)));
+        mapping.extend(
+            (0..old_input_len).map(|idx| input_col_change.try_map(idx).map(|x| x + new_input_len)),
+        );
+        mapping.push(Some(new_input_len * 2));
+
+        (
+            expand,
+            ColIndexMapping::with_target_size(mapping, new_input_len * 2 + 1),
+        )
+    }
+}
+
+impl_plan_tree_node_for_unary! {LogicalExpand}
+
+impl fmt::Display for LogicalExpand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_with_name(f, "LogicalExpand")
+    }
+}
+
+impl ColPrunable for LogicalExpand {
+    fn prune_col(&self, required_cols: &[usize]) -> PlanRef {
+        let input_len = self.input.schema().len();
+        let input_required_cols = {
+            let mut tmp = FixedBitSet::with_capacity(input_len);
+            tmp.extend(
+                required_cols
+                    .iter()
+                    .filter(|&&idx| idx < input_len * 2)
+                    .map(|&idx| idx % input_len),
+            );
+            tmp.ones().collect_vec()
+        };
+        let input = self.input.prune_col(&input_required_cols);
+        let input_change = ColIndexMapping::with_remaining_columns(&input_required_cols, input_len);
+        let (new_expand, mapping) = self.rewrite_with_input(input, input_change);
+
+        let output_required_cols = required_cols
+            .iter()
+            .map(|&idx| mapping.map(idx))
+            .collect_vec();
+        LogicalProject::with_mapping(
+            new_expand.into(),
+            ColIndexMapping::with_remaining_columns(&output_required_cols, mapping.target_size()),
+        )
+        .into()
+    }
+}
+
+impl ToBatch for LogicalExpand {
+    fn to_batch(&self) -> Result<PlanRef> {
+        let new_input = self.input().to_batch()?;
+        let new_logical = self.clone_with_input(new_input);
+        Ok(BatchExpand::new(new_logical).into())
+    }
+}
+
+impl ToStream for LogicalExpand {
+    fn to_stream(&self) -> Result<PlanRef> {
+        let new_input = self.input().to_stream()?;
+        let new_logical = self.clone_with_input(new_input);
+        Ok(StreamExpand::new(new_logical).into())
+    }
+
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
+        let (input, input_col_change) = self.input.logical_rewrite_for_stream()?;
+        let (expand, out_col_change) = self.rewrite_with_input(input, input_col_change);
+        Ok((expand.into(), out_col_change))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::assert_eq_input_ref;
+    use crate::optimizer::plan_node::LogicalValues;
+    use crate::session::OptimizerContext;
+
+    #[tokio::test]
+    /// Pruning
+    /// ```text
+    /// Expand(column_subsets: [[0, 1], [2]])
+    ///   TableScan(v1, v2, v3)
+    /// ```
+    /// with required columns [1, 4, 6] will result in
+    /// ```text
+    /// Project($0, $1, $2)
+    ///   Expand(column_subsets: [[0], []])
+    ///     TableScan(v2)
+    /// ```
+    async fn test_prune_expand() {
+        let ctx = OptimizerContext::mock().await;
+        let fields: Vec<Field> = vec![
+            Field::with_name(DataType::Int32, "v1"),
+            Field::with_name(DataType::Int32, "v2"),
+            Field::with_name(DataType::Int32, "v3"),
+        ];
+        let values = LogicalValues::new(
+            vec![],
+            Schema {
+                fields: fields.clone(),
+            },
+            ctx,
+        );
+        let expand = LogicalExpand::new(values.into(), vec![vec![0, 1], vec![2]]);
+        assert_eq!(expand.schema().len(), 7);
+
+        let required_cols = vec![1, 4, 6];
+        let plan = expand.prune_col(&required_cols);
+
+        let project = plan.as_logical_project().unwrap();
+        assert_eq!(project.exprs().len(), 3);
+        assert_eq_input_ref!(&project.exprs()[0], 0);
+        assert_eq_input_ref!(&project.exprs()[1], 1);
+        assert_eq_input_ref!(&project.exprs()[2], 2);
+
+        let expand = project.input();
+        let expand = expand.as_logical_expand().unwrap();
+        assert_eq!(expand.column_subsets(), &[vec![0], vec![]]);
+        assert_eq!(expand.schema().len(), 3);
+        let values = expand.input();
+        let values = values.as_logical_values().unwrap();
+        assert_eq!(values.schema().fields(), &fields[1..2]);
+    }
+}
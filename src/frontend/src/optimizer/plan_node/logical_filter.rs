@@ -22,7 +22,7 @@ use super::{
     ToStream,
 };
 use crate::expr::{assert_input_ref, ExprImpl};
-use crate::optimizer::plan_node::{BatchFilter, StreamFilter};
+use crate::optimizer::plan_node::{BatchFilter, BatchSeqScan, StreamFilter};
 use crate::risingwave_common::error::Result;
 use crate::utils::{ColIndexMapping, Condition};
 
@@ -145,6 +145,24 @@ impl ColPrunable for LogicalFilter {
 
 impl ToBatch for LogicalFilter {
     fn to_batch(&self) -> Result<PlanRef> {
+        // Push the conditions on the order key of a scanned table down to the scan as the ranges
+        // to scan.
+        if let Some(scan) = self.input.as_logical_scan() {
+            let (scan_ranges, predicate) = self
+                .predicate
+                .clone()
+                .split_to_scan_ranges(&scan.order_key_prefix());
+            if !scan_ranges.is_empty() {
+                let new_input: PlanRef =
+                    BatchSeqScan::new_with_scan_ranges(scan.clone(), scan_ranges).into();
+                if predicate.always_true() {
+                    return Ok(new_input);
+                }
+                let new_logical = LogicalFilter::new(new_input, predicate);
+                return Ok(BatchFilter::new(new_logical).into());
+            }
+        }
+
         let new_input = self.input().to_batch()?;
         let new_logical = self.clone_with_input(new_input);
         Ok(BatchFilter::new(new_logical).into())
@@ -17,6 +17,7 @@ use std::fmt;
 use risingwave_common::catalog::{Field, Schema};
 use risingwave_common::error::Result;
 use risingwave_common::types::DataType;
+use risingwave_pb::batch_plan::UniqueIndex as ProstUniqueIndex;
 
 use super::{BatchInsert, ColPrunable, PlanBase, PlanRef, PlanTreeNodeUnary, ToBatch, ToStream};
 use crate::catalog::TableId;
@@ -30,12 +31,22 @@ pub struct LogicalInsert {
     pub base: PlanBase,
     table_source_name: String, // explain-only
     source_id: TableId,        // TODO: use SourceId
+    /// Indices of the user-defined primary key in `input`, which can't be NULL.
+    pk_indices: Vec<usize>,
+    /// The unique indexes on the table, which the inserted rows are checked against.
+    unique_indexes: Vec<ProstUniqueIndex>,
     input: PlanRef,
 }
 
 impl LogicalInsert {
     /// Create a [`LogicalInsert`] node. Used internally by optimizer.
-    pub fn new(input: PlanRef, table_source_name: String, source_id: TableId) -> Self {
+    pub fn new(
+        input: PlanRef,
+        table_source_name: String,
+        source_id: TableId,
+        pk_indices: Vec<usize>,
+        unique_indexes: Vec<ProstUniqueIndex>,
+    ) -> Self {
         let ctx = input.ctx();
         let schema = Schema::new(vec![Field::unnamed(DataType::Int64)]);
         let base = PlanBase::new_logical(ctx, schema, vec![]);
@@ -43,13 +54,27 @@ impl LogicalInsert {
             base,
             table_source_name,
             source_id,
+            pk_indices,
+            unique_indexes,
             input,
         }
     }
 
     /// Create a [`LogicalInsert`] node. Used by planner.
-    pub fn create(input: PlanRef, table_source_name: String, source_id: TableId) -> Result<Self> {
-        Ok(Self::new(input, table_source_name, source_id))
+    pub fn create(
+        input: PlanRef,
+        table_source_name: String,
+        source_id: TableId,
+        pk_indices: Vec<usize>,
+        unique_indexes: Vec<ProstUniqueIndex>,
+    ) -> Result<Self> {
+        Ok(Self::new(
+            input,
+            table_source_name,
+            source_id,
+            pk_indices,
+            unique_indexes,
+        ))
     }
 
     pub(super) fn fmt_with_name(&self, f: &mut fmt::Formatter, name: &str) -> fmt::Result {
@@ -61,6 +86,18 @@ impl LogicalInsert {
     pub fn source_id(&self) -> TableId {
         self.source_id
     }
+
+    /// Get the indices of the user-defined primary key in the input.
+    #[must_use]
+    pub fn pk_indices(&self) -> &[usize] {
+        &self.pk_indices
+    }
+
+    /// Get the unique indexes on the table.
+    #[must_use]
+    pub fn unique_indexes(&self) -> &[ProstUniqueIndex] {
+        &self.unique_indexes
+    }
 }
 
 impl PlanTreeNodeUnary for LogicalInsert {
@@ -69,7 +106,13 @@ impl PlanTreeNodeUnary for LogicalInsert {
     }
 
     fn clone_with_input(&self, input: PlanRef) -> Self {
-        Self::new(input, self.table_source_name.clone(), self.source_id)
+        Self::new(
+            input,
+            self.table_source_name.clone(),
+            self.source_id,
+            self.pk_indices.clone(),
+            self.unique_indexes.clone(),
+        )
     }
 }
 
@@ -237,6 +237,15 @@ impl LogicalJoin {
     pub fn is_right_join(&self) -> bool {
         matches!(self.join_type(), JoinType::RightSemi | JoinType::RightAnti)
     }
+
+    /// Whether the join can run with a copy of the right side on every shard of the left side,
+    /// i.e. the right rows matching no left row never show up in the output.
+    pub fn can_broadcast_right(&self) -> bool {
+        matches!(
+            self.join_type(),
+            JoinType::Inner | JoinType::LeftOuter | JoinType::LeftSemi | JoinType::LeftAnti
+        )
+    }
 }
 
 impl PlanTreeNodeBinary for LogicalJoin {
@@ -396,6 +405,31 @@ impl ToStream for LogicalJoin {
             self.right.schema().len(),
             self.on.clone(),
         );
+
+        if !predicate.has_eq() {
+            if !self.can_broadcast_right() {
+                return Err(RwError::from(ErrorCode::NotImplemented(
+                    "stream nested-loop join".to_string(),
+                    None.into(),
+                )));
+            }
+            // All rows share the empty join key, so the right side is broadcast to the shards of
+            // the left side instead of gathering both sides on one of them.
+            let mut left = self.left().to_stream()?;
+            if !matches!(
+                left.distribution(),
+                Distribution::HashShard(_) | Distribution::Single
+            ) {
+                left =
+                    Distribution::HashShard(left.pk_indices().to_vec()).enforce(left, Order::any());
+            }
+            let right = self
+                .right()
+                .to_stream_with_dist_required(&Distribution::Broadcast)?;
+            let logical_join = self.clone_with_left_right(left, right);
+            return Ok(StreamHashJoin::new(logical_join, predicate).into());
+        }
+
         let right = self
             .right()
             .to_stream_with_dist_required(&Distribution::HashShard(predicate.right_eq_indexes()))?;
@@ -413,28 +447,21 @@ impl ToStream for LogicalJoin {
         }
         let logical_join = self.clone_with_left_right(left, right);
 
-        if predicate.has_eq() {
-            // Convert to Hash Join for equal joins
-            // For inner joins, pull non-equal conditions to a filter operator on top of it
-            let pull_filter = self.join_type == JoinType::Inner && predicate.has_non_eq();
-            if pull_filter {
-                let eq_cond = EqJoinPredicate::new(
-                    Condition::true_cond(),
-                    predicate.eq_keys().to_vec(),
-                    self.left.schema().len(),
-                );
-                let logical_join = logical_join.clone_with_cond(eq_cond.eq_cond());
-                let hash_join = StreamHashJoin::new(logical_join, eq_cond).into();
-                let logical_filter = LogicalFilter::new(hash_join, predicate.non_eq_cond());
-                Ok(StreamFilter::new(logical_filter).into())
-            } else {
-                Ok(StreamHashJoin::new(logical_join, predicate).into())
-            }
+        // Convert to Hash Join for equal joins
+        // For inner joins, pull non-equal conditions to a filter operator on top of it
+        let pull_filter = self.join_type == JoinType::Inner && predicate.has_non_eq();
+        if pull_filter {
+            let eq_cond = EqJoinPredicate::new(
+                Condition::true_cond(),
+                predicate.eq_keys().to_vec(),
+                self.left.schema().len(),
+            );
+            let logical_join = logical_join.clone_with_cond(eq_cond.eq_cond());
+            let hash_join = StreamHashJoin::new(logical_join, eq_cond).into();
+            let logical_filter = LogicalFilter::new(hash_join, predicate.non_eq_cond());
+            Ok(StreamFilter::new(logical_filter).into())
         } else {
-            Err(RwError::from(ErrorCode::NotImplemented(
-                "stream nested-loop join".to_string(),
-                None.into(),
-            )))
+            Ok(StreamHashJoin::new(logical_join, predicate).into())
         }
     }
 
@@ -0,0 +1,174 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use risingwave_common::catalog::{ColumnDesc, Schema, TableDesc};
+use risingwave_common::error::{ErrorCode, Result, RwError};
+
+use super::{BatchLookup, ColPrunable, PlanBase, PlanRef, PlanTreeNodeUnary, ToBatch, ToStream};
+use crate::utils::ColIndexMapping;
+
+/// `LogicalLookup` gets the row of a table with the primary key of each input row, e.g. to fetch
+/// the columns not in an index for the rows found by scanning the index.
+#[derive(Debug, Clone)]
+pub struct LogicalLookup {
+    pub base: PlanBase,
+    input: PlanRef,
+    table_name: String, // explain-only
+    /// The index of the looked up columns in the table.
+    required_col_idx: Vec<usize>,
+    table_desc: Rc<TableDesc>,
+    /// The indices of the input columns of the order key of the table.
+    lookup_key: Vec<usize>,
+}
+
+impl LogicalLookup {
+    pub fn new(
+        input: PlanRef,
+        table_name: String,
+        required_col_idx: Vec<usize>,
+        table_desc: Rc<TableDesc>,
+        lookup_key: Vec<usize>,
+    ) -> Self {
+        assert_eq!(lookup_key.len(), table_desc.order_desc.len());
+        let ctx = input.ctx();
+
+        let mut id_to_op_idx = HashMap::new();
+        let fields = required_col_idx
+            .iter()
+            .enumerate()
+            .map(|(op_idx, tb_idx)| {
+                let col = &table_desc.columns[*tb_idx];
+                id_to_op_idx.insert(col.column_id, op_idx);
+                col.into()
+            })
+            .collect();
+        let pk_indices = table_desc
+            .pks
+            .iter()
+            .map(|&c| id_to_op_idx.get(&table_desc.columns[c].column_id).copied())
+            .collect::<Option<Vec<_>>>()
+            .unwrap_or_default();
+
+        let base = PlanBase::new_logical(ctx, Schema { fields }, pk_indices);
+        Self {
+            base,
+            input,
+            table_name,
+            required_col_idx,
+            table_desc,
+            lookup_key,
+        }
+    }
+
+    pub fn table_name(&self) -> &str {
+        &self.table_name
+    }
+
+    pub fn table_desc(&self) -> &TableDesc {
+        self.table_desc.as_ref()
+    }
+
+    pub fn lookup_key(&self) -> &[usize] {
+        &self.lookup_key
+    }
+
+    /// Get the descs of the looked up columns.
+    pub fn column_descs(&self) -> Vec<ColumnDesc> {
+        self.required_col_idx
+            .iter()
+            .map(|i| self.table_desc.columns[*i].clone())
+            .collect()
+    }
+
+    pub(super) fn column_names(&self) -> Vec<String> {
+        self.schema()
+            .fields()
+            .iter()
+            .map(|f| f.name.clone())
+            .collect()
+    }
+}
+
+impl PlanTreeNodeUnary for LogicalLookup {
+    fn input(&self) -> PlanRef {
+        self.input.clone()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(
+            input,
+            self.table_name.clone(),
+            self.required_col_idx.clone(),
+            self.table_desc.clone(),
+            self.lookup_key.clone(),
+        )
+    }
+}
+impl_plan_tree_node_for_unary! {LogicalLookup}
+
+impl fmt::Display for LogicalLookup {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "LogicalLookup {{ table: {}, columns: [{}] }}",
+            self.table_name,
+            self.column_names().join(", ")
+        )
+    }
+}
+
+impl ColPrunable for LogicalLookup {
+    fn prune_col(&self, required_cols: &[usize]) -> PlanRef {
+        let required_col_idx = required_cols
+            .iter()
+            .map(|i| self.required_col_idx[*i])
+            .collect();
+        Self::new(
+            self.input.clone(),
+            self.table_name.clone(),
+            required_col_idx,
+            self.table_desc.clone(),
+            self.lookup_key.clone(),
+        )
+        .into()
+    }
+}
+
+impl ToBatch for LogicalLookup {
+    fn to_batch(&self) -> Result<PlanRef> {
+        let new_input = self.input().to_batch()?;
+        let new_logical = self.clone_with_input(new_input);
+        Ok(BatchLookup::new(new_logical).into())
+    }
+}
+
+impl ToStream for LogicalLookup {
+    fn to_stream(&self) -> Result<PlanRef> {
+        Err(RwError::from(ErrorCode::NotImplemented(
+            "there is no lookup stream operator".to_string(),
+            None.into(),
+        )))
+    }
+
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
+        Err(RwError::from(ErrorCode::NotImplemented(
+            "there is no lookup stream operator".to_string(),
+            None.into(),
+        )))
+    }
+}
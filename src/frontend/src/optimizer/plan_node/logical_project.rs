@@ -46,6 +46,7 @@ impl LogicalProject {
             assert_input_ref!(expr, input.schema().fields().len());
             assert!(!expr.has_subquery());
             assert!(!expr.has_agg_call());
+            assert!(!expr.has_table_function());
         }
         let base = PlanBase::new_logical(ctx, schema, pk_indices);
         LogicalProject { base, exprs, input }
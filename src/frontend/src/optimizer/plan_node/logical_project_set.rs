@@ -0,0 +1,377 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use fixedbitset::FixedBitSet;
+use itertools::Itertools;
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::Result;
+use risingwave_common::types::DataType;
+use risingwave_pb::expr::project_set_select_item::SelectItem;
+use risingwave_pb::expr::ProjectSetSelectItem;
+
+use super::{
+    BatchProjectSet, ColPrunable, LogicalProject, PlanBase, PlanRef, PlanTreeNodeUnary,
+    StreamProjectSet, ToBatch, ToStream,
+};
+use crate::expr::{assert_input_ref, Expr, ExprImpl, ExprRewriter, ExprVisitor, InputRef};
+use crate::optimizer::plan_node::CollectInputRef;
+use crate::utils::ColIndexMapping;
+
+/// `LogicalProjectSet` projects each input row into zero or more rows according to
+/// `select_list`, which may contain table functions (a.k.a. set-returning functions) at the top
+/// level, e.g. `SELECT unnest(arr), v FROM t`.
+///
+/// Each row is expanded into as many rows as the longest output of the table functions. The
+/// shorter outputs are padded with NULLs and the other expressions are repeated.
+///
+/// The output schema is `[projected_row_id, select_list...]`, where `projected_row_id` is the
+/// index of the row in the expansion. Together with the input pk, it forms the pk of the output.
+#[derive(Debug, Clone)]
+pub struct LogicalProjectSet {
+    pub base: PlanBase,
+    select_list: Vec<ExprImpl>,
+    input: PlanRef,
+}
+
+impl LogicalProjectSet {
+    pub fn new(input: PlanRef, select_list: Vec<ExprImpl>) -> Self {
+        assert!(
+            select_list.iter().any(|e| e.has_table_function()),
+            "ProjectSet should have at least one table function."
+        );
+        for expr in &select_list {
+            assert_input_ref!(expr, input.schema().fields().len());
+            assert!(!expr.has_subquery());
+            assert!(!expr.has_agg_call());
+            if let ExprImpl::TableFunction(table_function) = expr {
+                assert!(table_function.args.iter().all(|e| !e.has_table_function()));
+            } else {
+                assert!(!expr.has_table_function());
+            }
+        }
+
+        let ctx = input.ctx();
+        let schema = Self::derive_schema(&select_list, input.schema());
+        let pk_indices = Self::derive_pk(input.schema(), input.pk_indices(), &select_list);
+        let base = PlanBase::new_logical(ctx, schema, pk_indices);
+        LogicalProjectSet {
+            base,
+            select_list,
+            input,
+        }
+    }
+
+    pub fn create(input: PlanRef, select_list: Vec<ExprImpl>) -> PlanRef {
+        Self::new(input, select_list).into()
+    }
+
+    /// Maps the output columns to the input columns. `projected_row_id` and the table functions
+    /// are not mapped.
+    fn o2i_col_mapping_inner(input_len: usize, select_list: &[ExprImpl]) -> ColIndexMapping {
+        let map = std::iter::once(None)
+            .chain(select_list.iter().map(|expr| match expr {
+                ExprImpl::InputRef(input) => Some(input.index()),
+                _ => None,
+            }))
+            .collect();
+        ColIndexMapping::with_target_size(map, input_len)
+    }
+
+    /// If an input column corresponds to more than one output columns, maps to any one of them.
+    fn i2o_col_mapping_inner(input_len: usize, select_list: &[ExprImpl]) -> ColIndexMapping {
+        Self::o2i_col_mapping_inner(input_len, select_list).inverse()
+    }
+
+    pub fn o2i_col_mapping(&self) -> ColIndexMapping {
+        Self::o2i_col_mapping_inner(self.input.schema().len(), self.select_list())
+    }
+
+    pub fn i2o_col_mapping(&self) -> ColIndexMapping {
+        Self::i2o_col_mapping_inner(self.input.schema().len(), self.select_list())
+    }
+
+    fn derive_schema(select_list: &[ExprImpl], input_schema: &Schema) -> Schema {
+        let o2i = Self::o2i_col_mapping_inner(input_schema.len(), select_list);
+        let fields =
+            std::iter::once(Field::with_name(DataType::Int64, "projected_row_id"))
+                .chain(select_list.iter().enumerate().map(
+                    |(idx, expr)| match o2i.try_map(idx + 1) {
+                        Some(input_idx) => input_schema.fields()[input_idx].clone(),
+                        None => {
+                            let name = match expr {
+                                ExprImpl::TableFunction(table_function) => {
+                                    table_function.name().to_string()
+                                }
+                                _ => format!("expr#{}", idx + 1),
+                            };
+                            Field::with_name(expr.return_type(), name)
+                        }
+                    },
+                ))
+                .collect();
+        Schema { fields }
+    }
+
+    fn derive_pk(
+        input_schema: &Schema,
+        input_pk: &[usize],
+        select_list: &[ExprImpl],
+    ) -> Vec<usize> {
+        let i2o = Self::i2o_col_mapping_inner(input_schema.len(), select_list);
+        input_pk
+            .iter()
+            .map(|pk_col| i2o.try_map(*pk_col))
+            .collect::<Option<Vec<_>>>()
+            .map(|pk| std::iter::once(0).chain(pk).collect())
+            .unwrap_or_default()
+    }
+
+    pub fn select_list(&self) -> &Vec<ExprImpl> {
+        &self.select_list
+    }
+
+    /// Converts the select list to the protobuf, where the table functions are kept apart from the
+    /// other expressions.
+    pub fn select_list_to_protobuf(&self) -> Vec<ProjectSetSelectItem> {
+        self.select_list
+            .iter()
+            .map(|expr| {
+                let select_item = match expr {
+                    ExprImpl::TableFunction(table_function) => {
+                        SelectItem::TableFunction(table_function.to_protobuf())
+                    }
+                    expr => SelectItem::Expr(expr.to_expr_proto()),
+                };
+                ProjectSetSelectItem {
+                    select_item: Some(select_item),
+                }
+            })
+            .collect()
+    }
+
+    pub(super) fn fmt_with_name(&self, f: &mut fmt::Formatter, name: &str) -> fmt::Result {
+        f.debug_struct(name)
+            .field("select_list", self.select_list())
+            .finish()
+    }
+}
+
+impl PlanTreeNodeUnary for LogicalProjectSet {
+    fn input(&self) -> PlanRef {
+        self.input.clone()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(input, self.select_list.clone())
+    }
+
+    #[must_use]
+    fn rewrite_with_input(
+        &self,
+        input: PlanRef,
+        mut input_col_change: ColIndexMapping,
+    ) -> (Self, ColIndexMapping) {
+        let select_list = self
+            .select_list
+            .clone()
+            .into_iter()
+            .map(|expr| input_col_change.rewrite_expr(expr))
+            .collect();
+        let project_set = Self::new(input, select_list);
+        // change the input columns index will not change the output column index
+        let out_col_change = ColIndexMapping::identity(self.schema().len());
+        (project_set, out_col_change)
+    }
+}
+
+impl_plan_tree_node_for_unary! {LogicalProjectSet}
+
+impl fmt::Display for LogicalProjectSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_with_name(f, "LogicalProjectSet")
+    }
+}
+
+impl ColPrunable for LogicalProjectSet {
+    fn prune_col(&self, required_cols: &[usize]) -> PlanRef {
+        // The table functions decide the number of output rows, so they are always kept, even if
+        // not required. So is `projected_row_id`.
+        let kept_items = {
+            let mut tmp = FixedBitSet::with_capacity(self.select_list.len());
+            tmp.extend(
+                required_cols
+                    .iter()
+                    .filter(|&&idx| idx > 0)
+                    .map(|&idx| idx - 1),
+            );
+            tmp.extend(
+                self.select_list
+                    .iter()
+                    .positions(|expr| matches!(expr, ExprImpl::TableFunction(_))),
+            );
+            tmp.ones().collect_vec()
+        };
+
+        let input_col_num = self.input.schema().len();
+        let mut input_ref_collector = CollectInputRef::with_capacity(input_col_num);
+        kept_items
+            .iter()
+            .for_each(|&idx| input_ref_collector.visit_expr(&self.select_list[idx]));
+        let input_required_cols = FixedBitSet::from(input_ref_collector).ones().collect_vec();
+        let new_input = self.input.prune_col(&input_required_cols);
+        let mut mapping =
+            ColIndexMapping::with_remaining_columns(&input_required_cols, input_col_num);
+        let select_list = kept_items
+            .iter()
+            .map(|&idx| mapping.rewrite_expr(self.select_list[idx].clone()))
+            .collect();
+        let new_project_set = Self::new(new_input, select_list);
+
+        // Map the required columns to the output of the pruned `ProjectSet`.
+        let output_mapping = ColIndexMapping::with_remaining_columns(
+            &std::iter::once(0)
+                .chain(kept_items.iter().map(|idx| idx + 1))
+                .collect_vec(),
+            self.schema().len(),
+        );
+        let output_required_cols = required_cols
+            .iter()
+            .map(|&idx| output_mapping.map(idx))
+            .collect_vec();
+        LogicalProject::with_mapping(
+            new_project_set.into(),
+            ColIndexMapping::with_remaining_columns(
+                &output_required_cols,
+                output_mapping.target_size(),
+            ),
+        )
+        .into()
+    }
+}
+
+impl ToBatch for LogicalProjectSet {
+    fn to_batch(&self) -> Result<PlanRef> {
+        let new_input = self.input().to_batch()?;
+        let new_logical = self.clone_with_input(new_input);
+        Ok(BatchProjectSet::new(new_logical).into())
+    }
+}
+
+impl ToStream for LogicalProjectSet {
+    fn to_stream(&self) -> Result<PlanRef> {
+        let new_input = self.input().to_stream()?;
+        let new_logical = self.clone_with_input(new_input);
+        Ok(StreamProjectSet::new(new_logical).into())
+    }
+
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
+        let (input, input_col_change) = self.input.logical_rewrite_for_stream()?;
+        let (project_set, out_col_change) =
+            self.rewrite_with_input(input.clone(), input_col_change);
+
+        // Add the input pk columns missing from the select list, so that the output has a pk.
+        let input_pk = input.pk_indices();
+        let i2o = Self::i2o_col_mapping_inner(input.schema().len(), project_set.select_list());
+        let col_need_to_add = input_pk.iter().cloned().filter(|i| i2o.try_map(*i) == None);
+        let input_schema = input.schema();
+        let select_list =
+            project_set
+                .select_list()
+                .iter()
+                .cloned()
+                .chain(col_need_to_add.map(|idx| {
+                    InputRef::new(idx, input_schema.fields[idx].data_type.clone()).into()
+                }))
+                .collect();
+        let project_set = Self::new(input, select_list);
+        // The added columns are at the end, so the existing column indices are not changed.
+        Ok((project_set.into(), out_col_change))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::{assert_eq_input_ref, TableFunction, TableFunctionType};
+    use crate::optimizer::plan_node::LogicalValues;
+    use crate::session::OptimizerContext;
+
+    #[tokio::test]
+    /// Pruning
+    /// ```text
+    /// ProjectSet($1, unnest($0), $2)
+    ///   TableScan(v1, v2, v3)
+    /// ```
+    /// with required columns [3] will result in
+    /// ```text
+    /// Project($2)
+    ///   ProjectSet(unnest($0), $1)
+    ///     TableScan(v1, v3)
+    /// ```
+    async fn test_prune_project_set() {
+        let ctx = OptimizerContext::mock().await;
+        let list_type = DataType::List {
+            datatype: Box::new(DataType::Int32),
+        };
+        let fields: Vec<Field> = vec![
+            Field::with_name(list_type.clone(), "v1"),
+            Field::with_name(DataType::Int32, "v2"),
+            Field::with_name(DataType::Int32, "v3"),
+        ];
+        let values = LogicalValues::new(
+            vec![],
+            Schema {
+                fields: fields.clone(),
+            },
+            ctx,
+        );
+        let unnest = TableFunction::new(
+            TableFunctionType::Unnest,
+            vec![InputRef::new(0, list_type).into()],
+        )
+        .unwrap();
+        let project_set = LogicalProjectSet::new(
+            values.into(),
+            vec![
+                InputRef::new(1, DataType::Int32).into(),
+                unnest.into(),
+                InputRef::new(2, DataType::Int32).into(),
+            ],
+        );
+        assert_eq!(project_set.schema().len(), 4);
+
+        let plan = project_set.prune_col(&[3]);
+
+        let project = plan.as_logical_project().unwrap();
+        assert_eq!(project.exprs().len(), 1);
+        assert_eq_input_ref!(&project.exprs()[0], 2);
+
+        let project_set = project.input();
+        let project_set = project_set.as_logical_project_set().unwrap();
+        assert_eq!(project_set.select_list().len(), 2);
+        match &project_set.select_list()[0] {
+            ExprImpl::TableFunction(unnest) => assert_eq_input_ref!(&unnest.args[0], 0),
+            _ => panic!("expect unnest"),
+        }
+        assert_eq_input_ref!(&project_set.select_list()[1], 1);
+        let values = project_set.input();
+        let values = values.as_logical_values().unwrap();
+        assert_eq!(
+            values.schema().fields(),
+            &[fields[0].clone(), fields[2].clone()]
+        );
+    }
+}
@@ -150,8 +150,9 @@ impl LogicalScan {
             .collect()
     }
 
-    pub fn to_index_scan(&self, index_name: &str, index: &Rc<TableDesc>) -> LogicalScan {
-        let mut new_required_col_idx = Vec::with_capacity(self.required_col_idx.len());
+    /// Create a scan on the index which outputs the same columns as this scan. Returns `None` if
+    /// the index doesn't cover all the required columns.
+    pub fn to_index_scan(&self, index_name: &str, index: &Rc<TableDesc>) -> Option<LogicalScan> {
         let all_columns = index
             .columns
             .iter()
@@ -160,18 +161,45 @@ impl LogicalScan {
             .collect::<HashMap<_, _>>();
 
         // create index scan plan to match the output order of the current table scan
-        for &col_idx in &self.required_col_idx {
-            let column_idx_in_index = all_columns[&self.table_desc.columns[col_idx].column_id];
-            new_required_col_idx.push(column_idx_in_index);
-        }
+        let new_required_col_idx = self
+            .required_col_idx
+            .iter()
+            .map(|&col_idx| {
+                all_columns
+                    .get(&self.table_desc.columns[col_idx].column_id)
+                    .copied()
+            })
+            .collect::<Option<Vec<_>>>()?;
 
-        Self::new(
+        Some(Self::new(
             index_name.to_string(),
             new_required_col_idx,
             index.clone(),
             vec![],
             self.ctx(),
-        )
+        ))
+    }
+
+    /// Get the table indices of the required columns, i.e. the mapping from the output column
+    /// index to the column index in the table.
+    pub fn required_col_idx(&self) -> &[usize] {
+        &self.required_col_idx
+    }
+
+    /// Get the output column indices of the leading order key columns of the table, till the first
+    /// one not in the output.
+    pub fn order_key_prefix(&self) -> Vec<usize> {
+        let id_to_op_idx = self
+            .required_col_idx
+            .iter()
+            .enumerate()
+            .map(|(op_idx, tb_idx)| (self.table_desc.columns[*tb_idx].column_id, op_idx))
+            .collect::<HashMap<_, _>>();
+        self.table_desc
+            .order_desc
+            .iter()
+            .map_while(|order| id_to_op_idx.get(&order.column_desc.column_id).copied())
+            .collect()
     }
 }
 
@@ -0,0 +1,149 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::rc::Rc;
+
+use itertools::Itertools;
+use risingwave_common::array::Row;
+use risingwave_common::catalog::Schema;
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_pb::catalog::VirtualTable as ProstVirtualTable;
+
+use super::{BatchSysSeqScan, ColPrunable, PlanBase, PlanRef, ToBatch, ToStream};
+use crate::catalog::system_catalog::virtual_table_fields;
+use crate::session::OptimizerContextRef;
+use crate::utils::ColIndexMapping;
+
+/// `LogicalSysScan` returns the contents of a system table, whose rows are generated from the
+/// catalog by the binder.
+#[derive(Debug, Clone)]
+pub struct LogicalSysScan {
+    pub base: PlanBase,
+    table: Rc<ProstVirtualTable>,
+    /// The index of the scanned columns in the table.
+    required_col_idx: Vec<usize>,
+    /// All the rows of the table, with all of its columns.
+    rows: Rc<[Row]>,
+}
+
+impl LogicalSysScan {
+    /// Create a `LogicalSysScan` node. Used internally by optimizer.
+    pub fn new(
+        table: Rc<ProstVirtualTable>,
+        required_col_idx: Vec<usize>,
+        rows: Rc<[Row]>,
+        ctx: OptimizerContextRef,
+    ) -> Self {
+        let table_fields = virtual_table_fields(&table);
+        let fields = required_col_idx
+            .iter()
+            .map(|idx| table_fields[*idx].clone())
+            .collect();
+        let base = PlanBase::new_logical(ctx, Schema { fields }, vec![]);
+        Self {
+            base,
+            table,
+            required_col_idx,
+            rows,
+        }
+    }
+
+    /// Create a `LogicalSysScan` node scanning all the columns. Used by planner.
+    pub fn create(table: ProstVirtualTable, rows: Vec<Row>, ctx: OptimizerContextRef) -> PlanRef {
+        let required_col_idx = (0..table.columns.len()).collect();
+        Self::new(Rc::new(table), required_col_idx, rows.into(), ctx).into()
+    }
+
+    pub fn table(&self) -> &ProstVirtualTable {
+        &self.table
+    }
+
+    pub fn required_col_idx(&self) -> &[usize] {
+        &self.required_col_idx
+    }
+
+    /// The rows of the table with the scanned columns only.
+    pub fn projected_rows(&self) -> Vec<Row> {
+        self.rows
+            .iter()
+            .map(|row| {
+                Row(self
+                    .required_col_idx
+                    .iter()
+                    .map(|idx| row.0[*idx].clone())
+                    .collect())
+            })
+            .collect()
+    }
+
+    pub fn column_names(&self) -> Vec<String> {
+        self.schema()
+            .fields()
+            .iter()
+            .map(|field| field.name.clone())
+            .collect()
+    }
+}
+
+impl_plan_tree_node_for_leaf! { LogicalSysScan }
+
+impl fmt::Display for LogicalSysScan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "LogicalSysScan {{ table: {}, columns: [{}] }}",
+            self.table.name,
+            self.column_names().join(", ")
+        )
+    }
+}
+
+impl ColPrunable for LogicalSysScan {
+    fn prune_col(&self, required_cols: &[usize]) -> PlanRef {
+        let required_col_idx = required_cols
+            .iter()
+            .map(|i| self.required_col_idx[*i])
+            .collect_vec();
+        Self::new(
+            self.table.clone(),
+            required_col_idx,
+            self.rows.clone(),
+            self.base.ctx.clone(),
+        )
+        .into()
+    }
+}
+
+impl ToBatch for LogicalSysScan {
+    fn to_batch(&self) -> Result<PlanRef> {
+        Ok(BatchSysSeqScan::new(self.clone()).into())
+    }
+}
+
+impl ToStream for LogicalSysScan {
+    fn to_stream(&self) -> Result<PlanRef> {
+        Err(RwError::from(ErrorCode::NotImplemented(
+            "streaming on system tables".to_string(),
+            None.into(),
+        )))
+    }
+
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
+        Err(RwError::from(ErrorCode::NotImplemented(
+            "streaming on system tables".to_string(),
+            None.into(),
+        )))
+    }
+}
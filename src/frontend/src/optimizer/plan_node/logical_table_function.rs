@@ -0,0 +1,99 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::{ErrorCode, Result};
+
+use super::{ColPrunable, PlanBase, PlanRef, ToBatch, ToStream};
+use crate::expr::TableFunction;
+use crate::optimizer::plan_node::BatchTableFunction;
+use crate::session::OptimizerContextRef;
+
+/// `LogicalTableFunction` is a table function in the `FROM` clause, whose arguments are
+/// constants, e.g. `SELECT * FROM jsonb_array_elements('[1, 2]')`.
+#[derive(Debug, Clone)]
+pub struct LogicalTableFunction {
+    pub base: PlanBase,
+    pub(super) table_function: TableFunction,
+}
+
+impl LogicalTableFunction {
+    /// Create a [`LogicalTableFunction`] node. Used internally by optimizer.
+    pub fn new(table_function: TableFunction, ctx: OptimizerContextRef) -> Self {
+        let schema = Schema::new(vec![Field::with_name(
+            table_function.return_type.clone(),
+            table_function.name(),
+        )]);
+        let base = PlanBase::new_logical(ctx, schema, vec![]);
+        Self {
+            base,
+            table_function,
+        }
+    }
+
+    /// Create a [`LogicalTableFunction`] node. Used by planner.
+    pub fn create(table_function: TableFunction, ctx: OptimizerContextRef) -> PlanRef {
+        // No additional checks after binder.
+        Self::new(table_function, ctx).into()
+    }
+
+    pub fn table_function(&self) -> &TableFunction {
+        &self.table_function
+    }
+
+    pub fn fmt_with_name(&self, f: &mut fmt::Formatter, name: &str) -> fmt::Result {
+        write!(f, "{} {{ {:?} }}", name, self.table_function)
+    }
+}
+
+impl_plan_tree_node_for_leaf! { LogicalTableFunction }
+
+impl fmt::Display for LogicalTableFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_with_name(f, "LogicalTableFunction")
+    }
+}
+
+// the leaf node don't need colprunable
+impl ColPrunable for LogicalTableFunction {
+    fn prune_col(&self, required_cols: &[usize]) -> PlanRef {
+        let _ = required_cols;
+        self.clone().into()
+    }
+}
+
+impl ToBatch for LogicalTableFunction {
+    fn to_batch(&self) -> Result<PlanRef> {
+        Ok(BatchTableFunction::new(self.clone()).into())
+    }
+}
+
+impl ToStream for LogicalTableFunction {
+    fn to_stream(&self) -> Result<PlanRef> {
+        Err(
+            ErrorCode::NotImplemented("LogicalTableFunction::to_stream".to_string(), None.into())
+                .into(),
+        )
+    }
+
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, crate::utils::ColIndexMapping)> {
+        Err(ErrorCode::NotImplemented(
+            "LogicalTableFunction::logical_rewrite_for_stream".to_string(),
+            None.into(),
+        )
+        .into())
+    }
+}
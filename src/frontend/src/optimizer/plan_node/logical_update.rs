@@ -0,0 +1,169 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::{fmt, vec};
+
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::Result;
+use risingwave_common::types::DataType;
+use risingwave_pb::batch_plan::UniqueIndex as ProstUniqueIndex;
+
+use super::{BatchUpdate, ColPrunable, PlanBase, PlanRef, PlanTreeNodeUnary, ToBatch, ToStream};
+use crate::catalog::TableId;
+use crate::expr::ExprImpl;
+
+/// [`LogicalUpdate`] iterates on input relation, set some columns, and inject update records into
+/// specified table.
+///
+/// It corresponds to the `UPDATE` statements in SQL.
+#[derive(Debug, Clone)]
+pub struct LogicalUpdate {
+    pub base: PlanBase,
+    table_source_name: String, // explain-only
+    source_id: TableId,        // TODO: use SourceId
+    exprs: Vec<ExprImpl>,
+    /// Indices of the user-defined primary key in `exprs`, which can't be NULL.
+    pk_indices: Vec<usize>,
+    /// The unique indexes on the table, which the updated rows are checked against.
+    unique_indexes: Vec<ProstUniqueIndex>,
+    input: PlanRef,
+}
+
+impl LogicalUpdate {
+    /// Create a [`LogicalUpdate`] node. Used internally by optimizer.
+    pub fn new(
+        input: PlanRef,
+        table_source_name: String,
+        source_id: TableId,
+        exprs: Vec<ExprImpl>,
+        pk_indices: Vec<usize>,
+        unique_indexes: Vec<ProstUniqueIndex>,
+    ) -> Self {
+        let ctx = input.ctx();
+        // TODO: support `RETURNING`.
+        let schema = Schema::new(vec![Field::unnamed(DataType::Int64)]);
+        let base = PlanBase::new_logical(ctx, schema, vec![]);
+        Self {
+            base,
+            table_source_name,
+            source_id,
+            exprs,
+            pk_indices,
+            unique_indexes,
+            input,
+        }
+    }
+
+    /// Create a [`LogicalUpdate`] node. Used by planner.
+    pub fn create(
+        input: PlanRef,
+        table_source_name: String,
+        source_id: TableId,
+        exprs: Vec<ExprImpl>,
+        pk_indices: Vec<usize>,
+        unique_indexes: Vec<ProstUniqueIndex>,
+    ) -> Result<Self> {
+        Ok(Self::new(
+            input,
+            table_source_name,
+            source_id,
+            exprs,
+            pk_indices,
+            unique_indexes,
+        ))
+    }
+
+    pub(super) fn fmt_with_name(&self, f: &mut fmt::Formatter, name: &str) -> fmt::Result {
+        write!(
+            f,
+            "{} {{ table: {}, exprs: {:?} }}",
+            name, self.table_source_name, self.exprs
+        )
+    }
+
+    /// Get the logical update's source id.
+    #[must_use]
+    pub fn source_id(&self) -> TableId {
+        self.source_id
+    }
+
+    /// Get a reference to the logical update's exprs.
+    #[must_use]
+    pub fn exprs(&self) -> &[ExprImpl] {
+        self.exprs.as_ref()
+    }
+
+    /// Get the indices of the user-defined primary key in the exprs.
+    #[must_use]
+    pub fn pk_indices(&self) -> &[usize] {
+        &self.pk_indices
+    }
+
+    /// Get the unique indexes on the table.
+    #[must_use]
+    pub fn unique_indexes(&self) -> &[ProstUniqueIndex] {
+        &self.unique_indexes
+    }
+}
+
+impl PlanTreeNodeUnary for LogicalUpdate {
+    fn input(&self) -> PlanRef {
+        self.input.clone()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(
+            input,
+            self.table_source_name.clone(),
+            self.source_id,
+            self.exprs.clone(),
+            self.pk_indices.clone(),
+            self.unique_indexes.clone(),
+        )
+    }
+}
+
+impl_plan_tree_node_for_unary! { LogicalUpdate }
+
+impl fmt::Display for LogicalUpdate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with_name(f, "LogicalUpdate")
+    }
+}
+
+impl ColPrunable for LogicalUpdate {
+    fn prune_col(&self, _required_cols: &[usize]) -> PlanRef {
+        let required_cols: Vec<_> = (0..self.input.schema().len()).collect();
+        self.clone_with_input(self.input.prune_col(&required_cols))
+            .into()
+    }
+}
+
+impl ToBatch for LogicalUpdate {
+    fn to_batch(&self) -> Result<PlanRef> {
+        let new_input = self.input().to_batch()?;
+        let new_logical = self.clone_with_input(new_input);
+        Ok(BatchUpdate::new(new_logical).into())
+    }
+}
+
+impl ToStream for LogicalUpdate {
+    fn to_stream(&self) -> Result<PlanRef> {
+        unreachable!("update should always be converted to batch plan");
+    }
+
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, crate::utils::ColIndexMapping)> {
+        unreachable!("update should always be converted to batch plan");
+    }
+}
@@ -33,6 +33,7 @@ use std::rc::Rc;
 
 use downcast_rs::{impl_downcast, Downcast};
 use dyn_clone::{self, DynClone};
+use itertools::Itertools;
 use paste::paste;
 use risingwave_common::catalog::Schema;
 use risingwave_common::error::{ErrorCode, Result};
@@ -77,19 +78,29 @@ pub enum Convention {
 }
 
 impl dyn PlanNode {
-    /// Write explain the whole plan tree.
-    pub fn explain(&self, level: usize, f: &mut impl std::fmt::Write) -> std::fmt::Result {
-        writeln!(f, "{}{}", " ".repeat(level * 2), self)?;
+    /// Write explain the whole plan tree, with the output columns of each node if `verbose`.
+    pub fn explain(
+        &self,
+        level: usize,
+        verbose: bool,
+        f: &mut impl std::fmt::Write,
+    ) -> std::fmt::Result {
+        let indent = " ".repeat(level * 2);
+        writeln!(f, "{}{}", indent, self)?;
+        if verbose {
+            let fields = self.schema().fields().iter().map(|field| &field.name);
+            writeln!(f, "{}  Output: [{}]", indent, fields.format(", "))?;
+        }
         for input in self.inputs() {
-            input.explain(level + 1, f)?;
+            input.explain(level + 1, verbose, f)?;
         }
         Ok(())
     }
 
     /// Explain the plan node and return a string.
-    pub fn explain_to_string(&self) -> Result<String> {
+    pub fn explain_to_string(&self, verbose: bool) -> Result<String> {
         let mut output = String::new();
-        self.explain(0, &mut output)
+        self.explain(0, verbose, &mut output)
             .map_err(|e| ErrorCode::InternalError(format!("failed to explain: {}", e)))?;
         Ok(output)
     }
@@ -204,6 +215,7 @@ pub use to_prost::*;
 
 mod batch_delete;
 mod batch_exchange;
+mod batch_expand;
 mod batch_filter;
 mod batch_generate_series;
 mod batch_hash_agg;
@@ -211,30 +223,42 @@ mod batch_hash_join;
 mod batch_hop_window;
 mod batch_insert;
 mod batch_limit;
+mod batch_lookup;
 mod batch_nested_loop_join;
 mod batch_project;
+mod batch_project_set;
 mod batch_seq_scan;
 mod batch_simple_agg;
 mod batch_sort;
+mod batch_sys_seq_scan;
+mod batch_table_function;
 mod batch_topn;
+mod batch_update;
 mod batch_values;
 mod logical_agg;
 mod logical_apply;
 mod logical_delete;
+mod logical_expand;
 mod logical_filter;
 mod logical_generate_series;
 mod logical_hop_window;
 mod logical_insert;
 mod logical_join;
 mod logical_limit;
+mod logical_lookup;
 mod logical_multi_join;
 mod logical_project;
+mod logical_project_set;
 mod logical_scan;
 mod logical_source;
+mod logical_sys_scan;
+mod logical_table_function;
 mod logical_topn;
+mod logical_update;
 mod logical_values;
 mod stream_delta_join;
 mod stream_exchange;
+mod stream_expand;
 mod stream_filter;
 mod stream_hash_agg;
 mod stream_hash_join;
@@ -242,6 +266,7 @@ mod stream_hop_window;
 mod stream_index_scan;
 mod stream_materialize;
 mod stream_project;
+mod stream_project_set;
 mod stream_simple_agg;
 mod stream_source;
 mod stream_table_scan;
@@ -249,6 +274,7 @@ mod stream_topn;
 
 pub use batch_delete::BatchDelete;
 pub use batch_exchange::BatchExchange;
+pub use batch_expand::BatchExpand;
 pub use batch_filter::BatchFilter;
 pub use batch_generate_series::BatchGenerateSeries;
 pub use batch_hash_agg::BatchHashAgg;
@@ -256,30 +282,42 @@ pub use batch_hash_join::BatchHashJoin;
 pub use batch_hop_window::BatchHopWindow;
 pub use batch_insert::BatchInsert;
 pub use batch_limit::BatchLimit;
+pub use batch_lookup::BatchLookup;
 pub use batch_nested_loop_join::BatchNestedLoopJoin;
 pub use batch_project::BatchProject;
+pub use batch_project_set::BatchProjectSet;
 pub use batch_seq_scan::BatchSeqScan;
 pub use batch_simple_agg::BatchSimpleAgg;
 pub use batch_sort::BatchSort;
+pub use batch_sys_seq_scan::BatchSysSeqScan;
+pub use batch_table_function::BatchTableFunction;
 pub use batch_topn::BatchTopN;
+pub use batch_update::BatchUpdate;
 pub use batch_values::BatchValues;
 pub use logical_agg::{LogicalAgg, PlanAggCall};
 pub use logical_apply::LogicalApply;
 pub use logical_delete::LogicalDelete;
+pub use logical_expand::LogicalExpand;
 pub use logical_filter::LogicalFilter;
 pub use logical_generate_series::LogicalGenerateSeries;
 pub use logical_hop_window::LogicalHopWindow;
 pub use logical_insert::LogicalInsert;
 pub use logical_join::LogicalJoin;
 pub use logical_limit::LogicalLimit;
+pub use logical_lookup::LogicalLookup;
 pub use logical_multi_join::LogicalMultiJoin;
 pub use logical_project::LogicalProject;
+pub use logical_project_set::LogicalProjectSet;
 pub use logical_scan::LogicalScan;
 pub use logical_source::LogicalSource;
+pub use logical_sys_scan::LogicalSysScan;
+pub use logical_table_function::LogicalTableFunction;
 pub use logical_topn::LogicalTopN;
+pub use logical_update::LogicalUpdate;
 pub use logical_values::LogicalValues;
 pub use stream_delta_join::StreamDeltaJoin;
 pub use stream_exchange::StreamExchange;
+pub use stream_expand::StreamExpand;
 pub use stream_filter::StreamFilter;
 pub use stream_hash_agg::StreamHashAgg;
 pub use stream_hash_join::StreamHashJoin;
@@ -287,6 +325,7 @@ pub use stream_hop_window::StreamHopWindow;
 pub use stream_index_scan::StreamIndexScan;
 pub use stream_materialize::StreamMaterialize;
 pub use stream_project::StreamProject;
+pub use stream_project_set::StreamProjectSet;
 pub use stream_simple_agg::StreamSimpleAgg;
 pub use stream_source::StreamSource;
 pub use stream_table_scan::StreamTableScan;
@@ -316,16 +355,22 @@ macro_rules! for_all_plan_nodes {
             , { Logical, Filter }
             , { Logical, Project }
             , { Logical, Scan }
+            , { Logical, SysScan }
             , { Logical, Source }
             , { Logical, Insert }
             , { Logical, Delete }
+            , { Logical, Update }
             , { Logical, Join }
             , { Logical, Values }
             , { Logical, Limit }
+            , { Logical, Lookup }
             , { Logical, TopN }
             , { Logical, HopWindow }
             , { Logical, GenerateSeries }
+            , { Logical, TableFunction }
             , { Logical, MultiJoin }
+            , { Logical, Expand }
+            , { Logical, ProjectSet }
             // , { Logical, Sort } we don't need a LogicalSort, just require the Order
             , { Batch, SimpleAgg }
             , { Batch, HashAgg }
@@ -333,16 +378,22 @@ macro_rules! for_all_plan_nodes {
             , { Batch, Filter }
             , { Batch, Insert }
             , { Batch, Delete }
+            , { Batch, Update }
             , { Batch, SeqScan }
+            , { Batch, SysSeqScan }
             , { Batch, HashJoin }
             , { Batch, NestedLoopJoin }
             , { Batch, Values }
             , { Batch, Sort }
             , { Batch, Exchange }
             , { Batch, Limit }
+            , { Batch, Lookup }
             , { Batch, TopN }
             , { Batch, HopWindow }
             , { Batch, GenerateSeries }
+            , { Batch, TableFunction }
+            , { Batch, Expand }
+            , { Batch, ProjectSet }
             , { Stream, Project }
             , { Stream, Filter }
             , { Stream, TableScan }
@@ -356,6 +407,8 @@ macro_rules! for_all_plan_nodes {
             , { Stream, HopWindow }
             , { Stream, DeltaJoin }
             , { Stream, IndexScan }
+            , { Stream, Expand }
+            , { Stream, ProjectSet }
         }
     };
 }
@@ -371,16 +424,22 @@ macro_rules! for_logical_plan_nodes {
             , { Logical, Filter }
             , { Logical, Project }
             , { Logical, Scan }
+            , { Logical, SysScan }
             , { Logical, Source }
             , { Logical, Insert }
             , { Logical, Delete }
+            , { Logical, Update }
             , { Logical, Join }
             , { Logical, Values }
             , { Logical, Limit }
+            , { Logical, Lookup }
             , { Logical, TopN }
             , { Logical, HopWindow }
             , { Logical, GenerateSeries }
+            , { Logical, TableFunction }
             , { Logical, MultiJoin }
+            , { Logical, Expand }
+            , { Logical, ProjectSet }
             // , { Logical, Sort} not sure if we will support Order by clause in subquery/view/MV
             // if we dont support thatk, we don't need LogicalSort, just require the Order at the top of query
         }
@@ -398,17 +457,23 @@ macro_rules! for_batch_plan_nodes {
             , { Batch, Project }
             , { Batch, Filter }
             , { Batch, SeqScan }
+            , { Batch, SysSeqScan }
             , { Batch, HashJoin }
             , { Batch, NestedLoopJoin }
             , { Batch, Values }
             , { Batch, Limit }
+            , { Batch, Lookup }
             , { Batch, Sort }
             , { Batch, TopN }
             , { Batch, Exchange }
             , { Batch, Insert }
             , { Batch, Delete }
+            , { Batch, Update }
             , { Batch, HopWindow }
             , { Batch, GenerateSeries }
+            , { Batch, TableFunction }
+            , { Batch, Expand }
+            , { Batch, ProjectSet }
         }
     };
 }
@@ -432,6 +497,8 @@ macro_rules! for_stream_plan_nodes {
             , { Stream, HopWindow }
             , { Stream, DeltaJoin }
             , { Stream, IndexScan }
+            , { Stream, Expand }
+            , { Stream, ProjectSet }
         }
     };
 }
@@ -73,6 +73,7 @@ impl ToStreamProst for StreamFilter {
     fn to_stream_prost_body(&self) -> ProstStreamNode {
         ProstStreamNode::Filter(FilterNode {
             search_condition: Some(ExprImpl::from(self.predicate().clone()).to_expr_proto()),
+            error_handling: None,
         })
     }
 }
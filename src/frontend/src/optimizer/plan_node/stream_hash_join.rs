@@ -43,8 +43,6 @@ pub struct StreamHashJoin {
     is_delta: bool,
 }
 
-pub static DELTA_JOIN: &str = "RW_FORCE_DELTA_JOIN";
-
 impl StreamHashJoin {
     pub fn new(logical: LogicalJoin, eq_join_predicate: EqJoinPredicate) -> Self {
         let ctx = logical.base.ctx.clone();
@@ -60,11 +58,9 @@ impl StreamHashJoin {
             &logical.l2o_col_mapping(),
         );
 
-        let force_delta = if let Some(config) = ctx.inner().session_ctx.get_config(DELTA_JOIN) {
-            config.is_set(false)
-        } else {
-            false
-        };
+        // A delta join looks up the other side by the join key, so it needs one.
+        let force_delta =
+            ctx.inner().session_ctx.config().get_force_delta_join() && eq_join_predicate.has_eq();
 
         // TODO: derive from input
         let base = PlanBase::new_stream(
@@ -106,6 +102,11 @@ impl StreamHashJoin {
                 assert!(right.satisfies(&Distribution::HashShard(predicate.right_eq_indexes())));
                 l2o_mapping.rewrite_provided_distribution(left)
             }
+            // A join without equal keys, see `LogicalJoin::to_stream`.
+            (Distribution::HashShard(_) | Distribution::Single, Distribution::Broadcast) => {
+                assert!(!predicate.has_eq());
+                l2o_mapping.rewrite_provided_distribution(left)
+            }
             (_, _) => panic!(),
         }
     }
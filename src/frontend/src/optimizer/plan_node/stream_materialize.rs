@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 
 use fixedbitset::FixedBitSet;
@@ -39,6 +39,9 @@ pub struct StreamMaterialize {
     /// Child of Materialize plan
     input: PlanRef,
     table: TableCatalog,
+    /// Whether to turn an insertion on an existing pk into an update. Only set for tables with
+    /// user-defined primary key.
+    handle_pk_conflict: bool,
 }
 
 impl StreamMaterialize {
@@ -82,9 +85,14 @@ impl StreamMaterialize {
     }
 
     #[must_use]
-    pub fn new(input: PlanRef, table: TableCatalog) -> Self {
+    pub fn new(input: PlanRef, table: TableCatalog, handle_pk_conflict: bool) -> Self {
         let base = Self::derive_plan_base(&input).unwrap();
-        Self { base, input, table }
+        Self {
+            base,
+            input,
+            table,
+            handle_pk_conflict,
+        }
     }
 
     /// Create a materialize node.
@@ -175,10 +183,22 @@ impl StreamMaterialize {
             order_desc,
             pks: pk_indices.clone(),
             is_index_on,
+            is_unique_index: false,
+            index_key_count: if is_index_on.is_some() {
+                user_order_by.field_order.len()
+            } else {
+                0
+            },
             distribution_keys: base.dist.dist_column_indices().to_vec(),
+            error_table_id: None,
         };
 
-        Ok(Self { base, input, table })
+        Ok(Self {
+            base,
+            input,
+            table,
+            handle_pk_conflict: false,
+        })
     }
 
     /// Get a reference to the stream materialize's table.
@@ -191,10 +211,55 @@ impl StreamMaterialize {
         self.table.name()
     }
 
+    /// Let the materialize executor turn an insertion on an existing pk into an update.
+    #[must_use]
+    pub fn with_pk_conflict_handled(self) -> Self {
+        Self {
+            handle_pk_conflict: true,
+            ..self
+        }
+    }
+
+    /// Use the column descs of the indexed table for the columns of an index, so that the columns
+    /// of the index share the column ids with the table. `column_descs` are in the order of the
+    /// index columns.
+    #[must_use]
+    pub fn with_index_column_descs(mut self, column_descs: Vec<ColumnDesc>) -> Self {
+        assert!(self.table.is_index_on.is_some());
+        let id_to_desc = self
+            .table
+            .columns
+            .iter_mut()
+            .zip_eq(column_descs)
+            .map(|(column, mut desc)| {
+                desc.name = column.column_desc.name.clone();
+                let old_id = column.column_desc.column_id;
+                column.column_desc = desc.clone();
+                (old_id, desc)
+            })
+            .collect::<HashMap<_, _>>();
+        for order in &mut self.table.order_desc {
+            order.column_desc = id_to_desc[&order.column_desc.column_id].clone();
+        }
+        self
+    }
+
+    /// Let the index reject duplicated values on its key columns.
+    #[must_use]
+    pub fn with_unique_index(mut self) -> Self {
+        assert!(self.table.is_index_on.is_some());
+        self.table.is_unique_index = true;
+        self
+    }
+
     /// XXX(st1page): this function is used for potential DDL demand in future, and please try your
     /// best not convert `ColumnId` to `usize(col_index`)
     fn col_id_to_idx(&self, id: ColumnId) -> usize {
-        id.get_id() as usize
+        self.table
+            .columns()
+            .iter()
+            .position(|col| col.column_desc.column_id == id)
+            .unwrap()
     }
 }
 
@@ -242,7 +307,7 @@ impl PlanTreeNodeUnary for StreamMaterialize {
     }
 
     fn clone_with_input(&self, input: PlanRef) -> Self {
-        let new = Self::new(input, self.table().clone());
+        let new = Self::new(input, self.table().clone(), self.handle_pk_conflict);
         assert_eq!(new.plan_base().schema, self.plan_base().schema);
         assert_eq!(new.plan_base().pk_indices, self.plan_base().pk_indices);
         new
@@ -288,6 +353,12 @@ impl ToStreamProst for StreamMaterialize {
                 .iter()
                 .map(|idx| *idx as i32)
                 .collect_vec(),
+            handle_pk_conflict: self.handle_pk_conflict,
+            unique_key_count: if self.table.is_unique_index {
+                self.table.index_key_count as u32
+            } else {
+                0
+            },
         })
     }
 }
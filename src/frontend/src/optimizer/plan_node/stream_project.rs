@@ -75,6 +75,7 @@ impl ToStreamProst for StreamProject {
                 .iter()
                 .map(Expr::to_expr_proto)
                 .collect(),
+            error_handling: None,
         })
     }
 }
@@ -0,0 +1,74 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use risingwave_pb::stream_plan::stream_node::NodeBody as ProstStreamNode;
+use risingwave_pb::stream_plan::ProjectSetNode;
+
+use super::{LogicalProjectSet, PlanBase, PlanRef, PlanTreeNodeUnary, ToStreamProst};
+
+/// `StreamProjectSet` implements [`super::LogicalProjectSet`] to expand each input row by the
+/// table functions in the select list.
+#[derive(Debug, Clone)]
+pub struct StreamProjectSet {
+    pub base: PlanBase,
+    logical: LogicalProjectSet,
+}
+
+impl fmt::Display for StreamProjectSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.logical.fmt_with_name(f, "StreamProjectSet")
+    }
+}
+
+impl StreamProjectSet {
+    pub fn new(logical: LogicalProjectSet) -> Self {
+        let ctx = logical.base.ctx.clone();
+        let input = logical.input();
+        let pk_indices = logical.base.pk_indices.to_vec();
+        let distribution = logical
+            .i2o_col_mapping()
+            .rewrite_provided_distribution(input.distribution());
+        // ProjectSet executor won't change the append-only behavior of the stream, so it depends on
+        // input's `append_only`.
+        let base = PlanBase::new_stream(
+            ctx,
+            logical.schema().clone(),
+            pk_indices,
+            distribution,
+            logical.input().append_only(),
+        );
+        StreamProjectSet { base, logical }
+    }
+}
+
+impl PlanTreeNodeUnary for StreamProjectSet {
+    fn input(&self) -> PlanRef {
+        self.logical.input()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(self.logical.clone_with_input(input))
+    }
+}
+impl_plan_tree_node_for_unary! {StreamProjectSet}
+
+impl ToStreamProst for StreamProjectSet {
+    fn to_stream_prost_body(&self) -> ProstStreamNode {
+        ProstStreamNode::ProjectSet(ProjectSetNode {
+            select_list: self.logical.select_list_to_protobuf(),
+        })
+    }
+}
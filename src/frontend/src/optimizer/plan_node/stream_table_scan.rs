@@ -61,8 +61,14 @@ impl StreamTableScan {
         &self.logical
     }
 
-    pub fn to_index_scan(&self, index_name: &str, index: &Rc<TableDesc>) -> StreamIndexScan {
-        StreamIndexScan::new(self.logical.to_index_scan(index_name, index))
+    pub fn to_index_scan(
+        &self,
+        index_name: &str,
+        index: &Rc<TableDesc>,
+    ) -> Option<StreamIndexScan> {
+        self.logical
+            .to_index_scan(index_name, index)
+            .map(StreamIndexScan::new)
     }
 }
 
@@ -108,6 +114,7 @@ impl StreamTableScan {
                     name: column_name.clone(),
                     field_descs: vec![],
                     type_name: "".to_string(),
+                    default_value: col.to_protobuf().default_value,
                 })
                 .collect(),
             distribution_keys: self
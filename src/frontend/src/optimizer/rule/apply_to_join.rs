@@ -0,0 +1,81 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::super::plan_node::*;
+use super::{BoxedRule, Rule};
+use crate::optimizer::PlanRef;
+
+/// This rule converts an Apply into a Join once its right child no longer refers to the columns of
+/// its left child, e.g. after all correlated predicates have been pulled up, or if the subquery is
+/// not correlated at all.
+pub struct ApplyToJoin {}
+impl Rule for ApplyToJoin {
+    fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
+        let apply = plan.as_logical_apply()?;
+        if has_correlated_input_ref(&apply.right(), 1) {
+            return None;
+        }
+        let (left, right, on, join_type) = apply.clone().decompose();
+        Some(LogicalJoin::new(left, right, join_type, on).into())
+    }
+}
+
+/// Checks whether any expression in `plan` refers to the columns of a query at least `depth`
+/// levels above.
+fn has_correlated_input_ref(plan: &PlanRef, depth: usize) -> bool {
+    let has = if let Some(project) = plan.as_logical_project() {
+        project
+            .exprs()
+            .iter()
+            .any(|expr| expr.has_correlated_input_ref_from_depth(depth))
+    } else if let Some(filter) = plan.as_logical_filter() {
+        filter
+            .predicate()
+            .conjunctions
+            .iter()
+            .any(|expr| expr.has_correlated_input_ref_from_depth(depth))
+    } else if let Some(join) = plan.as_logical_join() {
+        join.on()
+            .conjunctions
+            .iter()
+            .any(|expr| expr.has_correlated_input_ref_from_depth(depth))
+    } else if let Some(values) = plan.as_logical_values() {
+        values
+            .rows()
+            .iter()
+            .flatten()
+            .any(|expr| expr.has_correlated_input_ref_from_depth(depth))
+    } else if let Some(apply) = plan.as_logical_apply() {
+        // The right child of a nested Apply is one level deeper.
+        return apply
+            .on()
+            .conjunctions
+            .iter()
+            .any(|expr| expr.has_correlated_input_ref_from_depth(depth))
+            || has_correlated_input_ref(&apply.left(), depth)
+            || has_correlated_input_ref(&apply.right(), depth + 1);
+    } else {
+        false
+    };
+    has || plan
+        .inputs()
+        .iter()
+        .any(|input| has_correlated_input_ref(input, depth))
+}
+
+impl ApplyToJoin {
+    pub fn create() -> BoxedRule {
+        Box::new(ApplyToJoin {})
+    }
+}
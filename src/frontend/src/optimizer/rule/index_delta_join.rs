@@ -98,7 +98,10 @@ impl Rule for IndexDeltaJoinRule {
                 }
 
                 if remaining_to_match.is_empty() {
-                    return Some(table_scan.to_index_scan(name, index).into());
+                    // 3. Check if the index covers all the columns of the scan.
+                    if let Some(index_scan) = table_scan.to_index_scan(name, index) {
+                        return Some(index_scan.into());
+                    }
                 }
             }
 
@@ -0,0 +1,164 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use fixedbitset::FixedBitSet;
+use itertools::Itertools;
+use risingwave_common::catalog::{OrderedColumnDesc, TableDesc};
+
+use super::super::plan_node::*;
+use super::{BoxedRule, Rule};
+use crate::utils::ColIndexMapping;
+
+/// Rewrites a [`LogicalFilter`] on a [`LogicalScan`] into a filter on a scan of the index whose
+/// order key has the longest prefix compared for equality with constants, so that point queries
+/// can be served by the index.
+///
+/// If the index doesn't contain all the columns required by the scan, the rows found in the index
+/// are looked up in the primary table by the primary key.
+pub struct IndexSelectionRule {}
+
+impl Rule for IndexSelectionRule {
+    fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
+        let filter = plan.as_logical_filter()?;
+        let input = filter.input();
+        let scan = input.as_logical_scan()?;
+        if scan.indexes().is_empty() {
+            return None;
+        }
+
+        let scan_column_ids = scan
+            .column_descs()
+            .iter()
+            .map(|desc| desc.column_id)
+            .collect_vec();
+        let eq_column_ids = filter
+            .predicate()
+            .conjunctions
+            .iter()
+            .filter_map(|expr| expr.as_eq_const())
+            .map(|(input_ref, _)| scan_column_ids[input_ref.index()])
+            .collect::<HashSet<_>>();
+        let matched_prefix_len = |order_desc: &[OrderedColumnDesc]| {
+            order_desc
+                .iter()
+                .take_while(|order| eq_column_ids.contains(&order.column_desc.column_id))
+                .count()
+        };
+
+        // Pick the index matching the longest prefix, preferring the ones covering the scan. An
+        // index is only useful if it matches more than the order key of the primary table.
+        let mut best = None;
+        let mut best_key = (matched_prefix_len(&scan.table_desc().order_desc), true);
+        for (name, index) in scan.indexes() {
+            let index_scan = scan.to_index_scan(name, index);
+            let key = (matched_prefix_len(&index.order_desc), index_scan.is_some());
+            if key > best_key {
+                best_key = key;
+                best = Some((name, index, index_scan));
+            }
+        }
+        let (index_name, index, index_scan) = best?;
+
+        match index_scan {
+            Some(index_scan) => Some(filter.clone_with_input(index_scan.into()).into()),
+            None => Self::to_index_lookup(filter, scan, index_name, index),
+        }
+    }
+}
+
+impl IndexSelectionRule {
+    pub fn create() -> BoxedRule {
+        Box::new(IndexSelectionRule {})
+    }
+
+    /// Scans the index with the conjunctions of the predicate only referencing columns in it, and
+    /// looks up the rows found in the primary table by its order key to get the other columns.
+    fn to_index_lookup(
+        filter: &LogicalFilter,
+        scan: &LogicalScan,
+        index_name: &str,
+        index: &Rc<TableDesc>,
+    ) -> Option<PlanRef> {
+        let index_column_idx = index
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(idx, desc)| (desc.column_id, idx))
+            .collect::<HashMap<_, _>>();
+        let table_desc = scan.table_desc();
+        let scan_column_ids = scan
+            .column_descs()
+            .iter()
+            .map(|desc| desc.column_id)
+            .collect_vec();
+        let scan_len = scan_column_ids.len();
+
+        // The conjunctions only referencing columns in the index are evaluated on the index.
+        let mut cols_not_in_index = FixedBitSet::with_capacity(scan_len);
+        cols_not_in_index.extend(
+            scan_column_ids
+                .iter()
+                .positions(|id| !index_column_idx.contains_key(id)),
+        );
+        let (table_predicate, index_predicate) = filter
+            .predicate()
+            .clone()
+            .split_disjoint(&cols_not_in_index);
+
+        // Scan the columns used by the conjunctions and the order key of the primary table from
+        // the index.
+        let mut index_predicate_cols = FixedBitSet::with_capacity(scan_len);
+        for expr in &index_predicate.conjunctions {
+            index_predicate_cols.union_with(&expr.collect_input_refs(scan_len));
+        }
+        let index_predicate_cols = index_predicate_cols.ones().collect_vec();
+        let index_required_col_idx = index_predicate_cols
+            .iter()
+            .map(|col| scan_column_ids[*col])
+            .chain(
+                table_desc
+                    .order_desc
+                    .iter()
+                    .map(|order| order.column_desc.column_id),
+            )
+            .map(|id| index_column_idx.get(&id).copied())
+            .collect::<Option<Vec<_>>>()?;
+        let index_scan = LogicalScan::new(
+            index_name.to_string(),
+            index_required_col_idx,
+            index.clone(),
+            vec![],
+            scan.ctx(),
+        );
+        let mut mapping = ColIndexMapping::with_remaining_columns(&index_predicate_cols, scan_len);
+        let index_side = LogicalFilter::create(
+            index_scan.into(),
+            index_predicate.rewrite_expr(&mut mapping),
+        );
+
+        // The lookup outputs the same columns as the original scan.
+        let lookup_key_offset = index_predicate_cols.len();
+        let lookup = LogicalLookup::new(
+            index_side,
+            scan.table_name().to_string(),
+            scan.required_col_idx().to_vec(),
+            Rc::new(table_desc.clone()),
+            (lookup_key_offset..lookup_key_offset + table_desc.order_desc.len()).collect(),
+        );
+        Some(LogicalFilter::create(lookup.into(), table_predicate))
+    }
+}
@@ -43,7 +43,13 @@ mod unnest_agg_for_loj;
 pub use unnest_agg_for_loj::*;
 mod pull_up_correlated_predicate;
 pub use pull_up_correlated_predicate::*;
+mod pull_up_correlated_project;
+pub use pull_up_correlated_project::*;
+mod apply_to_join;
+pub use apply_to_join::*;
 mod index_delta_join;
 pub use index_delta_join::*;
+mod index_selection;
+pub use index_selection::*;
 mod multijoin_join;
 pub use multijoin_join::*;
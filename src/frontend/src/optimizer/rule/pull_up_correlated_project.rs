@@ -0,0 +1,186 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use risingwave_common::types::DataType;
+use risingwave_pb::plan_common::JoinType;
+
+use super::super::plan_node::*;
+use super::{BoxedRule, Rule};
+use crate::expr::{
+    lift_correlated_input_ref, ExprImpl, ExprRewriter, ExprType, FunctionCall, InputRef,
+};
+use crate::optimizer::PlanRef;
+use crate::utils::{ColIndexMapping, Condition};
+
+/// This rule is for pattern: Apply->Project, where the Project refers to the columns of Apply's
+/// left child.
+///
+/// The expressions of the Project are substituted into the `on` condition of the Apply. For
+/// `LeftSemi` and `LeftAnti` Apply, that's all since the right columns are not in the output.
+///
+/// For `Inner` and `LeftOuter` Apply, the Project is pulled up on top of the Apply. For `LeftOuter`
+/// Apply, a constant column is added to the right child to tell the padded NULL rows apart, on
+/// which the pulled expressions must evaluate to NULL as well.
+///
+/// If the input of the Project is a single-row `LogicalValues` without any column, e.g.
+/// `(SELECT t.v1 + 1)`, the Apply is removed entirely.
+pub struct PullUpCorrelatedProject {}
+impl Rule for PullUpCorrelatedProject {
+    fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
+        let apply = plan.as_logical_apply()?;
+        let (apply_left, apply_right, apply_on, join_type) = apply.clone().decompose();
+
+        let project = apply_right.as_logical_project()?;
+        if !project
+            .exprs()
+            .iter()
+            .any(|expr| expr.has_correlated_input_ref_by_depth(1))
+        {
+            return None;
+        }
+        let (proj_exprs, input) = project.clone().decompose();
+
+        let apply_left_len = apply_left.schema().len();
+        let mut shift_input_ref =
+            ColIndexMapping::with_shift_offset(input.schema().len(), apply_left_len as isize);
+        // The expressions of the Project, in terms of `apply_left` concatenated with `input`.
+        let proj_exprs = proj_exprs
+            .into_iter()
+            .map(|expr| lift_correlated_input_ref(shift_input_ref.rewrite_expr(expr)))
+            .collect_vec();
+        let left_refs = input_refs(&apply_left).into_iter();
+
+        let mut substitute = Substitute {
+            offset: apply_left_len,
+            exprs: &proj_exprs,
+        };
+        let on = apply_on.rewrite_expr(&mut substitute);
+
+        match join_type {
+            JoinType::LeftSemi | JoinType::LeftAnti => {
+                if is_dummy_values(&input) {
+                    let predicate = match (join_type, on.as_expr_unless_true()) {
+                        (JoinType::LeftSemi, _) => on,
+                        (_, Some(expr)) => Condition::with_expr(
+                            FunctionCall::new(ExprType::IsNotTrue, vec![expr])
+                                .unwrap()
+                                .into(),
+                        ),
+                        (_, None) => Condition::with_expr(ExprImpl::literal_bool(false)),
+                    };
+                    return Some(LogicalFilter::create(apply_left, predicate));
+                }
+                let right = LogicalProject::create(input.clone(), input_refs(&input));
+                Some(LogicalApply::new(apply_left, right, join_type, on).into())
+            }
+            JoinType::Inner => {
+                let exprs = left_refs.chain(proj_exprs).collect();
+                if is_dummy_values(&input) {
+                    let filter = LogicalFilter::create(apply_left, on);
+                    return Some(LogicalProject::create(filter, exprs));
+                }
+                let right = LogicalProject::create(input.clone(), input_refs(&input));
+                let new_apply = LogicalApply::new(apply_left, right, join_type, on);
+                Some(LogicalProject::create(new_apply.into(), exprs))
+            }
+            JoinType::LeftOuter => {
+                if is_dummy_values(&input) {
+                    // The only row of the right child matches if the `on` condition is true.
+                    let exprs = match on.as_expr_unless_true() {
+                        Some(on) => left_refs
+                            .chain(proj_exprs.into_iter().map(|expr| {
+                                FunctionCall::new(ExprType::Case, vec![on.clone(), expr])
+                                    .unwrap()
+                                    .into()
+                            }))
+                            .collect(),
+                        None => left_refs.chain(proj_exprs).collect(),
+                    };
+                    return Some(LogicalProject::create(apply_left, exprs));
+                }
+
+                // Add a constant column at the end of the right child, which is NULL only on the
+                // padded rows.
+                let input_len = input.schema().len();
+                let mut input_exprs = input_refs(&input);
+                input_exprs.push(ExprImpl::literal_int(1));
+                let right = LogicalProject::create(input, input_exprs);
+                let new_apply = LogicalApply::new(apply_left, right, join_type, on);
+
+                let matched: ExprImpl = FunctionCall::new(
+                    ExprType::IsNotNull,
+                    vec![InputRef::new(apply_left_len + input_len, DataType::Int32).into()],
+                )
+                .unwrap()
+                .into();
+                let exprs = left_refs
+                    .chain(proj_exprs.into_iter().map(|expr| {
+                        // A column of the right child is already NULL on the padded rows.
+                        if matches!(expr, ExprImpl::InputRef(_)) {
+                            expr
+                        } else {
+                            FunctionCall::new(ExprType::Case, vec![matched.clone(), expr])
+                                .unwrap()
+                                .into()
+                        }
+                    }))
+                    .collect();
+                Some(LogicalProject::create(new_apply.into(), exprs))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// `InputRef`s to all columns of `plan`.
+fn input_refs(plan: &PlanRef) -> Vec<ExprImpl> {
+    plan.schema()
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(i, field)| InputRef::new(i, field.data_type()).into())
+        .collect()
+}
+
+/// Whether the plan is a single-row `LogicalValues` without any column, which is the input of a
+/// `SELECT` without `FROM`.
+fn is_dummy_values(plan: &PlanRef) -> bool {
+    plan.as_logical_values().map_or(false, |values| {
+        values.rows().len() == 1 && values.schema().fields().is_empty()
+    })
+}
+
+/// Substitutes the `input_ref`s referencing Apply's right child in the `on` condition with the
+/// expressions of the Project.
+struct Substitute<'a> {
+    offset: usize,
+    exprs: &'a [ExprImpl],
+}
+
+impl ExprRewriter for Substitute<'_> {
+    fn rewrite_input_ref(&mut self, input_ref: InputRef) -> ExprImpl {
+        if input_ref.index() < self.offset {
+            input_ref.into()
+        } else {
+            self.exprs[input_ref.index() - self.offset].clone()
+        }
+    }
+}
+
+impl PullUpCorrelatedProject {
+    pub fn create() -> BoxedRule {
+        Box::new(PullUpCorrelatedProject {})
+    }
+}
@@ -17,12 +17,12 @@ use risingwave_expr::expr::AggKind;
 use risingwave_pb::plan_common::JoinType;
 
 use super::{BoxedRule, Rule};
-use crate::expr::{ExprImpl, ExprRewriter, InputRef};
+use crate::expr::{lift_correlated_input_ref, ExprImpl, ExprRewriter, InputRef};
 use crate::optimizer::plan_node::{
-    LogicalAgg, LogicalProject, PlanTreeNodeBinary, PlanTreeNodeUnary,
+    LogicalAgg, LogicalApply, LogicalProject, PlanTreeNodeBinary, PlanTreeNodeUnary,
 };
 use crate::optimizer::PlanRef;
-use crate::utils::ColIndexMapping;
+use crate::utils::{ColIndexMapping, Condition};
 
 /// This rule is for pattern: Apply->Project(p1)->Agg->Project(p2), and it will be converted into
 /// Project(p1')->Agg->Apply->Project(p2').
@@ -41,7 +41,11 @@ pub struct UnnestAggForLOJ {}
 impl Rule for UnnestAggForLOJ {
     fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
         let apply = plan.as_logical_apply()?;
-        if apply.join_type() != JoinType::LeftOuter {
+        // A scalar agg always returns exactly one row, so an `Inner` Apply is the same as a
+        // `LeftOuter` one here.
+        if !matches!(apply.join_type(), JoinType::LeftOuter | JoinType::Inner)
+            || !apply.on().always_true()
+        {
             return None;
         }
         let apply_left_len = apply.left().schema().fields().len();
@@ -61,7 +65,12 @@ impl Rule for UnnestAggForLOJ {
         let idx_of_constant = exprs.len() - 1;
         let new_project = LogicalProject::new(input, exprs);
 
-        let new_apply = apply.clone_with_left_right(apply.left(), new_project.into());
+        let new_apply = LogicalApply::new(
+            apply.left(),
+            new_project.into(),
+            JoinType::LeftOuter,
+            Condition::true_cond(),
+        );
 
         // To pull LogicalAgg up on top of LogicalApply, we need to convert scalar agg to group agg
         // using pks of Apply.left as its group keys and convert count(*) to count(pk).
@@ -106,8 +115,9 @@ impl Rule for UnnestAggForLOJ {
             ColIndexMapping::with_shift_offset(agg.agg_calls().len(), apply_left_len as isize);
         // Extend with the project columns in the right.
         exprs.extend(project.exprs().clone().into_iter().map(|expr| {
-            // We currently assume that there is no correlated variable in LogicalProject.
-            shift_input_ref.rewrite_expr(expr)
+            let expr = shift_input_ref.rewrite_expr(expr);
+            // Correlated variables in LogicalProject now refer to the group keys of LogicalAgg.
+            lift_correlated_input_ref(expr)
         }));
 
         let project = LogicalProject::new(agg.into(), exprs);
@@ -32,6 +32,8 @@ impl Planner {
             input,
             insert.table_source.name,
             insert.table_source.source_id,
+            insert.table_source.pk_indices,
+            insert.table_source.unique_indexes,
         )?
         .into();
         let order = Order::any().clone();
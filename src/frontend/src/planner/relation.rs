@@ -20,13 +20,13 @@ use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::ScalarImpl;
 
 use crate::binder::{
-    BoundBaseTable, BoundGenerateSeriesFunction, BoundJoin, BoundSource, BoundWindowTableFunction,
-    Relation, WindowTableFunctionKind,
+    BoundBaseTable, BoundGenerateSeriesFunction, BoundJoin, BoundSource, BoundSystemTable,
+    BoundWindowTableFunction, Relation, WindowTableFunctionKind,
 };
 use crate::expr::{ExprImpl, ExprType, FunctionCall, InputRef};
 use crate::optimizer::plan_node::{
-    LogicalGenerateSeries, LogicalHopWindow, LogicalJoin, LogicalProject, LogicalScan,
-    LogicalSource, PlanRef,
+    LogicalApply, LogicalGenerateSeries, LogicalHopWindow, LogicalJoin, LogicalProject,
+    LogicalScan, LogicalSource, LogicalSysScan, LogicalTableFunction, PlanRef,
 };
 use crate::planner::Planner;
 
@@ -39,7 +39,9 @@ impl Planner {
             Relation::Join(join) => self.plan_join(*join),
             Relation::WindowTableFunction(tf) => self.plan_window_table_function(*tf),
             Relation::Source(s) => self.plan_source(*s),
+            Relation::SystemTable(t) => self.plan_sys_table(*t),
             Relation::GenerateSeriesFunction(gs) => self.plan_generate_series_function(*gs),
+            Relation::TableFunction(tf) => Ok(LogicalTableFunction::create(*tf, self.ctx())),
         }
     }
 
@@ -60,12 +62,29 @@ impl Planner {
         Ok(LogicalSource::new(Rc::new(source.catalog), self.ctx()).into())
     }
 
+    pub(super) fn plan_sys_table(&mut self, sys_table: BoundSystemTable) -> Result<PlanRef> {
+        Ok(LogicalSysScan::create(
+            sys_table.table,
+            sys_table.rows,
+            self.ctx(),
+        ))
+    }
+
     pub(super) fn plan_join(&mut self, join: BoundJoin) -> Result<PlanRef> {
+        // A correlated `LATERAL` subquery is planned as the right side of an apply.
+        let is_lateral = matches!(
+            &join.right,
+            Relation::Subquery(subquery) if subquery.lateral && subquery.query.is_correlated()
+        );
         let left = self.plan_relation(join.left)?;
         let right = self.plan_relation(join.right)?;
         let join_type = join.join_type;
         let on_clause = join.cond;
-        Ok(LogicalJoin::create(left, right, join_type, on_clause))
+        if is_lateral {
+            Ok(LogicalApply::create(left, right, join_type, on_clause))
+        } else {
+            Ok(LogicalJoin::create(left, right, join_type, on_clause))
+        }
     }
 
     pub(super) fn plan_window_table_function(
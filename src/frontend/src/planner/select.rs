@@ -15,16 +15,19 @@
 use itertools::Itertools;
 use risingwave_common::catalog::Schema;
 use risingwave_common::error::{ErrorCode, Result};
-use risingwave_common::types::DataType;
+use risingwave_common::types::{DataType, ScalarImpl};
+use risingwave_expr::expr::AggKind;
 use risingwave_pb::plan_common::JoinType;
 
 use crate::binder::BoundSelect;
 use crate::expr::{
-    Expr, ExprImpl, ExprRewriter, ExprType, FunctionCall, InputRef, Subquery, SubqueryKind,
+    Expr, ExprImpl, ExprRewriter, ExprType, FunctionCall, InputRef, Literal, Subquery,
+    SubqueryKind, TableFunction, TableFunctionType,
 };
 pub use crate::optimizer::plan_node::LogicalFilter;
 use crate::optimizer::plan_node::{
-    LogicalAgg, LogicalApply, LogicalJoin, LogicalProject, LogicalValues, PlanAggCall, PlanRef,
+    LogicalAgg, LogicalApply, LogicalJoin, LogicalProject, LogicalProjectSet, LogicalValues,
+    PlanAggCall, PlanRef,
 };
 use crate::planner::Planner;
 use crate::utils::Condition;
@@ -36,6 +39,7 @@ impl Planner {
             where_clause,
             mut select_items,
             group_by,
+            grouping_sets,
             mut having,
             distinct,
             ..
@@ -63,9 +67,18 @@ impl Planner {
         // Plan the SELECT clause.
         // TODO: select-agg, group-by, having can also contain subquery exprs.
         let has_agg_call = select_items.iter().any(|expr| expr.has_agg_call());
-        if !group_by.is_empty() || having.is_some() || has_agg_call {
+        let has_grouping_call = select_items
+            .iter()
+            .chain(having.iter())
+            .any(|expr| expr.has_grouping_call());
+        if !group_by.is_empty()
+            || !grouping_sets.is_empty()
+            || having.is_some()
+            || has_agg_call
+            || has_grouping_call
+        {
             (root, select_items, having) =
-                LogicalAgg::create(select_items, group_by, having, root)?;
+                LogicalAgg::create(select_items, group_by, grouping_sets, having, root)?;
         }
 
         if let Some(having) = having {
@@ -75,6 +88,9 @@ impl Planner {
         if select_items.iter().any(|e| e.has_subquery()) {
             (root, select_items) = self.substitute_subqueries(root, select_items)?;
         }
+        if select_items.iter().any(|e| e.has_table_function()) {
+            (root, select_items) = Self::substitute_table_functions(root, select_items);
+        }
         root = LogicalProject::create(root, select_items);
 
         if distinct {
@@ -85,6 +101,58 @@ impl Planner {
         Ok(root)
     }
 
+    /// Extracts the table functions in `exprs` into a [`LogicalProjectSet`] on top of `root`, and
+    /// rewrites `exprs` to refer to its output.
+    ///
+    /// The select list of the `ProjectSet` is all columns of `root` followed by the table
+    /// functions, so that the rest of `exprs` can be evaluated by the [`LogicalProject`] above.
+    /// For example, `select unnest(arr) + 1, v from t` is planned as
+    /// `Project([$3 + 1, $2]) -> ProjectSet([$0, $1, unnest($0)]) -> Scan(arr, v)`, where `$0` of
+    /// the `ProjectSet` is `projected_row_id`.
+    fn substitute_table_functions(root: PlanRef, exprs: Vec<ExprImpl>) -> (PlanRef, Vec<ExprImpl>) {
+        struct SubstituteTableFunctions {
+            input_col_num: usize,
+            table_functions: Vec<ExprImpl>,
+        }
+
+        impl ExprRewriter for SubstituteTableFunctions {
+            fn rewrite_input_ref(&mut self, input_ref: InputRef) -> ExprImpl {
+                // Shifted by `projected_row_id`.
+                InputRef::new(input_ref.index() + 1, input_ref.return_type()).into()
+            }
+
+            fn rewrite_table_function(&mut self, table_function: TableFunction) -> ExprImpl {
+                let input_ref = InputRef::new(
+                    1 + self.input_col_num + self.table_functions.len(),
+                    table_function.return_type.clone(),
+                )
+                .into();
+                self.table_functions.push(table_function.into());
+                input_ref
+            }
+        }
+
+        let input_col_num = root.schema().len();
+        let mut rewriter = SubstituteTableFunctions {
+            input_col_num,
+            table_functions: vec![],
+        };
+        let exprs = exprs
+            .into_iter()
+            .map(|e| rewriter.rewrite_expr(e))
+            .collect();
+
+        let select_list = root
+            .schema()
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(idx, field)| InputRef::new(idx, field.data_type()).into())
+            .chain(rewriter.table_functions)
+            .collect();
+        (LogicalProjectSet::create(root, select_list), exprs)
+    }
+
     /// Helper to create a dummy node as child of [`LogicalProject`].
     /// For example, `select 1+2, 3*4` will be `Project([1+2, 3+4]) - Values([[]])`.
     fn create_dummy_values(&self) -> PlanRef {
@@ -115,13 +183,18 @@ impl Planner {
         if !where_clause.has_subquery() {
             return Ok(LogicalFilter::create_with_expr(input, where_clause));
         }
+        // Scalar subqueries are handled with the other expressions.
+        let is_boolean_subquery = |expr: &ExprImpl| match expr {
+            ExprImpl::Subquery(subquery) => !matches!(subquery.kind, SubqueryKind::Scalar),
+            _ => false,
+        };
         let (subquery_conjunctions, not_subquery_conjunctions, others) =
             Condition::with_expr(where_clause)
                 .group_by::<_, 3>(|expr| match expr {
-                    ExprImpl::Subquery(_) => 0,
+                    expr if is_boolean_subquery(expr) => 0,
                     ExprImpl::FunctionCall(func_call)
                         if func_call.get_expr_type() == ExprType::Not
-                            && matches!(func_call.inputs()[0], ExprImpl::Subquery(_)) =>
+                            && is_boolean_subquery(&func_call.inputs()[0]) =>
                     {
                         1
                     }
@@ -131,12 +204,12 @@ impl Planner {
                 .next_tuple()
                 .unwrap();
 
-        // EXISTS and IN in WHERE.
+        // EXISTS, IN, SOME and ALL in WHERE.
         for expr in subquery_conjunctions {
             self.handle_exists_and_in(expr, false, &mut input)?;
         }
 
-        // NOT EXISTS and NOT IN in WHERE.
+        // NOT EXISTS, NOT IN, NOT SOME and NOT ALL in WHERE.
         for expr in not_subquery_conjunctions {
             let not = expr.into_function_call().unwrap();
             let (_, expr) = not.decompose_as_unary();
@@ -156,37 +229,57 @@ impl Planner {
         }
     }
 
-    /// Handle (NOT) EXISTS and (NOT) IN in WHERE clause.
+    /// Handle (NOT) EXISTS, (NOT) IN and (NOT) SOME/ALL in WHERE clause.
     ///
     /// We will use a = b to replace a in (select b from ....) for (NOT) IN thus avoiding adding a
-    /// `LogicalFilter` on `LogicalApply`.
+    /// `LogicalFilter` on `LogicalApply`. Since only rows satisfying the WHERE clause are kept,
+    /// a null comparison is treated as false for `IN`/`SOME` and as true for `NOT IN`/`NOT SOME`,
+    /// which gives the same result as the three-valued logic. `ALL` is planned as `NOT SOME` with
+    /// the negated comparison. An uncorrelated `NOT IN` is planned by [`Self::create_not_in`]
+    /// instead, so that the equality is not hidden in the null-aware join condition.
     fn handle_exists_and_in(
         &mut self,
         expr: ExprImpl,
         negated: bool,
         input: &mut PlanRef,
     ) -> Result<()> {
-        let join_type = if negated {
-            JoinType::LeftAnti
-        } else {
-            JoinType::LeftSemi
-        };
         let subquery = expr.into_subquery().unwrap();
         let is_correlated = subquery.is_correlated();
-        let output_column_type = subquery.query.data_types()[0].clone();
+        let output_column_types = subquery.query.data_types();
         let right_plan = self.plan_query(subquery.query)?.as_subplan();
-        let on = match subquery.kind {
-            SubqueryKind::Existential => ExprImpl::literal_bool(true),
-            SubqueryKind::In(left_expr) => {
-                let right_expr = InputRef::new(input.schema().fields().len(), output_column_type);
-                FunctionCall::new(ExprType::Equal, vec![left_expr, right_expr.into()])?.into()
+        let (join_type, on) = match subquery.kind {
+            SubqueryKind::Existential => {
+                let join_type = if negated {
+                    JoinType::LeftAnti
+                } else {
+                    JoinType::LeftSemi
+                };
+                (join_type, ExprImpl::literal_bool(true))
             }
             kind => {
-                return Err(ErrorCode::NotImplemented(
-                    format!("Not supported subquery kind: {:?}", kind),
-                    1343.into(),
-                )
-                .into())
+                let (left_expr, func_type, is_all) = match kind {
+                    SubqueryKind::In(left_expr) => (left_expr, ExprType::Equal, false),
+                    SubqueryKind::Some(left_expr, func_type) => (left_expr, func_type, false),
+                    SubqueryKind::All(left_expr, func_type) => (left_expr, func_type, true),
+                    _ => unreachable!(),
+                };
+                // `NOT IN`, `NOT (= SOME)` and `<> ALL`.
+                let is_not_in = matches!(
+                    (func_type, is_all, negated),
+                    (ExprType::Equal, false, true) | (ExprType::NotEqual, true, false)
+                );
+                if is_not_in && !is_correlated {
+                    *input = Self::create_not_in(
+                        input.clone(),
+                        right_plan,
+                        left_expr,
+                        output_column_types[0].clone(),
+                    )?;
+                    return Ok(());
+                }
+                let right_expr =
+                    InputRef::new(input.schema().len(), output_column_types[0].clone());
+                Self::create_quantified_on(left_expr, right_expr, func_type, is_all, negated)?
             }
         };
         *input =
@@ -194,6 +287,111 @@ impl Planner {
         Ok(())
     }
 
+    /// Helper to create the join type and the join condition of a semi/anti join for
+    /// `left_expr <func_type> SOME (subquery)`, or `ALL` when `is_all` is true. `right_expr` refers
+    /// to the output column of the subquery.
+    fn create_quantified_on(
+        left_expr: ExprImpl,
+        right_expr: InputRef,
+        func_type: ExprType,
+        is_all: bool,
+        negated: bool,
+    ) -> Result<(JoinType, ExprImpl)> {
+        let cmp: ExprImpl =
+            FunctionCall::new(func_type, vec![left_expr, right_expr.into()])?.into();
+        let (join_type, on) = match (is_all, negated) {
+            // The row is kept if any comparison is true.
+            (false, false) => (JoinType::LeftSemi, cmp),
+            // The row is removed if any comparison is true or null.
+            (false, true) => (
+                JoinType::LeftAnti,
+                FunctionCall::new(ExprType::IsNotFalse, vec![cmp])?.into(),
+            ),
+            // The row is removed if any comparison is false or null.
+            (true, false) => (
+                JoinType::LeftAnti,
+                FunctionCall::new(ExprType::IsNotTrue, vec![cmp])?.into(),
+            ),
+            // The row is kept if any comparison is false.
+            (true, true) => (
+                JoinType::LeftSemi,
+                FunctionCall::new(ExprType::IsFalse, vec![cmp])?.into(),
+            ),
+        };
+        Ok((join_type, on))
+    }
+
+    /// Creates the plan of `left_expr NOT IN (right)`, keeping the equality as the key of a hash
+    /// join.
+    ///
+    /// The rows of `left` equal to some row of `right` are removed by the first anti join. The
+    /// remaining rows are removed as well if a comparison is null, i.e. if `left_expr` is null and
+    /// `right` is not empty, or if `right` contains a null. The second anti join checks them with
+    /// the number of rows (`total`) and of non-null values (`nn`) of `right`. It has no key, so
+    /// the single row of counts is broadcast to the rows of `left` wherever they are.
+    /// ```text
+    /// Join(type: LeftAnti, on: (IsNull(left_expr) AND total > 0) OR total > nn)
+    ///   Join(type: LeftAnti, on: left_expr = right.$0)
+    ///     left
+    ///     right
+    ///   Agg(agg_calls: [count(*) as total, count(right.$0) as nn])
+    ///     right
+    /// ```
+    fn create_not_in(
+        left: PlanRef,
+        right: PlanRef,
+        left_expr: ExprImpl,
+        right_type: DataType,
+    ) -> Result<PlanRef> {
+        let left_len = left.schema().len();
+
+        let eq = FunctionCall::new(
+            ExprType::Equal,
+            vec![
+                left_expr.clone(),
+                InputRef::new(left_len, right_type.clone()).into(),
+            ],
+        )?;
+        let join = LogicalJoin::create(left, right.clone(), JoinType::LeftAnti, eq.into());
+
+        let count_non_null = PlanAggCall {
+            agg_kind: AggKind::Count,
+            return_type: DataType::Int64,
+            inputs: vec![InputRef::new(0, right_type)],
+            distinct: false,
+        };
+        let counts = LogicalAgg::new(
+            vec![PlanAggCall::count_star(), count_non_null],
+            vec![],
+            right,
+        );
+
+        let total: ExprImpl = InputRef::new(left_len, DataType::Int64).into();
+        let non_null: ExprImpl = InputRef::new(left_len + 1, DataType::Int64).into();
+        let null_left = FunctionCall::new(
+            ExprType::And,
+            vec![
+                FunctionCall::new(ExprType::IsNull, vec![left_expr])?.into(),
+                FunctionCall::new(
+                    ExprType::GreaterThan,
+                    vec![
+                        total.clone(),
+                        Literal::new(Some(ScalarImpl::Int64(0)), DataType::Int64).into(),
+                    ],
+                )?
+                .into(),
+            ],
+        )?;
+        let null_right = FunctionCall::new(ExprType::GreaterThan, vec![total, non_null])?;
+        let on = FunctionCall::new(ExprType::Or, vec![null_left.into(), null_right.into()])?;
+        Ok(LogicalJoin::create(
+            join,
+            counts.into(),
+            JoinType::LeftAnti,
+            on.into(),
+        ))
+    }
+
     /// Substitutes all [`Subquery`] in `exprs`.
     ///
     /// Each time a [`Subquery`] is found, it is replaced by a new [`InputRef`]. And `root` is
@@ -232,31 +430,241 @@ impl Planner {
 
         for subquery in rewriter.subqueries {
             let is_correlated = subquery.is_correlated();
-            let mut right = self.plan_query(subquery.query)?.as_subplan();
+            let right = self.plan_query(subquery.query)?.as_subplan();
 
-            match subquery.kind {
-                SubqueryKind::Scalar => {}
-                SubqueryKind::Existential => {
-                    right = self.create_exists(right)?;
-                }
-                _ => {
-                    return Err(ErrorCode::NotImplemented(
-                        format!("{:?}", subquery.kind),
-                        1343.into(),
-                    )
-                    .into())
-                }
-            }
+            root = match subquery.kind {
+                SubqueryKind::Scalar => Self::create_apply_or_join(
+                    is_correlated,
+                    root,
+                    right,
+                    ExprImpl::literal_bool(true),
+                    JoinType::LeftOuter,
+                ),
+                SubqueryKind::Existential => Self::create_apply_or_join(
+                    is_correlated,
+                    root,
+                    self.create_exists(right)?,
+                    ExprImpl::literal_bool(true),
+                    JoinType::LeftOuter,
+                ),
+                SubqueryKind::In(left_expr) => Self::create_quantified_comparison(
+                    is_correlated,
+                    root,
+                    right,
+                    left_expr,
+                    ExprType::Equal,
+                    false,
+                )?,
+                SubqueryKind::Some(left_expr, func_type) => Self::create_quantified_comparison(
+                    is_correlated,
+                    root,
+                    right,
+                    left_expr,
+                    func_type,
+                    false,
+                )?,
+                SubqueryKind::All(left_expr, func_type) => Self::create_quantified_comparison(
+                    is_correlated,
+                    root,
+                    right,
+                    left_expr,
+                    func_type,
+                    true,
+                )?,
+            };
+        }
+        Ok((root, exprs))
+    }
 
-            root = Self::create_apply_or_join(
+    /// Appends a column with the three-valued result of `left_expr <func_type> SOME (right)`, or
+    /// `ALL` when `is_all` is true, to `left`.
+    ///
+    /// The rows of `left` are told apart by grouping on all of its columns, which would merge the
+    /// duplicate rows of a `left` without pk. They are merged and counted beforehand instead, and
+    /// expanded back afterwards:
+    /// ```text
+    /// Project([left.*, result])
+    ///   ProjectSet([left.*, result, generate_series(1, cnt)])
+    ///     QuantifiedComparison
+    ///       Agg(group_keys: [left.*], agg_calls: [count(*) as cnt])
+    ///         left
+    /// ```
+    fn create_quantified_comparison(
+        is_correlated: bool,
+        left: PlanRef,
+        right: PlanRef,
+        left_expr: ExprImpl,
+        func_type: ExprType,
+        is_all: bool,
+    ) -> Result<PlanRef> {
+        if !left.pk_indices().is_empty() {
+            return Self::create_quantified_comparison_on_unique(
                 is_correlated,
-                root,
+                left,
                 right,
-                ExprImpl::literal_bool(true),
-                JoinType::LeftOuter,
+                left_expr,
+                func_type,
+                is_all,
             );
         }
-        Ok((root, exprs))
+
+        let left_len = left.schema().len();
+        let left = LogicalAgg::new(
+            vec![PlanAggCall::count_star()],
+            (0..left_len).collect(),
+            left,
+        );
+        // The count is appended after the columns of `left`, so `left_expr` and the correlated
+        // columns in `right` still refer to the same columns.
+        let plan = Self::create_quantified_comparison_on_unique(
+            is_correlated,
+            left.into(),
+            right,
+            left_expr,
+            func_type,
+            is_all,
+        )?;
+
+        let input_ref = |index: usize| -> ExprImpl {
+            InputRef::new(index, plan.schema().fields()[index].data_type()).into()
+        };
+        let mut select_list = (0..left_len).map(input_ref).collect_vec();
+        select_list.push(input_ref(left_len + 1));
+        select_list.push(
+            TableFunction::new(
+                TableFunctionType::GenerateSeries,
+                vec![ExprImpl::literal_int(1), input_ref(left_len)],
+            )?
+            .into(),
+        );
+        let project_set = LogicalProjectSet::create(plan, select_list);
+        let exprs = (1..=left_len + 1)
+            .map(|index| {
+                InputRef::new(index, project_set.schema().fields()[index].data_type()).into()
+            })
+            .collect();
+        Ok(LogicalProject::create(project_set, exprs))
+    }
+
+    /// Same as [`Self::create_quantified_comparison`], where the rows of `left` are unique.
+    ///
+    /// The rows of `right` are joined to `left` and counted per row of `left`: `decisive` counts
+    /// the comparisons that decide the result on their own (true ones for `SOME`, false ones for
+    /// `ALL`), and `unknown` counts the null ones. A constant column is appended to `right` to
+    /// tell the matched rows from the null-padded ones.
+    /// ```text
+    /// Project([left.*, CASE WHEN decisive > 0 THEN .. WHEN unknown > 0 THEN NULL ELSE .. END])
+    ///   Agg(group_keys: [left.*], agg_calls: [count(decisive), count(unknown)])
+    ///     Project([left.*, decisive, unknown])
+    ///       Apply/Join(type: LeftOuter, on: true)
+    ///         left
+    ///         Project([right.*, 1])
+    /// ```
+    fn create_quantified_comparison_on_unique(
+        is_correlated: bool,
+        left: PlanRef,
+        right: PlanRef,
+        left_expr: ExprImpl,
+        func_type: ExprType,
+        is_all: bool,
+    ) -> Result<PlanRef> {
+        let left_len = left.schema().len();
+        let left_refs: Vec<ExprImpl> = left
+            .schema()
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(i, field)| InputRef::new(i, field.data_type()).into())
+            .collect();
+        let right_type = right.schema().fields()[0].data_type();
+
+        let right = match right.as_logical_project() {
+            Some(project) => {
+                let (mut exprs, input) = project.clone().decompose();
+                exprs.push(ExprImpl::literal_int(1));
+                LogicalProject::create(input, exprs)
+            }
+            None => LogicalProject::create(
+                right,
+                vec![
+                    InputRef::new(0, right_type.clone()).into(),
+                    ExprImpl::literal_int(1),
+                ],
+            ),
+        };
+        let join = Self::create_apply_or_join(
+            is_correlated,
+            left,
+            right,
+            ExprImpl::literal_bool(true),
+            JoinType::LeftOuter,
+        );
+
+        let cmp: ExprImpl = FunctionCall::new(
+            func_type,
+            vec![left_expr, InputRef::new(left_len, right_type).into()],
+        )?
+        .into();
+        let decisive = if is_all {
+            FunctionCall::new(ExprType::Not, vec![cmp.clone()])?.into()
+        } else {
+            cmp.clone()
+        };
+        let unknown = FunctionCall::new(
+            ExprType::And,
+            vec![
+                FunctionCall::new(
+                    ExprType::IsNotNull,
+                    vec![InputRef::new(left_len + 1, DataType::Int32).into()],
+                )?
+                .into(),
+                FunctionCall::new(ExprType::IsNull, vec![cmp])?.into(),
+            ],
+        )?
+        .into();
+        let count_if = |cond: ExprImpl| -> Result<ExprImpl> {
+            Ok(FunctionCall::new(ExprType::Case, vec![cond, ExprImpl::literal_int(1)])?.into())
+        };
+        let mut exprs = left_refs.clone();
+        exprs.push(count_if(decisive)?);
+        exprs.push(count_if(unknown)?);
+        let project = LogicalProject::create(join, exprs);
+
+        let count = |index: usize| PlanAggCall {
+            agg_kind: AggKind::Count,
+            return_type: DataType::Int64,
+            inputs: vec![InputRef::new(index, DataType::Int32)],
+            distinct: false,
+        };
+        let agg = LogicalAgg::new(
+            vec![count(left_len), count(left_len + 1)],
+            (0..left_len).collect(),
+            project,
+        );
+
+        let positive = |index: usize| -> Result<ExprImpl> {
+            Ok(FunctionCall::new(
+                ExprType::GreaterThan,
+                vec![
+                    InputRef::new(index, DataType::Int64).into(),
+                    Literal::new(Some(ScalarImpl::Int64(0)), DataType::Int64).into(),
+                ],
+            )?
+            .into())
+        };
+        let result = FunctionCall::new(
+            ExprType::Case,
+            vec![
+                positive(left_len)?,
+                ExprImpl::literal_bool(!is_all),
+                positive(left_len + 1)?,
+                Literal::new(None, DataType::Boolean).into(),
+                ExprImpl::literal_bool(is_all),
+            ],
+        )?;
+        let mut exprs = left_refs;
+        exprs.push(result.into());
+        Ok(LogicalProject::create(agg.into(), exprs))
     }
 
     fn create_apply_or_join(
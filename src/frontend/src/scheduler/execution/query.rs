@@ -19,6 +19,7 @@ use std::sync::Arc;
 use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_pb::batch_plan::{TaskId as TaskIdProst, TaskOutputId as TaskOutputIdProst};
+use risingwave_pb::common::HostAddress;
 use tokio::sync::mpsc::{channel, Receiver, Sender};
 use tokio::sync::{oneshot, RwLock};
 use tokio::task::JoinHandle;
@@ -73,7 +74,7 @@ enum QueryState {
 pub struct QueryExecution {
     query: Arc<Query>,
     state: Arc<RwLock<QueryState>>,
-    _stage_executions: Arc<HashMap<StageId, Arc<StageExecution>>>,
+    stage_executions: Arc<HashMap<StageId, Arc<StageExecution>>>,
 }
 
 struct QueryRunner {
@@ -149,7 +150,7 @@ impl QueryExecution {
         Self {
             query,
             state: Arc::new(RwLock::new(state)),
-            _stage_executions: stage_executions,
+            stage_executions,
         }
     }
 
@@ -198,6 +199,14 @@ impl QueryExecution {
     }
 
     /// Cancel execution of this query.
+    /// Returns the ids and locations of the scheduled tasks of all stages.
+    pub fn all_task_locations(&self) -> Vec<(TaskIdProst, HostAddress)> {
+        self.stage_executions
+            .values()
+            .flat_map(|stage_execution| stage_execution.all_task_locations())
+            .collect()
+    }
+
     #[allow(unused)]
     pub async fn abort(&mut self) -> Result<()> {
         todo!()
@@ -414,6 +423,7 @@ mod tests {
                         name: "a".to_string(),
                         type_name: String::new(),
                         field_descs: vec![],
+                        default_value: None,
                     },
                     ColumnDesc {
                         data_type: DataType::Float64,
@@ -421,6 +431,7 @@ mod tests {
                         name: "b".to_string(),
                         type_name: String::new(),
                         field_descs: vec![],
+                        default_value: None,
                     },
                 ],
                 distribution_keys: vec![],
@@ -516,7 +527,7 @@ mod tests {
         let workers = vec![worker1, worker2, worker3];
         let worker_node_manager = Arc::new(WorkerNodeManager::mock(workers));
         // Break the plan node into fragments.
-        let fragmenter = BatchPlanFragmenter::new(worker_node_manager);
+        let fragmenter = BatchPlanFragmenter::new(worker_node_manager, None);
         fragmenter.split(batch_exchange_node3.clone()).unwrap()
     }
 
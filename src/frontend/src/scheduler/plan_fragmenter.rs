@@ -14,6 +14,7 @@
 
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Debug, Formatter};
+use std::num::NonZeroU64;
 use std::sync::Arc;
 
 use risingwave_common::error::Result;
@@ -68,6 +69,12 @@ impl ExecutionPlanNode {
     pub fn node_type(&self) -> PlanNodeType {
         self.plan_node_type
     }
+
+    /// The identity of the plan node sent to compute nodes. Executors report their runtime
+    /// statistics with it, so it must be unique within a query.
+    pub fn identity(&self) -> String {
+        format!("{:?}-{}", self.plan_node_type, self.plan_node_id.0)
+    }
 }
 
 /// `BatchPlanFragmenter` splits a query plan into fragments.
@@ -76,6 +83,9 @@ pub struct BatchPlanFragmenter {
     stage_graph_builder: StageGraphBuilder,
     next_stage_id: u32,
     worker_node_manager: WorkerNodeManagerRef,
+    /// The parallelism of non-root stages, set by the session variable `batch_parallelism`. If
+    /// not set, all worker nodes are used.
+    batch_parallelism: Option<NonZeroU64>,
 }
 
 impl Default for QueryId {
@@ -87,18 +97,22 @@ impl Default for QueryId {
 }
 
 impl BatchPlanFragmenter {
-    pub fn new(worker_node_manager: WorkerNodeManagerRef) -> Self {
+    pub fn new(
+        worker_node_manager: WorkerNodeManagerRef,
+        batch_parallelism: Option<NonZeroU64>,
+    ) -> Self {
         Self {
             query_id: Default::default(),
             stage_graph_builder: StageGraphBuilder::new(),
             next_stage_id: 0,
             worker_node_manager,
+            batch_parallelism,
         }
     }
 }
 
 /// Contains the connection info of each stage.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Query {
     /// Query id should always be unique.
     pub(crate) query_id: QueryId,
@@ -190,7 +204,7 @@ impl QueryStageBuilder {
 }
 
 /// Maintains how each stage are connected.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct StageGraph {
     pub(crate) root_stage_id: StageId,
     pub stages: HashMap<StageId, QueryStageRef>,
@@ -289,8 +303,16 @@ impl BatchPlanFragmenter {
         let next_stage_id = self.next_stage_id;
         self.next_stage_id += 1;
         let parallelism = match parent_parallelism {
+            // Non-root node which only looks up a few rows by keys.
+            Some(_) if Self::is_point_get_stage(&root) => 1,
+            // Non-root node which generates its rows without reading tables or other stages, e.g.
+            // values or system tables. Every task would produce all the rows.
+            Some(_) if Self::is_source_free_stage(&root) => 1,
             // Non-root node
-            Some(_) => self.worker_node_manager.worker_node_count(),
+            Some(_) => match self.batch_parallelism {
+                Some(parallelism) => parallelism.get() as usize,
+                None => self.worker_node_manager.worker_node_count(),
+            },
             // Root node.
             None => 1,
         };
@@ -313,6 +335,35 @@ impl BatchPlanFragmenter {
         builder.finish(&mut self.stage_graph_builder)
     }
 
+    /// Whether the stage rooted at `root` only gets rows from tables by the full order keys. Such a
+    /// stage is run by a single parallel unit, rather than contacting all of them for a few rows.
+    fn is_point_get_stage(root: &PlanRef) -> bool {
+        fn visit(node: &PlanRef, has_point_get: &mut bool) -> bool {
+            match node.node_type() {
+                PlanNodeType::BatchExchange => false,
+                PlanNodeType::BatchSeqScan => {
+                    let is_point_get = node.as_batch_seq_scan().unwrap().is_point_get();
+                    *has_point_get |= is_point_get;
+                    is_point_get
+                }
+                _ => node
+                    .inputs()
+                    .iter()
+                    .all(|input| visit(input, has_point_get)),
+            }
+        }
+        let mut has_point_get = false;
+        visit(root, &mut has_point_get) && has_point_get
+    }
+
+    /// Whether the stage rooted at `root` neither scans tables nor receives rows from other stages.
+    fn is_source_free_stage(root: &PlanRef) -> bool {
+        match root.node_type() {
+            PlanNodeType::BatchExchange | PlanNodeType::BatchSeqScan => false,
+            _ => root.inputs().iter().all(Self::is_source_free_stage),
+        }
+    }
+
     fn visit_node(
         &mut self,
         node: PlanRef,
@@ -367,11 +418,14 @@ impl BatchPlanFragmenter {
 #[cfg(test)]
 mod tests {
     use std::collections::{HashMap, HashSet};
+    use std::ops::Bound::*;
     use std::rc::Rc;
     use std::sync::Arc;
 
-    use risingwave_common::catalog::{ColumnDesc, TableDesc};
-    use risingwave_common::types::DataType;
+    use risingwave_common::catalog::{ColumnDesc, OrderedColumnDesc, TableDesc};
+    use risingwave_common::types::{DataType, ScalarImpl};
+    use risingwave_common::util::scan_range::ScanRange;
+    use risingwave_common::util::sort_util::OrderType;
     use risingwave_pb::batch_plan::plan_node::NodeBody;
     use risingwave_pb::common::{
         HostAddress, ParallelUnit, ParallelUnitType, WorkerNode, WorkerType,
@@ -415,6 +469,7 @@ mod tests {
                         name: "a".to_string(),
                         type_name: String::new(),
                         field_descs: vec![],
+                        default_value: None,
                     },
                     ColumnDesc {
                         data_type: DataType::Float64,
@@ -422,6 +477,7 @@ mod tests {
                         name: "b".to_string(),
                         type_name: String::new(),
                         field_descs: vec![],
+                        default_value: None,
                     },
                 ],
                 distribution_keys: vec![],
@@ -517,7 +573,7 @@ mod tests {
         let workers = vec![worker1, worker2, worker3];
         let worker_node_manager = Arc::new(WorkerNodeManager::mock(workers));
         // Break the plan node into fragments.
-        let fragmenter = BatchPlanFragmenter::new(worker_node_manager);
+        let fragmenter = BatchPlanFragmenter::new(worker_node_manager, None);
         let query = fragmenter.split(batch_exchange_node3.clone()).unwrap();
 
         assert_eq!(query.stage_graph.root_stage_id, 0);
@@ -592,6 +648,79 @@ mod tests {
         assert_eq!(0, scan_node2.root.children.len());
     }
 
+    #[tokio::test]
+    async fn test_fragmenter_point_get() {
+        let ctx = OptimizerContext::mock().await;
+        let column_desc = ColumnDesc {
+            data_type: DataType::Int32,
+            column_id: 0.into(),
+            name: "a".to_string(),
+            type_name: String::new(),
+            field_descs: vec![],
+            default_value: None,
+        };
+        let logical_scan = LogicalScan::new(
+            "".to_string(),
+            vec![0],
+            Rc::new(TableDesc {
+                table_id: 0.into(),
+                pks: vec![0],
+                order_desc: vec![OrderedColumnDesc {
+                    column_desc: column_desc.clone(),
+                    order: OrderType::Ascending,
+                }],
+                columns: vec![column_desc],
+                distribution_keys: vec![0],
+            }),
+            vec![],
+            ctx,
+        );
+        let point_get = |value: i32| ScanRange {
+            eq_conds: vec![Some(ScalarImpl::Int32(value))],
+            range: (Unbounded, Unbounded),
+        };
+        let range_scan = ScanRange {
+            eq_conds: vec![],
+            range: (Included(ScalarImpl::Int32(1)), Unbounded),
+        };
+
+        let worker_node_manager = Arc::new(WorkerNodeManager::mock(vec![
+            WorkerNode {
+                id: 0,
+                r#type: WorkerType::ComputeNode as i32,
+                host: Some(HostAddress {
+                    host: "127.0.0.1".to_string(),
+                    port: 5687,
+                }),
+                state: risingwave_pb::common::worker_node::State::Running as i32,
+                parallel_units: generate_parallel_units(0, 0),
+            },
+            WorkerNode {
+                id: 1,
+                r#type: WorkerType::ComputeNode as i32,
+                host: Some(HostAddress {
+                    host: "127.0.0.1".to_string(),
+                    port: 5688,
+                }),
+                state: risingwave_pb::common::worker_node::State::Running as i32,
+                parallel_units: generate_parallel_units(8, 1),
+            },
+        ]));
+        let scan_parallelism = |scan_ranges: Vec<ScanRange>| {
+            let scan: PlanRef =
+                BatchSeqScan::new_with_scan_ranges(logical_scan.clone(), scan_ranges).into();
+            let exchange: PlanRef =
+                BatchExchange::new(scan, Order::default(), Distribution::Single).into();
+            let fragmenter = BatchPlanFragmenter::new(worker_node_manager.clone(), None);
+            let query = fragmenter.split(exchange).unwrap();
+            query.stage_graph.stages[&1].parallelism
+        };
+
+        assert_eq!(scan_parallelism(vec![point_get(1), point_get(2)]), 1);
+        assert_eq!(scan_parallelism(vec![point_get(1), range_scan]), 2);
+        assert_eq!(scan_parallelism(vec![]), 2);
+    }
+
     fn generate_parallel_units(start_id: u32, node_id: u32) -> Vec<ParallelUnit> {
         let parallel_degree = 8;
         let mut parallel_units = vec![ParallelUnit {
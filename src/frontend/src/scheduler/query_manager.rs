@@ -13,14 +13,16 @@
 // limitations under the License.
 
 use std::fmt::{Debug, Formatter};
+use std::time::{Duration, Instant};
 
 use futures::Stream;
-use futures_async_stream::try_stream;
+use futures_async_stream::{for_await, try_stream};
 use log::debug;
 use risingwave_common::array::DataChunk;
 use risingwave_common::error::{Result, RwError};
 use risingwave_pb::batch_plan::{PlanNode as BatchPlanProst, TaskId, TaskOutputId};
 use risingwave_pb::common::HostAddress;
+use risingwave_pb::task_service::ExecutorProfile;
 use risingwave_rpc_client::{ComputeClient, ExchangeSource};
 use uuid::Uuid;
 
@@ -32,6 +34,15 @@ use crate::scheduler::ExecutionContextRef;
 
 pub trait DataChunkStream = Stream<Item = Result<DataChunk>>;
 
+/// Runtime statistics of a query, collected by [`QueryManager::profile`].
+pub struct QueryProfile {
+    /// Time spent in executing the query and fetching all its results.
+    pub elapsed: Duration,
+    pub output_rows: usize,
+    /// Statistics of the executors of all tasks.
+    pub executor_profiles: Vec<ExecutorProfile>,
+}
+
 pub struct QueryResultFetcher {
     // TODO: Remove these after implemented worker node level snapshot pinnning
     epoch: u64,
@@ -116,6 +127,45 @@ impl QueryManager {
 
         Ok(query_result_fetcher.run())
     }
+
+    /// Executes the query to completion, discarding its results, and collects the runtime
+    /// statistics of its executors from all tasks. Used by `EXPLAIN ANALYZE`.
+    pub async fn profile(
+        &self,
+        _context: ExecutionContextRef,
+        query: Query,
+    ) -> Result<QueryProfile> {
+        let epoch = self.hummock_snapshot_manager.get_epoch().await?;
+
+        let query_execution = QueryExecution::new(
+            query,
+            epoch,
+            self.worker_node_manager.clone(),
+            self.hummock_snapshot_manager.clone(),
+        );
+
+        let start = Instant::now();
+        let query_result_fetcher = query_execution.start().await?;
+        let mut output_rows = 0;
+        #[for_await]
+        for chunk in query_result_fetcher.run() {
+            output_rows += chunk?.cardinality();
+        }
+        let elapsed = start.elapsed();
+
+        let mut executor_profiles = vec![];
+        for (task_id, task_host) in query_execution.all_task_locations() {
+            let compute_client = ComputeClient::new((&task_host).into()).await?;
+            let task_info = compute_client.get_task_info(task_id).await?;
+            executor_profiles.extend(task_info.executor_profiles);
+        }
+
+        Ok(QueryProfile {
+            elapsed,
+            output_rows,
+            executor_profiles,
+        })
+    }
 }
 
 impl QueryResultFetcher {
@@ -12,7 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
 use std::error::Error;
 use std::fmt::Formatter;
 use std::marker::Sync;
@@ -21,22 +20,28 @@ use std::sync::atomic::{AtomicI32, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
-use parking_lot::RwLock;
+use parking_lot::{RwLock, RwLockReadGuard};
 use pgwire::pg_response::PgResponse;
 use pgwire::pg_server::{Session, SessionManager};
+use risingwave_common::catalog::DEFAULT_SUPER_USER;
 use risingwave_common::config::FrontendConfig;
-use risingwave_common::error::Result;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::session_config::ConfigMap;
 use risingwave_common::util::addr::HostAddr;
 use risingwave_pb::common::WorkerType;
+use risingwave_pb::user::grant_privilege::{Action, Target};
 use risingwave_rpc_client::MetaClient;
+use risingwave_sqlparser::ast::ObjectName;
 use risingwave_sqlparser::parser::Parser;
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::sync::watch;
 use tokio::task::JoinHandle;
 
+use crate::binder::Binder;
 use crate::catalog::catalog_service::{CatalogReader, CatalogWriter, CatalogWriterImpl};
 use crate::catalog::root_catalog::Catalog;
-use crate::handler::dml::IMPLICIT_FLUSH;
+use crate::catalog::search_path::SearchPath;
+use crate::expr::SessionTimezone;
 use crate::handler::handle;
 use crate::meta_client::{FrontendMetaClient, FrontendMetaClientImpl};
 use crate::observer::observer_manager::ObserverManager;
@@ -259,32 +264,18 @@ impl FrontendEnv {
 pub struct SessionImpl {
     env: FrontendEnv,
     database: String,
-    /// Stores the value of configurations.
-    config_map: RwLock<HashMap<String, ConfigEntry>>,
-}
-
-#[derive(Clone)]
-pub struct ConfigEntry {
-    str_val: String,
-}
-
-impl ConfigEntry {
-    pub fn new(str_val: String) -> Self {
-        ConfigEntry { str_val }
-    }
-
-    /// Only used for boolean configurations.
-    pub fn is_set(&self, default: bool) -> bool {
-        self.str_val.parse().unwrap_or(default)
-    }
+    user_name: String,
+    /// Stores the values of the session variables.
+    config_map: RwLock<ConfigMap>,
 }
 
 impl SessionImpl {
-    pub fn new(env: FrontendEnv, database: String) -> Self {
+    pub fn new(env: FrontendEnv, database: String, user_name: String) -> Self {
         Self {
             env,
             database,
-            config_map: Self::init_config_map(),
+            user_name,
+            config_map: Default::default(),
         }
     }
 
@@ -293,7 +284,8 @@ impl SessionImpl {
         Self {
             env: FrontendEnv::mock(),
             database: "dev".to_string(),
-            config_map: Self::init_config_map(),
+            user_name: DEFAULT_SUPER_USER.to_string(),
+            config_map: Default::default(),
         }
     }
 
@@ -305,28 +297,92 @@ impl SessionImpl {
         &self.database
     }
 
-    /// Set configuration values in this session.
-    /// For example, `set_config("RW_IMPLICIT_FLUSH", true)` will implicit flush for every inserts.
-    pub fn set_config(&self, key: &str, val: &str) {
-        self.config_map
-            .write()
-            .insert(key.to_string(), ConfigEntry::new(val.to_string()));
+    pub fn user_name(&self) -> &str {
+        &self.user_name
     }
 
-    /// Get configuration values in this session.
-    pub fn get_config(&self, key: &str) -> Option<ConfigEntry> {
-        let reader = self.config_map.read();
-        reader.get(key).cloned()
+    /// Checks that the session user is a superuser, which is required to manage users.
+    pub fn check_super_user(&self, operation: &str) -> Result<()> {
+        let reader = self.env.catalog_reader().read_guard();
+        if reader.get_user_by_name(&self.user_name)?.is_superuser {
+            Ok(())
+        } else {
+            Err(ErrorCode::PermissionDenied(format!("must be superuser to {}", operation)).into())
+        }
     }
 
-    fn init_config_map() -> RwLock<HashMap<String, ConfigEntry>> {
-        let mut map = HashMap::new();
-        // FIXME: May need better init way + default config.
-        map.insert(
-            IMPLICIT_FLUSH.to_string(),
-            ConfigEntry::new("false".to_string()),
-        );
-        RwLock::new(map)
+    /// Checks that the session user is allowed to perform `action` on `target`.
+    pub fn check_privilege(
+        &self,
+        target: &Target,
+        action: Action,
+        object_name: &str,
+    ) -> Result<()> {
+        self.env
+            .catalog_reader()
+            .read_guard()
+            .get_user_by_name(&self.user_name)?
+            .check_privilege(target, action, object_name)
+    }
+
+    /// Returns the schemas to resolve unqualified names in, following the session variable
+    /// `search_path`.
+    pub fn search_path(&self) -> SearchPath {
+        SearchPath::new(self.config().get_search_path(), &self.user_name)
+    }
+
+    /// Returns the rewriter passing the session variable `TimeZone` to the expressions depending
+    /// on it.
+    pub fn session_timezone(&self) -> SessionTimezone {
+        SessionTimezone::new(self.config().get_timezone().to_string())
+    }
+
+    /// Resolves the (`schema_name`, `relation_name`) of an existing table, source, materialized
+    /// view or view, looking it up in the search path if the name is not qualified.
+    pub fn resolve_relation_name(&self, name: ObjectName) -> Result<(String, String)> {
+        let (schema_name, relation_name) = Binder::resolve_schema_qualified_name(name)?;
+        let schema_name = self
+            .env
+            .catalog_reader()
+            .read_guard()
+            .resolve_relation_schema(
+                &self.database,
+                schema_name,
+                &self.search_path(),
+                &relation_name,
+            )?;
+        Ok((schema_name, relation_name))
+    }
+
+    /// Resolves the (`schema_name`, `name`) of an object to create. Unqualified names are created
+    /// in the first valid schema in the search path.
+    pub fn resolve_name_for_create(&self, name: ObjectName) -> Result<(String, String)> {
+        let (schema_name, name) = Binder::resolve_schema_qualified_name(name)?;
+        let schema_name = self
+            .env
+            .catalog_reader()
+            .read_guard()
+            .resolve_schema_for_create(&self.database, schema_name, &self.search_path())?;
+        Ok((schema_name, name))
+    }
+
+    pub fn config(&self) -> RwLockReadGuard<'_, ConfigMap> {
+        self.config_map.read()
+    }
+
+    /// Sets a session variable. For example, `set_config("RW_IMPLICIT_FLUSH", "true")` will
+    /// implicitly flush for every insert.
+    pub fn set_config(&self, key: &str, val: &str) -> Result<()> {
+        self.config_map.write().set(key, val)
+    }
+
+    /// Resets a session variable to its default value.
+    pub fn reset_config(&self, key: &str) -> Result<()> {
+        self.config_map.write().reset(key)
+    }
+
+    pub fn reset_all_config(&self) {
+        self.config_map.write().reset_all()
     }
 }
 
@@ -341,10 +397,27 @@ impl SessionManager for SessionManagerImpl {
     fn connect(
         &self,
         database: &str,
+        user_name: Option<&str>,
     ) -> std::result::Result<Arc<dyn Session>, Box<dyn Error + Send + Sync>> {
+        // Log in as the default superuser if the startup message doesn't specify a user.
+        let user_name = user_name.unwrap_or(DEFAULT_SUPER_USER);
+        {
+            let catalog_reader = self.env.catalog_reader().read_guard();
+            let user = catalog_reader.get_user_by_name(user_name).map_err(|_| {
+                ErrorCode::PermissionDenied(format!("role \"{}\" does not exist", user_name))
+            })?;
+            if !user.can_login {
+                return Err(ErrorCode::PermissionDenied(format!(
+                    "role \"{}\" is not permitted to log in",
+                    user_name
+                ))
+                .into());
+            }
+        }
         Ok(Arc::new(SessionImpl::new(
             self.env.clone(),
             database.to_string(),
+            user_name.to_string(),
         )))
     }
 }
@@ -390,7 +463,14 @@ impl Session for SessionImpl {
             ));
         }
         let stmt = stmts.swap_remove(0);
-        let rsp = handle(self, stmt).await.map_err(|e| {
+        let timeout = self.config().get_statement_timeout();
+        let rsp = match timeout {
+            Some(timeout) => tokio::time::timeout(timeout, handle(self, stmt))
+                .await
+                .unwrap_or_else(|_| Err(ErrorCode::StatementTimeout.into())),
+            None => handle(self, stmt).await,
+        }
+        .map_err(|e| {
             tracing::error!("failed to handle sql:\n{}:\n{}", sql, e);
             e
         })?;
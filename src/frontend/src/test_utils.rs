@@ -21,13 +21,20 @@ use std::sync::Arc;
 use parking_lot::RwLock;
 use pgwire::pg_response::PgResponse;
 use pgwire::pg_server::{Session, SessionManager};
-use risingwave_common::catalog::{TableId, DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME};
+use risingwave_common::catalog::{
+    gen_dropped_column_name, TableId, DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME,
+    DEFAULT_SUPER_USER,
+};
 use risingwave_common::error::Result;
+use risingwave_pb::catalog::source::Info;
 use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
 use risingwave_pb::catalog::{
     Database as ProstDatabase, Schema as ProstSchema, Source as ProstSource, Table as ProstTable,
+    View as ProstView,
 };
+use risingwave_pb::ddl_service::alter_relation_request::Operation as AlterRelationOperation;
 use risingwave_pb::stream_plan::StreamFragmentGraph;
+use risingwave_pb::user::{GrantPrivilege as ProstGrantPrivilege, UserInfo as ProstUserInfo};
 use risingwave_sqlparser::ast::Statement;
 use risingwave_sqlparser::parser::Parser;
 use tempfile::{Builder, NamedTempFile};
@@ -35,7 +42,7 @@ use tempfile::{Builder, NamedTempFile};
 use crate::binder::Binder;
 use crate::catalog::catalog_service::CatalogWriter;
 use crate::catalog::root_catalog::Catalog;
-use crate::catalog::{DatabaseId, SchemaId};
+use crate::catalog::{DatabaseId, SchemaId, UserId};
 use crate::meta_client::FrontendMetaClient;
 use crate::optimizer::PlanRef;
 use crate::planner::Planner;
@@ -52,6 +59,7 @@ impl SessionManager for LocalFrontend {
     fn connect(
         &self,
         _database: &str,
+        _user_name: Option<&str>,
     ) -> std::result::Result<Arc<dyn Session>, Box<dyn Error + Send + Sync>> {
         Ok(self.session_ref())
     }
@@ -71,6 +79,21 @@ impl LocalFrontend {
         self.session_ref().run_statement(sql.as_str()).await
     }
 
+    /// Same as [`LocalFrontend::run_sql`], but runs `sql` as the user `user_name`.
+    pub async fn run_user_sql(
+        &self,
+        sql: impl Into<String>,
+        user_name: impl Into<String>,
+    ) -> std::result::Result<PgResponse, Box<dyn std::error::Error + Send + Sync>> {
+        let sql = sql.into();
+        let session = Arc::new(SessionImpl::new(
+            self.env.clone(),
+            DEFAULT_DATABASE_NAME.to_string(),
+            user_name.into(),
+        ));
+        session.run_statement(sql.as_str()).await
+    }
+
     pub async fn query_formatted_result(&self, sql: impl Into<String>) -> Vec<String> {
         self.run_sql(sql)
             .await
@@ -91,6 +114,9 @@ impl LocalFrontend {
                 let mut binder = Binder::new(
                     session.env().catalog_reader().read_guard(),
                     session.database().to_string(),
+                    session.user_name().to_string(),
+                    session.search_path(),
+                    session.session_timezone(),
                 );
                 binder.bind(Statement::Query(query.clone()))?
             };
@@ -107,6 +133,7 @@ impl LocalFrontend {
         Arc::new(SessionImpl::new(
             self.env.clone(),
             DEFAULT_DATABASE_NAME.to_string(),
+            DEFAULT_SUPER_USER.to_string(),
         ))
     }
 }
@@ -116,6 +143,10 @@ pub struct MockCatalogWriter {
     id: AtomicU32,
     table_id_to_schema_id: RwLock<HashMap<u32, SchemaId>>,
     schema_id_to_database_id: RwLock<HashMap<u32, DatabaseId>>,
+    /// The tables and sources created, kept to be altered.
+    tables: RwLock<HashMap<u32, ProstTable>>,
+    sources: RwLock<HashMap<u32, ProstSource>>,
+    views: RwLock<HashMap<u32, ProstView>>,
 }
 
 #[async_trait::async_trait]
@@ -147,6 +178,7 @@ impl CatalogWriter for MockCatalogWriter {
         table.id = self.gen_id();
         self.catalog.write().create_table(&table);
         self.add_table_or_source_id(table.id, table.schema_id, table.database_id);
+        self.tables.write().insert(table.id, table);
         Ok(())
     }
 
@@ -167,7 +199,47 @@ impl CatalogWriter for MockCatalogWriter {
         self.create_source_inner(source).map(|_| ())
     }
 
-    async fn drop_materialized_source(&self, source_id: u32, table_id: TableId) -> Result<()> {
+    async fn create_view(&self, mut view: ProstView, or_replace: bool) -> Result<()> {
+        let existing = self
+            .views
+            .read()
+            .values()
+            .find(|v| {
+                v.database_id == view.database_id
+                    && v.schema_id == view.schema_id
+                    && v.name == view.name
+            })
+            .map(|v| v.id);
+        match existing {
+            Some(id) if or_replace => {
+                view.id = id;
+                self.catalog.write().update_view(&view);
+            }
+            _ => {
+                view.id = self.gen_id();
+                self.catalog.write().create_view(&view);
+                self.add_table_or_source_id(view.id, view.schema_id, view.database_id);
+            }
+        }
+        self.views.write().insert(view.id, view);
+        Ok(())
+    }
+
+    async fn drop_view(&self, view_id: u32, _cascade: bool) -> Result<()> {
+        let (database_id, schema_id) = self.drop_table_or_source_id(view_id);
+        self.catalog
+            .write()
+            .drop_view(database_id, schema_id, view_id);
+        self.views.write().remove(&view_id);
+        Ok(())
+    }
+
+    async fn drop_materialized_source(
+        &self,
+        source_id: u32,
+        table_id: TableId,
+        _cascade: bool,
+    ) -> Result<()> {
         let (database_id, schema_id) = self.drop_table_or_source_id(source_id);
         self.drop_table_or_source_id(table_id.table_id);
         self.catalog
@@ -176,14 +248,17 @@ impl CatalogWriter for MockCatalogWriter {
         self.catalog
             .write()
             .drop_source(database_id, schema_id, source_id);
+        self.tables.write().remove(&table_id.table_id);
+        self.sources.write().remove(&source_id);
         Ok(())
     }
 
-    async fn drop_source(&self, source_id: u32) -> Result<()> {
+    async fn drop_source(&self, source_id: u32, _cascade: bool) -> Result<()> {
         let (database_id, schema_id) = self.drop_table_or_source_id(source_id);
         self.catalog
             .write()
             .drop_source(database_id, schema_id, source_id);
+        self.sources.write().remove(&source_id);
         Ok(())
     }
 
@@ -198,12 +273,184 @@ impl CatalogWriter for MockCatalogWriter {
         Ok(())
     }
 
-    async fn drop_materialized_view(&self, table_id: TableId) -> Result<()> {
+    async fn drop_materialized_view(&self, table_id: TableId, _cascade: bool) -> Result<()> {
         let (database_id, schema_id) = self.drop_table_or_source_id(table_id.table_id);
         self.drop_table_or_source_id(table_id.table_id);
         self.catalog
             .write()
             .drop_table(database_id, schema_id, table_id);
+        let table = self.tables.write().remove(&table_id.table_id).unwrap();
+        if table.error_table_id != 0 {
+            let error_table = self
+                .tables
+                .read()
+                .get(&table.error_table_id)
+                .unwrap()
+                .clone();
+            let Some(OptionalAssociatedSourceId::AssociatedSourceId(source_id)) =
+                error_table.optional_associated_source_id else {
+                unreachable!()
+            };
+            self.drop_materialized_source(source_id, error_table.id.into(), false)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn alter_relation(
+        &self,
+        table_id: TableId,
+        operation: AlterRelationOperation,
+    ) -> Result<()> {
+        let mut table = self.tables.read().get(&table_id.table_id).unwrap().clone();
+        let mut source = match table.optional_associated_source_id {
+            Some(OptionalAssociatedSourceId::AssociatedSourceId(source_id)) => {
+                Some(self.sources.read().get(&source_id).unwrap().clone())
+            }
+            None => None,
+        };
+        let mut source_columns = source.as_mut().map(|source| match source.info.as_mut() {
+            Some(Info::TableSource(info)) => &mut info.columns,
+            Some(Info::StreamSource(info)) => &mut info.columns,
+            None => unreachable!(),
+        });
+
+        match operation {
+            AlterRelationOperation::AddColumn(add_column) => {
+                let mut column = add_column.column.unwrap();
+                let column_desc = column.column_desc.as_mut().unwrap();
+                column_desc.column_id = table
+                    .columns
+                    .iter()
+                    .map(|c| c.column_desc.as_ref().unwrap().column_id)
+                    .max()
+                    .unwrap_or(-1)
+                    + 1;
+                table.columns.push(column.clone());
+                if let Some(columns) = source_columns {
+                    columns.push(column);
+                }
+            }
+            AlterRelationOperation::DropColumn(drop_column) => {
+                let name = gen_dropped_column_name(drop_column.column_id.into());
+                let columns = table.columns.iter_mut().chain(
+                    source_columns
+                        .iter_mut()
+                        .flat_map(|columns| columns.iter_mut()),
+                );
+                for column in columns {
+                    let column_desc = column.column_desc.as_mut().unwrap();
+                    if column_desc.column_id == drop_column.column_id {
+                        column_desc.name = name.clone();
+                        column.is_hidden = true;
+                    }
+                }
+            }
+            AlterRelationOperation::RenameColumn(rename_column) => {
+                let columns = table.columns.iter_mut().chain(
+                    source_columns
+                        .iter_mut()
+                        .flat_map(|columns| columns.iter_mut()),
+                );
+                for column in columns {
+                    let column_desc = column.column_desc.as_mut().unwrap();
+                    if column_desc.column_id == rename_column.column_id {
+                        column_desc.name = rename_column.new_name.clone();
+                    }
+                }
+            }
+            AlterRelationOperation::RenameRelation(rename_relation) => {
+                table.name = rename_relation.new_name.clone();
+                if let Some(source) = &mut source {
+                    source.name = rename_relation.new_name;
+                }
+            }
+        }
+
+        self.catalog.write().update_table(&table);
+        self.tables.write().insert(table.id, table);
+        if let Some(source) = source {
+            self.catalog.write().update_source(source.clone());
+            self.sources.write().insert(source.id, source);
+        }
+        Ok(())
+    }
+
+    async fn create_user(&self, mut user: ProstUserInfo) -> Result<()> {
+        user.id = self.gen_id();
+        self.catalog.write().create_user(user);
+        Ok(())
+    }
+
+    async fn drop_user(&self, user_id: UserId) -> Result<()> {
+        self.catalog.write().drop_user(user_id);
+        Ok(())
+    }
+
+    async fn update_user(&self, mut user: ProstUserInfo) -> Result<()> {
+        user.grant_privileges = self.get_user(user.id).grant_privileges;
+        self.catalog.write().update_user(user);
+        Ok(())
+    }
+
+    async fn grant_privilege(
+        &self,
+        user_ids: Vec<UserId>,
+        privileges: Vec<ProstGrantPrivilege>,
+    ) -> Result<()> {
+        for user_id in user_ids {
+            let mut user = self.get_user(user_id);
+            for new in &privileges {
+                match user
+                    .grant_privileges
+                    .iter_mut()
+                    .find(|p| p.target == new.target)
+                {
+                    Some(privilege) => {
+                        for new_action in &new.action_with_opts {
+                            match privilege
+                                .action_with_opts
+                                .iter_mut()
+                                .find(|a| a.action == new_action.action)
+                            {
+                                Some(action) => {
+                                    action.with_grant_option |= new_action.with_grant_option
+                                }
+                                None => privilege.action_with_opts.push(new_action.clone()),
+                            }
+                        }
+                    }
+                    None => user.grant_privileges.push(new.clone()),
+                }
+            }
+            self.catalog.write().update_user(user);
+        }
+        Ok(())
+    }
+
+    async fn revoke_privilege(
+        &self,
+        user_ids: Vec<UserId>,
+        privileges: Vec<ProstGrantPrivilege>,
+    ) -> Result<()> {
+        for user_id in user_ids {
+            let mut user = self.get_user(user_id);
+            for revoked in &privileges {
+                for privilege in &mut user.grant_privileges {
+                    if privilege.target == revoked.target {
+                        privilege.action_with_opts.retain(|a| {
+                            !revoked
+                                .action_with_opts
+                                .iter()
+                                .any(|r| r.action == a.action)
+                        });
+                    }
+                }
+            }
+            user.grant_privileges
+                .retain(|p| !p.action_with_opts.is_empty());
+            self.catalog.write().update_user(user);
+        }
         Ok(())
     }
 }
@@ -219,6 +466,14 @@ impl MockCatalogWriter {
             name: DEFAULT_SCHEMA_NAME.to_string(),
             database_id: 0,
         });
+        catalog.write().create_user(ProstUserInfo {
+            id: 0,
+            name: DEFAULT_SUPER_USER.to_string(),
+            is_superuser: true,
+            can_create_db: true,
+            can_login: true,
+            grant_privileges: vec![],
+        });
         let mut map: HashMap<u32, DatabaseId> = HashMap::new();
         map.insert(0_u32, 0_u32);
         Self {
@@ -226,6 +481,9 @@ impl MockCatalogWriter {
             id: AtomicU32::new(0),
             table_id_to_schema_id: Default::default(),
             schema_id_to_database_id: RwLock::new(map),
+            tables: Default::default(),
+            sources: Default::default(),
+            views: Default::default(),
         }
     }
 
@@ -266,7 +524,17 @@ impl MockCatalogWriter {
         source.id = self.gen_id();
         self.catalog.write().create_source(source.clone());
         self.add_table_or_source_id(source.id, source.schema_id, source.database_id);
-        Ok(source.id)
+        let source_id = source.id;
+        self.sources.write().insert(source_id, source);
+        Ok(source_id)
+    }
+
+    fn get_user(&self, user_id: UserId) -> ProstUserInfo {
+        self.catalog
+            .read()
+            .get_user_by_id(user_id)
+            .unwrap()
+            .to_prost()
     }
 
     fn get_database_id_by_schema(&self, schema_id: u32) -> DatabaseId {
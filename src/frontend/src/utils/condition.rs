@@ -14,15 +14,21 @@
 
 use std::collections::HashSet;
 use std::fmt;
+use std::ops::Bound::{self, *};
 
 use fixedbitset::FixedBitSet;
 use itertools::Itertools;
+use risingwave_common::types::ScalarImpl;
+use risingwave_common::util::scan_range::ScanRange;
 
 use crate::expr::{
     factorization_expr, fold_boolean_constant, push_down_not, to_conjunctions,
-    try_get_bool_constant, ExprImpl, ExprRewriter, ExprType, ExprVisitor, InputRef,
+    try_get_bool_constant, Expr, ExprImpl, ExprRewriter, ExprType, ExprVisitor, InputRef,
 };
 
+/// The maximum number of scan ranges generated from `IN` lists.
+const MAX_SCAN_RANGES: usize = 1024;
+
 #[derive(Debug, Clone)]
 pub struct Condition {
     /// Condition expressions in conjunction form (combined with `AND`)
@@ -174,6 +180,64 @@ impl Condition {
         .unwrap()
     }
 
+    /// Extract the ranges of the order key to scan from the condition, where `order_key` contains
+    /// the indices of the leading order key columns of the table in the input. The returned
+    /// condition should still be applied to the rows scanned.
+    ///
+    /// The leading columns compared with constants for equality, either by `=` or `IN`, are used
+    /// as the prefixes of the ranges, and the comparisons between the next column and constants
+    /// bound the ranges. Equality conditions are removed from the returned condition, while range
+    /// conditions are kept because NULLs are not excluded by the ranges. No range is returned if
+    /// nothing is extracted, which means a full table scan.
+    #[must_use]
+    pub fn split_to_scan_ranges(self, order_key: &[usize]) -> (Vec<ScanRange>, Self) {
+        let mut conjunctions = self.conjunctions;
+        let mut scan_ranges = vec![ScanRange::full_table_scan()];
+
+        for &column in order_key {
+            // Use the first equality condition on the column, or the first `IN` condition not
+            // generating too many ranges.
+            let values = conjunctions.iter().enumerate().find_map(|(i, expr)| {
+                let values = eq_const_values(expr, column)?;
+                (values.len() * scan_ranges.len() <= MAX_SCAN_RANGES).then(|| (i, values))
+            });
+            if let Some((i, values)) = values {
+                conjunctions.remove(i);
+                scan_ranges = scan_ranges
+                    .into_iter()
+                    .cartesian_product(values)
+                    .map(|(mut scan_range, value)| {
+                        scan_range.eq_conds.push(Some(value));
+                        scan_range
+                    })
+                    .collect();
+                continue;
+            }
+
+            let mut range = (Unbounded, Unbounded);
+            for expr in &conjunctions {
+                if let Some((lower, upper)) = range_const_bound(expr, column) {
+                    if range.0 == Unbounded {
+                        range.0 = lower;
+                    }
+                    if range.1 == Unbounded {
+                        range.1 = upper;
+                    }
+                }
+            }
+            for scan_range in &mut scan_ranges {
+                scan_range.range = range.clone();
+            }
+            break;
+        }
+
+        let scan_ranges = match scan_ranges.as_slice() {
+            [scan_range] if scan_range.is_full_table_scan() => vec![],
+            _ => scan_ranges,
+        };
+        (scan_ranges, Self { conjunctions })
+    }
+
     /// Split the condition expressions into `N` groups.
     /// An expression `expr` is in the `i`-th group if `f(expr)==i`.
     ///
@@ -268,6 +332,83 @@ impl Condition {
     }
 }
 
+/// Evaluates a constant of the same type as input column `column`, returning `None` if it's not
+/// or fails to be evaluated.
+fn eval_const_of_column(expr: &ExprImpl, column: &InputRef) -> Option<Option<ScalarImpl>> {
+    if !expr.is_const() || expr.return_type() != column.return_type() {
+        return None;
+    }
+    expr.eval_const().ok()
+}
+
+/// Returns the distinct non-null values that input column `column` is compared with for equality
+/// if `expr` is `column = const` or `column IN (const, ...)`.
+fn eq_const_values(expr: &ExprImpl, column: usize) -> Option<Vec<ScalarImpl>> {
+    let values = if let Some((input_ref, value)) = expr.as_eq_const() {
+        if input_ref.index() != column {
+            return None;
+        }
+        vec![eval_const_of_column(&value, &input_ref)?]
+    } else if let ExprImpl::FunctionCall(function_call) = expr
+        && function_call.get_expr_type() == ExprType::In
+        && let [ExprImpl::InputRef(input_ref), list @ ..] = function_call.inputs()
+        && input_ref.index() == column
+    {
+        list.iter()
+            .map(|value| eval_const_of_column(value, input_ref))
+            .collect::<Option<Vec<_>>>()?
+    } else {
+        return None;
+    };
+
+    // NULLs never equal to anything.
+    let mut values = values.into_iter().flatten().collect_vec();
+    values.sort();
+    values.dedup();
+    Some(values)
+}
+
+/// Returns the bounds of input column `column` if `expr` compares it with a non-null constant.
+fn range_const_bound(
+    expr: &ExprImpl,
+    column: usize,
+) -> Option<(Bound<ScalarImpl>, Bound<ScalarImpl>)> {
+    let ExprImpl::FunctionCall(function_call) = expr else {
+        return None;
+    };
+    let (expr_type, left, right) = match function_call.get_expr_type() {
+        ExprType::LessThan
+        | ExprType::LessThanOrEqual
+        | ExprType::GreaterThan
+        | ExprType::GreaterThanOrEqual => function_call.clone().decompose_as_binary(),
+        _ => return None,
+    };
+    // Normalize the comparison as `column op value`.
+    let (expr_type, input_ref, value) = match (left, right) {
+        (ExprImpl::InputRef(input_ref), value) => (expr_type, input_ref, value),
+        (value, ExprImpl::InputRef(input_ref)) => {
+            let expr_type = match expr_type {
+                ExprType::LessThan => ExprType::GreaterThan,
+                ExprType::LessThanOrEqual => ExprType::GreaterThanOrEqual,
+                ExprType::GreaterThan => ExprType::LessThan,
+                _ => ExprType::LessThanOrEqual,
+            };
+            (expr_type, input_ref, value)
+        }
+        _ => return None,
+    };
+    if input_ref.index() != column {
+        return None;
+    }
+    let value = eval_const_of_column(&value, &input_ref)??;
+    Some(match expr_type {
+        ExprType::LessThan => (Unbounded, Excluded(value)),
+        ExprType::LessThanOrEqual => (Unbounded, Included(value)),
+        ExprType::GreaterThan => (Excluded(value), Unbounded),
+        _ => (Included(value), Unbounded),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use rand::Rng;
@@ -332,4 +473,64 @@ mod tests {
         assert_eq!(res.1.conjunctions, vec![right]);
         assert_eq!(res.2.conjunctions, vec![other]);
     }
+
+    #[test]
+    fn test_split_to_scan_ranges() {
+        let ty = DataType::Int32;
+        let cmp = |expr_type: ExprType, col: usize, value: i32| -> ExprImpl {
+            FunctionCall::new(
+                expr_type,
+                vec![
+                    InputRef::new(col, ty.clone()).into(),
+                    ExprImpl::literal_int(value),
+                ],
+            )
+            .unwrap()
+            .into()
+        };
+        let in_list: ExprImpl = FunctionCall::new(
+            ExprType::In,
+            vec![
+                InputRef::new(1, ty.clone()).into(),
+                ExprImpl::literal_int(3),
+                ExprImpl::literal_int(2),
+            ],
+        )
+        .unwrap()
+        .into();
+
+        // The order key is ($2, $1, $0).
+        let cond = Condition {
+            conjunctions: vec![
+                cmp(ExprType::GreaterThan, 0, 5),
+                in_list,
+                cmp(ExprType::Equal, 2, 1),
+                cmp(ExprType::LessThanOrEqual, 0, 10),
+            ],
+        };
+        let (scan_ranges, others) = cond.split_to_scan_ranges(&[2, 1, 0]);
+        let scan_range = |value| ScanRange {
+            eq_conds: vec![Some(ScalarImpl::Int32(1)), Some(ScalarImpl::Int32(value))],
+            range: (
+                Excluded(ScalarImpl::Int32(5)),
+                Included(ScalarImpl::Int32(10)),
+            ),
+        };
+        assert_eq!(scan_ranges, vec![scan_range(2), scan_range(3)]);
+        assert_eq!(
+            others.conjunctions,
+            vec![
+                cmp(ExprType::GreaterThan, 0, 5),
+                cmp(ExprType::LessThanOrEqual, 0, 10),
+            ]
+        );
+
+        // Nothing is extracted if the first column of the order key is not compared.
+        let cond = Condition {
+            conjunctions: vec![cmp(ExprType::Equal, 1, 1)],
+        };
+        let (scan_ranges, others) = cond.split_to_scan_ranges(&[0, 1]);
+        assert!(scan_ranges.is_empty());
+        assert_eq!(others.conjunctions, vec![cmp(ExprType::Equal, 1, 1)]);
+    }
 }
@@ -170,7 +170,7 @@ impl TestCase {
 
         if let Some(ref config_map) = self.with_config_map {
             for (key, val) in config_map {
-                session.set_config(key, val);
+                session.set_config(key, val).unwrap();
             }
         }
 
@@ -242,7 +242,10 @@ impl TestCase {
         for stmt in statements {
             let context = OptimizerContext::new(session.clone());
             match stmt.clone() {
-                Statement::Query(_) | Statement::Insert { .. } | Statement::Delete { .. } => {
+                Statement::Query(_)
+                | Statement::Insert { .. }
+                | Statement::Delete { .. }
+                | Statement::Update { .. } => {
                     if result.is_some() {
                         panic!("two queries in one test case");
                     }
@@ -252,8 +255,13 @@ impl TestCase {
                     }
                     result = Some(ret);
                 }
-                Statement::CreateTable { name, columns, .. } => {
-                    create_table::handle_create_table(context, name, columns).await?;
+                Statement::CreateTable {
+                    name,
+                    columns,
+                    constraints,
+                    ..
+                } => {
+                    create_table::handle_create_table(context, name, columns, constraints).await?;
                 }
                 Statement::CreateSource {
                     is_materialized,
@@ -265,10 +273,20 @@ impl TestCase {
                     name,
                     table_name,
                     columns,
-                    // TODO: support unique and if_not_exist in planner test
-                    ..
+                    include,
+                    unique,
+                    if_not_exists,
                 } => {
-                    create_index::handle_create_index(context, name, table_name, columns).await?;
+                    create_index::handle_create_index(
+                        context,
+                        unique,
+                        if_not_exists,
+                        name,
+                        table_name,
+                        columns,
+                        include,
+                    )
+                    .await?;
                 }
                 Statement::CreateView {
                     materialized: true,
@@ -300,6 +318,8 @@ impl TestCase {
             let mut binder = Binder::new(
                 session.env().catalog_reader().read_guard(),
                 session.database().to_string(),
+                session.user_name().to_string(),
+                session.search_path(),
             );
             match binder.bind(stmt.clone()) {
                 Ok(bound) => bound,
@@ -380,7 +400,7 @@ impl TestCase {
 }
 
 fn explain_plan(plan: &PlanRef) -> String {
-    plan.explain_to_string().expect("failed to explain")
+    plan.explain_to_string(false).expect("failed to explain")
 }
 
 fn check_result(expected: &TestCase, actual: &TestCaseResult) -> Result<()> {
@@ -20,7 +20,9 @@ use risingwave_common::error::{Result, RwError, ToRwResult};
 use risingwave_common::util::epoch::Epoch;
 use risingwave_pb::common::ActorInfo;
 use risingwave_pb::data::barrier::Mutation;
-use risingwave_pb::data::{AddMutation, DispatcherMutation, NothingMutation, StopMutation};
+use risingwave_pb::data::{
+    AddColumnsMutation, AddMutation, AddedColumn, DispatcherMutation, NothingMutation, StopMutation,
+};
 use risingwave_pb::stream_service::DropActorsRequest;
 use uuid::Uuid;
 
@@ -61,6 +63,18 @@ pub enum Command {
         table_sink_map: HashMap<TableId, Vec<ActorId>>,
         dispatches: HashMap<(ActorId, DispatcherId), Vec<ActorInfo>>,
     },
+
+    /// `AddColumns` command generates a `AddColumns` barrier for the columns added to the table
+    /// `table_id` by `ALTER TABLE ADD COLUMN`. The source and materialize executors of the table
+    /// start to read and write the new columns since this barrier.
+    ///
+    /// After the barrier is collected, it adds the columns to the table fragments info in meta
+    /// store, so that the actors rebuilt on recovery see them.
+    AddColumns {
+        table_id: TableId,
+        source_id: TableId,
+        columns: Vec<AddedColumn>,
+    },
 }
 
 impl Command {
@@ -142,6 +156,16 @@ where
                     .collect();
                 Mutation::Add(AddMutation { mutations })
             }
+
+            Command::AddColumns {
+                table_id,
+                source_id,
+                columns,
+            } => Mutation::AddColumns(AddColumnsMutation {
+                table_id: table_id.table_id(),
+                source_id: source_id.table_id(),
+                columns: columns.clone(),
+            }),
         };
 
         Ok(mutation)
@@ -219,6 +243,16 @@ where
                     )
                     .await?;
             }
+
+            Command::AddColumns {
+                table_id,
+                source_id,
+                columns,
+            } => {
+                self.fragment_manager
+                    .add_table_columns(table_id, source_id, columns)
+                    .await?;
+            }
         }
 
         Ok(())
@@ -18,13 +18,19 @@ use std::option::Option::Some;
 use std::sync::Arc;
 
 use anyhow::anyhow;
-use risingwave_common::catalog::{CatalogVersion, DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME};
+use risingwave_common::catalog::{
+    gen_dropped_column_name, is_default_super_user, is_dropped_column_name, CatalogVersion,
+    DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME, DEFAULT_SUPER_USER, DEFAULT_SUPER_USER_FOR_PG,
+};
 use risingwave_common::ensure;
 use risingwave_common::error::ErrorCode::{CatalogError, InternalError};
 use risingwave_common::error::{Result, RwError};
+use risingwave_pb::catalog::source::Info as SourceInfo;
 use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
-use risingwave_pb::catalog::{Database, Schema, Source, Table};
+use risingwave_pb::catalog::{Database, Schema, Source, Table, TableSourceInfo, View};
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
+use risingwave_pb::plan_common::ColumnCatalog;
+use risingwave_pb::user::{GrantPrivilege, UserInfo};
 use tokio::sync::{Mutex, MutexGuard};
 
 use super::IdCategory;
@@ -37,8 +43,17 @@ pub type SchemaId = u32;
 pub type TableId = u32;
 pub type SourceId = u32;
 pub type RelationId = u32;
-
-pub type Catalog = (Vec<Database>, Vec<Schema>, Vec<Table>, Vec<Source>);
+pub type UserId = u32;
+pub type ColumnId = i32;
+
+pub type Catalog = (
+    Vec<Database>,
+    Vec<Schema>,
+    Vec<Table>,
+    Vec<Source>,
+    Vec<View>,
+    Vec<UserInfo>,
+);
 
 pub struct CatalogManager<S: MetaStore> {
     env: MetaSrvEnv<S>,
@@ -60,7 +75,7 @@ where
         Ok(catalog_manager)
     }
 
-    // Create default database and schema.
+    // Create default database, schema and super user.
     async fn init(&self) -> Result<()> {
         let mut database = Database {
             name: DEFAULT_DATABASE_NAME.to_string(),
@@ -94,6 +109,24 @@ where
                 .await? as u32;
             self.create_schema(&schema).await?;
         }
+
+        for user_name in [DEFAULT_SUPER_USER, DEFAULT_SUPER_USER_FOR_PG] {
+            if !self.core.lock().await.has_user(user_name) {
+                let user = UserInfo {
+                    id: self
+                        .env
+                        .id_gen_manager()
+                        .generate::<{ IdCategory::User }>()
+                        .await? as u32,
+                    name: user_name.to_string(),
+                    is_superuser: true,
+                    can_create_db: true,
+                    can_login: true,
+                    grant_privileges: vec![],
+                };
+                self.create_user(&user).await?;
+            }
+        }
         Ok(())
     }
 
@@ -267,41 +300,29 @@ where
         }
     }
 
-    pub async fn drop_table(&self, table_id: TableId) -> Result<CatalogVersion> {
+    /// Drops the table `table_id`, which must have no dependents, together with its error table if
+    /// it's a materialized view created with one. See [`CatalogManager::drop_table_cascade`] for
+    /// the returned ids.
+    pub async fn drop_table(
+        &self,
+        table_id: TableId,
+    ) -> Result<(CatalogVersion, Vec<TableId>, Vec<SourceId>)> {
         let mut core = self.core.lock().await;
-        let table = Table::select(self.env.meta_store(), &table_id).await?;
-        if let Some(table) = table {
-            match core.get_ref_count(table_id) {
-                Some(ref_count) => Err(CatalogError(
-                    anyhow!(
-                        "Fail to delete table `{}` because {} other relation(s) depend on it.",
-                        table.name,
-                        ref_count
-                    )
-                    .into(),
-                )
-                .into()),
-                None => {
-                    Table::delete(self.env.meta_store(), &table_id).await?;
-                    core.drop_table(&table);
-                    for &dependent_relation_id in &table.dependent_relations {
-                        core.decrease_ref_count(dependent_relation_id);
-                    }
-
-                    let version = self
-                        .env
-                        .notification_manager()
-                        .notify_frontend(Operation::Delete, Info::Table(table))
-                        .await;
-
-                    Ok(version)
-                }
-            }
-        } else {
-            Err(RwError::from(InternalError(
-                "table doesn't exist".to_string(),
-            )))
+        let table = Table::select(self.env.meta_store(), &table_id)
+            .await?
+            .ok_or_else(|| InternalError("table doesn't exist".to_string()))?;
+        core.check_no_dependents("table", &table.name, table_id)
+            .await?;
+        // The materialized view itself is the only relation allowed to depend on its error table.
+        if table.error_table_id != 0 && core.get_ref_count(table.error_table_id) != Some(1) {
+            let error_table = Table::select(self.env.meta_store(), &table.error_table_id)
+                .await?
+                .ok_or_else(|| InternalError("error table doesn't exist".to_string()))?;
+            core.check_no_dependents("table", &error_table.name, error_table.id)
+                .await?;
         }
+        self.drop_relations_cascade(&mut core, vec![table], None, None)
+            .await
     }
 
     pub async fn start_create_source_procedure(&self, source: &Source) -> Result<()> {
@@ -376,29 +397,18 @@ where
         let mut core = self.core.lock().await;
         let source = Source::select(self.env.meta_store(), &source_id).await?;
         if let Some(source) = source {
-            match core.get_ref_count(source_id) {
-                Some(ref_count) => Err(CatalogError(
-                    anyhow!(
-                        "Fail to delete source `{}` because {} other relation(s) depend on it.",
-                        source.name,
-                        ref_count
-                    )
-                    .into(),
-                )
-                .into()),
-                None => {
-                    Source::delete(self.env.meta_store(), &source_id).await?;
-                    core.drop_source(&source);
+            core.check_no_dependents("source", &source.name, source_id)
+                .await?;
+            Source::delete(self.env.meta_store(), &source_id).await?;
+            core.drop_source(&source);
 
-                    let version = self
-                        .env
-                        .notification_manager()
-                        .notify_frontend(Operation::Delete, Info::Source(source))
-                        .await;
+            let version = self
+                .env
+                .notification_manager()
+                .notify_frontend(Operation::Delete, Info::Source(source))
+                .await;
 
-                    Ok(version)
-                }
-            }
+            Ok(version)
         } else {
             Err(RwError::from(InternalError(
                 "source doesn't exist".to_string(),
@@ -519,28 +529,10 @@ where
                     )));
                 }
                 // check ref count
-                if let Some(ref_count) = core.get_ref_count(mview_id) {
-                    return Err(CatalogError(
-                        anyhow!(
-                            "Fail to delete table `{}` because {} other relation(s) depend on it.",
-                            mview.name,
-                            ref_count
-                        )
-                        .into(),
-                    )
-                    .into());
-                }
-                if let Some(ref_count) = core.get_ref_count(source_id) {
-                    return Err(CatalogError(
-                        anyhow!(
-                            "Fail to delete source `{}` because {} other relation(s) depend on it.",
-                            source.name,
-                            ref_count
-                        )
-                        .into(),
-                    )
-                    .into());
-                }
+                core.check_no_dependents("table", &mview.name, mview_id)
+                    .await?;
+                core.check_no_dependents("source", &source.name, source_id)
+                    .await?;
 
                 // now is safe to delete both mview and source
                 let mut transaction = Transaction::default();
@@ -570,13 +562,813 @@ where
             ))),
         }
     }
+
+    /// Drops the table `table_id` together with the materialized views and indexes depending on it,
+    /// directly or indirectly, in one transaction. The error tables of the dropped materialized
+    /// views are dropped as well.
+    ///
+    /// Returns the ids of all dropped tables, where every table comes before the relations it
+    /// depends on, and the ids of the associated sources of the dropped error tables.
+    pub async fn drop_table_cascade(
+        &self,
+        table_id: TableId,
+    ) -> Result<(CatalogVersion, Vec<TableId>, Vec<SourceId>)> {
+        let mut core = self.core.lock().await;
+        let table = Table::select(self.env.meta_store(), &table_id)
+            .await?
+            .ok_or_else(|| InternalError("table doesn't exist".to_string()))?;
+        self.drop_relations_cascade(&mut core, vec![table], None, None)
+            .await
+    }
+
+    /// Drops the source `source_id` together with the materialized views depending on it. See
+    /// [`CatalogManager::drop_table_cascade`].
+    pub async fn drop_source_cascade(
+        &self,
+        source_id: SourceId,
+    ) -> Result<(CatalogVersion, Vec<TableId>, Vec<SourceId>)> {
+        let mut core = self.core.lock().await;
+        let source = Source::select(self.env.meta_store(), &source_id)
+            .await?
+            .ok_or_else(|| InternalError("source doesn't exist".to_string()))?;
+        self.drop_relations_cascade(&mut core, vec![], Some(source), None)
+            .await
+    }
+
+    /// Drops the materialized source `source_id` and its table `mview_id` together with the
+    /// materialized views and indexes depending on them. See
+    /// [`CatalogManager::drop_table_cascade`].
+    pub async fn drop_materialized_source_cascade(
+        &self,
+        source_id: SourceId,
+        mview_id: TableId,
+    ) -> Result<(CatalogVersion, Vec<TableId>, Vec<SourceId>)> {
+        let mut core = self.core.lock().await;
+        let mview = Table::select(self.env.meta_store(), &mview_id).await?;
+        let source = Source::select(self.env.meta_store(), &source_id).await?;
+        match (mview, source) {
+            (Some(mview), Some(source)) => {
+                if mview.optional_associated_source_id
+                    != Some(OptionalAssociatedSourceId::AssociatedSourceId(source_id))
+                {
+                    return Err(RwError::from(InternalError(
+                        "mview's associated source id doesn't match source id".to_string(),
+                    )));
+                }
+                self.drop_relations_cascade(&mut core, vec![mview], Some(source), None)
+                    .await
+            }
+            _ => Err(RwError::from(InternalError(
+                "table or source doesn't exist".to_string(),
+            ))),
+        }
+    }
+
+    async fn drop_relations_cascade(
+        &self,
+        core: &mut CatalogManagerCore<S>,
+        tables: Vec<Table>,
+        source: Option<Source>,
+        view: Option<View>,
+    ) -> Result<(CatalogVersion, Vec<TableId>, Vec<SourceId>)> {
+        let mut relation_ids = tables
+            .iter()
+            .map(|table| table.id)
+            .chain(source.iter().map(|source| source.id))
+            .chain(view.iter().map(|view| view.id))
+            .collect::<Vec<_>>();
+
+        // The error tables of the materialized views to drop are dropped as well, and so are the
+        // relations depending on them. A materialized view depends on its error table, so it's
+        // listed again as a dependent of the error table.
+        let mut error_tables = vec![];
+        let mut tables_to_drop = loop {
+            let dependents = core.list_dependent_tables(&relation_ids).await?;
+            let mut new_error_table_ids = dependents
+                .iter()
+                .chain(tables.iter())
+                .map(|table| table.error_table_id)
+                .filter(|id| *id != 0 && !relation_ids.contains(id))
+                .collect::<Vec<_>>();
+            new_error_table_ids.sort_unstable();
+            new_error_table_ids.dedup();
+            if new_error_table_ids.is_empty() {
+                break dependents;
+            }
+            for error_table_id in new_error_table_ids {
+                let error_table = Table::select(core.env.meta_store(), &error_table_id)
+                    .await?
+                    .ok_or_else(|| InternalError("error table doesn't exist".to_string()))?;
+                relation_ids.push(error_table_id);
+                error_tables.push(error_table);
+            }
+        };
+        let root_table_ids = tables.iter().map(|table| table.id).collect::<HashSet<_>>();
+        for table in tables.into_iter().chain(error_tables) {
+            if tables_to_drop.iter().all(|t| t.id != table.id) {
+                tables_to_drop.push(table);
+            }
+        }
+        let mut error_table_sources = vec![];
+        for table in &tables_to_drop {
+            // The error table itself may be the materialized source to drop.
+            if table.error_table_id != 0 && !root_table_ids.contains(&table.error_table_id) {
+                let Some(OptionalAssociatedSourceId::AssociatedSourceId(source_id)) = tables_to_drop
+                    .iter()
+                    .find(|t| t.id == table.error_table_id)
+                    .and_then(|t| t.optional_associated_source_id.clone())
+                else {
+                    return Err(InternalError(
+                        "error table has no associated source".to_string(),
+                    )
+                    .into());
+                };
+                let source = Source::select(core.env.meta_store(), &source_id)
+                    .await?
+                    .ok_or_else(|| InternalError("source doesn't exist".to_string()))?;
+                error_table_sources.push(source);
+            }
+        }
+
+        // Views may depend on any of the relations to drop, while no table depends on a view.
+        let mut views_to_drop = core
+            .list_dependent_views(
+                &relation_ids
+                    .iter()
+                    .cloned()
+                    .chain(tables_to_drop.iter().map(|table| table.id))
+                    .chain(error_table_sources.iter().map(|source| source.id))
+                    .collect::<Vec<_>>(),
+            )
+            .await?;
+        views_to_drop.extend(view);
+
+        // All stored dependents will be dropped, so any other reference comes from a relation in
+        // creating procedure, which must not lose its upstream.
+        for relation_id in relation_ids
+            .iter()
+            .chain(tables_to_drop.iter().map(|table| &table.id))
+            .chain(views_to_drop.iter().map(|view| &view.id))
+            .chain(error_table_sources.iter().map(|source| &source.id))
+        {
+            let stored_ref_count = tables_to_drop
+                .iter()
+                .map(|table| &table.dependent_relations)
+                .chain(views_to_drop.iter().map(|view| &view.dependent_relations))
+                .filter(|dependent_relations| dependent_relations.contains(relation_id))
+                .count();
+            if core.get_ref_count(*relation_id).unwrap_or(0) != stored_ref_count {
+                return Err(CatalogError(
+                    anyhow!(
+                        "Fail to delete relation {} because a relation on it is being created.",
+                        relation_id
+                    )
+                    .into(),
+                )
+                .into());
+            }
+        }
+
+        let mut transaction = Transaction::default();
+        for table in &tables_to_drop {
+            table.delete_in_transaction(&mut transaction)?;
+        }
+        for view in &views_to_drop {
+            view.delete_in_transaction(&mut transaction)?;
+        }
+        for source in source.iter().chain(&error_table_sources) {
+            source.delete_in_transaction(&mut transaction)?;
+        }
+        core.env.meta_store().txn(transaction).await?;
+
+        for table in &tables_to_drop {
+            core.drop_table(table);
+            for &dependent_relation_id in &table.dependent_relations {
+                core.decrease_ref_count(dependent_relation_id);
+            }
+        }
+        for view in &views_to_drop {
+            core.drop_view(view);
+            for &dependent_relation_id in &view.dependent_relations {
+                core.decrease_ref_count(dependent_relation_id);
+            }
+        }
+        for source in source.iter().chain(&error_table_sources) {
+            core.drop_source(source);
+        }
+
+        let table_ids = tables_to_drop.iter().map(|table| table.id).collect();
+        let error_table_source_ids = error_table_sources.iter().map(|source| source.id).collect();
+        let mut version = 0;
+        for view in views_to_drop {
+            version = self
+                .env
+                .notification_manager()
+                .notify_frontend(Operation::Delete, Info::View(view))
+                .await;
+        }
+        for table in tables_to_drop {
+            version = self
+                .env
+                .notification_manager()
+                .notify_frontend(Operation::Delete, Info::Table(table))
+                .await;
+        }
+        for source in source.into_iter().chain(error_table_sources) {
+            version = self
+                .env
+                .notification_manager()
+                .notify_frontend(Operation::Delete, Info::Source(source))
+                .await;
+        }
+
+        Ok((version, table_ids, error_table_source_ids))
+    }
+
+    /// Creates the view, or replaces the view with the same name if `or_replace` is true, in which
+    /// case the id of the replaced view is kept for its dependents. Returns the id of the view.
+    pub async fn create_view(
+        &self,
+        view: &View,
+        or_replace: bool,
+    ) -> Result<(RelationId, CatalogVersion)> {
+        let mut core = self.core.lock().await;
+        let key = (view.database_id, view.schema_id, view.name.clone());
+        if core.tables.contains(&key)
+            || core.sources.contains(&key)
+            || core.has_in_progress_creation(&key)
+        {
+            return Err(
+                CatalogError(anyhow!("relation {} already exists", view.name).into()).into(),
+            );
+        }
+
+        let origin_view = if core.views.contains(&key) {
+            if !or_replace {
+                return Err(
+                    CatalogError(anyhow!("view {} already exists", view.name).into()).into(),
+                );
+            }
+            View::list(self.env.meta_store())
+                .await?
+                .into_iter()
+                .find(|v| (v.database_id, v.schema_id, &v.name) == (key.0, key.1, &key.2))
+        } else {
+            None
+        };
+
+        let mut view = view.clone();
+        if let Some(origin_view) = &origin_view {
+            view.id = origin_view.id;
+            // The replaced view must not depend on itself, directly or via its dependents.
+            let dependents = core.list_dependent_views(&[view.id]).await?;
+            if view
+                .dependent_relations
+                .iter()
+                .any(|id| *id == view.id || dependents.iter().any(|v| v.id == *id))
+            {
+                return Err(CatalogError(
+                    anyhow!("view {} cannot depend on itself", view.name).into(),
+                )
+                .into());
+            }
+        }
+        view.insert(self.env.meta_store()).await?;
+
+        let operation = match origin_view {
+            Some(origin_view) => {
+                for &dependent_relation_id in &origin_view.dependent_relations {
+                    core.decrease_ref_count(dependent_relation_id);
+                }
+                Operation::Update
+            }
+            None => {
+                core.add_view(&view);
+                Operation::Add
+            }
+        };
+        for &dependent_relation_id in &view.dependent_relations {
+            core.increase_ref_count(dependent_relation_id);
+        }
+
+        let view_id = view.id;
+        let version = self
+            .env
+            .notification_manager()
+            .notify_frontend(operation, Info::View(view))
+            .await;
+
+        Ok((view_id, version))
+    }
+
+    pub async fn drop_view(&self, view_id: RelationId) -> Result<CatalogVersion> {
+        let mut core = self.core.lock().await;
+        let view = View::select(self.env.meta_store(), &view_id)
+            .await?
+            .ok_or_else(|| InternalError("view doesn't exist".to_string()))?;
+        core.check_no_dependents("view", &view.name, view_id)
+            .await?;
+        View::delete(self.env.meta_store(), &view_id).await?;
+        core.drop_view(&view);
+        for &dependent_relation_id in &view.dependent_relations {
+            core.decrease_ref_count(dependent_relation_id);
+        }
+
+        let version = self
+            .env
+            .notification_manager()
+            .notify_frontend(Operation::Delete, Info::View(view))
+            .await;
+
+        Ok(version)
+    }
+
+    /// Drops the view `view_id` together with the views depending on it. See
+    /// [`CatalogManager::drop_table_cascade`].
+    pub async fn drop_view_cascade(&self, view_id: RelationId) -> Result<CatalogVersion> {
+        let mut core = self.core.lock().await;
+        let view = View::select(self.env.meta_store(), &view_id)
+            .await?
+            .ok_or_else(|| InternalError("view doesn't exist".to_string()))?;
+        let (version, ..) = self
+            .drop_relations_cascade(&mut core, vec![], None, Some(view))
+            .await?;
+        Ok(version)
+    }
+
+    /// Validates the column to be added to table `table_id` by `ALTER TABLE ADD COLUMN`, and
+    /// assigns it the id next to all columns ever in the table, as the ids of dropped columns are
+    /// never reused. The table is marked as being altered until the procedure is finished or
+    /// cancelled.
+    ///
+    /// Returns the associated source id of the table and the column with its id.
+    pub async fn start_add_column_procedure(
+        &self,
+        table_id: TableId,
+        mut column: ColumnCatalog,
+    ) -> Result<(SourceId, ColumnCatalog)> {
+        let mut core = self.core.lock().await;
+        let table = Table::select(self.env.meta_store(), &table_id)
+            .await?
+            .ok_or_else(|| InternalError("table doesn't exist".to_string()))?;
+        let source_id = get_associated_source_id(&table)?;
+        let key = (table.database_id, table.schema_id, table.name.clone());
+        if core.has_in_progress_creation(&key) {
+            return Err(
+                CatalogError(anyhow!("table {} is being altered", table.name).into()).into(),
+            );
+        }
+
+        let column_desc = column.column_desc.as_mut().unwrap();
+        if table
+            .columns
+            .iter()
+            .any(|c| c.get_column_desc().unwrap().name == column_desc.name)
+        {
+            return Err(
+                CatalogError(anyhow!("column {} already exists", column_desc.name).into()).into(),
+            );
+        }
+        column_desc.column_id = table
+            .columns
+            .iter()
+            .map(|c| c.get_column_desc().unwrap().column_id)
+            .max()
+            .unwrap_or(-1)
+            + 1;
+        core.mark_creating(&key);
+
+        Ok((source_id, column))
+    }
+
+    /// Adds the column to table `table_id` and its associated source after the table has started
+    /// to write it.
+    pub async fn finish_add_column_procedure(
+        &self,
+        table_id: TableId,
+        column: ColumnCatalog,
+    ) -> Result<CatalogVersion> {
+        let mut core = self.core.lock().await;
+        let (mut table, mut source) = self.get_table_and_source(table_id).await?;
+        core.unmark_creating(&(table.database_id, table.schema_id, table.name.clone()));
+        let source_info = get_table_source_info(source.as_mut().unwrap())?;
+        source_info.columns.push(column.clone());
+        table.columns.push(column);
+
+        self.update_table_and_source(&mut core, table, source).await
+    }
+
+    pub async fn cancel_add_column_procedure(&self, table_id: TableId) -> Result<()> {
+        let mut core = self.core.lock().await;
+        let table = Table::select(self.env.meta_store(), &table_id)
+            .await?
+            .ok_or_else(|| InternalError("table doesn't exist".to_string()))?;
+        core.unmark_creating(&(table.database_id, table.schema_id, table.name));
+        Ok(())
+    }
+
+    /// Drops column `column_id` from table `table_id` by `ALTER TABLE DROP COLUMN`, unless some
+    /// materialized views, given by `reading_table_ids`, read it. The column is kept in the table
+    /// and its associated source as a hidden column with a reserved name, so that the layout of the
+    /// table doesn't change.
+    pub async fn drop_column(
+        &self,
+        table_id: TableId,
+        column_id: ColumnId,
+        reading_table_ids: &[TableId],
+    ) -> Result<CatalogVersion> {
+        let mut core = self.core.lock().await;
+        let (mut table, mut source) = self.get_table_and_source(table_id).await?;
+        if source.is_none() {
+            return Err(CatalogError(
+                anyhow!("cannot drop a column of materialized view {}", table.name).into(),
+            )
+            .into());
+        }
+        if !reading_table_ids.is_empty() {
+            let mut dependents = vec![];
+            for reading_table_id in reading_table_ids {
+                if let Some(reading_table) =
+                    Table::select(self.env.meta_store(), reading_table_id).await?
+                {
+                    dependents.push(format!("`{}`", reading_table.name));
+                }
+            }
+            return Err(CatalogError(
+                anyhow!(
+                    "Fail to drop the column because other relation(s) depend on it: {}.",
+                    dependents.join(", ")
+                )
+                .into(),
+            )
+            .into());
+        }
+        core.check_no_dependent_views("drop the column", table_id)
+            .await?;
+        if table.order_column_ids.contains(&column_id) {
+            return Err(CatalogError(
+                anyhow!("cannot drop a primary key column of table {}", table.name).into(),
+            )
+            .into());
+        }
+
+        let drop_column = |columns: &mut Vec<ColumnCatalog>| -> Result<()> {
+            let column = columns
+                .iter_mut()
+                .find(|c| {
+                    let desc = c.get_column_desc().unwrap();
+                    desc.column_id == column_id && !is_dropped_column_name(&desc.name)
+                })
+                .ok_or_else(|| InternalError("column doesn't exist".to_string()))?;
+            column.is_hidden = true;
+            column.column_desc.as_mut().unwrap().name = gen_dropped_column_name(column_id.into());
+            Ok(())
+        };
+        drop_column(&mut table.columns)?;
+        drop_column(&mut get_table_source_info(source.as_mut().unwrap())?.columns)?;
+
+        self.update_table_and_source(&mut core, table, source).await
+    }
+
+    /// Renames column `column_id` of table or materialized view `table_id`, and the column of the
+    /// associated source if there is one.
+    pub async fn rename_column(
+        &self,
+        table_id: TableId,
+        column_id: ColumnId,
+        new_name: &str,
+    ) -> Result<CatalogVersion> {
+        let mut core = self.core.lock().await;
+        let (mut table, mut source) = self.get_table_and_source(table_id).await?;
+        if table
+            .columns
+            .iter()
+            .any(|c| c.get_column_desc().unwrap().name == new_name)
+        {
+            return Err(CatalogError(anyhow!("column {} already exists", new_name).into()).into());
+        }
+        core.check_no_dependent_views("rename the column", table_id)
+            .await?;
+
+        let rename = |columns: &mut Vec<ColumnCatalog>| -> Result<()> {
+            let column = columns
+                .iter_mut()
+                .find(|c| c.get_column_desc().unwrap().column_id == column_id)
+                .ok_or_else(|| InternalError("column doesn't exist".to_string()))?;
+            column.column_desc.as_mut().unwrap().name = new_name.to_string();
+            Ok(())
+        };
+        rename(&mut table.columns)?;
+        if let Some(source) = source.as_mut() {
+            rename(&mut get_table_source_info(source)?.columns)?;
+        }
+
+        self.update_table_and_source(&mut core, table, source).await
+    }
+
+    /// Renames table or materialized view `table_id`, and the associated source if there is one.
+    pub async fn rename_relation(
+        &self,
+        table_id: TableId,
+        new_name: &str,
+    ) -> Result<CatalogVersion> {
+        let mut core = self.core.lock().await;
+        let (mut table, mut source) = self.get_table_and_source(table_id).await?;
+        if core.has_in_progress_creation(&(table.database_id, table.schema_id, table.name.clone()))
+        {
+            return Err(
+                CatalogError(anyhow!("table {} is being altered", table.name).into()).into(),
+            );
+        }
+        let key = (table.database_id, table.schema_id, new_name.to_string());
+        if core.tables.contains(&key)
+            || core.sources.contains(&key)
+            || core.views.contains(&key)
+            || core.has_in_progress_creation(&key)
+        {
+            return Err(
+                CatalogError(anyhow!("relation {} already exists", new_name).into()).into(),
+            );
+        }
+        core.check_no_dependent_views(&format!("rename relation `{}`", table.name), table_id)
+            .await?;
+
+        table.name = new_name.to_string();
+        if let Some(source) = source.as_mut() {
+            source.name = new_name.to_string();
+        }
+
+        self.update_table_and_source(&mut core, table, source).await
+    }
+
+    /// Returns table `table_id` and its associated source if there is one.
+    async fn get_table_and_source(&self, table_id: TableId) -> Result<(Table, Option<Source>)> {
+        let table = Table::select(self.env.meta_store(), &table_id)
+            .await?
+            .ok_or_else(|| InternalError("table doesn't exist".to_string()))?;
+        let source = match table.optional_associated_source_id {
+            Some(OptionalAssociatedSourceId::AssociatedSourceId(source_id)) => Some(
+                Source::select(self.env.meta_store(), &source_id)
+                    .await?
+                    .ok_or_else(|| InternalError("source doesn't exist".to_string()))?,
+            ),
+            None => None,
+        };
+        Ok((table, source))
+    }
+
+    /// Stores the altered table and its associated source in one transaction, and notifies the
+    /// frontends of the updates.
+    async fn update_table_and_source(
+        &self,
+        core: &mut CatalogManagerCore<S>,
+        table: Table,
+        source: Option<Source>,
+    ) -> Result<CatalogVersion> {
+        let origin_table = Table::select(self.env.meta_store(), &table.id)
+            .await?
+            .ok_or_else(|| InternalError("table doesn't exist".to_string()))?;
+
+        let mut transaction = Transaction::default();
+        table.upsert_in_transaction(&mut transaction)?;
+        if let Some(source) = &source {
+            source.upsert_in_transaction(&mut transaction)?;
+        }
+        core.env.meta_store().txn(transaction).await?;
+
+        if origin_table.name != table.name {
+            core.drop_table(&origin_table);
+            core.add_table(&table);
+            if let Some(source) = &source {
+                core.drop_source(&Source {
+                    name: origin_table.name.clone(),
+                    ..source.clone()
+                });
+                core.add_source(source);
+            }
+        }
+
+        let mut version = self
+            .env
+            .notification_manager()
+            .notify_frontend(Operation::Update, Info::Table(table))
+            .await;
+        if let Some(source) = source {
+            version = self
+                .env
+                .notification_manager()
+                .notify_frontend(Operation::Update, Info::Source(source))
+                .await;
+        }
+        Ok(version)
+    }
+
+    pub async fn create_user(&self, user: &UserInfo) -> Result<CatalogVersion> {
+        let mut core = self.core.lock().await;
+        if !core.has_user(&user.name) {
+            user.insert(self.env.meta_store()).await?;
+            core.add_user(&user.name);
+
+            let version = self
+                .env
+                .notification_manager()
+                .notify_frontend(Operation::Add, Info::User(user.to_owned()))
+                .await;
+
+            Ok(version)
+        } else {
+            Err(RwError::from(InternalError(format!(
+                "user {} already exists",
+                user.name
+            ))))
+        }
+    }
+
+    pub async fn drop_user(&self, user_id: UserId) -> Result<CatalogVersion> {
+        let mut core = self.core.lock().await;
+        let user = UserInfo::select(self.env.meta_store(), &user_id).await?;
+        if let Some(user) = user {
+            if is_default_super_user(&user.name) {
+                return Err(RwError::from(InternalError(format!(
+                    "cannot drop the default super user {}",
+                    user.name
+                ))));
+            }
+            UserInfo::delete(self.env.meta_store(), &user_id).await?;
+            core.drop_user(&user.name);
+
+            let version = self
+                .env
+                .notification_manager()
+                .notify_frontend(Operation::Delete, Info::User(user))
+                .await;
+
+            Ok(version)
+        } else {
+            Err(RwError::from(InternalError(
+                "user doesn't exist".to_string(),
+            )))
+        }
+    }
+
+    /// Updates the name and attributes of a user. The privileges of the user are kept unchanged.
+    pub async fn update_user(&self, user: &UserInfo) -> Result<CatalogVersion> {
+        let mut core = self.core.lock().await;
+        let origin = UserInfo::select(self.env.meta_store(), &user.id).await?;
+        if let Some(origin) = origin {
+            if origin.name != user.name {
+                if is_default_super_user(&origin.name) {
+                    return Err(RwError::from(InternalError(format!(
+                        "cannot rename the default super user {}",
+                        origin.name
+                    ))));
+                }
+                if core.has_user(&user.name) {
+                    return Err(RwError::from(InternalError(format!(
+                        "user {} already exists",
+                        user.name
+                    ))));
+                }
+            }
+            let new_user = UserInfo {
+                grant_privileges: origin.grant_privileges,
+                ..user.clone()
+            };
+            new_user.insert(self.env.meta_store()).await?;
+            core.drop_user(&origin.name);
+            core.add_user(&new_user.name);
+
+            let version = self
+                .env
+                .notification_manager()
+                .notify_frontend(Operation::Update, Info::User(new_user))
+                .await;
+
+            Ok(version)
+        } else {
+            Err(RwError::from(InternalError(
+                "user doesn't exist".to_string(),
+            )))
+        }
+    }
+
+    pub async fn grant_privilege(
+        &self,
+        user_ids: &[UserId],
+        privileges: &[GrantPrivilege],
+    ) -> Result<CatalogVersion> {
+        self.update_privileges(user_ids, |user| {
+            for privilege in privileges {
+                grant_privilege(&mut user.grant_privileges, privilege);
+            }
+        })
+        .await
+    }
+
+    pub async fn revoke_privilege(
+        &self,
+        user_ids: &[UserId],
+        privileges: &[GrantPrivilege],
+    ) -> Result<CatalogVersion> {
+        self.update_privileges(user_ids, |user| {
+            for privilege in privileges {
+                revoke_privilege(&mut user.grant_privileges, privilege);
+            }
+        })
+        .await
+    }
+
+    /// Applies `f` to the privileges of all given users in one transaction.
+    async fn update_privileges(
+        &self,
+        user_ids: &[UserId],
+        f: impl Fn(&mut UserInfo),
+    ) -> Result<CatalogVersion> {
+        let _core = self.core.lock().await;
+        let mut users = Vec::with_capacity(user_ids.len());
+        for user_id in user_ids {
+            let mut user = UserInfo::select(self.env.meta_store(), user_id)
+                .await?
+                .ok_or_else(|| RwError::from(InternalError("user doesn't exist".to_string())))?;
+            f(&mut user);
+            users.push(user);
+        }
+
+        let mut transaction = Transaction::default();
+        for user in &users {
+            user.upsert_in_transaction(&mut transaction)?;
+        }
+        self.env.meta_store().txn(transaction).await?;
+
+        let mut version = 0;
+        for user in users {
+            version = self
+                .env
+                .notification_manager()
+                .notify_frontend(Operation::Update, Info::User(user))
+                .await;
+        }
+        Ok(version)
+    }
+}
+
+fn get_associated_source_id(table: &Table) -> Result<SourceId> {
+    match table.optional_associated_source_id {
+        Some(OptionalAssociatedSourceId::AssociatedSourceId(source_id)) => Ok(source_id),
+        None => Err(CatalogError(
+            anyhow!("{} is a materialized view rather than a table", table.name).into(),
+        )
+        .into()),
+    }
+}
+
+fn get_table_source_info(source: &mut Source) -> Result<&mut TableSourceInfo> {
+    match source.info.as_mut() {
+        Some(SourceInfo::TableSource(info)) => Ok(info),
+        _ => Err(InternalError(format!("source {} is not a table source", source.name)).into()),
+    }
+}
+
+/// Merges `new` into the privileges of a user. An action already granted keeps its grant option
+/// if the new one doesn't carry it.
+fn grant_privilege(privileges: &mut Vec<GrantPrivilege>, new: &GrantPrivilege) {
+    match privileges.iter_mut().find(|p| p.target == new.target) {
+        Some(privilege) => {
+            for new_action in &new.action_with_opts {
+                match privilege
+                    .action_with_opts
+                    .iter_mut()
+                    .find(|a| a.action == new_action.action)
+                {
+                    Some(action) => action.with_grant_option |= new_action.with_grant_option,
+                    None => privilege.action_with_opts.push(new_action.clone()),
+                }
+            }
+        }
+        None => privileges.push(new.clone()),
+    }
+}
+
+/// Removes the actions of `revoked` from the privileges of a user.
+fn revoke_privilege(privileges: &mut Vec<GrantPrivilege>, revoked: &GrantPrivilege) {
+    if let Some(privilege) = privileges.iter_mut().find(|p| p.target == revoked.target) {
+        privilege.action_with_opts.retain(|a| {
+            !revoked
+                .action_with_opts
+                .iter()
+                .any(|r| r.action == a.action)
+        });
+    }
+    privileges.retain(|p| !p.action_with_opts.is_empty());
 }
 
 type DatabaseKey = String;
 type SchemaKey = (DatabaseId, String);
 type TableKey = (DatabaseId, SchemaId, String);
 type SourceKey = (DatabaseId, SchemaId, String);
+type ViewKey = (DatabaseId, SchemaId, String);
 type RelationKey = (DatabaseId, SchemaId, String);
+type UserKey = String;
 
 /// [`CatalogManagerCore`] caches meta catalog information and maintains dependent relationship
 /// between tables.
@@ -590,6 +1382,10 @@ pub struct CatalogManagerCore<S: MetaStore> {
     sources: HashSet<SourceKey>,
     /// Cached table key information.
     tables: HashSet<TableKey>,
+    /// Cached view key information.
+    views: HashSet<ViewKey>,
+    /// Cached user key information.
+    users: HashSet<UserKey>,
     /// Relation refer count mapping.
     relation_ref_count: HashMap<RelationId, usize>,
 
@@ -606,6 +1402,8 @@ where
         let schemas = Schema::list(env.meta_store()).await?;
         let sources = Source::list(env.meta_store()).await?;
         let tables = Table::list(env.meta_store()).await?;
+        let views = View::list(env.meta_store()).await?;
+        let users = UserInfo::list(env.meta_store()).await?;
 
         let mut relation_ref_count = HashMap::new();
 
@@ -622,10 +1420,17 @@ where
         );
         let tables = HashSet::from_iter(tables.into_iter().map(|table| {
             for depend_relation_id in &table.dependent_relations {
-                relation_ref_count.entry(*depend_relation_id).or_insert(0);
+                *relation_ref_count.entry(*depend_relation_id).or_insert(0) += 1;
             }
             (table.database_id, table.schema_id, table.name)
         }));
+        let views = HashSet::from_iter(views.into_iter().map(|view| {
+            for depend_relation_id in &view.dependent_relations {
+                *relation_ref_count.entry(*depend_relation_id).or_insert(0) += 1;
+            }
+            (view.database_id, view.schema_id, view.name)
+        }));
+        let users = HashSet::from_iter(users.into_iter().map(|user| user.name));
 
         let in_progress_creation_tracker = HashSet::new();
 
@@ -635,6 +1440,8 @@ where
             schemas,
             sources,
             tables,
+            views,
+            users,
             relation_ref_count,
             in_progress_creation_tracker,
         })
@@ -646,6 +1453,8 @@ where
             Schema::list(self.env.meta_store()).await?,
             Table::list(self.env.meta_store()).await?,
             Source::list(self.env.meta_store()).await?,
+            View::list(self.env.meta_store()).await?,
+            UserInfo::list(self.env.meta_store()).await?,
         ))
     }
 
@@ -710,6 +1519,28 @@ where
             .remove(&(source.database_id, source.schema_id, source.name.clone()))
     }
 
+    fn add_view(&mut self, view: &View) {
+        self.views
+            .insert((view.database_id, view.schema_id, view.name.clone()));
+    }
+
+    fn drop_view(&mut self, view: &View) -> bool {
+        self.views
+            .remove(&(view.database_id, view.schema_id, view.name.clone()))
+    }
+
+    fn has_user(&self, name: &str) -> bool {
+        self.users.contains(name)
+    }
+
+    fn add_user(&mut self, name: &str) {
+        self.users.insert(name.to_string());
+    }
+
+    fn drop_user(&mut self, name: &str) -> bool {
+        self.users.remove(name)
+    }
+
     pub async fn get_source(&self, id: SourceId) -> Result<Option<Source>> {
         Source::select(self.env.meta_store(), &id).await
     }
@@ -718,6 +1549,145 @@ where
         self.relation_ref_count.get(&relation_id).cloned()
     }
 
+    /// Returns an error listing the relations depending on `relation_id`, if there are any.
+    async fn check_no_dependents(
+        &self,
+        relation_type: &str,
+        relation_name: &str,
+        relation_id: RelationId,
+    ) -> Result<()> {
+        let ref_count = match self.get_ref_count(relation_id) {
+            Some(ref_count) => ref_count,
+            None => return Ok(()),
+        };
+        let dependent_tables = Table::list(self.env.meta_store())
+            .await?
+            .into_iter()
+            .filter(|table| table.dependent_relations.contains(&relation_id))
+            .map(|table| table.name);
+        let dependent_views = View::list(self.env.meta_store())
+            .await?
+            .into_iter()
+            .filter(|view| view.dependent_relations.contains(&relation_id))
+            .map(|view| view.name);
+        let dependents = dependent_tables
+            .chain(dependent_views)
+            .map(|name| format!("`{}`", name))
+            .collect::<Vec<_>>();
+        let hint = if dependents.is_empty() {
+            String::new()
+        } else {
+            format!(
+                ": {}. Use CASCADE to drop the dependent relations as well",
+                dependents.join(", ")
+            )
+        };
+        Err(CatalogError(
+            anyhow!(
+                "Fail to delete {} `{}` because {} other relation(s) depend on it{}.",
+                relation_type,
+                relation_name,
+                ref_count,
+                hint
+            )
+            .into(),
+        )
+        .into())
+    }
+
+    /// Returns an error listing the views depending on `relation_id` directly or indirectly, if
+    /// there are any. The views are stored as SQL referring to the relation and its columns by
+    /// name, so `action` would break them.
+    async fn check_no_dependent_views(&self, action: &str, relation_id: RelationId) -> Result<()> {
+        let views = self.list_dependent_views(&[relation_id]).await?;
+        if views.is_empty() {
+            return Ok(());
+        }
+        let dependents = views
+            .iter()
+            .map(|view| format!("`{}`", view.name))
+            .collect::<Vec<_>>();
+        Err(CatalogError(
+            anyhow!(
+                "Fail to {} because other view(s) depend on it: {}.",
+                action,
+                dependents.join(", ")
+            )
+            .into(),
+        )
+        .into())
+    }
+
+    /// Lists the stored tables depending on `relation_ids` directly or indirectly, where every
+    /// table comes before the relations it depends on.
+    async fn list_dependent_tables(&self, relation_ids: &[RelationId]) -> Result<Vec<Table>> {
+        fn visit(
+            relation_id: RelationId,
+            dependents: &HashMap<RelationId, Vec<Table>>,
+            visited: &mut HashSet<RelationId>,
+            tables: &mut Vec<Table>,
+        ) {
+            for table in dependents.get(&relation_id).into_iter().flatten() {
+                if visited.insert(table.id) {
+                    visit(table.id, dependents, visited, tables);
+                    tables.push(table.clone());
+                }
+            }
+        }
+
+        let mut dependents: HashMap<RelationId, Vec<Table>> = HashMap::new();
+        for table in Table::list(self.env.meta_store()).await? {
+            for &relation_id in &table.dependent_relations {
+                dependents
+                    .entry(relation_id)
+                    .or_default()
+                    .push(table.clone());
+            }
+        }
+
+        let mut visited = HashSet::new();
+        let mut tables = vec![];
+        for &relation_id in relation_ids {
+            visit(relation_id, &dependents, &mut visited, &mut tables);
+        }
+        Ok(tables)
+    }
+
+    /// Lists the stored views depending on `relation_ids` directly or indirectly, where every view
+    /// comes before the views it depends on.
+    async fn list_dependent_views(&self, relation_ids: &[RelationId]) -> Result<Vec<View>> {
+        fn visit(
+            relation_id: RelationId,
+            dependents: &HashMap<RelationId, Vec<View>>,
+            visited: &mut HashSet<RelationId>,
+            views: &mut Vec<View>,
+        ) {
+            for view in dependents.get(&relation_id).into_iter().flatten() {
+                if visited.insert(view.id) {
+                    visit(view.id, dependents, visited, views);
+                    views.push(view.clone());
+                }
+            }
+        }
+
+        let mut dependents: HashMap<RelationId, Vec<View>> = HashMap::new();
+        for view in View::list(self.env.meta_store()).await? {
+            for &relation_id in &view.dependent_relations {
+                dependents
+                    .entry(relation_id)
+                    .or_default()
+                    .push(view.clone());
+            }
+        }
+
+        let mut visited = HashSet::new();
+        let mut views = vec![];
+        for &relation_id in relation_ids {
+            visit(relation_id, &dependents, &mut visited, &mut views);
+        }
+        Ok(views)
+    }
+
     fn increase_ref_count(&mut self, relation_id: RelationId) {
         *self.relation_ref_count.entry(relation_id).or_insert(0) += 1;
     }
@@ -747,3 +1717,303 @@ where
         self.in_progress_creation_tracker.remove(&relation.clone());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use risingwave_pb::plan_common::ColumnDesc;
+
+    use super::*;
+
+    fn make_table(id: TableId, name: &str, dependent_relations: Vec<RelationId>) -> Table {
+        Table {
+            id,
+            name: name.to_string(),
+            dependent_relations,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_drop_table_cascade() -> Result<()> {
+        let env = MetaSrvEnv::for_test().await;
+        let catalog_manager = CatalogManager::new(env.clone()).await?;
+
+        catalog_manager
+            .create_table(&make_table(1, "t", vec![]))
+            .await?;
+        catalog_manager
+            .create_table(&make_table(2, "mv2", vec![1]))
+            .await?;
+        catalog_manager
+            .create_table(&make_table(3, "mv3", vec![1, 2]))
+            .await?;
+        catalog_manager
+            .create_table(&make_table(4, "mv4", vec![3]))
+            .await?;
+
+        let err = catalog_manager.drop_table(1).await.unwrap_err();
+        assert!(err.to_string().contains("`mv2`"), "{}", err);
+        assert!(err.to_string().contains("`mv3`"), "{}", err);
+        assert!(catalog_manager.drop_table(3).await.is_err());
+
+        // Every table is dropped before the relations it depends on.
+        let (_, dropped, _) = catalog_manager.drop_table_cascade(2).await?;
+        assert_eq!(dropped, vec![4, 3, 2]);
+        catalog_manager.drop_table(1).await?;
+        assert!(Table::list(env.meta_store()).await?.is_empty());
+
+        Ok(())
+    }
+
+    /// Creates the table `t` (1) and the materialized view `mv` (4) on it, with the error table
+    /// `mv_errors` (3) materialized from the source (2).
+    async fn create_mview_with_error_table<S: MetaStore>(
+        catalog_manager: &CatalogManager<S>,
+    ) -> Result<()> {
+        catalog_manager
+            .create_table(&make_table(1, "t", vec![]))
+            .await?;
+        catalog_manager
+            .create_source(&Source {
+                id: 2,
+                name: "mv_errors".to_string(),
+                ..Default::default()
+            })
+            .await?;
+        catalog_manager
+            .create_table(&Table {
+                optional_associated_source_id: Some(
+                    OptionalAssociatedSourceId::AssociatedSourceId(2),
+                ),
+                ..make_table(3, "mv_errors", vec![])
+            })
+            .await?;
+        catalog_manager
+            .create_table(&Table {
+                error_table_id: 3,
+                ..make_table(4, "mv", vec![1, 3])
+            })
+            .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_drop_error_table() -> Result<()> {
+        let env = MetaSrvEnv::for_test().await;
+        let catalog_manager = CatalogManager::new(env.clone()).await?;
+
+        create_mview_with_error_table(&catalog_manager).await?;
+        let err = catalog_manager.drop_table(3).await.unwrap_err();
+        assert!(err.to_string().contains("`mv`"), "{}", err);
+
+        let (_, dropped, dropped_sources) = catalog_manager.drop_table(4).await?;
+        assert_eq!(dropped, vec![4, 3]);
+        assert_eq!(dropped_sources, vec![2]);
+        catalog_manager.drop_table(1).await?;
+        assert!(Table::list(env.meta_store()).await?.is_empty());
+        assert!(Source::list(env.meta_store()).await?.is_empty());
+
+        create_mview_with_error_table(&catalog_manager).await?;
+        let (_, dropped, dropped_sources) = catalog_manager.drop_table_cascade(1).await?;
+        assert_eq!(dropped, vec![4, 1, 3]);
+        assert_eq!(dropped_sources, vec![2]);
+        assert!(Table::list(env.meta_store()).await?.is_empty());
+        assert!(Source::list(env.meta_store()).await?.is_empty());
+
+        Ok(())
+    }
+
+    fn make_view(id: RelationId, name: &str, dependent_relations: Vec<RelationId>) -> View {
+        View {
+            id,
+            name: name.to_string(),
+            dependent_relations,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_view() -> Result<()> {
+        let env = MetaSrvEnv::for_test().await;
+        let catalog_manager = CatalogManager::new(env.clone()).await?;
+
+        catalog_manager
+            .create_table(&make_table(1, "t", vec![]))
+            .await?;
+        catalog_manager
+            .create_view(&make_view(2, "v2", vec![1]), false)
+            .await?;
+        catalog_manager
+            .create_view(&make_view(3, "v3", vec![2]), false)
+            .await?;
+        assert!(catalog_manager
+            .create_view(&make_view(4, "v2", vec![]), false)
+            .await
+            .is_err());
+        assert!(catalog_manager
+            .create_view(&make_view(4, "t", vec![]), true)
+            .await
+            .is_err());
+
+        let err = catalog_manager.drop_table(1).await.unwrap_err();
+        assert!(err.to_string().contains("`v2`"), "{}", err);
+        assert!(catalog_manager.drop_view(2).await.is_err());
+
+        // The replaced view keeps its id, and no longer depends on the table.
+        let (view_id, _) = catalog_manager
+            .create_view(&make_view(4, "v2", vec![]), true)
+            .await?;
+        assert_eq!(view_id, 2);
+        assert!(catalog_manager
+            .create_view(&make_view(4, "v2", vec![3]), true)
+            .await
+            .is_err());
+        catalog_manager.drop_table(1).await?;
+
+        catalog_manager.drop_view_cascade(2).await?;
+        assert!(View::list(env.meta_store()).await?.is_empty());
+
+        Ok(())
+    }
+
+    fn make_column(column_id: ColumnId, name: &str) -> ColumnCatalog {
+        ColumnCatalog {
+            column_desc: Some(ColumnDesc {
+                column_id,
+                name: name.to_string(),
+                ..Default::default()
+            }),
+            is_hidden: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_alter_table() -> Result<()> {
+        let env = MetaSrvEnv::for_test().await;
+        let catalog_manager = CatalogManager::new(env.clone()).await?;
+
+        let columns = vec![
+            make_column(0, "_row_id#0"),
+            make_column(1, "v1"),
+            make_column(2, "v2"),
+        ];
+        let source = Source {
+            id: 1,
+            name: "t".to_string(),
+            info: Some(SourceInfo::TableSource(TableSourceInfo {
+                columns: columns.clone(),
+                pk_column_ids: vec![0],
+            })),
+            ..Default::default()
+        };
+        let table = Table {
+            id: 2,
+            name: "t".to_string(),
+            columns,
+            order_column_ids: vec![0],
+            optional_associated_source_id: Some(OptionalAssociatedSourceId::AssociatedSourceId(1)),
+            ..Default::default()
+        };
+        catalog_manager.create_source(&source).await?;
+        catalog_manager.create_table(&table).await?;
+
+        // The id of a dropped column is not reused.
+        catalog_manager.drop_column(2, 2, &[]).await?;
+        let (source_id, column) = catalog_manager
+            .start_add_column_procedure(2, make_column(0, "v3"))
+            .await?;
+        assert_eq!(source_id, 1);
+        assert_eq!(column.get_column_desc().unwrap().column_id, 3);
+        catalog_manager
+            .finish_add_column_procedure(2, column)
+            .await?;
+
+        assert!(catalog_manager.drop_column(2, 0, &[]).await.is_err());
+        assert!(catalog_manager.drop_column(2, 1, &[3]).await.is_err());
+        assert!(catalog_manager.drop_column(2, 2, &[]).await.is_err());
+        assert!(catalog_manager
+            .start_add_column_procedure(2, make_column(0, "v1"))
+            .await
+            .is_err());
+
+        catalog_manager.rename_column(2, 1, "w1").await?;
+        catalog_manager.rename_relation(2, "t2").await?;
+
+        let table = Table::select(env.meta_store(), &2).await?.unwrap();
+        let mut source = Source::select(env.meta_store(), &1).await?.unwrap();
+        assert_eq!(table.name, "t2");
+        assert_eq!(source.name, "t2");
+        let names = |columns: &[ColumnCatalog]| {
+            columns
+                .iter()
+                .map(|c| c.get_column_desc().unwrap().name.clone())
+                .collect::<Vec<_>>()
+        };
+        let expected = vec!["_row_id#0", "w1", "_dropped#2", "v3"];
+        assert_eq!(names(&table.columns), expected);
+        assert_eq!(
+            names(&get_table_source_info(&mut source)?.columns),
+            expected
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_alter_table_with_dependent_views() -> Result<()> {
+        let env = MetaSrvEnv::for_test().await;
+        let catalog_manager = CatalogManager::new(env.clone()).await?;
+
+        let columns = vec![make_column(0, "_row_id#0"), make_column(1, "v1")];
+        catalog_manager
+            .create_source(&Source {
+                id: 1,
+                name: "t".to_string(),
+                info: Some(SourceInfo::TableSource(TableSourceInfo {
+                    columns: columns.clone(),
+                    pk_column_ids: vec![0],
+                })),
+                ..Default::default()
+            })
+            .await?;
+        catalog_manager
+            .create_table(&Table {
+                id: 2,
+                name: "t".to_string(),
+                columns,
+                order_column_ids: vec![0],
+                optional_associated_source_id: Some(
+                    OptionalAssociatedSourceId::AssociatedSourceId(1),
+                ),
+                ..Default::default()
+            })
+            .await?;
+        catalog_manager
+            .create_view(&make_view(3, "v3", vec![2]), false)
+            .await?;
+        catalog_manager
+            .create_view(&make_view(4, "v4", vec![3]), false)
+            .await?;
+
+        let err = catalog_manager.drop_column(2, 1, &[]).await.unwrap_err();
+        assert!(err.to_string().contains("`v3`"), "{}", err);
+        assert!(err.to_string().contains("`v4`"), "{}", err);
+        let err = catalog_manager.rename_column(2, 1, "w1").await.unwrap_err();
+        assert!(err.to_string().contains("`v3`"), "{}", err);
+        let err = catalog_manager.rename_relation(2, "t2").await.unwrap_err();
+        assert!(err.to_string().contains("`v3`"), "{}", err);
+
+        // Nothing is altered.
+        let table = Table::select(env.meta_store(), &2).await?.unwrap();
+        assert_eq!(table.name, "t");
+        assert_eq!(table.columns[1].get_column_desc().unwrap().name, "v1");
+        assert!(!table.columns[1].is_hidden);
+
+        catalog_manager.drop_view_cascade(3).await?;
+        catalog_manager.rename_column(2, 1, "w1").await?;
+        catalog_manager.rename_relation(2, "t2").await?;
+        catalog_manager.drop_column(2, 1, &[]).await?;
+
+        Ok(())
+    }
+}
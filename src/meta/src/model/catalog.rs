@@ -13,7 +13,8 @@
 // limitations under the License.
 
 use risingwave_common::error::Result;
-use risingwave_pb::catalog::{Database, Schema, Source, Table};
+use risingwave_pb::catalog::{Database, Schema, Source, Table, View};
+use risingwave_pb::user::UserInfo;
 
 use crate::model::MetadataModel;
 
@@ -21,10 +22,14 @@ use crate::model::MetadataModel;
 const CATALOG_SOURCE_CF_NAME: &str = "cf/catalog_source";
 /// Column family name for table catalog.
 const CATALOG_TABLE_CF_NAME: &str = "cf/catalog_table";
+/// Column family name for view catalog.
+const CATALOG_VIEW_CF_NAME: &str = "cf/catalog_view";
 /// Column family name for schema catalog.
 const CATALOG_SCHEMA_CF_NAME: &str = "cf/catalog_schema";
 /// Column family name for database catalog.
 const CATALOG_DATABASE_CF_NAME: &str = "cf/catalog_database";
+/// Column family name for user info.
+const USER_INFO_CF_NAME: &str = "cf/user_info";
 
 macro_rules! impl_model_for_catalog {
     ($name:ident, $cf:ident, $key_ty:ty, $key_fn:ident) => {
@@ -53,8 +58,10 @@ macro_rules! impl_model_for_catalog {
 
 impl_model_for_catalog!(Source, CATALOG_SOURCE_CF_NAME, u32, get_id);
 impl_model_for_catalog!(Table, CATALOG_TABLE_CF_NAME, u32, get_id);
+impl_model_for_catalog!(View, CATALOG_VIEW_CF_NAME, u32, get_id);
 impl_model_for_catalog!(Schema, CATALOG_SCHEMA_CF_NAME, u32, get_id);
 impl_model_for_catalog!(Database, CATALOG_DATABASE_CF_NAME, u32, get_id);
+impl_model_for_catalog!(UserInfo, USER_INFO_CF_NAME, u32, get_id);
 
 #[cfg(test)]
 mod tests {
@@ -17,8 +17,10 @@ use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use itertools::Itertools;
 use risingwave_common::catalog::TableId;
 use risingwave_common::error::Result;
+use risingwave_pb::data::AddedColumn;
 use risingwave_pb::meta::table_fragments::{ActorState, ActorStatus, Fragment};
 use risingwave_pb::meta::TableFragments as ProstTableFragments;
+use risingwave_pb::plan_common::Field;
 use risingwave_pb::stream_plan::source_node::SourceType;
 use risingwave_pb::stream_plan::stream_node::NodeBody;
 use risingwave_pb::stream_plan::{FragmentType, StreamActor, StreamNode};
@@ -196,6 +198,73 @@ impl TableFragments {
         }
     }
 
+    /// Appends the columns added by `ALTER TABLE ADD COLUMN` to the nodes of this table, whose
+    /// associated source is `source_id`. All nodes of a table pass the columns through, so each
+    /// of their schemas gets the new columns.
+    pub fn add_table_columns(&mut self, source_id: TableId, columns: &[AddedColumn]) {
+        fn visit(
+            stream_node: &mut StreamNode,
+            table_id: TableId,
+            source_id: TableId,
+            columns: &[AddedColumn],
+        ) {
+            let fields = columns.iter().map(|c| Field {
+                data_type: c.column_type.clone(),
+                name: c.name.clone(),
+            });
+            let column_ids = columns.iter().map(|c| c.column_id);
+            match stream_node.node_body.as_mut() {
+                Some(NodeBody::Source(source))
+                    if TableId::from(&source.table_ref_id) == source_id =>
+                {
+                    source.column_ids.extend(column_ids);
+                }
+                Some(NodeBody::Materialize(materialize))
+                    if TableId::from(&materialize.table_ref_id) == table_id =>
+                {
+                    materialize.column_ids.extend(column_ids);
+                }
+                Some(NodeBody::Merge(merge)) => merge.fields.extend(fields.clone()),
+                _ => {}
+            }
+            stream_node.fields.extend(fields);
+
+            for child in &mut stream_node.input {
+                visit(child, table_id, source_id, columns);
+            }
+        }
+
+        let table_id = self.table_id;
+        for fragment in self.fragments.values_mut() {
+            for actor in &mut fragment.actors {
+                visit(actor.nodes.as_mut().unwrap(), table_id, source_id, columns);
+            }
+        }
+    }
+
+    /// Returns the ids of the columns read from the upstream `table_id` by the chain nodes.
+    pub fn chain_column_ids(&self, table_id: TableId) -> HashSet<i32> {
+        fn visit(stream_node: &StreamNode, table_id: TableId, column_ids: &mut HashSet<i32>) {
+            if let Some(NodeBody::Chain(chain)) = stream_node.node_body.as_ref() {
+                if TableId::from(&chain.table_ref_id) == table_id {
+                    column_ids.extend(chain.column_ids.iter().copied());
+                }
+            }
+
+            for child in &stream_node.input {
+                visit(child, table_id, column_ids);
+            }
+        }
+
+        let mut column_ids = HashSet::new();
+        self.fragments.values().for_each(|fragment| {
+            let actor = &fragment.actors[0];
+            visit(actor.nodes.as_ref().unwrap(), table_id, &mut column_ids);
+        });
+
+        column_ids
+    }
+
     /// Returns dependent table ids.
     pub fn dependent_table_ids(&self) -> HashSet<TableId> {
         let mut table_ids = HashSet::new();
@@ -23,3 +23,4 @@ pub use service::heartbeat_service::HeartbeatServiceImpl;
 pub use service::hummock_service::HummockServiceImpl;
 pub use service::notification_service::NotificationServiceImpl;
 pub use service::stream_service::StreamServiceImpl;
+pub use service::user_service::UserServiceImpl;
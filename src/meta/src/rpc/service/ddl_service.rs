@@ -19,9 +19,10 @@ use risingwave_common::error::{tonic_err, Result as RwResult};
 use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
 use risingwave_pb::catalog::*;
 use risingwave_pb::common::ParallelUnitType;
+use risingwave_pb::data::AddedColumn;
 use risingwave_pb::ddl_service::ddl_service_server::DdlService;
 use risingwave_pb::ddl_service::*;
-use risingwave_pb::plan_common::TableRefId;
+use risingwave_pb::plan_common::{ColumnCatalog, TableRefId};
 use risingwave_pb::stream_plan::stream_node::NodeBody;
 use risingwave_pb::stream_plan::{StreamFragmentGraph, StreamNode};
 use tonic::{Request, Response, Status};
@@ -203,22 +204,93 @@ where
         &self,
         request: Request<DropSourceRequest>,
     ) -> Result<Response<DropSourceResponse>, Status> {
-        let source_id = request.into_inner().source_id;
+        use risingwave_common::catalog::TableId;
 
-        // 1. Drop source in catalog. Ref count will be checked.
-        let version = self
-            .catalog_manager
-            .drop_source(source_id)
+        let request = request.into_inner();
+        let source_id = request.source_id;
+
+        // 1. Drop source in catalog. Ref count will be checked unless dropping in cascade mode,
+        // which drops the dependent mviews as well.
+        let (version, dropped_mview_ids, dropped_source_ids) = if request.cascade {
+            self.catalog_manager
+                .drop_source_cascade(source_id)
+                .await
+                .map_err(tonic_err)?
+        } else {
+            let version = self
+                .catalog_manager
+                .drop_source(source_id)
+                .await
+                .map_err(tonic_err)?;
+            (version, vec![], vec![])
+        };
+
+        // 2. Drop the dependent mviews in stream manager, downstream first.
+        for mview_id in dropped_mview_ids {
+            self.stream_manager
+                .drop_materialized_view(&TableId::new(mview_id))
+                .await
+                .map_err(tonic_err)?;
+        }
+
+        // 3. Drop source and the sources of the dropped error tables on compute nodes.
+        for source_id in std::iter::once(source_id).chain(dropped_source_ids) {
+            self.source_manager
+                .drop_source(source_id)
+                .await
+                .map_err(tonic_err)?;
+        }
+
+        Ok(Response::new(DropSourceResponse {
+            status: None,
+            version,
+        }))
+    }
+
+    async fn create_view(
+        &self,
+        request: Request<CreateViewRequest>,
+    ) -> Result<Response<CreateViewResponse>, Status> {
+        let request = request.into_inner();
+        let mut view = request.get_view().map_err(tonic_err)?.clone();
+
+        view.id = self
+            .env
+            .id_gen_manager()
+            .generate::<{ IdCategory::Table }>()
             .await
-            .map_err(tonic_err)?;
+            .map_err(tonic_err)? as u32;
 
-        // 2. Drop source on compute nodes.
-        self.source_manager
-            .drop_source(source_id)
+        let (view_id, version) = self
+            .catalog_manager
+            .create_view(&view, request.or_replace)
             .await
             .map_err(tonic_err)?;
 
-        Ok(Response::new(DropSourceResponse {
+        Ok(Response::new(CreateViewResponse {
+            status: None,
+            view_id,
+            version,
+        }))
+    }
+
+    async fn drop_view(
+        &self,
+        request: Request<DropViewRequest>,
+    ) -> Result<Response<DropViewResponse>, Status> {
+        let request = request.into_inner();
+
+        // A view only exists in catalog, so there is nothing to drop in stream manager.
+        let version = if request.cascade {
+            self.catalog_manager
+                .drop_view_cascade(request.view_id)
+                .await
+        } else {
+            self.catalog_manager.drop_view(request.view_id).await
+        }
+        .map_err(tonic_err)?;
+
+        Ok(Response::new(DropViewResponse {
             status: None,
             version,
         }))
@@ -275,6 +347,11 @@ where
                 !dependent_relations.is_empty(),
                 "there should be at lease 1 dependent relation when creating materialized view"
             );
+            // The error table is dropped together with the materialized view, and can't be dropped
+            // by itself before it.
+            if mview.error_table_id != 0 {
+                dependent_relations.insert(mview.error_table_id);
+            }
             mview.dependent_relations = dependent_relations.into_iter().collect();
         }
 
@@ -316,19 +393,37 @@ where
     ) -> Result<Response<DropMaterializedViewResponse>, Status> {
         use risingwave_common::catalog::TableId;
 
-        let table_id = request.into_inner().table_id;
-        // 1. Drop table in catalog. Ref count will be checked.
-        let version = self
-            .catalog_manager
-            .drop_table(table_id)
-            .await
-            .map_err(tonic_err)?;
+        let request = request.into_inner();
+        let table_id = request.table_id;
+        // 1. Drop table in catalog together with its error table. Ref count will be checked
+        // unless dropping in cascade mode, which drops the dependent mviews as well.
+        let (version, dropped_mview_ids, dropped_source_ids) = if request.cascade {
+            self.catalog_manager
+                .drop_table_cascade(table_id)
+                .await
+                .map_err(tonic_err)?
+        } else {
+            self.catalog_manager
+                .drop_table(table_id)
+                .await
+                .map_err(tonic_err)?
+        };
 
-        // 2. drop mv in stream manager
-        self.stream_manager
-            .drop_materialized_view(&TableId::new(table_id))
-            .await
-            .map_err(tonic_err)?;
+        // 2. drop mvs in stream manager, downstream first
+        for mview_id in dropped_mview_ids {
+            self.stream_manager
+                .drop_materialized_view(&TableId::new(mview_id))
+                .await
+                .map_err(tonic_err)?;
+        }
+
+        // 3. drop the sources of the dropped error tables on compute nodes
+        for source_id in dropped_source_ids {
+            self.source_manager
+                .drop_source(source_id)
+                .await
+                .map_err(tonic_err)?;
+        }
 
         Ok(Response::new(DropMaterializedViewResponse {
             status: None,
@@ -367,7 +462,7 @@ where
         let table_id = request.table_id;
 
         let version = self
-            .drop_materialized_source_inner(source_id, table_id)
+            .drop_materialized_source_inner(source_id, table_id, request.cascade)
             .await
             .map_err(tonic_err)?;
 
@@ -376,6 +471,43 @@ where
             version,
         }))
     }
+
+    async fn alter_relation(
+        &self,
+        request: Request<AlterRelationRequest>,
+    ) -> Result<Response<AlterRelationResponse>, Status> {
+        use alter_relation_request::Operation;
+
+        let request = request.into_inner();
+        let table_id = request.table_id;
+
+        let version = match request.get_operation().map_err(tonic_err)? {
+            Operation::AddColumn(add_column) => {
+                let column = add_column.get_column().map_err(tonic_err)?.clone();
+                self.add_column_inner(table_id, column).await
+            }
+            Operation::DropColumn(drop_column) => {
+                self.drop_column_inner(table_id, drop_column.column_id)
+                    .await
+            }
+            Operation::RenameColumn(rename_column) => {
+                self.catalog_manager
+                    .rename_column(table_id, rename_column.column_id, &rename_column.new_name)
+                    .await
+            }
+            Operation::RenameRelation(rename_relation) => {
+                self.catalog_manager
+                    .rename_relation(table_id, &rename_relation.new_name)
+                    .await
+            }
+        }
+        .map_err(tonic_err)?;
+
+        Ok(Response::new(AlterRelationResponse {
+            status: None,
+            version,
+        }))
+    }
 }
 
 impl<S> DdlServiceImpl<S>
@@ -535,22 +667,98 @@ where
         &self,
         source_id: SourceId,
         table_id: TableId,
+        cascade: bool,
     ) -> RwResult<CatalogVersion> {
         use risingwave_common::catalog::TableId;
 
         // 1. Drop materialized source in catalog, source_id will be checked if it is
-        // associated_source_id in mview.
-        let version = self
-            .catalog_manager
-            .drop_materialized_source(source_id, table_id)
-            .await?;
+        // associated_source_id in mview. In cascade mode, the dependent mviews are dropped as well.
+        let (version, dropped_mview_ids, dropped_source_ids) = if cascade {
+            self.catalog_manager
+                .drop_materialized_source_cascade(source_id, table_id)
+                .await?
+        } else {
+            let version = self
+                .catalog_manager
+                .drop_materialized_source(source_id, table_id)
+                .await?;
+            (version, vec![table_id], vec![])
+        };
 
-        // 2. Drop source and mv separately.
+        // 2. Drop source and mvs separately, downstream mvs first. The sources of the dropped
+        // error tables are dropped after their tables.
         self.source_manager.drop_source(source_id).await?;
-        self.stream_manager
-            .drop_materialized_view(&TableId::new(table_id))
-            .await?;
+        for mview_id in dropped_mview_ids {
+            self.stream_manager
+                .drop_materialized_view(&TableId::new(mview_id))
+                .await?;
+        }
+        for source_id in dropped_source_ids {
+            self.source_manager.drop_source(source_id).await?;
+        }
 
         Ok(version)
     }
+
+    async fn add_column_inner(
+        &self,
+        table_id: TableId,
+        column: ColumnCatalog,
+    ) -> RwResult<CatalogVersion> {
+        use risingwave_common::catalog::TableId;
+
+        // 1. Check the column and assign its id in catalog.
+        let (source_id, column) = self
+            .catalog_manager
+            .start_add_column_procedure(table_id, column)
+            .await?;
+
+        // 2. Let the table read and write the column since a barrier.
+        let column_desc = column.get_column_desc()?;
+        let added_column = AddedColumn {
+            column_id: column_desc.column_id,
+            column_type: column_desc.column_type.clone(),
+            name: column_desc.name.clone(),
+            default_value: column_desc.default_value.clone(),
+        };
+        if let Err(e) = self
+            .stream_manager
+            .add_table_columns(
+                &TableId::new(table_id),
+                &TableId::new(source_id),
+                vec![added_column],
+            )
+            .await
+        {
+            self.catalog_manager
+                .cancel_add_column_procedure(table_id)
+                .await?;
+            return Err(e);
+        }
+
+        // 3. Finally, update the catalog.
+        self.catalog_manager
+            .finish_add_column_procedure(table_id, column)
+            .await
+    }
+
+    async fn drop_column_inner(
+        &self,
+        table_id: TableId,
+        column_id: i32,
+    ) -> RwResult<CatalogVersion> {
+        use risingwave_common::catalog::TableId;
+
+        // The column is only hidden in catalog, as it may still be written by the table.
+        let reading_table_ids = self
+            .fragment_manager
+            .get_tables_reading_column(&TableId::new(table_id), column_id)
+            .await
+            .into_iter()
+            .map(|table_id| table_id.table_id())
+            .collect::<Vec<_>>();
+        self.catalog_manager
+            .drop_column(table_id, column_id, &reading_table_ids)
+            .await
+    }
 }
@@ -76,7 +76,7 @@ where
             }
             WorkerType::Frontend => {
                 let catalog_guard = self.catalog_manager.get_catalog_core_guard().await;
-                let (database, schema, table, source) = catalog_guard
+                let (database, schema, table, source, view, users) = catalog_guard
                     .get_catalog()
                     .await
                     .map_err(|e| e.to_grpc_status())?;
@@ -91,7 +91,8 @@ where
                     schema,
                     source,
                     table,
-                    view: Default::default(),
+                    view,
+                    users,
                 };
                 tx.send(Ok(SubscribeResponse {
                     status: None,
@@ -0,0 +1,143 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::error::tonic_err;
+use risingwave_pb::user::user_service_server::UserService;
+use risingwave_pb::user::*;
+use tonic::{Request, Response, Status};
+
+use crate::manager::{CatalogManagerRef, IdCategory, MetaSrvEnv};
+use crate::storage::MetaStore;
+
+/// Users and their privileges are persisted by the catalog manager and broadcast to frontends
+/// together with the other catalogs.
+#[derive(Clone)]
+pub struct UserServiceImpl<S: MetaStore> {
+    env: MetaSrvEnv<S>,
+
+    catalog_manager: CatalogManagerRef<S>,
+}
+
+impl<S> UserServiceImpl<S>
+where
+    S: MetaStore,
+{
+    pub fn new(env: MetaSrvEnv<S>, catalog_manager: CatalogManagerRef<S>) -> Self {
+        Self {
+            env,
+            catalog_manager,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<S> UserService for UserServiceImpl<S>
+where
+    S: MetaStore,
+{
+    async fn create_user(
+        &self,
+        request: Request<CreateUserRequest>,
+    ) -> Result<Response<CreateUserResponse>, Status> {
+        let req = request.into_inner();
+        let id = self
+            .env
+            .id_gen_manager()
+            .generate::<{ IdCategory::User }>()
+            .await
+            .map_err(tonic_err)? as u32;
+        let mut user = req.get_user().map_err(tonic_err)?.clone();
+        user.id = id;
+        let version = self
+            .catalog_manager
+            .create_user(&user)
+            .await
+            .map_err(tonic_err)?;
+
+        Ok(Response::new(CreateUserResponse {
+            status: None,
+            user_id: id,
+            version,
+        }))
+    }
+
+    async fn drop_user(
+        &self,
+        request: Request<DropUserRequest>,
+    ) -> Result<Response<DropUserResponse>, Status> {
+        let req = request.into_inner();
+        let version = self
+            .catalog_manager
+            .drop_user(req.get_user_id())
+            .await
+            .map_err(tonic_err)?;
+
+        Ok(Response::new(DropUserResponse {
+            status: None,
+            version,
+        }))
+    }
+
+    async fn update_user(
+        &self,
+        request: Request<UpdateUserRequest>,
+    ) -> Result<Response<UpdateUserResponse>, Status> {
+        let req = request.into_inner();
+        let user = req.get_user().map_err(tonic_err)?;
+        let version = self
+            .catalog_manager
+            .update_user(user)
+            .await
+            .map_err(tonic_err)?;
+
+        Ok(Response::new(UpdateUserResponse {
+            status: None,
+            version,
+        }))
+    }
+
+    async fn grant_privilege(
+        &self,
+        request: Request<GrantPrivilegeRequest>,
+    ) -> Result<Response<GrantPrivilegeResponse>, Status> {
+        let req = request.into_inner();
+        let version = self
+            .catalog_manager
+            .grant_privilege(&req.user_ids, &req.privileges)
+            .await
+            .map_err(tonic_err)?;
+
+        Ok(Response::new(GrantPrivilegeResponse {
+            status: None,
+            version,
+        }))
+    }
+
+    async fn revoke_privilege(
+        &self,
+        request: Request<RevokePrivilegeRequest>,
+    ) -> Result<Response<RevokePrivilegeResponse>, Status> {
+        let req = request.into_inner();
+        let version = self
+            .catalog_manager
+            .revoke_privilege(&req.user_ids, &req.privileges)
+            .await
+            .map_err(tonic_err)?;
+
+        Ok(Response::new(RevokePrivilegeResponse {
+            status: None,
+            version,
+        }))
+    }
+}
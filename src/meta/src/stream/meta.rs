@@ -20,6 +20,7 @@ use risingwave_common::catalog::TableId;
 use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::{Result, RwError};
 use risingwave_common::try_match_expand;
+use risingwave_pb::data::AddedColumn;
 use risingwave_pb::meta::table_fragments::ActorState;
 use risingwave_pb::stream_plan::{FragmentType, StreamActor};
 use tokio::sync::RwLock;
@@ -242,6 +243,48 @@ where
         }
     }
 
+    /// Adds the columns added by `ALTER TABLE ADD COLUMN` to the fragments of table `table_id`.
+    pub async fn add_table_columns(
+        &self,
+        table_id: &TableId,
+        source_id: &TableId,
+        columns: &[AddedColumn],
+    ) -> Result<()> {
+        let map = &mut self.core.write().await.table_fragments;
+
+        if let Some(table_fragments) = map.get(table_id) {
+            let mut table_fragments = table_fragments.clone();
+            table_fragments.add_table_columns(*source_id, columns);
+            table_fragments.insert(&*self.meta_store).await?;
+            map.insert(*table_id, table_fragments);
+
+            Ok(())
+        } else {
+            Err(RwError::from(InternalError(format!(
+                "table_fragment not exist: id={}",
+                table_id
+            ))))
+        }
+    }
+
+    /// Returns the ids of the tables reading column `column_id` of table `table_id`.
+    pub async fn get_tables_reading_column(
+        &self,
+        table_id: &TableId,
+        column_id: i32,
+    ) -> Vec<TableId> {
+        let map = &self.core.read().await.table_fragments;
+
+        map.values()
+            .filter(|table_fragments| {
+                table_fragments
+                    .chain_column_ids(*table_id)
+                    .contains(&column_id)
+            })
+            .map(|table_fragments| table_fragments.table_id())
+            .collect()
+    }
+
     /// Used in [`crate::barrier::GlobalBarrierManager`]
     pub async fn load_all_actors(&self, with_creating_table: Option<TableId>) -> ActorInfos {
         let mut actor_maps = HashMap::new();
@@ -23,6 +23,7 @@ use risingwave_common::error::{internal_error, Result, ToRwResult};
 use risingwave_common::util::compress::compress_data;
 use risingwave_pb::catalog::Source;
 use risingwave_pb::common::{ActorInfo, WorkerType};
+use risingwave_pb::data::AddedColumn;
 use risingwave_pb::meta::table_fragments::{ActorState, ActorStatus};
 use risingwave_pb::stream_plan::stream_node::NodeBody;
 use risingwave_pb::stream_plan::{
@@ -626,6 +627,25 @@ where
         Ok(())
     }
 
+    /// Adding columns to a table is done by barrier manager. Check [`Command::AddColumns`] for
+    /// details.
+    pub async fn add_table_columns(
+        &self,
+        table_id: &TableId,
+        source_id: &TableId,
+        columns: Vec<AddedColumn>,
+    ) -> Result<()> {
+        self.barrier_manager
+            .run_command(Command::AddColumns {
+                table_id: *table_id,
+                source_id: *source_id,
+                columns,
+            })
+            .await?;
+
+        Ok(())
+    }
+
     /// Flush means waiting for the next barrier to collect.
     pub async fn flush(&self) -> Result<()> {
         let start = Instant::now();
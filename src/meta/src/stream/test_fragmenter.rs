@@ -152,6 +152,7 @@ fn make_stream_node() -> StreamNode {
                 }),
                 rex_node: Some(RexNode::FuncCall(function_call)),
             }),
+            error_handling: None,
         })),
         fields: vec![], // TODO: fill this later
         input: vec![exchange_node],
@@ -227,6 +228,7 @@ fn make_stream_node() -> StreamNode {
                 make_inputref(0),
                 make_inputref(1),
             ],
+            error_handling: None,
         })),
         fields: vec![], // TODO: fill this later
         input: vec![simple_agg_node_1],
@@ -246,6 +248,8 @@ fn make_stream_node() -> StreamNode {
             column_ids: vec![0_i32, 1_i32],
             column_orders: vec![make_column_order(1), make_column_order(2)],
             distribution_keys: Default::default(),
+            handle_pk_conflict: false,
+            unique_key_count: 0,
         })),
         fields: vec![], // TODO: fill this later
         operator_id: 7,
@@ -32,6 +32,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         "stream_plan",
         "stream_service",
         "hummock",
+        "user",
     ];
     let protos: Vec<String> = proto_files
         .iter()
@@ -27,7 +27,7 @@ use risingwave_pb::task_service::exchange_service_client::ExchangeServiceClient;
 use risingwave_pb::task_service::task_service_client::TaskServiceClient;
 use risingwave_pb::task_service::{
     CreateTaskRequest, CreateTaskResponse, GetDataRequest, GetDataResponse, GetStreamRequest,
-    GetStreamResponse,
+    GetStreamResponse, GetTaskInfoRequest, TaskInfo,
 };
 use tonic::transport::{Channel, Endpoint};
 use tonic::Streaming;
@@ -134,6 +134,19 @@ impl ComputeClient {
         Ok(())
     }
 
+    pub async fn get_task_info(&self, task_id: TaskId) -> Result<TaskInfo> {
+        let resp = self
+            .task_client
+            .to_owned()
+            .get_task_info(GetTaskInfoRequest {
+                task_id: Some(task_id),
+            })
+            .await
+            .to_rw_result()?
+            .into_inner();
+        Ok(resp.task_info.unwrap_or_default())
+    }
+
     async fn create_task_inner(&self, req: CreateTaskRequest) -> Result<CreateTaskResponse> {
         Ok(self
             .task_client
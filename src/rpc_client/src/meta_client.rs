@@ -24,16 +24,19 @@ use risingwave_common::util::addr::HostAddr;
 use risingwave_hummock_sdk::{HummockEpoch, HummockSSTableId, HummockVersionId};
 use risingwave_pb::catalog::{
     Database as ProstDatabase, Schema as ProstSchema, Source as ProstSource, Table as ProstTable,
+    View as ProstView,
 };
 use risingwave_pb::common::{WorkerNode, WorkerType};
 use risingwave_pb::ddl_service::ddl_service_client::DdlServiceClient;
 use risingwave_pb::ddl_service::{
-    CreateDatabaseRequest, CreateDatabaseResponse, CreateMaterializedSourceRequest,
-    CreateMaterializedSourceResponse, CreateMaterializedViewRequest,
-    CreateMaterializedViewResponse, CreateSchemaRequest, CreateSchemaResponse, CreateSourceRequest,
-    CreateSourceResponse, DropDatabaseRequest, DropDatabaseResponse, DropMaterializedSourceRequest,
+    alter_relation_request, AlterRelationRequest, AlterRelationResponse, CreateDatabaseRequest,
+    CreateDatabaseResponse, CreateMaterializedSourceRequest, CreateMaterializedSourceResponse,
+    CreateMaterializedViewRequest, CreateMaterializedViewResponse, CreateSchemaRequest,
+    CreateSchemaResponse, CreateSourceRequest, CreateSourceResponse, CreateViewRequest,
+    CreateViewResponse, DropDatabaseRequest, DropDatabaseResponse, DropMaterializedSourceRequest,
     DropMaterializedSourceResponse, DropMaterializedViewRequest, DropMaterializedViewResponse,
-    DropSchemaRequest, DropSchemaResponse, DropSourceRequest, DropSourceResponse,
+    DropSchemaRequest, DropSchemaResponse, DropSourceRequest, DropSourceResponse, DropViewRequest,
+    DropViewResponse,
 };
 use risingwave_pb::hummock::hummock_manager_service_client::HummockManagerServiceClient;
 use risingwave_pb::hummock::{
@@ -56,6 +59,12 @@ use risingwave_pb::meta::{
     SubscribeRequest, SubscribeResponse,
 };
 use risingwave_pb::stream_plan::StreamFragmentGraph;
+use risingwave_pb::user::user_service_client::UserServiceClient;
+use risingwave_pb::user::{
+    CreateUserRequest, CreateUserResponse, DropUserRequest, DropUserResponse, GrantPrivilege,
+    GrantPrivilegeRequest, GrantPrivilegeResponse, RevokePrivilegeRequest, RevokePrivilegeResponse,
+    UpdateUserRequest, UpdateUserResponse, UserInfo,
+};
 use tokio::sync::mpsc::{Receiver, UnboundedSender};
 use tokio::task::JoinHandle;
 use tonic::transport::{Channel, Endpoint};
@@ -165,9 +174,14 @@ impl MetaClient {
         Ok((resp.table_id.into(), resp.version))
     }
 
-    pub async fn drop_materialized_view(&self, table_id: TableId) -> Result<CatalogVersion> {
+    pub async fn drop_materialized_view(
+        &self,
+        table_id: TableId,
+        cascade: bool,
+    ) -> Result<CatalogVersion> {
         let request = DropMaterializedViewRequest {
             table_id: table_id.table_id(),
+            cascade,
         };
 
         let resp = self.inner.drop_materialized_view(request).await?;
@@ -203,18 +217,52 @@ impl MetaClient {
         &self,
         source_id: u32,
         table_id: TableId,
+        cascade: bool,
     ) -> Result<CatalogVersion> {
         let request = DropMaterializedSourceRequest {
             source_id,
             table_id: table_id.table_id(),
+            cascade,
         };
 
         let resp = self.inner.drop_materialized_source(request).await?;
         Ok(resp.version)
     }
 
-    pub async fn drop_source(&self, source_id: u32) -> Result<CatalogVersion> {
-        let request = DropSourceRequest { source_id };
+    pub async fn alter_relation(
+        &self,
+        table_id: TableId,
+        operation: alter_relation_request::Operation,
+    ) -> Result<CatalogVersion> {
+        let request = AlterRelationRequest {
+            table_id: table_id.table_id(),
+            operation: Some(operation),
+        };
+        let resp = self.inner.alter_relation(request).await?;
+        Ok(resp.version)
+    }
+
+    pub async fn create_view(
+        &self,
+        view: ProstView,
+        or_replace: bool,
+    ) -> Result<(u32, CatalogVersion)> {
+        let request = CreateViewRequest {
+            view: Some(view),
+            or_replace,
+        };
+        let resp = self.inner.create_view(request).await?;
+        Ok((resp.view_id, resp.version))
+    }
+
+    pub async fn drop_view(&self, view_id: u32, cascade: bool) -> Result<CatalogVersion> {
+        let request = DropViewRequest { view_id, cascade };
+        let resp = self.inner.drop_view(request).await?;
+        Ok(resp.version)
+    }
+
+    pub async fn drop_source(&self, source_id: u32, cascade: bool) -> Result<CatalogVersion> {
+        let request = DropSourceRequest { source_id, cascade };
         let resp = self.inner.drop_source(request).await?;
         Ok(resp.version)
     }
@@ -231,6 +279,50 @@ impl MetaClient {
         Ok(resp.version)
     }
 
+    pub async fn create_user(&self, user: UserInfo) -> Result<(u32, CatalogVersion)> {
+        let request = CreateUserRequest { user: Some(user) };
+        let resp = self.inner.create_user(request).await?;
+        Ok((resp.user_id, resp.version))
+    }
+
+    pub async fn drop_user(&self, user_id: u32) -> Result<CatalogVersion> {
+        let request = DropUserRequest { user_id };
+        let resp = self.inner.drop_user(request).await?;
+        Ok(resp.version)
+    }
+
+    pub async fn update_user(&self, user: UserInfo) -> Result<CatalogVersion> {
+        let request = UpdateUserRequest { user: Some(user) };
+        let resp = self.inner.update_user(request).await?;
+        Ok(resp.version)
+    }
+
+    pub async fn grant_privilege(
+        &self,
+        user_ids: Vec<u32>,
+        privileges: Vec<GrantPrivilege>,
+    ) -> Result<CatalogVersion> {
+        let request = GrantPrivilegeRequest {
+            user_ids,
+            privileges,
+        };
+        let resp = self.inner.grant_privilege(request).await?;
+        Ok(resp.version)
+    }
+
+    pub async fn revoke_privilege(
+        &self,
+        user_ids: Vec<u32>,
+        privileges: Vec<GrantPrivilege>,
+    ) -> Result<CatalogVersion> {
+        let request = RevokePrivilegeRequest {
+            user_ids,
+            privileges,
+        };
+        let resp = self.inner.revoke_privilege(request).await?;
+        Ok(resp.version)
+    }
+
     /// Unregister the current node to the cluster.
     pub async fn unregister(&self, addr: HostAddr) -> Result<()> {
         let request = DeleteWorkerNodeRequest {
@@ -415,6 +507,7 @@ pub struct GrpcMetaClient {
     pub hummock_client: HummockManagerServiceClient<Channel>,
     pub notification_client: NotificationServiceClient<Channel>,
     pub stream_client: StreamManagerServiceClient<Channel>,
+    pub user_client: UserServiceClient<Channel>,
 }
 
 impl GrpcMetaClient {
@@ -431,7 +524,8 @@ impl GrpcMetaClient {
         let ddl_client = DdlServiceClient::new(channel.clone());
         let hummock_client = HummockManagerServiceClient::new(channel.clone());
         let notification_client = NotificationServiceClient::new(channel.clone());
-        let stream_client = StreamManagerServiceClient::new(channel);
+        let stream_client = StreamManagerServiceClient::new(channel.clone());
+        let user_client = UserServiceClient::new(channel);
         Ok(Self {
             cluster_client,
             heartbeat_client,
@@ -439,6 +533,7 @@ impl GrpcMetaClient {
             hummock_client,
             notification_client,
             stream_client,
+            user_client,
         })
     }
 }
@@ -481,6 +576,14 @@ macro_rules! for_all_meta_rpc {
             ,{ ddl_client, drop_source, DropSourceRequest, DropSourceResponse }
             ,{ ddl_client, drop_database, DropDatabaseRequest, DropDatabaseResponse }
             ,{ ddl_client, drop_schema, DropSchemaRequest, DropSchemaResponse }
+            ,{ ddl_client, alter_relation, AlterRelationRequest, AlterRelationResponse }
+            ,{ ddl_client, create_view, CreateViewRequest, CreateViewResponse }
+            ,{ ddl_client, drop_view, DropViewRequest, DropViewResponse }
+            ,{ user_client, create_user, CreateUserRequest, CreateUserResponse }
+            ,{ user_client, drop_user, DropUserRequest, DropUserResponse }
+            ,{ user_client, update_user, UpdateUserRequest, UpdateUserResponse }
+            ,{ user_client, grant_privilege, GrantPrivilegeRequest, GrantPrivilegeResponse }
+            ,{ user_client, revoke_privilege, RevokePrivilegeRequest, RevokePrivilegeResponse }
             ,{ hummock_client, pin_version, PinVersionRequest, PinVersionResponse }
             ,{ hummock_client, unpin_version, UnpinVersionRequest, UnpinVersionResponse }
             ,{ hummock_client, pin_snapshot, PinSnapshotRequest, PinSnapshotResponse }
@@ -12,10 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::str::FromStr;
+
 use num_traits::FromPrimitive;
+use risingwave_common::array::JsonbVal;
 use risingwave_common::error::ErrorCode::{self, InternalError};
 use risingwave_common::error::{Result, RwError};
-use risingwave_common::types::{DataType, Decimal, ScalarImpl, ScalarRef};
+use risingwave_common::types::{DataType, Decimal, ScalarImpl, ScalarRef, Uuid};
 use risingwave_expr::vector_op::cast::{str_to_date, str_to_timestamp};
 use serde_json::Value;
 
@@ -94,6 +97,14 @@ pub(crate) fn json_parse_value(
                 Err(e) => Err(e),
             },
         },
+        DataType::Uuid => match value.and_then(|v| v.as_str()) {
+            None => Err(RwError::from(InternalError("parse error".to_string()))),
+            Some(uuid_str) => Ok(ScalarImpl::Uuid(Uuid::from_str(uuid_str)?)),
+        },
+        // Keep the whole sub-value, so that nested or optional fields are not lost.
+        DataType::Jsonb => make_ScalarImpl!(value, |v: &Value| ScalarImpl::Jsonb(
+            JsonbVal::from_serde(v.clone())
+        )),
         _ => Err(ErrorCode::NotImplemented(
             "unsupported type for json_parse_value".to_string(),
             None.into(),
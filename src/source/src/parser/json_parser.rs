@@ -48,6 +48,9 @@ impl SourceParser for JSONParser {
 
 #[cfg(test)]
 mod tests {
+    use std::str::FromStr;
+
+    use risingwave_common::array::JsonbVal;
     use risingwave_common::catalog::ColumnId;
     use risingwave_common::types::{DataType, ScalarImpl};
     use risingwave_expr::vector_op::cast::{str_to_date, str_to_timestamp};
@@ -147,4 +150,35 @@ mod tests {
         let result = parser.parse(payload, &descs);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_json_parser_jsonb() {
+        let parser = JSONParser {};
+        let payload = r#"{"id":1,"payload":{"a":[1,{"b":null}],"c":"d"}}"#.as_bytes();
+        let descs = vec![
+            SourceColumnDesc {
+                name: "id".to_string(),
+                data_type: DataType::Int32,
+                column_id: ColumnId::from(0),
+                skip_parse: false,
+            },
+            SourceColumnDesc {
+                name: "payload".to_string(),
+                data_type: DataType::Jsonb,
+                column_id: ColumnId::from(1),
+                skip_parse: false,
+            },
+        ];
+
+        let event = parser.parse(payload, &descs).unwrap();
+        let row = event.rows.first().unwrap();
+        assert!(row[0].eq(&Some(ScalarImpl::Int32(1))));
+        let expected = JsonbVal::from_str(r#"{"a": [1, {"b": null}], "c": "d"}"#).unwrap();
+        assert!(row[1].eq(&Some(ScalarImpl::Jsonb(expected))));
+
+        let payload = r#"{"id":2}"#.as_bytes();
+        let event = parser.parse(payload, &descs).unwrap();
+        let row = event.rows.first().unwrap();
+        assert!(row[1].eq(&None));
+    }
 }
@@ -153,6 +153,7 @@ impl ProtobufParser {
                 column_type: Some(data_type.to_protobuf()),
                 field_descs: column_vec,
                 type_name: m.name().to_string(),
+                ..Default::default()
             })
         } else {
             *index += 1;
@@ -13,12 +13,14 @@
 // limitations under the License.
 
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::RwLock;
+use std::sync::{Arc, RwLock};
 
 use async_trait::async_trait;
 use rand::prelude::SliceRandom;
+use risingwave_common::array::column::Column;
 use risingwave_common::array::StreamChunk;
 use risingwave_common::catalog::{ColumnDesc, ColumnId};
+use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::Result;
 use tokio::sync::{mpsc, oneshot};
 
@@ -44,7 +46,7 @@ pub struct TableSourceV2 {
     core: RwLock<TableSourceV2Core>,
 
     /// All columns in this table.
-    column_descs: Vec<ColumnDesc>,
+    column_descs: RwLock<Vec<ColumnDesc>>,
 
     /// Current allocated row id.
     next_row_id: AtomicUsize,
@@ -58,7 +60,7 @@ impl TableSourceV2 {
 
         Self {
             core: RwLock::new(core),
-            column_descs,
+            column_descs: RwLock::new(column_descs),
             next_row_id: 0.into(),
         }
     }
@@ -81,7 +83,7 @@ impl TableSourceV2 {
             let core = self.core.read().unwrap();
             core.changes_txs
                 .choose(&mut rand::thread_rng())
-                .expect("no table reader exists")
+                .ok_or_else(|| InternalError("no table reader exists".to_owned()))?
                 .clone()
         };
 
@@ -108,6 +110,12 @@ impl TableSourceV2 {
 #[derive(Debug)]
 pub struct TableV2BatchReader;
 
+/// The columns read by a [`TableV2StreamReader`], as pairs of the index in the table and the
+/// column desc. It's shared with the streaming task so that columns added by `ALTER TABLE ADD
+/// COLUMN` can be read since the barrier carrying the change.
+#[derive(Debug, Clone)]
+pub struct TableV2ReaderColumns(Arc<RwLock<Vec<(usize, ColumnDesc)>>>);
+
 /// [`TableV2StreamReader`] reads changes from a certain table continuously.
 /// This struct should be only used for associated materialize task, thus the reader should be
 /// created only once. Further streaming task relying on this table source should follow the
@@ -118,7 +126,14 @@ pub struct TableV2StreamReader {
     rx: mpsc::UnboundedReceiver<(StreamChunk, oneshot::Sender<usize>)>,
 
     /// Mappings from the source column to the column to be read.
-    column_indices: Vec<usize>,
+    columns: TableV2ReaderColumns,
+}
+
+impl TableV2StreamReader {
+    /// Returns the handle of the columns to be read.
+    pub fn columns(&self) -> TableV2ReaderColumns {
+        self.columns.clone()
+    }
 }
 
 #[async_trait]
@@ -136,10 +151,23 @@ impl StreamSourceReader for TableV2StreamReader {
         let (ops, columns, bitmap) = chunk.into_inner();
 
         let selected_columns = self
-            .column_indices
+            .columns
+            .0
+            .read()
+            .unwrap()
             .iter()
-            .map(|i| columns[*i].clone())
-            .collect();
+            .map(|(i, desc)| match columns.get(*i) {
+                Some(column) => Ok(column.clone()),
+                // The chunk is written before the column is added, fill it with the default value.
+                None => {
+                    let mut builder = desc.data_type.create_array_builder(ops.len())?;
+                    for _ in 0..ops.len() {
+                        builder.append_datum(&desc.default_value)?;
+                    }
+                    Ok(Column::new(Arc::new(builder.finish()?)))
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
         let chunk = StreamChunk::new(ops, selected_columns, bitmap);
 
         // Notify about that we've taken the chunk.
@@ -155,13 +183,15 @@ impl StreamSourceReader for TableV2StreamReader {
 impl TableSourceV2 {
     /// Create a new stream reader.
     pub async fn stream_reader(&self, column_ids: Vec<ColumnId>) -> Result<TableV2StreamReader> {
-        let column_indices = column_ids
+        let column_descs = self.column_descs.read().unwrap();
+        let columns = column_ids
             .into_iter()
             .map(|id| {
-                self.column_descs
+                let index = column_descs
                     .iter()
                     .position(|c| c.column_id == id)
-                    .expect("column id not exists")
+                    .expect("column id not exists");
+                (index, column_descs[index].clone())
             })
             .collect();
 
@@ -169,7 +199,31 @@ impl TableSourceV2 {
         let (tx, rx) = mpsc::unbounded_channel();
         core.changes_txs.push(tx);
 
-        Ok(TableV2StreamReader { rx, column_indices })
+        Ok(TableV2StreamReader {
+            rx,
+            columns: TableV2ReaderColumns(Arc::new(RwLock::new(columns))),
+        })
+    }
+
+    /// Appends the columns added by `ALTER TABLE ADD COLUMN` to this table, and let the reader of
+    /// `reader_columns` read them. The table may be shared by several readers, so columns already
+    /// added by another one are skipped.
+    pub fn add_columns(&self, reader_columns: &TableV2ReaderColumns, added: Vec<ColumnDesc>) {
+        let mut column_descs = self.column_descs.write().unwrap();
+        let mut reader_columns = reader_columns.0.write().unwrap();
+        for desc in added {
+            let index = match column_descs
+                .iter()
+                .position(|c| c.column_id == desc.column_id)
+            {
+                Some(index) => index,
+                None => {
+                    column_descs.push(desc.clone());
+                    column_descs.len() - 1
+                }
+            };
+            reader_columns.push((index, desc));
+        }
     }
 }
 
@@ -181,7 +235,7 @@ mod tests {
     use itertools::Itertools;
     use risingwave_common::array::{Array, I64Array, Op};
     use risingwave_common::column_nonnull;
-    use risingwave_common::types::DataType;
+    use risingwave_common::types::{DataType, ScalarImpl};
     use risingwave_storage::memory::MemoryStateStore;
     use risingwave_storage::Keyspace;
 
@@ -233,4 +287,30 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_table_source_v2_add_columns() -> Result<()> {
+        let source = Arc::new(new_source());
+        let mut reader = source.stream_reader(vec![ColumnId::from(0)]).await?;
+
+        // Written before the column is added.
+        let chunk = StreamChunk::new(vec![Op::Insert], vec![column_nonnull!(I64Array, [1])], None);
+        let _notifier = source.write_chunk(chunk)?;
+
+        let mut added_column = ColumnDesc::unnamed(ColumnId::from(1), DataType::Int64);
+        added_column.default_value = Some(ScalarImpl::Int64(42));
+        source.add_columns(&reader.columns(), vec![added_column]);
+
+        let chunk = reader.next().await?.chunk;
+        assert_eq!(
+            chunk.columns()[1]
+                .array_ref()
+                .as_int64()
+                .iter()
+                .collect_vec(),
+            vec![Some(42)]
+        );
+
+        Ok(())
+    }
 }
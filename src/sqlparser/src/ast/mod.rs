@@ -235,6 +235,11 @@ pub enum Expr {
         expr: Box<Expr>,
         data_type: DataType,
     },
+    /// `<timestamp> AT TIME ZONE <time_zone>`
+    AtTimeZone {
+        timestamp: Box<Expr>,
+        time_zone: Box<Expr>,
+    },
     /// EXTRACT(DateTimeField FROM <expr>)
     Extract {
         field: DateTimeField,
@@ -270,9 +275,10 @@ pub enum Expr {
         data_type: DataType,
         value: String,
     },
-    MapAccess {
-        column: Box<Expr>,
-        keys: Vec<Expr>,
+    /// An array subscript, e.g. `arr[1]`, where the index is 1-based.
+    ArrayIndex {
+        obj: Box<Expr>,
+        index: Box<Expr>,
     },
     /// Scalar function call e.g. `LEFT(foo, 5)`
     Function(Function),
@@ -293,6 +299,10 @@ pub enum Expr {
     /// A parenthesized subquery `(SELECT ...)`, used in expression like
     /// `SELECT (subquery) AS x` or `WHERE (subquery) = x`
     Subquery(Box<Query>),
+    /// The right hand side of `<expr> <op> ANY (...)` or `<expr> <op> SOME (...)`
+    SomeOp(Box<Expr>),
+    /// The right hand side of `<expr> <op> ALL (...)`
+    AllOp(Box<Expr>),
     /// The `GROUPING SETS` expr.
     GroupingSets(Vec<Vec<Expr>>),
     /// The `CUBE` expr.
@@ -310,17 +320,7 @@ impl fmt::Display for Expr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Expr::Identifier(s) => write!(f, "{}", s),
-            Expr::MapAccess { column, keys } => {
-                write!(f, "{}", column)?;
-                for k in keys {
-                    match k {
-                        k @ Expr::Value(Value::Number(_, _)) => write!(f, "[{}]", k)?,
-                        Expr::Value(Value::SingleQuotedString(s)) => write!(f, "[\"{}\"]", s)?,
-                        _ => write!(f, "[{}]", k)?,
-                    }
-                }
-                Ok(())
-            }
+            Expr::ArrayIndex { obj, index } => write!(f, "{}[{}]", obj, index),
             Expr::CompoundIdentifier(s) => write!(f, "{}", display_separated(s, ".")),
             Expr::FieldIdentifier(ast, s) => write!(f, "{}.{}", ast, display_separated(s, ".")),
             Expr::IsNull(ast) => write!(f, "{} IS NULL", ast),
@@ -374,6 +374,10 @@ impl fmt::Display for Expr {
             }
             Expr::Cast { expr, data_type } => write!(f, "CAST({} AS {})", expr, data_type),
             Expr::TryCast { expr, data_type } => write!(f, "TRY_CAST({} AS {})", expr, data_type),
+            Expr::AtTimeZone {
+                timestamp,
+                time_zone,
+            } => write!(f, "{} AT TIME ZONE {}", timestamp, time_zone),
             Expr::Extract { field, expr } => write!(f, "EXTRACT({} FROM {})", field, expr),
             Expr::Collate { expr, collation } => write!(f, "{} COLLATE {}", expr, collation),
             Expr::Nested(ast) => write!(f, "({})", ast),
@@ -404,6 +408,8 @@ impl fmt::Display for Expr {
             }
             Expr::Exists(s) => write!(f, "EXISTS ({})", s),
             Expr::Subquery(s) => write!(f, "({})", s),
+            Expr::SomeOp(expr) => write!(f, "SOME {}", expr),
+            Expr::AllOp(expr) => write!(f, "ALL {}", expr),
             Expr::GroupingSets(sets) => {
                 write!(f, "GROUPING SETS (")?;
                 let mut sep = "";
@@ -753,6 +759,8 @@ pub enum Statement {
         name: ObjectName,
         table_name: ObjectName,
         columns: Vec<OrderByExpr>,
+        /// Non-key columns stored in the index, i.e. `INCLUDE (...)`
+        include: Vec<Ident>,
         unique: bool,
         if_not_exists: bool,
     },
@@ -767,6 +775,14 @@ pub enum Statement {
         name: ObjectName,
         operation: AlterTableOperation,
     },
+    /// ALTER [MATERIALIZED] VIEW
+    AlterView {
+        /// Whether it's a materialized view
+        materialized: bool,
+        /// View name
+        name: ObjectName,
+        operation: AlterTableOperation,
+    },
     /// DESCRIBE TABLE OR SOURCE
     Describe {
         /// Table or Source name
@@ -781,6 +797,10 @@ pub enum Statement {
     ShowObjects(ShowObject),
     /// DROP
     Drop(DropStatement),
+    /// CREATE USER
+    CreateUser(CreateUserStatement),
+    /// ALTER USER
+    AlterUser(AlterUserStatement),
     /// SET <variable>
     ///
     /// Note: this is not a standard SQL statement, but it is supported by at
@@ -795,6 +815,10 @@ pub enum Statement {
     ///
     /// Note: this is a PostgreSQL-specific statement.
     ShowVariable { variable: Vec<Ident> },
+    /// `RESET { <variable> | ALL }`
+    ///
+    /// Note: this is a PostgreSQL-specific statement.
+    ResetVariable { variable: Ident },
     /// `{ BEGIN [ TRANSACTION | WORK ] | START TRANSACTION } ...`
     StartTransaction { modes: Vec<TransactionMode> },
     /// ABORT
@@ -867,8 +891,8 @@ pub enum Statement {
         describe_alias: bool,
         /// Carry out the command and show actual run times and other statistics.
         analyze: bool,
-        // Display additional information regarding the plan.
-        verbose: bool,
+        /// Options of the explain statement, e.g. `VERBOSE` or `FORMAT JSON`.
+        options: ExplainOptions,
         /// A SQL query that specifies what to explain
         statement: Box<Statement>,
     },
@@ -886,8 +910,8 @@ impl fmt::Display for Statement {
         match self {
             Statement::Explain {
                 describe_alias,
-                verbose,
                 analyze,
+                options,
                 statement,
             } => {
                 if *describe_alias {
@@ -900,8 +924,15 @@ impl fmt::Display for Statement {
                     write!(f, "ANALYZE ")?;
                 }
 
-                if *verbose {
+                if *options
+                    == (ExplainOptions {
+                        verbose: true,
+                        ..Default::default()
+                    })
+                {
                     write!(f, "VERBOSE ")?;
+                } else if *options != ExplainOptions::default() {
+                    write!(f, "{} ", options)?;
                 }
 
                 write!(f, "{}", statement)
@@ -1082,17 +1113,24 @@ impl fmt::Display for Statement {
                 name,
                 table_name,
                 columns,
+                include,
                 unique,
                 if_not_exists,
-            } => write!(
-                f,
-                "CREATE {unique}INDEX {if_not_exists}{name} ON {table_name}({columns})",
-                unique = if *unique { "UNIQUE " } else { "" },
-                if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
-                name = name,
-                table_name = table_name,
-                columns = display_separated(columns, ",")
-            ),
+            } => {
+                write!(
+                    f,
+                    "CREATE {unique}INDEX {if_not_exists}{name} ON {table_name}({columns})",
+                    unique = if *unique { "UNIQUE " } else { "" },
+                    if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
+                    name = name,
+                    table_name = table_name,
+                    columns = display_separated(columns, ",")
+                )?;
+                if !include.is_empty() {
+                    write!(f, " INCLUDE({})", display_separated(include, ","))?;
+                }
+                Ok(())
+            }
             Statement::CreateSource {
                 is_materialized,
                 stmt,
@@ -1109,7 +1147,20 @@ impl fmt::Display for Statement {
             Statement::AlterTable { name, operation } => {
                 write!(f, "ALTER TABLE {} {}", name, operation)
             }
+            Statement::AlterView {
+                materialized,
+                name,
+                operation,
+            } => write!(
+                f,
+                "ALTER {}VIEW {} {}",
+                if *materialized { "MATERIALIZED " } else { "" },
+                name,
+                operation
+            ),
             Statement::Drop(stmt) => write!(f, "DROP {}", stmt),
+            Statement::CreateUser(stmt) => write!(f, "CREATE USER {}", stmt),
+            Statement::AlterUser(stmt) => write!(f, "ALTER USER {}", stmt),
             Statement::SetVariable {
                 local,
                 variable,
@@ -1133,6 +1184,7 @@ impl fmt::Display for Statement {
                 }
                 Ok(())
             }
+            Statement::ResetVariable { variable } => write!(f, "RESET {}", variable),
             Statement::StartTransaction { modes } => {
                 write!(f, "START TRANSACTION")?;
                 if !modes.is_empty() {
@@ -1367,12 +1419,18 @@ pub enum GrantObjects {
     AllSequencesInSchema { schemas: Vec<ObjectName> },
     /// Grant privileges on `ALL TABLES IN SCHEMA <schema_name> [, ...]`
     AllTablesInSchema { schemas: Vec<ObjectName> },
+    /// Grant privileges on specific databases
+    Databases(Vec<ObjectName>),
     /// Grant privileges on specific schemas
     Schemas(Vec<ObjectName>),
     /// Grant privileges on specific sequences
     Sequences(Vec<ObjectName>),
     /// Grant privileges on specific tables
     Tables(Vec<ObjectName>),
+    /// Grant privileges on specific sources
+    Sources(Vec<ObjectName>),
+    /// Grant privileges on specific materialized views
+    Mviews(Vec<ObjectName>),
 }
 
 impl fmt::Display for GrantObjects {
@@ -1381,9 +1439,18 @@ impl fmt::Display for GrantObjects {
             GrantObjects::Sequences(sequences) => {
                 write!(f, "SEQUENCE {}", display_comma_separated(sequences))
             }
+            GrantObjects::Databases(databases) => {
+                write!(f, "DATABASE {}", display_comma_separated(databases))
+            }
             GrantObjects::Schemas(schemas) => {
                 write!(f, "SCHEMA {}", display_comma_separated(schemas))
             }
+            GrantObjects::Sources(sources) => {
+                write!(f, "SOURCE {}", display_comma_separated(sources))
+            }
+            GrantObjects::Mviews(mviews) => {
+                write!(f, "MATERIALIZED VIEW {}", display_comma_separated(mviews))
+            }
             GrantObjects::Tables(tables) => {
                 write!(f, "{}", display_comma_separated(tables))
             }
@@ -1499,6 +1566,7 @@ pub enum ObjectType {
     Source,
     MaterializedSource,
     Database,
+    User,
 }
 
 impl fmt::Display for ObjectType {
@@ -1512,6 +1580,7 @@ impl fmt::Display for ObjectType {
             ObjectType::Source => "SOURCE",
             ObjectType::MaterializedSource => "MATERIALIZED SOURCE",
             ObjectType::Database => "DATABASE",
+            ObjectType::User => "USER",
         })
     }
 }
@@ -1534,9 +1603,11 @@ impl ParseTo for ObjectType {
             ObjectType::Schema
         } else if parser.parse_keyword(Keyword::DATABASE) {
             ObjectType::Database
+        } else if parser.parse_keyword(Keyword::USER) {
+            ObjectType::User
         } else {
             return parser.expected(
-                "TABLE, VIEW, INDEX, MATERIALIZED VIEW, SOURCE, MATERIALIZED SOURCE, or SCHEMA after DROP",
+                "TABLE, VIEW, INDEX, MATERIALIZED VIEW, SOURCE, MATERIALIZED SOURCE, SCHEMA, DATABASE or USER after DROP",
                 parser.peek_token(),
             );
         };
@@ -76,6 +76,8 @@ pub enum BinaryOperator {
     NotLike,
     ILike,
     NotILike,
+    SimilarTo,
+    NotSimilarTo,
     BitwiseOr,
     BitwiseAnd,
     BitwiseXor,
@@ -86,6 +88,12 @@ pub enum BinaryOperator {
     PGRegexIMatch,
     PGRegexNotMatch,
     PGRegexNotIMatch,
+    PGJsonGet,
+    PGJsonGetText,
+    PGJsonGetPath,
+    PGJsonContains,
+    PGJsonExists,
+    PGOverlap,
 }
 
 impl fmt::Display for BinaryOperator {
@@ -111,6 +119,8 @@ impl fmt::Display for BinaryOperator {
             BinaryOperator::NotLike => "NOT LIKE",
             BinaryOperator::ILike => "ILIKE",
             BinaryOperator::NotILike => "NOT ILIKE",
+            BinaryOperator::SimilarTo => "SIMILAR TO",
+            BinaryOperator::NotSimilarTo => "NOT SIMILAR TO",
             BinaryOperator::BitwiseOr => "|",
             BinaryOperator::BitwiseAnd => "&",
             BinaryOperator::BitwiseXor => "^",
@@ -121,6 +131,12 @@ impl fmt::Display for BinaryOperator {
             BinaryOperator::PGRegexIMatch => "~*",
             BinaryOperator::PGRegexNotMatch => "!~",
             BinaryOperator::PGRegexNotIMatch => "!~*",
+            BinaryOperator::PGJsonGet => "->",
+            BinaryOperator::PGJsonGetText => "->>",
+            BinaryOperator::PGJsonGetPath => "#>",
+            BinaryOperator::PGJsonContains => "@>",
+            BinaryOperator::PGJsonExists => "?",
+            BinaryOperator::PGOverlap => "&&",
         })
     }
 }
@@ -375,3 +375,233 @@ impl fmt::Display for DropMode {
         })
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UserOption {
+    SuperUser,
+    NoSuperUser,
+    CreateDB,
+    NoCreateDB,
+    Login,
+    NoLogin,
+}
+
+impl UserOption {
+    /// An option and its negation configure the same attribute of a user.
+    fn attribute(&self) -> &'static str {
+        match self {
+            UserOption::SuperUser | UserOption::NoSuperUser => "SUPERUSER",
+            UserOption::CreateDB | UserOption::NoCreateDB => "CREATEDB",
+            UserOption::Login | UserOption::NoLogin => "LOGIN",
+        }
+    }
+}
+
+impl fmt::Display for UserOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            UserOption::SuperUser => "SUPERUSER",
+            UserOption::NoSuperUser => "NOSUPERUSER",
+            UserOption::CreateDB => "CREATEDB",
+            UserOption::NoCreateDB => "NOCREATEDB",
+            UserOption::Login => "LOGIN",
+            UserOption::NoLogin => "NOLOGIN",
+        })
+    }
+}
+
+/// Options of `CREATE USER` and `ALTER USER`, e.g. `WITH SUPERUSER NOLOGIN`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UserOptions(pub Vec<UserOption>);
+
+impl ParseTo for UserOptions {
+    fn parse_to(parser: &mut Parser) -> Result<Self, ParserError> {
+        let _ = parser.parse_keyword(Keyword::WITH);
+        let mut options: Vec<UserOption> = vec![];
+        loop {
+            let option = if parser.parse_keyword(Keyword::SUPERUSER) {
+                UserOption::SuperUser
+            } else if parser.parse_keyword(Keyword::NOSUPERUSER) {
+                UserOption::NoSuperUser
+            } else if parser.parse_keyword(Keyword::CREATEDB) {
+                UserOption::CreateDB
+            } else if parser.parse_keyword(Keyword::NOCREATEDB) {
+                UserOption::NoCreateDB
+            } else if parser.parse_keyword(Keyword::LOGIN) {
+                UserOption::Login
+            } else if parser.parse_keyword(Keyword::NOLOGIN) {
+                UserOption::NoLogin
+            } else {
+                break;
+            };
+            let conflict = options.iter().any(|o| o.attribute() == option.attribute());
+            if conflict {
+                return Err(ParserError::ParserError(
+                    "conflicting or redundant options".to_string(),
+                ));
+            }
+            options.push(option);
+        }
+        Ok(Self(options))
+    }
+}
+
+impl fmt::Display for UserOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.0.is_empty() {
+            write!(f, "WITH {}", AstVec(self.0.clone()))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// sql_grammar!(CreateUserStatement {
+//     user_name: ObjectName,
+//     with_options: UserOptions,
+// });
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CreateUserStatement {
+    pub user_name: ObjectName,
+    pub with_options: UserOptions,
+}
+
+impl ParseTo for CreateUserStatement {
+    fn parse_to(p: &mut Parser) -> Result<Self, ParserError> {
+        impl_parse_to!(user_name: ObjectName, p);
+        impl_parse_to!(with_options: UserOptions, p);
+        Ok(Self {
+            user_name,
+            with_options,
+        })
+    }
+}
+
+impl fmt::Display for CreateUserStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut v: Vec<String> = vec![];
+        impl_fmt_display!(user_name, v, self);
+        impl_fmt_display!(with_options, v, self);
+        v.iter().join(" ").fmt(f)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AlterUserMode {
+    Options(UserOptions),
+    Rename(ObjectName),
+}
+
+impl fmt::Display for AlterUserMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlterUserMode::Options(options) => options.fmt(f),
+            AlterUserMode::Rename(new_name) => write!(f, "RENAME TO {}", new_name),
+        }
+    }
+}
+
+// sql_grammar!(AlterUserStatement {
+//     user_name: ObjectName,
+//     mode: AlterUserMode,
+// });
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AlterUserStatement {
+    pub user_name: ObjectName,
+    pub mode: AlterUserMode,
+}
+
+impl ParseTo for AlterUserStatement {
+    fn parse_to(p: &mut Parser) -> Result<Self, ParserError> {
+        impl_parse_to!(user_name: ObjectName, p);
+        let mode = if p.parse_keywords(&[Keyword::RENAME, Keyword::TO]) {
+            AlterUserMode::Rename(p.parse_object_name()?)
+        } else {
+            impl_parse_to!(with_options: UserOptions, p);
+            if with_options.0.is_empty() {
+                return p.expected("RENAME TO or user options", p.peek_token());
+            }
+            AlterUserMode::Options(with_options)
+        };
+        Ok(Self { user_name, mode })
+    }
+}
+
+impl fmt::Display for AlterUserStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut v: Vec<String> = vec![];
+        impl_fmt_display!(user_name, v, self);
+        impl_fmt_display!(mode, v, self);
+        v.iter().join(" ").fmt(f)
+    }
+}
+
+/// Options of `EXPLAIN`, specified either as `EXPLAIN VERBOSE` or as a parenthesized list like
+/// `EXPLAIN (VERBOSE, DISTSQL, FORMAT JSON)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ExplainOptions {
+    /// Display additional information regarding the plan.
+    pub verbose: bool,
+    /// Display the distributed plan, i.e. the fragments of a streaming job or the stages of a
+    /// batch query.
+    pub distsql: bool,
+    /// The output format.
+    pub format: ExplainFormat,
+}
+
+impl fmt::Display for ExplainOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut v: Vec<String> = vec![];
+        if self.verbose {
+            v.push("VERBOSE".to_string());
+        }
+        if self.distsql {
+            v.push("DISTSQL".to_string());
+        }
+        if self.format != ExplainFormat::default() {
+            v.push(format!("FORMAT {}", self.format));
+        }
+        write!(f, "({})", v.iter().join(", "))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ExplainFormat {
+    Text,
+    Json,
+}
+
+impl Default for ExplainFormat {
+    fn default() -> Self {
+        Self::Text
+    }
+}
+
+impl ParseTo for ExplainFormat {
+    fn parse_to(p: &mut Parser) -> Result<Self, ParserError> {
+        let format = if p.parse_keyword(Keyword::TEXT) {
+            ExplainFormat::Text
+        } else if p.parse_keyword(Keyword::JSON) {
+            ExplainFormat::Json
+        } else {
+            return p.expected("TEXT | JSON", p.peek_token());
+        };
+        Ok(format)
+    }
+}
+
+impl fmt::Display for ExplainFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ExplainFormat::Text => "TEXT",
+            ExplainFormat::Json => "JSON",
+        })
+    }
+}
@@ -141,6 +141,7 @@ define_keywords!(
     COVAR_POP,
     COVAR_SAMP,
     CREATE,
+    CREATEDB,
     CROSS,
     CSV,
     CUBE,
@@ -178,6 +179,7 @@ define_keywords!(
     DIRECTORY,
     DISCONNECT,
     DISTINCT,
+    DISTSQL,
     DOUBLE,
     DROP,
     DYNAMIC,
@@ -235,6 +237,7 @@ define_keywords!(
     IGNORE,
     ILIKE,
     IN,
+    INCLUDE,
     INDEX,
     INDICATOR,
     INNER,
@@ -251,6 +254,7 @@ define_keywords!(
     ISOLATION,
     JOIN,
     JSON,
+    JSONB,
     KEY,
     LANGUAGE,
     LARGE,
@@ -266,6 +270,7 @@ define_keywords!(
     LOCALTIME,
     LOCALTIMESTAMP,
     LOCATION,
+    LOGIN,
     LOWER,
     MATCH,
     MATERIALIZED,
@@ -288,9 +293,12 @@ define_keywords!(
     NEW,
     NEXT,
     NO,
+    NOCREATEDB,
+    NOLOGIN,
     NONE,
     NORMALIZE,
     NOSCAN,
+    NOSUPERUSER,
     NOT,
     NTH_VALUE,
     NTILE,
@@ -364,6 +372,7 @@ define_keywords!(
     REPAIR,
     REPEATABLE,
     REPLACE,
+    RESET,
     RESTRICT,
     RESULT,
     RETURN,
@@ -423,6 +432,7 @@ define_keywords!(
     SUBSTRING_REGEX,
     SUCCEEDS,
     SUM,
+    SUPERUSER,
     SYMMETRIC,
     SYNC,
     SYSTEM,
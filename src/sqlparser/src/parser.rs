@@ -119,6 +119,8 @@ pub struct Parser {
 }
 
 impl Parser {
+    /// `AT TIME ZONE` binds tighter than arithmetic operators, but looser than `::`.
+    const AT_TIME_ZONE_PREC: u8 = 45;
     const BETWEEN_PREC: u8 = 20;
     const PLUS_MINUS_PREC: u8 = 30;
     const UNARY_NOT_PREC: u8 = 15;
@@ -181,6 +183,9 @@ impl Parser {
                 Keyword::COPY => Ok(self.parse_copy()?),
                 Keyword::SET => Ok(self.parse_set()?),
                 Keyword::SHOW => Ok(self.parse_show()?),
+                Keyword::RESET => Ok(Statement::ResetVariable {
+                    variable: self.parse_identifier()?,
+                }),
                 Keyword::DESCRIBE => Ok(Statement::Describe {
                     name: self.parse_object_name()?,
                 }),
@@ -970,16 +975,28 @@ impl Parser {
             Token::TildeAsterisk => Some(BinaryOperator::PGRegexIMatch),
             Token::ExclamationMarkTilde => Some(BinaryOperator::PGRegexNotMatch),
             Token::ExclamationMarkTildeAsterisk => Some(BinaryOperator::PGRegexNotIMatch),
+            Token::Arrow => Some(BinaryOperator::PGJsonGet),
+            Token::LongArrow => Some(BinaryOperator::PGJsonGetText),
+            Token::HashArrow => Some(BinaryOperator::PGJsonGetPath),
+            Token::AtArrow => Some(BinaryOperator::PGJsonContains),
+            Token::DoubleAmpersand => Some(BinaryOperator::PGOverlap),
+            Token::QuestionMark => Some(BinaryOperator::PGJsonExists),
             Token::Word(w) => match w.keyword {
                 Keyword::AND => Some(BinaryOperator::And),
                 Keyword::OR => Some(BinaryOperator::Or),
                 Keyword::LIKE => Some(BinaryOperator::Like),
                 Keyword::ILIKE => Some(BinaryOperator::ILike),
+                Keyword::SIMILAR => {
+                    self.expect_keyword(Keyword::TO)?;
+                    Some(BinaryOperator::SimilarTo)
+                }
                 Keyword::NOT => {
                     if self.parse_keyword(Keyword::LIKE) {
                         Some(BinaryOperator::NotLike)
                     } else if self.parse_keyword(Keyword::ILIKE) {
                         Some(BinaryOperator::NotILike)
+                    } else if self.parse_keywords(&[Keyword::SIMILAR, Keyword::TO]) {
+                        Some(BinaryOperator::NotSimilarTo)
                     } else {
                         None
                     }
@@ -991,10 +1008,24 @@ impl Parser {
         };
 
         if let Some(op) = regular_binary_operator {
+            let right = if let Some(keyword) =
+                self.parse_one_of_keywords(&[Keyword::ANY, Keyword::SOME, Keyword::ALL])
+            {
+                if self.peek_token() != Token::LParen {
+                    return self.expected("( after ANY, SOME or ALL", self.peek_token());
+                }
+                let right = Box::new(self.parse_subexpr(precedence)?);
+                match keyword {
+                    Keyword::ALL => Expr::AllOp(right),
+                    _ => Expr::SomeOp(right),
+                }
+            } else {
+                self.parse_subexpr(precedence)?
+            };
             Ok(Expr::BinaryOp {
                 left: Box::new(expr),
                 op,
-                right: Box::new(self.parse_subexpr(precedence)?),
+                right: Box::new(right),
             })
         } else if let Token::Word(w) = &tok {
             match w.keyword {
@@ -1025,6 +1056,14 @@ impl Parser {
                         )
                     }
                 }
+                Keyword::AT => {
+                    self.expect_keywords(&[Keyword::TIME, Keyword::ZONE])?;
+                    let time_zone = self.parse_subexpr(precedence)?;
+                    Ok(Expr::AtTimeZone {
+                        timestamp: Box::new(expr),
+                        time_zone: Box::new(time_zone),
+                    })
+                }
                 Keyword::NOT | Keyword::IN | Keyword::BETWEEN => {
                     self.prev_token();
                     let negated = self.parse_keyword(Keyword::NOT);
@@ -1048,30 +1087,25 @@ impl Parser {
                 expr: Box::new(expr),
             })
         } else if Token::LBracket == tok {
-            self.parse_map_access(expr)
+            self.parse_array_index(expr)
         } else {
             // Can only happen if `get_next_precedence` got out of sync with this function
             parser_err!(format!("No infix parser for token {:?}", tok))
         }
     }
 
-    pub fn parse_map_access(&mut self, expr: Expr) -> Result<Expr, ParserError> {
-        let key = self.parse_map_key()?;
-        let tok = self.consume_token(&Token::RBracket);
-        debug!("Tok: {}", tok);
-        let mut key_parts: Vec<Expr> = vec![key];
-        while self.consume_token(&Token::LBracket) {
-            let key = self.parse_map_key()?;
-            let tok = self.consume_token(&Token::RBracket);
-            debug!("Tok: {}", tok);
-            key_parts.push(key);
-        }
-        match expr {
-            e @ Expr::Identifier(_) | e @ Expr::CompoundIdentifier(_) => Ok(Expr::MapAccess {
-                column: Box::new(e),
-                keys: key_parts,
-            }),
-            _ => Ok(expr),
+    /// Parse the subscripts after an array, e.g. `[1][2]`.
+    pub fn parse_array_index(&mut self, expr: Expr) -> Result<Expr, ParserError> {
+        let index = Box::new(self.parse_expr()?);
+        self.expect_token(&Token::RBracket)?;
+        let array_index = Expr::ArrayIndex {
+            obj: Box::new(expr),
+            index,
+        };
+        if self.consume_token(&Token::LBracket) {
+            self.parse_array_index(array_index)
+        } else {
+            Ok(array_index)
         }
     }
 
@@ -1137,13 +1171,25 @@ impl Parser {
                 Token::Word(w) if w.keyword == Keyword::BETWEEN => Ok(Self::BETWEEN_PREC),
                 Token::Word(w) if w.keyword == Keyword::LIKE => Ok(Self::BETWEEN_PREC),
                 Token::Word(w) if w.keyword == Keyword::ILIKE => Ok(Self::BETWEEN_PREC),
+                Token::Word(w) if w.keyword == Keyword::SIMILAR => Ok(Self::BETWEEN_PREC),
                 _ => Ok(0),
             },
             Token::Word(w) if w.keyword == Keyword::IS => Ok(17),
+            Token::Word(w) if w.keyword == Keyword::AT => {
+                match (self.peek_nth_token(1), self.peek_nth_token(2)) {
+                    (Token::Word(w1), Token::Word(w2))
+                        if w1.keyword == Keyword::TIME && w2.keyword == Keyword::ZONE =>
+                    {
+                        Ok(Self::AT_TIME_ZONE_PREC)
+                    }
+                    _ => Ok(0),
+                }
+            }
             Token::Word(w) if w.keyword == Keyword::IN => Ok(Self::BETWEEN_PREC),
             Token::Word(w) if w.keyword == Keyword::BETWEEN => Ok(Self::BETWEEN_PREC),
             Token::Word(w) if w.keyword == Keyword::LIKE => Ok(Self::BETWEEN_PREC),
             Token::Word(w) if w.keyword == Keyword::ILIKE => Ok(Self::BETWEEN_PREC),
+            Token::Word(w) if w.keyword == Keyword::SIMILAR => Ok(Self::BETWEEN_PREC),
             Token::Eq
             | Token::Lt
             | Token::LtEq
@@ -1157,13 +1203,24 @@ impl Parser {
             | Token::ExclamationMarkTildeAsterisk
             | Token::Spaceship => Ok(20),
             Token::Pipe => Ok(21),
-            Token::Caret | Token::Sharp | Token::ShiftRight | Token::ShiftLeft => Ok(22),
+            Token::Sharp
+            | Token::ShiftRight
+            | Token::ShiftLeft
+            | Token::Arrow
+            | Token::LongArrow
+            | Token::HashArrow
+            | Token::AtArrow
+            | Token::DoubleAmpersand
+            | Token::QuestionMark => Ok(22),
             Token::Ampersand => Ok(23),
             Token::Plus | Token::Minus => Ok(Self::PLUS_MINUS_PREC),
             Token::Mul | Token::Div | Token::Mod | Token::StringConcat => Ok(40),
+            // `^` is exponentiation in PostgreSQL, which binds tighter than multiplication.
+            Token::Caret => Ok(45),
             Token::DoubleColon => Ok(50),
             Token::ExclamationMark => Ok(50),
-            Token::LBracket => Ok(10),
+            // Subscripts bind as tightly as casts, e.g. `a + b[1]` is `a + (b[1])`.
+            Token::LBracket => Ok(50),
             _ => Ok(0),
         }
     }
@@ -1396,6 +1453,8 @@ impl Parser {
             self.parse_create_schema()
         } else if self.parse_keyword(Keyword::DATABASE) {
             self.parse_create_database()
+        } else if self.parse_keyword(Keyword::USER) {
+            self.parse_create_user()
         } else {
             self.expected("an object type after CREATE", self.peek_token())
         }
@@ -1467,6 +1526,10 @@ impl Parser {
         Ok(self.parse_options(Keyword::WITH)?.to_vec())
     }
 
+    pub fn parse_create_user(&mut self) -> Result<Statement, ParserError> {
+        Ok(Statement::CreateUser(CreateUserStatement::parse_to(self)?))
+    }
+
     pub fn parse_drop(&mut self) -> Result<Statement, ParserError> {
         Ok(Statement::Drop(DropStatement::parse_to(self)?))
     }
@@ -1479,10 +1542,19 @@ impl Parser {
         self.expect_token(&Token::LParen)?;
         let columns = self.parse_comma_separated(Parser::parse_order_by_expr)?;
         self.expect_token(&Token::RParen)?;
+        let include = if self.parse_keyword(Keyword::INCLUDE) {
+            self.expect_token(&Token::LParen)?;
+            let include = self.parse_comma_separated(Parser::parse_identifier)?;
+            self.expect_token(&Token::RParen)?;
+            include
+        } else {
+            vec![]
+        };
         Ok(Statement::CreateIndex {
             name: index_name,
             table_name,
             columns,
+            include,
             unique,
             if_not_exists,
         })
@@ -1738,8 +1810,32 @@ impl Parser {
     }
 
     pub fn parse_alter(&mut self) -> Result<Statement, ParserError> {
-        self.expect_keyword(Keyword::TABLE)?;
-        self.parse_alter_table()
+        if self.parse_keyword(Keyword::USER) {
+            self.parse_alter_user()
+        } else if self.parse_keyword(Keyword::VIEW) {
+            self.parse_alter_view(false)
+        } else if self.parse_keywords(&[Keyword::MATERIALIZED, Keyword::VIEW]) {
+            self.parse_alter_view(true)
+        } else {
+            self.expect_keyword(Keyword::TABLE)?;
+            self.parse_alter_table()
+        }
+    }
+
+    pub fn parse_alter_view(&mut self, materialized: bool) -> Result<Statement, ParserError> {
+        let name = self.parse_object_name()?;
+        // Only renaming is supported for views.
+        self.expect_keyword(Keyword::RENAME)?;
+        let operation = self.parse_alter_rename()?;
+        Ok(Statement::AlterView {
+            materialized,
+            name,
+            operation,
+        })
+    }
+
+    pub fn parse_alter_user(&mut self) -> Result<Statement, ParserError> {
+        Ok(Statement::AlterUser(AlterUserStatement::parse_to(self)?))
     }
 
     pub fn parse_alter_table(&mut self) -> Result<Statement, ParserError> {
@@ -1761,18 +1857,8 @@ impl Parser {
                 self.expect_keyword(Keyword::TO)?;
                 let new_name = self.parse_identifier()?;
                 AlterTableOperation::RenameConstraint { old_name, new_name }
-            } else if self.parse_keyword(Keyword::TO) {
-                let table_name = self.parse_object_name()?;
-                AlterTableOperation::RenameTable { table_name }
             } else {
-                let _ = self.parse_keyword(Keyword::COLUMN);
-                let old_column_name = self.parse_identifier()?;
-                self.expect_keyword(Keyword::TO)?;
-                let new_column_name = self.parse_identifier()?;
-                AlterTableOperation::RenameColumn {
-                    old_column_name,
-                    new_column_name,
-                }
+                self.parse_alter_rename()?
             }
         } else if self.parse_keyword(Keyword::DROP) {
             let _ = self.parse_keyword(Keyword::COLUMN);
@@ -1824,6 +1910,24 @@ impl Parser {
         })
     }
 
+    /// Parse the part after `RENAME` of `ALTER TABLE` or `ALTER VIEW`, i.e. `TO new_name` or
+    /// `[COLUMN] old_name TO new_name`.
+    fn parse_alter_rename(&mut self) -> Result<AlterTableOperation, ParserError> {
+        if self.parse_keyword(Keyword::TO) {
+            let table_name = self.parse_object_name()?;
+            Ok(AlterTableOperation::RenameTable { table_name })
+        } else {
+            let _ = self.parse_keyword(Keyword::COLUMN);
+            let old_column_name = self.parse_identifier()?;
+            self.expect_keyword(Keyword::TO)?;
+            let new_column_name = self.parse_identifier()?;
+            Ok(AlterTableOperation::RenameColumn {
+                old_column_name,
+                new_column_name,
+            })
+        }
+    }
+
     /// Parse a copy statement
     pub fn parse_copy(&mut self) -> Result<Statement, ParserError> {
         let table_name = self.parse_object_name()?;
@@ -1926,21 +2030,6 @@ impl Parser {
         }
     }
 
-    /// Parse a map key string
-    pub fn parse_map_key(&mut self) -> Result<Expr, ParserError> {
-        match self.next_token() {
-            Token::Word(Word { value, keyword, .. }) if keyword == Keyword::NoKeyword => {
-                if self.peek_token() == Token::LParen {
-                    return self.parse_function(ObjectName(vec![Ident::new(value)]));
-                }
-                Ok(Expr::Value(Value::SingleQuotedString(value)))
-            }
-            Token::SingleQuotedString(s) => Ok(Expr::Value(Value::SingleQuotedString(s))),
-            Token::Number(s, _) => Ok(Expr::Value(Value::Number(s, false))),
-            unexpected => self.expected("literal string, number or function", unexpected),
-        }
-    }
-
     /// Parse a SQL datatype (in the context of a CREATE TABLE statement for example) and convert
     /// into an array of that datatype if needed
     pub fn parse_data_type(&mut self) -> Result<DataType, ParserError> {
@@ -2047,6 +2136,7 @@ impl Parser {
                 }
                 Keyword::STRUCT => Ok(DataType::Struct(self.parse_struct_data_type()?)),
                 Keyword::BYTEA => Ok(DataType::Bytea),
+                Keyword::JSONB => Ok(DataType::Jsonb),
                 Keyword::NUMERIC | Keyword::DECIMAL | Keyword::DEC => {
                     let (precision, scale) = self.parse_optional_precision_scale()?;
                     Ok(DataType::Decimal(precision, scale))
@@ -2255,18 +2345,65 @@ impl Parser {
     }
 
     pub fn parse_explain(&mut self, describe_alias: bool) -> Result<Statement, ParserError> {
-        let analyze = self.parse_keyword(Keyword::ANALYZE);
-        let verbose = self.parse_keyword(Keyword::VERBOSE);
+        const OPTION_KEYWORDS: [Keyword; 4] = [
+            Keyword::ANALYZE,
+            Keyword::VERBOSE,
+            Keyword::DISTSQL,
+            Keyword::FORMAT,
+        ];
+
+        let mut options = ExplainOptions::default();
+        let mut analyze = self.parse_keyword(Keyword::ANALYZE);
+        let parenthesized = match (self.peek_token(), self.peek_nth_token(1)) {
+            (Token::LParen, Token::Word(w)) => OPTION_KEYWORDS.contains(&w.keyword),
+            _ => false,
+        };
+        if parenthesized {
+            // `EXPLAIN ( option [, ...] ) statement`, where `( SELECT ...)` is left to the
+            // statement parser.
+            self.expect_token(&Token::LParen)?;
+            loop {
+                match self.expect_one_of_keywords(&OPTION_KEYWORDS)? {
+                    Keyword::ANALYZE => analyze = self.parse_explain_option_value()?,
+                    Keyword::VERBOSE => options.verbose = self.parse_explain_option_value()?,
+                    Keyword::DISTSQL => options.distsql = self.parse_explain_option_value()?,
+                    Keyword::FORMAT => options.format = ExplainFormat::parse_to(self)?,
+                    _ => unreachable!(),
+                }
+                if !self.consume_token(&Token::Comma) {
+                    break;
+                }
+            }
+            self.expect_token(&Token::RParen)?;
+        } else {
+            options.verbose = self.parse_keyword(Keyword::VERBOSE);
+        }
 
         let statement = self.parse_statement()?;
         Ok(Statement::Explain {
             describe_alias,
             analyze,
-            verbose,
+            options,
             statement: Box::new(statement),
         })
     }
 
+    /// Parse the optional boolean value of an `EXPLAIN` option, which defaults to `true`.
+    fn parse_explain_option_value(&mut self) -> Result<bool, ParserError> {
+        let value = match self.peek_token() {
+            Token::Word(w) if w.keyword == Keyword::TRUE || w.keyword == Keyword::ON => true,
+            Token::Word(w)
+                if w.keyword == Keyword::FALSE || w.value.eq_ignore_ascii_case("off") =>
+            {
+                false
+            }
+            Token::Number(n, _) if n == "1" || n == "0" => n == "1",
+            _ => return Ok(true),
+        };
+        self.next_token();
+        Ok(value)
+    }
+
     /// Parse a query expression, i.e. a `SELECT` statement optionally
     /// preceeded with some `WITH` CTE declarations and optionally followed
     /// by `ORDER BY`. Unlike some other parse_... methods, this one doesn't
@@ -2507,8 +2644,14 @@ impl Parser {
 
     pub fn parse_set(&mut self) -> Result<Statement, ParserError> {
         let modifier = self.parse_one_of_keywords(&[Keyword::SESSION, Keyword::LOCAL]);
-        let variable = self.parse_identifier()?;
-        if self.consume_token(&Token::Eq) || self.parse_keyword(Keyword::TO) {
+        // `SET TIME ZONE <value>` is an alias of `SET timezone = <value>`.
+        let time_zone = self.parse_keywords(&[Keyword::TIME, Keyword::ZONE]);
+        let variable = if time_zone {
+            Ident::new("timezone")
+        } else {
+            self.parse_identifier()?
+        };
+        if time_zone || self.consume_token(&Token::Eq) || self.parse_keyword(Keyword::TO) {
             let mut values = vec![];
             loop {
                 let token = self.peek_token();
@@ -2816,10 +2959,14 @@ impl Parser {
                 self.parse_comma_separated(Parser::parse_grant_permission)?
                     .into_iter()
                     .map(|(kw, columns)| match kw {
+                        Keyword::CONNECT => Action::Connect,
+                        Keyword::CREATE => Action::Create,
                         Keyword::DELETE => Action::Delete,
+                        Keyword::EXECUTE => Action::Execute,
                         Keyword::INSERT => Action::Insert { columns },
                         Keyword::REFERENCES => Action::References { columns },
                         Keyword::SELECT => Action::Select { columns },
+                        Keyword::TEMPORARY => Action::Temporary,
                         Keyword::TRIGGER => Action::Trigger,
                         Keyword::TRUNCATE => Action::Truncate,
                         Keyword::UPDATE => Action::Update { columns },
@@ -2850,12 +2997,21 @@ impl Parser {
             GrantObjects::AllSequencesInSchema {
                 schemas: self.parse_comma_separated(Parser::parse_object_name)?,
             }
+        } else if self.parse_keywords(&[Keyword::MATERIALIZED, Keyword::VIEW]) {
+            GrantObjects::Mviews(self.parse_comma_separated(Parser::parse_object_name)?)
         } else {
-            let object_type =
-                self.parse_one_of_keywords(&[Keyword::SEQUENCE, Keyword::SCHEMA, Keyword::TABLE]);
+            let object_type = self.parse_one_of_keywords(&[
+                Keyword::DATABASE,
+                Keyword::SEQUENCE,
+                Keyword::SCHEMA,
+                Keyword::SOURCE,
+                Keyword::TABLE,
+            ]);
             let objects = self.parse_comma_separated(Parser::parse_object_name);
             match object_type {
+                Some(Keyword::DATABASE) => GrantObjects::Databases(objects?),
                 Some(Keyword::SCHEMA) => GrantObjects::Schemas(objects?),
+                Some(Keyword::SOURCE) => GrantObjects::Sources(objects?),
                 Some(Keyword::SEQUENCE) => GrantObjects::Sequences(objects?),
                 Some(Keyword::TABLE) | None => GrantObjects::Tables(objects?),
                 _ => unreachable!(),
@@ -138,6 +138,18 @@ pub enum Token {
     PGSquareRoot,
     /// `||/` , a cube root math operator in PostgreSQL
     PGCubeRoot,
+    /// `->`, access a json object field or array element in PostgreSQL
+    Arrow,
+    /// `->>`, access a json object field or array element as text in PostgreSQL
+    LongArrow,
+    /// `#>`, access a json value at the specified path in PostgreSQL
+    HashArrow,
+    /// `@>`, a json containment operator in PostgreSQL
+    AtArrow,
+    /// `?`, a json key existence operator in PostgreSQL
+    QuestionMark,
+    /// `&&`, an array overlap operator in PostgreSQL
+    DoubleAmpersand,
 }
 
 impl fmt::Display for Token {
@@ -193,6 +205,12 @@ impl fmt::Display for Token {
             Token::ShiftRight => f.write_str(">>"),
             Token::PGSquareRoot => f.write_str("|/"),
             Token::PGCubeRoot => f.write_str("||/"),
+            Token::Arrow => f.write_str("->"),
+            Token::LongArrow => f.write_str("->>"),
+            Token::HashArrow => f.write_str("#>"),
+            Token::AtArrow => f.write_str("@>"),
+            Token::QuestionMark => f.write_str("?"),
+            Token::DoubleAmpersand => f.write_str("&&"),
         }
     }
 }
@@ -475,6 +493,13 @@ impl<'a> Tokenizer<'a> {
                                 comment,
                             })))
                         }
+                        Some('>') => {
+                            chars.next(); // consume the '>'
+                            match chars.peek() {
+                                Some('>') => self.consume_and_return(chars, Token::LongArrow),
+                                _ => Ok(Some(Token::Arrow)),
+                            }
+                        }
                         // a regular '-' operator
                         _ => Ok(Some(Token::Minus)),
                     }
@@ -565,7 +590,13 @@ impl<'a> Tokenizer<'a> {
                 '\\' => self.consume_and_return(chars, Token::Backslash),
                 '[' => self.consume_and_return(chars, Token::LBracket),
                 ']' => self.consume_and_return(chars, Token::RBracket),
-                '&' => self.consume_and_return(chars, Token::Ampersand),
+                '&' => {
+                    chars.next(); // consume the '&'
+                    match chars.peek() {
+                        Some('&') => self.consume_and_return(chars, Token::DoubleAmpersand),
+                        _ => Ok(Some(Token::Ampersand)),
+                    }
+                }
                 '^' => self.consume_and_return(chars, Token::Caret),
                 '{' => self.consume_and_return(chars, Token::LBrace),
                 '}' => self.consume_and_return(chars, Token::RBrace),
@@ -576,8 +607,21 @@ impl<'a> Tokenizer<'a> {
                         _ => Ok(Some(Token::Tilde)),
                     }
                 }
-                '#' => self.consume_and_return(chars, Token::Sharp),
-                '@' => self.consume_and_return(chars, Token::AtSign),
+                '#' => {
+                    chars.next(); // consume the '#'
+                    match chars.peek() {
+                        Some('>') => self.consume_and_return(chars, Token::HashArrow),
+                        _ => Ok(Some(Token::Sharp)),
+                    }
+                }
+                '@' => {
+                    chars.next(); // consume the '@'
+                    match chars.peek() {
+                        Some('>') => self.consume_and_return(chars, Token::AtArrow),
+                        _ => Ok(Some(Token::AtSign)),
+                    }
+                }
+                '?' => self.consume_and_return(chars, Token::QuestionMark),
                 other => self.consume_and_return(chars, Token::Char(other)),
             },
             None => Ok(None),
@@ -1221,6 +1265,44 @@ mod tests {
         compare(expected, tokens);
     }
 
+    #[test]
+    fn tokenize_pg_json_ops() {
+        let sql = "a->'b'->>c#>'{d}'@>e?f-1";
+        let mut tokenizer = Tokenizer::new(sql);
+        let tokens = tokenizer.tokenize().unwrap();
+        let expected = vec![
+            Token::make_word("a", None),
+            Token::Arrow,
+            Token::SingleQuotedString("b".into()),
+            Token::LongArrow,
+            Token::make_word("c", None),
+            Token::HashArrow,
+            Token::SingleQuotedString("{d}".into()),
+            Token::AtArrow,
+            Token::make_word("e", None),
+            Token::QuestionMark,
+            Token::make_word("f", None),
+            Token::Minus,
+            Token::Number("1".into(), false),
+        ];
+        compare(expected, tokens);
+    }
+
+    #[test]
+    fn tokenize_pg_array_ops() {
+        let sql = "a&&b&c";
+        let mut tokenizer = Tokenizer::new(sql);
+        let tokens = tokenizer.tokenize().unwrap();
+        let expected = vec![
+            Token::make_word("a", None),
+            Token::DoubleAmpersand,
+            Token::make_word("b", None),
+            Token::Ampersand,
+            Token::make_word("c", None),
+        ];
+        compare(expected, tokens);
+    }
+
     fn compare(expected: Vec<Token>, actual: Vec<Token>) {
         // println!("------------------------------");
         // println!("tokens   = {:?}", actual);
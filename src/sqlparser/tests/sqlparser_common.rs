@@ -590,6 +590,29 @@ fn parse_is_distinct_from() {
         verified_expr(sql)
     );
 }
+#[test]
+fn parse_at_time_zone() {
+    let sql = "SELECT ts AT TIME ZONE 'UTC' + INTERVAL '1' HOUR FROM t";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &Expr::BinaryOp {
+            left: Box::new(Expr::AtTimeZone {
+                timestamp: Box::new(Expr::Identifier(Ident::new("ts"))),
+                time_zone: Box::new(Expr::Value(Value::SingleQuotedString("UTC".to_string()))),
+            }),
+            op: BinaryOperator::Plus,
+            right: Box::new(Expr::Value(Value::Interval {
+                value: "1".to_string(),
+                leading_field: Some(DateTimeField::Hour),
+                leading_precision: None,
+                last_field: None,
+                fractional_seconds_precision: None,
+            })),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+}
+
 #[test]
 fn parse_is_not_distinct_from() {
     use self::Expr::*;
@@ -760,6 +783,31 @@ fn parse_ilike() {
     chk(true);
 }
 
+#[test]
+fn parse_similar_to() {
+    fn chk(negated: bool) {
+        let sql = &format!(
+            "SELECT * FROM customers WHERE name {}SIMILAR TO '%(a|b)' IS NULL",
+            if negated { "NOT " } else { "" }
+        );
+        let select = verified_only_select(sql);
+        assert_eq!(
+            Expr::IsNull(Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("name"))),
+                op: if negated {
+                    BinaryOperator::NotSimilarTo
+                } else {
+                    BinaryOperator::SimilarTo
+                },
+                right: Box::new(Expr::Value(Value::SingleQuotedString("%(a|b)".to_string()))),
+            })),
+            select.selection.unwrap()
+        );
+    }
+    chk(false);
+    chk(true);
+}
+
 #[test]
 fn parse_in_list() {
     fn chk(negated: bool) {
@@ -798,6 +846,40 @@ fn parse_in_subquery() {
     );
 }
 
+#[test]
+fn parse_some_all_subquery() {
+    let sql = "SELECT * FROM customers WHERE segment = SOME (SELECT segm FROM bar)";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("segment"))),
+            op: BinaryOperator::Eq,
+            right: Box::new(Expr::SomeOp(Box::new(Expr::Subquery(Box::new(
+                verified_query("SELECT segm FROM bar")
+            ))))),
+        },
+        select.selection.unwrap()
+    );
+
+    let sql = "SELECT * FROM customers WHERE segment > ALL (SELECT segm FROM bar)";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("segment"))),
+            op: BinaryOperator::Gt,
+            right: Box::new(Expr::AllOp(Box::new(Expr::Subquery(Box::new(
+                verified_query("SELECT segm FROM bar")
+            ))))),
+        },
+        select.selection.unwrap()
+    );
+
+    one_statement_parses_to(
+        "SELECT * FROM customers WHERE segment <> ANY (SELECT segm FROM bar)",
+        "SELECT * FROM customers WHERE segment <> SOME (SELECT segm FROM bar)",
+    );
+}
+
 #[test]
 fn parse_string_agg() {
     let sql = "SELECT a || b";
@@ -1738,10 +1820,10 @@ fn run_explain_analyze(query: &str, expected_verbose: bool, expected_analyze: bo
         Statement::Explain {
             describe_alias: _,
             analyze,
-            verbose,
+            options,
             statement,
         } => {
-            assert_eq!(verbose, expected_verbose);
+            assert_eq!(options.verbose, expected_verbose);
             assert_eq!(analyze, expected_analyze);
             assert_eq!("SELECT sqrt(id) FROM foo", statement.to_string());
         }
@@ -1761,6 +1843,46 @@ fn parse_explain_analyze_with_simple_select() {
     );
 }
 
+#[test]
+fn parse_explain_with_options() {
+    let stmt = one_statement_parses_to(
+        "EXPLAIN (DISTSQL, FORMAT JSON) CREATE MATERIALIZED VIEW mv AS SELECT * FROM foo",
+        "EXPLAIN (DISTSQL, FORMAT JSON) CREATE MATERIALIZED VIEW mv AS SELECT * FROM foo",
+    );
+    match stmt {
+        Statement::Explain {
+            analyze, options, ..
+        } => {
+            assert!(!analyze);
+            assert_eq!(
+                options,
+                ExplainOptions {
+                    verbose: false,
+                    distsql: true,
+                    format: ExplainFormat::Json,
+                }
+            );
+        }
+        _ => panic!("Unexpected Statement, must be Explain"),
+    }
+
+    one_statement_parses_to(
+        "EXPLAIN (VERBOSE true, DISTSQL off) SELECT 1",
+        "EXPLAIN VERBOSE SELECT 1",
+    );
+    one_statement_parses_to(
+        "EXPLAIN (ANALYZE, FORMAT TEXT) SELECT 1",
+        "EXPLAIN ANALYZE SELECT 1",
+    );
+    one_statement_parses_to("EXPLAIN (SELECT 1)", "EXPLAIN (SELECT 1)");
+
+    let res = parse_sql_statements("EXPLAIN (FORMAT XML) SELECT 1");
+    assert_eq!(
+        ParserError::ParserError("Expected TEXT | JSON, found: XML".to_string()),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_named_argument_function() {
     let sql = "SELECT FUN(a => '1', b => '2') FROM foo";
@@ -3346,12 +3468,14 @@ fn parse_create_index() {
             name,
             table_name,
             columns,
+            include,
             unique,
             if_not_exists,
         } => {
             assert_eq!("idx_name", name.to_string());
             assert_eq!("test", table_name.to_string());
             assert_eq!(indexed_columns, columns);
+            assert!(include.is_empty());
             assert!(unique);
             assert!(if_not_exists)
         }
@@ -3359,6 +3483,26 @@ fn parse_create_index() {
     }
 }
 
+#[test]
+fn parse_create_index_include() {
+    let sql = "CREATE INDEX idx_name ON test(name) INCLUDE(age,address)";
+    match verified_stmt(sql) {
+        Statement::CreateIndex {
+            columns,
+            include,
+            unique,
+            if_not_exists,
+            ..
+        } => {
+            assert_eq!(1, columns.len());
+            assert_eq!(vec![Ident::new("age"), Ident::new("address")], include);
+            assert!(!unique);
+            assert!(!if_not_exists)
+        }
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_grant() {
     let sql = "GRANT SELECT, INSERT, UPDATE (shape, size), USAGE, DELETE, TRUNCATE, REFERENCES, TRIGGER ON abc, def TO xyz, m WITH GRANT OPTION GRANTED BY jj";
@@ -16,7 +16,7 @@
 
 #[macro_use]
 mod test_utils;
-use risingwave_sqlparser::ast::Expr::{Identifier, MapAccess};
+use risingwave_sqlparser::ast::Expr::{ArrayIndex, Identifier};
 use risingwave_sqlparser::ast::*;
 use risingwave_sqlparser::parser::ParserError;
 use test_utils::*;
@@ -440,6 +440,8 @@ fn parse_set() {
 
     one_statement_parses_to("SET a TO b", "SET a = b");
     one_statement_parses_to("SET SESSION a = b", "SET a = b");
+    one_statement_parses_to("SET TIME ZONE 'UTC'", "SET timezone = 'UTC'");
+    one_statement_parses_to("SET TIME ZONE LOCAL", "SET timezone = LOCAL");
 
     assert_eq!(
         parse_sql_statements("SET"),
@@ -482,6 +484,25 @@ fn parse_show() {
     )
 }
 
+#[test]
+fn parse_reset() {
+    let stmt = verified_stmt("RESET a");
+    assert_eq!(
+        stmt,
+        Statement::ResetVariable {
+            variable: "a".into()
+        }
+    );
+
+    let stmt = verified_stmt("RESET ALL");
+    assert_eq!(
+        stmt,
+        Statement::ResetVariable {
+            variable: "ALL".into()
+        }
+    );
+}
+
 #[test]
 fn parse_deallocate() {
     let stmt = verified_stmt("DEALLOCATE a");
@@ -630,6 +651,24 @@ fn parse_pg_bitwise_binary_ops() {
     }
 }
 
+#[test]
+fn parse_pg_caret_precedence() {
+    // `^` is exponentiation, which binds tighter than `*`, so this parses as 2 * (a ^ 3).
+    let select = verified_only_select("SELECT 2 * a ^ 3");
+    assert_eq!(
+        SelectItem::UnnamedExpr(Expr::BinaryOp {
+            left: Box::new(Expr::Value(number("2"))),
+            op: BinaryOperator::Multiply,
+            right: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                op: BinaryOperator::BitwiseXor,
+                right: Box::new(Expr::Value(number("3"))),
+            }),
+        }),
+        select.projection[0]
+    );
+}
+
 #[test]
 fn parse_pg_unary_ops() {
     let pg_unary_ops = &[
@@ -668,6 +707,50 @@ fn parse_pg_postfix_factorial() {
     }
 }
 
+#[test]
+fn parse_pg_json_ops() {
+    let pg_json_ops = &[
+        ("->", BinaryOperator::PGJsonGet),
+        ("->>", BinaryOperator::PGJsonGetText),
+        ("#>", BinaryOperator::PGJsonGetPath),
+        ("@>", BinaryOperator::PGJsonContains),
+        ("?", BinaryOperator::PGJsonExists),
+    ];
+
+    for (str_op, op) in pg_json_ops {
+        let select = verified_only_select(&format!("SELECT a {} 'b'", &str_op));
+        assert_eq!(
+            SelectItem::UnnamedExpr(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                op: op.clone(),
+                right: Box::new(Expr::Value(Value::SingleQuotedString("b".into()))),
+            }),
+            select.projection[0]
+        );
+    }
+
+    // The json operators bind tighter than comparisons.
+    let select = verified_only_select("SELECT a -> 'b' ->> 'c' = 'd'");
+    assert_eq!(
+        SelectItem::UnnamedExpr(Expr::BinaryOp {
+            left: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier(Ident::new("a"))),
+                    op: BinaryOperator::PGJsonGet,
+                    right: Box::new(Expr::Value(Value::SingleQuotedString("b".into()))),
+                }),
+                op: BinaryOperator::PGJsonGetText,
+                right: Box::new(Expr::Value(Value::SingleQuotedString("c".into()))),
+            }),
+            op: BinaryOperator::Eq,
+            right: Box::new(Expr::Value(Value::SingleQuotedString("d".into()))),
+        }),
+        select.projection[0]
+    );
+
+    verified_stmt("SELECT CAST('{}' AS JSONB)");
+}
+
 #[test]
 fn parse_pg_regex_match_ops() {
     let pg_regex_match_ops = &[
@@ -691,48 +774,63 @@ fn parse_pg_regex_match_ops() {
 }
 
 #[test]
-fn parse_map_access_expr() {
-    let zero = "0".to_string();
+fn parse_array_index_expr() {
+    let num = |n: &str| Box::new(Expr::Value(Value::Number(n.to_string(), false)));
     let sql = "SELECT foo[0] FROM foos";
     let select = verified_only_select(sql);
     assert_eq!(
-        &MapAccess {
-            column: Box::new(Identifier(Ident {
+        &ArrayIndex {
+            obj: Box::new(Identifier(Ident {
                 value: "foo".to_string(),
                 quote_style: None
             })),
-            keys: vec![Expr::Value(Value::Number(zero.clone(), false))]
+            index: num("0"),
         },
         expr_from_projection(only(&select.projection)),
     );
-    let sql = "SELECT foo[0][0] FROM foos";
+    let sql = "SELECT foo[0][1] FROM foos";
     let select = verified_only_select(sql);
     assert_eq!(
-        &MapAccess {
-            column: Box::new(Identifier(Ident {
-                value: "foo".to_string(),
-                quote_style: None
-            })),
-            keys: vec![
-                Expr::Value(Value::Number(zero.clone(), false)),
-                Expr::Value(Value::Number(zero.clone(), false))
-            ]
+        &ArrayIndex {
+            obj: Box::new(ArrayIndex {
+                obj: Box::new(Identifier(Ident {
+                    value: "foo".to_string(),
+                    quote_style: None
+                })),
+                index: num("0"),
+            }),
+            index: num("1"),
         },
         expr_from_projection(only(&select.projection)),
     );
-    let sql = r#"SELECT bar[0]["baz"]["fooz"] FROM foos"#;
+    // The index can be any expression, and subscripts bind tighter than binary operators.
+    let sql = "SELECT 1 + ARRAY[1, 2][i + 1]";
     let select = verified_only_select(sql);
     assert_eq!(
-        &MapAccess {
-            column: Box::new(Identifier(Ident {
-                value: "bar".to_string(),
-                quote_style: None
-            })),
-            keys: vec![
-                Expr::Value(Value::Number(zero, false)),
-                Expr::Value(Value::SingleQuotedString("baz".to_string())),
-                Expr::Value(Value::SingleQuotedString("fooz".to_string()))
-            ]
+        &Expr::BinaryOp {
+            left: num("1"),
+            op: BinaryOperator::Plus,
+            right: Box::new(ArrayIndex {
+                obj: Box::new(Expr::Array(vec![*num("1"), *num("2")])),
+                index: Box::new(Expr::BinaryOp {
+                    left: Box::new(Identifier(Ident::new("i"))),
+                    op: BinaryOperator::Plus,
+                    right: num("1"),
+                }),
+            }),
+        },
+        expr_from_projection(only(&select.projection)),
+    );
+}
+
+#[test]
+fn parse_array_overlap() {
+    let select = verified_only_select("SELECT a && b");
+    assert_eq!(
+        &Expr::BinaryOp {
+            left: Box::new(Identifier(Ident::new("a"))),
+            op: BinaryOperator::PGOverlap,
+            right: Box::new(Identifier(Ident::new("b"))),
         },
         expr_from_projection(only(&select.projection)),
     );
@@ -19,7 +19,7 @@ use risingwave_common::array::Row;
 use risingwave_common::catalog::{ColumnDesc, ColumnId};
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::Datum;
-use risingwave_common::util::ordered::deserialize_column_id;
+use risingwave_common::util::ordered::{deserialize_column_id, SENTINEL_CELL_ID};
 use risingwave_common::util::value_encoding::deserialize_cell;
 
 #[derive(Clone)]
@@ -33,6 +33,11 @@ pub struct CellBasedRowDeserializer {
     /// we have to know the cell id of each datum. So `pk_bytes` serves as an additional check
     /// which should also be done on the caller side.
     pk_bytes: Option<Vec<u8>>,
+
+    /// The largest column id written with the current row, read from its sentinel cell. Columns
+    /// added after the row was written are absent from it and read as their default values.
+    /// `None` if the sentinel cell carries no column id.
+    max_column_id: Option<i32>,
 }
 impl CellBasedRowDeserializer {
     pub fn new(table_column_descs: Vec<ColumnDesc>) -> Self {
@@ -47,6 +52,7 @@ impl CellBasedRowDeserializer {
             columns,
             data: vec![None; num_cells],
             pk_bytes: None,
+            max_column_id: None,
         }
     }
 
@@ -72,7 +78,11 @@ impl CellBasedRowDeserializer {
             self.pk_bytes = Some(cur_pk_bytes.to_vec());
         }
 
-        if let Some((column_desc, index)) = self.columns.get(&cell_id) {
+        if cell_id == SENTINEL_CELL_ID {
+            if !cell.is_empty() {
+                self.max_column_id = Some(deserialize_column_id(&cell[..])?.get_id());
+            }
+        } else if let Some((column_desc, index)) = self.columns.get(&cell_id) {
             if let Some(datum) = deserialize_cell(&mut cell.clone(), &column_desc.data_type)? {
                 let old = self.data.get_mut(*index).unwrap().replace(datum);
                 assert!(old.is_none());
@@ -87,7 +97,17 @@ impl CellBasedRowDeserializer {
     /// Take the remaining data out of the deserializer.
     pub fn take(&mut self) -> Option<(Vec<u8>, Row)> {
         let cur_pk_bytes = self.pk_bytes.take();
+        let max_column_id = self.max_column_id.take();
         cur_pk_bytes.map(|bytes| {
+            for (column_desc, index) in self.columns.values() {
+                let datum = &mut self.data[*index];
+                if datum.is_none()
+                    && column_desc.default_value.is_some()
+                    && max_column_id.map_or(true, |id| column_desc.column_id.get_id() > id)
+                {
+                    *datum = column_desc.default_value.clone();
+                }
+            }
             let ret = self.data.iter_mut().map(Option::take).collect::<Vec<_>>();
             (bytes, Row(ret))
         })
@@ -100,6 +120,7 @@ impl CellBasedRowDeserializer {
             datum.take();
         });
         self.pk_bytes.take();
+        self.max_column_id.take();
     }
 }
 
@@ -170,4 +191,53 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_cell_based_deserializer_default_value() {
+        let column_ids = vec![ColumnId::from(0), ColumnId::from(1)];
+        let pk1 = vec![0u8, 0u8, 0u8, 0u8];
+        let pk2 = vec![0u8, 0u8, 0u8, 1u8];
+        // `row1` is written before column 2 is added, and `row2` after with a null in it.
+        let row1 = Row(vec![Some(ScalarImpl::Int32(1)), None]);
+        let row2 = Row(vec![Some(ScalarImpl::Int32(2)), None, None]);
+        let bytes1 = serialize_pk_and_row_state(&pk1, &Some(row1), &column_ids).unwrap();
+        let bytes2 = serialize_pk_and_row_state(
+            &pk2,
+            &Some(row2),
+            &[column_ids.clone(), vec![ColumnId::from(2)]].concat(),
+        )
+        .unwrap();
+
+        let mut added_column = ColumnDesc::unnamed(ColumnId::from(2), DataType::Int64);
+        added_column.default_value = Some(ScalarImpl::Int64(42));
+        let table_column_descs = vec![
+            ColumnDesc::unnamed(column_ids[0], DataType::Int32),
+            ColumnDesc::unnamed(column_ids[1], DataType::Int32),
+            added_column,
+        ];
+
+        let mut result = vec![];
+        let mut deserializer = CellBasedRowDeserializer::new(table_column_descs);
+        for (key_bytes, value_bytes) in [bytes1, bytes2].concat() {
+            let pk_and_row = deserializer
+                .deserialize(&Bytes::from(key_bytes), &Bytes::from(value_bytes.unwrap()))
+                .unwrap();
+            if let Some(pk_and_row) = pk_and_row {
+                result.push(pk_and_row.1);
+            }
+        }
+        result.push(deserializer.take().unwrap().1);
+
+        assert_eq!(
+            result,
+            vec![
+                Row(vec![
+                    Some(ScalarImpl::Int32(1)),
+                    None,
+                    Some(ScalarImpl::Int64(42))
+                ]),
+                Row(vec![Some(ScalarImpl::Int32(2)), None, None]),
+            ]
+        );
+    }
 }
@@ -124,8 +124,26 @@ impl<S: StateStore> Keyspace<S> {
         limit: Option<usize>,
         epoch: u64,
     ) -> StorageResult<Vec<(Bytes, Bytes)>> {
-        let start_key_with_prefix = [self.prefix.as_slice(), start_key.as_slice()].concat();
-        let range = start_key_with_prefix..next_key(self.prefix.as_slice());
+        self.scan_with_range(start_key, None, limit, epoch).await
+    }
+
+    /// Scans `limit` keys from the keyspace between an inclusive `start_key` and an exclusive
+    /// `end_key` and get their values. If `end_key` is None, keys till the end of the keyspace will
+    /// be scanned. Note that the prefix of this keyspace will be stripped. The returned values are
+    /// based on a snapshot corresponding to the given `epoch`
+    pub async fn scan_with_range(
+        &self,
+        start_key: Vec<u8>,
+        end_key: Option<Vec<u8>>,
+        limit: Option<usize>,
+        epoch: u64,
+    ) -> StorageResult<Vec<(Bytes, Bytes)>> {
+        let start_key_with_prefix = self.prefixed_key(start_key);
+        let end_key_with_prefix = match end_key {
+            Some(end_key) => self.prefixed_key(end_key),
+            None => next_key(self.prefix.as_slice()),
+        };
+        let range = start_key_with_prefix..end_key_with_prefix;
         let mut pairs = self.store.scan(range, limit, epoch).await?;
         pairs
             .iter_mut()
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::ops::Bound::{self, *};
+use std::ops::RangeBounds;
 use std::sync::Arc;
 
 use bytes::Bytes;
@@ -21,6 +23,7 @@ use risingwave_common::array::column::Column;
 use risingwave_common::array::{DataChunk, Row};
 use risingwave_common::catalog::{ColumnDesc, ColumnId, Field, Schema};
 use risingwave_common::error::{ErrorCode, RwError};
+use risingwave_common::types::Datum;
 use risingwave_common::util::hash_util::CRC32FastBuilder;
 use risingwave_common::util::ordered::*;
 use risingwave_common::util::sort_util::OrderType;
@@ -121,6 +124,11 @@ impl<S: StateStore> CellBasedTable<S> {
         Self::new(keyspace, column_descs, None, stats)
     }
 
+    /// Waits until the data of `epoch` can be read from the table.
+    pub async fn wait_epoch(&self, epoch: u64) -> StorageResult<()> {
+        self.keyspace.state_store().wait_epoch(epoch).await
+    }
+
     // cell-based interface
     pub async fn get_row(&self, pk: &Row, epoch: u64) -> StorageResult<Option<Row>> {
         // get row by state_store get
@@ -218,12 +226,13 @@ impl<S: StateStore> CellBasedTable<S> {
                         local.put(key, StorageValue::new_put(value_meta, value))
                     }
                 }
-                RowOp::Delete(old_row) => {
-                    // TODO(wcy-fdu): only serialize key on deletion
-                    let bytes = self
-                        .cell_based_row_serializer
-                        .serialize(&arrange_key_buf, old_row, &self.column_ids)
-                        .map_err(err)?;
+                RowOp::Delete(_) => {
+                    // Delete the cells of all columns rather than the non-null ones in the old row,
+                    // so that no cell is left behind when the old row doesn't carry the stored
+                    // value, e.g. for a dropped column.
+                    let bytes =
+                        serialize_pk_and_row_state(&arrange_key_buf, &None, &self.column_ids)
+                            .map_err(err)?;
                     for (key, _) in bytes {
                         local.delete_with_value_meta(key, value_meta);
                     }
@@ -282,6 +291,78 @@ impl<S: StateStore> CellBasedTable<S> {
         CellBasedTableRowIter::new(
             self.keyspace.clone(),
             self.column_descs.clone(),
+            vec![],
+            None,
+            epoch,
+            self.stats.clone(),
+        )
+        .await
+    }
+
+    /// Returns an iterator over the rows whose pk starts with `pk_prefix` and whose next pk column
+    /// is within `next_col_bounds`, from a snapshot corresponding to the given `epoch`.
+    pub async fn iter_with_pk_bounds(
+        &self,
+        epoch: u64,
+        pk_prefix: &Row,
+        next_col_bounds: impl RangeBounds<Datum>,
+    ) -> StorageResult<CellBasedTableRowIter<S>> {
+        let pk_serializer = self.pk_serializer.as_ref().expect("pk_serializer is None");
+        let prefix_len = pk_prefix.size();
+        let prefix_key = serialize_pk(pk_prefix, &pk_serializer.prefix(prefix_len)).map_err(err)?;
+
+        let is_desc = match pk_serializer.order_types().get(prefix_len) {
+            Some(order_type) => *order_type == OrderType::Descending,
+            None => {
+                assert!(
+                    matches!(next_col_bounds.start_bound(), Unbounded)
+                        && matches!(next_col_bounds.end_bound(), Unbounded),
+                    "no pk column to bound"
+                );
+                false
+            }
+        };
+        let serialize_with_next_col = |datum: &Datum| {
+            let mut pk = pk_prefix.clone();
+            pk.0.push(datum.clone());
+            serialize_pk(&pk, &pk_serializer.prefix(prefix_len + 1)).map_err(err)
+        };
+        // The keys of the rows whose next pk column equals to a value start with the key serialized
+        // from the value, and are before the next key of it.
+        let start_key = |bound: Bound<&Datum>| -> StorageResult<Vec<u8>> {
+            Ok(match bound {
+                Included(datum) => serialize_with_next_col(datum)?,
+                Excluded(datum) => next_key(&serialize_with_next_col(datum)?),
+                Unbounded => prefix_key.clone(),
+            })
+        };
+        let end_key = |bound: Bound<&Datum>| -> StorageResult<Vec<u8>> {
+            Ok(match bound {
+                Included(datum) => next_key(&serialize_with_next_col(datum)?),
+                Excluded(datum) => serialize_with_next_col(datum)?,
+                Unbounded => next_key(&prefix_key),
+            })
+        };
+        // A descending column is serialized in reversed order, so its bounds are reversed too.
+        let (start_key, end_key) = if is_desc {
+            (
+                start_key(next_col_bounds.end_bound())?,
+                end_key(next_col_bounds.start_bound())?,
+            )
+        } else {
+            (
+                start_key(next_col_bounds.start_bound())?,
+                end_key(next_col_bounds.end_bound())?,
+            )
+        };
+        // An empty end key means there's no key after the range.
+        let end_key = Some(end_key).filter(|key| !key.is_empty());
+
+        CellBasedTableRowIter::new(
+            self.keyspace.clone(),
+            self.column_descs.clone(),
+            start_key,
+            end_key,
             epoch,
             self.stats.clone(),
         )
@@ -291,6 +372,26 @@ impl<S: StateStore> CellBasedTable<S> {
     pub fn schema(&self) -> &Schema {
         &self.schema
     }
+
+    /// Returns the number of the pk columns, which is unknown for an adhoc table.
+    pub fn pk_len(&self) -> Option<usize> {
+        self.pk_serializer
+            .as_ref()
+            .map(|pk_serializer| pk_serializer.order_types().len())
+    }
+
+    /// Appends columns added by `ALTER TABLE ADD COLUMN`. Rows written before will read these
+    /// columns as their default values.
+    pub fn add_columns(&mut self, column_descs: Vec<ColumnDesc>) {
+        for column_desc in column_descs {
+            self.schema.fields.push(Field::with_name(
+                column_desc.data_type.clone(),
+                column_desc.name.clone(),
+            ));
+            self.column_ids.push(column_desc.column_id);
+            self.column_descs.push(column_desc);
+        }
+    }
 }
 
 fn generate_column_id(column_descs: &[ColumnDesc]) -> Vec<ColumnId> {
@@ -307,6 +408,10 @@ pub struct CellBasedTableRowIter<S: StateStore> {
     next_idx: usize,
     /// A bool to indicate whether there are more data to fetch from state store
     done: bool,
+    /// The inclusive start key of the range to scan
+    start_key: Vec<u8>,
+    /// The exclusive end key of the range to scan, or `None` to scan till the end of the keyspace
+    end_key: Option<Vec<u8>>,
     /// An epoch representing the read snapshot
     epoch: u64,
     /// Cell-based row deserializer
@@ -321,18 +426,23 @@ impl<S: StateStore> CellBasedTableRowIter<S> {
     async fn new(
         keyspace: Keyspace<S>,
         table_descs: Vec<ColumnDesc>,
+        start_key: Vec<u8>,
+        end_key: Option<Vec<u8>>,
         epoch: u64,
         _stats: Arc<StateStoreMetrics>,
     ) -> StorageResult<Self> {
         keyspace.state_store().wait_epoch(epoch).await?;
 
         let cell_based_row_deserializer = CellBasedRowDeserializer::new(table_descs);
+        let done = matches!(&end_key, Some(end_key) if &start_key >= end_key);
 
         let iter = Self {
             keyspace,
             buf: vec![],
             next_idx: 0,
-            done: false,
+            done,
+            start_key,
+            end_key,
             epoch,
             cell_based_row_deserializer,
             _stats,
@@ -346,13 +456,23 @@ impl<S: StateStore> CellBasedTableRowIter<S> {
         if self.buf.is_empty() {
             self.buf = self
                 .keyspace
-                .scan(Some(Self::SCAN_LIMIT), self.epoch)
+                .scan_with_range(
+                    self.start_key.clone(),
+                    self.end_key.clone(),
+                    Some(Self::SCAN_LIMIT),
+                    self.epoch,
+                )
                 .await?;
         } else {
             let last_key = self.buf.last().unwrap().0.clone();
             let buf = self
                 .keyspace
-                .scan_with_start_key(last_key.to_vec(), Some(Self::SCAN_LIMIT), self.epoch)
+                .scan_with_range(
+                    last_key.to_vec(),
+                    self.end_key.clone(),
+                    Some(Self::SCAN_LIMIT),
+                    self.epoch,
+                )
                 .await?;
             assert!(!buf.is_empty());
             assert_eq!(buf.first().as_ref().unwrap().0, last_key);
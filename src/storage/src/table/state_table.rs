@@ -105,6 +105,12 @@ impl<S: StateStore> StateTable<S> {
     pub async fn iter(&self, _pk: Row) -> StorageResult<StateTableRowIter<S>> {
         todo!()
     }
+
+    /// Appends columns added by `ALTER TABLE ADD COLUMN`. The mem table must have been committed.
+    pub fn add_columns(&mut self, column_descs: Vec<ColumnDesc>) {
+        assert!(self.mem_table.buffer.is_empty());
+        self.cell_based_table.add_columns(column_descs);
+    }
 }
 
 pub struct StateTableRowIter<S: StateStore> {
@@ -0,0 +1,179 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use futures::StreamExt;
+use futures_async_stream::try_stream;
+use risingwave_common::array::column::Column;
+use risingwave_common::array::{I64Array, StreamChunk};
+use risingwave_common::catalog::Schema;
+use risingwave_common::error::Result;
+use risingwave_common::types::DataType;
+
+use super::error::StreamExecutorError;
+use super::{BoxedExecutor, BoxedMessageStream, Executor, ExecutorInfo, Message, PkIndicesRef};
+
+/// `ExpandExecutor` duplicates each input row once for every column subset. In the `i`-th copy,
+/// the columns not in `column_subsets[i]` are set to NULL, while the original columns and the flag
+/// `i` are appended after them. The op of the row is kept in all copies.
+pub struct ExpandExecutor {
+    input: BoxedExecutor,
+    info: ExecutorInfo,
+    column_subsets: Vec<Vec<usize>>,
+}
+
+impl ExpandExecutor {
+    pub fn new(input: BoxedExecutor, info: ExecutorInfo, column_subsets: Vec<Vec<usize>>) -> Self {
+        Self {
+            input,
+            info,
+            column_subsets,
+        }
+    }
+
+    #[try_stream(ok = Message, error = StreamExecutorError)]
+    async fn execute_inner(self: Box<Self>) {
+        let Self {
+            input,
+            column_subsets,
+            ..
+        } = *self;
+        let data_types = input.schema().data_types();
+
+        #[for_await]
+        for msg in input.execute() {
+            match msg? {
+                Message::Chunk(chunk) => {
+                    let chunk = chunk.compact().map_err(StreamExecutorError::executor_v1)?;
+                    let (data_chunk, ops) = chunk.into_parts();
+                    let cardinality = data_chunk.cardinality();
+                    let (columns, _) = data_chunk.into_parts();
+
+                    for (flag, subset) in column_subsets.iter().enumerate() {
+                        let mut new_columns = Vec::with_capacity(columns.len() * 2 + 1);
+                        for (idx, column) in columns.iter().enumerate() {
+                            if subset.contains(&idx) {
+                                new_columns.push(column.clone());
+                            } else {
+                                new_columns.push(
+                                    null_column(&data_types[idx], cardinality)
+                                        .map_err(StreamExecutorError::executor_v1)?,
+                                );
+                            }
+                        }
+                        new_columns.extend(columns.iter().cloned());
+                        let flags = I64Array::from_slice(&vec![Some(flag as i64); cardinality])
+                            .map_err(StreamExecutorError::executor_v1)?;
+                        new_columns.push(Column::new(Arc::new(flags.into())));
+                        yield Message::Chunk(StreamChunk::new(ops.clone(), new_columns, None));
+                    }
+                }
+                m => yield m,
+            }
+        }
+    }
+}
+
+fn null_column(data_type: &DataType, cardinality: usize) -> Result<Column> {
+    let mut builder = data_type.create_array_builder(cardinality)?;
+    for _ in 0..cardinality {
+        builder.append_null()?;
+    }
+    Ok(Column::new(Arc::new(builder.finish()?)))
+}
+
+impl Executor for ExpandExecutor {
+    fn execute(self: Box<Self>) -> BoxedMessageStream {
+        self.execute_inner().boxed()
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.info.schema
+    }
+
+    fn pk_indices(&self) -> PkIndicesRef {
+        &self.info.pk_indices
+    }
+
+    fn identity(&self) -> &str {
+        &self.info.identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use risingwave_common::array::stream_chunk::StreamChunkTestExt;
+    use risingwave_common::catalog::{Field, Schema};
+    use risingwave_common::types::DataType;
+
+    use super::ExpandExecutor;
+    use crate::executor::test_utils::MockSource;
+    use crate::executor::{Executor, ExecutorInfo, StreamChunk};
+
+    #[madsim::test]
+    async fn test_expand() {
+        let chunk1 = StreamChunk::from_pretty(
+            " I I I
+            + 1 4 1
+            + 5 2 2 D
+            + 6 6 3
+            - 7 5 4",
+        );
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ],
+        };
+        let source = MockSource::with_chunks(schema.clone(), vec![2], vec![chunk1]);
+
+        let column_subsets = vec![vec![0, 1], vec![1, 2]];
+        let expand = Box::new(ExpandExecutor::new(
+            Box::new(source),
+            ExecutorInfo {
+                // TODO: the schema is incorrect, but it seems useless here.
+                schema,
+                pk_indices: vec![5, 6],
+                identity: "ExpandExecutor".to_string(),
+            },
+            column_subsets,
+        ));
+        let mut expand = expand.execute();
+
+        let chunk = expand.next().await.unwrap().unwrap().into_chunk().unwrap();
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                " I I I I I I I
+                + 1 4 . 1 4 1 0
+                + 6 6 . 6 6 3 0
+                - 7 5 . 7 5 4 0"
+            )
+        );
+
+        let chunk = expand.next().await.unwrap().unwrap().into_chunk().unwrap();
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                " I I I I I I I
+                + . 4 1 1 4 1 1
+                + . 6 3 6 6 3 1
+                - . 5 4 7 5 4 1"
+            )
+        );
+    }
+}
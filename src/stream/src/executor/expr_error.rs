@@ -0,0 +1,230 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use itertools::Itertools;
+use risingwave_common::array::column::Column;
+use risingwave_common::array::{
+    ArrayBuilder, ArrayRef, DataChunk, I64ArrayBuilder, Op, RowRef, StreamChunk, Utf8ArrayBuilder,
+};
+use risingwave_common::buffer::{Bitmap, BitmapBuilder};
+use risingwave_common::catalog::TableId;
+use risingwave_common::error::Result;
+use risingwave_common::types::DataType;
+use risingwave_expr::expr::BoxedExpression;
+use risingwave_pb::stream_plan::expr_error_handling::Mode;
+use risingwave_pb::stream_plan::ExprErrorHandling;
+use risingwave_source::{SourceDesc, SourceManager};
+
+use super::error::StreamExecutorError;
+use super::StreamExecutorResult;
+
+/// A row that failed to be evaluated, pending to be reported.
+struct ExprError {
+    input: String,
+    error: String,
+}
+
+/// Decides what to do when an expression fails to evaluate on a chunk.
+///
+/// In [`Mode::Strict`], the error is returned and fails the actor. Otherwise the chunk is
+/// re-evaluated row by row: values of the failed rows become NULL, and the failed rows are
+/// reported to the error table of the materialized view on [`ExprErrorHandler::flush`].
+///
+/// Rows are written to the error table without waiting for them to be taken, and are not covered
+/// by the checkpoint of the materialized view: the same error may be reported more than once if
+/// the actor is recovered.
+pub struct ExprErrorHandler {
+    mode: Mode,
+
+    /// The table source of the error table, with columns `[_row_id, executor, input, error]`.
+    error_table: Option<SourceDesc>,
+
+    /// Errors reported since the last flush.
+    errors: Vec<ExprError>,
+}
+
+impl ExprErrorHandler {
+    /// Fails on the first evaluation error.
+    pub fn strict() -> Self {
+        Self::new(Mode::Strict, None)
+    }
+
+    pub fn new(mode: Mode, error_table: Option<SourceDesc>) -> Self {
+        Self {
+            mode,
+            error_table,
+            errors: vec![],
+        }
+    }
+
+    pub fn from_prost(
+        error_handling: Option<&ExprErrorHandling>,
+        source_manager: &dyn SourceManager,
+    ) -> Result<Self> {
+        let error_handling = match error_handling {
+            Some(error_handling) => error_handling,
+            None => return Ok(Self::strict()),
+        };
+        let mode = error_handling.get_mode()?;
+        let error_table = match mode {
+            Mode::Strict => None,
+            Mode::SetNull | Mode::DropRow => Some(
+                source_manager.get_source(&TableId::new(error_handling.error_table_source_id))?,
+            ),
+        };
+        Ok(Self::new(mode, error_table))
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Evaluates `expr` on `data_chunk`, whose columns are of `input_types`.
+    ///
+    /// Returns the output array and, if the chunk had to be evaluated row by row, a bitmap of the
+    /// rows that failed.
+    pub fn eval(
+        &mut self,
+        expr: &BoxedExpression,
+        data_chunk: &DataChunk,
+        input_types: &[DataType],
+    ) -> StreamExecutorResult<(ArrayRef, Option<Bitmap>)> {
+        match expr.eval(data_chunk) {
+            Ok(array) => return Ok((array, None)),
+            Err(err) if self.mode == Mode::Strict => {
+                return Err(StreamExecutorError::eval_error(err))
+            }
+            Err(_) => {}
+        }
+
+        let capacity = data_chunk.capacity();
+        let mut builder = expr
+            .return_type()
+            .create_array_builder(capacity)
+            .map_err(StreamExecutorError::eval_error)?;
+        let mut failed = BitmapBuilder::with_capacity(capacity);
+
+        for i in 0..capacity {
+            let visible = match data_chunk.visibility() {
+                Some(visibility) => visibility
+                    .is_set(i)
+                    .map_err(StreamExecutorError::eval_error)?,
+                None => true,
+            };
+            let row = data_chunk.row_at_unchecked_vis(i);
+            let datum = if visible {
+                let row_chunk = DataChunk::from_rows(&[row.to_owned_row()], input_types)
+                    .map_err(StreamExecutorError::eval_error)?;
+                match expr.eval(&row_chunk) {
+                    Ok(array) => {
+                        failed.append(false);
+                        array.datum_at(0)
+                    }
+                    Err(err) => {
+                        failed.append(true);
+                        self.errors.push(ExprError {
+                            input: format_row(&row),
+                            error: err.to_string(),
+                        });
+                        None
+                    }
+                }
+            } else {
+                failed.append(false);
+                None
+            };
+            builder
+                .append_datum(&datum)
+                .map_err(StreamExecutorError::eval_error)?;
+        }
+
+        let array = builder.finish().map_err(StreamExecutorError::eval_error)?;
+        Ok((Arc::new(array), Some(failed.finish())))
+    }
+
+    /// Reports the errors since the last flush. Should be called once per chunk.
+    pub fn flush(&mut self, identity: &str) {
+        if self.errors.is_empty() {
+            return;
+        }
+        let errors = std::mem::take(&mut self.errors);
+
+        tracing::warn!(
+            "{} failed to evaluate {} rows, e.g. {}: {}",
+            identity,
+            errors.len(),
+            errors[0].input,
+            errors[0].error
+        );
+
+        if let Some(error_table) = &self.error_table {
+            if let Err(err) = write_error_table(error_table, identity, &errors) {
+                tracing::warn!("{} failed to write the error table: {}", identity, err);
+            }
+        }
+    }
+}
+
+impl Debug for ExprErrorHandler {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExprErrorHandler")
+            .field("mode", &self.mode)
+            .finish()
+    }
+}
+
+/// Formats a row like `(1,NULL,abc)`.
+fn format_row(row: &RowRef<'_>) -> String {
+    format!(
+        "({})",
+        row.values()
+            .map(|datum| match datum {
+                Some(scalar) => scalar.to_string(),
+                None => "NULL".to_owned(),
+            })
+            .join(",")
+    )
+}
+
+fn write_error_table(source_desc: &SourceDesc, identity: &str, errors: &[ExprError]) -> Result<()> {
+    let source = source_desc
+        .source
+        .as_table_v2()
+        .expect("error table is not a table source");
+
+    let len = errors.len();
+    let mut row_id_builder = I64ArrayBuilder::new(len)?;
+    let mut executor_builder = Utf8ArrayBuilder::new(len)?;
+    let mut input_builder = Utf8ArrayBuilder::new(len)?;
+    let mut error_builder = Utf8ArrayBuilder::new(len)?;
+    for error in errors {
+        row_id_builder.append(Some(source_desc.next_row_id()))?;
+        executor_builder.append(Some(identity))?;
+        input_builder.append(Some(error.input.as_str()))?;
+        error_builder.append(Some(error.error.as_str()))?;
+    }
+    let columns = vec![
+        Column::from(row_id_builder.finish()?),
+        Column::from(executor_builder.finish()?),
+        Column::from(input_builder.finish()?),
+        Column::from(error_builder.finish()?),
+    ];
+
+    // Don't wait for the chunk to be taken, so that the stream is not blocked by the error table.
+    source.write_chunk(StreamChunk::new(vec![Op::Insert; len], columns, None))?;
+    Ok(())
+}
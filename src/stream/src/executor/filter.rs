@@ -21,7 +21,7 @@ use risingwave_common::catalog::Schema;
 use risingwave_expr::expr::BoxedExpression;
 
 use super::{
-    Executor, ExecutorInfo, PkIndicesRef, SimpleExecutor, SimpleExecutorWrapper,
+    Executor, ExecutorInfo, ExprErrorHandler, PkIndicesRef, SimpleExecutor, SimpleExecutorWrapper,
     StreamExecutorResult,
 };
 use crate::executor::error::StreamExecutorError;
@@ -37,6 +37,13 @@ impl FilterExecutor {
             inner: SimpleFilterExecutor::new(info, expr, executor_id),
         }
     }
+
+    /// Evaluates the expression non-strictly according to `error_handler`. Rows that fail to be
+    /// evaluated are filtered out.
+    pub fn with_error_handler(mut self, error_handler: ExprErrorHandler) -> Self {
+        self.inner.error_handler = error_handler;
+        self
+    }
 }
 
 /// `FilterExecutor` filters data with the `expr`. The `expr` takes a chunk of data,
@@ -49,6 +56,9 @@ pub struct SimpleFilterExecutor {
     /// Expression of the current filter, note that the filter must always have the same output for
     /// the same input.
     expr: BoxedExpression,
+
+    /// Decides what to do with the rows that fail to be evaluated.
+    error_handler: ExprErrorHandler,
 }
 
 impl SimpleFilterExecutor {
@@ -60,6 +70,7 @@ impl SimpleFilterExecutor {
                 identity: format!("FilterExecutor {:X}", executor_id),
             },
             expr,
+            error_handler: ExprErrorHandler::strict(),
         }
     }
 }
@@ -68,6 +79,7 @@ impl Debug for SimpleFilterExecutor {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("FilterExecutor")
             .field("expr", &self.expr)
+            .field("error_handler", &self.error_handler)
             .finish()
     }
 }
@@ -82,10 +94,12 @@ impl SimpleExecutor for SimpleFilterExecutor {
         let (ops, columns, _visibility) = chunk.into_inner();
         let data_chunk = DataChunk::builder().columns(columns).build();
 
-        let pred_output = self
-            .expr
-            .eval(&data_chunk)
-            .map_err(StreamExecutorError::eval_error)?;
+        let input_types = self.info.schema.data_types();
+        // The predicate of a failed row is NULL, so the row is filtered out.
+        let (pred_output, _) = self
+            .error_handler
+            .eval(&self.expr, &data_chunk, &input_types)?;
+        self.error_handler.flush(&self.info.identity);
 
         let (columns, visibility) = data_chunk.into_parts();
 
@@ -181,8 +195,9 @@ mod tests {
     use risingwave_common::catalog::{Field, Schema};
     use risingwave_common::types::DataType;
     use risingwave_expr::expr::expr_binary_nonnull::new_binary_expr;
-    use risingwave_expr::expr::InputRefExpression;
+    use risingwave_expr::expr::{InputRefExpression, LiteralExpression};
     use risingwave_pb::expr::expr_node::Type;
+    use risingwave_pb::stream_plan::expr_error_handling::Mode;
 
     use super::super::test_utils::MockSource;
     use super::super::*;
@@ -257,4 +272,55 @@ mod tests {
 
         assert!(filter.next().await.unwrap().unwrap().is_stop());
     }
+
+    #[madsim::test]
+    async fn test_filter_non_strict() {
+        let chunk = StreamChunk::from_pretty(
+            "  I I
+            +  6 2
+            +  1 0
+            U- 4 1  // fails on the new value
+            U+ 4 0  // expect Delete",
+        );
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ],
+        };
+        let source = MockSource::with_chunks(schema, PkIndices::new(), vec![chunk]);
+
+        // a / b > 1
+        let left_expr = InputRefExpression::new(DataType::Int64, 0);
+        let right_expr = InputRefExpression::new(DataType::Int64, 1);
+        let div_expr = new_binary_expr(
+            Type::Divide,
+            DataType::Int64,
+            Box::new(left_expr),
+            Box::new(right_expr),
+        );
+        let test_expr = new_binary_expr(
+            Type::GreaterThan,
+            DataType::Boolean,
+            div_expr,
+            Box::new(LiteralExpression::new(DataType::Int64, Some(1i64.into()))),
+        );
+        let filter = FilterExecutor::new(Box::new(source), test_expr, 1)
+            .with_error_handler(ExprErrorHandler::new(Mode::DropRow, None));
+        let mut filter = Box::new(filter).execute();
+
+        let chunk = filter.next().await.unwrap().unwrap().into_chunk().unwrap();
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                "  I I
+                +  6 2
+                +  1 0 D
+                -  4 1
+                U+ 4 0 D",
+            )
+        );
+
+        assert!(filter.next().await.unwrap().unwrap().is_stop());
+    }
 }
@@ -23,15 +23,17 @@ use madsim::collections::{HashMap, HashSet};
 use risingwave_common::array::column::Column;
 use risingwave_common::array::{ArrayImpl, ArrayRef, DataChunk, StreamChunk};
 use risingwave_common::buffer::Bitmap;
-use risingwave_common::catalog::Schema;
+use risingwave_common::catalog::{ColumnDesc, ColumnId, Schema, TableId};
 use risingwave_common::error::Result;
 use risingwave_common::types::DataType;
+use risingwave_common::util::value_encoding::{deserialize_cell, serialize_cell};
 use risingwave_pb::common::ActorInfo;
 use risingwave_pb::data::barrier::Mutation as ProstMutation;
 use risingwave_pb::data::stream_message::StreamMessage;
 use risingwave_pb::data::{
-    AddMutation, Barrier as ProstBarrier, DispatcherMutation, Epoch as ProstEpoch, NothingMutation,
-    StopMutation, StreamMessage as ProstStreamMessage, UpdateMutation,
+    AddColumnsMutation, AddMutation, AddedColumn, Barrier as ProstBarrier, DispatcherMutation,
+    Epoch as ProstEpoch, NothingMutation, StopMutation, StreamMessage as ProstStreamMessage,
+    UpdateMutation,
 };
 use smallvec::SmallVec;
 use tracing::trace_span;
@@ -46,6 +48,8 @@ mod chain;
 mod debug;
 pub mod dispatch;
 mod error;
+mod expand;
+mod expr_error;
 mod filter;
 mod global_simple_agg;
 mod hash_agg;
@@ -59,6 +63,7 @@ pub mod merge;
 pub mod monitor;
 mod mview;
 mod project;
+mod project_set;
 mod rearranged_chain;
 pub mod receiver;
 mod simple;
@@ -78,6 +83,8 @@ pub use batch_query::BatchQueryExecutor;
 pub use chain::ChainExecutor;
 pub use debug::DebugExecutor;
 pub use dispatch::DispatchExecutor;
+pub use expand::ExpandExecutor;
+pub use expr_error::ExprErrorHandler;
 pub use filter::FilterExecutor;
 pub use global_simple_agg::SimpleAggExecutor;
 pub use hash_agg::HashAggExecutor;
@@ -89,6 +96,7 @@ pub use lookup_union::LookupUnionExecutor;
 pub use merge::MergeExecutor;
 pub use mview::*;
 pub use project::ProjectExecutor;
+pub use project_set::ProjectSetExecutor;
 pub use rearranged_chain::RearrangedChainExecutor;
 use simple::{SimpleExecutor, SimpleExecutorWrapper};
 pub use source::*;
@@ -165,6 +173,12 @@ pub enum Mutation {
     Stop(HashSet<ActorId>),
     UpdateOutputs(HashMap<(ActorId, DispatcherId), Vec<ActorInfo>>),
     AddOutput(HashMap<(ActorId, DispatcherId), Vec<ActorInfo>>),
+    /// Columns added to a table by `ALTER TABLE ADD COLUMN`.
+    AddColumns {
+        table_id: TableId,
+        source_id: TableId,
+        columns: Vec<ColumnDesc>,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -252,6 +266,19 @@ impl Barrier {
         matches!(self.mutation.as_deref(), Some(Mutation::Stop(actors)) if actors.contains(&actor_id))
     }
 
+    /// Returns the columns added to a table by this barrier, if `relation_id` is the table or its
+    /// associated source.
+    pub fn added_columns(&self, relation_id: &TableId) -> Option<&[ColumnDesc]> {
+        match self.mutation.as_deref() {
+            Some(Mutation::AddColumns {
+                table_id,
+                source_id,
+                columns,
+            }) if table_id == relation_id || source_id == relation_id => Some(columns),
+            _ => None,
+        }
+    }
+
     pub fn is_to_add_output(&self, actor_id: ActorId) -> bool {
         matches!(
             self.mutation.as_deref(),
@@ -316,6 +343,23 @@ impl Barrier {
                         })
                         .collect(),
                 })),
+                Some(Mutation::AddColumns {
+                    table_id,
+                    source_id,
+                    columns,
+                }) => Some(ProstMutation::AddColumns(AddColumnsMutation {
+                    table_id: table_id.table_id(),
+                    source_id: source_id.table_id(),
+                    columns: columns
+                        .iter()
+                        .map(|c| AddedColumn {
+                            column_id: c.column_id.get_id(),
+                            column_type: Some(c.data_type.to_protobuf()),
+                            name: c.name.clone(),
+                            default_value: serialize_cell(&c.default_value).unwrap(),
+                        })
+                        .collect(),
+                })),
             },
             span: vec![],
         }
@@ -356,6 +400,30 @@ impl Barrier {
                 )
                 .into(),
             ),
+            ProstMutation::AddColumns(add_columns) => {
+                let columns = add_columns
+                    .columns
+                    .iter()
+                    .map(|c| {
+                        let mut desc = ColumnDesc::unnamed(
+                            ColumnId::new(c.column_id),
+                            DataType::from(c.get_column_type()?),
+                        );
+                        desc.name = c.name.clone();
+                        desc.default_value =
+                            deserialize_cell(&c.default_value[..], &desc.data_type)?;
+                        Ok(desc)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Some(
+                    Mutation::AddColumns {
+                        table_id: TableId::new(add_columns.table_id),
+                        source_id: TableId::new(add_columns.source_id),
+                        columns,
+                    }
+                    .into(),
+                )
+            }
         };
         let epoch = prost.get_epoch().unwrap();
         Ok(Barrier {
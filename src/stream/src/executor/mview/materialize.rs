@@ -16,13 +16,13 @@ use futures::StreamExt;
 use futures_async_stream::try_stream;
 use itertools::Itertools;
 use risingwave_common::array::Op::*;
-use risingwave_common::array::Row;
-use risingwave_common::catalog::{ColumnDesc, ColumnId, Schema};
-use risingwave_common::util::sort_util::OrderPair;
+use risingwave_common::array::{Row, StreamChunk};
+use risingwave_common::catalog::{ColumnDesc, ColumnId, Field, Schema, TableId};
+use risingwave_common::util::sort_util::{OrderPair, OrderType};
 use risingwave_storage::table::state_table::StateTable;
 use risingwave_storage::{Keyspace, StateStore};
 
-use crate::executor::error::StreamExecutorError;
+use crate::executor::error::{StreamExecutorError, StreamExecutorResult};
 use crate::executor::{
     BoxedExecutor, BoxedMessageStream, Executor, ExecutorInfo, Message, PkIndicesRef,
 };
@@ -31,21 +31,42 @@ use crate::executor::{
 pub struct MaterializeExecutor<S: StateStore> {
     input: BoxedExecutor,
 
+    /// Id of the materialized view or table.
+    table_id: TableId,
+
     state_table: StateTable<S>,
 
     /// Columns of arrange keys (including pk, group keys, join keys, etc.)
     arrange_columns: Vec<usize>,
 
+    /// Whether to turn an insertion on an existing key into an update. This is required by
+    /// tables with user-defined primary key, where inserting the same key twice is an upsert.
+    handle_pk_conflict: bool,
+
+    /// The key of the unique index materialized by this executor, if any.
+    unique_key: Option<UniqueKey<S>>,
+
     info: ExecutorInfo,
 }
 
+/// The key of a unique index, which consists of the leading arrange columns of the index.
+struct UniqueKey<S: StateStore> {
+    /// Number of the leading arrange columns in the key.
+    len: usize,
+
+    /// Maps each non-null key to the arrange key of the row having it.
+    owners: StateTable<S>,
+}
+
 impl<S: StateStore> MaterializeExecutor<S> {
     pub fn new(
         input: BoxedExecutor,
+        table_id: TableId,
         keyspace: Keyspace<S>,
         keys: Vec<OrderPair>,
         column_ids: Vec<ColumnId>,
         executor_id: u64,
+        handle_pk_conflict: bool,
     ) -> Self {
         let arrange_columns: Vec<usize> = keys.iter().map(|k| k.column_idx).collect();
         let arrange_order_types = keys.iter().map(|k| k.order_type).collect();
@@ -59,12 +80,16 @@ impl<S: StateStore> MaterializeExecutor<S> {
                 name: field.name,
                 field_descs: vec![],
                 type_name: "".to_string(),
+                default_value: None,
             })
             .collect_vec();
         Self {
             input,
+            table_id,
             state_table: StateTable::new(keyspace, column_descs, arrange_order_types),
             arrange_columns: arrange_columns.clone(),
+            handle_pk_conflict,
+            unique_key: None,
             info: ExecutorInfo {
                 schema,
                 pk_indices: arrange_columns,
@@ -73,13 +98,49 @@ impl<S: StateStore> MaterializeExecutor<S> {
         }
     }
 
+    /// Let the executor drop an insertion whose non-null value on the leading `unique_key_len`
+    /// arrange columns is already taken by another row. The owners of the values are kept in
+    /// `keyspace`.
+    #[must_use]
+    pub fn with_unique_key(mut self, keyspace: Keyspace<S>, unique_key_len: usize) -> Self {
+        assert!(unique_key_len <= self.arrange_columns.len());
+        let column_descs = self
+            .arrange_columns
+            .iter()
+            .enumerate()
+            .map(|(i, col_idx)| {
+                ColumnDesc::unnamed(
+                    ColumnId::from(i as i32),
+                    self.info.schema.fields[*col_idx].data_type.clone(),
+                )
+            })
+            .collect();
+        self.unique_key = Some(UniqueKey {
+            len: unique_key_len,
+            owners: StateTable::new(
+                keyspace,
+                column_descs,
+                vec![OrderType::Ascending; unique_key_len],
+            ),
+        });
+        self
+    }
+
     #[try_stream(ok = Message, error = StreamExecutorError)]
     async fn execute_inner(mut self) {
         let input = self.input.execute();
+        // The first message is always a barrier, so `epoch` will be set before any chunk comes.
+        let mut epoch = 0;
         #[for_await]
         for msg in input {
             let msg = msg?;
             yield match msg {
+                Message::Chunk(chunk) if self.handle_pk_conflict => {
+                    Message::Chunk(self.apply_chunk_with_pk_conflict(chunk, epoch).await?)
+                }
+                Message::Chunk(chunk) if self.unique_key.is_some() => {
+                    Message::Chunk(self.apply_chunk_with_unique_key(chunk, epoch).await?)
+                }
                 Message::Chunk(chunk) => {
                     for (idx, op) in chunk.ops().iter().enumerate() {
                         // check visibility
@@ -124,11 +185,191 @@ impl<S: StateStore> MaterializeExecutor<S> {
                         .commit_with_value_meta(b.epoch.prev)
                         .await
                         .map_err(StreamExecutorError::executor_v1)?;
+                    if let Some(unique_key) = &mut self.unique_key {
+                        unique_key
+                            .owners
+                            .commit(b.epoch.prev)
+                            .await
+                            .map_err(StreamExecutorError::executor_v1)?;
+                    }
+                    epoch = b.epoch.curr;
+                    if let Some(columns) = b.added_columns(&self.table_id) {
+                        self.add_columns(columns);
+                    }
                     Message::Barrier(b)
                 }
             }
         }
     }
+
+    /// Materializes the columns added by `ALTER TABLE ADD COLUMN` from the next epoch on.
+    fn add_columns(&mut self, columns: &[ColumnDesc]) {
+        for column in columns {
+            self.info.schema.fields.push(Field::with_name(
+                column.data_type.clone(),
+                column.name.clone(),
+            ));
+        }
+        self.state_table.add_columns(columns.to_vec());
+    }
+
+    /// Applies the chunk to the state table like `execute_inner`, except that an insertion on an
+    /// existing key is turned into an `UpdateDelete` of the old row followed by an `UpdateInsert`
+    /// of the new row, so that downstream sees a retraction before the new value.
+    async fn apply_chunk_with_pk_conflict(
+        &mut self,
+        chunk: StreamChunk,
+        epoch: u64,
+    ) -> StreamExecutorResult<StreamChunk> {
+        let mut rows = Vec::with_capacity(chunk.cardinality());
+
+        for (idx, op) in chunk.ops().iter().enumerate() {
+            // check visibility
+            let visible = chunk
+                .visibility()
+                .as_ref()
+                .map(|x| x.is_set(idx).unwrap())
+                .unwrap_or(true);
+            if !visible {
+                continue;
+            }
+
+            // assemble pk row
+            let arrange_row = Row(self
+                .arrange_columns
+                .iter()
+                .map(|col_idx| chunk.column_at(*col_idx).array_ref().datum_at(idx))
+                .collect_vec());
+
+            // assemble row
+            let row = Row(chunk
+                .columns()
+                .iter()
+                .map(|x| x.array_ref().datum_at(idx))
+                .collect_vec());
+
+            match op {
+                Insert | UpdateInsert => {
+                    match self.state_table.get_row(&arrange_row, epoch).await? {
+                        Some(old_row) => {
+                            self.state_table
+                                .delete(arrange_row.clone(), old_row.clone())?;
+                            self.state_table.insert(arrange_row, row.clone())?;
+                            // The `UpdateDelete` paired with this `UpdateInsert` is now followed
+                            // by another pair, so degrade it to a plain `Delete`.
+                            if *op == UpdateInsert
+                                && let Some((prev_op @ UpdateDelete, _)) = rows.last_mut()
+                            {
+                                *prev_op = Delete;
+                            }
+                            rows.push((UpdateDelete, old_row));
+                            rows.push((UpdateInsert, row));
+                        }
+                        None => {
+                            self.state_table.insert(arrange_row, row.clone())?;
+                            rows.push((*op, row));
+                        }
+                    }
+                }
+                Delete | UpdateDelete => {
+                    self.state_table.delete(arrange_row, row.clone())?;
+                    rows.push((*op, row));
+                }
+            }
+        }
+
+        StreamChunk::from_rows(&rows, &self.info.schema.data_types())
+            .map_err(StreamExecutorError::eval_error)
+    }
+
+    /// Applies the chunk to the state table like `execute_inner`, except that an insertion whose
+    /// unique key is taken by another row is dropped, and so is the deletion of a dropped row.
+    /// Rows with NULL in their unique key never conflict, as NULLs are not equal to each other.
+    async fn apply_chunk_with_unique_key(
+        &mut self,
+        chunk: StreamChunk,
+        epoch: u64,
+    ) -> StreamExecutorResult<StreamChunk> {
+        let unique_key = self.unique_key.as_mut().unwrap();
+        let mut rows = Vec::with_capacity(chunk.cardinality());
+        // Whether the last `UpdateDelete` is dropped, so its `UpdateInsert` has to be degraded to
+        // a plain `Insert`.
+        let mut update_delete_dropped = false;
+
+        for (idx, op) in chunk.ops().iter().enumerate() {
+            // check visibility
+            let visible = chunk
+                .visibility()
+                .as_ref()
+                .map(|x| x.is_set(idx).unwrap())
+                .unwrap_or(true);
+            if !visible {
+                continue;
+            }
+
+            // assemble pk row
+            let arrange_row = Row(self
+                .arrange_columns
+                .iter()
+                .map(|col_idx| chunk.column_at(*col_idx).array_ref().datum_at(idx))
+                .collect_vec());
+
+            // assemble row
+            let row = Row(chunk
+                .columns()
+                .iter()
+                .map(|x| x.array_ref().datum_at(idx))
+                .collect_vec());
+
+            let key = Row(arrange_row.0[..unique_key.len].to_vec());
+            let owner = if key.0.iter().all(Option::is_some) {
+                Some(unique_key.owners.get_row(&key, epoch).await?)
+            } else {
+                None
+            };
+
+            match op {
+                Insert | UpdateInsert => {
+                    let unpaired =
+                        *op == UpdateInsert && std::mem::take(&mut update_delete_dropped);
+                    if let Some(Some(owner)) = &owner && owner != &arrange_row {
+                        // The `UpdateDelete` paired with this dropped `UpdateInsert` is now a
+                        // plain `Delete`.
+                        if *op == UpdateInsert
+                            && !unpaired
+                            && let Some((prev_op @ UpdateDelete, _)) = rows.last_mut()
+                        {
+                            *prev_op = Delete;
+                        }
+                        continue;
+                    }
+                    if let Some(None) = owner {
+                        unique_key.owners.insert(key, arrange_row.clone())?;
+                    }
+                    self.state_table.insert(arrange_row, row.clone())?;
+                    rows.push((if unpaired { Insert } else { *op }, row));
+                }
+                Delete | UpdateDelete => {
+                    update_delete_dropped = false;
+                    match owner {
+                        Some(Some(owner)) if owner == arrange_row => {
+                            unique_key.owners.delete(key, owner)?;
+                        }
+                        Some(_) => {
+                            update_delete_dropped = *op == UpdateDelete;
+                            continue;
+                        }
+                        None => {}
+                    }
+                    self.state_table.delete(arrange_row, row.clone())?;
+                    rows.push((*op, row));
+                }
+            }
+        }
+
+        StreamChunk::from_rows(&rows, &self.info.schema.data_types())
+            .map_err(StreamExecutorError::eval_error)
+    }
 }
 
 impl<S: StateStore> Executor for MaterializeExecutor<S> {
@@ -220,10 +461,12 @@ mod tests {
         let table = CellBasedTable::new_for_test(keyspace.clone(), column_descs, order_types);
         let mut materialize_executor = Box::new(MaterializeExecutor::new(
             Box::new(source),
+            table_id,
             keyspace,
             vec![OrderPair::new(0, OrderType::Ascending)],
             column_ids,
             1,
+            false,
         ))
         .execute();
 
@@ -253,4 +496,214 @@ mod tests {
             _ => unreachable!(),
         }
     }
+
+    #[madsim::test]
+    async fn test_materialize_executor_handle_pk_conflict() {
+        // Prepare storage and memtable.
+        let memory_state_store = MemoryStateStore::new();
+        let table_id = TableId::new(1);
+        // Two columns of int32 type, the first column is PK.
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+        ]);
+        let column_ids = vec![0.into(), 1.into()];
+
+        // Prepare source chunks.
+        let chunk1 = StreamChunk::from_pretty(
+            " i i
+            + 1 4
+            + 2 5",
+        );
+        let chunk2 = StreamChunk::from_pretty(
+            " i i
+            + 1 8
+            + 3 6
+            + 3 9",
+        );
+
+        // Prepare stream executors.
+        let source = MockSource::with_messages(
+            schema.clone(),
+            PkIndices::new(),
+            vec![
+                Message::Barrier(Barrier::new_test_barrier(1)),
+                Message::Chunk(chunk1),
+                Message::Barrier(Barrier::new_test_barrier(2)),
+                Message::Chunk(chunk2),
+                Message::Barrier(Barrier::new_test_barrier(3)),
+            ],
+        );
+
+        let keyspace = Keyspace::table_root(memory_state_store.clone(), &table_id);
+        let order_types = vec![OrderType::Ascending];
+        let column_descs = vec![
+            ColumnDesc::unnamed(column_ids[0], DataType::Int32),
+            ColumnDesc::unnamed(column_ids[1], DataType::Int32),
+        ];
+        let table = CellBasedTable::new_for_test(keyspace.clone(), column_descs, order_types);
+        let mut materialize_executor = Box::new(MaterializeExecutor::new(
+            Box::new(source),
+            table_id,
+            keyspace,
+            vec![OrderPair::new(0, OrderType::Ascending)],
+            column_ids,
+            1,
+            true,
+        ))
+        .execute();
+
+        materialize_executor.next().await.transpose().unwrap();
+
+        // First stream chunk contains no conflict.
+        let chunk = materialize_executor.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " i i
+                + 1 4
+                + 2 5",
+            )
+        );
+        materialize_executor.next().await.transpose().unwrap();
+
+        // Second stream chunk updates key 1 in storage and key 3 in the same chunk.
+        let chunk = materialize_executor.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " i i
+                U- 1 4
+                U+ 1 8
+                +  3 6
+                U- 3 6
+                U+ 3 9",
+            )
+        );
+        match materialize_executor.next().await.transpose().unwrap() {
+            Some(Message::Barrier(_)) => {
+                let row = table
+                    .get_row(&Row(vec![Some(1_i32.into())]), u64::MAX)
+                    .await
+                    .unwrap();
+                assert_eq!(row, Some(Row(vec![Some(1_i32.into()), Some(8_i32.into())])));
+                let row = table
+                    .get_row(&Row(vec![Some(3_i32.into())]), u64::MAX)
+                    .await
+                    .unwrap();
+                assert_eq!(row, Some(Row(vec![Some(3_i32.into()), Some(9_i32.into())])));
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[madsim::test]
+    async fn test_materialize_executor_unique_key() {
+        // Prepare storage and memtable.
+        let memory_state_store = MemoryStateStore::new();
+        let table_id = TableId::new(1);
+        // Two columns of int32 type, the first column is the unique key and the second one is the
+        // pk of the indexed table.
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+        ]);
+        let column_ids = vec![0.into(), 1.into()];
+
+        // Prepare source chunks.
+        let chunk1 = StreamChunk::from_pretty(
+            " i i
+            + 1 10
+            + 2 11
+            + 1 12
+            + . 13
+            + . 14",
+        );
+        let chunk2 = StreamChunk::from_pretty(
+            "  i i
+            -  1 12
+            U- 2 11
+            U+ 1 11
+            -  1 10
+            +  1 15",
+        );
+
+        // Prepare stream executors.
+        let source = MockSource::with_messages(
+            schema.clone(),
+            PkIndices::new(),
+            vec![
+                Message::Barrier(Barrier::new_test_barrier(1)),
+                Message::Chunk(chunk1),
+                Message::Barrier(Barrier::new_test_barrier(2)),
+                Message::Chunk(chunk2),
+                Message::Barrier(Barrier::new_test_barrier(3)),
+            ],
+        );
+
+        let keyspace = Keyspace::table_root(memory_state_store.clone(), &table_id);
+        let order_types = vec![OrderType::Ascending, OrderType::Ascending];
+        let column_descs = vec![
+            ColumnDesc::unnamed(column_ids[0], DataType::Int32),
+            ColumnDesc::unnamed(column_ids[1], DataType::Int32),
+        ];
+        let table = CellBasedTable::new_for_test(keyspace.clone(), column_descs, order_types);
+        let mut materialize_executor = Box::new(
+            MaterializeExecutor::new(
+                Box::new(source),
+                table_id,
+                keyspace,
+                vec![
+                    OrderPair::new(0, OrderType::Ascending),
+                    OrderPair::new(1, OrderType::Ascending),
+                ],
+                column_ids,
+                1,
+                false,
+            )
+            .with_unique_key(Keyspace::executor_root(memory_state_store, 1), 1),
+        )
+        .execute();
+
+        materialize_executor.next().await.transpose().unwrap();
+
+        // The third row of the first chunk conflicts with the first one, while NULLs don't.
+        let chunk = materialize_executor.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " i i
+                + 1 10
+                + 2 11
+                + . 13
+                + . 14",
+            )
+        );
+        materialize_executor.next().await.transpose().unwrap();
+
+        // The deletion of the dropped row is dropped, and so is the conflicting update of row 11,
+        // whose deletion is kept. Key 1 can be taken again after row 10 is deleted.
+        let chunk = materialize_executor.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " i i
+                - 2 11
+                - 1 10
+                + 1 15",
+            )
+        );
+        match materialize_executor.next().await.transpose().unwrap() {
+            Some(Message::Barrier(_)) => {
+                // The whole row is the pk of the index.
+                let row_15 = Row(vec![Some(1_i32.into()), Some(15_i32.into())]);
+                let row = table.get_row(&row_15, u64::MAX).await.unwrap();
+                assert_eq!(row, Some(row_15));
+                let row_12 = Row(vec![Some(1_i32.into()), Some(12_i32.into())]);
+                let row = table.get_row(&row_12, u64::MAX).await.unwrap();
+                assert_eq!(row, None);
+            }
+            _ => unreachable!(),
+        }
+    }
 }
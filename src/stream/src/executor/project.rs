@@ -16,13 +16,16 @@ use std::fmt::{Debug, Formatter};
 
 use itertools::Itertools;
 use risingwave_common::array::column::Column;
-use risingwave_common::array::{DataChunk, StreamChunk};
+use risingwave_common::array::{DataChunk, Op, StreamChunk};
+use risingwave_common::buffer::{Bitmap, BitmapBuilder};
 use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::types::DataType;
 use risingwave_expr::expr::BoxedExpression;
+use risingwave_pb::stream_plan::expr_error_handling::Mode;
 
 use super::{
-    Executor, ExecutorInfo, PkIndices, PkIndicesRef, SimpleExecutor, SimpleExecutorWrapper,
-    StreamExecutorResult,
+    Executor, ExecutorInfo, ExprErrorHandler, PkIndices, PkIndicesRef, SimpleExecutor,
+    SimpleExecutorWrapper, StreamExecutorResult,
 };
 use crate::executor::error::StreamExecutorError;
 
@@ -45,6 +48,12 @@ impl ProjectExecutor {
             inner: SimpleProjectExecutor::new(info, exprs, execuotr_id),
         }
     }
+
+    /// Evaluates the expressions non-strictly according to `error_handler`.
+    pub fn with_error_handler(mut self, error_handler: ExprErrorHandler) -> Self {
+        self.inner.error_handler = error_handler;
+        self
+    }
 }
 
 /// `ProjectExecutor` project data with the `expr`. The `expr` takes a chunk of data,
@@ -53,8 +62,14 @@ impl ProjectExecutor {
 pub struct SimpleProjectExecutor {
     info: ExecutorInfo,
 
+    /// Types of the input columns.
+    input_types: Vec<DataType>,
+
     /// Expressions of the current projection.
     exprs: Vec<BoxedExpression>,
+
+    /// Decides what to do with the rows that fail to be evaluated.
+    error_handler: ExprErrorHandler,
 }
 
 impl SimpleProjectExecutor {
@@ -71,7 +86,9 @@ impl SimpleProjectExecutor {
                 pk_indices: input_info.pk_indices,
                 identity: format!("ProjectExecutor {:X}", executor_id),
             },
+            input_types: input_info.schema.data_types(),
             exprs,
+            error_handler: ExprErrorHandler::strict(),
         }
     }
 }
@@ -80,6 +97,7 @@ impl Debug for SimpleProjectExecutor {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ProjectExecutor")
             .field("exprs", &self.exprs)
+            .field("error_handler", &self.error_handler)
             .finish()
     }
 }
@@ -91,7 +109,7 @@ impl SimpleExecutor for SimpleProjectExecutor {
     ) -> StreamExecutorResult<Option<StreamChunk>> {
         let chunk = chunk.compact().map_err(StreamExecutorError::eval_error)?;
 
-        let (ops, columns, visibility) = chunk.into_inner();
+        let (mut ops, columns, visibility) = chunk.into_inner();
         let data_chunk = {
             let data_chunk_builder = DataChunk::builder().columns(columns);
             if let Some(visibility) = visibility {
@@ -101,17 +119,34 @@ impl SimpleExecutor for SimpleProjectExecutor {
             }
         };
 
-        let projected_columns = self
-            .exprs
-            .iter_mut()
-            .map(|expr| {
-                expr.eval(&data_chunk)
-                    .map(Column::new)
-                    .map_err(StreamExecutorError::eval_error)
-            })
-            .collect::<Result<Vec<Column>, _>>()?;
-
-        let new_chunk = StreamChunk::new(ops, projected_columns, None);
+        let mut projected_columns = Vec::with_capacity(self.exprs.len());
+        let mut failed_rows: Option<Vec<bool>> = None;
+        for expr in &self.exprs {
+            let (array, failed) = self
+                .error_handler
+                .eval(expr, &data_chunk, &self.input_types)?;
+            projected_columns.push(Column::new(array));
+            if let Some(failed) = failed {
+                let failed_rows = failed_rows.get_or_insert_with(|| vec![false; ops.len()]);
+                for (failed_row, failed) in failed_rows.iter_mut().zip_eq(failed.iter()) {
+                    *failed_row |= failed;
+                }
+            }
+        }
+        self.error_handler.flush(&self.info.identity);
+
+        let visibility = match failed_rows {
+            Some(failed_rows) if self.error_handler.mode() == Mode::DropRow => {
+                let visibility = drop_failed_rows(&mut ops, &failed_rows);
+                if visibility.num_high_bits() == 0 {
+                    return Ok(None);
+                }
+                Some(visibility)
+            }
+            _ => None,
+        };
+
+        let new_chunk = StreamChunk::new(ops, projected_columns, visibility);
         Ok(Some(new_chunk))
     }
 
@@ -128,6 +163,21 @@ impl SimpleExecutor for SimpleProjectExecutor {
     }
 }
 
+/// Hides the failed rows. If only one half of an update pair fails, the other half is turned into
+/// a plain insert or delete.
+fn drop_failed_rows(ops: &mut [Op], failed_rows: &[bool]) -> Bitmap {
+    let mut visibility = BitmapBuilder::with_capacity(ops.len());
+    for (i, op) in ops.iter_mut().enumerate() {
+        match *op {
+            Op::UpdateDelete if !failed_rows[i] && failed_rows[i + 1] => *op = Op::Delete,
+            Op::UpdateInsert if !failed_rows[i] && failed_rows[i - 1] => *op = Op::Insert,
+            _ => {}
+        }
+        visibility.append(!failed_rows[i]);
+    }
+    visibility.finish()
+}
+
 #[cfg(test)]
 mod tests {
     use futures::StreamExt;
@@ -204,4 +254,78 @@ mod tests {
 
         assert!(project.next().await.unwrap().unwrap().is_stop());
     }
+
+    fn divide_project(mode: Mode) -> BoxedExecutor {
+        let chunk = StreamChunk::from_pretty(
+            "  I I
+            +  6 2
+            +  1 0
+            U- 4 0
+            U+ 4 2",
+        );
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ],
+        };
+        let source = MockSource::with_chunks(schema, PkIndices::new(), vec![chunk]);
+
+        let left_expr = InputRefExpression::new(DataType::Int64, 0);
+        let right_expr = InputRefExpression::new(DataType::Int64, 1);
+        let test_expr = new_binary_expr(
+            Type::Divide,
+            DataType::Int64,
+            Box::new(left_expr),
+            Box::new(right_expr),
+        );
+
+        ProjectExecutor::new(Box::new(source), vec![], vec![test_expr], 1)
+            .with_error_handler(ExprErrorHandler::new(mode, None))
+            .boxed()
+    }
+
+    #[madsim::test]
+    async fn test_projection_strict() {
+        let mut project = divide_project(Mode::Strict).execute();
+        assert!(project.next().await.unwrap().is_err());
+    }
+
+    #[madsim::test]
+    async fn test_projection_set_null() {
+        let mut project = divide_project(Mode::SetNull).execute();
+
+        let msg = project.next().await.unwrap().unwrap();
+        assert_eq!(
+            *msg.as_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                "  I
+                +  3
+                +  .
+                U- .
+                U+ 2"
+            )
+        );
+
+        assert!(project.next().await.unwrap().unwrap().is_stop());
+    }
+
+    #[madsim::test]
+    async fn test_projection_drop_row() {
+        let mut project = divide_project(Mode::DropRow).execute();
+
+        let msg = project.next().await.unwrap().unwrap();
+        assert_eq!(
+            *msg.as_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                "  I
+                +  3
+                +  . D
+                U- . D
+                +  2"
+            )
+        );
+
+        assert!(project.next().await.unwrap().unwrap().is_stop());
+    }
 }
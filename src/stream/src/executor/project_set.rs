@@ -0,0 +1,274 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use futures::StreamExt;
+use futures_async_stream::try_stream;
+use itertools::Itertools;
+use risingwave_common::array::column::Column;
+use risingwave_common::array::{ArrayRef, Op, StreamChunk};
+use risingwave_common::catalog::Schema;
+use risingwave_common::error::Result;
+use risingwave_common::types::DataType;
+use risingwave_expr::table_function::ProjectSetSelectItem;
+
+use super::error::StreamExecutorError;
+use super::{BoxedExecutor, BoxedMessageStream, Executor, ExecutorInfo, Message, PkIndicesRef};
+
+/// `ProjectSetExecutor` expands each input row into as many rows as the longest output of the
+/// table functions in the select list. The shorter outputs are padded with NULLs and the other
+/// expressions are repeated. The first output column is `projected_row_id`, the index of the row
+/// in the expansion, which is used to derive the pk together with the input pk.
+///
+/// As an update may expand to a different number of rows before and after, `UpdateDelete` and
+/// `UpdateInsert` are emitted as `Delete` and `Insert` respectively.
+pub struct ProjectSetExecutor {
+    input: BoxedExecutor,
+    info: ExecutorInfo,
+    select_list: Vec<ProjectSetSelectItem>,
+}
+
+impl ProjectSetExecutor {
+    pub fn new(
+        input: BoxedExecutor,
+        info: ExecutorInfo,
+        select_list: Vec<ProjectSetSelectItem>,
+    ) -> Self {
+        Self {
+            input,
+            info,
+            select_list,
+        }
+    }
+
+    #[try_stream(ok = Message, error = StreamExecutorError)]
+    async fn execute_inner(self: Box<Self>) {
+        let Self {
+            input,
+            info,
+            select_list,
+        } = *self;
+        let data_types = info.schema.data_types();
+
+        #[for_await]
+        for msg in input.execute() {
+            match msg? {
+                Message::Chunk(chunk) => {
+                    let chunk = chunk.compact().map_err(StreamExecutorError::executor_v1)?;
+                    let (data_chunk, ops) = chunk.into_parts();
+                    let outputs: Vec<SelectItemOutput> = select_list
+                        .iter()
+                        .map(|item| match item {
+                            ProjectSetSelectItem::TableFunction(tf) => {
+                                tf.eval(&data_chunk).map(SelectItemOutput::TableFunction)
+                            }
+                            ProjectSetSelectItem::Expr(expr) => {
+                                expr.eval(&data_chunk).map(SelectItemOutput::Expr)
+                            }
+                        })
+                        .try_collect()
+                        .map_err(StreamExecutorError::eval_error)?;
+
+                    let chunk = build_chunk(&data_types, &ops, &outputs)
+                        .map_err(StreamExecutorError::executor_v1)?;
+                    if chunk.cardinality() > 0 {
+                        yield Message::Chunk(chunk);
+                    }
+                }
+                m => yield m,
+            }
+        }
+    }
+}
+
+/// The values of a select item on the input rows, which is an array per row for table functions.
+enum SelectItemOutput {
+    TableFunction(Vec<ArrayRef>),
+    Expr(ArrayRef),
+}
+
+fn build_chunk(
+    data_types: &[DataType],
+    ops: &[Op],
+    outputs: &[SelectItemOutput],
+) -> Result<StreamChunk> {
+    let mut new_ops = vec![];
+    let mut builders: Vec<_> = data_types
+        .iter()
+        .map(|ty| ty.create_array_builder(ops.len()))
+        .try_collect()?;
+
+    for (row_idx, op) in ops.iter().enumerate() {
+        let op = match op {
+            Op::Insert | Op::UpdateInsert => Op::Insert,
+            Op::Delete | Op::UpdateDelete => Op::Delete,
+        };
+        let len = outputs
+            .iter()
+            .map(|output| match output {
+                SelectItemOutput::TableFunction(arrays) => arrays[row_idx].len(),
+                SelectItemOutput::Expr(_) => 0,
+            })
+            .max()
+            .unwrap_or(0);
+        for projected_row_id in 0..len {
+            new_ops.push(op);
+            builders[0].append_datum(&Some((projected_row_id as i64).into()))?;
+            for (output, builder) in outputs.iter().zip_eq(builders[1..].iter_mut()) {
+                match output {
+                    SelectItemOutput::TableFunction(arrays) => {
+                        let array = &arrays[row_idx];
+                        if projected_row_id < array.len() {
+                            builder.append_datum_ref(array.value_at(projected_row_id))?;
+                        } else {
+                            builder.append_null()?;
+                        }
+                    }
+                    SelectItemOutput::Expr(array) => {
+                        builder.append_datum_ref(array.value_at(row_idx))?;
+                    }
+                }
+            }
+        }
+    }
+
+    let columns = builders
+        .into_iter()
+        .map(|builder| Ok(Column::new(Arc::new(builder.finish()?))))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(StreamChunk::new(new_ops, columns, None))
+}
+
+impl Executor for ProjectSetExecutor {
+    fn execute(self: Box<Self>) -> BoxedMessageStream {
+        self.execute_inner().boxed()
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.info.schema
+    }
+
+    fn pk_indices(&self) -> PkIndicesRef {
+        &self.info.pk_indices
+    }
+
+    fn identity(&self) -> &str {
+        &self.info.identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::stream_chunk::StreamChunkTestExt;
+    use risingwave_common::catalog::Field;
+    use risingwave_expr::expr::{BoxedExpression, InputRefExpression, LiteralExpression};
+    use risingwave_expr::table_function::GenerateSeries;
+
+    use super::*;
+    use crate::executor::test_utils::MockSource;
+
+    #[madsim::test]
+    async fn test_project_set() {
+        let chunk1 = StreamChunk::from_pretty(
+            " I I
+            + 1 3
+            + 2 1 D
+            + 3 4",
+        );
+        let chunk2 = StreamChunk::from_pretty(
+            "  I I
+            U- 1 3
+            U+ 1 2",
+        );
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ],
+        };
+        let source = MockSource::with_chunks(schema, vec![0], vec![chunk1, chunk2]);
+
+        let input_ref = |idx: usize| -> BoxedExpression {
+            Box::new(InputRefExpression::new(DataType::Int64, idx))
+        };
+        let one = || -> BoxedExpression {
+            Box::new(LiteralExpression::new(DataType::Int64, Some(1i64.into())))
+        };
+        // SELECT v1, generate_series(v1, v2, 1)
+        let select_list = vec![
+            ProjectSetSelectItem::Expr(input_ref(0)),
+            ProjectSetSelectItem::TableFunction(Box::new(GenerateSeries::new(
+                input_ref(0),
+                input_ref(1),
+                one(),
+                DataType::Int64,
+            ))),
+        ];
+        let project_set = Box::new(ProjectSetExecutor::new(
+            Box::new(source),
+            ExecutorInfo {
+                schema: Schema {
+                    fields: vec![
+                        Field::unnamed(DataType::Int64),
+                        Field::unnamed(DataType::Int64),
+                        Field::unnamed(DataType::Int64),
+                    ],
+                },
+                pk_indices: vec![0, 1],
+                identity: "ProjectSetExecutor".to_string(),
+            },
+            select_list,
+        ));
+        let mut project_set = project_set.execute();
+
+        let chunk = project_set
+            .next()
+            .await
+            .unwrap()
+            .unwrap()
+            .into_chunk()
+            .unwrap();
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                " I I I
+                + 0 1 1
+                + 1 1 2
+                + 2 1 3
+                + 0 3 3
+                + 1 3 4"
+            )
+        );
+
+        let chunk = project_set
+            .next()
+            .await
+            .unwrap()
+            .unwrap()
+            .into_chunk()
+            .unwrap();
+        assert_eq!(
+            chunk,
+            StreamChunk::from_pretty(
+                " I I I
+                - 0 1 1
+                - 1 1 2
+                - 2 1 3
+                + 0 1 1
+                + 1 1 2"
+            )
+        );
+    }
+}
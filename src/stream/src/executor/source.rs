@@ -21,7 +21,7 @@ use futures::{Stream, StreamExt};
 use futures_async_stream::try_stream;
 use risingwave_common::array::column::Column;
 use risingwave_common::array::{ArrayBuilder, ArrayImpl, I64ArrayBuilder, StreamChunk};
-use risingwave_common::catalog::{ColumnId, Schema, TableId};
+use risingwave_common::catalog::{ColumnDesc, ColumnId, Field, Schema, TableId};
 use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::{Result, RwError};
 use risingwave_common::try_match_expand;
@@ -180,6 +180,25 @@ impl SourceReader {
 }
 
 impl<S: StateStore> SourceExecutor<S> {
+    /// Reads the columns added by `ALTER TABLE ADD COLUMN` from now on. Chunks written before are
+    /// filled with the default values by the table reader.
+    fn add_columns(
+        &mut self,
+        columns: &[ColumnDesc],
+        table_reader_columns: Option<&TableV2ReaderColumns>,
+    ) {
+        if let SourceImpl::TableV2(t) = self.source_desc.source.as_ref() {
+            t.add_columns(table_reader_columns.unwrap(), columns.to_vec());
+        }
+        for column in columns {
+            self.column_ids.push(column.column_id);
+            self.schema.fields.push(Field::with_name(
+                column.data_type.clone(),
+                column.name.clone(),
+            ));
+        }
+    }
+
     #[try_stream(ok = Message, error = StreamExecutorError)]
     async fn into_stream(mut self) {
         let mut barrier_receiver = self.barrier_receiver.take().unwrap();
@@ -213,6 +232,10 @@ impl<S: StateStore> SourceExecutor<S> {
             }
         }
         .map_err(StreamExecutorError::source_error)?;
+        let table_reader_columns = match &stream_reader {
+            SourceStreamReaderImpl::TableV2(r) => Some(r.columns()),
+            _ => None,
+        };
 
         let reader = SourceReader {
             stream_reader: Box::new(stream_reader),
@@ -227,6 +250,9 @@ impl<S: StateStore> SourceExecutor<S> {
                 Either::Left(barrier) => {
                     match barrier.map_err(StreamExecutorError::source_error)? {
                         Message::Barrier(barrier) => {
+                            if let Some(columns) = barrier.added_columns(&self.source_id) {
+                                self.add_columns(columns, table_reader_columns.as_ref());
+                            }
                             let epoch = barrier.epoch.prev;
                             if self.state_cache.is_some() {
                                 self.split_state_store
@@ -328,6 +354,7 @@ mod tests {
                 name: String::new(),
                 field_descs: vec![],
                 type_name: "".to_string(),
+                default_value: None,
             },
             ColumnDesc {
                 column_id: ColumnId::from(1),
@@ -335,6 +362,7 @@ mod tests {
                 name: String::new(),
                 field_descs: vec![],
                 type_name: "".to_string(),
+                default_value: None,
             },
             ColumnDesc {
                 column_id: ColumnId::from(2),
@@ -342,6 +370,7 @@ mod tests {
                 name: String::new(),
                 field_descs: vec![],
                 type_name: "".to_string(),
+                default_value: None,
             },
         ];
         let source_manager = MemSourceManager::default();
@@ -456,6 +485,7 @@ mod tests {
                 name: String::new(),
                 field_descs: vec![],
                 type_name: "".to_string(),
+                default_value: None,
             },
             ColumnDesc {
                 column_id: ColumnId::from(1),
@@ -463,6 +493,7 @@ mod tests {
                 name: String::new(),
                 field_descs: vec![],
                 type_name: "".to_string(),
+                default_value: None,
             },
             ColumnDesc {
                 column_id: ColumnId::from(2),
@@ -470,6 +501,7 @@ mod tests {
                 name: String::new(),
                 field_descs: vec![],
                 type_name: "".to_string(),
+                default_value: None,
             },
         ];
         let source_manager = MemSourceManager::default();
@@ -0,0 +1,65 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::types::DataType;
+
+use super::*;
+use crate::executor::ExpandExecutor;
+
+pub struct ExpandExecutorBuilder;
+
+impl ExecutorBuilder for ExpandExecutorBuilder {
+    fn new_boxed_executor(
+        params: ExecutorParams,
+        node: &StreamNode,
+        _store: impl StateStore,
+        _stream: &mut LocalStreamManagerCore,
+    ) -> Result<BoxedExecutor> {
+        let ExecutorParams {
+            input,
+            pk_indices,
+            executor_id,
+            ..
+        } = params;
+        let expand_node = try_match_expand!(node.get_node_body().unwrap(), NodeBody::Expand)?;
+        let column_subsets = expand_node
+            .column_subsets
+            .iter()
+            .map(|subset| {
+                subset
+                    .column_indices
+                    .iter()
+                    .map(|key| *key as usize)
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        let input = input.into_iter().next().unwrap();
+        // TODO: reuse the schema deriviation with frontend.
+        let schema = {
+            let mut fields = input.schema().clone().into_fields();
+            fields.extend(fields.clone());
+            fields.push(Field::with_name(DataType::Int64, "flag"));
+            Schema::new(fields)
+        };
+        let info = ExecutorInfo {
+            schema,
+            identity: format!("ExpandExecutor {:X}", executor_id),
+            pk_indices,
+        };
+        Ok(ExpandExecutor::new(input, info, column_subsets).boxed())
+    }
+}
@@ -15,7 +15,7 @@
 use risingwave_expr::expr::build_from_prost;
 
 use super::*;
-use crate::executor::FilterExecutor;
+use crate::executor::{ExprErrorHandler, FilterExecutor};
 
 pub struct FilterExecutorBuilder;
 
@@ -28,9 +28,14 @@ impl ExecutorBuilder for FilterExecutorBuilder {
     ) -> Result<BoxedExecutor> {
         let node = try_match_expand!(node.get_node_body().unwrap(), NodeBody::Filter)?;
         let search_condition = build_from_prost(node.get_search_condition()?)?;
+        let error_handler = ExprErrorHandler::from_prost(
+            node.error_handling.as_ref(),
+            params.env.source_manager(),
+        )?;
 
         Ok(
             FilterExecutor::new(params.input.remove(0), search_condition, params.executor_id)
+                .with_error_handler(error_handler)
                 .boxed(),
         )
     }
@@ -100,6 +100,16 @@ impl ExecutorBuilder for HashJoinExecutorBuilder {
 
         let left_table_id = TableId::from(node.left_table_id);
         let right_table_id = TableId::from(node.right_table_id);
+        let mut keyspace_l = Keyspace::table_root(store.clone(), &left_table_id);
+        let mut keyspace_r = Keyspace::table_root(store, &right_table_id);
+        // Without join keys, the right side is broadcast to all actors and every row falls into
+        // the same empty key. Scope the states by actor so that an actor never loads the rows of
+        // another one.
+        if params_l.key_indices.is_empty() {
+            let actor_id = params.actor_id.to_be_bytes().to_vec();
+            keyspace_l = keyspace_l.append(actor_id.clone());
+            keyspace_r = keyspace_r.append(actor_id);
+        }
 
         let args = HashJoinExecutorDispatcherArgs {
             source_l,
@@ -111,8 +121,8 @@ impl ExecutorBuilder for HashJoinExecutorBuilder {
             cond: condition,
             op_info: params.op_info,
             key_indices,
-            keyspace_l: Keyspace::table_root(store.clone(), &left_table_id),
-            keyspace_r: Keyspace::table_root(store, &right_table_id),
+            keyspace_l,
+            keyspace_r,
         };
 
         for_all_join_types! { impl_create_hash_join_executor };
@@ -16,6 +16,7 @@
 
 mod batch_query;
 mod chain;
+mod expand;
 mod filter;
 mod global_simple_agg;
 mod hash_agg;
@@ -27,6 +28,7 @@ mod lookup_union;
 mod merge;
 mod mview;
 mod project;
+mod project_set;
 mod source;
 mod top_n;
 mod top_n_appendonly;
@@ -42,6 +44,7 @@ use risingwave_storage::{Keyspace, StateStore};
 
 use self::batch_query::*;
 use self::chain::*;
+use self::expand::*;
 use self::filter::*;
 use self::global_simple_agg::*;
 use self::hash_agg::*;
@@ -53,6 +56,7 @@ use self::lookup_union::*;
 use self::merge::*;
 use self::mview::*;
 use self::project::*;
+use self::project_set::*;
 use self::source::*;
 use self::top_n::*;
 use self::top_n_appendonly::*;
@@ -118,5 +122,7 @@ pub fn create_executor(
         NodeBody::Lookup => LookupExecutorBuilder,
         NodeBody::Union => UnionExecutorBuilder,
         NodeBody::LookupUnion => LookupUnionExecutorBuilder,
+        NodeBody::Expand => ExpandExecutorBuilder,
+        NodeBody::ProjectSet => ProjectSetExecutorBuilder,
     }
 }
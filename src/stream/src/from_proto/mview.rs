@@ -41,16 +41,26 @@ impl ExecutorBuilder for MaterializeExecutorBuilder {
             .map(|id| ColumnId::from(*id))
             .collect();
 
-        let keyspace = Keyspace::table_root(store, &table_id);
+        let keyspace = Keyspace::table_root(store.clone(), &table_id);
 
         let executor = MaterializeExecutor::new(
             params.input.remove(0),
+            table_id,
             keyspace,
             keys,
             column_ids,
             params.executor_id,
+            node.handle_pk_conflict,
         );
 
+        if node.unique_key_count > 0 {
+            // The index is distributed by its key, so the owners of the keys are only kept by
+            // this executor.
+            let keyspace = Keyspace::executor_root(store, params.executor_id);
+            let executor = executor.with_unique_key(keyspace, node.unique_key_count as usize);
+            return Ok(executor.boxed());
+        }
+
         Ok(executor.boxed())
     }
 }
@@ -66,7 +76,8 @@ impl ExecutorBuilder for ArrangeExecutorBuilder {
     ) -> Result<BoxedExecutor> {
         let arrange_node = try_match_expand!(node.get_node_body().unwrap(), NodeBody::Arrange)?;
 
-        let keyspace = Keyspace::table_root(store, &TableId::from(arrange_node.table_id));
+        let table_id = TableId::from(arrange_node.table_id);
+        let keyspace = Keyspace::table_root(store, &table_id);
 
         let keys = arrange_node
             .get_table_info()?
@@ -84,10 +95,12 @@ impl ExecutorBuilder for ArrangeExecutorBuilder {
 
         let executor = MaterializeExecutor::new(
             params.input.remove(0),
+            table_id,
             keyspace,
             keys,
             column_ids,
             params.executor_id,
+            false,
         );
 
         Ok(executor.boxed())
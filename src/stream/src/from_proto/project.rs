@@ -15,7 +15,7 @@
 use risingwave_expr::expr::build_from_prost;
 
 use super::*;
-use crate::executor::ProjectExecutor;
+use crate::executor::{ExprErrorHandler, ProjectExecutor};
 
 pub struct ProjectExecutorBuilder;
 
@@ -32,6 +32,10 @@ impl ExecutorBuilder for ProjectExecutorBuilder {
             .iter()
             .map(build_from_prost)
             .collect::<Result<Vec<_>>>()?;
+        let error_handler = ExprErrorHandler::from_prost(
+            node.error_handling.as_ref(),
+            params.env.source_manager(),
+        )?;
 
         Ok(ProjectExecutor::new(
             params.input.remove(0),
@@ -39,6 +43,7 @@ impl ExecutorBuilder for ProjectExecutorBuilder {
             project_exprs,
             params.executor_id,
         )
+        .with_error_handler(error_handler)
         .boxed())
     }
 }
@@ -0,0 +1,63 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::types::DataType;
+use risingwave_expr::table_function::ProjectSetSelectItem;
+
+use super::*;
+use crate::executor::ProjectSetExecutor;
+
+pub struct ProjectSetExecutorBuilder;
+
+impl ExecutorBuilder for ProjectSetExecutorBuilder {
+    fn new_boxed_executor(
+        params: ExecutorParams,
+        node: &StreamNode,
+        _store: impl StateStore,
+        _stream: &mut LocalStreamManagerCore,
+    ) -> Result<BoxedExecutor> {
+        let ExecutorParams {
+            input,
+            pk_indices,
+            executor_id,
+            ..
+        } = params;
+        let node = try_match_expand!(node.get_node_body().unwrap(), NodeBody::ProjectSet)?;
+        let select_list: Vec<_> = node
+            .get_select_list()
+            .iter()
+            .map(ProjectSetSelectItem::from_prost)
+            .try_collect()?;
+
+        let input = input.into_iter().next().unwrap();
+        let schema = {
+            let fields = std::iter::once(Field::with_name(DataType::Int64, "projected_row_id"))
+                .chain(
+                    select_list
+                        .iter()
+                        .map(|item| Field::unnamed(item.return_type())),
+                )
+                .collect();
+            Schema::new(fields)
+        };
+        let info = ExecutorInfo {
+            schema,
+            identity: format!("ProjectSetExecutor {:X}", executor_id),
+            pk_indices,
+        };
+        Ok(ProjectSetExecutor::new(input, info, select_list).boxed())
+    }
+}
@@ -44,7 +44,13 @@ impl PgFieldDescriptor {
             | TypeOid::Time
             | TypeOid::Timestampz => 8,
             TypeOid::SmallInt => 2,
-            TypeOid::CharArray | TypeOid::Varchar | TypeOid::Decimal => -1,
+            TypeOid::Uuid | TypeOid::Interval => 16,
+            TypeOid::CharArray
+            | TypeOid::Varchar
+            | TypeOid::Bpchar
+            | TypeOid::Decimal
+            | TypeOid::Jsonb
+            | TypeOid::Bytea => -1,
         };
 
         Self {
@@ -102,6 +108,11 @@ pub enum TypeOid {
     Timestamp,
     Timestampz,
     Decimal,
+    Interval,
+    Jsonb,
+    Bytea,
+    Uuid,
+    Bpchar,
 }
 
 impl TypeOid {
@@ -120,6 +131,11 @@ impl TypeOid {
             TypeOid::Timestamp => 1114,
             TypeOid::Timestampz => 1184,
             TypeOid::Decimal => 1231,
+            TypeOid::Interval => 1186,
+            TypeOid::Jsonb => 3802,
+            TypeOid::Bytea => 17,
+            TypeOid::Uuid => 2950,
+            TypeOid::Bpchar => 1042,
         }
     }
 }
@@ -12,7 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::io::{Error, ErrorKind, IoSlice, Result, Write};
+use std::collections::HashMap;
+use std::io::{Error, ErrorKind, Result};
 
 use byteorder::{BigEndian, ByteOrder};
 /// Part of code learned from https://github.com/zenithdb/zenith/blob/main/zenith_utils/src/pq_proto.rs.
@@ -32,7 +33,10 @@ pub enum FeMessage {
     Terminate,
 }
 
-pub struct FeStartupMessage {}
+pub struct FeStartupMessage {
+    /// Parameters sent by the client, e.g. `user` and `database`.
+    pub config: HashMap<String, String>,
+}
 
 /// Query message contains the string sql.
 pub struct FeQueryMessage {
@@ -74,6 +78,26 @@ impl FeMessage {
 }
 
 impl FeStartupMessage {
+    /// Parse the parameters in startup message, which are pairs of null-terminated strings and end
+    /// with an extra null byte.
+    fn parse_config(payload: &[u8]) -> Result<HashMap<String, String>> {
+        let invalid = || Error::new(ErrorKind::InvalidInput, "invalid startup parameters");
+        let mut strings = payload
+            .split(|b| *b == 0)
+            .map(|s| String::from_utf8(s.to_vec()).map_err(|_| invalid()));
+        let mut config = HashMap::new();
+        loop {
+            match strings.next().transpose()? {
+                Some(key) if !key.is_empty() => {
+                    let value = strings.next().ok_or_else(invalid)??;
+                    config.insert(key, value);
+                }
+                _ => break,
+            }
+        }
+        Ok(config)
+    }
+
     /// Read startup message from the stream.
     pub async fn read(stream: &mut (impl AsyncRead + Unpin)) -> Result<FeMessage> {
         let len = stream.read_i32().await?;
@@ -85,7 +109,9 @@ impl FeStartupMessage {
         }
         match protocol_num {
             // code from: https://www.postgresql.org/docs/current/protocol-message-formats.html
-            196608 => Ok(FeMessage::Startup(FeStartupMessage {})),
+            196608 => Ok(FeMessage::Startup(FeStartupMessage {
+                config: Self::parse_config(&payload)?,
+            })),
             80877103 => Ok(FeMessage::Ssl),
             // Cancel request code.
             80877102 => Ok(FeMessage::CancelQuery),
@@ -121,6 +147,11 @@ pub enum BeParameterStatusMessage<'a> {
     ClientEncoding(&'a str),
     StandardConformingString(&'a str),
     ServerVersion(&'a str),
+    /// A session variable whose value is changed, e.g. by `SET`.
+    Changed {
+        name: &'a str,
+        value: &'a str,
+    },
 }
 
 #[derive(Debug)]
@@ -172,19 +203,16 @@ impl<'a> BeMessage<'a> {
                         [b"standard_conforming_strings", val.as_bytes()]
                     }
                     ServerVersion(val) => [b"server_version", val.as_bytes()],
+                    Changed { name, value } => [name.as_bytes(), value.as_bytes()],
                 };
 
                 // Parameter names and values are passed as null-terminated strings
-                let iov = &mut [name, b"\0", value, b"\0"].map(IoSlice::new);
-                let mut buffer = [0u8; 64]; // this should be enough
-                let cnt = buffer.as_mut().write_vectored(iov).unwrap();
-
                 buf.put_u8(b'S');
                 write_body(buf, |stream| {
-                    stream.put_slice(&buffer[..cnt]);
+                    write_cstr(stream, name)?;
+                    write_cstr(stream, value)?;
                     Ok(())
-                })
-                .unwrap();
+                })?;
             }
 
             // CommandComplete
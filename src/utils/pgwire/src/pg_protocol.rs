@@ -96,10 +96,13 @@ where
                     })?;
             }
             FeMessage::Startup(msg) => {
-                self.process_startup_msg(msg).map_err(|e| {
+                if let Err(e) = self.process_startup_msg(msg) {
                     tracing::error!("failed to set up pg session: {}", e);
-                    e
-                })?;
+                    // Report the error to the client and close the connection.
+                    self.write_message_no_flush(&BeMessage::ErrorResponse(Box::new(e)))?;
+                    self.flush().await?;
+                    return Ok(true);
+                }
                 self.state = PgProtocolState::Regular;
             }
             FeMessage::Query(query_msg) => {
@@ -125,9 +128,14 @@ where
         }
     }
 
-    fn process_startup_msg(&mut self, _msg: FeStartupMessage) -> Result<()> {
+    fn process_startup_msg(&mut self, msg: FeStartupMessage) -> Result<()> {
         // TODO: Replace `DEFAULT_DATABASE_NAME` with true database name in `FeStartupMessage`.
-        self.session = Some(self.session_mgr.connect("dev").map_err(IoError::other)?);
+        let user_name = msg.config.get("user").map(String::as_str);
+        self.session = Some(
+            self.session_mgr
+                .connect("dev", user_name)
+                .map_err(IoError::other)?,
+        );
         self.write_message_no_flush(&BeMessage::AuthenticationOk)?;
         self.write_message_no_flush(&BeMessage::ParameterStatus(
             BeParameterStatusMessage::ClientEncoding("utf8"),
@@ -154,6 +162,11 @@ where
         let process_res = session.run_statement(query.get_sql()).await;
         match process_res {
             Ok(res) => {
+                for (name, value) in res.get_parameter_status() {
+                    self.write_message_no_flush(&BeMessage::ParameterStatus(
+                        BeParameterStatusMessage::Changed { name, value },
+                    ))?;
+                }
                 if res.is_empty() {
                     self.write_message_no_flush(&BeMessage::EmptyQueryResponse)?;
                 } else if res.is_query() {
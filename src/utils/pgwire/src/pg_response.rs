@@ -31,15 +31,26 @@ pub enum StatementType {
     EXPLAIN,
     CREATE_TABLE,
     CREATE_MATERIALIZED_VIEW,
+    CREATE_VIEW,
+    CREATE_INDEX,
     CREATE_SOURCE,
     CREATE_DATABASE,
     CREATE_SCHEMA,
+    CREATE_USER,
     DESCRIBE_TABLE,
     DROP_TABLE,
     DROP_MATERIALIZED_VIEW,
+    DROP_VIEW,
     DROP_SOURCE,
     DROP_SCHEMA,
     DROP_DATABASE,
+    DROP_USER,
+    ALTER_USER,
+    ALTER_TABLE,
+    ALTER_MATERIALIZED_VIEW,
+    RESET,
+    GRANT_PRIVILEGE,
+    REVOKE_PRIVILEGE,
     // Introduce ORDER_BY statement type cuz Calcite unvalidated AST has SqlKind.ORDER_BY. Note
     // that Statement Type is not designed to be one to one mapping with SqlKind.
     ORDER_BY,
@@ -67,6 +78,8 @@ pub struct PgResponse {
     notice: Option<String>,
     values: Vec<Row>,
     row_desc: Vec<PgFieldDescriptor>,
+    /// The names and new values of the session variables to report to the client.
+    parameter_status: Vec<(String, String)>,
 }
 
 impl StatementType {
@@ -97,6 +110,7 @@ impl PgResponse {
             values,
             row_desc,
             notice: None,
+            parameter_status: vec![],
         }
     }
 
@@ -111,9 +125,15 @@ impl PgResponse {
             values: vec![],
             row_desc: vec![],
             notice: Some(notice),
+            parameter_status: vec![],
         }
     }
 
+    pub fn with_parameter_status(mut self, parameter_status: Vec<(String, String)>) -> Self {
+        self.parameter_status = parameter_status;
+        self
+    }
+
     pub fn get_stmt_type(&self) -> StatementType {
         self.stmt_type
     }
@@ -122,6 +142,10 @@ impl PgResponse {
         self.notice.clone()
     }
 
+    pub fn get_parameter_status(&self) -> &[(String, String)] {
+        &self.parameter_status
+    }
+
     pub fn get_effected_rows_cnt(&self) -> i32 {
         self.row_cnt
     }
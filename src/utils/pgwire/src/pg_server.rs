@@ -26,7 +26,12 @@ use crate::pg_response::PgResponse;
 /// The interface for a database system behind pgwire protocol.
 /// We can mock it for testing purpose.
 pub trait SessionManager: Send + Sync {
-    fn connect(&self, database: &str) -> Result<Arc<dyn Session>, Box<dyn Error + Send + Sync>>;
+    /// `user_name` is `None` if the client doesn't specify one in the startup message.
+    fn connect(
+        &self,
+        database: &str,
+        user_name: Option<&str>,
+    ) -> Result<Arc<dyn Session>, Box<dyn Error + Send + Sync>>;
 }
 
 /// A psql connection. Each connection binds with a database. Switching database will need to